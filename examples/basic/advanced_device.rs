@@ -8,7 +8,7 @@
 //! - Status flags and reliability
 
 use bacnet_rs::{
-    app::{Apdu, MaxApduSize, MaxSegments},
+    app::{Apdu, MaxApdu, MaxSegments},
     network::Npdu,
     object::{
         AnalogInput, AnalogOutput, AnalogValue, BacnetObject, BinaryInput, BinaryOutput, BinaryPV,
@@ -117,15 +117,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let ao_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
 
     let ai_props = vec![
-        PropertyReference::new(PropertyIdentifier::ObjectName as u32),
-        PropertyReference::new(PropertyIdentifier::PresentValue as u32),
-        PropertyReference::new(PropertyIdentifier::OutOfService as u32),
+        PropertyReference::new(PropertyIdentifier::ObjectName.as_u32()),
+        PropertyReference::new(PropertyIdentifier::PresentValue.as_u32()),
+        PropertyReference::new(PropertyIdentifier::OutOfService.as_u32()),
     ];
 
     let ao_props = vec![
-        PropertyReference::new(PropertyIdentifier::ObjectName as u32),
-        PropertyReference::new(PropertyIdentifier::PresentValue as u32),
-        PropertyReference::new(PropertyIdentifier::PriorityArray as u32),
+        PropertyReference::new(PropertyIdentifier::ObjectName.as_u32()),
+        PropertyReference::new(PropertyIdentifier::PresentValue.as_u32()),
+        PropertyReference::new(PropertyIdentifier::PriorityArray.as_u32()),
     ];
 
     let ai_spec = ReadAccessSpecification::new(ai_id, ai_props);
@@ -165,8 +165,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("Active subscriptions: {}", cov_manager.active_count());
 
     // Simulate time passing
-    cov_manager.update_timers(1800); // 30 minutes
-    println!("After 30 minutes:");
+    let expired = cov_manager.update_timers(1800); // 30 minutes
+    println!("After 30 minutes ({} expired):", expired.len());
 
     let ai_subs = cov_manager.get_subscriptions_for_object(ai_id);
     if !ai_subs.is_empty() {
@@ -197,7 +197,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Write Property APDU
     let write_prop = WritePropertyRequest::with_priority(
         ao_id,
-        PropertyIdentifier::PresentValue as u32,
+        PropertyIdentifier::PresentValue.as_u32(),
         vec![0x44, 0x42, 0x96, 0x00, 0x00], // Real 75.0 encoded
         8,
     );
@@ -210,7 +210,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         more_follows: false,
         segmented_response_accepted: true,
         max_segments: MaxSegments::Unspecified,
-        max_response_size: MaxApduSize::Up1476,
+        max_response_size: MaxApdu::Up1476,
         invoke_id: 42,
         sequence_number: None,
         proposed_window_size: None,
@@ -227,7 +227,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         more_follows: false,
         segmented_response_accepted: true,
         max_segments: MaxSegments::Unspecified,
-        max_response_size: MaxApduSize::Up1476,
+        max_response_size: MaxApdu::Up1476,
         invoke_id: 43,
         sequence_number: None,
         proposed_window_size: None,
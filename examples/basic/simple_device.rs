@@ -8,10 +8,12 @@
 //! - Process Read Property requests
 
 use bacnet_rs::{
-    app::{Apdu, MaxApduSize, MaxSegments},
+    app::{Apdu, MaxApdu, MaxSegments},
     datalink::{bip::BacnetIpDataLink, DataLink},
     network::Npdu,
-    object::{BacnetObject, Device, ObjectIdentifier, ObjectType, PropertyIdentifier},
+    object::{
+        BacnetObject, Device, ObjectIdentifier, ObjectType, PropertyIdentifier, Segmentation,
+    },
     service::{IAmRequest, ReadPropertyRequest, UnconfirmedServiceChoice, WhoIsRequest},
     ConfirmedServiceChoice,
 };
@@ -83,7 +85,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Create an I-Am response
     let device_id = ObjectIdentifier::new(ObjectType::Device, 12345);
-    let _iam = IAmRequest::new(device_id, 1476, 0, 999);
+    let _iam = IAmRequest::new(device_id, 1476, Segmentation::Both, 999);
     println!("Created I-Am response");
 
     // Create APDU examples
@@ -108,7 +110,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Confirmed request (Read Property)
-    let read_prop = ReadPropertyRequest::new(device_id, PropertyIdentifier::ObjectName as u32);
+    let read_prop = ReadPropertyRequest::new(device_id, PropertyIdentifier::ObjectName.as_u32());
 
     let mut read_prop_data = Vec::new();
     read_prop.encode(&mut read_prop_data)?;
@@ -118,7 +120,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         more_follows: false,
         segmented_response_accepted: true,
         max_segments: MaxSegments::Unspecified,
-        max_response_size: MaxApduSize::Up1476,
+        max_response_size: MaxApdu::Up1476,
         invoke_id: 42,
         sequence_number: None,
         proposed_window_size: None,
@@ -6,6 +6,7 @@
 use bacnet_rs::client::{get_object_type_name, BacnetClient};
 use bacnet_rs::property::{decode_units, get_unit_id};
 use std::env;
+use std::net::SocketAddr;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
@@ -16,7 +17,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     let target_ip = &args[1];
-    let target_addr = format!("{}:47808", target_ip).parse()?;
+    let target_addr: SocketAddr = format!("{}:47808", target_ip).parse()?;
 
     println!("BACnet Client Test with Comprehensive Units");
     println!("===========================================\n");
@@ -4,7 +4,7 @@
 //! the response parsing more clearly.
 
 use bacnet_rs::{
-    app::{Apdu, MaxApduSize, MaxSegments},
+    app::{Apdu, MaxApdu, MaxSegments},
     network::Npdu,
     object::{ObjectIdentifier, ObjectType},
     property::decode_units,
@@ -286,7 +286,7 @@ fn send_confirmed_request(
         more_follows: false,
         segmented_response_accepted: true,
         max_segments: MaxSegments::Unspecified,
-        max_response_size: MaxApduSize::Up1476,
+        max_response_size: MaxApdu::Up1476,
         invoke_id,
         sequence_number: None,
         proposed_window_size: None,
@@ -396,7 +396,7 @@ fn encode_rpm_request(
 
     for spec in &request.read_access_specifications {
         let object_id = encode_object_id(
-            spec.object_identifier.object_type as u16,
+            spec.object_identifier.object_type.as_u16(),
             spec.object_identifier.instance,
         );
         buffer.push(0x0C);
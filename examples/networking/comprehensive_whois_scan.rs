@@ -5,7 +5,7 @@
 
 use bacnet_rs::{
     datalink::bip::{BvlcFunction, BvlcHeader},
-    network::{NetworkAddress, Npdu},
+    network::{NetworkAddress, NetworkMessageType, Npdu},
     object::{ObjectType, PropertyIdentifier},
     service::{IAmRequest, ReadPropertyResponse, WhoIsRequest},
     vendor::get_vendor_name,
@@ -140,11 +140,8 @@ fn discover_routers(
     let mut routers = HashMap::new();
 
     // Create Who-Is-Router-To-Network NPDU
-    let mut npdu = Npdu::new();
-    npdu.control.network_message = true;
-
-    let network_msg = vec![0x01]; // Who-Is-Router-To-Network
-    let npdu_bytes = encode_npdu_with_data(&npdu, &network_msg);
+    let npdu = Npdu::network_message(NetworkMessageType::WhoIsRouterToNetwork);
+    let npdu_bytes = npdu.encode();
 
     let header = BvlcHeader::new(
         BvlcFunction::OriginalBroadcastNpdu,
@@ -165,11 +162,10 @@ fn discover_routers(
             if len > 4 {
                 let npdu_data = &buffer[4..len];
                 if let Ok((npdu, offset)) = Npdu::decode(npdu_data) {
-                    if npdu.is_network_message()
-                        && npdu_data.len() > offset
-                        && npdu_data[offset] == 0x02
+                    if npdu.network_message_type
+                        == Some(NetworkMessageType::IAmRouterToNetwork as u8)
                     {
-                        let mut idx = offset + 1;
+                        let mut idx = offset;
                         while idx + 1 < npdu_data.len() {
                             let network = u16::from_be_bytes([npdu_data[idx], npdu_data[idx + 1]]);
                             routers.insert(network, src_addr);
@@ -285,7 +281,7 @@ fn collect_i_am_responses(
                                         vendor_id: i_am.vendor_identifier,
                                         vendor_name: vendor_name.to_string(),
                                         max_apdu: i_am.max_apdu_length_accepted,
-                                        segmentation: i_am.segmentation_supported,
+                                        segmentation: i_am.segmentation_supported as u32,
                                         object_name: None,
                                         model_name: None,
                                         firmware_revision: None,
@@ -332,7 +328,8 @@ fn analyze_device(
     // Read basic device properties
     println!("   📋 Reading device properties...");
 
-    if let Ok(name) = read_device_property(socket, device, PropertyIdentifier::ObjectName as u32) {
+    if let Ok(name) = read_device_property(socket, device, PropertyIdentifier::ObjectName.as_u32())
+    {
         if let Ok(parsed_name) = parse_string_from_response(&name) {
             // Clean up device names with null bytes and control characters
             let cleaned_name = parsed_name
@@ -348,15 +345,18 @@ fn analyze_device(
         }
     }
 
-    if let Ok(model) = read_device_property(socket, device, PropertyIdentifier::ModelName as u32) {
+    if let Ok(model) = read_device_property(socket, device, PropertyIdentifier::ModelName.as_u32())
+    {
         if let Ok(parsed_model) = parse_string_from_response(&model) {
             device.model_name = Some(parsed_model);
         }
     }
 
-    if let Ok(firmware) =
-        read_device_property(socket, device, PropertyIdentifier::FirmwareRevision as u32)
-    {
+    if let Ok(firmware) = read_device_property(
+        socket,
+        device,
+        PropertyIdentifier::FirmwareRevision.as_u32(),
+    ) {
         if let Ok(parsed_firmware) = parse_string_from_response(&firmware) {
             device.firmware_revision = Some(parsed_firmware);
         }
@@ -380,7 +380,7 @@ fn analyze_device(
                     socket,
                     device,
                     &device.objects[i],
-                    PropertyIdentifier::ObjectName as u32,
+                    PropertyIdentifier::ObjectName.as_u32(),
                 ) {
                     if let Ok(parsed_name) = parse_string_from_response(&name) {
                         // Clean up object names - remove null bytes and control characters
@@ -424,7 +424,7 @@ fn analyze_device(
                         socket,
                         device,
                         &device.objects[i],
-                        PropertyIdentifier::PresentValue as u32,
+                        PropertyIdentifier::PresentValue.as_u32(),
                     ) {
                         // Special handling for binary objects
                         if let Ok(parsed_value) = parse_value_from_response(&value) {
@@ -443,7 +443,7 @@ fn analyze_device(
                             socket,
                             device,
                             &device.objects[i],
-                            PropertyIdentifier::OutputUnits as u32,
+                            PropertyIdentifier::OutputUnits.as_u32(),
                         ) {
                             if let Ok(parsed_units) = parse_units_from_response(&units) {
                                 device.objects[i].description =
@@ -466,13 +466,13 @@ fn analyze_device(
     //         let objects_to_read = std::cmp::min(device.objects.len(), 10);
     //         for i in 0..objects_to_read {
     //             // Read object name
-    //             if let Ok(name) = read_object_property(socket, device, &device.objects[i], PropertyIdentifier::ObjectName as u32) {
+    //             if let Ok(name) = read_object_property(socket, device, &device.objects[i], PropertyIdentifier::ObjectName.as_u32()) {
     //                 device.objects[i].name = Some(name);
     //             }
     //
     //             // Read present value for I/O objects
     //             if is_io_object(device.objects[i].object_type) {
-    //                 if let Ok(value) = read_object_property(socket, device, &device.objects[i], PropertyIdentifier::PresentValue as u32) {
+    //                 if let Ok(value) = read_object_property(socket, device, &device.objects[i], PropertyIdentifier::PresentValue.as_u32()) {
     //                     device.objects[i].present_value = Some(value);
     //                 }
     //             }
@@ -509,7 +509,7 @@ fn read_device_property(
 
     // Context tag 0: Object Identifier (BACnetObjectIdentifier)
     // Encode as 4-byte object identifier: (object_type << 22) | instance
-    let object_type = ObjectType::Device as u32; // 8
+    let object_type = ObjectType::Device.as_u16() as u32; // 8
     let object_id = (object_type << 22) | (device.device_id & 0x3FFFFF);
     apdu.push(0x0C); // Context tag [0], length 4
     apdu.extend_from_slice(&object_id.to_be_bytes());
@@ -535,7 +535,8 @@ fn try_read_object_list_multiple_approaches(
     device: &mut BACnetDevice,
 ) -> Result<usize, Box<dyn std::error::Error>> {
     // First try to read the array length (index 0)
-    match read_property_with_array_index(socket, device, PropertyIdentifier::ObjectList as u32, 0) {
+    match read_property_with_array_index(socket, device, PropertyIdentifier::ObjectList.as_u32(), 0)
+    {
         Ok(length_response) => {
             // Parse the length - it might be a number like "84" or an encoded value
             let length = length_response.parse::<u32>().unwrap_or(100);
@@ -546,7 +547,7 @@ fn try_read_object_list_multiple_approaches(
                 match read_property_with_array_index(
                     socket,
                     device,
-                    PropertyIdentifier::ObjectList as u32,
+                    PropertyIdentifier::ObjectList.as_u32(),
                     i,
                 ) {
                     Ok(obj_response) => {
@@ -655,7 +656,7 @@ fn read_device_property_simple(
     ];
 
     // Object ID for device
-    let obj_id = ((ObjectType::Device as u32) << 22) | (device.device_id & 0x3FFFFF);
+    let obj_id = ((ObjectType::Device.as_u16() as u32) << 22) | (device.device_id & 0x3FFFFF);
     apdu.push(0x0C); // Context tag 0, length 4
     apdu.extend_from_slice(&obj_id.to_be_bytes());
 
@@ -684,7 +685,7 @@ fn read_device_property_alternative(
     ];
 
     // Object ID for device
-    let obj_id = ((ObjectType::Device as u32) << 22) | (device.device_id & 0x3FFFFF);
+    let obj_id = ((ObjectType::Device.as_u16() as u32) << 22) | (device.device_id & 0x3FFFFF);
     apdu.push(0x0C); // Context tag 0, length 4
     apdu.extend_from_slice(&obj_id.to_be_bytes());
 
@@ -701,7 +702,7 @@ fn read_object_list(
 ) -> Result<usize, Box<dyn std::error::Error>> {
     // First try to read the entire object list at once
     println!("   🔍 Attempting to read entire object list at once...");
-    match read_device_property(socket, device, PropertyIdentifier::ObjectList as u32) {
+    match read_device_property(socket, device, PropertyIdentifier::ObjectList.as_u32()) {
         Ok(obj_list_data) => {
             println!(
                 "   ✅ Received object list response: {} bytes",
@@ -737,7 +738,8 @@ fn read_object_list(
 
     // Fallback to reading array length first
     println!("   🔄 Falling back to reading object list by array indices...");
-    match read_property_with_array_index(socket, device, PropertyIdentifier::ObjectList as u32, 0) {
+    match read_property_with_array_index(socket, device, PropertyIdentifier::ObjectList.as_u32(), 0)
+    {
         Ok(length_str) => {
             if let Ok(length) = length_str.parse::<u32>() {
                 println!("   📊 Object list has {} items", length);
@@ -753,7 +755,7 @@ fn read_object_list(
                     match read_property_with_array_index(
                         socket,
                         device,
-                        PropertyIdentifier::ObjectList as u32,
+                        PropertyIdentifier::ObjectList.as_u32(),
                         i,
                     ) {
                         Ok(obj_data) => {
@@ -796,7 +798,7 @@ fn read_object_list(
                 match read_property_with_array_index(
                     socket,
                     device,
-                    PropertyIdentifier::ObjectList as u32,
+                    PropertyIdentifier::ObjectList.as_u32(),
                     i,
                 ) {
                     Ok(obj_data) => {
@@ -846,7 +848,7 @@ fn read_property_with_array_index(
 
     // Context tag 0: Object Identifier (BACnetObjectIdentifier)
     // Encode as 4-byte object identifier: (object_type << 22) | instance
-    let object_type = ObjectType::Device as u32; // 8
+    let object_type = ObjectType::Device.as_u16() as u32; // 8
     let object_id = (object_type << 22) | (device.device_id & 0x3FFFFF);
     apdu.push(0x0C); // Context tag [0], length 4
     apdu.extend_from_slice(&object_id.to_be_bytes());
@@ -897,7 +899,7 @@ fn read_object_property_simple(
     ];
 
     // Object ID
-    let obj_id = ((object.object_type as u32) << 22) | (object.instance & 0x3FFFFF);
+    let obj_id = ((object.object_type.as_u16() as u32) << 22) | (object.instance & 0x3FFFFF);
     apdu.push(0x0C); // Context tag 0, length 4
     apdu.extend_from_slice(&obj_id.to_be_bytes());
 
@@ -931,7 +933,7 @@ fn read_object_property(
 
     // Context tag 0: Object Identifier (BACnetObjectIdentifier)
     // Encode as 4-byte object identifier: (object_type << 22) | instance
-    let object_id = ((object.object_type as u32) << 22) | (object.instance & 0x3FFFFF);
+    let object_id = ((object.object_type.as_u16() as u32) << 22) | (object.instance & 0x3FFFFF);
     apdu.push(0x0C); // Context tag [0], length 4
     apdu.extend_from_slice(&object_id.to_be_bytes());
 
@@ -5,7 +5,7 @@
 
 use bacnet_rs::{
     datalink::bip::{BvlcFunction, BvlcHeader},
-    network::{NetworkAddress, Npdu},
+    network::{NetworkAddress, NetworkMessageType, Npdu},
     object::{ObjectType, PropertyIdentifier},
     service::{IAmRequest, ReadPropertyResponse, WhoIsRequest},
     vendor::get_vendor_name,
@@ -151,12 +151,8 @@ fn discover_routers(
     let mut routers = HashMap::new();
 
     // Create Who-Is-Router-To-Network NPDU (network layer message)
-    let mut npdu = Npdu::new();
-    npdu.control.network_message = true;
-
-    // Network message data: message type 0x01 (Who-Is-Router-To-Network)
-    let network_msg = vec![0x01];
-    let npdu_bytes = encode_npdu_with_data(&npdu, &network_msg);
+    let npdu = Npdu::network_message(NetworkMessageType::WhoIsRouterToNetwork);
+    let npdu_bytes = npdu.encode();
 
     // Wrap in BVLC header for broadcast
     let header = BvlcHeader::new(
@@ -186,11 +182,13 @@ fn discover_routers(
                     // Skip BVLC header (4 bytes)
                     let npdu_data = &buffer[4..len];
                     if let Ok((npdu, offset)) = Npdu::decode(npdu_data) {
-                        if npdu.is_network_message() && npdu_data.len() > offset {
-                            // Check if this is I-Am-Router-To-Network (0x02)
-                            if npdu_data[offset] == 0x02 {
+                        if npdu.is_network_message() {
+                            // Check if this is I-Am-Router-To-Network
+                            if npdu.network_message_type
+                                == Some(NetworkMessageType::IAmRouterToNetwork as u8)
+                            {
                                 // Parse network numbers
-                                let mut idx = offset + 1;
+                                let mut idx = offset;
                                 while idx + 1 < npdu_data.len() {
                                     let network =
                                         u16::from_be_bytes([npdu_data[idx], npdu_data[idx + 1]]);
@@ -382,11 +380,11 @@ fn read_device_properties(socket: &UdpSocket, device: &mut RemoteDevice) {
 
     // First read basic device properties
     let basic_properties = vec![
-        (PropertyIdentifier::ObjectName as u32, "Object Name"),
-        (PropertyIdentifier::ModelName as u32, "Model Name"),
-        (PropertyIdentifier::VendorName as u32, "Vendor Name"),
+        (PropertyIdentifier::ObjectName.as_u32(), "Object Name"),
+        (PropertyIdentifier::ModelName.as_u32(), "Model Name"),
+        (PropertyIdentifier::VendorName.as_u32(), "Vendor Name"),
         (
-            PropertyIdentifier::FirmwareRevision as u32,
+            PropertyIdentifier::FirmwareRevision.as_u32(),
             "Firmware Revision",
         ),
     ];
@@ -445,7 +443,7 @@ fn read_device_properties(socket: &UdpSocket, device: &mut RemoteDevice) {
                     socket,
                     device,
                     obj_id,
-                    PropertyIdentifier::ObjectName as u32,
+                    PropertyIdentifier::ObjectName.as_u32(),
                 ) {
                     Ok(name) => println!("      Name: {}", name),
                     Err(_) => println!("      Name: <unavailable>"),
@@ -457,7 +455,7 @@ fn read_device_properties(socket: &UdpSocket, device: &mut RemoteDevice) {
                         socket,
                         device,
                         obj_id,
-                        PropertyIdentifier::PresentValue as u32,
+                        PropertyIdentifier::PresentValue.as_u32(),
                     ) {
                         println!("      Present Value: {}", value);
                     }
@@ -497,7 +495,7 @@ fn read_property(
 
     // Context tag 0: Object Identifier (BACnetObjectIdentifier)
     // Encode as 4-byte object identifier: (object_type << 22) | instance
-    let object_type = ObjectType::Device as u32; // 8
+    let object_type = ObjectType::Device.as_u16() as u32; // 8
     let object_id = (object_type << 22) | (device.device_id & 0x3FFFFF);
     apdu.push(0x0C); // Context tag [0], length 4
     apdu.extend_from_slice(&object_id.to_be_bytes());
@@ -575,24 +573,18 @@ fn read_property(
 
                             if resp_invoke_id == (invoke_id & 0x0F) {
                                 match pdu_type {
-                                    0x3 => {
-                                        // PDU_TYPE_COMPLEX_ACK
-                                        // Complex ACK format: [PDU_TYPE+invoke_id] [service_choice] [service_data...]
-                                        if apdu_data.len() >= 2 && apdu_data[1] == 0x0C {
-                                            // SERVICE_CONFIRMED_READ_PROPERTY
-                                            // Parse ReadProperty-ACK service data starting at byte 2
-                                            if let Ok(response) =
-                                                ReadPropertyResponse::decode(&apdu_data[2..])
-                                            {
-                                                return extract_string_value(
-                                                    &response.property_value,
-                                                );
-                                            } else {
-                                                // Try manual parsing if decode fails
-                                                return parse_read_property_ack_manual(
-                                                    &apdu_data[2..],
-                                                );
-                                            }
+                                    // PDU_TYPE_COMPLEX_ACK
+                                    // Complex ACK format: [PDU_TYPE+invoke_id] [service_choice] [service_data...]
+                                    // SERVICE_CONFIRMED_READ_PROPERTY
+                                    0x3 if apdu_data.len() >= 2 && apdu_data[1] == 0x0C => {
+                                        // Parse ReadProperty-ACK service data starting at byte 2
+                                        if let Ok(response) =
+                                            ReadPropertyResponse::decode(&apdu_data[2..])
+                                        {
+                                            return extract_string_value(&response.property_value);
+                                        } else {
+                                            // Try manual parsing if decode fails
+                                            return parse_read_property_ack_manual(&apdu_data[2..]);
                                         }
                                     }
                                     0x5 => {
@@ -778,7 +770,7 @@ fn read_object_list(
     device: &RemoteDevice,
 ) -> Result<Vec<BACnetObjectId>, Box<dyn std::error::Error>> {
     // Read Object-List property (property ID 76)
-    match read_property(socket, device, PropertyIdentifier::ObjectList as u32) {
+    match read_property(socket, device, PropertyIdentifier::ObjectList.as_u32()) {
         Ok(_raw_data) => {
             // The Object-List is typically too large to read at once, so we might get an error
             // Let's try reading it with array indices
@@ -799,7 +791,8 @@ fn read_object_list_with_indices(
     let mut objects = Vec::new();
 
     // First try to read index 0 to get the array length
-    match read_property_with_array_index(socket, device, PropertyIdentifier::ObjectList as u32, 0) {
+    match read_property_with_array_index(socket, device, PropertyIdentifier::ObjectList.as_u32(), 0)
+    {
         Ok(length_str) => {
             if let Ok(length) = length_str.parse::<u32>() {
                 println!("    Object-List has {} objects", length);
@@ -810,7 +803,7 @@ fn read_object_list_with_indices(
                     match read_property_with_array_index(
                         socket,
                         device,
-                        PropertyIdentifier::ObjectList as u32,
+                        PropertyIdentifier::ObjectList.as_u32(),
                         i,
                     ) {
                         Ok(obj_data) => {
@@ -831,7 +824,7 @@ fn read_object_list_with_indices(
                 match read_property_with_array_index(
                     socket,
                     device,
-                    PropertyIdentifier::ObjectList as u32,
+                    PropertyIdentifier::ObjectList.as_u32(),
                     i,
                 ) {
                     Ok(obj_data) => {
@@ -871,7 +864,7 @@ fn read_object_property(
 
     // ReadProperty Service Data
     // Context tag 0: Object Identifier
-    let object_type = object_id.object_type as u32;
+    let object_type = object_id.object_type.as_u16() as u32;
     let obj_id = (object_type << 22) | (object_id.instance & 0x3FFFFF);
     apdu.push(0x0C); // Context tag [0], length 4
     apdu.extend_from_slice(&obj_id.to_be_bytes());
@@ -972,7 +965,7 @@ fn read_property_with_array_index(
 
     // ReadProperty Service Data
     // Context tag 0: Object Identifier (Device)
-    let object_type = ObjectType::Device as u32;
+    let object_type = ObjectType::Device.as_u16() as u32;
     let obj_id = (object_type << 22) | (device.device_id & 0x3FFFFF);
     apdu.push(0x0C); // Context tag [0], length 4
     apdu.extend_from_slice(&obj_id.to_be_bytes());
@@ -248,7 +248,7 @@ fn process_response(data: &[u8], source: SocketAddr) -> Option<DiscoveredDevice>
                 vendor_id: iam.vendor_identifier,
                 vendor_name,
                 max_apdu: iam.max_apdu_length_accepted,
-                segmentation: iam.segmentation_supported,
+                segmentation: iam.segmentation_supported as u32,
             })
         }
         Err(_) => None,
@@ -4,7 +4,7 @@
 //! BACnet device using ReadPropertyMultiple requests.
 
 use bacnet_rs::{
-    app::{Apdu, MaxApduSize, MaxSegments},
+    app::{Apdu, MaxApdu, MaxSegments},
     network::Npdu,
     object::{ObjectIdentifier, ObjectType},
     property::decode_units,
@@ -46,40 +46,7 @@ struct ObjectInfo {
 #[allow(dead_code)]
 impl ObjectInfo {
     fn new(object_identifier: ObjectIdentifier) -> Self {
-        let object_type_name = match object_identifier.object_type {
-            ObjectType::Device => "Device",
-            ObjectType::AnalogInput => "Analog Input",
-            ObjectType::AnalogOutput => "Analog Output",
-            ObjectType::AnalogValue => "Analog Value",
-            ObjectType::BinaryInput => "Binary Input",
-            ObjectType::BinaryOutput => "Binary Output",
-            ObjectType::BinaryValue => "Binary Value",
-            ObjectType::MultiStateInput => "Multi-State Input",
-            ObjectType::MultiStateOutput => "Multi-State Output",
-            ObjectType::MultiStateValue => "Multi-State Value",
-            ObjectType::Calendar => "Calendar",
-            ObjectType::Command => "Command",
-            ObjectType::EventEnrollment => "Event Enrollment",
-            ObjectType::File => "File",
-            ObjectType::Group => "Group",
-            ObjectType::Loop => "Loop",
-            ObjectType::NotificationClass => "Notification Class",
-            ObjectType::Program => "Program",
-            ObjectType::Schedule => "Schedule",
-            ObjectType::Averaging => "Averaging",
-            ObjectType::TrendLog => "Trend Log",
-            ObjectType::LifeSafetyPoint => "Life Safety Point",
-            ObjectType::LifeSafetyZone => "Life Safety Zone",
-            ObjectType::Accumulator => "Accumulator",
-            ObjectType::PulseConverter => "Pulse Converter",
-            ObjectType::EventLog => "Event Log",
-            ObjectType::GlobalGroup => "Global Group",
-            ObjectType::TrendLogMultiple => "Trend Log Multiple",
-            ObjectType::LoadControl => "Load Control",
-            ObjectType::StructuredView => "Structured View",
-            ObjectType::AccessDoor => "Access Door",
-        }
-        .to_string();
+        let object_type_name = get_object_type_name(object_identifier.object_type).to_string();
 
         Self {
             object_identifier,
@@ -894,7 +861,7 @@ fn send_confirmed_request(
         more_follows: false,
         segmented_response_accepted: true,
         max_segments: MaxSegments::Unspecified,
-        max_response_size: MaxApduSize::Up1476,
+        max_response_size: MaxApdu::Up1476,
         invoke_id,
         sequence_number: None,
         proposed_window_size: None,
@@ -1030,7 +997,7 @@ fn encode_rpm_request(
     for spec in &request.read_access_specifications {
         // Object identifier - context tag 0
         let object_id = encode_object_id(
-            spec.object_identifier.object_type as u16,
+            spec.object_identifier.object_type.as_u16(),
             spec.object_identifier.instance,
         );
         buffer.push(0x0C); // Context tag 0, length 4
@@ -1172,36 +1139,36 @@ fn parse_rpm_response(
             .object_identifier
             .object_type
         {
-            ObjectType::AnalogInput | ObjectType::AnalogOutput | ObjectType::AnalogValue => {
-                if pos < data.len() && data[pos] == 0x44 {
-                    // Real value tag
-                    if let Some((value, consumed)) = extract_present_value(
-                        &data[pos..],
-                        objects_info[current_obj_index]
-                            .object_identifier
-                            .object_type,
-                    ) {
-                        // Debug: comment out for clean output
-                        // println!("Debug: Extracted present value: '{}'", value);
-                        objects_info[current_obj_index].present_value = Some(value);
-                        pos += consumed;
-                    }
+            ObjectType::AnalogInput | ObjectType::AnalogOutput | ObjectType::AnalogValue
+                if pos < data.len() && data[pos] == 0x44 =>
+            {
+                // Real value tag
+                if let Some((value, consumed)) = extract_present_value(
+                    &data[pos..],
+                    objects_info[current_obj_index]
+                        .object_identifier
+                        .object_type,
+                ) {
+                    // Debug: comment out for clean output
+                    // println!("Debug: Extracted present value: '{}'", value);
+                    objects_info[current_obj_index].present_value = Some(value);
+                    pos += consumed;
                 }
             }
-            ObjectType::BinaryInput | ObjectType::BinaryOutput | ObjectType::BinaryValue => {
-                if pos < data.len() && data[pos] == 0x11 {
-                    // Boolean value tag
-                    if let Some((value, consumed)) = extract_present_value(
-                        &data[pos..],
-                        objects_info[current_obj_index]
-                            .object_identifier
-                            .object_type,
-                    ) {
-                        // Debug: comment out for clean output
-                        // println!("Debug: Extracted present value: '{}'", value);
-                        objects_info[current_obj_index].present_value = Some(value);
-                        pos += consumed;
-                    }
+            ObjectType::BinaryInput | ObjectType::BinaryOutput | ObjectType::BinaryValue
+                if pos < data.len() && data[pos] == 0x11 =>
+            {
+                // Boolean value tag
+                if let Some((value, consumed)) = extract_present_value(
+                    &data[pos..],
+                    objects_info[current_obj_index]
+                        .object_identifier
+                        .object_type,
+                ) {
+                    // Debug: comment out for clean output
+                    // println!("Debug: Extracted present value: '{}'", value);
+                    objects_info[current_obj_index].present_value = Some(value);
+                    pos += consumed;
                 }
             }
             _ => {}
@@ -1355,37 +1322,5 @@ fn decode_object_id(encoded: u32) -> (u16, u32) {
 /// Get object type name as string
 #[allow(dead_code)]
 fn get_object_type_name(object_type: ObjectType) -> &'static str {
-    match object_type {
-        ObjectType::Device => "Device",
-        ObjectType::AnalogInput => "Analog Input",
-        ObjectType::AnalogOutput => "Analog Output",
-        ObjectType::AnalogValue => "Analog Value",
-        ObjectType::BinaryInput => "Binary Input",
-        ObjectType::BinaryOutput => "Binary Output",
-        ObjectType::BinaryValue => "Binary Value",
-        ObjectType::MultiStateInput => "Multi-State Input",
-        ObjectType::MultiStateOutput => "Multi-State Output",
-        ObjectType::MultiStateValue => "Multi-State Value",
-        ObjectType::Calendar => "Calendar",
-        ObjectType::Command => "Command",
-        ObjectType::EventEnrollment => "Event Enrollment",
-        ObjectType::File => "File",
-        ObjectType::Group => "Group",
-        ObjectType::Loop => "Loop",
-        ObjectType::NotificationClass => "Notification Class",
-        ObjectType::Program => "Program",
-        ObjectType::Schedule => "Schedule",
-        ObjectType::Averaging => "Averaging",
-        ObjectType::TrendLog => "Trend Log",
-        ObjectType::LifeSafetyPoint => "Life Safety Point",
-        ObjectType::LifeSafetyZone => "Life Safety Zone",
-        ObjectType::Accumulator => "Accumulator",
-        ObjectType::PulseConverter => "Pulse Converter",
-        ObjectType::EventLog => "Event Log",
-        ObjectType::GlobalGroup => "Global Group",
-        ObjectType::TrendLogMultiple => "Trend Log Multiple",
-        ObjectType::LoadControl => "Load Control",
-        ObjectType::StructuredView => "Structured View",
-        ObjectType::AccessDoor => "Access Door",
-    }
+    bacnet_rs::client::get_object_type_name(object_type)
 }
@@ -4,9 +4,9 @@
 //! including object management, property access, and search capabilities.
 
 use bacnet_rs::object::{
-    AnalogInput, AnalogValue, BinaryInput, BinaryOutput, BinaryPV, DatabaseBuilder, Device,
-    EngineeringUnits, MultiStateValue, ObjectDatabase, ObjectIdentifier, ObjectType,
-    PropertyIdentifier, PropertyValue,
+    AnalogInput, AnalogValue, BacnetObject, BinaryInput, BinaryOutput, BinaryPV, DatabaseBuilder,
+    Device, EngineeringUnits, MultiStateValue, ObjectDatabase, ObjectError, ObjectIdentifier,
+    ObjectType, PropertyIdentifier, PropertyValue,
 };
 use std::thread;
 use std::time::Duration;
@@ -43,6 +43,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Demo 6: Database statistics
     demo_database_statistics(&db)?;
 
+    // Demo 7: Custom object types
+    demo_custom_objects(&db)?;
+
     println!("\nObject Database Demo Complete!");
     Ok(())
 }
@@ -328,7 +331,7 @@ fn demo_database_statistics(db: &ObjectDatabase) -> Result<(), Box<dyn std::erro
 
     println!("\nObject counts by type:");
     let mut type_counts: Vec<_> = stats.type_counts.iter().collect();
-    type_counts.sort_by_key(|(t, _)| **t as u16);
+    type_counts.sort_by_key(|(t, _)| t.as_u16());
 
     for (object_type, count) in type_counts {
         println!("  {}: {}", format_object_type(*object_type), count);
@@ -337,6 +340,111 @@ fn demo_database_statistics(db: &ObjectDatabase) -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
+/// Vendor-specific zone object whose name is kept in several languages
+struct LocalizedZone {
+    identifier: ObjectIdentifier,
+    /// Names by language tag; the first is reported as Object_Name
+    names: Vec<(&'static str, String)>,
+}
+
+impl LocalizedZone {
+    /// Get the name in `language`, if the zone has one
+    fn name_in(&self, language: &str) -> Option<&str> {
+        self.names
+            .iter()
+            .find(|(tag, _)| *tag == language)
+            .map(|(_, name)| name.as_str())
+    }
+}
+
+impl BacnetObject for LocalizedZone {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(
+        &self,
+        property: PropertyIdentifier,
+    ) -> bacnet_rs::object::Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.names[0].1.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                self.identifier.object_type.as_u16() as u32,
+            )),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(
+        &mut self,
+        _property: PropertyIdentifier,
+        _value: PropertyValue,
+    ) -> bacnet_rs::object::Result<()> {
+        Err(ObjectError::PropertyNotWritable)
+    }
+
+    fn is_property_writable(&self, _property: PropertyIdentifier) -> bool {
+        false
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+        ]
+    }
+}
+
+/// Demonstrate storing a custom object type and recovering it
+fn demo_custom_objects(db: &ObjectDatabase) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n7. Custom Object Types");
+    println!("---------------------");
+
+    let zone_id = ObjectIdentifier::try_new(ObjectType::proprietary(600)?, 1)?;
+    db.add_object(Box::new(LocalizedZone {
+        identifier: zone_id,
+        names: vec![
+            ("en", "Lobby".to_string()),
+            ("de", "Eingangshalle".to_string()),
+        ],
+    }))?;
+    println!(
+        "Added custom zone object {}:{}",
+        zone_id.object_type.as_u16(),
+        zone_id.instance
+    );
+
+    // The database stores a Box<dyn BacnetObject>; with_object recovers the
+    // concrete type, so methods outside the trait are reachable again
+    let german = db.with_object(zone_id, |zone: &LocalizedZone| {
+        zone.name_in("de").map(str::to_string)
+    })?;
+    println!("  German name: {}", german.as_deref().unwrap_or("-"));
+
+    // The same works on a trait object, here from a read view
+    let view = db.read();
+    if let Some(zone) = view
+        .object_by_id(zone_id)
+        .and_then(|object| object.downcast_ref::<LocalizedZone>())
+    {
+        println!("  Languages: {}", zone.names.len());
+    }
+
+    // Asking for the wrong concrete type fails instead of panicking
+    match db.with_object(zone_id, |_: &AnalogInput| ()) {
+        Ok(_) => println!("  Unexpected success!"),
+        Err(e) => println!("  Expected error: {}", e),
+    }
+
+    Ok(())
+}
+
 /// Format object type for display
 fn format_object_type(object_type: ObjectType) -> &'static str {
     match object_type {
@@ -365,7 +473,9 @@ fn format_property_value(value: &PropertyValue) -> String {
         PropertyValue::Double(d) => format!("{:.2}", d),
         PropertyValue::CharacterString(s) => format!("\"{}\"", s),
         PropertyValue::Enumerated(e) => format!("Enum({})", e),
-        PropertyValue::ObjectIdentifier(id) => format!("{}:{}", id.object_type as u16, id.instance),
+        PropertyValue::ObjectIdentifier(id) => {
+            format!("{}:{}", id.object_type.as_u16(), id.instance)
+        }
         _ => "Complex Value".to_string(),
     }
 }
@@ -0,0 +1,93 @@
+//! Fuzz the APDU decoder and the service decoders behind it
+//!
+//! Run with `cargo fuzz run apdu_decode` from the repository root. Any panic,
+//! or an allocation large enough to abort, is a finding.
+
+#![no_main]
+
+use bacnet_rs::app::Apdu;
+use bacnet_rs::encoding::DecodeLimits;
+use bacnet_rs::service::{
+    AcknowledgeAlarmRequest, AtomicReadFileRequest, AtomicReadFileResponse,
+    AtomicWriteFileRequest, ConfirmedServiceChoice, CovNotificationRequest,
+    DeviceCommunicationControlRequest, ReadPropertyAck, ReadPropertyMultipleAck,
+    ReadPropertyMultipleRequest, ReadPropertyRequest, ReadRangeAck, ReadRangeRequest,
+    ReinitializeDeviceRequest, SubscribeCovRequest, WritePropertyRequest,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // Tight limits, so crafted counts are rejected well before memory runs out
+    let limits = DecodeLimits { max_elements: 64 };
+
+    match Apdu::decode(data) {
+        Ok(Apdu::ConfirmedRequest {
+            service_choice,
+            service_data,
+            ..
+        }) => {
+            let data = &service_data[..];
+            match service_choice {
+                ConfirmedServiceChoice::ReadProperty => {
+                    let _ = ReadPropertyRequest::decode(data);
+                }
+                ConfirmedServiceChoice::WriteProperty => {
+                    if let Ok(request) = WritePropertyRequest::decode(data) {
+                        let _ = request.value();
+                    }
+                }
+                ConfirmedServiceChoice::ReadPropertyMultiple => {
+                    let _ = ReadPropertyMultipleRequest::decode_with_limits(data, &limits);
+                }
+                ConfirmedServiceChoice::SubscribeCOV => {
+                    let _ = SubscribeCovRequest::decode(data);
+                }
+                ConfirmedServiceChoice::ConfirmedCOVNotification => {
+                    let _ = CovNotificationRequest::decode(data);
+                }
+                ConfirmedServiceChoice::AcknowledgeAlarm => {
+                    let _ = AcknowledgeAlarmRequest::decode(data);
+                }
+                ConfirmedServiceChoice::AtomicReadFile => {
+                    let _ = AtomicReadFileRequest::decode(data);
+                }
+                ConfirmedServiceChoice::AtomicWriteFile => {
+                    let _ = AtomicWriteFileRequest::decode_with_limits(data, &limits);
+                }
+                ConfirmedServiceChoice::ReadRange => {
+                    let _ = ReadRangeRequest::decode(data);
+                }
+                ConfirmedServiceChoice::DeviceCommunicationControl => {
+                    let _ = DeviceCommunicationControlRequest::decode(data);
+                }
+                ConfirmedServiceChoice::ReinitializeDevice => {
+                    let _ = ReinitializeDeviceRequest::decode(data);
+                }
+                _ => {}
+            }
+        }
+        Ok(Apdu::ComplexAck {
+            service_choice,
+            service_data,
+            ..
+        }) => {
+            let data = &service_data[..];
+            match ConfirmedServiceChoice::try_from(service_choice) {
+                Ok(ConfirmedServiceChoice::ReadProperty) => {
+                    let _ = ReadPropertyAck::decode_with_limits(data, &limits);
+                }
+                Ok(ConfirmedServiceChoice::ReadPropertyMultiple) => {
+                    let _ = ReadPropertyMultipleAck::decode_with_limits(data, &limits);
+                }
+                Ok(ConfirmedServiceChoice::AtomicReadFile) => {
+                    let _ = AtomicReadFileResponse::decode_with_limits(data, &limits);
+                }
+                Ok(ConfirmedServiceChoice::ReadRange) => {
+                    let _ = ReadRangeAck::decode(data);
+                }
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+});
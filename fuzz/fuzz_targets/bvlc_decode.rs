@@ -0,0 +1,23 @@
+//! Fuzz the BACnet/IP BVLC decoder and the NPDU it carries
+//!
+//! Run with `cargo fuzz run bvlc_decode` from the repository root. Any panic,
+//! or an allocation large enough to abort, is a finding.
+
+#![no_main]
+
+use bacnet_rs::datalink::bip::Bvlc;
+use bacnet_rs::network::Npdu;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(bvlc) = Bvlc::decode(data) else {
+        return;
+    };
+
+    // Anything decoded must encode again or fail cleanly
+    let _ = bvlc.encode();
+
+    if let Some(npdu) = bvlc.npdu() {
+        let _ = Npdu::decode(npdu);
+    }
+});
@@ -0,0 +1,23 @@
+//! Fuzz the NPDU decoder and the network layer messages behind it
+//!
+//! Run with `cargo fuzz run npdu_decode` from the repository root. Any panic,
+//! or an allocation large enough to abort, is a finding.
+
+#![no_main]
+
+use bacnet_rs::network::{NetworkMessage, Npdu};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok((npdu, consumed)) = Npdu::decode(data) else {
+        return;
+    };
+    assert!(consumed <= data.len());
+
+    if npdu.is_network_message() {
+        if let Ok(message) = NetworkMessage::decode(&data[consumed..]) {
+            // Anything decoded must encode again or fail cleanly
+            let _ = message.encode();
+        }
+    }
+});
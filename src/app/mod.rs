@@ -41,17 +41,24 @@
 use std::error::Error;
 
 #[cfg(feature = "std")]
-use std::{fmt, time::Duration};
+use std::{collections::BTreeMap, fmt, time::Duration};
 
 #[cfg(not(feature = "std"))]
 use core::fmt;
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 #[cfg(not(feature = "std"))]
 use core::time::Duration;
 
+use crate::encoding::{decode_enumerated, encode_enumerated};
 use crate::object::Segmentation;
 use crate::service::{AbortReason, ConfirmedServiceChoice, RejectReason, UnconfirmedServiceChoice};
 
@@ -121,7 +128,7 @@ pub enum Apdu {
         more_follows: bool,
         segmented_response_accepted: bool,
         max_segments: MaxSegments,
-        max_response_size: MaxApduSize,
+        max_response_size: MaxApdu,
         invoke_id: u8,
         sequence_number: Option<u8>,
         proposed_window_size: Option<u8>,
@@ -159,11 +166,16 @@ pub enum Apdu {
     },
 
     /// Error PDU
+    ///
+    /// `error_data` holds the parameters that follow the error class and
+    /// code in the errors of services like WritePropertyMultiple; it is
+    /// empty for a plain error.
     Error {
         invoke_id: u8,
         service_choice: u8,
-        error_class: u8,
-        error_code: u8,
+        error_class: u32,
+        error_code: u32,
+        error_data: Vec<u8>,
     },
 
     /// Reject PDU
@@ -192,7 +204,7 @@ pub enum MaxSegments {
 
 /// Maximum APDU size that can be accepted
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum MaxApduSize {
+pub enum MaxApdu {
     Up50 = 0,
     Up128 = 1,
     Up206 = 2,
@@ -201,18 +213,52 @@ pub enum MaxApduSize {
     Up1476 = 5,
 }
 
-impl MaxApduSize {
+impl MaxApdu {
+    /// Get the size for its 4-bit code, as carried in Confirmed-Request PDUs
+    ///
+    /// Codes 6-15 are reserved and yield `None`.
+    pub fn from_enum(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(MaxApdu::Up50),
+            1 => Some(MaxApdu::Up128),
+            2 => Some(MaxApdu::Up206),
+            3 => Some(MaxApdu::Up480),
+            4 => Some(MaxApdu::Up1024),
+            5 => Some(MaxApdu::Up1476),
+            _ => None,
+        }
+    }
+
+    /// Get the largest size code that does not exceed `length` bytes
+    ///
+    /// Used to express a peer's Max_APDU_Length_Accepted (e.g. from I-Am).
+    pub fn from_length(length: u32) -> Self {
+        match length {
+            0..=127 => MaxApdu::Up50,
+            128..=205 => MaxApdu::Up128,
+            206..=479 => MaxApdu::Up206,
+            480..=1023 => MaxApdu::Up480,
+            1024..=1475 => MaxApdu::Up1024,
+            _ => MaxApdu::Up1476,
+        }
+    }
+
     /// Get the actual size in bytes
-    pub fn size(&self) -> usize {
+    pub fn as_bytes(&self) -> usize {
         match self {
-            MaxApduSize::Up50 => 50,
-            MaxApduSize::Up128 => 128,
-            MaxApduSize::Up206 => 206,
-            MaxApduSize::Up480 => 480,
-            MaxApduSize::Up1024 => 1024,
-            MaxApduSize::Up1476 => 1476,
+            MaxApdu::Up50 => 50,
+            MaxApdu::Up128 => 128,
+            MaxApdu::Up206 => 206,
+            MaxApdu::Up480 => 480,
+            MaxApdu::Up1024 => 1024,
+            MaxApdu::Up1476 => 1476,
         }
     }
+
+    /// Largest service data an unsegmented Confirmed-Request of this size carries
+    pub fn max_request_service_data(&self) -> usize {
+        self.as_bytes() - CONFIRMED_REQUEST_HEADER_LEN
+    }
 }
 
 /// Transaction state for confirmed services
@@ -393,6 +439,7 @@ impl Apdu {
                 service_choice,
                 error_class,
                 error_code,
+                error_data,
             } => {
                 // PDU Type
                 buffer.push((ApduType::Error as u8) << 4);
@@ -400,10 +447,19 @@ impl Apdu {
                 buffer.push(*invoke_id);
                 // Service choice
                 buffer.push(*service_choice);
-                // Error class
-                buffer.push(*error_class);
-                // Error code
-                buffer.push(*error_code);
+                // Error class and code - application tagged enumerated; a
+                // u32 always fits the 4 byte tag, so encoding cannot fail.
+                // Errors with parameters wrap them in context tag 0.
+                if error_data.is_empty() {
+                    let _ = encode_enumerated(&mut buffer, *error_class);
+                    let _ = encode_enumerated(&mut buffer, *error_code);
+                } else {
+                    buffer.push(0x0E);
+                    let _ = encode_enumerated(&mut buffer, *error_class);
+                    let _ = encode_enumerated(&mut buffer, *error_code);
+                    buffer.push(0x0F);
+                    buffer.extend_from_slice(error_data);
+                }
             }
 
             Apdu::Reject {
@@ -485,15 +541,8 @@ impl Apdu {
                     _ => MaxSegments::Unspecified,
                 };
 
-                let max_response_size = match max_info & 0x0F {
-                    0 => MaxApduSize::Up50,
-                    1 => MaxApduSize::Up128,
-                    2 => MaxApduSize::Up206,
-                    3 => MaxApduSize::Up480,
-                    4 => MaxApduSize::Up1024,
-                    5 => MaxApduSize::Up1476,
-                    _ => MaxApduSize::Up50,
-                };
+                let max_response_size =
+                    MaxApdu::from_enum(max_info & 0x0F).unwrap_or(MaxApdu::Up50);
 
                 let invoke_id = data[2];
                 let mut pos = 3;
@@ -675,14 +724,31 @@ impl Apdu {
 
                 let invoke_id = data[1];
                 let service_choice = data[2];
-                let error_class = data[3];
-                let error_code = data[4];
+                let invalid = |_| ApplicationError::InvalidApdu("Invalid Error PDU".to_string());
+                // Errors with parameters wrap the class and code in context tag 0
+                let wrapped = data[3] == 0x0E;
+                let mut pos = if wrapped { 4 } else { 3 };
+                let (error_class, consumed) = decode_enumerated(&data[pos..]).map_err(invalid)?;
+                pos += consumed;
+                let (error_code, consumed) = decode_enumerated(&data[pos..]).map_err(invalid)?;
+                pos += consumed;
+                let error_data = if wrapped {
+                    if data.get(pos) != Some(&0x0F) {
+                        return Err(ApplicationError::InvalidApdu(
+                            "Unterminated Error PDU".to_string(),
+                        ));
+                    }
+                    data[pos + 1..].to_vec()
+                } else {
+                    Vec::new()
+                };
 
                 Ok(Apdu::Error {
                     invoke_id,
                     service_choice,
                     error_class,
                     error_code,
+                    error_data,
                 })
             }
 
@@ -721,6 +787,14 @@ impl Apdu {
             }
         }
     }
+
+    /// Describe this APDU as a JSON object, with services, object types and
+    /// properties given by name
+    ///
+    /// See [`crate::util::debug::format_apdu_json`].
+    pub fn to_json(&self) -> String {
+        crate::util::debug::format_apdu_json(self)
+    }
 }
 
 /// Invoke ID manager for handling transaction IDs
@@ -777,6 +851,168 @@ impl Default for InvokeIdManager {
     }
 }
 
+/// Confirmed request awaiting its response
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct OutstandingRequest<T> {
+    /// Service of the request
+    pub service_choice: ConfirmedServiceChoice,
+    /// When the request, or its last retransmission, was sent
+    pub sent_at: std::time::Instant,
+    /// Caller data kept with the request, such as its destination
+    pub context: T,
+}
+
+/// Invoke ID allocator tracking outstanding confirmed requests
+///
+/// Each confirmed request holds its invoke ID until the response arrives or
+/// the request times out, so a late response can never be taken for the
+/// answer to a newer request. At most 256 requests can be outstanding.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct InvokeIdAllocator<T> {
+    next_id: u8,
+    outstanding: BTreeMap<u8, OutstandingRequest<T>>,
+    timeout: Duration,
+}
+
+#[cfg(feature = "std")]
+impl<T> InvokeIdAllocator<T> {
+    /// Create an allocator whose requests expire after `timeout`
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            next_id: 0,
+            outstanding: BTreeMap::new(),
+            timeout,
+        }
+    }
+
+    /// Allocate an invoke ID for a new request
+    ///
+    /// Fails with [`ApplicationError::TransactionError`] while all 256
+    /// invoke IDs are outstanding.
+    pub fn allocate(&mut self, service_choice: ConfirmedServiceChoice, context: T) -> Result<u8> {
+        self.allocate_with(service_choice, |_| context)
+    }
+
+    /// Allocate an invoke ID, building the request's data from the ID
+    pub fn allocate_with(
+        &mut self,
+        service_choice: ConfirmedServiceChoice,
+        context: impl FnOnce(u8) -> T,
+    ) -> Result<u8> {
+        if self.outstanding.len() > u8::MAX as usize {
+            return Err(ApplicationError::TransactionError(
+                "All invoke IDs are outstanding".to_string(),
+            ));
+        }
+        while self.outstanding.contains_key(&self.next_id) {
+            self.next_id = self.next_id.wrapping_add(1);
+        }
+
+        let invoke_id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.outstanding.insert(
+            invoke_id,
+            OutstandingRequest {
+                service_choice,
+                sent_at: std::time::Instant::now(),
+                context: context(invoke_id),
+            },
+        );
+        Ok(invoke_id)
+    }
+
+    /// Find the outstanding request a response APDU answers
+    ///
+    /// Simple-ACK, Complex-ACK and Error PDUs must also name the service of
+    /// the request; Reject and Abort PDUs only carry the invoke ID.
+    pub fn match_response(&self, apdu: &Apdu) -> Option<u8> {
+        let (invoke_id, service_choice) = match apdu {
+            Apdu::SimpleAck {
+                invoke_id,
+                service_choice,
+            }
+            | Apdu::ComplexAck {
+                invoke_id,
+                service_choice,
+                ..
+            }
+            | Apdu::Error {
+                invoke_id,
+                service_choice,
+                ..
+            } => (*invoke_id, Some(*service_choice)),
+            Apdu::Reject { invoke_id, .. }
+            | Apdu::Abort {
+                server: true,
+                invoke_id,
+                ..
+            } => (*invoke_id, None),
+            _ => return None,
+        };
+
+        let request = self.outstanding.get(&invoke_id)?;
+        match service_choice {
+            Some(service_choice) if service_choice != request.service_choice as u8 => None,
+            _ => Some(invoke_id),
+        }
+    }
+
+    /// Get an outstanding request
+    pub fn get(&self, invoke_id: u8) -> Option<&OutstandingRequest<T>> {
+        self.outstanding.get(&invoke_id)
+    }
+
+    /// Get an outstanding request mutably
+    pub fn get_mut(&mut self, invoke_id: u8) -> Option<&mut OutstandingRequest<T>> {
+        self.outstanding.get_mut(&invoke_id)
+    }
+
+    /// Change the time after which an outstanding request expires
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Restart the timeout of a request after retransmitting it
+    pub fn restart_timer(&mut self, invoke_id: u8) {
+        if let Some(request) = self.outstanding.get_mut(&invoke_id) {
+            request.sent_at = std::time::Instant::now();
+        }
+    }
+
+    /// Complete a request, freeing its invoke ID
+    pub fn complete(&mut self, invoke_id: u8) -> Option<T> {
+        self.outstanding
+            .remove(&invoke_id)
+            .map(|request| request.context)
+    }
+
+    /// Free the invoke IDs of requests that timed out, returning them
+    pub fn expire(&mut self) -> Vec<(u8, T)> {
+        let expired: Vec<u8> = self
+            .outstanding
+            .iter()
+            .filter(|(_, request)| request.sent_at.elapsed() >= self.timeout)
+            .map(|(&invoke_id, _)| invoke_id)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|invoke_id| Some((invoke_id, self.complete(invoke_id)?)))
+            .collect()
+    }
+
+    /// Check if an invoke ID is held by an outstanding request
+    pub fn is_outstanding(&self, invoke_id: u8) -> bool {
+        self.outstanding.contains_key(&invoke_id)
+    }
+
+    /// Get the number of outstanding requests
+    pub fn outstanding_count(&self) -> usize {
+        self.outstanding.len()
+    }
+}
+
 /// Segmentation information for large APDUs
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SegmentationInfo {
@@ -831,6 +1067,15 @@ impl SegmentationInfo {
     }
 }
 
+/// Header length of an unsegmented Confirmed-Request APDU
+pub const CONFIRMED_REQUEST_HEADER_LEN: usize = 4;
+
+/// Header length of a segmented Confirmed-Request APDU
+pub const SEGMENTED_REQUEST_HEADER_LEN: usize = 6;
+
+/// Header length of a segmented Complex-ACK APDU
+pub const SEGMENTED_ACK_HEADER_LEN: usize = 5;
+
 /// Segment reassembly buffer for incoming segmented messages
 #[derive(Debug)]
 pub struct SegmentReassemblyBuffer {
@@ -997,6 +1242,21 @@ impl SegmentationManager {
         Ok(segments)
     }
 
+    /// Split a confirmed request into segments that fit the peer's APDU size
+    ///
+    /// `peer_max_apdu` is the peer's Max_APDU_Length_Accepted, as reported
+    /// in its I-Am. Each segment leaves room for the segmented
+    /// Confirmed-Request header.
+    pub fn segment_for_peer(
+        &self,
+        data: &[u8],
+        peer_max_apdu: MaxApdu,
+        max_segments: u8,
+    ) -> Result<Vec<Vec<u8>>> {
+        let max_segment_size = peer_max_apdu.as_bytes() - SEGMENTED_REQUEST_HEADER_LEN;
+        self.segment_message(data, max_segment_size, max_segments)
+    }
+
     /// Process an incoming segment
     pub fn process_segment(
         &mut self,
@@ -1097,6 +1357,213 @@ impl Default for SegmentationManager {
     }
 }
 
+/// Progress of a segmented Complex-ACK reassembly
+#[derive(Debug, Clone)]
+pub enum SegmentProgress {
+    /// More segments are expected; send the SegmentACK if one is due
+    InProgress(Option<Apdu>),
+    /// All segments were received; send the final SegmentACK
+    Complete {
+        /// SegmentACK acknowledging the last segment
+        segment_ack: Apdu,
+        /// Service choice of the Complex-ACK
+        service_choice: u8,
+        /// Reassembled service data
+        service_data: Vec<u8>,
+    },
+}
+
+/// Client side reassembly of a segmented Complex-ACK
+///
+/// Follows the segmented response procedure of Clause 5.4.4: the first
+/// segment fixes the window size proposed by the server, a SegmentACK is
+/// returned after each full window and after the last segment, and an
+/// out-of-order segment is answered with a negative SegmentACK naming the
+/// last segment received in order.
+#[derive(Debug)]
+pub struct SegmentedAckReceiver {
+    /// Invoke ID of the confirmed request
+    invoke_id: u8,
+    /// Largest APDU this device accepts per segment
+    max_apdu_length: usize,
+    /// Maximum number of segments this device accepts (None = unlimited)
+    max_segments: Option<usize>,
+    /// Window size proposed by the server in the first segment
+    actual_window_size: u8,
+    /// Sequence number that opened the current window
+    initial_sequence_number: u8,
+    /// Last sequence number received in order
+    last_sequence_number: Option<u8>,
+    /// Number of segments received in order
+    segment_count: usize,
+    /// Service choice of the Complex-ACK
+    service_choice: u8,
+    /// Reassembled service data
+    data: Vec<u8>,
+    /// Remaining retransmissions of the last SegmentACK on timeout
+    retries_left: u8,
+}
+
+impl SegmentedAckReceiver {
+    /// Create a receiver for the response to `invoke_id`
+    ///
+    /// `max_apdu_length` and `max_segments` are the limits this device
+    /// advertised in its request; `retries` bounds how often the last
+    /// SegmentACK is retransmitted when the server goes quiet.
+    pub fn new(
+        invoke_id: u8,
+        max_apdu_length: MaxApdu,
+        max_segments: MaxSegments,
+        retries: u8,
+    ) -> Self {
+        let max_segments = match max_segments {
+            MaxSegments::Unspecified | MaxSegments::GreaterThan64 => None,
+            MaxSegments::Two => Some(2),
+            MaxSegments::Four => Some(4),
+            MaxSegments::Eight => Some(8),
+            MaxSegments::Sixteen => Some(16),
+            MaxSegments::ThirtyTwo => Some(32),
+            MaxSegments::SixtyFour => Some(64),
+        };
+
+        Self {
+            invoke_id,
+            max_apdu_length: max_apdu_length.as_bytes(),
+            max_segments,
+            actual_window_size: 1,
+            initial_sequence_number: 0,
+            last_sequence_number: None,
+            segment_count: 0,
+            service_choice: 0,
+            data: Vec::new(),
+            retries_left: retries,
+        }
+    }
+
+    /// Invoke ID this receiver belongs to
+    pub fn invoke_id(&self) -> u8 {
+        self.invoke_id
+    }
+
+    /// Window size in use, as proposed by the server
+    pub fn window_size(&self) -> u8 {
+        self.actual_window_size
+    }
+
+    /// Number of segments received in order so far
+    pub fn segments_received(&self) -> usize {
+        self.segment_count
+    }
+
+    /// Process a received Complex-ACK segment
+    ///
+    /// `apdu_length` is the encoded length of the segment's APDU and is
+    /// checked against this device's Max_APDU_Length_Accepted.
+    pub fn process(&mut self, apdu: &Apdu, apdu_length: usize) -> Result<SegmentProgress> {
+        let Apdu::ComplexAck {
+            segmented: true,
+            more_follows,
+            invoke_id,
+            sequence_number: Some(sequence_number),
+            proposed_window_size: Some(proposed_window_size),
+            service_choice,
+            service_data,
+        } = apdu
+        else {
+            return Err(ApplicationError::SegmentationError(
+                "Expected a segmented Complex-ACK".to_string(),
+            ));
+        };
+
+        if *invoke_id != self.invoke_id {
+            return Err(ApplicationError::SegmentationError(format!(
+                "Segment for invoke ID {} received by transaction {}",
+                invoke_id, self.invoke_id
+            )));
+        }
+        if apdu_length > self.max_apdu_length {
+            return Err(ApplicationError::MaxApduLengthExceeded);
+        }
+
+        let expected = match self.last_sequence_number {
+            None => 0,
+            Some(last) => last.wrapping_add(1),
+        };
+
+        if *sequence_number != expected {
+            // Discard and ask for retransmission after the last good segment
+            return match self.last_sequence_number {
+                Some(last) => Ok(SegmentProgress::InProgress(Some(
+                    self.segment_ack(true, last),
+                ))),
+                None => Ok(SegmentProgress::InProgress(None)),
+            };
+        }
+
+        if let Some(max_segments) = self.max_segments {
+            if self.segment_count >= max_segments {
+                return Err(ApplicationError::SegmentationError(
+                    "Response exceeds the maximum segments accepted".to_string(),
+                ));
+            }
+        }
+
+        if self.last_sequence_number.is_none() {
+            self.actual_window_size = (*proposed_window_size).clamp(1, 127);
+            self.service_choice = *service_choice;
+        }
+        self.last_sequence_number = Some(*sequence_number);
+        self.segment_count += 1;
+        self.data.extend_from_slice(service_data);
+
+        if !more_follows {
+            return Ok(SegmentProgress::Complete {
+                segment_ack: self.segment_ack(false, *sequence_number),
+                service_choice: self.service_choice,
+                service_data: core::mem::take(&mut self.data),
+            });
+        }
+
+        // Acknowledge the first segment and every completed window
+        let window_full =
+            sequence_number.wrapping_sub(self.initial_sequence_number) == self.actual_window_size;
+        if *sequence_number == 0 || window_full {
+            self.initial_sequence_number = *sequence_number;
+            Ok(SegmentProgress::InProgress(Some(
+                self.segment_ack(false, *sequence_number),
+            )))
+        } else {
+            Ok(SegmentProgress::InProgress(None))
+        }
+    }
+
+    /// Handle a segment timeout
+    ///
+    /// Returns the SegmentACK to retransmit, or [`ApplicationError::Timeout`]
+    /// once the retries are exhausted and the transaction should be aborted.
+    pub fn on_timeout(&mut self) -> Result<Apdu> {
+        if self.retries_left == 0 {
+            return Err(ApplicationError::Timeout);
+        }
+        self.retries_left -= 1;
+
+        match self.last_sequence_number {
+            Some(last) => Ok(self.segment_ack(false, last)),
+            None => Err(ApplicationError::Timeout),
+        }
+    }
+
+    fn segment_ack(&self, negative: bool, sequence_number: u8) -> Apdu {
+        Apdu::SegmentAck {
+            negative,
+            server: false,
+            invoke_id: self.invoke_id,
+            sequence_number,
+            window_size: self.actual_window_size,
+        }
+    }
+}
+
 /// Application layer service handler
 #[derive(Debug)]
 pub struct ApplicationLayerHandler {
@@ -1231,6 +1698,7 @@ impl ApplicationLayerHandler {
                 service_choice,
                 error_class,
                 error_code,
+                ..
             } => self.process_error(*invoke_id, *service_choice, *error_class, *error_code),
             Apdu::Reject {
                 invoke_id,
@@ -1285,6 +1753,7 @@ impl ApplicationLayerHandler {
                                 service_choice: service_choice as u8,
                                 error_class: 0, // Object
                                 error_code: 0,  // Unknown object
+                                error_data: Vec::new(),
                             }))
                         }
                     }
@@ -1351,8 +1820,8 @@ impl ApplicationLayerHandler {
         &mut self,
         invoke_id: u8,
         _service_choice: u8,
-        error_class: u8,
-        error_code: u8,
+        error_class: u32,
+        error_code: u32,
     ) -> Result<Option<Apdu>> {
         self.stats.errors += 1;
         self.transaction_manager
@@ -1463,7 +1932,7 @@ impl TransactionManager {
     }
 
     /// Mark transaction as error
-    pub fn error_transaction(&mut self, invoke_id: u8, _error_class: u8, _error_code: u8) {
+    pub fn error_transaction(&mut self, invoke_id: u8, _error_class: u32, _error_code: u32) {
         if let Some(transaction) = self
             .transactions
             .iter_mut()
@@ -1743,7 +2212,7 @@ mod tests {
             more_follows: false,
             segmented_response_accepted: true,
             max_segments: MaxSegments::Unspecified,
-            max_response_size: MaxApduSize::Up1476,
+            max_response_size: MaxApdu::Up1476,
             invoke_id: 123,
             sequence_number: None,
             proposed_window_size: None,
@@ -1769,6 +2238,55 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_invoke_id_allocator() {
+        let mut allocator = InvokeIdAllocator::new(Duration::from_secs(3));
+        let read = ConfirmedServiceChoice::ReadProperty;
+        for expected in 0..=u8::MAX {
+            assert_eq!(allocator.allocate(read, expected).unwrap(), expected);
+        }
+        assert!(matches!(
+            allocator.allocate(read, 0),
+            Err(ApplicationError::TransactionError(_))
+        ));
+
+        // Responses are matched by invoke ID and, where they carry it, service
+        let ack = |invoke_id, service_choice| Apdu::SimpleAck {
+            invoke_id,
+            service_choice,
+        };
+        assert_eq!(allocator.match_response(&ack(7, read as u8)), Some(7));
+        assert_eq!(
+            allocator.match_response(&ack(7, ConfirmedServiceChoice::WriteProperty as u8)),
+            None
+        );
+        let abort = Apdu::Abort {
+            server: true,
+            invoke_id: 9,
+            abort_reason: 0,
+        };
+        assert_eq!(allocator.match_response(&abort), Some(9));
+
+        // Completing frees the ID for the next request
+        assert_eq!(allocator.complete(7), Some(7));
+        assert_eq!(allocator.match_response(&ack(7, read as u8)), None);
+        assert_eq!(
+            allocator
+                .allocate(ConfirmedServiceChoice::WriteProperty, 42)
+                .unwrap(),
+            7
+        );
+        assert_eq!(allocator.get(7).unwrap().context, 42);
+
+        // Timed out requests give their IDs back
+        let mut allocator = InvokeIdAllocator::new(Duration::ZERO);
+        let first = allocator.allocate(read, "first").unwrap();
+        assert_eq!(allocator.expire(), vec![(first, "first")]);
+        assert_eq!(allocator.outstanding_count(), 0);
+        assert!(!allocator.is_outstanding(first));
+    }
+
     #[test]
     fn test_invoke_id_manager() {
         let mut manager = InvokeIdManager::new();
@@ -1796,9 +2314,30 @@ mod tests {
 
     #[test]
     fn test_max_apdu_size() {
-        assert_eq!(MaxApduSize::Up50.size(), 50);
-        assert_eq!(MaxApduSize::Up128.size(), 128);
-        assert_eq!(MaxApduSize::Up1476.size(), 1476);
+        assert_eq!(MaxApdu::Up50.as_bytes(), 50);
+        assert_eq!(MaxApdu::Up128.as_bytes(), 128);
+        assert_eq!(MaxApdu::Up1476.as_bytes(), 1476);
+    }
+
+    #[test]
+    fn test_max_apdu_mapping() {
+        let table = [
+            (0, MaxApdu::Up50, 50),
+            (1, MaxApdu::Up128, 128),
+            (2, MaxApdu::Up206, 206),
+            (3, MaxApdu::Up480, 480),
+            (4, MaxApdu::Up1024, 1024),
+            (5, MaxApdu::Up1476, 1476),
+        ];
+        for (code, max_apdu, bytes) in table {
+            assert_eq!(MaxApdu::from_enum(code), Some(max_apdu));
+            assert_eq!(max_apdu as u8, code);
+            assert_eq!(max_apdu.as_bytes(), bytes);
+            assert_eq!(MaxApdu::from_length(bytes as u32), max_apdu);
+            assert_eq!(max_apdu.max_request_service_data(), bytes - 4);
+        }
+        assert_eq!(MaxApdu::from_enum(6), None);
+        assert_eq!(MaxApdu::from_enum(15), None);
     }
 
     #[test]
@@ -1907,6 +2446,132 @@ mod tests {
         assert_eq!(missing, vec![1]);
     }
 
+    fn segment(sequence_number: u8, more_follows: bool, data: &[u8]) -> Apdu {
+        Apdu::ComplexAck {
+            segmented: true,
+            more_follows,
+            invoke_id: 7,
+            sequence_number: Some(sequence_number),
+            proposed_window_size: Some(2),
+            service_choice: 14,
+            service_data: data.to_vec(),
+        }
+    }
+
+    fn ack_sequence(progress: &SegmentProgress) -> Option<(bool, u8)> {
+        let ack = match progress {
+            SegmentProgress::InProgress(ack) => ack.as_ref()?,
+            SegmentProgress::Complete { segment_ack, .. } => segment_ack,
+        };
+        match ack {
+            Apdu::SegmentAck {
+                negative,
+                sequence_number,
+                window_size,
+                ..
+            } => {
+                assert_eq!(*window_size, 2);
+                Some((*negative, *sequence_number))
+            }
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_segmented_ack_receiver_windows() {
+        let mut receiver = SegmentedAckReceiver::new(7, MaxApdu::Up480, MaxSegments::Sixteen, 2);
+
+        // First segment is acknowledged immediately
+        let progress = receiver.process(&segment(0, true, b"ab"), 7).unwrap();
+        assert_eq!(ack_sequence(&progress), Some((false, 0)));
+        assert_eq!(receiver.window_size(), 2);
+
+        // Mid-window segment is not acknowledged
+        let progress = receiver.process(&segment(1, true, b"cd"), 7).unwrap();
+        assert_eq!(ack_sequence(&progress), None);
+
+        // Window full
+        let progress = receiver.process(&segment(2, true, b"ef"), 7).unwrap();
+        assert_eq!(ack_sequence(&progress), Some((false, 2)));
+
+        // Out of order segment gets a negative ack for the last good one
+        let progress = receiver.process(&segment(4, true, b"zz"), 7).unwrap();
+        assert_eq!(ack_sequence(&progress), Some((true, 2)));
+
+        receiver.process(&segment(3, true, b"gh"), 7).unwrap();
+        match receiver.process(&segment(4, false, b"ij"), 7).unwrap() {
+            SegmentProgress::Complete {
+                segment_ack,
+                service_choice,
+                service_data,
+            } => {
+                assert!(matches!(
+                    segment_ack,
+                    Apdu::SegmentAck {
+                        negative: false,
+                        sequence_number: 4,
+                        ..
+                    }
+                ));
+                assert_eq!(service_choice, 14);
+                assert_eq!(service_data, b"abcdefghij");
+            }
+            other => panic!("expected completion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_segmented_ack_receiver_limits_and_timeout() {
+        let mut receiver = SegmentedAckReceiver::new(7, MaxApdu::Up50, MaxSegments::Two, 1);
+
+        // Segment larger than the advertised APDU size
+        assert!(matches!(
+            receiver.process(&segment(0, true, b"ab"), 51),
+            Err(ApplicationError::MaxApduLengthExceeded)
+        ));
+
+        // Timeout before any segment aborts immediately
+        let mut idle = SegmentedAckReceiver::new(7, MaxApdu::Up50, MaxSegments::Two, 3);
+        assert!(matches!(idle.on_timeout(), Err(ApplicationError::Timeout)));
+
+        receiver.process(&segment(0, true, b"ab"), 7).unwrap();
+        receiver.process(&segment(1, true, b"cd"), 7).unwrap();
+        assert!(receiver.process(&segment(2, false, b"ef"), 7).is_err());
+
+        // One retransmission of the last SegmentACK, then give up
+        assert!(matches!(
+            receiver.on_timeout(),
+            Ok(Apdu::SegmentAck {
+                sequence_number: 1,
+                ..
+            })
+        ));
+        assert!(matches!(
+            receiver.on_timeout(),
+            Err(ApplicationError::Timeout)
+        ));
+
+        // Wrong invoke ID
+        let mut other = SegmentedAckReceiver::new(8, MaxApdu::Up480, MaxSegments::Two, 1);
+        assert!(other.process(&segment(0, true, b"ab"), 7).is_err());
+    }
+
+    #[test]
+    fn test_segment_for_peer_max_apdu() {
+        let manager = SegmentationManager::new();
+        let data = vec![0u8; 1000];
+
+        // Peer accepts 480 byte APDUs: 474 bytes of service data per segment
+        let segments = manager.segment_for_peer(&data, MaxApdu::Up480, 8).unwrap();
+        assert_eq!(segments.len(), 3);
+        assert!(segments
+            .iter()
+            .all(|s| s.len() + SEGMENTED_REQUEST_HEADER_LEN <= 480));
+        assert_eq!(MaxApdu::from_length(480), MaxApdu::Up480);
+        assert_eq!(MaxApdu::from_length(1000), MaxApdu::Up480);
+        assert_eq!(MaxApdu::from_length(50), MaxApdu::Up50);
+    }
+
     #[test]
     fn test_segmentation_error_cases() {
         let manager = SegmentationManager::new();
@@ -1957,4 +2622,47 @@ mod tests {
         let reassembled = buffer.reassemble().unwrap();
         assert_eq!(reassembled, vec![1, 2, 3, 7, 8, 9]);
     }
+
+    #[test]
+    fn test_error_pdu_tagged_enumerations() {
+        let apdu = Apdu::Error {
+            invoke_id: 3,
+            service_choice: 12,
+            error_class: 2,
+            error_code: 32,
+            error_data: Vec::new(),
+        };
+        let encoded = apdu.encode();
+        assert_eq!(encoded, vec![0x50, 3, 12, 0x91, 2, 0x91, 32]);
+
+        match Apdu::decode(&encoded).unwrap() {
+            Apdu::Error {
+                invoke_id,
+                error_class,
+                error_code,
+                ..
+            } => {
+                assert_eq!(invoke_id, 3);
+                assert_eq!(error_class, 2);
+                assert_eq!(error_code, 32);
+            }
+            other => panic!("expected Error PDU, got {:?}", other),
+        }
+
+        // Error code above one byte
+        let apdu = Apdu::Error {
+            invoke_id: 1,
+            service_choice: 12,
+            error_class: 0,
+            error_code: 300,
+            error_data: Vec::new(),
+        };
+        assert!(matches!(
+            Apdu::decode(&apdu.encode()).unwrap(),
+            Apdu::Error {
+                error_code: 300,
+                ..
+            }
+        ));
+    }
 }
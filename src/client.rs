@@ -3,9 +3,23 @@
 //! This module provides high-level client utilities for common BACnet operations
 //! such as device discovery, object enumeration, and property reading.
 
+#[cfg(feature = "async")]
+mod async_client;
+
+#[cfg(feature = "async")]
+pub use async_client::{AsyncBacnetClient, AsyncClientError};
+
+#[cfg(feature = "std")]
+mod sync_client;
+
+#[cfg(feature = "std")]
+pub use sync_client::SyncClient;
+
 #[cfg(feature = "std")]
 use std::{
-    net::{SocketAddr, UdpSocket},
+    collections::HashMap,
+    net::{Ipv4Addr, SocketAddr, UdpSocket},
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
@@ -13,33 +27,116 @@ use std::{
 use alloc::{collections::BTreeMap as HashMap, string::String, vec::Vec};
 
 use crate::{
-    app::{Apdu, MaxApduSize, MaxSegments},
-    network::Npdu,
-    object::{ObjectIdentifier, ObjectType},
+    app::{
+        Apdu, ApplicationError, InvokeIdAllocator, MaxApdu, MaxSegments, SegmentProgress,
+        SegmentedAckReceiver,
+    },
+    datalink::{
+        bip::{Bvlc, BvlcFunction, ForeignDevice, ForeignDeviceRegistration},
+        DataLinkAddress, DataLinkType, Direction, FrameTrace,
+    },
+    network::{BacnetAddress, MacAddress, Npdu},
+    object::{
+        EngineeringUnits, ObjectIdentifier, ObjectType, PropertyIdentifier,
+        PropertyValue as ObjectPropertyValue,
+    },
     service::{
-        ConfirmedServiceChoice, IAmRequest, PropertyReference, ReadAccessSpecification,
-        ReadPropertyMultipleRequest, UnconfirmedServiceChoice, WhoIsRequest,
+        BacnetDateTime, BacnetError, ConfirmedServiceChoice, CovNotificationRequest, IAmRequest,
+        PropertyReference, ReadAccessSpecification, ReadPropertyAck, ReadPropertyMultipleAck,
+        ReadPropertyMultipleRequest, ReadPropertyRequest, TimeSynchronizationRequest,
+        UnconfirmedServiceChoice, WhoIsRequest,
     },
 };
 
+/// Confirmed request timing and size policy
+///
+/// Mirrors the Device object's APDU_Timeout, Number_Of_APDU_Retries and
+/// Max_APDU_Length_Accepted properties. A confirmed request is sent once and
+/// retransmitted up to `retries` times, waiting `apdu_timeout` after each
+/// attempt, before it fails with a timeout.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// Time to wait for an acknowledgement after each transmission
+    pub apdu_timeout: Duration,
+    /// Number of retransmissions after the first attempt
+    pub retries: u8,
+    /// Largest APDU this client accepts in a response
+    pub max_apdu_length: MaxApdu,
+}
+
+#[cfg(feature = "std")]
+impl ClientConfig {
+    /// Largest time a confirmed request can wait before failing
+    pub fn total_timeout(&self) -> Duration {
+        self.apdu_timeout * (self.retries as u32 + 1)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for ClientConfig {
+    /// Defaults of the Device object: 3 s APDU_Timeout, 3 retries, 1476 bytes
+    fn default() -> Self {
+        Self {
+            apdu_timeout: Duration::from_millis(3000),
+            retries: 3,
+            max_apdu_length: MaxApdu::Up1476,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::fmt::Display for ClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "APDU timeout {} ms, {} retries, max APDU {} bytes",
+            self.apdu_timeout.as_millis(),
+            self.retries,
+            self.max_apdu_length.as_bytes()
+        )
+    }
+}
+
 /// High-level BACnet client for device communication
 #[cfg(feature = "std")]
 pub struct BacnetClient {
     socket: UdpSocket,
-    timeout: Duration,
+    config: ClientConfig,
+    broadcast_addr: SocketAddr,
+    /// BBMD that broadcasts are distributed through, instead of broadcasting locally
+    bbmd_addr: Option<SocketAddr>,
+    /// Router to each remote network, learned from received frames
+    routers: Mutex<HashMap<u16, SocketAddr>>,
+    /// Invoke IDs of the confirmed requests awaiting a response
+    invoke_ids: Mutex<InvokeIdAllocator<()>>,
+    /// Hook called with every datagram sent or received
+    trace: Option<Arc<Mutex<FrameTrace>>>,
+    /// Max_APDU_Length_Accepted of each device, learned from I-Am
+    peers: Mutex<std::collections::HashMap<BacnetAddress, MaxApdu>>,
 }
 
 /// Discovered BACnet device information
 #[derive(Debug, Clone)]
 pub struct DeviceInfo {
     pub device_id: u32,
+    /// BACnet/IP address the I-Am came from, the router's for remote devices
     pub address: SocketAddr,
+    /// Address to send requests to, including the network of remote devices
+    pub bacnet_address: BacnetAddress,
     pub vendor_id: u32,
     pub vendor_name: String,
     pub max_apdu: u32,
     pub segmentation: u32,
 }
 
+impl DeviceInfo {
+    /// Get the device's Max_APDU_Length_Accepted as a standard size
+    pub fn max_apdu_size(&self) -> MaxApdu {
+        MaxApdu::from_length(self.max_apdu)
+    }
+}
+
 /// Object information with common properties
 #[derive(Debug, Clone)]
 pub struct ObjectInfo {
@@ -63,45 +160,163 @@ pub enum PropertyValue {
     Null,
 }
 
+impl PropertyValue {
+    /// Convert a decoded property value, if it has a counterpart here
+    fn from_value(value: &ObjectPropertyValue) -> Option<Self> {
+        match value {
+            ObjectPropertyValue::Real(value) => Some(Self::Real(*value)),
+            ObjectPropertyValue::Boolean(value) => Some(Self::Boolean(*value)),
+            ObjectPropertyValue::UnsignedInteger(value) => Some(Self::Unsigned(*value)),
+            ObjectPropertyValue::SignedInt(value) => Some(Self::Signed(*value)),
+            ObjectPropertyValue::CharacterString(value) => {
+                Some(Self::CharacterString(value.clone()))
+            }
+            ObjectPropertyValue::Enumerated(value) => Some(Self::Enumerated(*value)),
+            ObjectPropertyValue::Null => Some(Self::Null),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(feature = "std")]
 impl BacnetClient {
-    /// Create a new BACnet client
+    /// Create a new BACnet client with the default [`ClientConfig`]
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
-        let socket = UdpSocket::bind("0.0.0.0:0")?;
-        socket.set_read_timeout(Some(Duration::from_secs(5)))?;
+        Self::with_config(ClientConfig::default())
+    }
+
+    /// Create a new BACnet client with a timeout and retry policy
+    pub fn with_config(config: ClientConfig) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Self::from_socket(UdpSocket::bind("0.0.0.0:0")?, config)?)
+    }
+
+    /// Create a client sending and receiving on a bound socket
+    fn from_socket(socket: UdpSocket, config: ClientConfig) -> std::io::Result<Self> {
+        socket.set_read_timeout(Some(config.apdu_timeout))?;
+        socket.set_broadcast(true)?;
 
         Ok(Self {
             socket,
-            timeout: Duration::from_secs(5),
+            broadcast_addr: SocketAddr::from((Ipv4Addr::BROADCAST, 0xBAC0)),
+            bbmd_addr: None,
+            routers: Mutex::new(HashMap::new()),
+            invoke_ids: Mutex::new(InvokeIdAllocator::new(config.total_timeout())),
+            config,
+            trace: None,
+            peers: Mutex::new(std::collections::HashMap::new()),
         })
     }
 
-    /// Discover a device by IP address
+    /// Set the address Who-Is requests are broadcast to
+    pub fn with_broadcast_address(mut self, addr: SocketAddr) -> Self {
+        self.broadcast_addr = addr;
+        self
+    }
+
+    /// Distribute broadcasts through the BBMD at `addr`
+    ///
+    /// Broadcasts, including the Who-Is of [`discover_devices`](Self::discover_devices),
+    /// are then sent to the BBMD as Distribute-Broadcast-To-Network rather than
+    /// to the local broadcast address. The BBMD only distributes broadcasts
+    /// from registered foreign devices; see
+    /// [`register_foreign_device`](Self::register_foreign_device).
+    pub fn with_bbmd_address(mut self, addr: SocketAddr) -> Self {
+        self.bbmd_addr = Some(addr);
+        self
+    }
+
+    /// Register this client as a foreign device with its BBMD for `ttl` seconds
+    ///
+    /// The registration is renewed in the background until the returned
+    /// handle is dropped. Fails if no BBMD is configured with
+    /// [`with_bbmd_address`](Self::with_bbmd_address) or the BBMD rejects
+    /// the registration.
+    pub fn register_foreign_device(
+        &self,
+        ttl: u16,
+    ) -> Result<ForeignDeviceRegistration, Box<dyn std::error::Error>> {
+        let bbmd = self.bbmd_addr.ok_or("No BBMD address is configured")?;
+        let mut device =
+            ForeignDevice::new(self.socket.try_clone()?).with_timeout(self.config.apdu_timeout);
+        if let Some(trace) = self.trace.clone() {
+            device = device.with_trace(Box::new(move |direction, frame| {
+                (trace.lock().unwrap())(direction, frame)
+            }));
+        }
+        let registration = device.register(bbmd, ttl)?;
+        Ok(registration)
+    }
+
+    /// Call `trace` with every datagram sent or received, BVLC header included
+    ///
+    /// Replaces any previous hook. Without one, frames are not traced.
+    /// Foreign device registrations keep the hook they were made with.
+    pub fn set_trace(&mut self, trace: FrameTrace) {
+        self.trace = Some(Arc::new(Mutex::new(trace)));
+    }
+
+    /// Stop tracing frames
+    pub fn clear_trace(&mut self) {
+        self.trace = None;
+    }
+
+    /// Get the effective timeout and retry policy
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// Route requests for a remote network through the router at `router`
+    ///
+    /// Routers are also learned from the source of received frames. Until
+    /// one is known, requests for the network are broadcast locally.
+    pub fn add_router(&self, network: u16, router: SocketAddr) {
+        self.routers.lock().unwrap().insert(network, router);
+    }
+
+    /// Limit confirmed requests to `device` to APDUs of `max_apdu`
+    ///
+    /// The limit is also learned from each I-Am the client receives. Until
+    /// one is known, requests are limited to the client's own
+    /// [`max_apdu_length`](ClientConfig::max_apdu_length).
+    pub fn set_peer_max_apdu(&self, device: impl Into<BacnetAddress>, max_apdu: MaxApdu) {
+        self.peers.lock().unwrap().insert(device.into(), max_apdu);
+    }
+
+    /// Largest service data a confirmed request to `device` can carry unsegmented
+    ///
+    /// Larger requests must be split with
+    /// [`SegmentationManager::segment_for_peer`](crate::app::SegmentationManager::segment_for_peer).
+    pub fn max_request_service_data(&self, device: &DeviceInfo) -> usize {
+        device.max_apdu_size().max_request_service_data()
+    }
+
+    /// Discover a device by address
     pub fn discover_device(
         &self,
-        target_addr: SocketAddr,
+        target: impl Into<BacnetAddress>,
     ) -> Result<DeviceInfo, Box<dyn std::error::Error>> {
+        let target = target.into();
+
         // Send Who-Is request
         let whois = WhoIsRequest::new();
         let mut buffer = Vec::new();
         whois.encode(&mut buffer)?;
-
-        // Create and send message
-        let message =
-            self.create_unconfirmed_message(UnconfirmedServiceChoice::WhoIs as u8, &buffer);
-        self.socket.send_to(&message, target_addr)?;
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoIs,
+            service_data: buffer,
+        };
+        self.send_apdu(&apdu, &target, false)?;
 
         // Wait for I-Am response
         let mut recv_buffer = [0u8; 1500];
         let start_time = Instant::now();
 
-        while start_time.elapsed() < self.timeout {
-            match self.socket.recv_from(&mut recv_buffer) {
+        while start_time.elapsed() < self.config.total_timeout() {
+            match self.recv_from(&mut recv_buffer) {
                 Ok((len, source)) => {
-                    if source == target_addr {
-                        if let Some(device_info) =
-                            self.parse_iam_response(&recv_buffer[..len], source)
-                        {
+                    if let Some(device_info) = self.parse_iam_response(&recv_buffer[..len], source)
+                    {
+                        if device_info.bacnet_address == target {
                             return Ok(device_info);
                         }
                     }
@@ -114,10 +329,173 @@ impl BacnetClient {
         Err("Device discovery timeout".into())
     }
 
+    /// Broadcast a Who-Is and collect every device that answers within `timeout`
+    ///
+    /// Responses keep arriving after the first one, so this listens for the
+    /// whole timeout. Devices outside the request's instance range are
+    /// ignored, and each device is reported once. With a BBMD configured the
+    /// Who-Is is distributed through it, reaching the other subnets too.
+    pub fn discover_devices(
+        &self,
+        request: &WhoIsRequest,
+        timeout: Duration,
+    ) -> Result<Vec<DeviceInfo>, Box<dyn std::error::Error>> {
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer)?;
+        let (addr, function) = self.broadcast_hop();
+        let message = self.create_unconfirmed_message(
+            UnconfirmedServiceChoice::WhoIs as u8,
+            &buffer,
+            function,
+        );
+        self.send_to(&message, addr)?;
+        self.collect_devices(request, timeout)
+    }
+
+    /// Send a directed Who-Is to `target` and collect the devices that answer
+    ///
+    /// `target` may be a single device, or a remote network's broadcast
+    /// address to discover the devices behind a router. Answers are collected
+    /// as with [`discover_devices`](Self::discover_devices).
+    pub fn discover_devices_at(
+        &self,
+        target: impl Into<BacnetAddress>,
+        request: &WhoIsRequest,
+        timeout: Duration,
+    ) -> Result<Vec<DeviceInfo>, Box<dyn std::error::Error>> {
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer)?;
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoIs,
+            service_data: buffer,
+        };
+        self.send_apdu(&apdu, &target.into(), false)?;
+        self.collect_devices(request, timeout)
+    }
+
+    /// Collect the devices answering `request` within `timeout`
+    fn collect_devices(
+        &self,
+        request: &WhoIsRequest,
+        timeout: Duration,
+    ) -> Result<Vec<DeviceInfo>, Box<dyn std::error::Error>> {
+        let mut devices: Vec<DeviceInfo> = Vec::new();
+        let mut recv_buffer = [0u8; 1500];
+        let deadline = Instant::now() + timeout;
+        let result = loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break Ok(());
+            }
+            self.socket.set_read_timeout(Some(remaining))?;
+            match self.recv_from(&mut recv_buffer) {
+                Ok((len, source)) => {
+                    if let Some(device) = self.parse_iam_response(&recv_buffer[..len], source) {
+                        let known = devices
+                            .iter()
+                            .any(|known| known.device_id == device.device_id);
+                        if request.matches(device.device_id) && !known {
+                            devices.push(device);
+                        }
+                    }
+                }
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    continue
+                }
+                Err(e) => break Err(e),
+            }
+        };
+
+        // Confirmed requests rely on the configured APDU timeout
+        self.socket
+            .set_read_timeout(Some(self.config.apdu_timeout))?;
+        result?;
+        Ok(devices)
+    }
+
+    /// Broadcast a TimeSynchronization request setting every device to `now`
+    ///
+    /// `now` is local time; devices apply it without a UTC offset.
+    pub fn time_sync_broadcast(
+        &self,
+        now: BacnetDateTime,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut buffer = Vec::new();
+        TimeSynchronizationRequest::new(now).encode(&mut buffer)?;
+        let (addr, function) = self.broadcast_hop();
+        let message = self.create_unconfirmed_message(
+            UnconfirmedServiceChoice::TimeSynchronization as u8,
+            &buffer,
+            function,
+        );
+        self.send_to(&message, addr)?;
+        Ok(())
+    }
+
+    /// Wait up to `timeout` for a COV notification from a subscription
+    ///
+    /// Confirmed notifications are acknowledged with a Simple-ACK before
+    /// they are returned, so the device does not retransmit them or cancel
+    /// the subscription; unconfirmed ones need no reply. Other traffic is
+    /// discarded. Returns `None` if nothing arrives in time.
+    pub fn receive_cov_notification(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<(BacnetAddress, CovNotificationRequest)>, Box<dyn std::error::Error>> {
+        let mut recv_buffer = [0u8; 1500];
+        let deadline = Instant::now() + timeout;
+        let result = loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break Ok(None);
+            }
+            self.socket.set_read_timeout(Some(remaining))?;
+            let (len, source) = match self.recv_from(&mut recv_buffer) {
+                Ok(received) => received,
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    continue
+                }
+                Err(e) => break Err(e.into()),
+            };
+            let Some((notification, invoke_id)) = self.parse_cov_notification(&recv_buffer[..len])
+            else {
+                continue;
+            };
+            let Some(source) = self.frame_source(&recv_buffer[..len], source) else {
+                continue;
+            };
+            if let Some(invoke_id) = invoke_id {
+                let ack = Apdu::SimpleAck {
+                    invoke_id,
+                    service_choice: ConfirmedServiceChoice::ConfirmedCOVNotification as u8,
+                };
+                if let Err(e) = self.send_apdu(&ack, &source, false) {
+                    break Err(e);
+                }
+            }
+            break Ok(Some((source, notification)));
+        };
+
+        // Confirmed requests rely on the configured APDU timeout
+        self.socket
+            .set_read_timeout(Some(self.config.apdu_timeout))?;
+        result
+    }
+
     /// Read the device's object list
     pub fn read_object_list(
         &self,
-        target_addr: SocketAddr,
+        target: impl Into<BacnetAddress>,
         device_id: u32,
     ) -> Result<Vec<ObjectIdentifier>, Box<dyn std::error::Error>> {
         let device_object = ObjectIdentifier::new(ObjectType::Device, device_id);
@@ -125,27 +503,57 @@ impl BacnetClient {
         let read_spec = ReadAccessSpecification::new(device_object, vec![property_ref]);
         let rpm_request = ReadPropertyMultipleRequest::new(vec![read_spec]);
 
-        let invoke_id = 1;
+        let ack = self.read_property_multiple(target, &rpm_request)?;
+        self.parse_object_list_response(&ack)
+    }
+
+    /// Read a property of an object with ReadProperty
+    ///
+    /// A segmented acknowledgement is reassembled before it is decoded.
+    pub fn read_property(
+        &self,
+        target: impl Into<BacnetAddress>,
+        object_identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+    ) -> Result<ObjectPropertyValue, Box<dyn std::error::Error>> {
+        let mut service_data = Vec::new();
+        ReadPropertyRequest::new(object_identifier, property.as_u32()).encode(&mut service_data)?;
         let response_data = self.send_confirmed_request(
-            target_addr,
-            invoke_id,
-            ConfirmedServiceChoice::ReadPropertyMultiple,
-            &self.encode_rpm_request(&rpm_request)?,
+            &target.into(),
+            ConfirmedServiceChoice::ReadProperty,
+            &service_data,
         )?;
+        Ok(ReadPropertyAck::decode(&response_data)?.value)
+    }
 
-        self.parse_object_list_response(&response_data)
+    /// Read properties of several objects with ReadPropertyMultiple
+    ///
+    /// A segmented acknowledgement is reassembled before it is decoded, so
+    /// results may span segment boundaries.
+    pub fn read_property_multiple(
+        &self,
+        target: impl Into<BacnetAddress>,
+        request: &ReadPropertyMultipleRequest,
+    ) -> Result<ReadPropertyMultipleAck, Box<dyn std::error::Error>> {
+        let response_data = self.send_confirmed_request(
+            &target.into(),
+            ConfirmedServiceChoice::ReadPropertyMultiple,
+            &self.encode_rpm_request(request)?,
+        )?;
+        Ok(ReadPropertyMultipleAck::decode(&response_data)?)
     }
 
     /// Read properties for multiple objects
     pub fn read_objects_properties(
         &self,
-        target_addr: SocketAddr,
+        target: impl Into<BacnetAddress>,
         objects: &[ObjectIdentifier],
     ) -> Result<Vec<ObjectInfo>, Box<dyn std::error::Error>> {
+        let target = target.into();
         let mut objects_info = Vec::new();
         let batch_size = 5;
 
-        for (batch_idx, chunk) in objects.chunks(batch_size).enumerate() {
+        for chunk in objects.chunks(batch_size) {
             let mut read_specs = Vec::new();
 
             for obj in chunk {
@@ -186,32 +594,9 @@ impl BacnetClient {
             }
 
             let rpm_request = ReadPropertyMultipleRequest::new(read_specs);
-            let invoke_id = (batch_idx + 2) as u8;
 
-            match self.send_confirmed_request(
-                target_addr,
-                invoke_id,
-                ConfirmedServiceChoice::ReadPropertyMultiple,
-                &self.encode_rpm_request(&rpm_request)?,
-            ) {
-                Ok(response_data) => {
-                    match self.parse_rpm_response(&response_data, chunk) {
-                        Ok(mut batch_info) => objects_info.append(&mut batch_info),
-                        Err(_) => {
-                            // Add objects with minimal info on parse failure
-                            for obj in chunk {
-                                objects_info.push(ObjectInfo {
-                                    object_identifier: *obj,
-                                    object_name: None,
-                                    description: None,
-                                    present_value: None,
-                                    units: None,
-                                    status_flags: None,
-                                });
-                            }
-                        }
-                    }
-                }
+            match self.read_property_multiple(target.clone(), &rpm_request) {
+                Ok(ack) => objects_info.extend(self.parse_rpm_response(&ack, chunk)),
                 Err(_) => {
                     // Add objects with minimal info on communication failure
                     for obj in chunk {
@@ -234,8 +619,13 @@ impl BacnetClient {
         Ok(objects_info)
     }
 
-    /// Create an unconfirmed message
-    fn create_unconfirmed_message(&self, service_choice: u8, service_data: &[u8]) -> Vec<u8> {
+    /// Create an unconfirmed message, as a unicast or broadcast BVLC frame
+    fn create_unconfirmed_message(
+        &self,
+        service_choice: u8,
+        service_data: &[u8],
+        function: BvlcFunction,
+    ) -> Vec<u8> {
         // Create NPDU
         let mut npdu = Npdu::new();
         npdu.control.expecting_reply = false;
@@ -251,8 +641,8 @@ impl BacnetClient {
         let mut message = npdu_buffer;
         message.extend_from_slice(&apdu);
 
-        // Wrap in BVLC header for BACnet/IP (unicast)
-        let mut bvlc_message = vec![0x81, 0x0A, 0x00, 0x00];
+        // Wrap in BVLC header for BACnet/IP
+        let mut bvlc_message = vec![0x81, function as u8, 0x00, 0x00];
         bvlc_message.extend_from_slice(&message);
 
         // Update BVLC length
@@ -264,9 +654,43 @@ impl BacnetClient {
     }
 
     /// Send a confirmed request and wait for response
+    ///
+    /// The request holds a free invoke ID until it completes or times out.
+    /// IDs still held by requests that stopped retransmitting, such as those
+    /// of a caller that panicked, are freed first. Requests too large for the
+    /// device's Max_APDU_Length_Accepted fail with
+    /// [`ApplicationError::MaxApduLengthExceeded`] without being sent.
     fn send_confirmed_request(
         &self,
-        target_addr: SocketAddr,
+        target: &BacnetAddress,
+        service_choice: ConfirmedServiceChoice,
+        service_data: &[u8],
+    ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let peer_max_apdu = self
+            .peers
+            .lock()
+            .unwrap()
+            .get(target)
+            .copied()
+            .unwrap_or(self.config.max_apdu_length);
+        if service_data.len() > peer_max_apdu.max_request_service_data() {
+            return Err(ApplicationError::MaxApduLengthExceeded.into());
+        }
+
+        let invoke_id = {
+            let mut invoke_ids = self.invoke_ids.lock().unwrap();
+            invoke_ids.expire();
+            invoke_ids.allocate(service_choice, ())?
+        };
+        let result = self.exchange_confirmed(target, invoke_id, service_choice, service_data);
+        self.invoke_ids.lock().unwrap().complete(invoke_id);
+        result
+    }
+
+    /// Send a confirmed request under `invoke_id` until its response arrives
+    fn exchange_confirmed(
+        &self,
+        target: &BacnetAddress,
         invoke_id: u8,
         service_choice: ConfirmedServiceChoice,
         service_data: &[u8],
@@ -276,7 +700,7 @@ impl BacnetClient {
             more_follows: false,
             segmented_response_accepted: true,
             max_segments: MaxSegments::Unspecified,
-            max_response_size: MaxApduSize::Up1476,
+            max_response_size: self.config.max_apdu_length,
             invoke_id,
             sequence_number: None,
             proposed_window_size: None,
@@ -284,66 +708,204 @@ impl BacnetClient {
             service_data: service_data.to_vec(),
         };
 
+        self.send_apdu(&apdu, target, true)?;
+
+        // Wait for response, reassembling a segmented Complex-ACK if needed
+        let mut receiver = SegmentedAckReceiver::new(
+            invoke_id,
+            self.config.max_apdu_length,
+            MaxSegments::Unspecified,
+            self.config.retries,
+        );
+        let mut retries_left = self.config.retries;
+        let mut recv_buffer = [0u8; 1500];
+        let mut start_time = Instant::now();
+
+        loop {
+            if start_time.elapsed() >= self.config.apdu_timeout {
+                if receiver.segments_received() > 0 {
+                    // Re-acknowledge the last segment until the retries run out
+                    let segment_ack = receiver.on_timeout()?;
+                    self.send_apdu(&segment_ack, target, false)?;
+                } else if retries_left > 0 {
+                    retries_left -= 1;
+                    self.send_apdu(&apdu, target, true)?;
+                } else {
+                    return Err(ApplicationError::Timeout.into());
+                }
+                self.invoke_ids.lock().unwrap().restart_timer(invoke_id);
+                start_time = Instant::now();
+            }
+
+            let (len, source) = match self.recv_from(&mut recv_buffer) {
+                Ok(received) => received,
+                Err(e)
+                    if e.kind() == std::io::ErrorKind::WouldBlock
+                        || e.kind() == std::io::ErrorKind::TimedOut =>
+                {
+                    continue
+                }
+                Err(e) => return Err(e.into()),
+            };
+            if self.frame_source(&recv_buffer[..len], source).as_ref() != Some(target) {
+                continue;
+            }
+            let Some((apdu, apdu_len)) =
+                self.process_confirmed_response(&recv_buffer[..len], invoke_id)
+            else {
+                continue;
+            };
+
+            match apdu {
+                Apdu::ComplexAck {
+                    segmented: false,
+                    service_data,
+                    ..
+                } => return Ok(service_data),
+                Apdu::ComplexAck { .. } => match receiver.process(&apdu, apdu_len)? {
+                    SegmentProgress::InProgress(segment_ack) => {
+                        if let Some(segment_ack) = segment_ack {
+                            self.send_apdu(&segment_ack, target, false)?;
+                        }
+                        self.invoke_ids.lock().unwrap().restart_timer(invoke_id);
+                        start_time = Instant::now();
+                    }
+                    SegmentProgress::Complete {
+                        segment_ack,
+                        service_data,
+                        ..
+                    } => {
+                        self.send_apdu(&segment_ack, target, false)?;
+                        return Ok(service_data);
+                    }
+                },
+                Apdu::Error {
+                    error_class,
+                    error_code,
+                    ..
+                } => return Err(BacnetError::from_error(error_class, error_code).into()),
+                Apdu::Reject { reject_reason, .. } => {
+                    return Err(BacnetError::Reject(reject_reason.into()).into())
+                }
+                Apdu::Abort { abort_reason, .. } => {
+                    return Err(BacnetError::Abort(abort_reason.into()).into())
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Wrap an APDU in NPDU and BVLC headers and send it
+    ///
+    /// Remote targets get DNET/DADR in the NPDU, and the frame goes to the
+    /// network's router.
+    fn send_apdu(
+        &self,
+        apdu: &Apdu,
+        target: &BacnetAddress,
+        expecting_reply: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (target_addr, function) = self.next_hop(target)?;
         let apdu_data = apdu.encode();
-        let mut npdu = Npdu::new();
-        npdu.control.expecting_reply = true;
+        let mut npdu = Npdu::to_address(target);
+        npdu.control.expecting_reply = expecting_reply;
         npdu.control.priority = 0;
         let npdu_data = npdu.encode();
 
         let mut message = npdu_data;
         message.extend_from_slice(&apdu_data);
 
-        let mut bvlc_message = vec![0x81, 0x0A, 0x00, 0x00];
+        let mut bvlc_message = vec![0x81, function as u8, 0x00, 0x00];
         bvlc_message.extend_from_slice(&message);
 
         let total_len = bvlc_message.len() as u16;
         bvlc_message[2] = (total_len >> 8) as u8;
         bvlc_message[3] = (total_len & 0xFF) as u8;
 
-        self.socket.send_to(&bvlc_message, target_addr)?;
+        self.send_to(&bvlc_message, target_addr)?;
+        Ok(())
+    }
+
+    /// Send a datagram, passing it to the trace hook once sent
+    fn send_to(&self, frame: &[u8], addr: SocketAddr) -> std::io::Result<usize> {
+        let sent = self.socket.send_to(frame, addr)?;
+        self.trace_frame(Direction::Sent, frame);
+        Ok(sent)
+    }
 
-        // Wait for response
-        let mut recv_buffer = [0u8; 1500];
-        let start_time = Instant::now();
+    /// Receive a datagram, passing it to the trace hook
+    fn recv_from(&self, buffer: &mut [u8]) -> std::io::Result<(usize, SocketAddr)> {
+        let (len, source) = self.socket.recv_from(buffer)?;
+        self.trace_frame(Direction::Received, &buffer[..len]);
+        Ok((len, source))
+    }
 
-        while start_time.elapsed() < self.timeout {
-            match self.socket.recv_from(&mut recv_buffer) {
-                Ok((len, source)) => {
-                    if source == target_addr {
-                        if let Some(response_data) =
-                            self.process_confirmed_response(&recv_buffer[..len], invoke_id)
-                        {
-                            return Ok(response_data);
-                        }
-                    }
-                }
-                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => continue,
-                Err(e) => return Err(e.into()),
-            }
+    /// Pass a datagram to the trace hook, if one is set
+    fn trace_frame(&self, direction: Direction, frame: &[u8]) {
+        if let Some(trace) = &self.trace {
+            (trace.lock().unwrap())(direction, frame);
         }
+    }
 
-        Err("Request timeout".into())
+    /// Find the BACnet/IP address a frame for `target` is sent to
+    ///
+    /// Also returns the BVLC function to send it with, a broadcast one for
+    /// broadcasts and remote networks without a known router.
+    fn next_hop(
+        &self,
+        target: &BacnetAddress,
+    ) -> Result<(SocketAddr, BvlcFunction), Box<dyn std::error::Error>> {
+        let router = match target.network_number {
+            Some(network) => self.routers.lock().unwrap().get(&network).copied(),
+            None => None,
+        };
+        match (router, target.network_number, &target.mac) {
+            (Some(router), _, _) => Ok((router, BvlcFunction::OriginalUnicastNpdu)),
+            (None, Some(_), _) | (None, None, MacAddress::Broadcast) => Ok(self.broadcast_hop()),
+            (None, None, MacAddress::Ip(addr)) => {
+                Ok((SocketAddr::V4(*addr), BvlcFunction::OriginalUnicastNpdu))
+            }
+            (None, None, mac) => Err(format!("{} is not a BACnet/IP address", mac).into()),
+        }
     }
 
-    /// Parse I-Am response
-    fn parse_iam_response(&self, data: &[u8], source: SocketAddr) -> Option<DeviceInfo> {
-        // Check BVLC header
-        if data.len() < 4 || data[0] != 0x81 {
-            return None;
+    /// Find where a broadcast is sent, and the BVLC function to send it with
+    ///
+    /// Broadcasts go to the BBMD as Distribute-Broadcast-To-Network when one
+    /// is configured, and to the local broadcast address otherwise.
+    fn broadcast_hop(&self) -> (SocketAddr, BvlcFunction) {
+        match self.bbmd_addr {
+            Some(bbmd) => (bbmd, BvlcFunction::DistributeBroadcastToNetwork),
+            None => (self.broadcast_addr, BvlcFunction::OriginalBroadcastNpdu),
         }
+    }
 
-        let bvlc_length = ((data[2] as u16) << 8) | (data[3] as u16);
-        if data.len() != bvlc_length as usize {
-            return None;
+    /// Get the sender of a BACnet/IP frame received from `source`
+    ///
+    /// Frames from remote networks name the sender in the NPDU; the IP
+    /// sender is then remembered as the router to that network.
+    fn frame_source(&self, data: &[u8], source: SocketAddr) -> Option<BacnetAddress> {
+        let (bvlc, sender) = decode_frame(data, source)?;
+        let (npdu, _) = Npdu::decode(bvlc.npdu()?).ok()?;
+        if let Some(remote) = &npdu.source {
+            self.add_router(remote.network, sender);
         }
+        Some(BacnetAddress::npdu_source(
+            &npdu,
+            &DataLinkAddress::Ip(sender),
+            DataLinkType::BacnetIp,
+        ))
+    }
 
-        // Decode NPDU
-        let npdu_start = 4;
-        let (_npdu, npdu_len) = Npdu::decode(&data[npdu_start..]).ok()?;
+    /// Parse I-Am response
+    fn parse_iam_response(&self, data: &[u8], source: SocketAddr) -> Option<DeviceInfo> {
+        let bacnet_address = self.frame_source(data, source)?;
+        let (bvlc, sender) = decode_frame(data, source)?;
 
-        // Decode APDU
-        let apdu_start = npdu_start + npdu_len;
-        let apdu = &data[apdu_start..];
+        // Decode NPDU and APDU
+        let npdu_data = bvlc.npdu()?;
+        let (_npdu, npdu_len) = Npdu::decode(npdu_data).ok()?;
+        let apdu = &npdu_data[npdu_len..];
 
         if apdu.len() < 2 || apdu[0] != 0x10 || apdu[1] != UnconfirmedServiceChoice::IAm as u8 {
             return None;
@@ -355,49 +917,76 @@ impl BacnetClient {
                     .unwrap_or("Unknown Vendor")
                     .to_string();
 
+                self.peers.lock().unwrap().insert(
+                    bacnet_address.clone(),
+                    MaxApdu::from_length(iam.max_apdu_length_accepted),
+                );
                 Some(DeviceInfo {
                     device_id: iam.device_identifier.instance,
-                    address: source,
+                    address: sender,
+                    bacnet_address,
                     vendor_id: iam.vendor_identifier,
                     vendor_name,
                     max_apdu: iam.max_apdu_length_accepted,
-                    segmentation: iam.segmentation_supported,
+                    segmentation: iam.segmentation_supported as u32,
                 })
             }
             Err(_) => None,
         }
     }
 
-    /// Process confirmed response
-    fn process_confirmed_response(&self, data: &[u8], expected_invoke_id: u8) -> Option<Vec<u8>> {
-        // Check BVLC header
-        if data.len() < 4 || data[0] != 0x81 {
-            return None;
-        }
-
-        let bvlc_length = ((data[2] as u16) << 8) | (data[3] as u16);
-        if data.len() != bvlc_length as usize {
-            return None;
-        }
+    /// Parse a COV notification, with the invoke ID if it is confirmed
+    fn parse_cov_notification(&self, data: &[u8]) -> Option<(CovNotificationRequest, Option<u8>)> {
+        let bvlc = Bvlc::decode(data).ok()?;
 
         // Decode NPDU and APDU
-        let npdu_start = 4;
-        let (_npdu, npdu_len) = Npdu::decode(&data[npdu_start..]).ok()?;
-
-        let apdu_start = npdu_start + npdu_len;
-        let apdu = Apdu::decode(&data[apdu_start..]).ok()?;
+        let npdu_data = bvlc.npdu()?;
+        let (_npdu, npdu_len) = Npdu::decode(npdu_data).ok()?;
+        let apdu = Apdu::decode(&npdu_data[npdu_len..]).ok()?;
 
         match apdu {
-            Apdu::ComplexAck {
+            Apdu::ConfirmedRequest {
                 invoke_id,
+                service_choice: ConfirmedServiceChoice::ConfirmedCOVNotification,
                 service_data,
                 ..
-            } => {
-                if invoke_id == expected_invoke_id {
-                    Some(service_data)
-                } else {
-                    None
-                }
+            } => Some((
+                CovNotificationRequest::decode(&service_data).ok()?,
+                Some(invoke_id),
+            )),
+            Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::UnconfirmedCOVNotification,
+                service_data,
+            } => Some((CovNotificationRequest::decode(&service_data).ok()?, None)),
+            _ => None,
+        }
+    }
+
+    /// Process confirmed response
+    ///
+    /// Returns the Complex-ACK, Error, Reject or Abort PDU for
+    /// `expected_invoke_id` and its encoded length.
+    fn process_confirmed_response(
+        &self,
+        data: &[u8],
+        expected_invoke_id: u8,
+    ) -> Option<(Apdu, usize)> {
+        let bvlc = Bvlc::decode(data).ok()?;
+
+        // Decode NPDU and APDU
+        let npdu_data = bvlc.npdu()?;
+        let (_npdu, npdu_len) = Npdu::decode(npdu_data).ok()?;
+        let apdu = Apdu::decode(&npdu_data[npdu_len..]).ok()?;
+
+        let matched = self.invoke_ids.lock().unwrap().match_response(&apdu);
+        match apdu {
+            Apdu::ComplexAck { .. }
+            | Apdu::Error { .. }
+            | Apdu::Reject { .. }
+            | Apdu::Abort { .. }
+                if matched == Some(expected_invoke_id) =>
+            {
+                Some((apdu, npdu_data.len() - npdu_len))
             }
             _ => None,
         }
@@ -409,117 +998,119 @@ impl BacnetClient {
         request: &ReadPropertyMultipleRequest,
     ) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
         let mut buffer = Vec::new();
-
-        for spec in &request.read_access_specifications {
-            // Object identifier - context tag 0
-            let object_id = encode_object_id(
-                spec.object_identifier.object_type as u16,
-                spec.object_identifier.instance,
-            );
-            buffer.push(0x0C);
-            buffer.extend_from_slice(&object_id.to_be_bytes());
-
-            // Property references - context tag 1
-            buffer.push(0x1E);
-            for prop_ref in &spec.property_references {
-                buffer.push(0x09);
-                buffer.push(prop_ref.property_identifier as u8);
-
-                if let Some(array_index) = prop_ref.property_array_index {
-                    buffer.push(0x19);
-                    buffer.push(array_index as u8);
-                }
-            }
-            buffer.push(0x1F);
-        }
-
+        request.encode(&mut buffer)?;
         Ok(buffer)
     }
 
-    /// Parse object list response
+    /// Collect the objects of an Object_List read, except the device itself
     fn parse_object_list_response(
         &self,
-        data: &[u8],
+        ack: &ReadPropertyMultipleAck,
     ) -> Result<Vec<ObjectIdentifier>, Box<dyn std::error::Error>> {
         let mut objects = Vec::new();
-        let mut pos = 0;
-
-        // Scan for object identifiers (0xC4 tag)
-        while pos + 5 <= data.len() {
-            if data[pos] == 0xC4 {
-                pos += 1;
-                let obj_id_bytes = [data[pos], data[pos + 1], data[pos + 2], data[pos + 3]];
-                let obj_id = u32::from_be_bytes(obj_id_bytes);
-                let (obj_type, instance) = decode_object_id(obj_id);
-
-                // Skip device object itself
-                if obj_type != 8 {
-                    if let Ok(object_type) = ObjectType::try_from(obj_type) {
-                        objects.push(ObjectIdentifier::new(object_type, instance));
-                    }
+        let results = ack
+            .read_access_results
+            .iter()
+            .flat_map(|access_result| &access_result.results)
+            .filter(|result| result.property_identifier == 76);
+        for result in results {
+            let values = match &result.value {
+                Ok(ObjectPropertyValue::Array(values)) => values.as_slice(),
+                Ok(_) => return Err("Object_List is not an array".into()),
+                Err(error) => {
+                    return Err(
+                        BacnetError::from_error(error.error_class as u32, error.error_code).into(),
+                    )
                 }
-                pos += 4;
-            } else {
-                pos += 1;
-            }
+            };
+            objects.extend(values.iter().filter_map(|value| match value {
+                ObjectPropertyValue::ObjectIdentifier(id)
+                    if id.object_type != ObjectType::Device =>
+                {
+                    Some(*id)
+                }
+                _ => None,
+            }));
         }
 
         Ok(objects)
     }
 
-    /// Parse ReadPropertyMultiple response
+    /// Fill in the information of each object from a ReadPropertyMultiple ack
     fn parse_rpm_response(
         &self,
-        data: &[u8],
+        ack: &ReadPropertyMultipleAck,
         objects: &[ObjectIdentifier],
-    ) -> Result<Vec<ObjectInfo>, Box<dyn std::error::Error>> {
-        let mut objects_info = Vec::new();
-
-        // Simple implementation - create ObjectInfo for each requested object
-        for obj in objects {
-            let mut object_info = ObjectInfo {
-                object_identifier: *obj,
-                object_name: None,
-                description: None,
-                present_value: None,
-                units: None,
-                status_flags: None,
-            };
-
-            // Parse properties from response data
-            // This is a simplified implementation - in practice you'd need more robust parsing
-            if let Some(PropertyValue::CharacterString(s)) = extract_property_value(data, 77) {
-                object_info.object_name = Some(s);
-            }
-
-            if let Some(PropertyValue::CharacterString(s)) = extract_property_value(data, 28) {
-                object_info.description = Some(s);
-            }
-
-            if let Some(value) = extract_property_value(data, 85) {
-                object_info.present_value = Some(value);
-            }
-
-            objects_info.push(object_info);
-        }
-
-        Ok(objects_info)
+    ) -> Vec<ObjectInfo> {
+        objects
+            .iter()
+            .map(|obj| {
+                let mut object_info = ObjectInfo {
+                    object_identifier: *obj,
+                    object_name: None,
+                    description: None,
+                    present_value: None,
+                    units: None,
+                    status_flags: None,
+                };
+
+                let results = ack
+                    .read_access_results
+                    .iter()
+                    .filter(|access_result| access_result.object_identifier == *obj)
+                    .flat_map(|access_result| &access_result.results);
+                for result in results {
+                    let Ok(value) = &result.value else {
+                        continue;
+                    };
+                    match (result.property_identifier, value) {
+                        (77, ObjectPropertyValue::CharacterString(name)) => {
+                            object_info.object_name = Some(name.clone())
+                        }
+                        (28, ObjectPropertyValue::CharacterString(description)) => {
+                            object_info.description = Some(description.clone())
+                        }
+                        (85, value) => object_info.present_value = PropertyValue::from_value(value),
+                        (111, ObjectPropertyValue::BitString(flags)) => {
+                            object_info.status_flags = Some(flags.clone())
+                        }
+                        (117, ObjectPropertyValue::Enumerated(units)) => {
+                            object_info.units = Some(EngineeringUnits::from(*units).to_string())
+                        }
+                        _ => {}
+                    }
+                }
+                object_info
+            })
+            .collect()
     }
 }
 
-/// Extract property value from encoded data (simplified implementation)
-fn extract_property_value(_data: &[u8], _property_id: u32) -> Option<PropertyValue> {
-    // This would need a full implementation based on BACnet encoding rules
-    // For now, return None as a placeholder
-    None
+/// Decode a BACnet/IP frame received from `source`, with its original sender
+///
+/// A Forwarded-NPDU relayed by a BBMD names the device that broadcast it;
+/// any other frame was sent by `source` itself.
+fn decode_frame(data: &[u8], source: SocketAddr) -> Option<(Bvlc, SocketAddr)> {
+    let bvlc = Bvlc::decode(data).ok()?;
+    let sender = match &bvlc {
+        Bvlc::ForwardedNpdu {
+            source_ip,
+            source_port,
+            ..
+        } => SocketAddr::from((*source_ip, *source_port)),
+        _ => source,
+    };
+    Some((bvlc, sender))
 }
 
 /// Encode object identifier
+#[cfg(test)]
 fn encode_object_id(object_type: u16, instance: u32) -> u32 {
     ((object_type as u32) << 22) | (instance & 0x3FFFFF)
 }
 
-/// Decode object identifier  
+/// Decode object identifier
+#[cfg(test)]
 fn decode_object_id(encoded: u32) -> (u16, u32) {
     let object_type = ((encoded >> 22) & 0x3FF) as u16;
     let instance = encoded & 0x3FFFFF;
@@ -560,12 +1151,49 @@ pub fn get_object_type_name(object_type: ObjectType) -> &'static str {
         ObjectType::LoadControl => "Load Control",
         ObjectType::StructuredView => "Structured View",
         ObjectType::AccessDoor => "Access Door",
+        ObjectType::Timer => "Timer",
+        ObjectType::AccessCredential => "Access Credential",
+        ObjectType::AccessPoint => "Access Point",
+        ObjectType::AccessRights => "Access Rights",
+        ObjectType::AccessUser => "Access User",
+        ObjectType::AccessZone => "Access Zone",
+        ObjectType::CredentialDataInput => "Credential Data Input",
+        ObjectType::NetworkSecurity => "Network Security",
+        ObjectType::BitStringValue => "BitString Value",
+        ObjectType::CharacterStringValue => "CharacterString Value",
+        ObjectType::DatePatternValue => "Date Pattern Value",
+        ObjectType::DateValue => "Date Value",
+        ObjectType::DateTimePatternValue => "DateTime Pattern Value",
+        ObjectType::DateTimeValue => "DateTime Value",
+        ObjectType::IntegerValue => "Integer Value",
+        ObjectType::LargeAnalogValue => "Large Analog Value",
+        ObjectType::OctetStringValue => "OctetString Value",
+        ObjectType::PositiveIntegerValue => "Positive Integer Value",
+        ObjectType::TimePatternValue => "Time Pattern Value",
+        ObjectType::TimeValue => "Time Value",
+        ObjectType::NotificationForwarder => "Notification Forwarder",
+        ObjectType::AlertEnrollment => "Alert Enrollment",
+        ObjectType::Channel => "Channel",
+        ObjectType::LightingOutput => "Lighting Output",
+        ObjectType::BinaryLightingOutput => "Binary Lighting Output",
+        ObjectType::NetworkPort => "Network Port",
+        ObjectType::ElevatorGroup => "Elevator Group",
+        ObjectType::Escalator => "Escalator",
+        ObjectType::Lift => "Lift",
+        ObjectType::Staging => "Staging",
+        ObjectType::AuditLog => "Audit Log",
+        ObjectType::AuditReporter => "Audit Reporter",
+        ObjectType::Color => "Color",
+        ObjectType::ColorTemperature => "Color Temperature",
+        ObjectType::Reserved(_) => "Reserved",
+        ObjectType::Proprietary(_) => "Proprietary",
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::network::NetworkAddress;
 
     #[test]
     fn test_object_type_names() {
@@ -592,4 +1220,553 @@ mod tests {
         assert_eq!(obj_type, 8);
         assert_eq!(instance, 5047);
     }
+
+    #[test]
+    fn test_client_config_defaults() {
+        let config = ClientConfig::default();
+        assert_eq!(config.apdu_timeout, Duration::from_secs(3));
+        assert_eq!(config.retries, 3);
+        assert_eq!(config.total_timeout(), Duration::from_secs(12));
+        assert_eq!(
+            config.to_string(),
+            "APDU timeout 3000 ms, 3 retries, max APDU 1476 bytes"
+        );
+    }
+
+    #[test]
+    fn test_discover_devices_dedups_and_filters() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let client = BacnetClient::new()
+            .unwrap()
+            .with_broadcast_address(device.local_addr().unwrap());
+
+        let i_am = |instance| {
+            let mut data = Vec::new();
+            IAmRequest::new(
+                ObjectIdentifier::new(ObjectType::Device, instance),
+                1476,
+                crate::object::Segmentation::NoSegmentation,
+                260,
+            )
+            .encode(&mut data)
+            .unwrap();
+            client.create_unconfirmed_message(
+                UnconfirmedServiceChoice::IAm as u8,
+                &data,
+                BvlcFunction::OriginalUnicastNpdu,
+            )
+        };
+        let replies = vec![i_am(10), i_am(10), i_am(500), i_am(20)];
+
+        let responder = std::thread::spawn(move || {
+            let mut buffer = [0u8; 1500];
+            let (_, client_addr) = device.recv_from(&mut buffer).unwrap();
+            // Who-Is goes out as an Original-Broadcast-NPDU
+            assert_eq!(&buffer[..2], &[0x81, 0x0B]);
+            for reply in replies {
+                device.send_to(&reply, client_addr).unwrap();
+            }
+        });
+
+        let devices = client
+            .discover_devices(&WhoIsRequest::for_range(1, 100), Duration::from_millis(300))
+            .unwrap();
+        responder.join().unwrap();
+
+        let ids: Vec<u32> = devices.iter().map(|device| device.device_id).collect();
+        assert_eq!(ids, vec![10, 20]);
+        assert_eq!(devices[0].vendor_id, 260);
+        assert_eq!(devices[0].max_apdu, 1476);
+        assert_eq!(devices[0].max_apdu_size(), MaxApdu::Up1476);
+        assert_eq!(client.max_request_service_data(&devices[0]), 1472);
+    }
+
+    #[test]
+    fn test_requests_are_limited_to_the_peer_max_apdu() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        device
+            .set_read_timeout(Some(Duration::from_millis(100)))
+            .unwrap();
+        let device_addr = device.local_addr().unwrap();
+        let client = BacnetClient::new().unwrap();
+
+        // 5 objects with 4 properties each take 75 bytes of service data
+        let specs = (0..5)
+            .map(|instance| {
+                ReadAccessSpecification::new(
+                    ObjectIdentifier::new(ObjectType::AnalogInput, instance),
+                    [77, 85, 111, 117].map(PropertyReference::new).to_vec(),
+                )
+            })
+            .collect();
+        let request = ReadPropertyMultipleRequest::new(specs);
+        let too_long = |result: Result<ReadPropertyMultipleAck, Box<dyn std::error::Error>>| {
+            matches!(
+                result.unwrap_err().downcast_ref::<ApplicationError>(),
+                Some(ApplicationError::MaxApduLengthExceeded)
+            )
+        };
+
+        // Learned from the device's I-Am
+        let mut data = Vec::new();
+        IAmRequest::new(
+            ObjectIdentifier::new(ObjectType::Device, 7),
+            50,
+            crate::object::Segmentation::NoSegmentation,
+            260,
+        )
+        .encode(&mut data)
+        .unwrap();
+        let i_am = client.create_unconfirmed_message(
+            UnconfirmedServiceChoice::IAm as u8,
+            &data,
+            BvlcFunction::OriginalUnicastNpdu,
+        );
+        assert!(client.parse_iam_response(&i_am, device_addr).is_some());
+        assert!(too_long(
+            client.read_property_multiple(device_addr, &request)
+        ));
+
+        // Set explicitly
+        let other = SocketAddr::from(([127, 0, 0, 1], device_addr.port() + 1));
+        client.set_peer_max_apdu(other, MaxApdu::Up50);
+        assert!(too_long(client.read_property_multiple(other, &request)));
+
+        // Nothing was sent
+        let mut buffer = [0u8; 1500];
+        assert!(device.recv_from(&mut buffer).is_err());
+    }
+
+    #[test]
+    fn test_discovery_through_bbmd_and_directed_who_is() {
+        let bbmd = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let device_addr = device.local_addr().unwrap();
+        let client = BacnetClient::new()
+            .unwrap()
+            .with_bbmd_address(bbmd.local_addr().unwrap());
+
+        let mut data = Vec::new();
+        IAmRequest::new(
+            ObjectIdentifier::new(ObjectType::Device, 42),
+            1476,
+            crate::object::Segmentation::NoSegmentation,
+            260,
+        )
+        .encode(&mut data)
+        .unwrap();
+        let i_am = client.create_unconfirmed_message(
+            UnconfirmedServiceChoice::IAm as u8,
+            &data,
+            BvlcFunction::OriginalUnicastNpdu,
+        );
+
+        let responder = std::thread::spawn(move || {
+            let mut buffer = [0u8; 1500];
+            // The broadcast Who-Is is handed to the BBMD to distribute
+            let (_, client_addr) = bbmd.recv_from(&mut buffer).unwrap();
+            assert_eq!(&buffer[..2], &[0x81, 0x09]);
+            device.send_to(&i_am, client_addr).unwrap();
+
+            // The directed Who-Is goes straight to the device
+            let (_, client_addr) = device.recv_from(&mut buffer).unwrap();
+            assert_eq!(&buffer[..2], &[0x81, 0x0A]);
+            device.send_to(&i_am, client_addr).unwrap();
+        });
+
+        let devices = client
+            .discover_devices(&WhoIsRequest::new(), Duration::from_millis(200))
+            .unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].device_id, 42);
+
+        let devices = client
+            .discover_devices_at(
+                device_addr,
+                &WhoIsRequest::new(),
+                Duration::from_millis(200),
+            )
+            .unwrap();
+        responder.join().unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].address, device_addr);
+    }
+
+    #[test]
+    fn test_discovery_of_devices_forwarded_by_bbmd() {
+        let bbmd = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let client = BacnetClient::new()
+            .unwrap()
+            .with_bbmd_address(bbmd.local_addr().unwrap());
+
+        // I-Am broadcast on another subnet, relayed by the BBMD
+        let mut data = Vec::new();
+        IAmRequest::new(
+            ObjectIdentifier::new(ObjectType::Device, 77),
+            480,
+            crate::object::Segmentation::NoSegmentation,
+            260,
+        )
+        .encode(&mut data)
+        .unwrap();
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::IAm,
+            service_data: data,
+        };
+        let mut npdu = Npdu::new().encode();
+        npdu.extend_from_slice(&apdu.encode());
+        let forwarded = Bvlc::ForwardedNpdu {
+            source_ip: [10, 1, 2, 3],
+            source_port: 0xBAC0,
+            npdu,
+        }
+        .encode()
+        .unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buffer = [0u8; 1500];
+            let (_, client_addr) = bbmd.recv_from(&mut buffer).unwrap();
+            assert_eq!(&buffer[..2], &[0x81, 0x09]);
+            bbmd.send_to(&forwarded, client_addr).unwrap();
+        });
+
+        let devices = client
+            .discover_devices(&WhoIsRequest::new(), Duration::from_millis(200))
+            .unwrap();
+        responder.join().unwrap();
+
+        let remote: SocketAddr = "10.1.2.3:47808".parse().unwrap();
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].device_id, 77);
+        assert_eq!(devices[0].max_apdu, 480);
+        assert_eq!(devices[0].address, remote);
+        assert_eq!(devices[0].bacnet_address, BacnetAddress::from(remote));
+    }
+
+    #[test]
+    fn test_time_sync_broadcast() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let client = BacnetClient::new()
+            .unwrap()
+            .with_broadcast_address(device.local_addr().unwrap());
+
+        let now = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2025,
+                month: 10,
+                day: 13,
+                weekday: 1,
+            },
+            crate::object::Time {
+                hour: 8,
+                minute: 30,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+        client.time_sync_broadcast(now).unwrap();
+
+        let mut buffer = [0u8; 1500];
+        let (len, _) = device.recv_from(&mut buffer).unwrap();
+        assert_eq!(&buffer[..2], &[0x81, 0x0B]);
+        // BVLC header and a two byte NPDU precede the APDU
+        let apdu = &buffer[6..len];
+        assert_eq!(
+            &apdu[..2],
+            &[0x10, UnconfirmedServiceChoice::TimeSynchronization as u8]
+        );
+        let request = TimeSynchronizationRequest::decode(&apdu[2..]).unwrap();
+        assert_eq!(request.date_time, now);
+    }
+
+    #[test]
+    fn test_frame_trace() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let mut client = BacnetClient::new()
+            .unwrap()
+            .with_broadcast_address(device.local_addr().unwrap());
+        let frames = Arc::new(Mutex::new(Vec::new()));
+        let log = frames.clone();
+        client.set_trace(Box::new(move |direction, frame| {
+            log.lock().unwrap().push((direction, frame.to_vec()));
+        }));
+
+        let now = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2025,
+                month: 10,
+                day: 13,
+                weekday: 1,
+            },
+            crate::object::Time {
+                hour: 8,
+                minute: 30,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+        client.time_sync_broadcast(now).unwrap();
+        let mut buffer = [0u8; 1500];
+        let (len, _) = device.recv_from(&mut buffer).unwrap();
+
+        let frames = frames.lock().unwrap();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].0, Direction::Sent);
+        assert_eq!(frames[0].1, &buffer[..len]);
+    }
+
+    #[test]
+    fn test_confirmed_cov_notification_is_acknowledged() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let client = BacnetClient::new().unwrap();
+        let client_addr = SocketAddr::from((
+            Ipv4Addr::LOCALHOST,
+            client.socket.local_addr().unwrap().port(),
+        ));
+
+        let mut notification = Vec::new();
+        CovNotificationRequest::new(
+            7,
+            ObjectIdentifier::new(ObjectType::Device, 1),
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+            60,
+            vec![crate::service::BacnetPropertyValue::new(
+                85,
+                crate::object::PropertyValue::Real(25.5),
+            )],
+        )
+        .encode(&mut notification)
+        .unwrap();
+
+        // Unconfirmed notifications are consumed without a reply
+        let unconfirmed = client.create_unconfirmed_message(
+            UnconfirmedServiceChoice::UnconfirmedCOVNotification as u8,
+            &notification,
+            BvlcFunction::OriginalUnicastNpdu,
+        );
+        device.send_to(&unconfirmed, client_addr).unwrap();
+        let (source, received) = client
+            .receive_cov_notification(Duration::from_millis(500))
+            .unwrap()
+            .unwrap();
+        assert_eq!(source, BacnetAddress::from(device.local_addr().unwrap()));
+        assert_eq!(received.subscriber_process_identifier, 7);
+
+        let apdu = Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: false,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApdu::Up1476,
+            invoke_id: 42,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ConfirmedCOVNotification,
+            service_data: notification,
+        };
+        let mut frame = vec![0x81, 0x0A, 0x00, 0x00, 0x01, 0x04];
+        frame.extend_from_slice(&apdu.encode());
+        frame[3] = frame.len() as u8;
+        device.send_to(&frame, client_addr).unwrap();
+
+        let (_, received) = client
+            .receive_cov_notification(Duration::from_millis(500))
+            .unwrap()
+            .unwrap();
+        let values: Vec<_> = received.values().collect();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].0, crate::object::PropertyIdentifier::PresentValue);
+
+        // The Simple-ACK echoes the invoke ID and service choice
+        device
+            .set_read_timeout(Some(Duration::from_millis(500)))
+            .unwrap();
+        let mut buffer = [0u8; 1500];
+        let (len, _) = device.recv_from(&mut buffer).unwrap();
+        assert_eq!(&buffer[6..len], &[0x20, 42, 0x01]);
+
+        assert!(client
+            .receive_cov_notification(Duration::from_millis(50))
+            .unwrap()
+            .is_none());
+
+        // A notification routed from MS/TP station 3 on network 5 is
+        // acknowledged through the router, addressed back to the station
+        let mut frame = vec![0x81, 0x0A, 0x00, 0x00, 0x01, 0x0C, 0x00, 0x05, 0x01, 0x03];
+        frame.extend_from_slice(&apdu.encode());
+        frame[3] = frame.len() as u8;
+        device.send_to(&frame, client_addr).unwrap();
+        let (source, _) = client
+            .receive_cov_notification(Duration::from_millis(500))
+            .unwrap()
+            .unwrap();
+        assert_eq!(source, BacnetAddress::remote(5, MacAddress::MsTp(3)));
+        let (len, _) = device.recv_from(&mut buffer).unwrap();
+        assert_eq!(
+            &buffer[4..len],
+            &[0x01, 0x20, 0x00, 0x05, 0x01, 0x03, 0xFF, 0x20, 42, 0x01]
+        );
+    }
+
+    /// ReadPropertyMultiple-ACK service data for two Analog Inputs,
+    /// as captured from a device
+    const RPM_ACK: [u8; 93] = [
+        0x0C, 0x00, 0x00, 0x00, 0x01, 0x1E, 0x29, 0x4D, 0x4E, 0x75, 0x11, 0x00, 0x5A, 0x6F, 0x6E,
+        0x65, 0x20, 0x54, 0x65, 0x6D, 0x70, 0x65, 0x72, 0x61, 0x74, 0x75, 0x72, 0x65, 0x4F, 0x29,
+        0x55, 0x4E, 0x44, 0x41, 0xAC, 0x00, 0x00, 0x4F, 0x29, 0x6F, 0x4E, 0x82, 0x04, 0x40, 0x4F,
+        0x29, 0x75, 0x4E, 0x91, 0x3E, 0x4F, 0x1F, 0x0C, 0x00, 0x00, 0x00, 0x02, 0x1E, 0x29, 0x4D,
+        0x4E, 0x75, 0x0B, 0x00, 0x53, 0x75, 0x70, 0x70, 0x6C, 0x79, 0x20, 0x41, 0x69, 0x72, 0x4F,
+        0x29, 0x1C, 0x5E, 0x91, 0x02, 0x91, 0x20, 0x5F, 0x29, 0x55, 0x4E, 0x44, 0x41, 0x50, 0x00,
+        0x00, 0x4F, 0x1F,
+    ];
+
+    #[test]
+    fn test_segmented_read_property_multiple_ack_is_reassembled() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let device_addr = device.local_addr().unwrap();
+        let client = BacnetClient::with_config(ClientConfig {
+            apdu_timeout: Duration::from_millis(500),
+            retries: 0,
+            ..ClientConfig::default()
+        })
+        .unwrap();
+
+        let responder = std::thread::spawn(move || {
+            let mut buffer = [0u8; 1500];
+            let (len, client_addr) = device.recv_from(&mut buffer).unwrap();
+            let Ok(Apdu::ConfirmedRequest {
+                invoke_id,
+                service_choice: ConfirmedServiceChoice::ReadPropertyMultiple,
+                segmented_response_accepted: true,
+                ..
+            }) = Apdu::decode(&buffer[6..len])
+            else {
+                panic!("Expected a ReadPropertyMultiple request");
+            };
+
+            // Segment boundaries fall inside a character string and an
+            // object's list of results
+            let segments = [&RPM_ACK[..20], &RPM_ACK[20..60], &RPM_ACK[60..]];
+            for (sequence_number, segment) in segments.iter().enumerate() {
+                let apdu = Apdu::ComplexAck {
+                    segmented: true,
+                    more_follows: sequence_number < 2,
+                    invoke_id,
+                    sequence_number: Some(sequence_number as u8),
+                    proposed_window_size: Some(3),
+                    service_choice: ConfirmedServiceChoice::ReadPropertyMultiple as u8,
+                    service_data: segment.to_vec(),
+                };
+                let mut frame = vec![0x81, 0x0A, 0x00, 0x00, 0x01, 0x00];
+                frame.extend_from_slice(&apdu.encode());
+                frame[3] = frame.len() as u8;
+                device.send_to(&frame, client_addr).unwrap();
+            }
+
+            // The client acknowledges the last segment once reassembled
+            loop {
+                let (len, _) = device.recv_from(&mut buffer).unwrap();
+                if let Ok(Apdu::SegmentAck {
+                    sequence_number: 2,
+                    negative: false,
+                    ..
+                }) = Apdu::decode(&buffer[6..len])
+                {
+                    break;
+                }
+            }
+        });
+
+        let ai_1 = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let ai_2 = ObjectIdentifier::new(ObjectType::AnalogInput, 2);
+        let request = ReadPropertyMultipleRequest::new(vec![
+            ReadAccessSpecification::new(
+                ai_1,
+                vec![
+                    PropertyReference::new(77),
+                    PropertyReference::new(85),
+                    PropertyReference::new(111),
+                    PropertyReference::new(117),
+                ],
+            ),
+            ReadAccessSpecification::new(
+                ai_2,
+                vec![
+                    PropertyReference::new(77),
+                    PropertyReference::new(28),
+                    PropertyReference::new(85),
+                ],
+            ),
+        ]);
+        let ack = client
+            .read_property_multiple(device_addr, &request)
+            .unwrap();
+        responder.join().unwrap();
+
+        assert_eq!(ack.read_access_results.len(), 2);
+        assert!(matches!(
+            ack.read_access_results[1].results[1].value,
+            Err(crate::service::PropertyAccessError { error_code: 32, .. })
+        ));
+
+        let info = client.parse_rpm_response(&ack, &[ai_1, ai_2]);
+        assert_eq!(info[0].object_name.as_deref(), Some("Zone Temperature"));
+        assert!(matches!(info[0].present_value, Some(PropertyValue::Real(v)) if v == 21.5));
+        assert_eq!(info[0].status_flags, Some(vec![false, true, false, false]));
+        assert_eq!(info[0].units.as_deref(), Some("°C"));
+        assert_eq!(info[1].object_name.as_deref(), Some("Supply Air"));
+        assert_eq!(info[1].description, None);
+        assert!(matches!(info[1].present_value, Some(PropertyValue::Real(v)) if v == 13.0));
+    }
+
+    #[test]
+    fn test_requests_to_remote_devices_go_through_the_router() {
+        let router = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let client = BacnetClient::with_config(ClientConfig {
+            apdu_timeout: Duration::from_millis(500),
+            retries: 0,
+            ..ClientConfig::default()
+        })
+        .unwrap();
+        let client_addr = SocketAddr::from((
+            Ipv4Addr::LOCALHOST,
+            client.socket.local_addr().unwrap().port(),
+        ));
+        client.add_router(5, router.local_addr().unwrap());
+
+        let responder = std::thread::spawn(move || {
+            let mut buffer = [0u8; 1500];
+            let (len, _) = router.recv_from(&mut buffer).unwrap();
+            let (npdu, npdu_len) = Npdu::decode(&buffer[4..len]).unwrap();
+            assert_eq!(npdu.destination, Some(NetworkAddress::new(5, vec![9])));
+            let Ok(Apdu::ConfirmedRequest { invoke_id, .. }) =
+                Apdu::decode(&buffer[4 + npdu_len..len])
+            else {
+                panic!("Expected a confirmed request");
+            };
+
+            // Answer on behalf of the device behind the router
+            let mut reply = Npdu::new();
+            reply.source = Some(NetworkAddress::new(5, vec![9]));
+            let mut frame = vec![0x81, 0x0A, 0x00, 0x00];
+            frame.extend_from_slice(&reply.encode());
+            frame.extend_from_slice(
+                &Apdu::Error {
+                    invoke_id,
+                    service_choice: ConfirmedServiceChoice::ReadPropertyMultiple as u8,
+                    error_class: 1,
+                    error_code: 31,
+                    error_data: Vec::new(),
+                }
+                .encode(),
+            );
+            frame[3] = frame.len() as u8;
+            router.send_to(&frame, client_addr).unwrap();
+        });
+
+        let device = BacnetAddress::remote(5, MacAddress::MsTp(9));
+        let error = client.read_object_list(device, 1).unwrap_err();
+        responder.join().unwrap();
+        assert!(error.downcast_ref::<BacnetError>().is_some());
+    }
 }
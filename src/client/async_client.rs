@@ -0,0 +1,865 @@
+//! Async BACnet/IP Client
+//!
+//! [`AsyncBacnetClient`] drives confirmed and unconfirmed services over a single
+//! `tokio::net::UdpSocket`. A background task owns the receive side of the
+//! socket and routes every acknowledgement to the request that is waiting on its
+//! invoke ID, so any number of requests can be in flight concurrently.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use bacnet_rs::client::AsyncBacnetClient;
+//! use bacnet_rs::object::{ObjectIdentifier, ObjectType, PropertyIdentifier};
+//!
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = AsyncBacnetClient::bind("0.0.0.0:0").await?;
+//! let device = "192.168.1.100:47808".parse()?;
+//! let value = client
+//!     .read_property(
+//!         device,
+//!         ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+//!         PropertyIdentifier::PresentValue,
+//!     )
+//!     .await?;
+//! println!("{:?}", value);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt,
+    net::{Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio::{
+    net::{ToSocketAddrs, UdpSocket},
+    sync::{broadcast, oneshot},
+    task::JoinHandle,
+};
+
+use super::ClientConfig;
+use crate::{
+    app::{
+        Apdu, ApplicationError, InvokeIdAllocator, MaxApdu, MaxSegments, SegmentProgress,
+        SegmentedAckReceiver,
+    },
+    datalink::bip::Bvlc,
+    encoding::EncodingError,
+    network::Npdu,
+    object::{ObjectIdentifier, PropertyIdentifier, PropertyValue},
+    service::{
+        BacnetError, ConfirmedServiceChoice, IAmRequest, ReadPropertyAck, ReadPropertyRequest,
+        UnconfirmedServiceChoice, WhoIsRequest, WritePropertyRequest,
+    },
+};
+
+/// Errors returned by [`AsyncBacnetClient`]
+#[derive(Debug)]
+pub enum AsyncClientError {
+    /// Socket error
+    Io(std::io::Error),
+    /// Request or response could not be encoded or decoded
+    Encoding(EncodingError),
+    /// Application layer error, e.g. during segment reassembly
+    Application(ApplicationError),
+    /// No acknowledgement arrived after the configured retries
+    Timeout,
+    /// All 256 invoke IDs are in use by outstanding requests
+    NoInvokeId,
+    /// The device answered with an Error, Reject or Abort PDU
+    Bacnet(BacnetError),
+    /// The device answered with an acknowledgement of the wrong kind
+    UnexpectedResponse,
+    /// The receive task has stopped
+    Closed,
+}
+
+impl fmt::Display for AsyncClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsyncClientError::Io(e) => write!(f, "Socket error: {}", e),
+            AsyncClientError::Encoding(e) => write!(f, "Encoding error: {}", e),
+            AsyncClientError::Application(e) => write!(f, "Application error: {}", e),
+            AsyncClientError::Timeout => write!(f, "Request timed out"),
+            AsyncClientError::NoInvokeId => write!(f, "No free invoke ID"),
+            AsyncClientError::Bacnet(e) => write!(f, "Device refused request: {}", e),
+            AsyncClientError::UnexpectedResponse => write!(f, "Unexpected response"),
+            AsyncClientError::Closed => write!(f, "Client receive task stopped"),
+        }
+    }
+}
+
+impl Error for AsyncClientError {}
+
+impl From<std::io::Error> for AsyncClientError {
+    fn from(e: std::io::Error) -> Self {
+        AsyncClientError::Io(e)
+    }
+}
+
+impl From<EncodingError> for AsyncClientError {
+    fn from(e: EncodingError) -> Self {
+        AsyncClientError::Encoding(e)
+    }
+}
+
+impl From<BacnetError> for AsyncClientError {
+    fn from(e: BacnetError) -> Self {
+        AsyncClientError::Bacnet(e)
+    }
+}
+
+impl From<ApplicationError> for AsyncClientError {
+    fn from(e: ApplicationError) -> Self {
+        AsyncClientError::Application(e)
+    }
+}
+
+/// Result type for async client operations
+pub type Result<T> = std::result::Result<T, AsyncClientError>;
+
+/// Outstanding confirmed request waiting for its acknowledgement
+struct Transaction {
+    /// Device the request was sent to
+    peer: SocketAddr,
+    /// Reassembly state for a segmented Complex-ACK
+    segments: SegmentedAckReceiver,
+    /// Completes the waiting request
+    reply: oneshot::Sender<Result<Apdu>>,
+}
+
+type Transactions = Arc<Mutex<InvokeIdAllocator<Transaction>>>;
+
+/// Max_APDU_Length_Accepted of each device, learned from I-Am
+type Peers = Arc<Mutex<HashMap<SocketAddr, MaxApdu>>>;
+
+/// Async BACnet/IP client multiplexing requests over one UDP socket
+pub struct AsyncBacnetClient {
+    socket: Arc<UdpSocket>,
+    transactions: Transactions,
+    peers: Peers,
+    i_am: broadcast::Sender<(IAmRequest, SocketAddr)>,
+    config: ClientConfig,
+    broadcast_addr: SocketAddr,
+    receive_task: JoinHandle<()>,
+}
+
+impl AsyncBacnetClient {
+    /// Bind a client to a local address
+    pub async fn bind(addr: impl ToSocketAddrs) -> Result<Self> {
+        let socket = UdpSocket::bind(addr).await?;
+        Ok(Self::from_socket(socket))
+    }
+
+    /// Create a client from an already bound socket
+    ///
+    /// Must be called from within a tokio runtime, as it spawns the receive task.
+    pub fn from_socket(socket: UdpSocket) -> Self {
+        let _ = socket.set_broadcast(true);
+        let socket = Arc::new(socket);
+        let config = ClientConfig::default();
+        let transactions: Transactions =
+            Arc::new(Mutex::new(InvokeIdAllocator::new(config.total_timeout())));
+        let peers: Peers = Arc::new(Mutex::new(HashMap::new()));
+        let (i_am, _) = broadcast::channel(64);
+
+        let receive_task = tokio::spawn(receive_loop(
+            socket.clone(),
+            transactions.clone(),
+            peers.clone(),
+            i_am.clone(),
+        ));
+
+        Self {
+            socket,
+            transactions,
+            peers,
+            i_am,
+            config,
+            broadcast_addr: SocketAddr::from((Ipv4Addr::BROADCAST, 0xBAC0)),
+            receive_task,
+        }
+    }
+
+    /// Set the timeout and retry policy for confirmed requests
+    pub fn with_config(mut self, config: ClientConfig) -> Self {
+        self.config = config;
+        self.transactions
+            .lock()
+            .unwrap()
+            .set_timeout(config.total_timeout());
+        self
+    }
+
+    /// Set the time to wait for each confirmed service acknowledgement
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        let config = ClientConfig {
+            apdu_timeout: timeout,
+            ..self.config
+        };
+        self.with_config(config)
+    }
+
+    /// Get the effective timeout and retry policy
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// Set the address Who-Is requests are broadcast to
+    pub fn with_broadcast_address(mut self, addr: SocketAddr) -> Self {
+        self.broadcast_addr = addr;
+        self
+    }
+
+    /// Limit confirmed requests to `device` to APDUs of `max_apdu`
+    ///
+    /// The limit is also learned from each I-Am the client receives. Until
+    /// one is known, requests are limited to the client's own
+    /// [`max_apdu_length`](ClientConfig::max_apdu_length).
+    pub fn set_peer_max_apdu(&self, device: SocketAddr, max_apdu: MaxApdu) {
+        self.peers.lock().unwrap().insert(device, max_apdu);
+    }
+
+    /// Get the local address of the client socket
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        Ok(self.socket.local_addr()?)
+    }
+
+    /// Read a property value from an object on a device
+    pub async fn read_property(
+        &self,
+        device: SocketAddr,
+        object_identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+    ) -> Result<PropertyValue> {
+        let request = ReadPropertyRequest::new(object_identifier, property.as_u32());
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data)?;
+
+        match self
+            .confirmed_request(device, ConfirmedServiceChoice::ReadProperty, service_data)
+            .await?
+        {
+            Apdu::ComplexAck { service_data, .. } => {
+                Ok(ReadPropertyAck::decode(&service_data)?.value)
+            }
+            _ => Err(AsyncClientError::UnexpectedResponse),
+        }
+    }
+
+    /// Write a property value to an object on a device
+    ///
+    /// `priority` (1-16) only applies to commandable properties.
+    pub async fn write_property(
+        &self,
+        device: SocketAddr,
+        object_identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+        value: &PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        let mut request =
+            WritePropertyRequest::with_value(object_identifier, property.as_u32(), value)?;
+        request.priority = priority;
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data)?;
+
+        match self
+            .confirmed_request(device, ConfirmedServiceChoice::WriteProperty, service_data)
+            .await?
+        {
+            Apdu::SimpleAck { .. } => Ok(()),
+            _ => Err(AsyncClientError::UnexpectedResponse),
+        }
+    }
+
+    /// Broadcast a Who-Is and collect the I-Am responses received within `wait`
+    pub async fn who_is(
+        &self,
+        request: &WhoIsRequest,
+        wait: Duration,
+    ) -> Result<Vec<(IAmRequest, SocketAddr)>> {
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data)?;
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoIs,
+            service_data,
+        };
+
+        // Subscribe before sending so no early response is missed
+        let mut responses = self.i_am.subscribe();
+        let bvlc = Bvlc::OriginalBroadcastNpdu(encode_npdu(&apdu, false));
+        send_bvlc(&self.socket, &bvlc, self.broadcast_addr).await?;
+
+        let mut devices: Vec<(IAmRequest, SocketAddr)> = Vec::new();
+        let deadline = tokio::time::Instant::now() + wait;
+        loop {
+            match tokio::time::timeout_at(deadline, responses.recv()).await {
+                Ok(Ok((i_am, source))) => {
+                    let matches = request.matches(i_am.device_identifier.instance);
+                    let known = devices
+                        .iter()
+                        .any(|(known, _)| known.device_identifier == i_am.device_identifier);
+                    if matches && !known {
+                        devices.push((i_am, source));
+                    }
+                }
+                Ok(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+                Ok(Err(broadcast::error::RecvError::Closed)) => {
+                    return Err(AsyncClientError::Closed)
+                }
+                Err(_) => break,
+            }
+        }
+
+        Ok(devices)
+    }
+
+    /// Send a confirmed request and wait for the acknowledgement matching its
+    /// invoke ID
+    ///
+    /// Requests too large for the device's Max_APDU_Length_Accepted fail with
+    /// [`ApplicationError::MaxApduLengthExceeded`] without being sent.
+    async fn confirmed_request(
+        &self,
+        device: SocketAddr,
+        service_choice: ConfirmedServiceChoice,
+        service_data: Vec<u8>,
+    ) -> Result<Apdu> {
+        let peer_max_apdu = self
+            .peers
+            .lock()
+            .unwrap()
+            .get(&device)
+            .copied()
+            .unwrap_or(self.config.max_apdu_length);
+        if service_data.len() > peer_max_apdu.max_request_service_data() {
+            return Err(ApplicationError::MaxApduLengthExceeded.into());
+        }
+
+        let (reply, mut response) = oneshot::channel();
+        let invoke_id = self.register(device, service_choice, reply)?;
+
+        let apdu = Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: true,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: self.config.max_apdu_length,
+            invoke_id,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice,
+            service_data,
+        };
+
+        let request = Bvlc::OriginalUnicastNpdu(encode_npdu(&apdu, true));
+        let mut retries_left = self.config.retries;
+        let mut bvlc = request.clone();
+
+        loop {
+            if let Err(e) = send_bvlc(&self.socket, &bvlc, device).await {
+                self.unregister(invoke_id);
+                return Err(e);
+            }
+
+            match tokio::time::timeout(self.config.apdu_timeout, &mut response).await {
+                Ok(Ok(result)) => return result,
+                Ok(Err(_)) => return Err(AsyncClientError::Closed),
+                Err(_) => {}
+            }
+
+            // Resend the last SegmentACK if a segmented response stalled,
+            // otherwise retransmit the request itself
+            let segment_ack = {
+                let mut transactions = self.transactions.lock().unwrap();
+                match transactions.get_mut(invoke_id) {
+                    Some(transaction) if transaction.context.segments.segments_received() > 0 => {
+                        transaction.context.segments.on_timeout().ok()
+                    }
+                    _ => None,
+                }
+            };
+
+            bvlc = match segment_ack {
+                Some(segment_ack) => Bvlc::OriginalUnicastNpdu(encode_npdu(&segment_ack, false)),
+                None if retries_left > 0 => {
+                    retries_left -= 1;
+                    request.clone()
+                }
+                None => {
+                    self.unregister(invoke_id);
+                    return Err(AsyncClientError::Timeout);
+                }
+            };
+            self.transactions.lock().unwrap().restart_timer(invoke_id);
+        }
+    }
+
+    /// Allocate a free invoke ID and register the transaction under it
+    ///
+    /// IDs still held by requests whose futures were dropped before they
+    /// completed are freed first.
+    fn register(
+        &self,
+        peer: SocketAddr,
+        service_choice: ConfirmedServiceChoice,
+        reply: oneshot::Sender<Result<Apdu>>,
+    ) -> Result<u8> {
+        let mut transactions = self.transactions.lock().unwrap();
+        transactions.expire();
+        transactions
+            .allocate_with(service_choice, |invoke_id| Transaction {
+                peer,
+                segments: SegmentedAckReceiver::new(
+                    invoke_id,
+                    self.config.max_apdu_length,
+                    MaxSegments::Unspecified,
+                    self.config.retries,
+                ),
+                reply,
+            })
+            .map_err(|_| AsyncClientError::NoInvokeId)
+    }
+
+    fn unregister(&self, invoke_id: u8) {
+        self.transactions.lock().unwrap().complete(invoke_id);
+    }
+}
+
+impl Drop for AsyncBacnetClient {
+    fn drop(&mut self) {
+        self.receive_task.abort();
+    }
+}
+
+/// Wrap an APDU in an NPDU
+fn encode_npdu(apdu: &Apdu, expecting_reply: bool) -> Vec<u8> {
+    let mut npdu = Npdu::new();
+    npdu.control.expecting_reply = expecting_reply;
+    let mut message = npdu.encode();
+    message.extend_from_slice(&apdu.encode());
+    message
+}
+
+async fn send_bvlc(socket: &UdpSocket, bvlc: &Bvlc, addr: SocketAddr) -> Result<()> {
+    let frame = bvlc
+        .encode()
+        .map_err(|_| AsyncClientError::Encoding(EncodingError::BufferOverflow))?;
+    socket.send_to(&frame, addr).await?;
+    Ok(())
+}
+
+/// Wait after the first failed receive, doubled on each further failure
+const RECEIVE_ERROR_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Longest wait between failed receives
+const MAX_RECEIVE_ERROR_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Receive frames and dispatch them to waiting transactions and Who-Is callers
+async fn receive_loop(
+    socket: Arc<UdpSocket>,
+    transactions: Transactions,
+    peers: Peers,
+    i_am: broadcast::Sender<(IAmRequest, SocketAddr)>,
+) {
+    let mut buffer = [0u8; 1500];
+    let mut backoff = RECEIVE_ERROR_BACKOFF;
+
+    loop {
+        let (len, mut source) = match socket.recv_from(&mut buffer).await {
+            Ok(received) => {
+                backoff = RECEIVE_ERROR_BACKOFF;
+                received
+            }
+            Err(error) => {
+                // Errors such as an ICMP port unreachable clear up on their
+                // own; waiting keeps a persistent one from spinning the task
+                log::warn!(
+                    "BACnet client receive failed, retrying in {:?}: {}",
+                    backoff,
+                    error
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_RECEIVE_ERROR_BACKOFF);
+                continue;
+            }
+        };
+
+        let Ok(bvlc) = Bvlc::decode(&buffer[..len]) else {
+            continue;
+        };
+        if let Bvlc::ForwardedNpdu {
+            source_ip,
+            source_port,
+            ..
+        } = &bvlc
+        {
+            source = SocketAddr::from((*source_ip, *source_port));
+        }
+        let Some(npdu_data) = bvlc.npdu() else {
+            continue;
+        };
+        let Ok((npdu, npdu_len)) = Npdu::decode(npdu_data) else {
+            continue;
+        };
+        if npdu.is_network_message() {
+            continue;
+        }
+        let apdu_data = &npdu_data[npdu_len..];
+        let Ok(apdu) = Apdu::decode(apdu_data) else {
+            continue;
+        };
+
+        if let Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::IAm,
+            service_data,
+        } = &apdu
+        {
+            if let Ok(request) = IAmRequest::decode(service_data) {
+                let max_apdu = MaxApdu::from_length(request.max_apdu_length_accepted);
+                peers.lock().unwrap().insert(source, max_apdu);
+                let _ = i_am.send((request, source));
+            }
+            continue;
+        }
+
+        let mut segment_ack = None;
+        {
+            let mut transactions = transactions.lock().unwrap();
+            let Some(invoke_id) = transactions.match_response(&apdu) else {
+                continue;
+            };
+            let Some(transaction) = transactions.get_mut(invoke_id) else {
+                continue;
+            };
+            let transaction = &mut transaction.context;
+            if transaction.peer != source {
+                continue;
+            }
+
+            let result = match apdu {
+                Apdu::ComplexAck {
+                    segmented: true, ..
+                } => match transaction.segments.process(&apdu, apdu_data.len()) {
+                    Ok(SegmentProgress::InProgress(ack)) => {
+                        segment_ack = ack;
+                        None
+                    }
+                    Ok(SegmentProgress::Complete {
+                        segment_ack: ack,
+                        service_choice,
+                        service_data,
+                    }) => {
+                        segment_ack = Some(ack);
+                        Some(Ok(Apdu::ComplexAck {
+                            segmented: false,
+                            more_follows: false,
+                            invoke_id,
+                            sequence_number: None,
+                            proposed_window_size: None,
+                            service_choice,
+                            service_data,
+                        }))
+                    }
+                    Err(e) => Some(Err(e.into())),
+                },
+                Apdu::Error {
+                    error_class,
+                    error_code,
+                    ..
+                } => Some(Err(BacnetError::from_error(error_class, error_code).into())),
+                Apdu::Reject { reject_reason, .. } => {
+                    Some(Err(BacnetError::Reject(reject_reason.into()).into()))
+                }
+                Apdu::Abort { abort_reason, .. } => {
+                    Some(Err(BacnetError::Abort(abort_reason.into()).into()))
+                }
+                ack => Some(Ok(ack)),
+            };
+
+            match result {
+                Some(result) => {
+                    if let Some(transaction) = transactions.complete(invoke_id) {
+                        let _ = transaction.reply.send(result);
+                    }
+                }
+                // A segment arrived, so the request is still alive
+                None => transactions.restart_timer(invoke_id),
+            }
+        }
+
+        if let Some(ack) = segment_ack {
+            let bvlc = Bvlc::OriginalUnicastNpdu(encode_npdu(&ack, false));
+            let _ = send_bvlc(&socket, &bvlc, source).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::ObjectType;
+    use crate::service::{ErrorClass, ErrorCode};
+
+    /// Fake device answering ReadProperty requests in reverse arrival order
+    async fn reversing_device(socket: UdpSocket, requests: usize) {
+        let mut buffer = [0u8; 1500];
+        let mut pending = Vec::new();
+
+        for _ in 0..requests {
+            let (len, source) = socket.recv_from(&mut buffer).await.unwrap();
+            let bvlc = Bvlc::decode(&buffer[..len]).unwrap();
+            let npdu_data = bvlc.npdu().unwrap();
+            let (_, npdu_len) = Npdu::decode(npdu_data).unwrap();
+            match Apdu::decode(&npdu_data[npdu_len..]).unwrap() {
+                Apdu::ConfirmedRequest {
+                    invoke_id,
+                    service_data,
+                    ..
+                } => {
+                    let request = ReadPropertyRequest::decode(&service_data).unwrap();
+                    pending.push((invoke_id, request, source));
+                }
+                other => panic!("unexpected APDU {:?}", other),
+            }
+        }
+
+        for (invoke_id, request, source) in pending.into_iter().rev() {
+            let ack = ReadPropertyAck::new(
+                request.object_identifier,
+                request.property_identifier,
+                PropertyValue::Real(request.object_identifier.instance as f32),
+            );
+            let mut service_data = Vec::new();
+            ack.encode(&mut service_data).unwrap();
+            let apdu = Apdu::ComplexAck {
+                segmented: false,
+                more_follows: false,
+                invoke_id,
+                sequence_number: None,
+                proposed_window_size: None,
+                service_choice: ConfirmedServiceChoice::ReadProperty as u8,
+                service_data,
+            };
+            let bvlc = Bvlc::OriginalUnicastNpdu(encode_npdu(&apdu, false));
+            send_bvlc(&socket, &bvlc, source).await.unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_are_matched_by_invoke_id() {
+        let device = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let device_addr = device.local_addr().unwrap();
+        let server = tokio::spawn(reversing_device(device, 2));
+
+        let client = AsyncBacnetClient::bind("127.0.0.1:0").await.unwrap();
+        let (first, second) = tokio::join!(
+            client.read_property(
+                device_addr,
+                ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+                PropertyIdentifier::PresentValue,
+            ),
+            client.read_property(
+                device_addr,
+                ObjectIdentifier::new(ObjectType::AnalogInput, 2),
+                PropertyIdentifier::PresentValue,
+            ),
+        );
+
+        assert!(matches!(first.unwrap(), PropertyValue::Real(v) if v == 1.0));
+        assert!(matches!(second.unwrap(), PropertyValue::Real(v) if v == 2.0));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_error_pdu_surfaces_as_bacnet_error() {
+        let device = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let device_addr = device.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut buffer = [0u8; 1500];
+            let (len, source) = device.recv_from(&mut buffer).await.unwrap();
+            let bvlc = Bvlc::decode(&buffer[..len]).unwrap();
+            let npdu_data = bvlc.npdu().unwrap();
+            let (_, npdu_len) = Npdu::decode(npdu_data).unwrap();
+            let Apdu::ConfirmedRequest { invoke_id, .. } =
+                Apdu::decode(&npdu_data[npdu_len..]).unwrap()
+            else {
+                panic!("expected a confirmed request");
+            };
+
+            let apdu = Apdu::Error {
+                invoke_id,
+                service_choice: ConfirmedServiceChoice::ReadProperty as u8,
+                error_class: ErrorClass::Property as u32,
+                error_code: u32::from(ErrorCode::UnknownProperty),
+                error_data: Vec::new(),
+            };
+            let bvlc = Bvlc::OriginalUnicastNpdu(encode_npdu(&apdu, false));
+            send_bvlc(&device, &bvlc, source).await.unwrap();
+        });
+
+        let client = AsyncBacnetClient::bind("127.0.0.1:0").await.unwrap();
+        let result = client
+            .read_property(
+                device_addr,
+                ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+                PropertyIdentifier::ActiveText,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(AsyncClientError::Bacnet(BacnetError::Error {
+                class: ErrorClass::Property,
+                code: ErrorCode::UnknownProperty,
+            }))
+        ));
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_request_retransmits_then_times_out() {
+        let silent = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let client = AsyncBacnetClient::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .with_config(ClientConfig {
+                apdu_timeout: Duration::from_millis(30),
+                retries: 2,
+                ..ClientConfig::default()
+            });
+        assert_eq!(client.config().retries, 2);
+
+        let result = client
+            .read_property(
+                silent.local_addr().unwrap(),
+                ObjectIdentifier::new(ObjectType::Device, 1),
+                PropertyIdentifier::ObjectName,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AsyncClientError::Timeout)));
+        assert_eq!(client.transactions.lock().unwrap().outstanding_count(), 0);
+
+        // The original transmission plus two retries, all with one invoke ID
+        let mut buffer = [0u8; 1500];
+        let mut invoke_ids = Vec::new();
+        while let Ok(Ok((len, _))) =
+            tokio::time::timeout(Duration::from_millis(10), silent.recv_from(&mut buffer)).await
+        {
+            let bvlc = Bvlc::decode(&buffer[..len]).unwrap();
+            let npdu_data = bvlc.npdu().unwrap();
+            let (_, npdu_len) = Npdu::decode(npdu_data).unwrap();
+            if let Apdu::ConfirmedRequest { invoke_id, .. } =
+                Apdu::decode(&npdu_data[npdu_len..]).unwrap()
+            {
+                invoke_ids.push(invoke_id);
+            }
+        }
+        assert_eq!(invoke_ids, vec![0, 0, 0]);
+    }
+
+    #[tokio::test]
+    async fn test_abandoned_request_frees_its_invoke_id() {
+        let silent = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let client = AsyncBacnetClient::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .with_timeout(Duration::from_millis(10));
+        let read = || {
+            client.read_property(
+                silent.local_addr().unwrap(),
+                ObjectIdentifier::new(ObjectType::Device, 1),
+                PropertyIdentifier::ObjectName,
+            )
+        };
+
+        // Dropping the future leaves its transaction behind
+        assert!(tokio::time::timeout(Duration::from_millis(1), read())
+            .await
+            .is_err());
+        assert_eq!(client.transactions.lock().unwrap().outstanding_count(), 1);
+
+        // Once the retry budget has passed, the next request reclaims it
+        tokio::time::sleep(client.config().total_timeout()).await;
+        assert!(tokio::time::timeout(Duration::from_millis(1), read())
+            .await
+            .is_err());
+        let transactions = client.transactions.lock().unwrap();
+        assert_eq!(transactions.outstanding_count(), 1);
+        assert!(!transactions.is_outstanding(0));
+    }
+
+    #[tokio::test]
+    async fn test_requests_are_limited_to_the_peer_max_apdu() {
+        let device = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let device_addr = device.local_addr().unwrap();
+        let client = AsyncBacnetClient::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .with_broadcast_address(device_addr);
+        let name = PropertyValue::CharacterString("x".repeat(60));
+        let write = |device| {
+            client.write_property(
+                device,
+                ObjectIdentifier::new(ObjectType::AnalogValue, 1),
+                PropertyIdentifier::ObjectName,
+                &name,
+                None,
+            )
+        };
+
+        // The device answers Who-Is with a Max_APDU_Length_Accepted of 50
+        let responder = tokio::spawn(async move {
+            let mut buffer = [0u8; 1500];
+            let (_, source) = device.recv_from(&mut buffer).await.unwrap();
+            let mut service_data = Vec::new();
+            IAmRequest::new(
+                ObjectIdentifier::new(ObjectType::Device, 7),
+                50,
+                crate::object::Segmentation::NoSegmentation,
+                260,
+            )
+            .encode(&mut service_data)
+            .unwrap();
+            let apdu = Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::IAm,
+                service_data,
+            };
+            let bvlc = Bvlc::OriginalUnicastNpdu(encode_npdu(&apdu, false));
+            send_bvlc(&device, &bvlc, source).await.unwrap();
+
+            // Nothing else arrives
+            tokio::time::timeout(Duration::from_millis(100), device.recv_from(&mut buffer))
+                .await
+                .is_err()
+        });
+        let devices = client
+            .who_is(&WhoIsRequest::new(), Duration::from_millis(100))
+            .await
+            .unwrap();
+        assert_eq!(devices.len(), 1);
+
+        assert!(matches!(
+            write(device_addr).await,
+            Err(AsyncClientError::Application(
+                ApplicationError::MaxApduLengthExceeded
+            ))
+        ));
+        assert!(responder.await.unwrap());
+
+        let other = SocketAddr::from(([127, 0, 0, 1], device_addr.port() + 1));
+        client.set_peer_max_apdu(other, MaxApdu::Up50);
+        assert!(matches!(
+            write(other).await,
+            Err(AsyncClientError::Application(
+                ApplicationError::MaxApduLengthExceeded
+            ))
+        ));
+    }
+}
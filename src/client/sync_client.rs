@@ -0,0 +1,231 @@
+//! Blocking BACnet/IP Client
+//!
+//! [`SyncClient`] reads properties over a plain `std::net::UdpSocket`, with no
+//! async runtime. It is a thin wrapper over [`BacnetClient`]: each call sends
+//! one confirmed request, waits for the acknowledgement carrying its invoke ID
+//! and returns the decoded value, which suits one-off reads from simple
+//! scripts.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use bacnet_rs::client::SyncClient;
+//! use bacnet_rs::object::{ObjectIdentifier, ObjectType, PropertyIdentifier};
+//!
+//! # fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = SyncClient::new()?;
+//! let value = client.read_property(
+//!     "192.168.1.100:47808".parse()?,
+//!     ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+//!     PropertyIdentifier::PresentValue,
+//! )?;
+//! println!("{:?}", value);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    error::Error,
+    net::{SocketAddr, ToSocketAddrs, UdpSocket},
+};
+
+use super::{BacnetClient, ClientConfig};
+use crate::{
+    app::ApplicationError,
+    object::{ObjectIdentifier, PropertyIdentifier, PropertyValue},
+    service::{BacnetError, ErrorClass, ErrorCode},
+};
+
+/// Blocking BACnet/IP client with one request in flight at a time
+pub struct SyncClient {
+    client: BacnetClient,
+}
+
+impl SyncClient {
+    /// Bind a client to an ephemeral port on all interfaces
+    pub fn new() -> std::io::Result<Self> {
+        Self::bind("0.0.0.0:0", ClientConfig::default())
+    }
+
+    /// Bind a client to a local address, with a timeout and retry policy
+    pub fn bind(addr: impl ToSocketAddrs, config: ClientConfig) -> std::io::Result<Self> {
+        Ok(Self {
+            client: BacnetClient::from_socket(UdpSocket::bind(addr)?, config)?,
+        })
+    }
+
+    /// Get the effective timeout and retry policy
+    pub fn config(&self) -> &ClientConfig {
+        self.client.config()
+    }
+
+    /// Read a property value from an object on a device
+    ///
+    /// Failures on this side of the network are reported as communication
+    /// errors: timeout when no acknowledgement arrives after the configured
+    /// retries, other for anything else.
+    pub fn read_property(
+        &self,
+        device: SocketAddr,
+        object_identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+    ) -> Result<PropertyValue, BacnetError> {
+        self.client
+            .read_property(device, object_identifier, property)
+            .map_err(communication_error)
+    }
+}
+
+/// Map a client failure onto the BACnet error reported for it
+fn communication_error(error: Box<dyn Error>) -> BacnetError {
+    let error = match error.downcast::<BacnetError>() {
+        Ok(error) => return *error,
+        Err(error) => error,
+    };
+    let code = match error.downcast_ref::<ApplicationError>() {
+        Some(ApplicationError::Timeout) => ErrorCode::Timeout,
+        _ => ErrorCode::Other,
+    };
+    BacnetError::Error {
+        class: ErrorClass::Communication,
+        code,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::Apdu,
+        datalink::bip::Bvlc,
+        network::Npdu,
+        object::ObjectType,
+        service::{ConfirmedServiceChoice, ReadPropertyAck},
+    };
+    use std::{thread, time::Duration};
+
+    /// Answer one confirmed request from `socket` with `reply(invoke_id)`
+    fn answer_once(socket: &UdpSocket, reply: impl Fn(u8) -> Apdu) {
+        let mut buffer = [0u8; 1500];
+        let (len, source) = socket.recv_from(&mut buffer).unwrap();
+        let bvlc = Bvlc::decode(&buffer[..len]).unwrap();
+        let npdu_data = bvlc.npdu().unwrap();
+        let (_, npdu_len) = Npdu::decode(npdu_data).unwrap();
+        let Apdu::ConfirmedRequest { invoke_id, .. } =
+            Apdu::decode(&npdu_data[npdu_len..]).unwrap()
+        else {
+            panic!("expected a confirmed request");
+        };
+        let mut message = Npdu::new().encode();
+        message.extend_from_slice(&reply(invoke_id).encode());
+        let frame = Bvlc::OriginalUnicastNpdu(message).encode().unwrap();
+        socket.send_to(&frame, source).unwrap();
+    }
+
+    fn read_ack(invoke_id: u8, value: f32) -> Apdu {
+        let ack = ReadPropertyAck::new(
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+            PropertyIdentifier::PresentValue.as_u32(),
+            PropertyValue::Real(value),
+        );
+        let mut service_data = Vec::new();
+        ack.encode(&mut service_data).unwrap();
+        Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty as u8,
+            service_data,
+        }
+    }
+
+    #[test]
+    fn test_read_property_matches_invoke_id() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let device_addr = device.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            // A stale answer to another invoke ID is ignored
+            answer_once(&device, |invoke_id| {
+                read_ack(invoke_id.wrapping_add(7), 1.0)
+            });
+            answer_once(&device, |invoke_id| read_ack(invoke_id, 21.5));
+        });
+
+        let client = SyncClient::bind(
+            "127.0.0.1:0",
+            ClientConfig {
+                apdu_timeout: Duration::from_millis(200),
+                retries: 1,
+                ..ClientConfig::default()
+            },
+        )
+        .unwrap();
+        let value = client
+            .read_property(
+                device_addr,
+                ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+                PropertyIdentifier::PresentValue,
+            )
+            .unwrap();
+        assert!(matches!(value, PropertyValue::Real(v) if v == 21.5));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_read_property_passes_device_errors_through() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let device_addr = device.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            answer_once(&device, |invoke_id| Apdu::Error {
+                invoke_id,
+                service_choice: ConfirmedServiceChoice::ReadProperty as u8,
+                error_class: ErrorClass::Property as u32,
+                error_code: 32, // unknown-property
+                error_data: Vec::new(),
+            });
+        });
+
+        let client = SyncClient::bind("127.0.0.1:0", ClientConfig::default()).unwrap();
+        let result = client.read_property(
+            device_addr,
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+            PropertyIdentifier::Priority,
+        );
+        assert!(matches!(
+            result,
+            Err(BacnetError::Error {
+                class: ErrorClass::Property,
+                code: ErrorCode::UnknownProperty,
+            })
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_read_property_times_out() {
+        let device = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let client = SyncClient::bind(
+            "127.0.0.1:0",
+            ClientConfig {
+                apdu_timeout: Duration::from_millis(20),
+                retries: 1,
+                ..ClientConfig::default()
+            },
+        )
+        .unwrap();
+        let result = client.read_property(
+            device.local_addr().unwrap(),
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+            PropertyIdentifier::PresentValue,
+        );
+        assert!(matches!(
+            result,
+            Err(BacnetError::Error {
+                class: ErrorClass::Communication,
+                code: ErrorCode::Timeout,
+            })
+        ));
+    }
+}
@@ -72,13 +72,20 @@
 use std::{
     io::ErrorKind,
     net::{SocketAddr, ToSocketAddrs, UdpSocket},
+    sync::{
+        mpsc::{self, RecvTimeoutError, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
     time::{Duration, Instant},
 };
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{vec, vec::Vec};
 
-use crate::datalink::{DataLink, DataLinkAddress, DataLinkError, DataLinkType, Result};
+#[cfg(feature = "std")]
+use crate::datalink::{DataLink, DataLinkAddress, DataLinkType, Direction, FrameTrace};
+use crate::datalink::{DataLinkError, Result};
 
 /// BACnet/IP well-known UDP port number.
 ///
@@ -125,6 +132,12 @@ pub const BACNET_IP_PORT: u16 = 47808;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum BvlcFunction {
+    /// BVLC-Result (0x00).
+    ///
+    /// Sent by a BBMD to acknowledge or reject a BVLC request such as
+    /// Register-Foreign-Device. Carries a 2-byte result code.
+    Result = 0x00,
+
     /// Original-Unicast-NPDU (0x0A).
     ///
     /// Encapsulates an NPDU for unicast delivery to a specific BACnet/IP device.
@@ -331,6 +344,7 @@ impl BvlcHeader {
         }
 
         let function = match data[1] {
+            0x00 => BvlcFunction::Result,
             0x0A => BvlcFunction::OriginalUnicastNpdu,
             0x0B => BvlcFunction::OriginalBroadcastNpdu,
             0x04 => BvlcFunction::ForwardedNpdu,
@@ -356,6 +370,226 @@ impl BvlcHeader {
     }
 }
 
+/// BVLC-Result codes returned by a BBMD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u16)]
+pub enum BvlcResultCode {
+    /// Successful completion (0x0000).
+    Success = 0x0000,
+    /// Write-Broadcast-Distribution-Table NAK (0x0010).
+    WriteBroadcastDistributionTableNak = 0x0010,
+    /// Read-Broadcast-Distribution-Table NAK (0x0020).
+    ReadBroadcastDistributionTableNak = 0x0020,
+    /// Register-Foreign-Device NAK (0x0030).
+    RegisterForeignDeviceNak = 0x0030,
+    /// Read-Foreign-Device-Table NAK (0x0040).
+    ReadForeignDeviceTableNak = 0x0040,
+    /// Delete-Foreign-Device-Table-Entry NAK (0x0050).
+    DeleteForeignDeviceTableEntryNak = 0x0050,
+    /// Distribute-Broadcast-To-Network NAK (0x0060).
+    DistributeBroadcastToNetworkNak = 0x0060,
+}
+
+impl TryFrom<u16> for BvlcResultCode {
+    type Error = DataLinkError;
+
+    fn try_from(value: u16) -> Result<Self> {
+        match value {
+            0x0000 => Ok(BvlcResultCode::Success),
+            0x0010 => Ok(BvlcResultCode::WriteBroadcastDistributionTableNak),
+            0x0020 => Ok(BvlcResultCode::ReadBroadcastDistributionTableNak),
+            0x0030 => Ok(BvlcResultCode::RegisterForeignDeviceNak),
+            0x0040 => Ok(BvlcResultCode::ReadForeignDeviceTableNak),
+            0x0050 => Ok(BvlcResultCode::DeleteForeignDeviceTableEntryNak),
+            0x0060 => Ok(BvlcResultCode::DistributeBroadcastToNetworkNak),
+            _ => Err(DataLinkError::InvalidFrame),
+        }
+    }
+}
+
+/// A complete BVLC message with its function-specific payload.
+///
+/// Covers the BVLC functions that carry NPDUs or are needed to join a
+/// BBMD as a foreign device. The 4-byte header (including the 2-byte
+/// length field) is produced by [`Bvlc::encode`] and validated by
+/// [`Bvlc::decode`].
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::datalink::bip::Bvlc;
+///
+/// let message = Bvlc::OriginalUnicastNpdu(vec![0x01, 0x00]);
+/// let bytes = message.encode().unwrap();
+/// assert_eq!(bytes, vec![0x81, 0x0A, 0x00, 0x06, 0x01, 0x00]);
+///
+/// assert_eq!(Bvlc::decode(&bytes).unwrap(), message);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Bvlc {
+    /// Original-Unicast-NPDU carrying an NPDU.
+    OriginalUnicastNpdu(Vec<u8>),
+
+    /// Original-Broadcast-NPDU carrying an NPDU.
+    OriginalBroadcastNpdu(Vec<u8>),
+
+    /// Forwarded-NPDU with the B/IP address of the original sender.
+    ForwardedNpdu {
+        /// IPv4 address of the original sender.
+        source_ip: [u8; 4],
+        /// UDP port of the original sender.
+        source_port: u16,
+        /// The forwarded NPDU.
+        npdu: Vec<u8>,
+    },
+
+    /// Distribute-Broadcast-To-Network sent by a foreign device to its BBMD.
+    DistributeBroadcastToNetwork(Vec<u8>),
+
+    /// Register-Foreign-Device with the requested Time-to-Live in seconds.
+    RegisterForeignDevice {
+        /// Time-to-Live in seconds.
+        ttl: u16,
+    },
+
+    /// Delete-Foreign-Device-Table-Entry for the given B/IP address.
+    DeleteForeignDeviceTableEntry {
+        /// IPv4 address of the entry to delete.
+        ip: [u8; 4],
+        /// UDP port of the entry to delete.
+        port: u16,
+    },
+
+    /// BVLC-Result returned by a BBMD.
+    Result(BvlcResultCode),
+}
+
+impl Bvlc {
+    /// Get the BVLC function code for this message.
+    pub fn function(&self) -> BvlcFunction {
+        match self {
+            Bvlc::OriginalUnicastNpdu(_) => BvlcFunction::OriginalUnicastNpdu,
+            Bvlc::OriginalBroadcastNpdu(_) => BvlcFunction::OriginalBroadcastNpdu,
+            Bvlc::ForwardedNpdu { .. } => BvlcFunction::ForwardedNpdu,
+            Bvlc::DistributeBroadcastToNetwork(_) => BvlcFunction::DistributeBroadcastToNetwork,
+            Bvlc::RegisterForeignDevice { .. } => BvlcFunction::RegisterForeignDevice,
+            Bvlc::DeleteForeignDeviceTableEntry { .. } => {
+                BvlcFunction::DeleteForeignDeviceTableEntry
+            }
+            Bvlc::Result(_) => BvlcFunction::Result,
+        }
+    }
+
+    /// Get the encapsulated NPDU, if this message carries one.
+    pub fn npdu(&self) -> Option<&[u8]> {
+        match self {
+            Bvlc::OriginalUnicastNpdu(npdu)
+            | Bvlc::OriginalBroadcastNpdu(npdu)
+            | Bvlc::DistributeBroadcastToNetwork(npdu)
+            | Bvlc::ForwardedNpdu { npdu, .. } => Some(npdu),
+            Bvlc::RegisterForeignDevice { .. }
+            | Bvlc::DeleteForeignDeviceTableEntry { .. }
+            | Bvlc::Result(_) => None,
+        }
+    }
+
+    /// Encode the message, including its BVLC header, to wire format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::InvalidFrame`] if the message does not fit
+    /// in the 16-bit length field.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut payload = Vec::new();
+        match self {
+            Bvlc::OriginalUnicastNpdu(npdu)
+            | Bvlc::OriginalBroadcastNpdu(npdu)
+            | Bvlc::DistributeBroadcastToNetwork(npdu) => payload.extend_from_slice(npdu),
+            Bvlc::ForwardedNpdu {
+                source_ip,
+                source_port,
+                npdu,
+            } => {
+                payload.extend_from_slice(source_ip);
+                payload.extend_from_slice(&source_port.to_be_bytes());
+                payload.extend_from_slice(npdu);
+            }
+            Bvlc::RegisterForeignDevice { ttl } => payload.extend_from_slice(&ttl.to_be_bytes()),
+            Bvlc::DeleteForeignDeviceTableEntry { ip, port } => {
+                payload.extend_from_slice(ip);
+                payload.extend_from_slice(&port.to_be_bytes());
+            }
+            Bvlc::Result(code) => payload.extend_from_slice(&(*code as u16).to_be_bytes()),
+        }
+
+        let length = u16::try_from(4 + payload.len()).map_err(|_| DataLinkError::InvalidFrame)?;
+        let mut frame = BvlcHeader::new(self.function(), length).encode();
+        frame.extend_from_slice(&payload);
+        Ok(frame)
+    }
+
+    /// Decode a complete BVLC message from wire format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::InvalidFrame`] if the header is invalid, the
+    /// declared length does not match the buffer length, or the payload is
+    /// malformed. Returns [`DataLinkError::UnsupportedType`] for BVLC
+    /// functions not represented by this enum.
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let header = BvlcHeader::decode(data)?;
+        if header.length as usize != data.len() {
+            return Err(DataLinkError::InvalidFrame);
+        }
+
+        let payload = &data[4..];
+        match header.function {
+            BvlcFunction::OriginalUnicastNpdu => Ok(Bvlc::OriginalUnicastNpdu(payload.to_vec())),
+            BvlcFunction::OriginalBroadcastNpdu => {
+                Ok(Bvlc::OriginalBroadcastNpdu(payload.to_vec()))
+            }
+            BvlcFunction::DistributeBroadcastToNetwork => {
+                Ok(Bvlc::DistributeBroadcastToNetwork(payload.to_vec()))
+            }
+            BvlcFunction::ForwardedNpdu => {
+                if payload.len() < 6 {
+                    return Err(DataLinkError::InvalidFrame);
+                }
+                Ok(Bvlc::ForwardedNpdu {
+                    source_ip: [payload[0], payload[1], payload[2], payload[3]],
+                    source_port: u16::from_be_bytes([payload[4], payload[5]]),
+                    npdu: payload[6..].to_vec(),
+                })
+            }
+            BvlcFunction::RegisterForeignDevice => {
+                if payload.len() != 2 {
+                    return Err(DataLinkError::InvalidFrame);
+                }
+                Ok(Bvlc::RegisterForeignDevice {
+                    ttl: u16::from_be_bytes([payload[0], payload[1]]),
+                })
+            }
+            BvlcFunction::DeleteForeignDeviceTableEntry => {
+                if payload.len() != 6 {
+                    return Err(DataLinkError::InvalidFrame);
+                }
+                Ok(Bvlc::DeleteForeignDeviceTableEntry {
+                    ip: [payload[0], payload[1], payload[2], payload[3]],
+                    port: u16::from_be_bytes([payload[4], payload[5]]),
+                })
+            }
+            BvlcFunction::Result => {
+                if payload.len() != 2 {
+                    return Err(DataLinkError::InvalidFrame);
+                }
+                let code = u16::from_be_bytes([payload[0], payload[1]]);
+                Ok(Bvlc::Result(BvlcResultCode::try_from(code)?))
+            }
+            _ => Err(DataLinkError::UnsupportedType),
+        }
+    }
+}
+
 /// Broadcast Distribution Table (BDT) entry.
 ///
 /// Represents a peer BBMD in the broadcast distribution network. BBMDs use
@@ -534,6 +768,12 @@ pub struct BacnetIpDataLink {
     /// Calculated based on the local IP address and subnet mask.
     /// Used for Original-Broadcast-NPDU messages.
     broadcast_addr: SocketAddr,
+
+    /// Hook called with every datagram sent or received.
+    ///
+    /// Held in a mutex only so the data link stays `Sync`; it is always
+    /// reached through `&mut self`, so the lock is never contended.
+    trace: Option<Mutex<FrameTrace>>,
 }
 
 #[cfg(feature = "std")]
@@ -611,9 +851,23 @@ impl BacnetIpDataLink {
             bdt: Vec::new(),
             fdt: Vec::new(),
             broadcast_addr,
+            trace: None,
         })
     }
 
+    /// Call `trace` with every datagram sent or received, BVLC header included.
+    ///
+    /// Replaces any previous hook. Without one, frames are not traced and
+    /// nothing is copied.
+    pub fn set_trace(&mut self, trace: FrameTrace) {
+        self.trace = Some(Mutex::new(trace));
+    }
+
+    /// Stop tracing frames.
+    pub fn clear_trace(&mut self) {
+        self.trace = None;
+    }
+
     /// Send a unicast NPDU to a specific device.
     ///
     /// Wraps the NPDU in a BVLC Original-Unicast-NPDU message and sends it
@@ -651,6 +905,7 @@ impl BacnetIpDataLink {
         self.socket
             .send_to(&frame, dest)
             .map_err(DataLinkError::IoError)?;
+        trace_frame(&mut self.trace, Direction::Sent, &frame);
 
         Ok(())
     }
@@ -695,10 +950,13 @@ impl BacnetIpDataLink {
         self.socket
             .send_to(&frame, self.broadcast_addr)
             .map_err(DataLinkError::IoError)?;
+        trace_frame(&mut self.trace, Direction::Sent, &frame);
 
         // Send to all BDT entries
         for entry in &self.bdt {
-            let _ = self.socket.send_to(&frame, entry.address);
+            if self.socket.send_to(&frame, entry.address).is_ok() {
+                trace_frame(&mut self.trace, Direction::Sent, &frame);
+            }
         }
 
         Ok(())
@@ -750,6 +1008,7 @@ impl BacnetIpDataLink {
         self.socket
             .send_to(&frame, bbmd_addr)
             .map_err(DataLinkError::IoError)?;
+        trace_frame(&mut self.trace, Direction::Sent, &frame);
 
         Ok(())
     }
@@ -875,6 +1134,258 @@ impl BacnetIpDataLink {
     }
 }
 
+/// Foreign device registration helper.
+///
+/// Registers a socket with a BBMD so that it receives broadcasts from the
+/// BBMD's network, and keeps the registration alive in the background.
+/// The registration must use the same socket the application communicates
+/// on, since the BBMD forwards broadcasts to the registered source address.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[cfg(feature = "std")] {
+/// use bacnet_rs::datalink::bip::ForeignDevice;
+/// use std::net::UdpSocket;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let socket = UdpSocket::bind("0.0.0.0:47808")?;
+/// let bbmd = "10.0.0.1:47808".parse()?;
+///
+/// // Register for 5 minutes; re-registration happens automatically
+/// let registration = ForeignDevice::new(socket.try_clone()?).register(bbmd, 300)?;
+///
+/// // ... communicate on `socket` ...
+///
+/// // Dropping the handle stops re-registration and deregisters
+/// drop(registration);
+/// # Ok(())
+/// # }
+/// # }
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ForeignDevice {
+    /// Socket used for registration traffic
+    socket: UdpSocket,
+    /// Time to wait for the BBMD's BVLC-Result
+    timeout: Duration,
+    /// Hook called with every registration datagram sent or received
+    trace: Option<SharedTrace>,
+}
+
+#[cfg(feature = "std")]
+impl ForeignDevice {
+    /// Create a foreign device helper for a socket.
+    ///
+    /// The BVLC-Result wait defaults to 3 seconds.
+    pub fn new(socket: UdpSocket) -> Self {
+        Self {
+            socket,
+            timeout: Duration::from_secs(3),
+            trace: None,
+        }
+    }
+
+    /// Set how long to wait for the BBMD's BVLC-Result.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Call `trace` with every registration datagram sent or received.
+    ///
+    /// This covers Register-Foreign-Device and its re-registrations, all
+    /// datagrams read while waiting for the BVLC-Result, and the final
+    /// Delete-Foreign-Device-Table-Entry, BVLC header included.
+    pub fn with_trace(mut self, trace: FrameTrace) -> Self {
+        self.trace = Some(SharedTrace(Arc::new(Mutex::new(trace))));
+        self
+    }
+
+    /// Register with a BBMD for `ttl` seconds.
+    ///
+    /// Sends Register-Foreign-Device and waits for the BBMD's BVLC-Result.
+    /// On success a background thread re-registers at half the TTL until
+    /// the returned handle is dropped. Other datagrams received while
+    /// waiting are kept for [`ForeignDeviceRegistration::take_received`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::BvlcNak`] with the BBMD's result code if the
+    /// registration is rejected, or an I/O error if no result arrives in time.
+    pub fn register(self, bbmd_addr: SocketAddr, ttl: u16) -> Result<ForeignDeviceRegistration> {
+        let frame = Bvlc::RegisterForeignDevice { ttl }.encode()?;
+        self.socket
+            .send_to(&frame, bbmd_addr)
+            .map_err(DataLinkError::IoError)?;
+        SharedTrace::frame(&self.trace, Direction::Sent, &frame);
+
+        let mut received = Vec::new();
+        match self.wait_for_result(bbmd_addr, &mut received)? {
+            BvlcResultCode::Success => {}
+            code => return Err(DataLinkError::BvlcNak(code)),
+        }
+
+        let (stop, stopped) = mpsc::channel::<()>();
+        let socket = self.socket.try_clone().map_err(DataLinkError::IoError)?;
+        let interval = Duration::from_secs(u64::from(ttl / 2).max(1));
+        let trace = self.trace.clone();
+        let worker = thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                // Results of re-registration arrive on the application's socket
+                if socket.send_to(&frame, bbmd_addr).is_ok() {
+                    SharedTrace::frame(&trace, Direction::Sent, &frame);
+                }
+            }
+        });
+
+        Ok(ForeignDeviceRegistration {
+            socket: self.socket,
+            bbmd_addr,
+            ttl,
+            received,
+            stop: Some(stop),
+            worker: Some(worker),
+            trace: self.trace,
+        })
+    }
+
+    /// Wait for a BVLC-Result from the BBMD, queueing other traffic.
+    fn wait_for_result(
+        &self,
+        bbmd_addr: SocketAddr,
+        received: &mut Vec<(Vec<u8>, SocketAddr)>,
+    ) -> Result<BvlcResultCode> {
+        let previous_timeout = self.socket.read_timeout().map_err(DataLinkError::IoError)?;
+        let deadline = Instant::now() + self.timeout;
+        let mut buffer = [0u8; 1500];
+
+        let result = loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break Err(DataLinkError::IoError(ErrorKind::TimedOut.into()));
+            }
+            if let Err(e) = self.socket.set_read_timeout(Some(remaining)) {
+                break Err(DataLinkError::IoError(e));
+            }
+
+            match self.socket.recv_from(&mut buffer) {
+                Ok((len, source)) => {
+                    SharedTrace::frame(&self.trace, Direction::Received, &buffer[..len]);
+                    if source == bbmd_addr {
+                        if let Ok(Bvlc::Result(code)) = Bvlc::decode(&buffer[..len]) {
+                            break Ok(code);
+                        }
+                    }
+                    received.push((buffer[..len].to_vec(), source));
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock || e.kind() == ErrorKind::TimedOut => {}
+                Err(e) => break Err(DataLinkError::IoError(e)),
+            }
+        };
+
+        self.socket
+            .set_read_timeout(previous_timeout)
+            .map_err(DataLinkError::IoError)?;
+        result
+    }
+}
+
+/// Handle for an active foreign device registration.
+///
+/// Dropping the handle stops re-registration and sends
+/// Delete-Foreign-Device-Table-Entry to the BBMD. The delete is only sent
+/// when the socket is bound to a specific IPv4 address; otherwise the BBMD
+/// entry simply expires with its TTL.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct ForeignDeviceRegistration {
+    socket: UdpSocket,
+    bbmd_addr: SocketAddr,
+    ttl: u16,
+    received: Vec<(Vec<u8>, SocketAddr)>,
+    stop: Option<Sender<()>>,
+    worker: Option<JoinHandle<()>>,
+    trace: Option<SharedTrace>,
+}
+
+#[cfg(feature = "std")]
+impl ForeignDeviceRegistration {
+    /// Address of the BBMD this device is registered with.
+    pub fn bbmd_addr(&self) -> SocketAddr {
+        self.bbmd_addr
+    }
+
+    /// Registered Time-to-Live in seconds.
+    pub fn ttl(&self) -> u16 {
+        self.ttl
+    }
+
+    /// Take the datagrams that arrived while waiting for the BVLC-Result.
+    ///
+    /// Registration reads from the application's socket, so traffic for the
+    /// application received before the result is queued here, oldest first.
+    pub fn take_received(&mut self) -> Vec<(Vec<u8>, SocketAddr)> {
+        core::mem::take(&mut self.received)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for ForeignDeviceRegistration {
+    fn drop(&mut self) {
+        // Closing the channel wakes the worker immediately
+        self.stop.take();
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+
+        if let Ok(SocketAddr::V4(local)) = self.socket.local_addr() {
+            if !local.ip().is_unspecified() {
+                let delete = Bvlc::DeleteForeignDeviceTableEntry {
+                    ip: local.ip().octets(),
+                    port: local.port(),
+                };
+                if let Ok(frame) = delete.encode() {
+                    if self.socket.send_to(&frame, self.bbmd_addr).is_ok() {
+                        SharedTrace::frame(&self.trace, Direction::Sent, &frame);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Trace hook shared between a registration and its re-registration thread.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+struct SharedTrace(Arc<Mutex<FrameTrace>>);
+
+#[cfg(feature = "std")]
+impl SharedTrace {
+    /// Pass a datagram to the trace hook, if one is set.
+    fn frame(trace: &Option<Self>, direction: Direction, frame: &[u8]) {
+        if let Some(Ok(mut trace)) = trace.as_ref().map(|trace| trace.0.lock()) {
+            trace(direction, frame);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for SharedTrace {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SharedTrace")
+    }
+}
+
+/// Pass a datagram to the trace hook, if one is set.
+#[cfg(feature = "std")]
+fn trace_frame(trace: &mut Option<Mutex<FrameTrace>>, direction: Direction, frame: &[u8]) {
+    if let Some(Ok(trace)) = trace.as_mut().map(Mutex::get_mut) {
+        trace(direction, frame);
+    }
+}
+
 #[cfg(feature = "std")]
 impl DataLink for BacnetIpDataLink {
     fn send_frame(&mut self, frame: &[u8], dest: &DataLinkAddress) -> Result<()> {
@@ -891,6 +1402,7 @@ impl DataLink for BacnetIpDataLink {
         match self.socket.recv_from(&mut buffer) {
             Ok((len, source)) => {
                 let data = &buffer[..len];
+                trace_frame(&mut self.trace, Direction::Received, data);
 
                 if let Some(npdu) = self.process_bvlc_message(data, source)? {
                     Ok((npdu, DataLinkAddress::Ip(source)))
@@ -936,6 +1448,193 @@ mod tests {
         assert_eq!(decoded.length, 1024);
     }
 
+    #[test]
+    fn test_bvlc_message_round_trip() {
+        let messages = [
+            Bvlc::OriginalUnicastNpdu(vec![0x01, 0x04, 0x00, 0x05]),
+            Bvlc::OriginalBroadcastNpdu(vec![0x01, 0x20, 0xFF, 0xFF, 0x00, 0xFF]),
+            Bvlc::ForwardedNpdu {
+                source_ip: [192, 168, 1, 20],
+                source_port: BACNET_IP_PORT,
+                npdu: vec![0x01, 0x00, 0x10, 0x08],
+            },
+            Bvlc::DistributeBroadcastToNetwork(vec![0x01, 0x00]),
+            Bvlc::RegisterForeignDevice { ttl: 300 },
+        ];
+
+        for message in messages {
+            let encoded = message.encode().unwrap();
+            assert_eq!(encoded[1], message.function() as u8);
+            assert_eq!(
+                u16::from_be_bytes([encoded[2], encoded[3]]) as usize,
+                encoded.len()
+            );
+            assert_eq!(Bvlc::decode(&encoded).unwrap(), message);
+        }
+
+        let forwarded = Bvlc::ForwardedNpdu {
+            source_ip: [10, 0, 0, 1],
+            source_port: 0xBAC0,
+            npdu: vec![0x01, 0x00],
+        };
+        assert_eq!(
+            forwarded.encode().unwrap(),
+            vec![0x81, 0x04, 0x00, 0x0C, 10, 0, 0, 1, 0xBA, 0xC0, 0x01, 0x00]
+        );
+        assert_eq!(
+            Bvlc::RegisterForeignDevice { ttl: 60 }.encode().unwrap(),
+            vec![0x81, 0x05, 0x00, 0x06, 0x00, 0x3C]
+        );
+    }
+
+    #[test]
+    fn test_bvlc_message_length_mismatch() {
+        // Declared length longer than the buffer
+        assert!(matches!(
+            Bvlc::decode(&[0x81, 0x0A, 0x00, 0x08, 0x01, 0x00]),
+            Err(DataLinkError::InvalidFrame)
+        ));
+        // Declared length shorter than the buffer
+        assert!(matches!(
+            Bvlc::decode(&[0x81, 0x0A, 0x00, 0x05, 0x01, 0x00]),
+            Err(DataLinkError::InvalidFrame)
+        ));
+        // Register-Foreign-Device without a TTL
+        assert!(matches!(
+            Bvlc::decode(&[0x81, 0x05, 0x00, 0x04]),
+            Err(DataLinkError::InvalidFrame)
+        ));
+        // Forwarded-NPDU too short for the original source address
+        assert!(matches!(
+            Bvlc::decode(&[0x81, 0x04, 0x00, 0x06, 10, 0]),
+            Err(DataLinkError::InvalidFrame)
+        ));
+    }
+
+    #[test]
+    fn test_bvlc_result_round_trip() {
+        let nak = Bvlc::Result(BvlcResultCode::RegisterForeignDeviceNak);
+        let encoded = nak.encode().unwrap();
+        assert_eq!(encoded, vec![0x81, 0x00, 0x00, 0x06, 0x00, 0x30]);
+        assert_eq!(Bvlc::decode(&encoded).unwrap(), nak);
+
+        // Unknown result codes are rejected
+        assert!(Bvlc::decode(&[0x81, 0x00, 0x00, 0x06, 0x00, 0x31]).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    fn fake_bbmd(result: BvlcResultCode) -> (SocketAddr, JoinHandle<Vec<Bvlc>>) {
+        let bbmd = UdpSocket::bind("127.0.0.1:0").unwrap();
+        bbmd.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+        let addr = bbmd.local_addr().unwrap();
+
+        let handle = thread::spawn(move || {
+            let mut received = Vec::new();
+            let mut buffer = [0u8; 1500];
+            while let Ok((len, source)) = bbmd.recv_from(&mut buffer) {
+                let message = Bvlc::decode(&buffer[..len]).unwrap();
+                let done = !matches!(message, Bvlc::RegisterForeignDevice { .. })
+                    || result != BvlcResultCode::Success;
+                if let Bvlc::RegisterForeignDevice { .. } = message {
+                    let reply = Bvlc::Result(result).encode().unwrap();
+                    bbmd.send_to(&reply, source).unwrap();
+                }
+                received.push(message);
+                if done {
+                    break;
+                }
+            }
+            received
+        });
+
+        (addr, handle)
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_foreign_device_registration() {
+        let (bbmd_addr, bbmd) = fake_bbmd(BvlcResultCode::Success);
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let local = socket.local_addr().unwrap();
+
+        // Application traffic arriving before the result is handed back
+        let peer = UdpSocket::bind("127.0.0.1:0").unwrap();
+        peer.send_to(&[0x81, 0x0A, 0x00, 0x04], local).unwrap();
+
+        let mut registration = ForeignDevice::new(socket).register(bbmd_addr, 600).unwrap();
+        assert_eq!(registration.bbmd_addr(), bbmd_addr);
+        assert_eq!(registration.ttl(), 600);
+        assert_eq!(
+            registration.take_received(),
+            vec![(vec![0x81, 0x0A, 0x00, 0x04], peer.local_addr().unwrap())]
+        );
+        assert!(registration.take_received().is_empty());
+        drop(registration);
+
+        let received = bbmd.join().unwrap();
+        assert_eq!(received[0], Bvlc::RegisterForeignDevice { ttl: 600 });
+        assert_eq!(
+            received.last().unwrap(),
+            &Bvlc::DeleteForeignDeviceTableEntry {
+                ip: [127, 0, 0, 1],
+                port: local.port(),
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_foreign_device_registration_trace() {
+        let (bbmd_addr, bbmd) = fake_bbmd(BvlcResultCode::Success);
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let local = socket.local_addr().unwrap();
+
+        let frames = Arc::new(Mutex::new(Vec::new()));
+        let log = frames.clone();
+        let registration = ForeignDevice::new(socket)
+            .with_trace(Box::new(move |direction, frame| {
+                log.lock()
+                    .unwrap()
+                    .push((direction, Bvlc::decode(frame).unwrap()));
+            }))
+            .register(bbmd_addr, 600)
+            .unwrap();
+        drop(registration);
+        bbmd.join().unwrap();
+
+        let frames = frames.lock().unwrap();
+        assert_eq!(
+            *frames,
+            vec![
+                (Direction::Sent, Bvlc::RegisterForeignDevice { ttl: 600 }),
+                (Direction::Received, Bvlc::Result(BvlcResultCode::Success)),
+                (
+                    Direction::Sent,
+                    Bvlc::DeleteForeignDeviceTableEntry {
+                        ip: [127, 0, 0, 1],
+                        port: local.port(),
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_foreign_device_registration_rejected() {
+        let (bbmd_addr, bbmd) = fake_bbmd(BvlcResultCode::RegisterForeignDeviceNak);
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        let result = ForeignDevice::new(socket).register(bbmd_addr, 60);
+        assert!(matches!(
+            result,
+            Err(DataLinkError::BvlcNak(
+                BvlcResultCode::RegisterForeignDeviceNak
+            ))
+        ));
+        bbmd.join().unwrap();
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_bacnet_ip_creation() {
@@ -945,4 +1644,45 @@ mod tests {
         let datalink = result.unwrap();
         assert_eq!(datalink.link_type(), DataLinkType::BacnetIp);
     }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_frame_trace() {
+        use std::sync::{Arc, Mutex};
+
+        let mut sender = BacnetIpDataLink::new("127.0.0.1:0").unwrap();
+        let mut receiver = BacnetIpDataLink::new("127.0.0.1:0").unwrap();
+        let frames = Arc::new(Mutex::new(Vec::new()));
+        let log = frames.clone();
+        sender.set_trace(Box::new(move |direction, frame| {
+            log.lock().unwrap().push((direction, frame.to_vec()));
+        }));
+        let log = frames.clone();
+        receiver.set_trace(Box::new(move |direction, frame| {
+            log.lock().unwrap().push((direction, frame.to_vec()));
+        }));
+
+        let npdu = [0x01, 0x00, 0x10, 0x08];
+        sender
+            .send_unicast_npdu(&npdu, receiver.local_addr)
+            .unwrap();
+        let (received, _) = receiver.receive_frame().unwrap();
+        assert_eq!(received, npdu);
+
+        let wire = [0x81, 0x0A, 0x00, 0x08, 0x01, 0x00, 0x10, 0x08];
+        assert_eq!(
+            *frames.lock().unwrap(),
+            [
+                (Direction::Sent, wire.to_vec()),
+                (Direction::Received, wire.to_vec())
+            ]
+        );
+
+        sender.clear_trace();
+        sender
+            .send_unicast_npdu(&npdu, receiver.local_addr)
+            .unwrap();
+        receiver.receive_frame().unwrap();
+        assert_eq!(frames.lock().unwrap().len(), 3);
+    }
 }
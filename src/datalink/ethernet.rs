@@ -72,9 +72,11 @@
 use std::sync::{Arc, Mutex};
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 
-use crate::datalink::{DataLink, DataLinkAddress, DataLinkError, DataLinkType, Result};
+#[cfg(feature = "std")]
+use crate::datalink::{DataLink, DataLinkAddress, DataLinkType};
+use crate::datalink::{DataLinkError, Result};
 
 /// Ethernet broadcast MAC address (all ones).
 ///
@@ -106,6 +108,21 @@ pub const ETHERNET_BROADCAST_MAC: [u8; 6] = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
 /// ```
 pub const BACNET_ETHERNET_TYPE: u16 = 0x82DC;
 
+/// Largest value of the IEEE 802.3 length field.
+///
+/// Values of the type/length field up to 1500 give the length of the LLC
+/// header and payload, as used by ASHRAE 135 Clause 7. Larger values are an
+/// Ethernet type.
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::datalink::ethernet::{IEEE_802_3_MAX_LENGTH, BACNET_ETHERNET_TYPE};
+///
+/// assert!(BACNET_ETHERNET_TYPE > IEEE_802_3_MAX_LENGTH);
+/// ```
+pub const IEEE_802_3_MAX_LENGTH: u16 = 1500;
+
 /// BACnet LLC (Logical Link Control) header.
 ///
 /// This 3-byte header follows the Ethernet header and identifies the
@@ -184,10 +201,11 @@ pub struct EthernetFrame {
     /// (LSB of first byte must be 0).
     pub src_mac: [u8; 6],
 
-    /// Ethernet type field (2 bytes).
+    /// Ethernet type or IEEE 802.3 length field (2 bytes).
     ///
-    /// For BACnet frames, this must be 0x82DC. Other values indicate
-    /// non-BACnet traffic.
+    /// Either 0x82DC, or a value up to [`IEEE_802_3_MAX_LENGTH`] for IEEE
+    /// 802.3 frames as used by ASHRAE 135 Clause 7. For 802.3 frames the
+    /// length is recomputed from the payload when encoding.
     pub ether_type: u16,
 
     /// LLC header (3 bytes).
@@ -261,6 +279,52 @@ impl EthernetFrame {
         Self::new(ETHERNET_BROADCAST_MAC, src_mac, npdu)
     }
 
+    /// Create an IEEE 802.3 frame for BACnet communication.
+    ///
+    /// Uses a length field instead of an Ethernet type, followed by the
+    /// standard BACnet LLC header. This is the framing defined by ASHRAE 135
+    /// Clause 7 and expected by existing BACnet/Ethernet controllers.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_mac` - Destination MAC address
+    /// * `src_mac` - Source MAC address
+    /// * `npdu` - BACnet NPDU data
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bacnet_rs::datalink::ethernet::EthernetFrame;
+    ///
+    /// let frame = EthernetFrame::ieee802_3(
+    ///     [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF],
+    ///     [0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+    ///     vec![0x01, 0x04, 0x00, 0x00],
+    /// );
+    ///
+    /// let encoded = frame.encode();
+    /// // Length of the LLC header and NPDU
+    /// assert_eq!(&encoded[12..17], &[0x00, 0x07, 0x82, 0x82, 0x03]);
+    ///
+    /// // Padding is stripped again when decoding
+    /// let decoded = EthernetFrame::decode(&encoded).unwrap();
+    /// assert_eq!(decoded.payload, vec![0x01, 0x04, 0x00, 0x00]);
+    /// ```
+    pub fn ieee802_3(dest_mac: [u8; 6], src_mac: [u8; 6], npdu: Vec<u8>) -> Self {
+        Self {
+            dest_mac,
+            src_mac,
+            ether_type: (LLC_HEADER_SIZE + npdu.len()) as u16,
+            llc_header: BACNET_LLC_HEADER,
+            payload: npdu,
+        }
+    }
+
+    /// Check if this frame uses an IEEE 802.3 length field.
+    pub fn is_ieee802_3(&self) -> bool {
+        self.ether_type <= IEEE_802_3_MAX_LENGTH
+    }
+
     /// Encode the frame to its wire format.
     ///
     /// Returns the complete Ethernet frame ready for transmission, excluding
@@ -294,8 +358,13 @@ impl EthernetFrame {
         // Source MAC
         frame.extend_from_slice(&self.src_mac);
 
-        // Ethernet type
-        frame.extend_from_slice(&self.ether_type.to_be_bytes());
+        // Ethernet type, or the length of the LLC header and payload
+        let type_or_length = if self.is_ieee802_3() {
+            (LLC_HEADER_SIZE + self.payload.len()) as u16
+        } else {
+            self.ether_type
+        };
+        frame.extend_from_slice(&type_or_length.to_be_bytes());
 
         // LLC header
         frame.extend_from_slice(&self.llc_header);
@@ -328,8 +397,8 @@ impl EthernetFrame {
     ///
     /// Returns [`DataLinkError::InvalidFrame`] if:
     /// - The buffer is too short
-    /// - The Ethernet type is not 0x82DC
-    /// - The LLC header is incorrect
+    /// - The Ethernet type is not 0x82DC and is not a valid 802.3 length
+    /// - The LLC header is not addressed to and from the BACnet SAP
     ///
     /// # Examples
     ///
@@ -376,10 +445,18 @@ impl EthernetFrame {
 
         let ether_type = u16::from_be_bytes([data[12], data[13]]);
 
-        // Verify this is a BACnet frame
-        if ether_type != BACNET_ETHERNET_TYPE {
+        // Verify this is a BACnet frame, and find the end of an 802.3 frame
+        let end = if ether_type <= IEEE_802_3_MAX_LENGTH {
+            let length = ether_type as usize;
+            if length < LLC_HEADER_SIZE || data.len() - ETHERNET_HEADER_SIZE < length {
+                return Err(DataLinkError::InvalidFrame);
+            }
+            ETHERNET_HEADER_SIZE + length
+        } else if ether_type == BACNET_ETHERNET_TYPE {
+            data.len()
+        } else {
             return Err(DataLinkError::InvalidFrame);
-        }
+        };
 
         let mut llc_header = [0u8; 3];
         llc_header.copy_from_slice(&data[14..17]);
@@ -389,8 +466,8 @@ impl EthernetFrame {
             return Err(DataLinkError::InvalidFrame);
         }
 
-        // Extract payload (strip any padding)
-        let payload = data[17..].to_vec();
+        // Extract payload; only 802.3 frames allow padding to be stripped
+        let payload = data[17..end].to_vec();
 
         Ok(Self {
             dest_mac,
@@ -463,12 +540,52 @@ impl EthernetFrame {
     }
 }
 
+/// Raw Ethernet frame transport.
+///
+/// Abstracts the platform-specific access to the network interface, such as
+/// an `AF_PACKET` socket on Linux, BPF on macOS or Npcap on Windows. Frames
+/// are passed without the FCS, which is handled by the network hardware.
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::datalink::ethernet::RawFrameTransport;
+/// use bacnet_rs::datalink::{DataLinkError, Result};
+///
+/// struct Loopback {
+///     frames: Vec<Vec<u8>>,
+/// }
+///
+/// impl RawFrameTransport for Loopback {
+///     fn send_raw(&mut self, frame: &[u8]) -> Result<()> {
+///         self.frames.push(frame.to_vec());
+///         Ok(())
+///     }
+///
+///     fn receive_raw(&mut self) -> Result<Vec<u8>> {
+///         self.frames.pop().ok_or(DataLinkError::InvalidFrame)
+///     }
+/// }
+/// ```
+pub trait RawFrameTransport: Send + Sync {
+    /// Send a complete Ethernet frame.
+    fn send_raw(&mut self, frame: &[u8]) -> Result<()>;
+
+    /// Receive the next Ethernet frame from the interface.
+    ///
+    /// Frames of any protocol may be returned; the data link filters out
+    /// everything that is not BACnet. Blocking behaviour and timeouts are up
+    /// to the implementation.
+    fn receive_raw(&mut self) -> Result<Vec<u8>>;
+}
+
 /// BACnet/Ethernet data link implementation.
 ///
 /// Provides BACnet communication over Ethernet networks using raw frames.
-/// This implementation simulates Ethernet communication for demonstration
-/// purposes. In production, use platform-specific raw socket APIs or
-/// packet capture libraries.
+/// A data link created with [`with_transport`](Self::with_transport) sends
+/// and receives IEEE 802.3 frames through a [`RawFrameTransport`]. One
+/// created with [`new`](Self::new) only simulates Ethernet communication for
+/// demonstration purposes.
 ///
 /// # Platform Requirements
 ///
@@ -519,6 +636,9 @@ pub struct EthernetDataLink {
     ///
     /// Used to control the receive thread in a real implementation.
     _running: Arc<Mutex<bool>>,
+
+    /// Raw frame transport, if not simulating.
+    transport: Option<Box<dyn RawFrameTransport>>,
 }
 
 #[cfg(feature = "std")]
@@ -580,9 +700,47 @@ impl EthernetDataLink {
             _interface: interface.to_string(),
             rx_buffer,
             _running: running,
+            transport: None,
         })
     }
 
+    /// Create an Ethernet data link over a raw frame transport.
+    ///
+    /// Outgoing NPDUs are sent as IEEE 802.3 frames with the BACnet LLC
+    /// header. Incoming frames are only delivered if they carry the BACnet
+    /// LLC header and are addressed to `local_mac` or to a multicast or
+    /// broadcast address.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - Access to the network interface
+    /// * `local_mac` - MAC address of the network interface
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[cfg(feature = "std")] {
+    /// use bacnet_rs::datalink::ethernet::{EthernetDataLink, RawFrameTransport};
+    /// use bacnet_rs::datalink::{DataLink, DataLinkAddress};
+    ///
+    /// # fn example(transport: impl RawFrameTransport + 'static) -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut eth_link = EthernetDataLink::with_transport(transport, [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+    /// eth_link.send_frame(&[0x01, 0x20, 0xFF, 0xFF, 0x00, 0xFF], &DataLinkAddress::Broadcast)?;
+    /// let (npdu, source) = eth_link.receive_frame()?;
+    /// # Ok(())
+    /// # }
+    /// # }
+    /// ```
+    pub fn with_transport(transport: impl RawFrameTransport + 'static, local_mac: [u8; 6]) -> Self {
+        Self {
+            local_mac,
+            _interface: String::new(),
+            rx_buffer: Arc::new(Mutex::new(Vec::new())),
+            _running: Arc::new(Mutex::new(true)),
+            transport: Some(Box::new(transport)),
+        }
+    }
+
     /// Send an Ethernet frame on the network interface.
     ///
     /// In a real implementation, this would write the frame to a raw socket
@@ -599,11 +757,7 @@ impl EthernetDataLink {
     /// - The frame exceeds the maximum Ethernet size
     /// - The network interface is down
     /// - Insufficient permissions
-    fn send_ethernet_frame(&self, frame: &EthernetFrame) -> Result<()> {
-        // In a real implementation, this would:
-        // 1. Encode the frame
-        // 2. Send it via raw socket or pcap
-
+    fn send_ethernet_frame(&mut self, frame: &EthernetFrame) -> Result<()> {
         let encoded = frame.encode();
 
         // Validate frame size
@@ -611,6 +765,10 @@ impl EthernetDataLink {
             return Err(DataLinkError::InvalidFrame);
         }
 
+        if let Some(transport) = &mut self.transport {
+            return transport.send_raw(&encoded);
+        }
+
         // Simulate sending (in real implementation, use raw socket)
         println!(
             "Sending Ethernet frame: {} bytes to {:02X?}",
@@ -650,11 +808,24 @@ impl DataLink for EthernetDataLink {
             }
         };
 
-        let eth_frame = EthernetFrame::new(dest_mac, self.local_mac, frame.to_vec());
+        let eth_frame = EthernetFrame::ieee802_3(dest_mac, self.local_mac, frame.to_vec());
         self.send_ethernet_frame(&eth_frame)
     }
 
     fn receive_frame(&mut self) -> Result<(Vec<u8>, DataLinkAddress)> {
+        if let Some(transport) = &mut self.transport {
+            loop {
+                let data = transport.receive_raw()?;
+                // Skip other protocols and frames for other stations
+                let Ok(frame) = EthernetFrame::decode(&data) else {
+                    continue;
+                };
+                if frame.dest_mac == self.local_mac || frame.is_multicast() {
+                    return Ok((frame.payload, DataLinkAddress::Ethernet(frame.src_mac)));
+                }
+            }
+        }
+
         // Check receive buffer
         let mut buffer = self.rx_buffer.lock().unwrap();
 
@@ -779,7 +950,7 @@ pub fn format_mac_address(mac: &[u8; 6]) -> String {
 ///
 /// Returns [`DataLinkError::InvalidFrame`] if:
 /// - The frame is too short or too long
-/// - The Ethernet type is not 0x82DC
+/// - The Ethernet type is not 0x82DC and is not a valid 802.3 length
 /// - The LLC header is incorrect
 ///
 /// # Examples
@@ -811,9 +982,14 @@ pub fn validate_ethernet_frame(data: &[u8]) -> Result<()> {
         return Err(DataLinkError::InvalidFrame);
     }
 
-    // Check Ethernet type
+    // Check Ethernet type or 802.3 length
     let ether_type = u16::from_be_bytes([data[12], data[13]]);
-    if ether_type != BACNET_ETHERNET_TYPE {
+    if ether_type <= IEEE_802_3_MAX_LENGTH {
+        let length = ether_type as usize;
+        if length < LLC_HEADER_SIZE || data.len() - ETHERNET_HEADER_SIZE < length {
+            return Err(DataLinkError::InvalidFrame);
+        }
+    } else if ether_type != BACNET_ETHERNET_TYPE {
         return Err(DataLinkError::InvalidFrame);
     }
 
@@ -922,4 +1098,68 @@ mod tests {
         let result = datalink.send_frame(&npdu, &DataLinkAddress::Broadcast);
         assert!(result.is_ok());
     }
+
+    #[cfg(feature = "std")]
+    struct Wire {
+        inbound: Vec<Vec<u8>>,
+        outbound: Arc<Mutex<Vec<Vec<u8>>>>,
+    }
+
+    #[cfg(feature = "std")]
+    impl RawFrameTransport for Wire {
+        fn send_raw(&mut self, frame: &[u8]) -> Result<()> {
+            self.outbound.lock().unwrap().push(frame.to_vec());
+            Ok(())
+        }
+
+        fn receive_raw(&mut self) -> Result<Vec<u8>> {
+            if self.inbound.is_empty() {
+                return Err(DataLinkError::InvalidFrame);
+            }
+            Ok(self.inbound.remove(0))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ethernet_datalink_over_transport() {
+        let local_mac = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+        let peer_mac = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+        let npdu = vec![0x01, 0x00, 0x10, 0x08];
+
+        let mut ipv4 = EthernetFrame::ieee802_3(local_mac, peer_mac, npdu.clone()).encode();
+        ipv4[12..14].copy_from_slice(&[0x08, 0x00]);
+        let mut other_sap = EthernetFrame::ieee802_3(local_mac, peer_mac, npdu.clone()).encode();
+        other_sap[14..16].copy_from_slice(&[0x42, 0x42]);
+        let inbound = vec![
+            ipv4,
+            other_sap,
+            EthernetFrame::ieee802_3([0x02; 6], peer_mac, vec![0xFF]).encode(),
+            EthernetFrame::ieee802_3(local_mac, peer_mac, npdu.clone()).encode(),
+            EthernetFrame::broadcast(peer_mac, vec![0x01, 0x20]).encode(),
+        ];
+        let outbound = Arc::new(Mutex::new(Vec::new()));
+        let wire = Wire {
+            inbound,
+            outbound: outbound.clone(),
+        };
+        let mut datalink = EthernetDataLink::with_transport(wire, local_mac);
+
+        // Only BACnet frames for this station reach the caller, unpadded
+        let (received, source) = datalink.receive_frame().unwrap();
+        assert_eq!(received, npdu);
+        assert_eq!(source, DataLinkAddress::Ethernet(peer_mac));
+        let (received, _) = datalink.receive_frame().unwrap();
+        assert!(received.starts_with(&[0x01, 0x20]));
+        assert!(datalink.receive_frame().is_err());
+
+        datalink
+            .send_frame(&npdu, &DataLinkAddress::Broadcast)
+            .unwrap();
+        let sent = outbound.lock().unwrap();
+        assert_eq!(sent[0].len(), MIN_ETHERNET_FRAME_SIZE);
+        assert_eq!(&sent[0][..6], &ETHERNET_BROADCAST_MAC);
+        assert_eq!(&sent[0][12..17], &[0x00, 0x07, 0x82, 0x82, 0x03]);
+        assert!(validate_ethernet_frame(&sent[0]).is_ok());
+    }
 }
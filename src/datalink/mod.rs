@@ -24,7 +24,7 @@
 //!
 //! ## BACnet/Ethernet (ISO 8802-3)
 //! - Direct Ethernet frame communication
-//! - Uses Ethernet type 0x82DC for BACnet
+//! - IEEE 802.3 frames, or Ethernet type 0x82DC
 //! - LLC header for protocol identification
 //! - Suitable for high-speed local networks
 //!
@@ -43,6 +43,11 @@
 //! - Legacy token-passing network
 //! - Less common in modern installations
 //!
+//! ## BACnet/SC (Annex AB)
+//! - Secure websocket connections to a hub
+//! - Nodes addressed by 6-byte virtual MAC addresses
+//! - TLS and certificates provided by the websocket transport
+//!
 //! # Architecture
 //!
 //! The module uses a trait-based design with the [`DataLink`] trait providing a common
@@ -115,7 +120,7 @@ use core::fmt;
 use std::net::SocketAddr;
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{boxed::Box, string::String, vec::Vec};
 
 /// Result type for data link operations.
 ///
@@ -193,6 +198,18 @@ pub enum DataLinkError {
     /// supported by the current implementation or when mixing incompatible
     /// address types with data link types.
     UnsupportedType,
+
+    /// A BBMD rejected a BVLC request.
+    ///
+    /// Carries the BVLC-Result code returned by the BBMD, for example when
+    /// a Register-Foreign-Device request is refused.
+    BvlcNak(bip::BvlcResultCode),
+
+    /// A BACnet/SC peer rejected a BVLC-SC request.
+    ///
+    /// Carries the error class, code and details of the BVLC-Result, for
+    /// example when a hub refuses a Connect-Request.
+    ScNak(sc::ScNak),
 }
 
 impl fmt::Display for DataLinkError {
@@ -204,6 +221,20 @@ impl fmt::Display for DataLinkError {
             DataLinkError::CrcError => write!(f, "CRC check failed"),
             DataLinkError::AddressError(msg) => write!(f, "Address error: {}", msg),
             DataLinkError::UnsupportedType => write!(f, "Unsupported data link type"),
+            DataLinkError::BvlcNak(code) => {
+                write!(
+                    f,
+                    "BVLC request rejected (result code {:#06X})",
+                    *code as u16
+                )
+            }
+            DataLinkError::ScNak(nak) => {
+                write!(
+                    f,
+                    "BVLC-SC request rejected (error class {}, code {}): {}",
+                    nak.error_class, nak.error_code, nak.details
+                )
+            }
         }
     }
 }
@@ -265,6 +296,13 @@ pub enum DataLinkType {
     /// BACnet standard, it is rarely used in new installations. Included for
     /// compatibility with older systems.
     Arcnet,
+
+    /// BACnet/SC (Annex AB).
+    ///
+    /// Carries BACnet over TLS secured websocket connections to a hub. Suited
+    /// to networks where UDP broadcasts are unavailable or where traffic must
+    /// be authenticated and encrypted.
+    SecureConnect,
 }
 
 /// Common trait for all data link layer implementations.
@@ -469,9 +507,43 @@ pub enum DataLinkAddress {
     /// - BACnet/IP: UDP broadcast or multicast
     /// - Ethernet: FF:FF:FF:FF:FF:FF
     /// - MS/TP: Station address 255
+    /// - BACnet/SC: VMAC FF:FF:FF:FF:FF:FF
     Broadcast,
+
+    /// BACnet/SC virtual MAC address.
+    ///
+    /// Used with BACnet/SC data links, where each node is identified by a
+    /// 6-byte VMAC independent of its network address.
+    Vmac(sc::Vmac),
+}
+
+/// Direction of a frame reported to a [`FrameTrace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The frame was sent by this device.
+    Sent,
+    /// The frame was received by this device.
+    Received,
 }
 
+/// Hook called with the raw bytes of every frame sent or received.
+///
+/// The bytes are the frame as it is on the wire, including the data link
+/// header (such as the BVLC header of BACnet/IP), so they can be compared
+/// with a packet capture or fed to a protocol analyzer.
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::datalink::{Direction, FrameTrace};
+///
+/// let trace: FrameTrace = Box::new(|direction, frame| {
+///     let arrow = if direction == Direction::Sent { "->" } else { "<-" };
+///     println!("{} {:02X?}", arrow, frame);
+/// });
+/// ```
+pub type FrameTrace = Box<dyn FnMut(Direction, &[u8]) + Send>;
+
 /// BACnet/IP (Annex J) implementation.
 ///
 /// This module provides BACnet communication over IP networks using UDP port 47808.
@@ -493,6 +565,13 @@ pub mod ethernet;
 /// its low cost and ability to support long cable runs.
 pub mod mstp;
 
+/// BACnet/SC (Annex AB) implementation.
+///
+/// This module provides BVLC-SC message framing and a data link that carries
+/// it over a pluggable websocket transport. TLS and certificate handling are
+/// left to the transport implementation.
+pub mod sc;
+
 /// Frame validation and analysis utilities.
 ///
 /// This module provides comprehensive validation functions for all supported
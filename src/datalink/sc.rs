@@ -0,0 +1,1155 @@
+//! BACnet Secure Connect Data Link Implementation (ASHRAE 135 Annex AB).
+//!
+//! This module provides the BVLC-SC message framing used by BACnet/SC, and a
+//! data link that carries it over a pluggable websocket transport. BACnet/SC
+//! replaces UDP broadcasts with TLS secured websocket connections to a hub,
+//! which makes it suitable for networks where BACnet/IP cannot be used.
+//!
+//! # Overview
+//!
+//! Every BVLC-SC message starts with a 4-byte header, followed by optional
+//! virtual addresses, optional header options and the function payload:
+//!
+//! - **BVLC Function** (1 byte): The message type
+//! - **Control Flags** (1 byte): Which optional fields are present
+//! - **Message ID** (2 bytes): Matches responses to their requests
+//! - **Originating/Destination VMAC** (6 bytes each, optional)
+//! - **Destination/Data Options** (variable, optional)
+//!
+//! Nodes are addressed by a 6-byte virtual MAC address (VMAC) instead of an
+//! IP address.
+//!
+//! # Transport
+//!
+//! The websocket connection, including TLS and certificate handling, is
+//! supplied by an implementation of [`WebSocketTransport`]. Each BVLC-SC
+//! message travels in one binary websocket message.
+//!
+//! # Hub
+//!
+//! [`ScHub`] is a minimal hub function that relays Encapsulated-NPDUs
+//! between the nodes connected to it, by destination VMAC.
+//!
+//! # Examples
+//!
+//! ```
+//! use bacnet_rs::datalink::sc::{ScMessage, ScPayload, BROADCAST_VMAC};
+//!
+//! let mut message = ScMessage::new(1, ScPayload::EncapsulatedNpdu(vec![0x01, 0x00]));
+//! message.destination = Some(BROADCAST_VMAC);
+//!
+//! let bytes = message.encode().unwrap();
+//! assert_eq!(&bytes[..4], &[0x01, 0x04, 0x00, 0x01]);
+//! assert_eq!(ScMessage::decode(&bytes).unwrap(), message);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+use core::fmt;
+
+use crate::datalink::{DataLink, DataLinkAddress, DataLinkError, DataLinkType, Result};
+
+/// Virtual MAC address of a BACnet/SC node.
+///
+/// A 6-byte address that identifies a node independently of the websocket
+/// connection it uses. Nodes normally pick a random VMAC with the "locally
+/// administered" bit set, e.g. `02:xx:xx:xx:xx:xx`.
+///
+/// # Examples
+///
+/// ```
+/// use bacnet_rs::datalink::sc::{Vmac, BROADCAST_VMAC};
+///
+/// let vmac = Vmac([0x02, 0x00, 0x00, 0x00, 0x00, 0x2A]);
+/// assert_eq!(vmac.to_string(), "02:00:00:00:00:2A");
+/// assert!(!vmac.is_broadcast());
+/// assert!(BROADCAST_VMAC.is_broadcast());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vmac(pub [u8; 6]);
+
+/// The local broadcast VMAC, used as the destination of broadcast NPDUs.
+pub const BROADCAST_VMAC: Vmac = Vmac([0xFF; 6]);
+
+impl Vmac {
+    /// Check if this is the local broadcast VMAC.
+    pub fn is_broadcast(&self) -> bool {
+        *self == BROADCAST_VMAC
+    }
+
+    /// Get the address bytes.
+    pub fn as_bytes(&self) -> &[u8; 6] {
+        &self.0
+    }
+
+    /// Read a VMAC from the start of `data`.
+    fn decode(data: &[u8]) -> Result<Self> {
+        let bytes = data.get(..6).ok_or(DataLinkError::InvalidFrame)?;
+        let mut vmac = [0u8; 6];
+        vmac.copy_from_slice(bytes);
+        Ok(Vmac(vmac))
+    }
+}
+
+impl From<[u8; 6]> for Vmac {
+    fn from(bytes: [u8; 6]) -> Self {
+        Vmac(bytes)
+    }
+}
+
+impl fmt::Display for Vmac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(
+            f,
+            "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+            a, b, c, d, e, g
+        )
+    }
+}
+
+/// Control flag: an originating VMAC is present.
+const ORIGINATING_VMAC_FLAG: u8 = 0x08;
+/// Control flag: a destination VMAC is present.
+const DESTINATION_VMAC_FLAG: u8 = 0x04;
+/// Control flag: destination options are present.
+const DESTINATION_OPTIONS_FLAG: u8 = 0x02;
+/// Control flag: data options are present.
+const DATA_OPTIONS_FLAG: u8 = 0x01;
+
+/// BVLC-SC function codes.
+///
+/// Identifies the type of a BVLC-SC message. Only some of the functions are
+/// represented by [`ScPayload`]; the codes of the others are listed so that
+/// they can be recognised and reported as unsupported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BvlcScFunction {
+    /// BVLC-Result (0x00).
+    ///
+    /// Acknowledges or rejects a request, e.g. a Connect-Request.
+    Result = 0x00,
+
+    /// Encapsulated-NPDU (0x01).
+    ///
+    /// Carries an NPDU to a single node or, with the broadcast VMAC, to all.
+    EncapsulatedNpdu = 0x01,
+
+    /// Address-Resolution (0x02).
+    AddressResolution = 0x02,
+
+    /// Address-Resolution-ACK (0x03).
+    AddressResolutionAck = 0x03,
+
+    /// Advertisement (0x04).
+    Advertisement = 0x04,
+
+    /// Advertisement-Solicitation (0x05).
+    AdvertisementSolicitation = 0x05,
+
+    /// Connect-Request (0x06).
+    ///
+    /// Sent by the initiating peer after the websocket is established.
+    ConnectRequest = 0x06,
+
+    /// Connect-Accept (0x07).
+    ///
+    /// Accepts a Connect-Request and reports the accepting peer's limits.
+    ConnectAccept = 0x07,
+
+    /// Disconnect-Request (0x08).
+    DisconnectRequest = 0x08,
+
+    /// Disconnect-ACK (0x09).
+    DisconnectAck = 0x09,
+
+    /// Heartbeat-Request (0x0A).
+    ///
+    /// Keeps an idle connection alive.
+    HeartbeatRequest = 0x0A,
+
+    /// Heartbeat-ACK (0x0B).
+    HeartbeatAck = 0x0B,
+
+    /// Proprietary-Message (0x0C).
+    ProprietaryMessage = 0x0C,
+}
+
+impl TryFrom<u8> for BvlcScFunction {
+    type Error = DataLinkError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0x00 => Ok(BvlcScFunction::Result),
+            0x01 => Ok(BvlcScFunction::EncapsulatedNpdu),
+            0x02 => Ok(BvlcScFunction::AddressResolution),
+            0x03 => Ok(BvlcScFunction::AddressResolutionAck),
+            0x04 => Ok(BvlcScFunction::Advertisement),
+            0x05 => Ok(BvlcScFunction::AdvertisementSolicitation),
+            0x06 => Ok(BvlcScFunction::ConnectRequest),
+            0x07 => Ok(BvlcScFunction::ConnectAccept),
+            0x08 => Ok(BvlcScFunction::DisconnectRequest),
+            0x09 => Ok(BvlcScFunction::DisconnectAck),
+            0x0A => Ok(BvlcScFunction::HeartbeatRequest),
+            0x0B => Ok(BvlcScFunction::HeartbeatAck),
+            0x0C => Ok(BvlcScFunction::ProprietaryMessage),
+            _ => Err(DataLinkError::UnsupportedType),
+        }
+    }
+}
+
+/// A destination or data header option.
+///
+/// Options are carried through unchanged; this implementation does not act
+/// on any of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderOption {
+    /// Header option type (0-31).
+    pub option_type: u8,
+
+    /// Recipients that do not understand the option must reject the message.
+    pub must_understand: bool,
+
+    /// Option data, if the option carries any.
+    pub data: Option<Vec<u8>>,
+}
+
+/// Negative result of a BVLC-SC request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScNak {
+    /// BACnet error class.
+    pub error_class: u16,
+
+    /// BACnet error code.
+    pub error_code: u16,
+
+    /// Human readable details, possibly empty.
+    pub details: String,
+}
+
+/// Parameters exchanged in Connect-Request and Connect-Accept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectInfo {
+    /// VMAC of the sending peer.
+    pub vmac: Vmac,
+
+    /// Device UUID of the sending peer.
+    pub device_uuid: [u8; 16],
+
+    /// Largest BVLC-SC message the sender accepts, in bytes.
+    pub max_bvlc_length: u16,
+
+    /// Largest NPDU the sender accepts, in bytes.
+    pub max_npdu_length: u16,
+}
+
+impl ConnectInfo {
+    fn encode(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(self.vmac.as_bytes());
+        buffer.extend_from_slice(&self.device_uuid);
+        buffer.extend_from_slice(&self.max_bvlc_length.to_be_bytes());
+        buffer.extend_from_slice(&self.max_npdu_length.to_be_bytes());
+    }
+
+    fn decode(payload: &[u8]) -> Result<Self> {
+        if payload.len() != 26 {
+            return Err(DataLinkError::InvalidFrame);
+        }
+        let vmac = Vmac::decode(payload)?;
+        let mut device_uuid = [0u8; 16];
+        device_uuid.copy_from_slice(&payload[6..22]);
+        Ok(Self {
+            vmac,
+            device_uuid,
+            max_bvlc_length: u16::from_be_bytes([payload[22], payload[23]]),
+            max_npdu_length: u16::from_be_bytes([payload[24], payload[25]]),
+        })
+    }
+}
+
+/// Function-specific payload of a BVLC-SC message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScPayload {
+    /// BVLC-Result for a request of the given function.
+    Result {
+        /// Function of the request this result answers.
+        result_for: BvlcScFunction,
+        /// `None` for an ACK, or the reason for a NAK.
+        nak: Option<ScNak>,
+    },
+
+    /// Encapsulated-NPDU carrying an NPDU.
+    EncapsulatedNpdu(Vec<u8>),
+
+    /// Connect-Request with the initiating peer's parameters.
+    ConnectRequest(ConnectInfo),
+
+    /// Connect-Accept with the accepting peer's parameters.
+    ConnectAccept(ConnectInfo),
+
+    /// Heartbeat-Request.
+    HeartbeatRequest,
+
+    /// Heartbeat-ACK.
+    HeartbeatAck,
+}
+
+impl ScPayload {
+    /// Get the BVLC-SC function code for this payload.
+    pub fn function(&self) -> BvlcScFunction {
+        match self {
+            ScPayload::Result { .. } => BvlcScFunction::Result,
+            ScPayload::EncapsulatedNpdu(_) => BvlcScFunction::EncapsulatedNpdu,
+            ScPayload::ConnectRequest(_) => BvlcScFunction::ConnectRequest,
+            ScPayload::ConnectAccept(_) => BvlcScFunction::ConnectAccept,
+            ScPayload::HeartbeatRequest => BvlcScFunction::HeartbeatRequest,
+            ScPayload::HeartbeatAck => BvlcScFunction::HeartbeatAck,
+        }
+    }
+}
+
+/// A complete BVLC-SC message.
+///
+/// Responses carry the message ID of the request they answer; new requests
+/// use a fresh ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScMessage {
+    /// Message ID.
+    pub message_id: u16,
+
+    /// VMAC of the node the message came from, if present.
+    pub originating: Option<Vmac>,
+
+    /// VMAC of the node the message is for, if present.
+    pub destination: Option<Vmac>,
+
+    /// Destination options.
+    pub destination_options: Vec<HeaderOption>,
+
+    /// Data options.
+    pub data_options: Vec<HeaderOption>,
+
+    /// Function-specific payload.
+    pub payload: ScPayload,
+}
+
+impl ScMessage {
+    /// Create a message without virtual addresses or header options.
+    pub fn new(message_id: u16, payload: ScPayload) -> Self {
+        Self {
+            message_id,
+            originating: None,
+            destination: None,
+            destination_options: Vec::new(),
+            data_options: Vec::new(),
+            payload,
+        }
+    }
+
+    /// Encode the message to wire format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::InvalidFrame`] if a header option type does
+    /// not fit in 5 bits or its data does not fit the 16-bit length field.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut flags = 0;
+        if self.originating.is_some() {
+            flags |= ORIGINATING_VMAC_FLAG;
+        }
+        if self.destination.is_some() {
+            flags |= DESTINATION_VMAC_FLAG;
+        }
+        if !self.destination_options.is_empty() {
+            flags |= DESTINATION_OPTIONS_FLAG;
+        }
+        if !self.data_options.is_empty() {
+            flags |= DATA_OPTIONS_FLAG;
+        }
+
+        let mut buffer = vec![self.payload.function() as u8, flags];
+        buffer.extend_from_slice(&self.message_id.to_be_bytes());
+        if let Some(vmac) = &self.originating {
+            buffer.extend_from_slice(vmac.as_bytes());
+        }
+        if let Some(vmac) = &self.destination {
+            buffer.extend_from_slice(vmac.as_bytes());
+        }
+        encode_options(&mut buffer, &self.destination_options)?;
+        encode_options(&mut buffer, &self.data_options)?;
+
+        match &self.payload {
+            ScPayload::Result { result_for, nak } => {
+                buffer.push(*result_for as u8);
+                match nak {
+                    None => buffer.push(0x00),
+                    Some(nak) => {
+                        buffer.push(0x01);
+                        // Error header marker: no header option in error
+                        buffer.push(0x00);
+                        buffer.extend_from_slice(&nak.error_class.to_be_bytes());
+                        buffer.extend_from_slice(&nak.error_code.to_be_bytes());
+                        buffer.extend_from_slice(nak.details.as_bytes());
+                    }
+                }
+            }
+            ScPayload::EncapsulatedNpdu(npdu) => buffer.extend_from_slice(npdu),
+            ScPayload::ConnectRequest(info) | ScPayload::ConnectAccept(info) => {
+                info.encode(&mut buffer)
+            }
+            ScPayload::HeartbeatRequest | ScPayload::HeartbeatAck => {}
+        }
+
+        Ok(buffer)
+    }
+
+    /// Decode a complete BVLC-SC message from wire format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::InvalidFrame`] if the message is truncated
+    /// or malformed. Returns [`DataLinkError::UnsupportedType`] for
+    /// functions not represented by [`ScPayload`].
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        if data.len() < 4 {
+            return Err(DataLinkError::InvalidFrame);
+        }
+        let function = BvlcScFunction::try_from(data[0])?;
+        let flags = data[1];
+        if flags & 0xF0 != 0 {
+            return Err(DataLinkError::InvalidFrame);
+        }
+        let message_id = u16::from_be_bytes([data[2], data[3]]);
+        let mut pos = 4;
+
+        let mut read_vmac = |present: bool| -> Result<Option<Vmac>> {
+            if !present {
+                return Ok(None);
+            }
+            let vmac = Vmac::decode(&data[pos..])?;
+            pos += 6;
+            Ok(Some(vmac))
+        };
+        let originating = read_vmac(flags & ORIGINATING_VMAC_FLAG != 0)?;
+        let destination = read_vmac(flags & DESTINATION_VMAC_FLAG != 0)?;
+
+        let destination_options = if flags & DESTINATION_OPTIONS_FLAG != 0 {
+            decode_options(data, &mut pos)?
+        } else {
+            Vec::new()
+        };
+        let data_options = if flags & DATA_OPTIONS_FLAG != 0 {
+            decode_options(data, &mut pos)?
+        } else {
+            Vec::new()
+        };
+
+        let payload = &data[pos..];
+        let payload = match function {
+            BvlcScFunction::Result => decode_result(payload)?,
+            BvlcScFunction::EncapsulatedNpdu => ScPayload::EncapsulatedNpdu(payload.to_vec()),
+            BvlcScFunction::ConnectRequest => {
+                ScPayload::ConnectRequest(ConnectInfo::decode(payload)?)
+            }
+            BvlcScFunction::ConnectAccept => {
+                ScPayload::ConnectAccept(ConnectInfo::decode(payload)?)
+            }
+            BvlcScFunction::HeartbeatRequest if payload.is_empty() => ScPayload::HeartbeatRequest,
+            BvlcScFunction::HeartbeatAck if payload.is_empty() => ScPayload::HeartbeatAck,
+            BvlcScFunction::HeartbeatRequest | BvlcScFunction::HeartbeatAck => {
+                return Err(DataLinkError::InvalidFrame)
+            }
+            _ => return Err(DataLinkError::UnsupportedType),
+        };
+
+        Ok(Self {
+            message_id,
+            originating,
+            destination,
+            destination_options,
+            data_options,
+            payload,
+        })
+    }
+}
+
+/// Encode a list of header options, marking all but the last as followed by more.
+fn encode_options(buffer: &mut Vec<u8>, options: &[HeaderOption]) -> Result<()> {
+    for (index, option) in options.iter().enumerate() {
+        if option.option_type > 0x1F {
+            return Err(DataLinkError::InvalidFrame);
+        }
+        let mut marker = option.option_type;
+        if index + 1 < options.len() {
+            marker |= 0x80;
+        }
+        if option.must_understand {
+            marker |= 0x40;
+        }
+        if option.data.is_some() {
+            marker |= 0x20;
+        }
+        buffer.push(marker);
+        if let Some(data) = &option.data {
+            let length = u16::try_from(data.len()).map_err(|_| DataLinkError::InvalidFrame)?;
+            buffer.extend_from_slice(&length.to_be_bytes());
+            buffer.extend_from_slice(data);
+        }
+    }
+    Ok(())
+}
+
+/// Decode header options starting at `pos` until one is not followed by more.
+fn decode_options(data: &[u8], pos: &mut usize) -> Result<Vec<HeaderOption>> {
+    let mut options = Vec::new();
+    loop {
+        let marker = *data.get(*pos).ok_or(DataLinkError::InvalidFrame)?;
+        *pos += 1;
+        let option_data = if marker & 0x20 != 0 {
+            let length = data
+                .get(*pos..*pos + 2)
+                .ok_or(DataLinkError::InvalidFrame)?;
+            let length = u16::from_be_bytes([length[0], length[1]]) as usize;
+            *pos += 2;
+            let bytes = data
+                .get(*pos..*pos + length)
+                .ok_or(DataLinkError::InvalidFrame)?;
+            *pos += length;
+            Some(bytes.to_vec())
+        } else {
+            None
+        };
+        options.push(HeaderOption {
+            option_type: marker & 0x1F,
+            must_understand: marker & 0x40 != 0,
+            data: option_data,
+        });
+        if marker & 0x80 == 0 {
+            return Ok(options);
+        }
+    }
+}
+
+/// Decode a BVLC-Result payload.
+fn decode_result(payload: &[u8]) -> Result<ScPayload> {
+    if payload.len() < 2 {
+        return Err(DataLinkError::InvalidFrame);
+    }
+    let result_for = BvlcScFunction::try_from(payload[0])?;
+    let nak = match payload[1] {
+        0x00 if payload.len() == 2 => None,
+        0x01 if payload.len() >= 7 => {
+            // payload[2] is the error header marker, which is not used
+            let details =
+                core::str::from_utf8(&payload[7..]).map_err(|_| DataLinkError::InvalidFrame)?;
+            Some(ScNak {
+                error_class: u16::from_be_bytes([payload[3], payload[4]]),
+                error_code: u16::from_be_bytes([payload[5], payload[6]]),
+                details: details.into(),
+            })
+        }
+        _ => return Err(DataLinkError::InvalidFrame),
+    };
+    Ok(ScPayload::Result { result_for, nak })
+}
+
+/// A websocket connection carrying BVLC-SC messages.
+///
+/// Implementations own the websocket, including the TLS handshake and
+/// certificate validation required by BACnet/SC. Each call transfers one
+/// complete binary websocket message.
+pub trait WebSocketTransport: Send + Sync {
+    /// Send one binary websocket message.
+    fn send_message(&mut self, message: &[u8]) -> Result<()>;
+
+    /// Receive the next binary websocket message.
+    ///
+    /// Blocking behaviour and timeouts are up to the implementation.
+    fn receive_message(&mut self) -> Result<Vec<u8>>;
+}
+
+/// BACnet/SC data link for a node connected to a hub.
+///
+/// Sends and receives NPDUs as Encapsulated-NPDU messages, and answers
+/// Heartbeat-Requests from the hub while receiving.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bacnet_rs::datalink::sc::{ScDataLink, WebSocketTransport};
+/// use bacnet_rs::datalink::Result;
+///
+/// # fn example(transport: impl WebSocketTransport) -> Result<()> {
+/// use bacnet_rs::datalink::sc::Vmac;
+///
+/// let vmac = Vmac([0x02, 0, 0, 0, 0, 0x01]);
+/// let mut data_link = ScDataLink::new(transport, vmac, [0x11; 16]);
+/// let hub = data_link.connect()?;
+/// println!("Connected to hub {}", hub.vmac);
+/// # Ok(())
+/// # }
+/// ```
+pub struct ScDataLink<T: WebSocketTransport> {
+    transport: T,
+    connect_info: ConnectInfo,
+    next_message_id: u16,
+    hub: Option<ConnectInfo>,
+}
+
+impl<T: WebSocketTransport> ScDataLink<T> {
+    /// Create a data link over `transport` for the node with the given VMAC
+    /// and device UUID.
+    ///
+    /// The node advertises the minimum lengths every BACnet/SC node must
+    /// accept: 1600 octet BVLC-SC messages carrying 1497 octet NPDUs.
+    pub fn new(transport: T, vmac: Vmac, device_uuid: [u8; 16]) -> Self {
+        Self {
+            transport,
+            connect_info: ConnectInfo {
+                vmac,
+                device_uuid,
+                max_bvlc_length: 1600,
+                max_npdu_length: 1497,
+            },
+            next_message_id: 0,
+            hub: None,
+        }
+    }
+
+    /// Get the parameters reported by the hub, once connected.
+    pub fn hub(&self) -> Option<&ConnectInfo> {
+        self.hub.as_ref()
+    }
+
+    /// Send a Connect-Request and wait for the hub to accept it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DataLinkError::ScNak`] if the hub rejects the connection.
+    pub fn connect(&mut self) -> Result<ConnectInfo> {
+        let message_id = self.send_request(ScPayload::ConnectRequest(self.connect_info))?;
+        match self.wait_for_response(message_id, BvlcScFunction::ConnectRequest)? {
+            ScPayload::ConnectAccept(info) => {
+                self.hub = Some(info);
+                Ok(info)
+            }
+            _ => Err(DataLinkError::InvalidFrame),
+        }
+    }
+
+    /// Send a Heartbeat-Request and wait for its acknowledgement.
+    pub fn heartbeat(&mut self) -> Result<()> {
+        let message_id = self.send_request(ScPayload::HeartbeatRequest)?;
+        match self.wait_for_response(message_id, BvlcScFunction::HeartbeatRequest)? {
+            ScPayload::HeartbeatAck => Ok(()),
+            _ => Err(DataLinkError::InvalidFrame),
+        }
+    }
+
+    /// Send an NPDU to a node, or to all nodes with [`BROADCAST_VMAC`].
+    pub fn send_npdu(&mut self, npdu: &[u8], destination: Vmac) -> Result<()> {
+        let mut message = ScMessage::new(
+            self.take_message_id(),
+            ScPayload::EncapsulatedNpdu(npdu.to_vec()),
+        );
+        message.destination = Some(destination);
+        self.transport.send_message(&message.encode()?)
+    }
+
+    /// Receive the next NPDU and the VMAC of its sender.
+    ///
+    /// Heartbeat-Requests received meanwhile are acknowledged, and other
+    /// messages are skipped.
+    pub fn receive_npdu(&mut self) -> Result<(Vec<u8>, Vmac)> {
+        loop {
+            let message = self.receive()?;
+            if let ScPayload::EncapsulatedNpdu(npdu) = message.payload {
+                // Messages relayed by the hub carry the originating VMAC
+                let source = message.originating.ok_or(DataLinkError::InvalidFrame)?;
+                return Ok((npdu, source));
+            }
+        }
+    }
+
+    fn take_message_id(&mut self) -> u16 {
+        let message_id = self.next_message_id;
+        self.next_message_id = self.next_message_id.wrapping_add(1);
+        message_id
+    }
+
+    fn send_request(&mut self, payload: ScPayload) -> Result<u16> {
+        let message_id = self.take_message_id();
+        let message = ScMessage::new(message_id, payload);
+        self.transport.send_message(&message.encode()?)?;
+        Ok(message_id)
+    }
+
+    /// Receive messages until the response to request `message_id` arrives.
+    fn wait_for_response(&mut self, message_id: u16, request: BvlcScFunction) -> Result<ScPayload> {
+        loop {
+            let message = self.receive()?;
+            if message.message_id != message_id {
+                continue;
+            }
+            match message.payload {
+                ScPayload::Result {
+                    result_for,
+                    nak: Some(nak),
+                } if result_for == request => return Err(DataLinkError::ScNak(nak)),
+                ScPayload::Result { .. } => continue,
+                payload => return Ok(payload),
+            }
+        }
+    }
+
+    /// Receive one message, acknowledging Heartbeat-Requests.
+    fn receive(&mut self) -> Result<ScMessage> {
+        let message = ScMessage::decode(&self.transport.receive_message()?)?;
+        if message.payload == ScPayload::HeartbeatRequest {
+            let ack = ScMessage::new(message.message_id, ScPayload::HeartbeatAck);
+            self.transport.send_message(&ack.encode()?)?;
+        }
+        Ok(message)
+    }
+}
+
+impl<T: WebSocketTransport> DataLink for ScDataLink<T> {
+    fn send_frame(&mut self, frame: &[u8], dest: &DataLinkAddress) -> Result<()> {
+        let destination = match dest {
+            DataLinkAddress::Vmac(vmac) => *vmac,
+            DataLinkAddress::Broadcast => BROADCAST_VMAC,
+            _ => {
+                return Err(DataLinkError::AddressError(
+                    "BACnet/SC requires a VMAC address".into(),
+                ))
+            }
+        };
+        self.send_npdu(frame, destination)
+    }
+
+    fn receive_frame(&mut self) -> Result<(Vec<u8>, DataLinkAddress)> {
+        let (npdu, source) = self.receive_npdu()?;
+        Ok((npdu, DataLinkAddress::Vmac(source)))
+    }
+
+    fn link_type(&self) -> DataLinkType {
+        DataLinkType::SecureConnect
+    }
+
+    fn local_address(&self) -> DataLinkAddress {
+        DataLinkAddress::Vmac(self.connect_info.vmac)
+    }
+}
+
+/// Error class COMMUNICATION, used in BVLC-SC NAKs.
+const ERROR_CLASS_COMMUNICATION: u16 = 7;
+
+/// Error code NODE_DUPLICATE_VMAC, sent when a node's VMAC is already in use.
+const ERROR_CODE_NODE_DUPLICATE_VMAC: u16 = 151;
+
+/// A websocket connection accepted by an [`ScHub`].
+struct HubConnection<T: WebSocketTransport> {
+    transport: T,
+    /// Parameters of the node, once its Connect-Request has been accepted.
+    peer: Option<ConnectInfo>,
+}
+
+/// Minimal BACnet/SC hub function.
+///
+/// Relays Encapsulated-NPDU messages between the nodes connected to it:
+/// unicast messages go to the connection whose node has the destination
+/// VMAC, and messages to [`BROADCAST_VMAC`] go to every other connected node.
+/// Relayed messages carry the sender's VMAC as originating VMAC. Messages
+/// for unknown VMACs are discarded.
+///
+/// The hub does not own any sockets. Connections are added with
+/// [`accept`](Self::accept) once their websocket is established, and each
+/// message is handled by [`poll`](Self::poll) for the connection it arrived
+/// on, so the caller decides how connections are serviced.
+///
+/// # Examples
+///
+/// ```no_run
+/// use bacnet_rs::datalink::sc::{ScHub, Vmac, WebSocketTransport};
+/// use bacnet_rs::datalink::Result;
+///
+/// # fn example<T: WebSocketTransport>(first: T, second: T) -> Result<()> {
+/// let mut hub = ScHub::new(Vmac([0x02, 0, 0, 0, 0, 0xFE]), [0x22; 16]);
+/// let connections = [hub.accept(first), hub.accept(second)];
+/// loop {
+///     for &connection in &connections {
+///         hub.poll(connection)?;
+///     }
+/// }
+/// # }
+/// ```
+pub struct ScHub<T: WebSocketTransport> {
+    connect_info: ConnectInfo,
+    connections: Vec<Option<HubConnection<T>>>,
+}
+
+impl<T: WebSocketTransport> ScHub<T> {
+    /// Create a hub with the given VMAC and device UUID.
+    pub fn new(vmac: Vmac, device_uuid: [u8; 16]) -> Self {
+        Self {
+            connect_info: ConnectInfo {
+                vmac,
+                device_uuid,
+                max_bvlc_length: 1600,
+                max_npdu_length: 1497,
+            },
+            connections: Vec::new(),
+        }
+    }
+
+    /// Add an established websocket connection, returning its handle.
+    ///
+    /// The node on the connection is not reachable until it has sent a
+    /// Connect-Request.
+    pub fn accept(&mut self, transport: T) -> usize {
+        let connection = Some(HubConnection {
+            transport,
+            peer: None,
+        });
+        match self.connections.iter().position(Option::is_none) {
+            Some(index) => {
+                self.connections[index] = connection;
+                index
+            }
+            None => {
+                self.connections.push(connection);
+                self.connections.len() - 1
+            }
+        }
+    }
+
+    /// Remove a connection, returning its transport.
+    pub fn disconnect(&mut self, connection: usize) -> Option<T> {
+        self.connections
+            .get_mut(connection)?
+            .take()
+            .map(|connection| connection.transport)
+    }
+
+    /// Get the VMAC of the node on a connection, once it has connected.
+    pub fn peer_vmac(&self, connection: usize) -> Option<Vmac> {
+        let connection = self.connections.get(connection)?.as_ref()?;
+        connection.peer.map(|peer| peer.vmac)
+    }
+
+    /// Find the connection of the node with the given VMAC.
+    pub fn find_connection(&self, vmac: Vmac) -> Option<usize> {
+        (0..self.connections.len()).find(|&index| self.peer_vmac(index) == Some(vmac))
+    }
+
+    /// Receive one message on a connection and handle it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection does not exist or receiving fails.
+    pub fn poll(&mut self, connection: usize) -> Result<()> {
+        let data = self
+            .connection_mut(connection)?
+            .transport
+            .receive_message()?;
+        self.handle_message(connection, &data)
+    }
+
+    /// Handle a message received on a connection.
+    ///
+    /// Connect-Requests are accepted unless another node already uses the
+    /// VMAC, Heartbeat-Requests are acknowledged and Encapsulated-NPDUs are
+    /// relayed. NPDUs from connections that have not connected yet and other
+    /// messages are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message is malformed or sending fails.
+    pub fn handle_message(&mut self, connection: usize, data: &[u8]) -> Result<()> {
+        let message = ScMessage::decode(data)?;
+        match message.payload {
+            ScPayload::ConnectRequest(info) => {
+                let duplicate = self
+                    .find_connection(info.vmac)
+                    .is_some_and(|other| other != connection);
+                let payload = if duplicate || info.vmac.is_broadcast() {
+                    ScPayload::Result {
+                        result_for: BvlcScFunction::ConnectRequest,
+                        nak: Some(ScNak {
+                            error_class: ERROR_CLASS_COMMUNICATION,
+                            error_code: ERROR_CODE_NODE_DUPLICATE_VMAC,
+                            details: String::new(),
+                        }),
+                    }
+                } else {
+                    self.connection_mut(connection)?.peer = Some(info);
+                    ScPayload::ConnectAccept(self.connect_info)
+                };
+                self.send(connection, &ScMessage::new(message.message_id, payload))
+            }
+            ScPayload::HeartbeatRequest => {
+                let ack = ScMessage::new(message.message_id, ScPayload::HeartbeatAck);
+                self.send(connection, &ack)
+            }
+            ScPayload::EncapsulatedNpdu(npdu) => {
+                let (Some(source), Some(destination)) =
+                    (self.peer_vmac(connection), message.destination)
+                else {
+                    return Ok(());
+                };
+                let mut relayed =
+                    ScMessage::new(message.message_id, ScPayload::EncapsulatedNpdu(npdu));
+                relayed.originating = Some(source);
+                relayed.data_options = message.data_options;
+                if destination.is_broadcast() {
+                    relayed.destination = Some(BROADCAST_VMAC);
+                    let data = relayed.encode()?;
+                    for index in 0..self.connections.len() {
+                        if index != connection && self.peer_vmac(index).is_some() {
+                            self.connection_mut(index)?.transport.send_message(&data)?;
+                        }
+                    }
+                    Ok(())
+                } else {
+                    match self.find_connection(destination) {
+                        Some(target) => self.send(target, &relayed),
+                        None => Ok(()),
+                    }
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn connection_mut(&mut self, connection: usize) -> Result<&mut HubConnection<T>> {
+        self.connections
+            .get_mut(connection)
+            .and_then(Option::as_mut)
+            .ok_or_else(|| DataLinkError::AddressError("Unknown hub connection".into()))
+    }
+
+    fn send(&mut self, connection: usize, message: &ScMessage) -> Result<()> {
+        let data = message.encode()?;
+        self.connection_mut(connection)?
+            .transport
+            .send_message(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_sc_message_wire_format() {
+        let info = ConnectInfo {
+            vmac: Vmac([0x02, 0x00, 0x00, 0x00, 0x00, 0x01]),
+            device_uuid: [0xAB; 16],
+            max_bvlc_length: 1600,
+            max_npdu_length: 1497,
+        };
+        let bytes = ScMessage::new(0x1234, ScPayload::ConnectRequest(info))
+            .encode()
+            .unwrap();
+        assert_eq!(&bytes[..4], &[0x06, 0x00, 0x12, 0x34]);
+        assert_eq!(&bytes[4..10], info.vmac.as_bytes());
+        assert_eq!(&bytes[26..], &[0x06, 0x40, 0x05, 0xD9]);
+
+        let mut message = ScMessage::new(7, ScPayload::EncapsulatedNpdu(vec![0x01, 0x00]));
+        message.originating = Some(Vmac([0x02, 0, 0, 0, 0, 0x09]));
+        message.destination = Some(BROADCAST_VMAC);
+        message.data_options = vec![
+            HeaderOption {
+                option_type: 31,
+                must_understand: false,
+                data: Some(vec![0xAA]),
+            },
+            HeaderOption {
+                option_type: 1,
+                must_understand: true,
+                data: None,
+            },
+        ];
+        let bytes = message.encode().unwrap();
+        assert_eq!(&bytes[..4], &[0x01, 0x0D, 0x00, 0x07]);
+        assert_eq!(&bytes[16..], &[0xBF, 0x00, 0x01, 0xAA, 0x41, 0x01, 0x00]);
+        assert_eq!(ScMessage::decode(&bytes).unwrap(), message);
+
+        let nak = ScMessage::new(
+            3,
+            ScPayload::Result {
+                result_for: BvlcScFunction::ConnectRequest,
+                nak: Some(ScNak {
+                    error_class: 7,
+                    error_code: 0x0087,
+                    details: "duplicate VMAC".into(),
+                }),
+            },
+        );
+        assert_eq!(ScMessage::decode(&nak.encode().unwrap()).unwrap(), nak);
+
+        // Truncated VMAC, unterminated options and unsupported functions
+        assert!(ScMessage::decode(&[0x01, 0x08, 0x00, 0x01, 0x02]).is_err());
+        assert!(ScMessage::decode(&[0x01, 0x01, 0x00, 0x01, 0x81]).is_err());
+        assert!(matches!(
+            ScMessage::decode(&[0x08, 0x00, 0x00, 0x01]),
+            Err(DataLinkError::UnsupportedType)
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Default)]
+    struct Loopback {
+        inbound: VecDeque<Vec<u8>>,
+        outbound: Vec<ScMessage>,
+    }
+
+    #[cfg(feature = "std")]
+    impl WebSocketTransport for Loopback {
+        fn send_message(&mut self, message: &[u8]) -> Result<()> {
+            self.outbound.push(ScMessage::decode(message)?);
+            Ok(())
+        }
+
+        fn receive_message(&mut self) -> Result<Vec<u8>> {
+            self.inbound
+                .pop_front()
+                .ok_or(DataLinkError::AddressError("closed".into()))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sc_data_link_connects_and_answers_heartbeats() {
+        let hub = ConnectInfo {
+            vmac: Vmac([0x02, 0, 0, 0, 0, 0xFE]),
+            device_uuid: [0x22; 16],
+            max_bvlc_length: 4000,
+            max_npdu_length: 1497,
+        };
+        let mut relayed = ScMessage::new(40, ScPayload::EncapsulatedNpdu(vec![0x01, 0x00]));
+        relayed.originating = Some(Vmac([0x02, 0, 0, 0, 0, 0x07]));
+
+        let mut transport = Loopback::default();
+        for message in [
+            ScMessage::new(0, ScPayload::ConnectAccept(hub)),
+            ScMessage::new(9, ScPayload::HeartbeatRequest),
+            relayed,
+        ] {
+            transport.inbound.push_back(message.encode().unwrap());
+        }
+
+        let vmac = Vmac([0x02, 0, 0, 0, 0, 0x01]);
+        let mut data_link = ScDataLink::new(transport, vmac, [0x11; 16]);
+        assert_eq!(data_link.connect().unwrap(), hub);
+        assert_eq!(data_link.hub(), Some(&hub));
+
+        let (npdu, source) = data_link.receive_frame().unwrap();
+        assert_eq!(npdu, vec![0x01, 0x00]);
+        assert_eq!(
+            source,
+            DataLinkAddress::Vmac(Vmac([0x02, 0, 0, 0, 0, 0x07]))
+        );
+
+        data_link
+            .send_frame(&[0x01, 0x20], &DataLinkAddress::Broadcast)
+            .unwrap();
+        let outbound = &data_link.transport.outbound;
+        assert!(
+            matches!(&outbound[0].payload, ScPayload::ConnectRequest(info) if info.vmac == vmac)
+        );
+        // The heartbeat is acknowledged with the hub's message ID
+        assert_eq!(outbound[1], ScMessage::new(9, ScPayload::HeartbeatAck));
+        assert_eq!(outbound[2].destination, Some(BROADCAST_VMAC));
+        assert_eq!(outbound[2].message_id, 1);
+        assert_eq!(data_link.local_address(), DataLinkAddress::Vmac(vmac));
+    }
+
+    #[cfg(feature = "std")]
+    struct Pipe {
+        sender: std::sync::Mutex<std::sync::mpsc::Sender<Vec<u8>>>,
+        receiver: std::sync::Mutex<std::sync::mpsc::Receiver<Vec<u8>>>,
+    }
+
+    #[cfg(feature = "std")]
+    fn pipe() -> (Pipe, Pipe) {
+        let (to_hub, from_node) = std::sync::mpsc::channel();
+        let (to_node, from_hub) = std::sync::mpsc::channel();
+        let end = |sender, receiver| Pipe {
+            sender: std::sync::Mutex::new(sender),
+            receiver: std::sync::Mutex::new(receiver),
+        };
+        (end(to_hub, from_hub), end(to_node, from_node))
+    }
+
+    #[cfg(feature = "std")]
+    impl WebSocketTransport for Pipe {
+        fn send_message(&mut self, message: &[u8]) -> Result<()> {
+            let sender = self.sender.lock().unwrap();
+            sender
+                .send(message.to_vec())
+                .map_err(|_| DataLinkError::AddressError("closed".into()))
+        }
+
+        fn receive_message(&mut self) -> Result<Vec<u8>> {
+            let receiver = self.receiver.lock().unwrap();
+            receiver
+                .recv_timeout(std::time::Duration::from_secs(5))
+                .map_err(|_| DataLinkError::AddressError("closed".into()))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_sc_hub_relays_between_nodes() {
+        let mut hub = ScHub::new(Vmac([0x02, 0, 0, 0, 0, 0xFE]), [0x22; 16]);
+        let mut connect = |vmac: Vmac| {
+            let (node_end, hub_end) = pipe();
+            let connection = hub.accept(hub_end);
+            let node = std::thread::spawn(move || {
+                let mut node = ScDataLink::new(node_end, vmac, [0x11; 16]);
+                node.connect().map(|_| node)
+            });
+            hub.poll(connection).unwrap();
+            (connection, node.join().unwrap())
+        };
+        let a_vmac = Vmac([0x02, 0, 0, 0, 0, 0x0A]);
+        let b_vmac = Vmac([0x02, 0, 0, 0, 0, 0x0B]);
+        let (a_connection, a) = connect(a_vmac);
+        let (b_connection, b) = connect(b_vmac);
+        let (_, duplicate) = connect(a_vmac);
+        let (mut a, mut b) = (a.unwrap(), b.unwrap());
+        assert!(matches!(
+            duplicate,
+            Err(DataLinkError::ScNak(ScNak {
+                error_code: ERROR_CODE_NODE_DUPLICATE_VMAC,
+                ..
+            }))
+        ));
+        assert_eq!(hub.find_connection(b_vmac), Some(b_connection));
+
+        a.send_frame(&[0x01, 0x04, 0xAA], &DataLinkAddress::Vmac(b_vmac))
+            .unwrap();
+        hub.poll(a_connection).unwrap();
+        let (npdu, source) = b.receive_frame().unwrap();
+        assert_eq!(npdu, vec![0x01, 0x04, 0xAA]);
+        assert_eq!(source, DataLinkAddress::Vmac(a_vmac));
+
+        // Broadcasts reach every node but the sender
+        b.send_frame(&[0x01, 0x20], &DataLinkAddress::Broadcast)
+            .unwrap();
+        hub.poll(b_connection).unwrap();
+        assert_eq!(a.receive_npdu().unwrap(), (vec![0x01, 0x20], b_vmac));
+
+        // Unknown destinations are discarded
+        a.send_npdu(&[0x01, 0x00], Vmac([0x02, 0, 0, 0, 0, 0x0C]))
+            .unwrap();
+        hub.poll(a_connection).unwrap();
+        assert!(hub.disconnect(a_connection).is_some());
+        assert_eq!(hub.find_connection(a_vmac), None);
+    }
+}
@@ -12,6 +12,14 @@
 //! - Protocol-specific checks
 //! - Common error detection patterns
 
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
 use crate::datalink::DataLinkType;
 use crate::util::crc16_mstp;
 
@@ -172,13 +180,20 @@ pub fn validate_ethernet_frame(data: &[u8]) -> ValidationResult {
         });
     }
 
-    // Check Ethernet type for BACnet
+    // Check Ethernet type for BACnet, or the IEEE 802.3 length
     let ether_type = ((data[12] as u16) << 8) | (data[13] as u16);
-    if ether_type != 0x82DC {
+    if ether_type <= 1500 {
+        if ether_type < 3 || data.len() - 14 < ether_type as usize {
+            result.is_valid = false;
+            result.errors.push(ValidationError::InvalidHeader {
+                reason: format!("Invalid 802.3 length: {}", ether_type),
+            });
+        }
+    } else if ether_type != 0x82DC {
         result.is_valid = false;
         result.errors.push(ValidationError::InvalidHeader {
             reason: format!(
-                "Invalid Ethernet type: 0x{:04X}, expected 0x82DC",
+                "Invalid Ethernet type: 0x{:04X}, expected 0x82DC or an 802.3 length",
                 ether_type
             ),
         });
@@ -371,10 +386,10 @@ pub fn validate_frame(data: &[u8]) -> ValidationResult {
         return validate_bacnet_ip_frame(data);
     }
 
-    // Check for Ethernet (has BACnet Ethernet type at offset 12-13)
+    // Check for Ethernet (BACnet Ethernet type, or 802.3 length and BACnet LLC)
     if data.len() >= 14 {
         let ether_type = ((data[12] as u16) << 8) | (data[13] as u16);
-        if ether_type == 0x82DC {
+        if ether_type == 0x82DC || (ether_type <= 1500 && data.get(14) == Some(&0x82)) {
             return validate_ethernet_frame(data);
         }
     }
@@ -490,7 +505,7 @@ fn calculate_frame_statistics(data: &[u8]) -> FrameStatistics {
     for count in byte_distribution.iter() {
         if *count > 0 {
             let probability = *count as f64 / total;
-            entropy -= probability * probability.log2();
+            entropy -= probability * log2(probability);
         }
     }
 
@@ -606,6 +621,36 @@ fn detect_patterns(data: &[u8]) -> Vec<Pattern> {
     patterns
 }
 
+/// Base-2 logarithm
+#[cfg(feature = "std")]
+fn log2(x: f64) -> f64 {
+    x.log2()
+}
+
+/// Base-2 logarithm of a positive normal value, for builds without std
+///
+/// The exponent comes from the bits; the mantissa's logarithm from the series
+/// ln(m) = 2 atanh((m - 1) / (m + 1)).
+#[cfg(any(test, not(feature = "std")))]
+fn log2_fallback(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7FF) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & 0x000F_FFFF_FFFF_FFFF) | 0x3FF0_0000_0000_0000);
+
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let z2 = z * z;
+    let mut term = z;
+    let mut sum = 0.0;
+    for k in 0..20 {
+        sum += term / (2 * k + 1) as f64;
+        term *= z2;
+    }
+    exponent as f64 + 2.0 * sum / core::f64::consts::LN_2
+}
+
+#[cfg(not(feature = "std"))]
+use log2_fallback as log2;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -721,4 +766,11 @@ mod tests {
             .iter()
             .any(|p| matches!(p, Pattern::AsciiText { .. })));
     }
+
+    #[test]
+    fn test_log2_fallback() {
+        for x in [1.0, 0.5, 0.3, 1.0 / 3.0, 0.999, 1.0 / 1476.0, 1e-6] {
+            assert!((log2_fallback(x) - x.log2()).abs() < 1e-12, "log2({})", x);
+        }
+    }
 }
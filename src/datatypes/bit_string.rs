@@ -0,0 +1,156 @@
+//! BACnet Bit String Datatype
+//!
+//! A bit string is a sequence of bits of arbitrary length, numbered from 0.
+//! On the wire bit 0 is the most significant bit of the first byte, and a
+//! leading byte records how many bits of the final byte are unused padding.
+//! Properties such as Status_Flags and Limit_Enable name each bit position.
+
+use crate::encoding::{self, Result};
+use crate::object::PropertyValue;
+
+#[cfg(not(feature = "std"))]
+use alloc::{vec, vec::Vec};
+
+/// BACnet bit string
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BitString {
+    bits: Vec<bool>,
+}
+
+impl BitString {
+    /// Create a bit string of `len` cleared bits
+    pub fn new(len: usize) -> Self {
+        Self {
+            bits: vec![false; len],
+        }
+    }
+
+    /// Get a bit; bits past the end read as cleared
+    pub fn get(&self, bit: usize) -> bool {
+        self.bits.get(bit).copied().unwrap_or(false)
+    }
+
+    /// Set a bit, extending the string with cleared bits if needed
+    pub fn set(&mut self, bit: usize, value: bool) {
+        if bit >= self.bits.len() {
+            self.bits.resize(bit + 1, false);
+        }
+        self.bits[bit] = value;
+    }
+
+    /// Number of bits
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Check if the string has no bits
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    /// Get the bits, bit 0 first
+    pub fn bits(&self) -> &[bool] {
+        &self.bits
+    }
+
+    /// Number of padding bits in the final encoded byte
+    pub fn unused_bits(&self) -> u8 {
+        ((8 - self.bits.len() % 8) % 8) as u8
+    }
+
+    /// Encode as an application tagged bit string
+    ///
+    /// A partial final byte is padded with cleared bits, and the number of
+    /// padding bits is recorded in the leading byte.
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        encoding::encode_bit_string(buffer, &self.bits)
+    }
+
+    /// Decode an application tagged bit string, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> Result<(Self, usize)> {
+        let (bits, consumed) = encoding::decode_bit_string(data)?;
+        Ok((Self { bits }, consumed))
+    }
+}
+
+impl From<Vec<bool>> for BitString {
+    fn from(bits: Vec<bool>) -> Self {
+        Self { bits }
+    }
+}
+
+impl From<&[bool]> for BitString {
+    fn from(bits: &[bool]) -> Self {
+        Self {
+            bits: bits.to_vec(),
+        }
+    }
+}
+
+impl From<BitString> for Vec<bool> {
+    fn from(bit_string: BitString) -> Self {
+        bit_string.bits
+    }
+}
+
+impl From<BitString> for PropertyValue {
+    fn from(bit_string: BitString) -> Self {
+        PropertyValue::BitString(bit_string.bits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_string_get_set() {
+        let mut bits = BitString::new(4);
+        assert_eq!(bits.len(), 4);
+        assert!(!bits.get(2));
+
+        bits.set(2, true);
+        assert!(bits.get(2));
+        assert!(!bits.get(10));
+
+        // Setting past the end grows the string
+        bits.set(9, true);
+        assert_eq!(bits.len(), 10);
+        assert!(bits.get(9));
+        assert!(!bits.get(8));
+    }
+
+    #[test]
+    fn test_bit_string_encoding_pads_final_byte() {
+        let mut bits = BitString::new(10);
+        bits.set(0, true);
+        bits.set(9, true);
+        assert_eq!(bits.unused_bits(), 6);
+
+        let mut buffer = Vec::new();
+        bits.encode(&mut buffer).unwrap();
+        // Tag 8 with length 3, 6 unused bits, then the padded bytes
+        assert_eq!(buffer, vec![0x83, 0x06, 0x80, 0x40]);
+
+        let (decoded, consumed) = BitString::decode(&buffer).unwrap();
+        assert_eq!(decoded, bits);
+        assert_eq!(consumed, buffer.len());
+
+        // Whole bytes need no padding
+        let mut buffer = Vec::new();
+        BitString::new(8).encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x82, 0x00, 0x00]);
+
+        let mut buffer = Vec::new();
+        BitString::new(0).encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x81, 0x00]);
+        assert!(BitString::decode(&buffer).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn test_bit_string_rejects_bad_unused_bits() {
+        assert!(BitString::decode(&[0x82, 0x08, 0x00]).is_err());
+        // Padding needs a byte to live in
+        assert!(BitString::decode(&[0x81, 0x03]).is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! Wall Clock Sources
+//!
+//! Schedules, trend logs and notification routing all depend on the current
+//! date and time. Rather than reading the system clock directly they take a
+//! [`Clock`], so the same logic runs on embedded targets with their own real
+//! time clock and in tests with a [`ManualClock`] that only moves when told.
+
+use crate::object::Time;
+use crate::service::BacnetDateTime;
+
+use super::{days_in_month, Weekday, UNSPECIFIED};
+
+use core::cell::Cell;
+use core::time::Duration;
+
+/// Source of the current local date and time
+pub trait Clock {
+    /// Current date and time
+    fn now(&self) -> BacnetDateTime;
+}
+
+/// Clock reading the local system time
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> BacnetDateTime {
+        BacnetDateTime::now()
+    }
+}
+
+/// Clock that stays at a set time until moved
+#[derive(Debug, Clone)]
+pub struct ManualClock {
+    now: Cell<BacnetDateTime>,
+}
+
+impl ManualClock {
+    /// Create a clock stopped at `now`
+    pub fn new(now: BacnetDateTime) -> Self {
+        Self {
+            now: Cell::new(now),
+        }
+    }
+
+    /// Move the clock to `now`
+    pub fn set(&self, now: BacnetDateTime) {
+        self.now.set(now);
+    }
+
+    /// Move the clock forward, rolling over into following days
+    ///
+    /// Precision is one hundredth of a second. The weekday is kept in step
+    /// with the date. A date that is not a calendar date, such as one with
+    /// wildcard fields, is left unchanged and only the time of day moves.
+    pub fn advance(&self, by: Duration) {
+        let BacnetDateTime { mut date, time } = self.now.get();
+        const HUNDREDTHS_PER_DAY: u64 = 24 * 60 * 60 * 100;
+        let of_day = ((time.hour as u64 * 60 + time.minute as u64) * 60 + time.second as u64) * 100
+            + time.hundredths as u64;
+        let total = of_day + by.as_millis() as u64 / 10;
+
+        if Weekday::of_date(date.year, date.month, date.day).is_none() {
+            self.now.set(BacnetDateTime::new(date, time_of_day(total)));
+            return;
+        }
+        for _ in 0..total / HUNDREDTHS_PER_DAY {
+            if date.day < days_in_month(date.year, date.month) {
+                date.day += 1;
+            } else if date.month < 12 {
+                date.month += 1;
+                date.day = 1;
+            } else {
+                date.year += 1;
+                date.month = 1;
+                date.day = 1;
+            }
+        }
+        date.weekday =
+            Weekday::of_date(date.year, date.month, date.day).map_or(UNSPECIFIED, |day| day as u8);
+
+        self.now.set(BacnetDateTime::new(date, time_of_day(total)));
+    }
+}
+
+/// Time of day reached `hundredths` after a midnight
+fn time_of_day(hundredths: u64) -> Time {
+    let of_day = hundredths % (24 * 60 * 60 * 100);
+    Time {
+        hour: (of_day / 360_000) as u8,
+        minute: (of_day / 6_000 % 60) as u8,
+        second: (of_day / 100 % 60) as u8,
+        hundredths: (of_day % 100) as u8,
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> BacnetDateTime {
+        self.now.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::Date;
+
+    #[test]
+    fn test_manual_clock_advances_across_days() {
+        let clock = ManualClock::new(BacnetDateTime::new(
+            Date {
+                year: 2024,
+                month: 12,
+                day: 31,
+                weekday: 2,
+            },
+            Time {
+                hour: 23,
+                minute: 59,
+                second: 30,
+                hundredths: 50,
+            },
+        ));
+
+        clock.advance(Duration::from_millis(20_000));
+        assert_eq!(clock.now().time.second, 50);
+        assert_eq!(clock.now().time.hundredths, 50);
+        assert_eq!(clock.now().date.day, 31);
+
+        // Past midnight into the new year, which starts on a Wednesday
+        clock.advance(Duration::from_secs(10));
+        let now = clock.now();
+        assert_eq!(
+            (
+                now.date.year,
+                now.date.month,
+                now.date.day,
+                now.date.weekday
+            ),
+            (2025, 1, 1, 3)
+        );
+        assert_eq!((now.time.hour, now.time.minute, now.time.second), (0, 0, 0));
+        assert_eq!(now.time.hundredths, 50);
+
+        clock.advance(Duration::from_secs(59 * 24 * 60 * 60));
+        assert_eq!((clock.now().date.month, clock.now().date.day), (3, 1));
+    }
+
+    #[test]
+    fn test_manual_clock_keeps_wildcard_date() {
+        let mut start = BacnetDateTime::unspecified();
+        start.time = Time {
+            hour: 23,
+            minute: 0,
+            second: 0,
+            hundredths: 0,
+        };
+        let clock = ManualClock::new(start);
+
+        clock.advance(Duration::from_secs(2 * 60 * 60));
+        let now = clock.now();
+        assert_eq!(now.date, start.date);
+        assert_eq!((now.time.hour, now.time.minute), (1, 0));
+    }
+}
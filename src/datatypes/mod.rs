@@ -0,0 +1,650 @@
+//! BACnet Date and Time Datatypes
+//!
+//! BACnet dates and times are fixed four byte values in which any field may be
+//! unspecified (encoded as 255), and some fields have additional wildcard values
+//! such as "odd months" or "last day of the month". These types model those
+//! wildcards explicitly so Calendar, Schedule and time synchronization code does
+//! not have to interpret raw bytes.
+//!
+//! With the `chrono` feature, fully specified values convert to and from
+//! `chrono::NaiveDate` and `chrono::NaiveTime`.
+//!
+//! Bit strings with per-bit access live in the [`bit_string`] submodule,
+//! notification recipients and destinations in [`recipient`], and the
+//! [`Clock`] sources of the current date and time in [`clock`].
+
+use crate::encoding::{self, ApplicationTag, EncodingError, Result};
+use crate::object::{Date, Time};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Bit strings with per-bit access
+pub mod bit_string;
+
+/// Sources of the current date and time
+pub mod clock;
+
+/// Notification recipients and destinations
+pub mod recipient;
+
+pub use bit_string::BitString;
+#[cfg(feature = "std")]
+pub use clock::SystemClock;
+pub use clock::{Clock, ManualClock};
+pub use recipient::{Destination, EventTransition, Recipient};
+
+/// Encoded value of an unspecified date or time field
+pub const UNSPECIFIED: u8 = 255;
+
+/// First year representable in a BACnet date
+pub const MIN_YEAR: u16 = 1900;
+
+/// Last year representable in a BACnet date
+pub const MAX_YEAR: u16 = 1900 + 254;
+
+/// Month field of a BACnet date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Month {
+    /// A specific month, 1 (January) to 12 (December)
+    Specific(u8),
+    /// Any odd month (encoded as 13)
+    Odd,
+    /// Any even month (encoded as 14)
+    Even,
+    /// Any month
+    Any,
+}
+
+impl Month {
+    /// Encoded byte value
+    pub fn as_u8(self) -> u8 {
+        match self {
+            Month::Specific(month) => month,
+            Month::Odd => 13,
+            Month::Even => 14,
+            Month::Any => UNSPECIFIED,
+        }
+    }
+
+    /// Decode from the encoded byte value
+    pub fn from_u8(value: u8) -> Result<Self> {
+        match value {
+            1..=12 => Ok(Month::Specific(value)),
+            13 => Ok(Month::Odd),
+            14 => Ok(Month::Even),
+            UNSPECIFIED => Ok(Month::Any),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+
+    /// Check if a specific month (1-12) matches this pattern
+    pub fn matches(self, month: u8) -> bool {
+        match self {
+            Month::Specific(expected) => expected == month,
+            Month::Odd => month % 2 == 1,
+            Month::Even => month.is_multiple_of(2),
+            Month::Any => true,
+        }
+    }
+}
+
+/// Day field of a BACnet date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DayOfMonth {
+    /// A specific day, 1 to 31
+    Specific(u8),
+    /// The last day of the month (encoded as 32)
+    Last,
+    /// Any odd day (encoded as 33)
+    Odd,
+    /// Any even day (encoded as 34)
+    Even,
+    /// Any day
+    Any,
+}
+
+impl DayOfMonth {
+    /// Encoded byte value
+    pub fn as_u8(self) -> u8 {
+        match self {
+            DayOfMonth::Specific(day) => day,
+            DayOfMonth::Last => 32,
+            DayOfMonth::Odd => 33,
+            DayOfMonth::Even => 34,
+            DayOfMonth::Any => UNSPECIFIED,
+        }
+    }
+
+    /// Decode from the encoded byte value
+    pub fn from_u8(value: u8) -> Result<Self> {
+        match value {
+            1..=31 => Ok(DayOfMonth::Specific(value)),
+            32 => Ok(DayOfMonth::Last),
+            33 => Ok(DayOfMonth::Odd),
+            34 => Ok(DayOfMonth::Even),
+            UNSPECIFIED => Ok(DayOfMonth::Any),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+
+    /// Check if a specific day of the given month matches this pattern
+    pub fn matches(self, year: u16, month: u8, day: u8) -> bool {
+        match self {
+            DayOfMonth::Specific(expected) => expected == day,
+            DayOfMonth::Last => day == days_in_month(year, month),
+            DayOfMonth::Odd => day % 2 == 1,
+            DayOfMonth::Even => day.is_multiple_of(2),
+            DayOfMonth::Any => true,
+        }
+    }
+}
+
+/// Day of week, numbered as in BACnet (1 = Monday)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Weekday {
+    Monday = 1,
+    Tuesday = 2,
+    Wednesday = 3,
+    Thursday = 4,
+    Friday = 5,
+    Saturday = 6,
+    Sunday = 7,
+}
+
+impl Weekday {
+    /// Decode from the BACnet day number (1 = Monday)
+    pub fn from_u8(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Weekday::Monday),
+            2 => Ok(Weekday::Tuesday),
+            3 => Ok(Weekday::Wednesday),
+            4 => Ok(Weekday::Thursday),
+            5 => Ok(Weekday::Friday),
+            6 => Ok(Weekday::Saturday),
+            7 => Ok(Weekday::Sunday),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+
+    /// Day of week for a calendar date
+    ///
+    /// Returns `None` unless the year is at least 1, the month is 1-12 and
+    /// the day falls within that month, so wildcard date fields give `None`.
+    pub fn of_date(year: u16, month: u8, day: u8) -> Option<Self> {
+        if year == 0 || !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        // Sakamoto's method, yielding 0 = Sunday
+        const OFFSETS: [u16; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+        let year = if month < 3 { year - 1 } else { year };
+        let sunday_based = (year + year / 4 - year / 100
+            + year / 400
+            + OFFSETS[(month - 1) as usize]
+            + day as u16)
+            % 7;
+        match sunday_based {
+            0 => Some(Weekday::Sunday),
+            day => Self::from_u8(day as u8).ok(),
+        }
+    }
+}
+
+/// Check if a year is a leap year
+pub fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// Number of days in a month (1-12) of a year
+pub fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// BACnet Date with wildcard support
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BacnetDate {
+    /// Year (1900-2154), or `None` for any year
+    pub year: Option<u16>,
+    /// Month
+    pub month: Month,
+    /// Day of month
+    pub day: DayOfMonth,
+    /// Day of week, or `None` for any day
+    pub weekday: Option<Weekday>,
+}
+
+impl BacnetDate {
+    /// Create a fully specified date, computing the day of week
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Self> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year)
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > days_in_month(year, month)
+        {
+            return Err(EncodingError::ValueOutOfRange);
+        }
+        Ok(Self {
+            year: Some(year),
+            month: Month::Specific(month),
+            day: DayOfMonth::Specific(day),
+            weekday: Weekday::of_date(year, month, day),
+        })
+    }
+
+    /// A date with every field unspecified
+    pub fn any() -> Self {
+        Self {
+            year: None,
+            month: Month::Any,
+            day: DayOfMonth::Any,
+            weekday: None,
+        }
+    }
+
+    /// Check if no field is a wildcard
+    pub fn is_specific(&self) -> bool {
+        self.year.is_some()
+            && matches!(self.month, Month::Specific(_))
+            && matches!(self.day, DayOfMonth::Specific(_))
+            && self.weekday.is_some()
+    }
+
+    /// Year, month and day when all three are specified
+    pub fn ymd(&self) -> Option<(u16, u8, u8)> {
+        match (self.year, self.month, self.day) {
+            (Some(year), Month::Specific(month), DayOfMonth::Specific(day)) => {
+                Some((year, month, day))
+            }
+            _ => None,
+        }
+    }
+
+    /// Check if a specific date matches this date pattern
+    ///
+    /// Unspecified fields and the odd/even/last-day wildcards match any
+    /// corresponding value. A `date` that is itself not specific never matches.
+    pub fn matches(&self, date: &BacnetDate) -> bool {
+        let Some((year, month, day)) = date.ymd() else {
+            return false;
+        };
+        self.year.is_none_or(|expected| expected == year)
+            && self.month.matches(month)
+            && self.day.matches(year, month, day)
+            && self
+                .weekday
+                .is_none_or(|expected| Some(expected) == Weekday::of_date(year, month, day))
+    }
+
+    /// Encode to the 4 byte wire format (year - 1900, month, day, weekday)
+    pub fn encode(&self) -> [u8; 4] {
+        [
+            self.year
+                .map(|year| year.saturating_sub(MIN_YEAR).min(254) as u8)
+                .unwrap_or(UNSPECIFIED),
+            self.month.as_u8(),
+            self.day.as_u8(),
+            self.weekday.map(|day| day as u8).unwrap_or(UNSPECIFIED),
+        ]
+    }
+
+    /// Decode from the 4 byte wire format
+    pub fn decode(bytes: [u8; 4]) -> Result<Self> {
+        Ok(Self {
+            year: match bytes[0] {
+                UNSPECIFIED => None,
+                offset => Some(MIN_YEAR + offset as u16),
+            },
+            month: Month::from_u8(bytes[1])?,
+            day: DayOfMonth::from_u8(bytes[2])?,
+            weekday: match bytes[3] {
+                UNSPECIFIED => None,
+                day => Some(Weekday::from_u8(day)?),
+            },
+        })
+    }
+
+    /// Encode as an application tagged Date
+    pub fn encode_application(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        encoding::encode_application_tag(buffer, ApplicationTag::Date, 4)?;
+        buffer.extend_from_slice(&self.encode());
+        Ok(())
+    }
+
+    /// Decode an application tagged Date, returning it and the bytes consumed
+    pub fn decode_application(data: &[u8]) -> Result<(Self, usize)> {
+        let (bytes, consumed) = decode_tagged_value(data, ApplicationTag::Date)?;
+        Ok((Self::decode(bytes)?, consumed))
+    }
+}
+
+/// BACnet Time with wildcard support
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BacnetTime {
+    /// Hour (0-23), or `None` for any hour
+    pub hour: Option<u8>,
+    /// Minute (0-59), or `None` for any minute
+    pub minute: Option<u8>,
+    /// Second (0-59), or `None` for any second
+    pub second: Option<u8>,
+    /// Hundredths of a second (0-99), or `None` for any
+    pub hundredths: Option<u8>,
+}
+
+impl BacnetTime {
+    /// Create a fully specified time
+    pub fn new(hour: u8, minute: u8, second: u8, hundredths: u8) -> Result<Self> {
+        if hour > 23 || minute > 59 || second > 59 || hundredths > 99 {
+            return Err(EncodingError::ValueOutOfRange);
+        }
+        Ok(Self {
+            hour: Some(hour),
+            minute: Some(minute),
+            second: Some(second),
+            hundredths: Some(hundredths),
+        })
+    }
+
+    /// A time with every field unspecified
+    pub fn any() -> Self {
+        Self {
+            hour: None,
+            minute: None,
+            second: None,
+            hundredths: None,
+        }
+    }
+
+    /// Check if no field is a wildcard
+    pub fn is_specific(&self) -> bool {
+        self.hour.is_some()
+            && self.minute.is_some()
+            && self.second.is_some()
+            && self.hundredths.is_some()
+    }
+
+    /// Encode to the 4 byte wire format (hour, minute, second, hundredths)
+    pub fn encode(&self) -> [u8; 4] {
+        [
+            self.hour.unwrap_or(UNSPECIFIED),
+            self.minute.unwrap_or(UNSPECIFIED),
+            self.second.unwrap_or(UNSPECIFIED),
+            self.hundredths.unwrap_or(UNSPECIFIED),
+        ]
+    }
+
+    /// Decode from the 4 byte wire format
+    pub fn decode(bytes: [u8; 4]) -> Result<Self> {
+        Ok(Self {
+            hour: decode_time_field(bytes[0], 23)?,
+            minute: decode_time_field(bytes[1], 59)?,
+            second: decode_time_field(bytes[2], 59)?,
+            hundredths: decode_time_field(bytes[3], 99)?,
+        })
+    }
+
+    /// Encode as an application tagged Time
+    pub fn encode_application(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        encoding::encode_application_tag(buffer, ApplicationTag::Time, 4)?;
+        buffer.extend_from_slice(&self.encode());
+        Ok(())
+    }
+
+    /// Decode an application tagged Time, returning it and the bytes consumed
+    pub fn decode_application(data: &[u8]) -> Result<(Self, usize)> {
+        let (bytes, consumed) = decode_tagged_value(data, ApplicationTag::Time)?;
+        Ok((Self::decode(bytes)?, consumed))
+    }
+}
+
+fn decode_time_field(value: u8, max: u8) -> Result<Option<u8>> {
+    match value {
+        UNSPECIFIED => Ok(None),
+        value if value <= max => Ok(Some(value)),
+        _ => Err(EncodingError::ValueOutOfRange),
+    }
+}
+
+/// Decode the four content bytes of an application tagged Date or Time
+fn decode_tagged_value(data: &[u8], expected: ApplicationTag) -> Result<([u8; 4], usize)> {
+    let (tag, length, consumed) = encoding::decode_application_tag(data)?;
+    if tag != expected {
+        return Err(EncodingError::InvalidTag);
+    }
+    if length != 4 || data.len() < consumed + 4 {
+        return Err(EncodingError::InvalidLength);
+    }
+    let mut bytes = [0u8; 4];
+    bytes.copy_from_slice(&data[consumed..consumed + 4]);
+    Ok((bytes, consumed + 4))
+}
+
+impl From<BacnetDate> for Date {
+    fn from(date: BacnetDate) -> Self {
+        let [_, month, day, weekday] = date.encode();
+        Date {
+            year: date.year.unwrap_or(UNSPECIFIED as u16),
+            month,
+            day,
+            weekday,
+        }
+    }
+}
+
+impl TryFrom<Date> for BacnetDate {
+    type Error = EncodingError;
+
+    fn try_from(date: Date) -> Result<Self> {
+        let year = match date.year {
+            year if year < MIN_YEAR => UNSPECIFIED,
+            year if year <= MAX_YEAR => (year - MIN_YEAR) as u8,
+            _ => return Err(EncodingError::ValueOutOfRange),
+        };
+        Self::decode([year, date.month, date.day, date.weekday])
+    }
+}
+
+impl From<BacnetTime> for Time {
+    fn from(time: BacnetTime) -> Self {
+        let [hour, minute, second, hundredths] = time.encode();
+        Time {
+            hour,
+            minute,
+            second,
+            hundredths,
+        }
+    }
+}
+
+impl TryFrom<Time> for BacnetTime {
+    type Error = EncodingError;
+
+    fn try_from(time: Time) -> Result<Self> {
+        Self::decode([time.hour, time.minute, time.second, time.hundredths])
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_conversions {
+    use super::*;
+    use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+
+    impl TryFrom<NaiveDate> for BacnetDate {
+        type Error = EncodingError;
+
+        fn try_from(date: NaiveDate) -> Result<Self> {
+            let year = u16::try_from(date.year()).map_err(|_| EncodingError::ValueOutOfRange)?;
+            BacnetDate::new(year, date.month() as u8, date.day() as u8)
+        }
+    }
+
+    impl TryFrom<BacnetDate> for NaiveDate {
+        type Error = EncodingError;
+
+        fn try_from(date: BacnetDate) -> Result<Self> {
+            match (date.year, date.month, date.day) {
+                (Some(year), Month::Specific(month), DayOfMonth::Specific(day)) => {
+                    NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+                        .ok_or(EncodingError::ValueOutOfRange)
+                }
+                _ => Err(EncodingError::InvalidFormat(
+                    "Date has unspecified fields".to_string(),
+                )),
+            }
+        }
+    }
+
+    impl From<NaiveTime> for BacnetTime {
+        fn from(time: NaiveTime) -> Self {
+            // Leap seconds report more than a billion nanoseconds
+            let hundredths = (time.nanosecond() / 10_000_000).min(99) as u8;
+            BacnetTime {
+                hour: Some(time.hour() as u8),
+                minute: Some(time.minute() as u8),
+                second: Some(time.second() as u8),
+                hundredths: Some(hundredths),
+            }
+        }
+    }
+
+    impl TryFrom<BacnetTime> for NaiveTime {
+        type Error = EncodingError;
+
+        fn try_from(time: BacnetTime) -> Result<Self> {
+            match (time.hour, time.minute, time.second, time.hundredths) {
+                (Some(hour), Some(minute), Some(second), hundredths) => {
+                    NaiveTime::from_hms_milli_opt(
+                        hour as u32,
+                        minute as u32,
+                        second as u32,
+                        hundredths.unwrap_or(0) as u32 * 10,
+                    )
+                    .ok_or(EncodingError::ValueOutOfRange)
+                }
+                _ => Err(EncodingError::InvalidFormat(
+                    "Time has unspecified fields".to_string(),
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_encoding_round_trip() {
+        let date = BacnetDate::new(2024, 2, 29).unwrap();
+        assert_eq!(date.weekday, Some(Weekday::Thursday));
+        assert_eq!(date.encode(), [124, 2, 29, 4]);
+        assert_eq!(BacnetDate::decode(date.encode()).unwrap(), date);
+        assert!(BacnetDate::new(2023, 2, 29).is_err());
+
+        let mut buffer = Vec::new();
+        date.encode_application(&mut buffer).unwrap();
+        assert_eq!(buffer, [0xA4, 124, 2, 29, 4]);
+        assert_eq!(BacnetDate::decode_application(&buffer).unwrap(), (date, 5));
+    }
+
+    #[test]
+    fn test_date_wildcards() {
+        let date = BacnetDate::decode([255, 13, 32, 255]).unwrap();
+        assert_eq!(date.year, None);
+        assert_eq!(date.month, Month::Odd);
+        assert_eq!(date.day, DayOfMonth::Last);
+        assert_eq!(date.weekday, None);
+        assert!(!date.is_specific());
+        assert_eq!(BacnetDate::any().encode(), [255; 4]);
+        assert!(BacnetDate::decode([124, 15, 1, 1]).is_err());
+        assert!(BacnetDate::decode([124, 1, 1, 8]).is_err());
+    }
+
+    #[test]
+    fn test_date_pattern_matching() {
+        let leap_day = BacnetDate::new(2024, 2, 29).unwrap();
+        let last_day = BacnetDate {
+            day: DayOfMonth::Last,
+            ..BacnetDate::any()
+        };
+        assert!(last_day.matches(&leap_day));
+        assert!(!last_day.matches(&BacnetDate::new(2024, 2, 28).unwrap()));
+
+        let odd_month_mondays = BacnetDate {
+            month: Month::Odd,
+            weekday: Some(Weekday::Monday),
+            ..BacnetDate::any()
+        };
+        assert!(odd_month_mondays.matches(&BacnetDate::new(2025, 1, 6).unwrap()));
+        assert!(!odd_month_mondays.matches(&BacnetDate::new(2025, 2, 3).unwrap()));
+        assert!(!BacnetDate::any().matches(&BacnetDate::any()));
+    }
+
+    #[test]
+    fn test_weekday_of_date() {
+        assert_eq!(Weekday::of_date(2025, 1, 6), Some(Weekday::Monday));
+        assert_eq!(Weekday::of_date(2024, 2, 29), Some(Weekday::Thursday));
+        assert_eq!(Weekday::of_date(2000, 3, 1), Some(Weekday::Wednesday));
+        // Wildcard and out-of-range fields have no weekday
+        assert_eq!(Weekday::of_date(2025, 2, 29), None);
+        assert_eq!(Weekday::of_date(2025, 255, 255), None);
+        assert_eq!(Weekday::of_date(2025, 13, 1), None);
+        assert_eq!(Weekday::of_date(2025, 1, 0), None);
+        assert_eq!(Weekday::of_date(0, 1, 1), None);
+    }
+
+    #[test]
+    fn test_time_encoding() {
+        let time = BacnetTime::new(13, 45, 30, 50).unwrap();
+        let mut buffer = Vec::new();
+        time.encode_application(&mut buffer).unwrap();
+        assert_eq!(buffer, [0xB4, 13, 45, 30, 50]);
+        assert_eq!(BacnetTime::decode_application(&buffer).unwrap(), (time, 5));
+
+        let any_second = BacnetTime::decode([8, 0, 255, 255]).unwrap();
+        assert_eq!(any_second.second, None);
+        assert!(!any_second.is_specific());
+        assert!(BacnetTime::new(24, 0, 0, 0).is_err());
+        assert!(BacnetTime::decode([12, 60, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_object_date_time_conversions() {
+        let date = BacnetDate::decode([255, 14, 1, 255]).unwrap();
+        let raw = Date::from(date);
+        assert_eq!(raw.year, 255);
+        assert_eq!(raw.month, 14);
+        assert_eq!(BacnetDate::try_from(raw).unwrap(), date);
+
+        let time = Time::from(BacnetTime::any());
+        assert_eq!(time.hour, 255);
+        assert_eq!(BacnetTime::try_from(time).unwrap(), BacnetTime::any());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_chrono_conversions() {
+        use chrono::{NaiveDate, NaiveTime};
+
+        let naive = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let date = BacnetDate::try_from(naive).unwrap();
+        assert_eq!(date.weekday, Some(Weekday::Monday));
+        assert_eq!(NaiveDate::try_from(date).unwrap(), naive);
+        assert!(NaiveDate::try_from(BacnetDate::any()).is_err());
+
+        let naive = NaiveTime::from_hms_milli_opt(7, 30, 15, 250).unwrap();
+        let time = BacnetTime::from(naive);
+        assert_eq!(time.hundredths, Some(25));
+        assert_eq!(NaiveTime::try_from(time).unwrap(), naive);
+    }
+}
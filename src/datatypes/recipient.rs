@@ -0,0 +1,366 @@
+//! BACnet Recipient and Destination Datatypes
+//!
+//! A recipient names where a notification goes: a device, found through
+//! its identifier, or a network address. A destination adds when that
+//! recipient wants notifications (days of the week and a time window),
+//! which transitions it cares about and whether they must be confirmed.
+//! Notification Class objects hold a list of destinations.
+
+use crate::encoding::{
+    self,
+    advanced::context::{encode_closing_tag, encode_opening_tag},
+    EncodingError, Result, TagClass,
+};
+use crate::network::NetworkAddress;
+use crate::object::{ObjectIdentifier, ObjectType, PropertyValue};
+use crate::service::BacnetDateTime;
+
+use super::{BacnetTime, Weekday};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Event transition reported to recipients
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum EventTransition {
+    ToOffnormal = 0,
+    ToFault = 1,
+    ToNormal = 2,
+}
+
+/// BACnetRecipient: a device or a network address
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Recipient {
+    /// Device object identifier (context tag 0)
+    Device(ObjectIdentifier),
+    /// Network number and MAC address (context tag 1)
+    Address(NetworkAddress),
+}
+
+impl Recipient {
+    /// Encode the recipient choice
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        match self {
+            Recipient::Device(device) => {
+                buffer.extend_from_slice(&encoding::encode_context_object_id(
+                    device.object_type.as_u16(),
+                    device.instance,
+                    0,
+                )?);
+                Ok(())
+            }
+            Recipient::Address(address) => {
+                encode_opening_tag(buffer, 1)?;
+                PropertyValue::UnsignedInteger(address.network as u32).encode(buffer)?;
+                PropertyValue::OctetString(address.address.clone()).encode(buffer)?;
+                encode_closing_tag(buffer, 1)
+            }
+        }
+    }
+
+    /// Decode a recipient choice, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> Result<(Self, usize)> {
+        let (tag, header) = encoding::decode_tag(data)?;
+        if tag.class != TagClass::Context {
+            return Err(EncodingError::InvalidTag);
+        }
+        match tag.number {
+            0 => {
+                let ((object_type, instance), consumed) =
+                    encoding::decode_context_object_id(data, 0)?;
+                let object_type = ObjectType::try_from(object_type)
+                    .map_err(|_| EncodingError::ValueOutOfRange)?;
+                Ok((
+                    Recipient::Device(ObjectIdentifier::new(object_type, instance)),
+                    consumed,
+                ))
+            }
+            1 if tag.is_opening => {
+                let mut pos = header;
+                let (PropertyValue::UnsignedInteger(network), consumed) =
+                    PropertyValue::decode(&data[pos..])?
+                else {
+                    return Err(EncodingError::InvalidTag);
+                };
+                let network = u16::try_from(network).map_err(|_| EncodingError::ValueOutOfRange)?;
+                pos += consumed;
+
+                let (PropertyValue::OctetString(address), consumed) =
+                    PropertyValue::decode(&data[pos..])?
+                else {
+                    return Err(EncodingError::InvalidTag);
+                };
+                pos += consumed;
+
+                let (closing, consumed) = encoding::decode_tag(&data[pos..])?;
+                if !closing.is_closing || closing.number != 1 {
+                    return Err(EncodingError::InvalidTag);
+                }
+                Ok((
+                    Recipient::Address(NetworkAddress::new(network, address)),
+                    pos + consumed,
+                ))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
+/// BACnetDestination: a recipient and when it wants notifications
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Destination {
+    /// Days notifications are sent, Monday first
+    pub valid_days: [bool; 7],
+    /// Start of the daily window
+    pub from_time: BacnetTime,
+    /// End of the daily window, inclusive
+    pub to_time: BacnetTime,
+    /// Where notifications go
+    pub recipient: Recipient,
+    /// Process identifier notifications are addressed to
+    pub process_identifier: u32,
+    /// Send confirmed rather than unconfirmed notifications
+    pub issue_confirmed_notifications: bool,
+    /// To-offnormal, to-fault and to-normal transitions sent
+    pub transitions: [bool; 3],
+}
+
+impl Destination {
+    /// Create a destination receiving every transition at any time
+    pub fn new(recipient: Recipient, process_identifier: u32) -> Self {
+        Self {
+            valid_days: [true; 7],
+            from_time: BacnetTime {
+                hour: Some(0),
+                minute: Some(0),
+                second: Some(0),
+                hundredths: Some(0),
+            },
+            to_time: BacnetTime {
+                hour: Some(23),
+                minute: Some(59),
+                second: Some(59),
+                hundredths: Some(99),
+            },
+            recipient,
+            process_identifier,
+            issue_confirmed_notifications: false,
+            transitions: [true; 3],
+        }
+    }
+
+    /// Check if `now` falls on a valid day and within the time window
+    ///
+    /// Unspecified fields of `from_time` count as their lowest value and
+    /// those of `to_time` as their highest. If the weekday of `now` is
+    /// unspecified it is worked out from the date; when that is not a
+    /// calendar date either, the destination is not active.
+    pub fn is_active_at(&self, now: &BacnetDateTime) -> bool {
+        let weekday = Weekday::from_u8(now.date.weekday)
+            .ok()
+            .or_else(|| Weekday::of_date(now.date.year, now.date.month, now.date.day));
+        let Some(weekday) = weekday else {
+            return false;
+        };
+        if !self.valid_days[weekday as usize - 1] {
+            return false;
+        }
+
+        let from = self.from_time;
+        let from = [
+            from.hour.unwrap_or(0),
+            from.minute.unwrap_or(0),
+            from.second.unwrap_or(0),
+            from.hundredths.unwrap_or(0),
+        ];
+        let time = [
+            now.time.hour,
+            now.time.minute,
+            now.time.second,
+            now.time.hundredths,
+        ];
+        from <= time && time <= self.to_time.encode()
+    }
+
+    /// Check if a transition at `now` should be sent to this recipient
+    pub fn should_notify(&self, transition: EventTransition, now: &BacnetDateTime) -> bool {
+        self.transitions[transition as usize] && self.is_active_at(now)
+    }
+
+    /// Encode the destination
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        PropertyValue::BitString(self.valid_days.to_vec()).encode(buffer)?;
+        self.from_time.encode_application(buffer)?;
+        self.to_time.encode_application(buffer)?;
+        self.recipient.encode(buffer)?;
+        PropertyValue::UnsignedInteger(self.process_identifier).encode(buffer)?;
+        PropertyValue::Boolean(self.issue_confirmed_notifications).encode(buffer)?;
+        PropertyValue::BitString(self.transitions.to_vec()).encode(buffer)
+    }
+
+    /// Decode a destination, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> Result<(Self, usize)> {
+        let mut pos = 0;
+
+        let (days, consumed) = PropertyValue::decode(data)?;
+        let valid_days = bits::<7>(days)?;
+        pos += consumed;
+
+        let (from_time, consumed) = BacnetTime::decode_application(&data[pos..])?;
+        pos += consumed;
+        let (to_time, consumed) = BacnetTime::decode_application(&data[pos..])?;
+        pos += consumed;
+
+        let (recipient, consumed) = Recipient::decode(&data[pos..])?;
+        pos += consumed;
+
+        let (PropertyValue::UnsignedInteger(process_identifier), consumed) =
+            PropertyValue::decode(&data[pos..])?
+        else {
+            return Err(EncodingError::InvalidTag);
+        };
+        pos += consumed;
+
+        let (PropertyValue::Boolean(issue_confirmed_notifications), consumed) =
+            PropertyValue::decode(&data[pos..])?
+        else {
+            return Err(EncodingError::InvalidTag);
+        };
+        pos += consumed;
+
+        let (transitions, consumed) = PropertyValue::decode(&data[pos..])?;
+        let transitions = bits::<3>(transitions)?;
+        pos += consumed;
+
+        Ok((
+            Self {
+                valid_days,
+                from_time,
+                to_time,
+                recipient,
+                process_identifier,
+                issue_confirmed_notifications,
+                transitions,
+            },
+            pos,
+        ))
+    }
+}
+
+/// Take the first `N` bits of a bit string, reading missing bits as cleared
+fn bits<const N: usize>(value: PropertyValue) -> Result<[bool; N]> {
+    let PropertyValue::BitString(bits) = value else {
+        return Err(EncodingError::InvalidTag);
+    };
+    let mut fixed = [false; N];
+    for (bit, value) in fixed.iter_mut().zip(bits) {
+        *bit = value;
+    }
+    Ok(fixed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Date, Time};
+
+    fn at(weekday: u8, hour: u8, minute: u8) -> BacnetDateTime {
+        BacnetDateTime::new(
+            Date {
+                year: 2025,
+                month: 3,
+                day: 9 + weekday,
+                weekday,
+            },
+            Time {
+                hour,
+                minute,
+                second: 0,
+                hundredths: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn test_recipient_encoding() {
+        let device = Recipient::Device(ObjectIdentifier::new(ObjectType::Device, 7));
+        let mut buffer = Vec::new();
+        device.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x0C, 0x02, 0x00, 0x00, 0x07]);
+        assert_eq!(Recipient::decode(&buffer).unwrap(), (device, 5));
+
+        let address = Recipient::Address(NetworkAddress::new(5, vec![0xC0, 0xA8, 0x00, 0x0A]));
+        let mut buffer = Vec::new();
+        address.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![0x1E, 0x21, 0x05, 0x64, 0xC0, 0xA8, 0x00, 0x0A, 0x1F]
+        );
+        assert_eq!(Recipient::decode(&buffer).unwrap(), (address, 9));
+
+        assert!(Recipient::decode(&[0x29, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_destination_round_trip() {
+        let mut destination = Destination::new(
+            Recipient::Device(ObjectIdentifier::new(ObjectType::Device, 7)),
+            12,
+        );
+        destination.valid_days = [true, true, true, true, true, false, false];
+        destination.from_time = BacnetTime::new(8, 0, 0, 0).unwrap();
+        destination.to_time = BacnetTime::new(17, 30, 0, 0).unwrap();
+        destination.issue_confirmed_notifications = true;
+        destination.transitions = [true, true, false];
+
+        let mut buffer = Vec::new();
+        destination.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[..3], &[0x82, 0x01, 0xF8]);
+        assert_eq!(
+            Destination::decode(&buffer).unwrap(),
+            (destination, buffer.len())
+        );
+    }
+
+    #[test]
+    fn test_destination_respects_days_and_window() {
+        let mut destination = Destination::new(
+            Recipient::Device(ObjectIdentifier::new(ObjectType::Device, 7)),
+            12,
+        );
+        destination.valid_days = [true, true, true, true, true, false, false];
+        destination.from_time = BacnetTime::new(8, 0, 0, 0).unwrap();
+        destination.to_time = BacnetTime {
+            hour: Some(17),
+            minute: None,
+            second: None,
+            hundredths: None,
+        };
+        destination.transitions = [true, true, false];
+
+        // Monday inside the window, including the end of the final hour
+        assert!(destination.is_active_at(&at(1, 8, 0)));
+        assert!(destination.is_active_at(&at(1, 17, 59)));
+        assert!(!destination.is_active_at(&at(1, 7, 59)));
+        assert!(!destination.is_active_at(&at(1, 18, 0)));
+        // Saturday is not a valid day
+        assert!(!destination.is_active_at(&at(6, 12, 0)));
+
+        // An unspecified weekday is worked out from the date
+        let mut tuesday = at(2, 12, 0);
+        tuesday.date.weekday = 255;
+        assert!(destination.is_active_at(&tuesday));
+
+        // Nor can it be from a wildcard date
+        assert!(!destination.is_active_at(&BacnetDateTime::unspecified()));
+        let mut any_month = tuesday;
+        any_month.date.month = 255;
+        assert!(!destination.is_active_at(&any_month));
+
+        assert!(destination.should_notify(EventTransition::ToOffnormal, &at(3, 12, 0)));
+        assert!(!destination.should_notify(EventTransition::ToNormal, &at(3, 12, 0)));
+    }
+}
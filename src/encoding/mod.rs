@@ -134,7 +134,12 @@ use core::fmt;
 use std::fmt;
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 /// Result type for encoding operations
 #[cfg(feature = "std")]
@@ -160,6 +165,8 @@ pub enum EncodingError {
     InvalidFormat(String),
     /// Value out of valid range
     ValueOutOfRange,
+    /// A list or array holds more elements than the decode limits allow
+    TooManyElements,
 }
 
 impl fmt::Display for EncodingError {
@@ -172,6 +179,7 @@ impl fmt::Display for EncodingError {
             EncodingError::UnexpectedEndOfData => write!(f, "Unexpected end of data"),
             EncodingError::InvalidFormat(msg) => write!(f, "Invalid format: {}", msg),
             EncodingError::ValueOutOfRange => write!(f, "Value out of valid range"),
+            EncodingError::TooManyElements => write!(f, "Too many list elements"),
         }
     }
 }
@@ -179,6 +187,38 @@ impl fmt::Display for EncodingError {
 #[cfg(feature = "std")]
 impl Error for EncodingError {}
 
+/// Default cap on the elements of one decoded list or array
+pub const DEFAULT_MAX_ELEMENTS: usize = 16_384;
+
+/// Bounds applied while decoding untrusted input
+///
+/// Decoders that build lists check each list against these limits, so a
+/// crafted element count fails with [`EncodingError::TooManyElements`]
+/// instead of reserving memory for billions of elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeLimits {
+    /// Maximum number of elements in one list or array
+    pub max_elements: usize,
+}
+
+impl DecodeLimits {
+    /// Check that a list of `count` elements is within the limits
+    pub fn check_elements(&self, count: usize) -> Result<()> {
+        if count > self.max_elements {
+            return Err(EncodingError::TooManyElements);
+        }
+        Ok(())
+    }
+}
+
+impl Default for DecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_elements: DEFAULT_MAX_ELEMENTS,
+        }
+    }
+}
+
 /// BACnet application tag numbers
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
@@ -273,6 +313,123 @@ pub fn decode_application_tag(data: &[u8]) -> Result<(ApplicationTag, usize, usi
     Ok((tag, length, consumed))
 }
 
+/// Tag class, from bit 3 of the initial tag octet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagClass {
+    /// Application tag (data type identified by the tag number)
+    Application,
+    /// Context specific tag (meaning identified by position in a production)
+    Context,
+}
+
+/// A decoded tag header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tag {
+    /// Tag number (0-254, values above 14 use the extended tag number octet)
+    pub number: u8,
+    /// Tag class
+    pub class: TagClass,
+    /// Length/value/type field: content length in octets, or the value itself
+    /// for application tagged booleans
+    pub length: usize,
+    /// Opening tag of a constructed context value
+    pub is_opening: bool,
+    /// Closing tag of a constructed context value
+    pub is_closing: bool,
+}
+
+/// Encode a tag header of either class, including extended tag numbers
+pub fn encode_tag(buffer: &mut Vec<u8>, number: u8, class: TagClass, length: usize) -> Result<()> {
+    if number == 0xFF {
+        return Err(EncodingError::ValueOutOfRange);
+    }
+
+    let class_bit = match class {
+        TagClass::Application => 0x00,
+        TagClass::Context => 0x08,
+    };
+    let number_bits = if number <= 14 { number << 4 } else { 0xF0 };
+    let lvt = if length < 5 { length as u8 } else { 5 };
+
+    buffer.push(number_bits | class_bit | lvt);
+    if number > 14 {
+        buffer.push(number);
+    }
+
+    if length >= 5 {
+        if length < 254 {
+            buffer.push(length as u8);
+        } else if length < 65536 {
+            buffer.push(254);
+            buffer.extend_from_slice(&(length as u16).to_be_bytes());
+        } else {
+            buffer.push(255);
+            buffer.extend_from_slice(&(length as u32).to_be_bytes());
+        }
+    }
+
+    Ok(())
+}
+
+/// Decode a tag header of either class
+///
+/// Returns the tag and the number of header bytes consumed. The content
+/// octets are not checked; callers should confirm `length` bytes remain.
+pub fn decode_tag(data: &[u8]) -> Result<(Tag, usize)> {
+    let first = *data.first().ok_or(EncodingError::UnexpectedEndOfData)?;
+    let class = if first & 0x08 != 0 {
+        TagClass::Context
+    } else {
+        TagClass::Application
+    };
+    let mut consumed = 1;
+
+    let mut number = first >> 4;
+    if number == 0x0F {
+        number = *data.get(1).ok_or(EncodingError::UnexpectedEndOfData)?;
+        consumed += 1;
+    }
+
+    let lvt = first & 0x07;
+    let mut tag = Tag {
+        number,
+        class,
+        length: lvt as usize,
+        is_opening: class == TagClass::Context && lvt == 6,
+        is_closing: class == TagClass::Context && lvt == 7,
+    };
+    if tag.is_opening || tag.is_closing {
+        tag.length = 0;
+        return Ok((tag, consumed));
+    }
+
+    if lvt == 5 {
+        let len_byte = *data
+            .get(consumed)
+            .ok_or(EncodingError::UnexpectedEndOfData)?;
+        consumed += 1;
+        tag.length = match len_byte {
+            0..=253 => len_byte as usize,
+            254 => {
+                let bytes = data
+                    .get(consumed..consumed + 2)
+                    .ok_or(EncodingError::UnexpectedEndOfData)?;
+                consumed += 2;
+                u16::from_be_bytes([bytes[0], bytes[1]]) as usize
+            }
+            255 => {
+                let bytes = data
+                    .get(consumed..consumed + 4)
+                    .ok_or(EncodingError::UnexpectedEndOfData)?;
+                consumed += 4;
+                u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize
+            }
+        };
+    }
+
+    Ok((tag, consumed))
+}
+
 /// Encode a BACnet boolean value
 pub fn encode_boolean(buffer: &mut Vec<u8>, value: bool) -> Result<()> {
     encode_application_tag(buffer, ApplicationTag::Boolean, if value { 1 } else { 0 })?;
@@ -324,7 +481,7 @@ pub fn decode_unsigned(data: &[u8]) -> Result<(u32, usize)> {
         return Err(EncodingError::InvalidTag);
     }
 
-    if data.len() < consumed + length {
+    if data.len() - consumed < length {
         return Err(EncodingError::BufferUnderflow);
     }
 
@@ -374,7 +531,7 @@ pub fn decode_signed(data: &[u8]) -> Result<(i32, usize)> {
         return Err(EncodingError::InvalidTag);
     }
 
-    if data.len() < consumed + length {
+    if data.len() - consumed < length {
         return Err(EncodingError::BufferUnderflow);
     }
 
@@ -457,7 +614,7 @@ pub fn decode_octet_string(data: &[u8]) -> Result<(Vec<u8>, usize)> {
         return Err(EncodingError::InvalidTag);
     }
 
-    if data.len() < consumed + length {
+    if data.len() - consumed < length {
         return Err(EncodingError::BufferUnderflow);
     }
 
@@ -475,34 +632,194 @@ pub fn encode_character_string(buffer: &mut Vec<u8>, value: &str) -> Result<()>
         ApplicationTag::CharacterString,
         string_bytes.len() + 1,
     )?;
-    buffer.push(0); // Character set encoding (0 = ANSI X3.4)
+    buffer.push(CharacterSet::Utf8 as u8);
     buffer.extend_from_slice(string_bytes);
     Ok(())
 }
 
 /// Decode a BACnet character string
+///
+/// ISO 8859-1, UCS-2 and UCS-4 strings are converted to UTF-8; strings in
+/// the DBCS and JIS character sets cannot be decoded.
 pub fn decode_character_string(data: &[u8]) -> Result<(String, usize)> {
-    let (tag, length, mut consumed) = decode_application_tag(data)?;
+    let (string, consumed) = CharacterString::decode(data)?;
+    let value = string.to_text().ok_or_else(|| {
+        EncodingError::InvalidFormat(format!(
+            "Cannot decode {:?} character string",
+            string.charset()
+        ))
+    })?;
+    Ok((value, consumed))
+}
 
-    if tag != ApplicationTag::CharacterString {
-        return Err(EncodingError::InvalidTag);
+/// BACnet character set identifiers carried in the first octet of a character string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum CharacterSet {
+    /// ISO 10646 (UTF-8), historically ANSI X3.4
+    Utf8 = 0,
+    /// IBM/Microsoft DBCS
+    IbmMicrosoftDbcs = 1,
+    /// JIS X 0208
+    JisX0208 = 2,
+    /// ISO 10646 (UCS-4)
+    Ucs4 = 3,
+    /// ISO 10646 (UCS-2)
+    Ucs2 = 4,
+    /// ISO 8859-1
+    Iso8859_1 = 5,
+}
+
+impl TryFrom<u8> for CharacterSet {
+    type Error = EncodingError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(CharacterSet::Utf8),
+            1 => Ok(CharacterSet::IbmMicrosoftDbcs),
+            2 => Ok(CharacterSet::JisX0208),
+            3 => Ok(CharacterSet::Ucs4),
+            4 => Ok(CharacterSet::Ucs2),
+            5 => Ok(CharacterSet::Iso8859_1),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
     }
+}
 
-    if data.len() < consumed + length || length == 0 {
-        return Err(EncodingError::BufferUnderflow);
+/// A BACnet character string together with its character set
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CharacterStringFields"))]
+pub struct CharacterString {
+    charset: CharacterSet,
+    data: Vec<u8>,
+}
+
+/// Unchecked fields of a deserialized character string
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct CharacterStringFields {
+    charset: CharacterSet,
+    data: Vec<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CharacterStringFields> for CharacterString {
+    type Error = EncodingError;
+
+    fn try_from(fields: CharacterStringFields) -> Result<Self> {
+        Self::new(fields.charset, fields.data)
     }
+}
 
-    // Skip character set encoding byte
-    let _encoding = data[consumed];
-    consumed += 1;
+impl CharacterString {
+    /// Create a character string, requiring at least one printable character
+    ///
+    /// Strings in a character set that cannot be decoded locally (DBCS, JIS)
+    /// are only checked for being non-empty.
+    pub fn new(charset: CharacterSet, data: Vec<u8>) -> Result<Self> {
+        let string = Self { charset, data };
+        if string.data.is_empty() {
+            return Err(EncodingError::InvalidFormat(
+                "Character string must not be empty".to_string(),
+            ));
+        }
+        if let Some(text) = string.to_text() {
+            if text.is_empty() || text.chars().any(char::is_control) {
+                return Err(EncodingError::InvalidFormat(
+                    "Character string must contain only printable characters".to_string(),
+                ));
+            }
+        }
+        Ok(string)
+    }
 
-    let string_data = &data[consumed..consumed + length - 1];
-    let value = String::from_utf8(string_data.to_vec())
-        .map_err(|_| EncodingError::InvalidFormat("Invalid UTF-8 string".to_string()))?;
+    /// Create a UTF-8 character string holding `text` unchanged
+    ///
+    /// Unlike [`new`](Self::new) this does not check the text, so it can
+    /// carry any string value, including an empty one.
+    pub fn from_text(text: &str) -> Self {
+        Self {
+            charset: CharacterSet::Utf8,
+            data: text.as_bytes().to_vec(),
+        }
+    }
+
+    /// Get the character set
+    pub fn charset(&self) -> CharacterSet {
+        self.charset
+    }
 
-    consumed += length - 1;
+    /// Get the raw encoded characters (without the character set octet)
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
 
-    Ok((value, consumed))
+    /// Decode the characters to text, if the character set is supported
+    pub fn to_text(&self) -> Option<String> {
+        match self.charset {
+            CharacterSet::Utf8 => String::from_utf8(self.data.clone()).ok(),
+            CharacterSet::Iso8859_1 => Some(self.data.iter().map(|&b| b as char).collect()),
+            CharacterSet::Ucs2 => {
+                if !self.data.len().is_multiple_of(2) {
+                    return None;
+                }
+                let units: Vec<u16> = self
+                    .data
+                    .chunks_exact(2)
+                    .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                    .collect();
+                String::from_utf16(&units).ok()
+            }
+            CharacterSet::Ucs4 => {
+                if !self.data.len().is_multiple_of(4) {
+                    return None;
+                }
+                self.data
+                    .chunks_exact(4)
+                    .map(|c| char::from_u32(u32::from_be_bytes([c[0], c[1], c[2], c[3]])))
+                    .collect()
+            }
+            CharacterSet::IbmMicrosoftDbcs | CharacterSet::JisX0208 => None,
+        }
+    }
+
+    /// Encode as an application tagged character string
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> Result<()> {
+        encode_application_tag(buffer, ApplicationTag::CharacterString, self.data.len() + 1)?;
+        buffer.push(self.charset as u8);
+        buffer.extend_from_slice(&self.data);
+        Ok(())
+    }
+
+    /// Decode an application tagged character string in any character set
+    pub fn decode(data: &[u8]) -> Result<(Self, usize)> {
+        let (tag, length, consumed) = decode_application_tag(data)?;
+
+        if tag != ApplicationTag::CharacterString {
+            return Err(EncodingError::InvalidTag);
+        }
+
+        if data.len() - consumed < length || length == 0 {
+            return Err(EncodingError::BufferUnderflow);
+        }
+
+        let charset = CharacterSet::try_from(data[consumed])?;
+        let string = Self {
+            charset,
+            data: data[consumed + 1..consumed + length].to_vec(),
+        };
+
+        Ok((string, consumed + length))
+    }
+}
+
+impl From<&str> for CharacterString {
+    /// Create a UTF-8 character string, unchecked as by [`CharacterString::from_text`]
+    fn from(value: &str) -> Self {
+        Self::from_text(value)
+    }
 }
 
 /// Encode a BACnet enumerated value
@@ -531,7 +848,7 @@ pub fn decode_enumerated(data: &[u8]) -> Result<(u32, usize)> {
         return Err(EncodingError::InvalidTag);
     }
 
-    if data.len() < consumed + length {
+    if data.len() - consumed < length {
         return Err(EncodingError::BufferUnderflow);
     }
 
@@ -556,9 +873,18 @@ pub fn decode_enumerated(data: &[u8]) -> Result<(u32, usize)> {
 }
 
 /// Encode a BACnet date
+///
+/// The year is 1900 through 2154, or 255 for the unspecified year that
+/// [`decode_date`] returns. Year octet 255 is reserved for "unspecified",
+/// so 2155 cannot be encoded.
 pub fn encode_date(buffer: &mut Vec<u8>, year: u16, month: u8, day: u8, weekday: u8) -> Result<()> {
+    let year = match year {
+        255 => 255,
+        1900..=2154 => (year - 1900) as u8,
+        _ => return Err(EncodingError::ValueOutOfRange),
+    };
     encode_application_tag(buffer, ApplicationTag::Date, 4)?;
-    buffer.push(((year - 1900) % 256) as u8);
+    buffer.push(year);
     buffer.push(month);
     buffer.push(day);
     buffer.push(weekday);
@@ -814,7 +1140,7 @@ pub fn decode_context_unsigned(data: &[u8], expected_tag: u8) -> Result<(u32, us
         return Err(EncodingError::InvalidTag);
     }
 
-    if data.len() < tag_consumed + length {
+    if data.len() - tag_consumed < length {
         return Err(EncodingError::BufferUnderflow);
     }
 
@@ -932,11 +1258,13 @@ impl TryFrom<u8> for ApplicationTag {
     }
 }
 
+pub use advanced::bitstring::{decode_bit_string, encode_bit_string};
+
 /// Advanced encoding features and optimizations
 pub mod advanced {
     use super::*;
     #[cfg(not(feature = "std"))]
-    use alloc::{collections::BTreeMap, vec::Vec};
+    use alloc::vec::Vec;
 
     /// Buffer manager for efficient encoding/decoding operations
     #[derive(Debug)]
@@ -1149,21 +1477,21 @@ pub mod advanced {
                 return Err(EncodingError::InvalidTag);
             }
 
-            if length == 0 || data.len() < consumed + length {
+            if length == 0 || data.len() - consumed < length {
                 return Err(EncodingError::BufferUnderflow);
             }
 
             let unused_bits = data[consumed] as usize;
             consumed += 1;
 
-            if unused_bits > 7 {
+            let byte_count = length - 1;
+            if unused_bits > 7 || (byte_count == 0 && unused_bits != 0) {
                 return Err(EncodingError::InvalidFormat(
                     "Invalid unused bits count".to_string(),
                 ));
             }
 
             let mut bits = Vec::new();
-            let byte_count = length - 1;
 
             for i in 0..byte_count {
                 let byte_val = data[consumed + i];
@@ -1292,19 +1620,15 @@ pub mod advanced {
                     pos += consumed;
 
                     match tag {
-                        ApplicationTag::CharacterString => {
-                            if length > self.max_string_length {
-                                return Err(EncodingError::InvalidFormat(
-                                    "String too long".to_string(),
-                                ));
-                            }
+                        ApplicationTag::CharacterString if length > self.max_string_length => {
+                            return Err(EncodingError::InvalidFormat(
+                                "String too long".to_string(),
+                            ));
                         }
-                        ApplicationTag::OctetString => {
-                            if length > self.max_string_length * 2 {
-                                return Err(EncodingError::InvalidFormat(
-                                    "Octet string too long".to_string(),
-                                ));
-                            }
+                        ApplicationTag::OctetString if length > self.max_string_length * 2 => {
+                            return Err(EncodingError::InvalidFormat(
+                                "Octet string too long".to_string(),
+                            ));
                         }
                         _ => {}
                     }
@@ -1590,6 +1914,113 @@ impl<'a> DecodingStream<'a> {
     }
 }
 
+/// Incremental tag reader over a byte cursor
+///
+/// Yields one tag and its content octets at a time without copying or
+/// collecting the values, so a constructed value such as a large
+/// ReadPropertyMultiple response can be walked on memory-limited targets.
+/// Opening and closing tags are yielded like any other tag, with empty
+/// content.
+#[derive(Debug, Clone)]
+pub struct TagReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> TagReader<'a> {
+    /// Create a reader positioned at the start of `data`
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// Number of bytes not yet read
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    /// Check if every byte has been read
+    pub fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Offset of the next tag from the start of the buffer
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Read the next tag and its content octets
+    ///
+    /// Returns `Ok(None)` once the buffer is exhausted, and
+    /// [`EncodingError::UnexpectedEndOfData`] if the buffer ends inside a tag
+    /// header or its content. Application tagged booleans carry their value
+    /// in [`Tag::length`] and have empty content.
+    pub fn next_tag(&mut self) -> Result<Option<(Tag, &'a [u8])>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        let (tag, content) = self.peek_at(self.position)?;
+        self.position = content.1;
+        Ok(Some((tag, &self.data[content.0..content.1])))
+    }
+
+    /// Look at the next tag without consuming it
+    pub fn peek_tag(&self) -> Result<Option<Tag>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        self.peek_at(self.position).map(|(tag, _)| Some(tag))
+    }
+
+    /// Read a constructed value, returning a reader over its contents
+    ///
+    /// The next tag must be the opening tag `number`; the reader moves past
+    /// the matching closing tag, skipping any nested constructed values.
+    pub fn read_constructed(&mut self, number: u8) -> Result<TagReader<'a>> {
+        match self.peek_tag()? {
+            Some(tag) if tag.is_opening && tag.number == number => {}
+            Some(_) => return Err(EncodingError::InvalidTag),
+            None => return Err(EncodingError::UnexpectedEndOfData),
+        }
+        let mut cursor = self.clone();
+        cursor.next_tag()?;
+        let start = cursor.position;
+        let mut depth = 0usize;
+        loop {
+            let end = cursor.position;
+            let (tag, _) = cursor
+                .next_tag()?
+                .ok_or(EncodingError::UnexpectedEndOfData)?;
+            if tag.is_opening {
+                depth += 1;
+            } else if tag.is_closing {
+                if depth == 0 {
+                    if tag.number != number {
+                        return Err(EncodingError::InvalidTag);
+                    }
+                    self.position = cursor.position;
+                    return Ok(TagReader::new(&self.data[start..end]));
+                }
+                depth -= 1;
+            }
+        }
+    }
+
+    /// Decode the tag header at `offset`, returning the tag and the range of
+    /// its content octets
+    fn peek_at(&self, offset: usize) -> Result<(Tag, (usize, usize))> {
+        let (tag, header) = decode_tag(&self.data[offset..])?;
+        let start = offset + header;
+        let is_boolean =
+            tag.class == TagClass::Application && tag.number == ApplicationTag::Boolean as u8;
+        let length = if is_boolean { 0 } else { tag.length };
+        let end = start
+            .checked_add(length)
+            .filter(|&end| end <= self.data.len())
+            .ok_or(EncodingError::UnexpectedEndOfData)?;
+        Ok((tag, (start, end)))
+    }
+}
+
 /// Property array encoder
 #[derive(Default)]
 pub struct PropertyArrayEncoder {
@@ -1806,7 +2237,7 @@ impl EncodingAnalyzer {
         if let Some(pattern) = self
             .error_patterns
             .iter_mut()
-            .find(|p| std::mem::discriminant(&p.error_type) == std::mem::discriminant(&error))
+            .find(|p| core::mem::discriminant(&p.error_type) == core::mem::discriminant(&error))
         {
             pattern.count += 1;
             #[cfg(feature = "std")]
@@ -1850,7 +2281,7 @@ impl EncodingAnalyzer {
             .iter()
             .map(|p| (&p.error_type, p.count))
             .collect();
-        errors.sort_by(|a, b| b.1.cmp(&a.1));
+        errors.sort_by_key(|e| core::cmp::Reverse(e.1));
         errors.truncate(limit);
         errors
     }
@@ -1991,6 +2422,15 @@ pub enum ValidationLevel {
     Paranoid,
 }
 
+impl EncodingConfig {
+    /// Decode limits matching this configuration
+    pub fn decode_limits(&self) -> DecodeLimits {
+        DecodeLimits {
+            max_elements: self.max_array_size,
+        }
+    }
+}
+
 impl Default for EncodingConfig {
     fn default() -> Self {
         Self {
@@ -2186,6 +2626,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_integer_minimal_length() {
+        // The tag's length/value/type field carries the content length
+        let cases: [(u32, &[u8]); 6] = [
+            (0, &[0x21, 0x00]),
+            (255, &[0x21, 0xFF]),
+            (256, &[0x22, 0x01, 0x00]),
+            (65536, &[0x23, 0x01, 0x00, 0x00]),
+            (16777216, &[0x24, 0x01, 0x00, 0x00, 0x00]),
+            (0xDEADBEEF, &[0x24, 0xDE, 0xAD, 0xBE, 0xEF]),
+        ];
+        for (value, expected) in cases {
+            let mut buffer = Vec::new();
+            encode_unsigned(&mut buffer, value).unwrap();
+            assert_eq!(buffer, expected);
+            assert_eq!(decode_unsigned(&buffer).unwrap(), (value, buffer.len()));
+        }
+
+        let cases: [(i32, &[u8]); 6] = [
+            (0, &[0x31, 0x00]),
+            (-1, &[0x31, 0xFF]),
+            (127, &[0x31, 0x7F]),
+            (128, &[0x32, 0x00, 0x80]),
+            (-129, &[0x32, 0xFF, 0x7F]),
+            (i32::MIN, &[0x34, 0x80, 0x00, 0x00, 0x00]),
+        ];
+        for (value, expected) in cases {
+            let mut buffer = Vec::new();
+            encode_signed(&mut buffer, value).unwrap();
+            assert_eq!(buffer, expected);
+            assert_eq!(decode_signed(&buffer).unwrap(), (value, buffer.len()));
+        }
+
+        // A length the content does not fill is an error, not a short read
+        assert!(decode_unsigned(&[0x22, 0x01]).is_err());
+        // Trailing bytes belong to the next value
+        assert_eq!(decode_unsigned(&[0x21, 0x05, 0x00]).unwrap(), (5, 2));
+    }
+
     #[test]
     fn test_encode_decode_real() {
         let mut buffer = Vec::new();
@@ -2220,6 +2699,115 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_character_string_multibyte_length() {
+        let mut buffer = Vec::new();
+        for name in ["Büro 1", "温度", "Zone Δ"] {
+            buffer.clear();
+            encode_character_string(&mut buffer, name).unwrap();
+
+            // The length counts the character set octet and UTF-8 bytes
+            let (tag, length, header) = decode_application_tag(&buffer).unwrap();
+            assert_eq!(tag, ApplicationTag::CharacterString);
+            assert_eq!(length, name.len() + 1);
+            assert_eq!(buffer[header], CharacterSet::Utf8 as u8);
+            assert_eq!(&buffer[header + 1..], name.as_bytes());
+
+            let (decoded, consumed) = decode_character_string(&buffer).unwrap();
+            assert_eq!(decoded, name);
+            assert_eq!(consumed, buffer.len());
+        }
+        assert_eq!(buffer[..2], [0x75, 0x08]);
+
+        // Other character sets decode to the same text
+        let latin1 = [0x75, 0x07, 0x05, b'B', 0xFC, b'r', b'o', b' ', b'1'];
+        assert_eq!(decode_character_string(&latin1).unwrap().0, "Büro 1");
+        let ucs2 = [0x75, 0x05, 0x04, 0x6E, 0x29, 0x5E, 0xA6];
+        assert_eq!(decode_character_string(&ucs2).unwrap().0, "温度");
+        let dbcs = [0x75, 0x03, 0x01, 0x89, 0xB7];
+        assert!(decode_character_string(&dbcs).is_err());
+    }
+
+    #[test]
+    fn test_encode_decode_tag() {
+        let mut buffer = Vec::new();
+
+        // Context tag 3, length 2 fits in a single octet
+        encode_tag(&mut buffer, 3, TagClass::Context, 2).unwrap();
+        assert_eq!(buffer, vec![0x3A]);
+
+        // Extended tag number with an extended length
+        buffer.clear();
+        encode_tag(&mut buffer, 33, TagClass::Context, 300).unwrap();
+        assert_eq!(buffer, vec![0xFD, 33, 254, 0x01, 0x2C]);
+        let (tag, consumed) = decode_tag(&buffer).unwrap();
+        assert_eq!(tag.number, 33);
+        assert_eq!(tag.class, TagClass::Context);
+        assert_eq!(tag.length, 300);
+        assert_eq!(consumed, 5);
+
+        // Application tags decode with their class
+        let (tag, _) = decode_tag(&[0x44, 0, 0, 0, 0]).unwrap();
+        assert_eq!(tag.number, ApplicationTag::Real as u8);
+        assert_eq!(tag.class, TagClass::Application);
+        assert_eq!(tag.length, 4);
+
+        // Opening and closing tags
+        let (open, _) = decode_tag(&[0x3E]).unwrap();
+        let (close, _) = decode_tag(&[0x3F]).unwrap();
+        assert!(open.is_opening && !open.is_closing);
+        assert!(close.is_closing && open.number == close.number);
+
+        // Truncated headers are reported rather than panicking
+        for truncated in [&[][..], &[0xF9], &[0x3D], &[0x3D, 254, 0x01]] {
+            assert!(matches!(
+                decode_tag(truncated),
+                Err(EncodingError::UnexpectedEndOfData)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_character_string_charsets() {
+        let name = CharacterString::from("AHU-1");
+        assert_eq!(name.charset(), CharacterSet::Utf8);
+
+        let mut buffer = Vec::new();
+        name.encode(&mut buffer).unwrap();
+        let (decoded, consumed) = CharacterString::decode(&buffer).unwrap();
+        assert_eq!(decoded, name);
+        assert_eq!(consumed, buffer.len());
+
+        // UCS-2 "Hi" survives decoding with its character set intact
+        let data = [0x75, 0x05, 0x04, 0x00, 0x48, 0x00, 0x69];
+        let (ucs2, _) = CharacterString::decode(&data).unwrap();
+        assert_eq!(ucs2.charset(), CharacterSet::Ucs2);
+        assert_eq!(ucs2.to_text().as_deref(), Some("Hi"));
+
+        assert!(CharacterString::new(CharacterSet::Utf8, Vec::new()).is_err());
+        assert!(CharacterString::new(CharacterSet::Utf8, b"line\n".to_vec()).is_err());
+        assert!(CharacterString::new(CharacterSet::Iso8859_1, vec![0x43, 0xE9]).is_ok());
+        // Conversion from text keeps it as is; only `new` validates
+        assert_eq!(CharacterString::from("Zone\t1").as_bytes(), b"Zone\t1");
+        assert!(CharacterString::from("").as_bytes().is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_character_string_deserialize_validates() {
+        let name = CharacterString::from("AHU-1");
+        let json = serde_json::to_string(&name).unwrap();
+        assert_eq!(
+            serde_json::from_str::<CharacterString>(&json).unwrap(),
+            name
+        );
+
+        let empty = r#"{"charset":"Utf8","data":[]}"#;
+        assert!(serde_json::from_str::<CharacterString>(empty).is_err());
+        let control = r#"{"charset":"Utf8","data":[65,10]}"#;
+        assert!(serde_json::from_str::<CharacterString>(control).is_err());
+    }
+
     #[test]
     fn test_encode_decode_octet_string() {
         let mut buffer = Vec::new();
@@ -2255,6 +2843,24 @@ mod tests {
         assert_eq!(weekday, 5);
     }
 
+    #[test]
+    fn test_encode_date_year_range() {
+        let mut buffer = Vec::new();
+        assert!(matches!(
+            encode_date(&mut buffer, 1800, 1, 1, 3),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+        assert!(matches!(
+            encode_date(&mut buffer, 2155, 1, 1, 3),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+        assert!(buffer.is_empty());
+
+        encode_date(&mut buffer, 1900, 1, 1, 1).unwrap();
+        encode_date(&mut buffer, 2154, 12, 31, 4).unwrap();
+        assert_eq!(buffer, vec![0xA4, 0, 1, 1, 1, 0xA4, 254, 12, 31, 4]);
+    }
+
     #[test]
     fn test_encode_decode_time() {
         let mut buffer = Vec::new();
@@ -2407,4 +3013,66 @@ mod tests {
             assert_eq!(value, i);
         }
     }
+
+    #[test]
+    fn test_tag_reader_walks_constructed_value() {
+        let mut buffer = encode_context_unsigned(85, 0).unwrap();
+        advanced::context::encode_opening_tag(&mut buffer, 1).unwrap();
+        encode_real(&mut buffer, 21.5).unwrap();
+        advanced::context::encode_opening_tag(&mut buffer, 2).unwrap();
+        encode_boolean(&mut buffer, true).unwrap();
+        advanced::context::encode_closing_tag(&mut buffer, 2).unwrap();
+        advanced::context::encode_closing_tag(&mut buffer, 1).unwrap();
+        encode_unsigned(&mut buffer, 7).unwrap();
+
+        let mut reader = TagReader::new(&buffer);
+        let (tag, content) = reader.next_tag().unwrap().unwrap();
+        assert_eq!(
+            (tag.class, tag.number, content),
+            (TagClass::Context, 0, &[85][..])
+        );
+
+        let mut inner = reader.read_constructed(1).unwrap();
+        let (tag, content) = inner.next_tag().unwrap().unwrap();
+        assert_eq!(tag.number, ApplicationTag::Real as u8);
+        assert_eq!(content, &21.5f32.to_be_bytes()[..]);
+        assert!(inner.next_tag().unwrap().unwrap().0.is_opening);
+        let (tag, content) = inner.next_tag().unwrap().unwrap();
+        assert_eq!((tag.length, content.len()), (1, 0));
+        assert!(inner.next_tag().unwrap().unwrap().0.is_closing);
+        assert_eq!(inner.next_tag().unwrap(), None);
+
+        assert_eq!(reader.remaining(), 2);
+        let (_, content) = reader.next_tag().unwrap().unwrap();
+        assert_eq!(content, &[7][..]);
+        assert!(reader.is_empty());
+        assert_eq!(reader.next_tag().unwrap(), None);
+    }
+
+    #[test]
+    fn test_tag_reader_detects_truncation() {
+        let mut buffer = Vec::new();
+        encode_character_string(&mut buffer, "Zone Temp").unwrap();
+        buffer.truncate(buffer.len() - 1);
+        let mut reader = TagReader::new(&buffer);
+        assert!(matches!(
+            reader.next_tag(),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+        // A failed read leaves the cursor in place
+        assert_eq!(reader.position(), 0);
+
+        let mut buffer = Vec::new();
+        advanced::context::encode_opening_tag(&mut buffer, 3).unwrap();
+        encode_unsigned(&mut buffer, 1).unwrap();
+        let mut reader = TagReader::new(&buffer);
+        assert!(matches!(
+            reader.read_constructed(3),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+        assert!(matches!(
+            reader.read_constructed(4),
+            Err(EncodingError::InvalidTag)
+        ));
+    }
 }
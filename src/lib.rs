@@ -78,7 +78,7 @@
 //!
 //! - `std` (default): Enable standard library support
 //! - `async` (default): Enable async/await support with Tokio
-//! - `serde` (default): Enable serialization support
+//! - `serde` (default): Enable serde serialization of objects and property values
 //! - `no-std`: Disable standard library for embedded use
 //!
 //! ## License
@@ -99,6 +99,9 @@ extern crate alloc;
 /// Application layer protocol services and message handling
 pub mod app;
 
+/// BACnet datatypes: dates and times with wildcard support, and bit strings
+pub mod datatypes;
+
 /// Data link layer implementations for various BACnet physical networks
 pub mod datalink;
 
@@ -130,6 +133,10 @@ pub mod client;
 /// Property value decoders for various BACnet data types
 pub mod property;
 
+/// Device server answering requests from the objects it holds (requires std feature)
+#[cfg(feature = "std")]
+pub mod server;
+
 // Re-export main types for convenient access
 pub use datalink::{DataLink, DataLinkAddress, DataLinkType};
 pub use encoding::{ApplicationTag, EncodingError};
@@ -164,7 +171,7 @@ mod tests {
         assert_eq!(tag as u8, 1);
 
         let obj_type = ObjectType::AnalogInput;
-        assert_eq!(obj_type as u16, 0);
+        assert_eq!(obj_type.as_u16(), 0);
 
         let obj_id = ObjectIdentifier::new(ObjectType::Device, 123);
         assert_eq!(obj_id.instance, 123);
@@ -34,6 +34,8 @@
 //!     destination: None,
 //!     source: None,
 //!     hop_count: None,
+//!     network_message_type: None,
+//!     vendor_id: None,
 //! };
 //! ```
 
@@ -52,13 +54,22 @@ extern crate alloc;
 #[cfg(not(feature = "std"))]
 use alloc::{
     collections::{BTreeMap, BTreeSet},
-    string::String,
+    format,
+    string::{String, ToString},
+    vec,
     vec::Vec,
 };
 
 #[cfg(feature = "std")]
 use std::collections::{BTreeMap, BTreeSet};
 
+use core::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+
+use crate::datalink::{sc::Vmac, DataLinkAddress, DataLinkType};
+
+/// Router forwarding NPDUs between data link ports
+pub mod router;
+
 /// Result type for network operations
 #[cfg(feature = "std")]
 pub type Result<T> = std::result::Result<T, NetworkError>;
@@ -114,6 +125,31 @@ pub enum NetworkMessageType {
     NetworkNumberIs = 0x13,
 }
 
+impl TryFrom<u8> for NetworkMessageType {
+    type Error = NetworkError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0x00 => Ok(NetworkMessageType::WhoIsRouterToNetwork),
+            0x01 => Ok(NetworkMessageType::IAmRouterToNetwork),
+            0x02 => Ok(NetworkMessageType::ICouldBeRouterToNetwork),
+            0x03 => Ok(NetworkMessageType::RejectMessageToNetwork),
+            0x04 => Ok(NetworkMessageType::RouterBusyToNetwork),
+            0x05 => Ok(NetworkMessageType::RouterAvailableToNetwork),
+            0x06 => Ok(NetworkMessageType::InitializeRoutingTable),
+            0x07 => Ok(NetworkMessageType::InitializeRoutingTableAck),
+            0x08 => Ok(NetworkMessageType::EstablishConnectionToNetwork),
+            0x09 => Ok(NetworkMessageType::DisconnectConnectionToNetwork),
+            0x12 => Ok(NetworkMessageType::WhatIsNetworkNumber),
+            0x13 => Ok(NetworkMessageType::NetworkNumberIs),
+            _ => Err(NetworkError::InvalidNpdu(format!(
+                "Unknown network message type: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// NPDU control flags
 #[derive(Debug, Clone, Copy, Default)]
 pub struct NpduControl {
@@ -163,6 +199,7 @@ impl NpduControl {
 
 /// Network address (network number + MAC address)
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkAddress {
     /// Network number (0 = local network, 65535 = broadcast)
     pub network: u16,
@@ -187,6 +224,245 @@ impl NetworkAddress {
     }
 }
 
+/// Global broadcast network number
+pub const GLOBAL_BROADCAST_NETWORK: u16 = 0xFFFF;
+
+/// MAC address of a BACnet device on its own network
+///
+/// Encodes to the octet string carried in NPDU DADR/SADR fields, where an
+/// empty string is a broadcast on the network.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MacAddress {
+    /// BACnet/IP address: IPv4 address and UDP port (6 octets)
+    Ip(SocketAddrV4),
+    /// MS/TP station (1 octet)
+    MsTp(u8),
+    /// Ethernet MAC address (6 octets)
+    Ethernet([u8; 6]),
+    /// BACnet/SC virtual MAC address (6 octets)
+    Vmac(Vmac),
+    /// Broadcast on the network (no octets)
+    Broadcast,
+    /// Address on another kind of data link, as its octets
+    Other(Vec<u8>),
+}
+
+impl MacAddress {
+    /// Encode as the DADR/SADR octet string
+    pub fn octets(&self) -> Vec<u8> {
+        match self {
+            MacAddress::Ip(addr) => {
+                let mut octets = addr.ip().octets().to_vec();
+                octets.extend_from_slice(&addr.port().to_be_bytes());
+                octets
+            }
+            MacAddress::MsTp(station) => vec![*station],
+            MacAddress::Ethernet(mac) => mac.to_vec(),
+            MacAddress::Vmac(vmac) => vmac.as_bytes().to_vec(),
+            MacAddress::Broadcast => Vec::new(),
+            MacAddress::Other(octets) => octets.clone(),
+        }
+    }
+
+    /// Decode a DADR/SADR octet string
+    ///
+    /// One octet is an MS/TP station. Six octets are ambiguous, so
+    /// `link_type` picks between Ethernet, BACnet/SC and BACnet/IP, which is
+    /// assumed for other data link types. Any other length is kept as-is.
+    pub fn from_octets(octets: &[u8], link_type: DataLinkType) -> Self {
+        match (octets, link_type) {
+            ([], _) => MacAddress::Broadcast,
+            ([station], _) => MacAddress::MsTp(*station),
+            (&[a, b, c, d, e, f], DataLinkType::Ethernet) => {
+                MacAddress::Ethernet([a, b, c, d, e, f])
+            }
+            (&[a, b, c, d, e, f], DataLinkType::SecureConnect) => {
+                MacAddress::Vmac(Vmac([a, b, c, d, e, f]))
+            }
+            (&[a, b, c, d, e, f], _) => MacAddress::Ip(SocketAddrV4::new(
+                Ipv4Addr::new(a, b, c, d),
+                u16::from_be_bytes([e, f]),
+            )),
+            (octets, _) => MacAddress::Other(octets.to_vec()),
+        }
+    }
+
+    /// Get the data link address to send a frame to this device directly
+    ///
+    /// Returns `None` for addresses of other data link types.
+    pub fn to_datalink_address(&self) -> Option<DataLinkAddress> {
+        match self {
+            #[cfg(feature = "std")]
+            MacAddress::Ip(addr) => Some(DataLinkAddress::Ip(SocketAddr::V4(*addr))),
+            MacAddress::MsTp(station) => Some(DataLinkAddress::MsTP(*station)),
+            MacAddress::Ethernet(mac) => Some(DataLinkAddress::Ethernet(*mac)),
+            MacAddress::Vmac(vmac) => Some(DataLinkAddress::Vmac(*vmac)),
+            MacAddress::Broadcast => Some(DataLinkAddress::Broadcast),
+            _ => None,
+        }
+    }
+}
+
+impl From<&DataLinkAddress> for MacAddress {
+    fn from(addr: &DataLinkAddress) -> Self {
+        match addr {
+            #[cfg(feature = "std")]
+            DataLinkAddress::Ip(addr) => addr.into(),
+            DataLinkAddress::Ethernet(mac) => MacAddress::Ethernet(*mac),
+            DataLinkAddress::MsTP(station) => MacAddress::MsTp(*station),
+            DataLinkAddress::Broadcast => MacAddress::Broadcast,
+            DataLinkAddress::Vmac(vmac) => MacAddress::Vmac(*vmac),
+        }
+    }
+}
+
+impl From<&SocketAddr> for MacAddress {
+    fn from(addr: &SocketAddr) -> Self {
+        match addr {
+            SocketAddr::V4(addr) => MacAddress::Ip(*addr),
+            SocketAddr::V6(addr) => {
+                let mut octets = addr.ip().octets().to_vec();
+                octets.extend_from_slice(&addr.port().to_be_bytes());
+                MacAddress::Other(octets)
+            }
+        }
+    }
+}
+
+/// Address of a BACnet device, on the local or a remote network
+///
+/// Local addresses are reached directly on the data link. Remote addresses
+/// are reached through a router, with the network number and MAC address
+/// carried in the NPDU as DNET/DADR.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BacnetAddress {
+    /// Network number, `None` for the local network
+    pub network_number: Option<u16>,
+    /// MAC address on that network
+    pub mac: MacAddress,
+}
+
+impl BacnetAddress {
+    /// Create an address on the local network
+    pub fn local(mac: MacAddress) -> Self {
+        Self {
+            network_number: None,
+            mac,
+        }
+    }
+
+    /// Create an address on a remote network
+    pub fn remote(network_number: u16, mac: MacAddress) -> Self {
+        Self {
+            network_number: Some(network_number),
+            mac,
+        }
+    }
+
+    /// Create the global broadcast address
+    pub fn global_broadcast() -> Self {
+        Self::remote(GLOBAL_BROADCAST_NETWORK, MacAddress::Broadcast)
+    }
+
+    /// Check if the address is on a remote network
+    pub fn is_remote(&self) -> bool {
+        self.network_number.is_some()
+    }
+
+    /// Check if this is a broadcast, local, remote or global
+    pub fn is_broadcast(&self) -> bool {
+        self.mac == MacAddress::Broadcast
+    }
+
+    /// Get the NPDU DNET/DADR (or SNET/SADR) for this address
+    ///
+    /// Returns `None` for local addresses, which are not carried in the NPDU.
+    pub fn to_network_address(&self) -> Option<NetworkAddress> {
+        let network = self.network_number?;
+        let address = if network == GLOBAL_BROADCAST_NETWORK {
+            Vec::new()
+        } else {
+            self.mac.octets()
+        };
+        Some(NetworkAddress::new(network, address))
+    }
+
+    /// Create the address of a remote device from an NPDU SNET/SADR or DNET/DADR
+    ///
+    /// `link_type` resolves the MAC address format as in
+    /// [`MacAddress::from_octets`].
+    pub fn from_network_address(address: &NetworkAddress, link_type: DataLinkType) -> Self {
+        Self::remote(
+            address.network,
+            MacAddress::from_octets(&address.address, link_type),
+        )
+    }
+
+    /// Get the address of the device that sent an NPDU
+    ///
+    /// The NPDU's SNET/SADR identify devices behind a router; otherwise the
+    /// sender is the local device at `source`, which sent the frame.
+    pub fn npdu_source(npdu: &Npdu, source: &DataLinkAddress, link_type: DataLinkType) -> Self {
+        match &npdu.source {
+            Some(address) => Self::from_network_address(address, link_type),
+            None => Self::local(source.into()),
+        }
+    }
+}
+
+impl From<MacAddress> for BacnetAddress {
+    fn from(mac: MacAddress) -> Self {
+        Self::local(mac)
+    }
+}
+
+impl From<&DataLinkAddress> for BacnetAddress {
+    fn from(addr: &DataLinkAddress) -> Self {
+        Self::local(addr.into())
+    }
+}
+
+impl From<DataLinkAddress> for BacnetAddress {
+    fn from(addr: DataLinkAddress) -> Self {
+        Self::local((&addr).into())
+    }
+}
+
+impl From<SocketAddr> for BacnetAddress {
+    fn from(addr: SocketAddr) -> Self {
+        Self::local((&addr).into())
+    }
+}
+
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MacAddress::Ip(addr) => write!(f, "{}", addr),
+            MacAddress::MsTp(station) => write!(f, "{}", station),
+            MacAddress::Vmac(vmac) => write!(f, "{}", vmac),
+            MacAddress::Broadcast => write!(f, "broadcast"),
+            MacAddress::Ethernet(_) | MacAddress::Other(_) => {
+                let octets: Vec<String> = self
+                    .octets()
+                    .iter()
+                    .map(|octet| format!("{:02X}", octet))
+                    .collect();
+                write!(f, "{}", octets.join(":"))
+            }
+        }
+    }
+}
+
+impl fmt::Display for BacnetAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.network_number {
+            Some(GLOBAL_BROADCAST_NETWORK) => write!(f, "global broadcast"),
+            Some(network) => write!(f, "{}:{}", network, self.mac),
+            None => write!(f, "{}", self.mac),
+        }
+    }
+}
+
 /// Network Protocol Data Unit (NPDU)
 #[derive(Debug, Clone)]
 pub struct Npdu {
@@ -200,6 +476,10 @@ pub struct Npdu {
     pub source: Option<NetworkAddress>,
     /// Hop count (only present if destination is present)
     pub hop_count: Option<u8>,
+    /// Network layer message type (only present for network layer messages)
+    pub network_message_type: Option<u8>,
+    /// Vendor ID (only present for proprietary message types 0x80-0xFF)
+    pub vendor_id: Option<u16>,
 }
 
 impl Npdu {
@@ -211,6 +491,8 @@ impl Npdu {
             destination: None,
             source: None,
             hop_count: None,
+            network_message_type: None,
+            vendor_id: None,
         }
     }
 
@@ -231,13 +513,37 @@ impl Npdu {
             }),
             source: None,
             hop_count: Some(255),
+            network_message_type: None,
+            vendor_id: None,
         }
     }
 
+    /// Create NPDU carrying a network layer message
+    pub fn network_message(message_type: NetworkMessageType) -> Self {
+        let mut npdu = Self::new();
+        npdu.control.network_message = true;
+        npdu.network_message_type = Some(message_type as u8);
+        npdu
+    }
+
     /// Check if this is a network layer message
     pub fn is_network_message(&self) -> bool {
         self.control.network_message
     }
+
+    /// Create an NPDU addressed to `destination`
+    ///
+    /// Remote destinations are carried as DNET/DADR with a full hop count;
+    /// local ones need no addressing in the NPDU.
+    pub fn to_address(destination: &BacnetAddress) -> Self {
+        let mut npdu = Self::new();
+        npdu.destination = destination.to_network_address();
+        npdu.control.destination_present = npdu.destination.is_some();
+        if npdu.destination.is_some() {
+            npdu.hop_count = Some(255);
+        }
+        npdu
+    }
 }
 
 /// Router information
@@ -259,8 +565,12 @@ impl Npdu {
         // Version
         buffer.push(self.version);
 
-        // Control byte
-        buffer.push(self.control.to_byte());
+        // Control byte, with the addressing bits matching the fields present
+        let mut control = self.control;
+        control.destination_present = self.destination.is_some();
+        control.source_present = self.source.is_some();
+        control.network_message |= self.network_message_type.is_some();
+        buffer.push(control.to_byte());
 
         // Destination network address
         if let Some(ref dest) = self.destination {
@@ -281,6 +591,14 @@ impl Npdu {
             buffer.push(self.hop_count.unwrap_or(255));
         }
 
+        // Network layer message type (and vendor ID for proprietary types)
+        if let Some(message_type) = self.network_message_type {
+            buffer.push(message_type);
+            if message_type >= 0x80 {
+                buffer.extend_from_slice(&self.vendor_id.unwrap_or(0).to_be_bytes());
+            }
+        }
+
         buffer
     }
 
@@ -375,12 +693,37 @@ impl Npdu {
             None
         };
 
+        // Network layer message type (only for network layer messages)
+        let mut vendor_id = None;
+        let network_message_type = if control.network_message {
+            if pos >= data.len() {
+                return Err(NetworkError::InvalidNpdu(
+                    "Missing network message type".to_string(),
+                ));
+            }
+            let message_type = data[pos];
+            pos += 1;
+
+            if message_type >= 0x80 {
+                if pos + 2 > data.len() {
+                    return Err(NetworkError::InvalidNpdu("Missing vendor ID".to_string()));
+                }
+                vendor_id = Some(u16::from_be_bytes([data[pos], data[pos + 1]]));
+                pos += 2;
+            }
+            Some(message_type)
+        } else {
+            None
+        };
+
         let npdu = Npdu {
             version,
             control,
             destination,
             source,
             hop_count,
+            network_message_type,
+            vendor_id,
         };
 
         Ok((npdu, pos))
@@ -393,7 +736,48 @@ impl Default for Npdu {
     }
 }
 
+/// Reason codes of a Reject-Message-To-Network message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RejectMessageReason {
+    /// Any reason not covered by another code
+    Other = 0,
+    /// The router does not know a route to the network
+    UnknownNetwork = 1,
+    /// The router is busy and not accepting messages for the network
+    RouterBusy = 2,
+    /// The network layer message type is not known
+    UnknownMessageType = 3,
+    /// The message is too long to be routed to the network
+    MessageTooLong = 4,
+    /// The message was rejected for security reasons
+    SecurityError = 5,
+    /// The source or destination address is invalid
+    AddressingError = 6,
+}
+
+impl TryFrom<u8> for RejectMessageReason {
+    type Error = NetworkError;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(RejectMessageReason::Other),
+            1 => Ok(RejectMessageReason::UnknownNetwork),
+            2 => Ok(RejectMessageReason::RouterBusy),
+            3 => Ok(RejectMessageReason::UnknownMessageType),
+            4 => Ok(RejectMessageReason::MessageTooLong),
+            5 => Ok(RejectMessageReason::SecurityError),
+            6 => Ok(RejectMessageReason::AddressingError),
+            _ => Err(NetworkError::InvalidNpdu(format!(
+                "Unknown reject reason: {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Network layer message handling
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NetworkLayerMessage {
     /// Message type
     pub message_type: NetworkMessageType,
@@ -422,26 +806,7 @@ impl NetworkLayerMessage {
             ));
         }
 
-        let message_type = match data[0] {
-            0x00 => NetworkMessageType::WhoIsRouterToNetwork,
-            0x01 => NetworkMessageType::IAmRouterToNetwork,
-            0x02 => NetworkMessageType::ICouldBeRouterToNetwork,
-            0x03 => NetworkMessageType::RejectMessageToNetwork,
-            0x04 => NetworkMessageType::RouterBusyToNetwork,
-            0x05 => NetworkMessageType::RouterAvailableToNetwork,
-            0x06 => NetworkMessageType::InitializeRoutingTable,
-            0x07 => NetworkMessageType::InitializeRoutingTableAck,
-            0x08 => NetworkMessageType::EstablishConnectionToNetwork,
-            0x09 => NetworkMessageType::DisconnectConnectionToNetwork,
-            0x12 => NetworkMessageType::WhatIsNetworkNumber,
-            0x13 => NetworkMessageType::NetworkNumberIs,
-            _ => {
-                return Err(NetworkError::InvalidNpdu(format!(
-                    "Unknown network message type: {}",
-                    data[0]
-                )))
-            }
-        };
+        let message_type = NetworkMessageType::try_from(data[0])?;
 
         let message_data = if data.len() > 1 {
             data[1..].to_vec()
@@ -453,6 +818,296 @@ impl NetworkLayerMessage {
     }
 }
 
+/// Routing table entry of an Initialize-Routing-Table message
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutingTablePort {
+    /// Destination network reachable through the port
+    pub network: u16,
+    /// Port ID, 0 to remove the entry
+    pub port_id: u8,
+    /// Port information, such as a dial string
+    pub port_info: Vec<u8>,
+}
+
+/// Decoded network layer message
+///
+/// Network layer messages ride in an NPDU with the network layer message
+/// control bit set and carry no APDU.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkMessage {
+    /// Who-Is-Router-To-Network, for one network or for all
+    WhoIsRouterToNetwork(Option<u16>),
+    /// I-Am-Router-To-Network with the networks the router reaches
+    IAmRouterToNetwork(Vec<u16>),
+    /// I-Could-Be-Router-To-Network, for a half-router that can dial out
+    ICouldBeRouterToNetwork { network: u16, performance_index: u8 },
+    /// Reject-Message-To-Network
+    RejectMessageToNetwork {
+        reason: RejectMessageReason,
+        network: u16,
+    },
+    /// Router-Busy-To-Network, for the listed networks or all of them
+    RouterBusyToNetwork(Vec<u16>),
+    /// Router-Available-To-Network, for the listed networks or all of them
+    RouterAvailableToNetwork(Vec<u16>),
+    /// Initialize-Routing-Table, an empty table queries the router's table
+    InitializeRoutingTable(Vec<RoutingTablePort>),
+    /// Initialize-Routing-Table-Ack
+    InitializeRoutingTableAck(Vec<RoutingTablePort>),
+    /// Establish-Connection-To-Network, with a termination time in seconds
+    EstablishConnectionToNetwork { network: u16, termination_time: u8 },
+    /// Disconnect-Connection-To-Network
+    DisconnectConnectionToNetwork(u16),
+    /// What-Is-Network-Number
+    WhatIsNetworkNumber,
+    /// Network-Number-Is, `configured` is false for a learned number
+    NetworkNumberIs { network: u16, configured: bool },
+}
+
+impl NetworkMessage {
+    /// Get the message type
+    pub fn message_type(&self) -> NetworkMessageType {
+        match self {
+            NetworkMessage::WhoIsRouterToNetwork(_) => NetworkMessageType::WhoIsRouterToNetwork,
+            NetworkMessage::IAmRouterToNetwork(_) => NetworkMessageType::IAmRouterToNetwork,
+            NetworkMessage::ICouldBeRouterToNetwork { .. } => {
+                NetworkMessageType::ICouldBeRouterToNetwork
+            }
+            NetworkMessage::RejectMessageToNetwork { .. } => {
+                NetworkMessageType::RejectMessageToNetwork
+            }
+            NetworkMessage::RouterBusyToNetwork(_) => NetworkMessageType::RouterBusyToNetwork,
+            NetworkMessage::RouterAvailableToNetwork(_) => {
+                NetworkMessageType::RouterAvailableToNetwork
+            }
+            NetworkMessage::InitializeRoutingTable(_) => NetworkMessageType::InitializeRoutingTable,
+            NetworkMessage::InitializeRoutingTableAck(_) => {
+                NetworkMessageType::InitializeRoutingTableAck
+            }
+            NetworkMessage::EstablishConnectionToNetwork { .. } => {
+                NetworkMessageType::EstablishConnectionToNetwork
+            }
+            NetworkMessage::DisconnectConnectionToNetwork(_) => {
+                NetworkMessageType::DisconnectConnectionToNetwork
+            }
+            NetworkMessage::WhatIsNetworkNumber => NetworkMessageType::WhatIsNetworkNumber,
+            NetworkMessage::NetworkNumberIs { .. } => NetworkMessageType::NetworkNumberIs,
+        }
+    }
+
+    /// Encode the message type and data
+    ///
+    /// Fails for a routing table with more than 255 ports, or with port
+    /// information longer than 255 octets.
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        Ok(NetworkLayerMessage::try_from(self)?.encode())
+    }
+
+    /// Decode a message type followed by its data
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        Self::try_from(&NetworkLayerMessage::decode(data)?)
+    }
+
+    /// Wrap the message in an NPDU addressed to `destination`
+    pub fn to_frame(&self, destination: &BacnetAddress) -> Result<Vec<u8>> {
+        let message = NetworkLayerMessage::try_from(self)?;
+        let mut npdu = Npdu::to_address(destination);
+        npdu.control.network_message = true;
+        npdu.network_message_type = Some(message.message_type as u8);
+        let mut frame = npdu.encode();
+        frame.extend_from_slice(&message.data);
+        Ok(frame)
+    }
+
+    /// Decode the message carried by a decoded NPDU
+    ///
+    /// `data` is what follows the NPDU header.
+    pub fn from_npdu(npdu: &Npdu, data: &[u8]) -> Result<Self> {
+        let message_type = npdu
+            .network_message_type
+            .filter(|_| npdu.is_network_message())
+            .ok_or_else(|| NetworkError::InvalidNpdu("Not a network message".to_string()))?;
+        let message_type = NetworkMessageType::try_from(message_type)?;
+        Self::try_from(&NetworkLayerMessage::new(message_type, data.to_vec()))
+    }
+
+    /// Decode an NPDU and the network message it carries
+    pub fn from_frame(frame: &[u8]) -> Result<(Npdu, Self)> {
+        let (npdu, len) = Npdu::decode(frame)?;
+        let message = Self::from_npdu(&npdu, &frame[len..])?;
+        Ok((npdu, message))
+    }
+}
+
+fn encode_routing_table(ports: &[RoutingTablePort]) -> Result<Vec<u8>> {
+    let count = u8::try_from(ports.len()).map_err(|_| {
+        NetworkError::InvalidNpdu(format!("Too many routing table ports: {}", ports.len()))
+    })?;
+    let mut data = vec![count];
+    for port in ports {
+        let info_len = u8::try_from(port.port_info.len()).map_err(|_| {
+            NetworkError::InvalidNpdu(format!(
+                "Port information too long: {} octets",
+                port.port_info.len()
+            ))
+        })?;
+        data.extend_from_slice(&port.network.to_be_bytes());
+        data.push(port.port_id);
+        data.push(info_len);
+        data.extend_from_slice(&port.port_info);
+    }
+    Ok(data)
+}
+
+/// Network numbers listed two octets each
+fn decode_networks(data: &[u8]) -> Vec<u16> {
+    data.chunks_exact(2)
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+        .collect()
+}
+
+fn decode_routing_table(data: &[u8]) -> Result<Vec<RoutingTablePort>> {
+    let truncated = || NetworkError::InvalidNpdu("Truncated routing table".to_string());
+    let (&count, mut rest) = data.split_first().ok_or_else(truncated)?;
+    let mut ports = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        if rest.len() < 4 {
+            return Err(truncated());
+        }
+        let info_len = rest[3] as usize;
+        let port_info = rest.get(4..4 + info_len).ok_or_else(truncated)?.to_vec();
+        ports.push(RoutingTablePort {
+            network: u16::from_be_bytes([rest[0], rest[1]]),
+            port_id: rest[2],
+            port_info,
+        });
+        rest = &rest[4 + info_len..];
+    }
+    Ok(ports)
+}
+
+impl TryFrom<&NetworkMessage> for NetworkLayerMessage {
+    type Error = NetworkError;
+
+    fn try_from(message: &NetworkMessage) -> Result<Self> {
+        let networks = |networks: &[u16]| -> Vec<u8> {
+            networks.iter().flat_map(|n| n.to_be_bytes()).collect()
+        };
+        let data = match message {
+            NetworkMessage::WhoIsRouterToNetwork(network) => {
+                network.map_or(Vec::new(), |n| n.to_be_bytes().to_vec())
+            }
+            NetworkMessage::IAmRouterToNetwork(list)
+            | NetworkMessage::RouterBusyToNetwork(list)
+            | NetworkMessage::RouterAvailableToNetwork(list) => networks(list),
+            NetworkMessage::ICouldBeRouterToNetwork {
+                network,
+                performance_index,
+            } => {
+                let mut data = network.to_be_bytes().to_vec();
+                data.push(*performance_index);
+                data
+            }
+            NetworkMessage::RejectMessageToNetwork { reason, network } => {
+                let mut data = vec![*reason as u8];
+                data.extend_from_slice(&network.to_be_bytes());
+                data
+            }
+            NetworkMessage::InitializeRoutingTable(ports)
+            | NetworkMessage::InitializeRoutingTableAck(ports) => encode_routing_table(ports)?,
+            NetworkMessage::EstablishConnectionToNetwork {
+                network,
+                termination_time,
+            } => {
+                let mut data = network.to_be_bytes().to_vec();
+                data.push(*termination_time);
+                data
+            }
+            NetworkMessage::DisconnectConnectionToNetwork(network) => {
+                network.to_be_bytes().to_vec()
+            }
+            NetworkMessage::WhatIsNetworkNumber => Vec::new(),
+            NetworkMessage::NetworkNumberIs {
+                network,
+                configured,
+            } => {
+                let mut data = network.to_be_bytes().to_vec();
+                data.push(*configured as u8);
+                data
+            }
+        };
+        Ok(NetworkLayerMessage::new(message.message_type(), data))
+    }
+}
+
+impl TryFrom<&NetworkLayerMessage> for NetworkMessage {
+    type Error = NetworkError;
+
+    fn try_from(message: &NetworkLayerMessage) -> Result<Self> {
+        let data = &message.data;
+        let network = || {
+            data.get(..2)
+                .map(|n| u16::from_be_bytes([n[0], n[1]]))
+                .ok_or_else(|| NetworkError::InvalidNpdu("Missing network number".to_string()))
+        };
+        let octet = |index: usize| {
+            data.get(index)
+                .copied()
+                .ok_or_else(|| NetworkError::InvalidNpdu("Truncated network message".to_string()))
+        };
+
+        Ok(match message.message_type {
+            NetworkMessageType::WhoIsRouterToNetwork => {
+                NetworkMessage::WhoIsRouterToNetwork(network().ok())
+            }
+            NetworkMessageType::IAmRouterToNetwork => {
+                NetworkMessage::IAmRouterToNetwork(decode_networks(data))
+            }
+            NetworkMessageType::ICouldBeRouterToNetwork => {
+                NetworkMessage::ICouldBeRouterToNetwork {
+                    network: network()?,
+                    performance_index: octet(2)?,
+                }
+            }
+            NetworkMessageType::RejectMessageToNetwork => NetworkMessage::RejectMessageToNetwork {
+                reason: RejectMessageReason::try_from(octet(0)?)?,
+                network: data
+                    .get(1..3)
+                    .map(|n| u16::from_be_bytes([n[0], n[1]]))
+                    .ok_or_else(|| {
+                        NetworkError::InvalidNpdu("Missing network number".to_string())
+                    })?,
+            },
+            NetworkMessageType::RouterBusyToNetwork => {
+                NetworkMessage::RouterBusyToNetwork(decode_networks(data))
+            }
+            NetworkMessageType::RouterAvailableToNetwork => {
+                NetworkMessage::RouterAvailableToNetwork(decode_networks(data))
+            }
+            NetworkMessageType::InitializeRoutingTable => {
+                NetworkMessage::InitializeRoutingTable(decode_routing_table(data)?)
+            }
+            NetworkMessageType::InitializeRoutingTableAck => {
+                NetworkMessage::InitializeRoutingTableAck(decode_routing_table(data)?)
+            }
+            NetworkMessageType::EstablishConnectionToNetwork => {
+                NetworkMessage::EstablishConnectionToNetwork {
+                    network: network()?,
+                    termination_time: octet(2)?,
+                }
+            }
+            NetworkMessageType::DisconnectConnectionToNetwork => {
+                NetworkMessage::DisconnectConnectionToNetwork(network()?)
+            }
+            NetworkMessageType::WhatIsNetworkNumber => NetworkMessage::WhatIsNetworkNumber,
+            NetworkMessageType::NetworkNumberIs => NetworkMessage::NetworkNumberIs {
+                network: network()?,
+                configured: octet(2)? == 1,
+            },
+        })
+    }
+}
+
 /// Basic routing table implementation
 #[derive(Debug, Clone)]
 pub struct RoutingTable {
@@ -1526,6 +2181,112 @@ mod tests {
         assert_eq!(decoded.hop_count, Some(5));
     }
 
+    #[test]
+    fn test_npdu_global_broadcast_round_trip() {
+        // Global broadcast carrying an unconfirmed Who-Is APDU
+        let apdu = [0x10, 0x08];
+        let mut frame = Npdu::global_broadcast().encode();
+        frame.extend_from_slice(&apdu);
+        assert_eq!(frame, vec![0x01, 0x20, 0xFF, 0xFF, 0x00, 0xFF, 0x10, 0x08]);
+
+        let (decoded, consumed) = Npdu::decode(&frame).unwrap();
+        assert!(decoded.control.destination_present);
+        assert!(!decoded.is_network_message());
+        assert!(decoded.destination.as_ref().unwrap().is_broadcast());
+        assert!(decoded.destination.as_ref().unwrap().address.is_empty());
+        assert_eq!(decoded.hop_count, Some(255));
+        assert_eq!(&frame[consumed..], &apdu);
+        assert_eq!(decoded.encode(), frame[..consumed]);
+    }
+
+    #[test]
+    fn test_npdu_routed_source_and_destination() {
+        let mut npdu = Npdu::new();
+        npdu.destination = Some(NetworkAddress::new(5, vec![0x0A]));
+        npdu.source = Some(NetworkAddress::new(2001, vec![192, 168, 1, 10, 0xBA, 0xC0]));
+        npdu.hop_count = Some(254);
+        npdu.control.expecting_reply = true;
+
+        let encoded = npdu.encode();
+        // Control bits are set from the addressing that is present
+        assert_eq!(encoded[1], 0x2C);
+
+        let (decoded, consumed) = Npdu::decode(&encoded).unwrap();
+        assert_eq!(consumed, encoded.len());
+        assert_eq!(decoded.destination, npdu.destination);
+        assert_eq!(decoded.source, npdu.source);
+        assert_eq!(decoded.hop_count, Some(254));
+        assert!(decoded.control.expecting_reply);
+    }
+
+    #[test]
+    fn test_bacnet_address_npdu_round_trip() {
+        let device = BacnetAddress::remote(5, MacAddress::MsTp(0x0A));
+        let npdu = Npdu::to_address(&device);
+        assert_eq!(npdu.destination, Some(NetworkAddress::new(5, vec![0x0A])));
+        assert_eq!(npdu.hop_count, Some(255));
+        let (decoded, _) = Npdu::decode(&npdu.encode()).unwrap();
+        let destination = decoded.destination.unwrap();
+        assert_eq!(
+            BacnetAddress::from_network_address(&destination, DataLinkType::BacnetIp),
+            device
+        );
+
+        // Six octet SADRs take their format from the data link type
+        let ip: SocketAddrV4 = "192.168.1.10:47808".parse().unwrap();
+        let sadr = NetworkAddress::new(2001, vec![192, 168, 1, 10, 0xBA, 0xC0]);
+        assert_eq!(
+            BacnetAddress::from_network_address(&sadr, DataLinkType::BacnetIp),
+            BacnetAddress::remote(2001, MacAddress::Ip(ip))
+        );
+        let vmac = BacnetAddress::from_network_address(&sadr, DataLinkType::SecureConnect);
+        assert!(matches!(vmac.mac, MacAddress::Vmac(_)));
+        assert_eq!(vmac.to_network_address(), Some(sadr.clone()));
+        assert_eq!(vmac.to_string(), "2001:C0:A8:01:0A:BA:C0");
+
+        // Local addresses stay out of the NPDU
+        let mut routed = Npdu::new();
+        let hop = DataLinkAddress::MsTP(3);
+        let sender = BacnetAddress::npdu_source(&routed, &hop, DataLinkType::MsTP);
+        assert_eq!(sender, BacnetAddress::local(MacAddress::MsTp(3)));
+        assert_eq!(sender.to_network_address(), None);
+        assert!(Npdu::to_address(&sender).destination.is_none());
+        routed.source = Some(sadr);
+        assert!(BacnetAddress::npdu_source(&routed, &hop, DataLinkType::MsTP).is_remote());
+
+        let global = BacnetAddress::global_broadcast();
+        assert!(global.is_broadcast());
+        assert_eq!(
+            global.to_network_address(),
+            Some(NetworkAddress::new(GLOBAL_BROADCAST_NETWORK, vec![]))
+        );
+        assert_eq!(
+            MacAddress::from_octets(&[1, 2, 3], DataLinkType::Arcnet),
+            MacAddress::Other(vec![1, 2, 3])
+        );
+    }
+
+    #[test]
+    fn test_npdu_network_message_type() {
+        let encoded = Npdu::network_message(NetworkMessageType::WhoIsRouterToNetwork).encode();
+        assert_eq!(encoded, vec![0x01, 0x80, 0x00]);
+
+        let (decoded, consumed) = Npdu::decode(&encoded).unwrap();
+        assert_eq!(consumed, 3);
+        assert_eq!(decoded.network_message_type, Some(0x00));
+
+        // Proprietary message types carry a vendor ID
+        let mut proprietary = Npdu::new();
+        proprietary.network_message_type = Some(0x80);
+        proprietary.vendor_id = Some(260);
+        let encoded = proprietary.encode();
+        assert_eq!(encoded, vec![0x01, 0x80, 0x80, 0x01, 0x04]);
+        let (decoded, _) = Npdu::decode(&encoded).unwrap();
+        assert_eq!(decoded.vendor_id, Some(260));
+
+        assert!(Npdu::decode(&[0x01, 0x80]).is_err());
+    }
+
     #[test]
     fn test_network_message() {
         let message = NetworkLayerMessage::new(
@@ -1810,4 +2571,89 @@ mod tests {
         assert_eq!(reset_metrics.network_unreachable_count, 0);
         assert_eq!(reset_metrics.hop_count_exceeded, 0);
     }
+
+    #[test]
+    fn test_network_message_round_trip() {
+        let messages = vec![
+            NetworkMessage::WhoIsRouterToNetwork(None),
+            NetworkMessage::WhoIsRouterToNetwork(Some(5)),
+            NetworkMessage::IAmRouterToNetwork(vec![1, 2, 0x1234]),
+            NetworkMessage::ICouldBeRouterToNetwork {
+                network: 7,
+                performance_index: 3,
+            },
+            NetworkMessage::RejectMessageToNetwork {
+                reason: RejectMessageReason::RouterBusy,
+                network: 9,
+            },
+            NetworkMessage::RouterBusyToNetwork(vec![]),
+            NetworkMessage::RouterAvailableToNetwork(vec![4]),
+            NetworkMessage::InitializeRoutingTable(vec![]),
+            NetworkMessage::InitializeRoutingTableAck(vec![
+                RoutingTablePort {
+                    network: 10,
+                    port_id: 1,
+                    port_info: vec![],
+                },
+                RoutingTablePort {
+                    network: 11,
+                    port_id: 2,
+                    port_info: b"555".to_vec(),
+                },
+            ]),
+            NetworkMessage::EstablishConnectionToNetwork {
+                network: 12,
+                termination_time: 60,
+            },
+            NetworkMessage::DisconnectConnectionToNetwork(12),
+            NetworkMessage::WhatIsNetworkNumber,
+            NetworkMessage::NetworkNumberIs {
+                network: 3,
+                configured: true,
+            },
+        ];
+        for message in messages {
+            let encoded = message.encode().unwrap();
+            assert_eq!(encoded[0], message.message_type() as u8);
+            assert_eq!(NetworkMessage::decode(&encoded).unwrap(), message);
+
+            let frame = message
+                .to_frame(&BacnetAddress::local(MacAddress::Broadcast))
+                .unwrap();
+            let (npdu, decoded) = NetworkMessage::from_frame(&frame).unwrap();
+            assert!(npdu.is_network_message());
+            assert_eq!(decoded, message);
+        }
+
+        assert_eq!(
+            NetworkMessage::RejectMessageToNetwork {
+                reason: RejectMessageReason::UnknownNetwork,
+                network: 0x0102,
+            }
+            .encode()
+            .unwrap(),
+            vec![0x03, 0x01, 0x01, 0x02]
+        );
+        // Counts past one octet are rejected instead of truncated
+        let port = RoutingTablePort {
+            network: 1,
+            port_id: 1,
+            port_info: Vec::new(),
+        };
+        assert!(
+            NetworkMessage::InitializeRoutingTable(vec![port.clone(); 256])
+                .encode()
+                .is_err()
+        );
+        let long_info = RoutingTablePort {
+            port_info: vec![0; 256],
+            ..port
+        };
+        assert!(NetworkMessage::InitializeRoutingTableAck(vec![long_info])
+            .to_frame(&BacnetAddress::local(MacAddress::Broadcast))
+            .is_err());
+        // Truncated routing tables and APDU frames are rejected
+        assert!(NetworkMessage::decode(&[0x06, 0x01, 0x00, 0x0A, 0x01]).is_err());
+        assert!(NetworkMessage::from_frame(&Npdu::new().encode()).is_err());
+    }
 }
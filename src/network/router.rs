@@ -0,0 +1,459 @@
+//! BACnet Router
+//!
+//! A [`Router`] connects several BACnet networks, each on its own data link
+//! port, and forwards NPDUs between them by destination network number
+//! (DNET), as described in ASHRAE 135 Clause 6.6. It answers
+//! Who-Is-Router-To-Network for the networks it reaches and learns routes to
+//! networks behind other routers from I-Am-Router-To-Network.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use bacnet_rs::datalink::{bip::BacnetIpDataLink, mstp::MstpDataLink};
+//! use bacnet_rs::network::router::Router;
+//!
+//! # fn example(mstp: MstpDataLink) -> Result<(), Box<dyn std::error::Error>> {
+//! let mut router = Router::new();
+//! router.add_port(1, BacnetIpDataLink::new("0.0.0.0:47808")?);
+//! router.add_port(2, mstp);
+//!
+//! loop {
+//!     router.poll(1)?;
+//!     router.poll(2)?;
+//! }
+//! # }
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::{boxed::Box, collections::BTreeMap, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+use crate::datalink::{DataLink, DataLinkAddress, DataLinkError, Result};
+use crate::network::{
+    BacnetAddress, MacAddress, NetworkAddress, NetworkMessage, Npdu, RejectMessageReason,
+    GLOBAL_BROADCAST_NETWORK,
+};
+
+/// Route to a network behind another router
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    /// Network number of the port the other router is on
+    pub port: u16,
+    /// Address of the other router on that port
+    pub router: DataLinkAddress,
+}
+
+/// Router forwarding NPDUs between data link ports
+///
+/// Each port is identified by the network number of the network it is
+/// attached to.
+#[derive(Default)]
+pub struct Router {
+    ports: BTreeMap<u16, Box<dyn DataLink>>,
+    routes: BTreeMap<u16, Route>,
+}
+
+impl Router {
+    /// Create a router without ports
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attach a data link as the port to `network`
+    pub fn add_port(&mut self, network: u16, datalink: impl DataLink + 'static) {
+        self.ports.insert(network, Box::new(datalink));
+    }
+
+    /// Add a route to `network` through the router at `router` on `port`
+    pub fn add_route(&mut self, network: u16, port: u16, router: DataLinkAddress) {
+        self.routes.insert(network, Route { port, router });
+    }
+
+    /// Get the route to a network behind another router
+    pub fn route(&self, network: u16) -> Option<&Route> {
+        self.routes.get(&network)
+    }
+
+    /// Networks reachable from `port` through this router
+    ///
+    /// Lists the other directly connected networks and the networks behind
+    /// routers on other ports.
+    pub fn reachable_networks(&self, port: u16) -> Vec<u16> {
+        let direct = self.ports.keys().copied().filter(|&n| n != port);
+        let routed = self
+            .routes
+            .iter()
+            .filter(|(network, route)| route.port != port && !self.ports.contains_key(network))
+            .map(|(&network, _)| network);
+        direct.chain(routed).collect()
+    }
+
+    /// Receive one frame on `port` and route it
+    ///
+    /// Returns `Ok(false)` when the data link timed out.
+    pub fn poll(&mut self, port: u16) -> Result<bool> {
+        let received = self.port_mut(port)?.receive_frame();
+        let (frame, source) = match received {
+            Ok(received) => received,
+            #[cfg(feature = "std")]
+            Err(DataLinkError::IoError(e))
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                ) =>
+            {
+                return Ok(false)
+            }
+            Err(DataLinkError::InvalidFrame) => return Ok(false),
+            Err(e) => return Err(e),
+        };
+        self.handle_frame(port, &frame, &source)?;
+        Ok(true)
+    }
+
+    /// Route an NPDU received on `port` from `source`
+    ///
+    /// NPDUs for a directly connected network are delivered to the DADR on
+    /// that network, and NPDUs for networks behind other routers are sent to
+    /// the next router with the hop count decremented. Global broadcasts go
+    /// out on every other port. Messages for unknown networks are answered
+    /// with Reject-Message-To-Network. Undecodable frames, local traffic and
+    /// messages whose hop count ran out are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `port` is unknown or a data link fails to send.
+    pub fn handle_frame(
+        &mut self,
+        port: u16,
+        frame: &[u8],
+        source: &DataLinkAddress,
+    ) -> Result<()> {
+        let Ok((mut npdu, npdu_len)) = Npdu::decode(frame) else {
+            return Ok(());
+        };
+        let payload = &frame[npdu_len..];
+
+        let Some(destination) = npdu.destination.clone() else {
+            // Only network layer messages to the router itself are of interest
+            if npdu.is_network_message() {
+                self.handle_network_message(port, &npdu, payload, source)?;
+            }
+            return Ok(());
+        };
+
+        let hops = npdu.hop_count.unwrap_or(255);
+        if hops == 0 {
+            return Ok(());
+        }
+        npdu.hop_count = Some(hops - 1);
+
+        // Name the original sender, unless a previous router already did
+        let original_source = npdu.source.clone();
+        if npdu.source.is_none() {
+            npdu.source = Some(NetworkAddress::new(port, MacAddress::from(source).octets()));
+        }
+
+        if destination.network == GLOBAL_BROADCAST_NETWORK {
+            if npdu.is_network_message() {
+                self.handle_network_message(port, &npdu, payload, source)?;
+            }
+            let frame = encode_frame(&npdu, payload);
+            for (_, datalink) in self.ports.iter_mut().filter(|(&n, _)| n != port) {
+                datalink.send_frame(&frame, &DataLinkAddress::Broadcast)?;
+            }
+            return Ok(());
+        }
+
+        if destination.network == port {
+            // Already on the destination network
+            return Ok(());
+        }
+
+        if let Some(datalink) = self.ports.get_mut(&destination.network) {
+            // Deliver on the directly connected network, without DNET/DADR
+            let mac = MacAddress::from_octets(&destination.address, datalink.link_type());
+            let Some(dest) = mac.to_datalink_address() else {
+                return Ok(());
+            };
+            npdu.destination = None;
+            npdu.hop_count = None;
+            return datalink.send_frame(&encode_frame(&npdu, payload), &dest);
+        }
+
+        match self.routes.get(&destination.network).cloned() {
+            Some(route) if route.port != port => {
+                let frame = encode_frame(&npdu, payload);
+                self.port_mut(route.port)?.send_frame(&frame, &route.router)
+            }
+            _ => {
+                let reject = NetworkMessage::RejectMessageToNetwork {
+                    reason: RejectMessageReason::UnknownNetwork,
+                    network: destination.network,
+                };
+                let sender = match &original_source {
+                    Some(address) => BacnetAddress::from_network_address(
+                        address,
+                        self.port_mut(port)?.link_type(),
+                    ),
+                    None => BacnetAddress::local(MacAddress::Broadcast),
+                };
+                let frame = reject
+                    .to_frame(&sender)
+                    .map_err(|_| DataLinkError::InvalidFrame)?;
+                self.port_mut(port)?.send_frame(&frame, source)
+            }
+        }
+    }
+
+    /// Answer or learn from a network layer message sent to the router
+    fn handle_network_message(
+        &mut self,
+        port: u16,
+        npdu: &Npdu,
+        payload: &[u8],
+        source: &DataLinkAddress,
+    ) -> Result<()> {
+        match NetworkMessage::from_npdu(npdu, payload) {
+            Ok(NetworkMessage::WhoIsRouterToNetwork(requested)) => {
+                let networks: Vec<u16> = self
+                    .reachable_networks(port)
+                    .into_iter()
+                    .filter(|&n| requested.is_none_or(|network| n == network))
+                    .collect();
+                if networks.is_empty() {
+                    return Ok(());
+                }
+                let i_am = NetworkMessage::IAmRouterToNetwork(networks)
+                    .to_frame(&BacnetAddress::local(MacAddress::Broadcast))
+                    .map_err(|_| DataLinkError::InvalidFrame)?;
+                self.port_mut(port)?
+                    .send_frame(&i_am, &DataLinkAddress::Broadcast)
+            }
+            Ok(NetworkMessage::IAmRouterToNetwork(networks)) => {
+                for network in networks {
+                    if !self.ports.contains_key(&network) {
+                        self.add_route(network, port, source.clone());
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn port_mut(&mut self, port: u16) -> Result<&mut Box<dyn DataLink>> {
+        self.ports
+            .get_mut(&port)
+            .ok_or_else(|| DataLinkError::AddressError("Unknown router port".into()))
+    }
+}
+
+/// Encode an NPDU followed by its APDU or network message data
+fn encode_frame(npdu: &Npdu, payload: &[u8]) -> Vec<u8> {
+    let mut frame = npdu.encode();
+    frame.extend_from_slice(payload);
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datalink::DataLinkType;
+
+    #[cfg(feature = "std")]
+    use std::sync::{Arc, Mutex};
+
+    /// Frames sent on a port, with their destinations
+    #[cfg(feature = "std")]
+    type SentLog = Arc<Mutex<Vec<(Vec<u8>, DataLinkAddress)>>>;
+
+    /// Data link recording what is sent through a shared log
+    #[cfg(feature = "std")]
+    struct Port {
+        link_type: DataLinkType,
+        sent: SentLog,
+    }
+
+    #[cfg(feature = "std")]
+    impl DataLink for Port {
+        fn send_frame(&mut self, frame: &[u8], dest: &DataLinkAddress) -> Result<()> {
+            self.sent
+                .lock()
+                .unwrap()
+                .push((frame.to_vec(), dest.clone()));
+            Ok(())
+        }
+
+        fn receive_frame(&mut self) -> Result<(Vec<u8>, DataLinkAddress)> {
+            Err(DataLinkError::InvalidFrame)
+        }
+
+        fn link_type(&self) -> DataLinkType {
+            self.link_type
+        }
+
+        fn local_address(&self) -> DataLinkAddress {
+            DataLinkAddress::Broadcast
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_router_forwards_between_ports() {
+        let ethernet_log = Arc::new(Mutex::new(Vec::new()));
+        let mstp_log = Arc::new(Mutex::new(Vec::new()));
+        let mut router = Router::new();
+        router.add_port(
+            1,
+            Port {
+                link_type: DataLinkType::Ethernet,
+                sent: ethernet_log.clone(),
+            },
+        );
+        router.add_port(
+            2,
+            Port {
+                link_type: DataLinkType::MsTP,
+                sent: mstp_log.clone(),
+            },
+        );
+        let workstation_mac = [0x00, 0x11, 0x22, 0x33, 0x44, 0x55];
+        let workstation = DataLinkAddress::Ethernet(workstation_mac);
+        let apdu = [0x10, 0x08];
+        let take = |log: &SentLog| {
+            let (frame, dest) = log.lock().unwrap().remove(0);
+            let (npdu, len) = Npdu::decode(&frame).unwrap();
+            (npdu, frame[len..].to_vec(), dest)
+        };
+
+        // Ethernet to a station on the MS/TP network
+        let mut frame = Npdu::to_address(&BacnetAddress::remote(2, MacAddress::MsTp(5))).encode();
+        frame.extend_from_slice(&apdu);
+        router.handle_frame(1, &frame, &workstation).unwrap();
+        let (npdu, payload, dest) = take(&mstp_log);
+        assert_eq!(dest, DataLinkAddress::MsTP(5));
+        assert_eq!(npdu.destination, None);
+        assert_eq!(
+            npdu.source,
+            Some(NetworkAddress::new(1, workstation_mac.to_vec()))
+        );
+        assert_eq!(payload, apdu);
+
+        // The reply goes back to the workstation
+        let mut reply = Npdu::to_address(&BacnetAddress::remote(
+            1,
+            MacAddress::Ethernet(workstation_mac),
+        ));
+        reply.hop_count = Some(1);
+        let mut frame = reply.encode();
+        frame.extend_from_slice(&apdu);
+        router
+            .handle_frame(2, &frame, &DataLinkAddress::MsTP(5))
+            .unwrap();
+        let (npdu, _, dest) = take(&ethernet_log);
+        assert_eq!(dest, workstation);
+        assert_eq!(npdu.source, Some(NetworkAddress::new(2, vec![5])));
+
+        // Global broadcasts keep their DNET
+        let mut frame = Npdu::global_broadcast().encode();
+        frame.extend_from_slice(&apdu);
+        router.handle_frame(1, &frame, &workstation).unwrap();
+        let (npdu, _, dest) = take(&mstp_log);
+        assert_eq!(dest, DataLinkAddress::Broadcast);
+        assert!(npdu.destination.unwrap().is_broadcast());
+        assert_eq!(npdu.hop_count, Some(254));
+        assert!(ethernet_log.lock().unwrap().is_empty());
+
+        // Exhausted hop counts are dropped
+        let mut expired = Npdu::to_address(&BacnetAddress::remote(2, MacAddress::MsTp(5)));
+        expired.hop_count = Some(0);
+        router
+            .handle_frame(1, &expired.encode(), &workstation)
+            .unwrap();
+        assert!(mstp_log.lock().unwrap().is_empty());
+
+        // Unknown networks are rejected back to the sender
+        let mut frame = Npdu::to_address(&BacnetAddress::remote(9, MacAddress::MsTp(5))).encode();
+        frame.extend_from_slice(&apdu);
+        router.handle_frame(1, &frame, &workstation).unwrap();
+        let (npdu, payload, dest) = take(&ethernet_log);
+        assert_eq!(dest, workstation);
+        assert_eq!(
+            NetworkMessage::from_npdu(&npdu, &payload).unwrap(),
+            NetworkMessage::RejectMessageToNetwork {
+                reason: RejectMessageReason::UnknownNetwork,
+                network: 9
+            }
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_router_network_messages() {
+        let ethernet_log = Arc::new(Mutex::new(Vec::new()));
+        let mstp_log = Arc::new(Mutex::new(Vec::new()));
+        let mut router = Router::new();
+        router.add_port(
+            1,
+            Port {
+                link_type: DataLinkType::Ethernet,
+                sent: ethernet_log.clone(),
+            },
+        );
+        router.add_port(
+            2,
+            Port {
+                link_type: DataLinkType::MsTP,
+                sent: mstp_log.clone(),
+            },
+        );
+        let workstation = DataLinkAddress::Ethernet([0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
+        let local = BacnetAddress::local(MacAddress::Broadcast);
+
+        // A second router on the MS/TP network announces network 3
+        let i_am = NetworkMessage::IAmRouterToNetwork(vec![3])
+            .to_frame(&local)
+            .unwrap();
+        router
+            .handle_frame(2, &i_am, &DataLinkAddress::MsTP(7))
+            .unwrap();
+        assert_eq!(
+            router.route(3),
+            Some(&Route {
+                port: 2,
+                router: DataLinkAddress::MsTP(7)
+            })
+        );
+        assert_eq!(router.reachable_networks(1), vec![2, 3]);
+        assert_eq!(router.reachable_networks(2), vec![1]);
+
+        let who_is = NetworkMessage::WhoIsRouterToNetwork(None)
+            .to_frame(&local)
+            .unwrap();
+        router.handle_frame(1, &who_is, &workstation).unwrap();
+        let (frame, dest) = ethernet_log.lock().unwrap().remove(0);
+        assert_eq!(dest, DataLinkAddress::Broadcast);
+        assert_eq!(
+            NetworkMessage::from_frame(&frame).unwrap().1,
+            NetworkMessage::IAmRouterToNetwork(vec![2, 3])
+        );
+
+        // Networks the router cannot reach are not answered
+        let who_is = NetworkMessage::WhoIsRouterToNetwork(Some(4))
+            .to_frame(&local)
+            .unwrap();
+        router.handle_frame(1, &who_is, &workstation).unwrap();
+        assert!(ethernet_log.lock().unwrap().is_empty());
+
+        // Traffic for network 3 goes through the second router
+        let frame = Npdu::to_address(&BacnetAddress::remote(3, MacAddress::MsTp(1))).encode();
+        router.handle_frame(1, &frame, &workstation).unwrap();
+        let (frame, dest) = mstp_log.lock().unwrap().remove(0);
+        assert_eq!(dest, DataLinkAddress::MsTP(7));
+        let (npdu, _) = Npdu::decode(&frame).unwrap();
+        assert_eq!(npdu.destination, Some(NetworkAddress::new(3, vec![1])));
+        assert_eq!(npdu.hop_count, Some(254));
+    }
+}
@@ -0,0 +1,566 @@
+//! Accumulator Object Implementation
+//!
+//! This module implements the Accumulator object type as defined in ASHRAE 135.
+//! An Accumulator counts pulses from a meter. Incoming pulses are divided down
+//! by the prescale, added to the unsigned present value, and wrap back to zero
+//! past Max_Pres_Value. The scale converts the count to an engineering value
+//! such as kilowatt hours or litres.
+
+use crate::encoding::{ApplicationTag, EncodingError, Result as EncodingResult};
+use crate::object::{
+    analog::{EventState, Reliability},
+    callback::PropertyCallbacks,
+    constructed_value,
+    engineering_units::EngineeringUnits,
+    status_flags::StatusFlags,
+    units_value, validate_description, write_rejected, BacnetObject, ObjectError, ObjectIdentifier,
+    ObjectType, PropertyIdentifier, PropertyValue, Result,
+};
+use crate::service::{decode_context_value, encode_context_value, BacnetDateTime};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// BACnetScale: conversion of a count to an engineering value
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scale {
+    /// Multiply the count by a factor (context tag 0)
+    Float(f32),
+    /// Multiply the count by ten to a power (context tag 1)
+    Integer(i32),
+}
+
+impl Scale {
+    /// Engineering value of a count
+    pub fn apply(self, count: u32) -> f32 {
+        match self {
+            Scale::Float(factor) => count as f32 * factor,
+            Scale::Integer(exponent) => {
+                let power = (0..exponent.unsigned_abs()).fold(1.0f64, |power, _| power * 10.0);
+                if exponent < 0 {
+                    (count as f64 / power) as f32
+                } else {
+                    (count as f64 * power) as f32
+                }
+            }
+        }
+    }
+
+    /// Encode the scale choice
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        match *self {
+            Scale::Float(factor) => encode_context_value(buffer, 0, &PropertyValue::Real(factor)),
+            Scale::Integer(exponent) => {
+                encode_context_value(buffer, 1, &PropertyValue::SignedInt(exponent))
+            }
+        }
+    }
+
+    /// Decode a scale choice, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        if let Ok((PropertyValue::Real(factor), consumed)) =
+            decode_context_value(data, 0, ApplicationTag::Real)
+        {
+            return Ok((Scale::Float(factor), consumed));
+        }
+        match decode_context_value(data, 1, ApplicationTag::SignedInt)? {
+            (PropertyValue::SignedInt(exponent), consumed) => {
+                Ok((Scale::Integer(exponent), consumed))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
+/// BACnetPrescale: pulses per counted unit
+///
+/// Each pulse adds `multiplier / modulo_divide` to the count; fractions are
+/// carried over to the next pulse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Prescale {
+    /// Counts added per `modulo_divide` pulses
+    pub multiplier: u32,
+    /// Pulses per `multiplier` counts
+    pub modulo_divide: u32,
+}
+
+impl Prescale {
+    /// Encode the prescale
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        encode_context_value(buffer, 0, &PropertyValue::UnsignedInteger(self.multiplier))?;
+        encode_context_value(
+            buffer,
+            1,
+            &PropertyValue::UnsignedInteger(self.modulo_divide),
+        )
+    }
+
+    /// Decode a prescale, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (PropertyValue::UnsignedInteger(multiplier), first) =
+            decode_context_value(data, 0, ApplicationTag::UnsignedInt)?
+        else {
+            return Err(EncodingError::InvalidTag);
+        };
+        let (PropertyValue::UnsignedInteger(modulo_divide), second) =
+            decode_context_value(&data[first..], 1, ApplicationTag::UnsignedInt)?
+        else {
+            return Err(EncodingError::InvalidTag);
+        };
+        Ok((
+            Self {
+                multiplier,
+                modulo_divide,
+            },
+            first + second,
+        ))
+    }
+}
+
+impl Default for Prescale {
+    fn default() -> Self {
+        Self {
+            multiplier: 1,
+            modulo_divide: 1,
+        }
+    }
+}
+
+/// Accumulator object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Accumulator {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Present value: the accumulated count
+    pub present_value: u32,
+    /// Description
+    pub description: String,
+    /// Status flags
+    pub status_flags: StatusFlags,
+    /// Event state
+    pub event_state: EventState,
+    /// Reliability
+    pub reliability: Reliability,
+    /// Out of service; pulses are ignored while set
+    pub out_of_service: bool,
+    /// Conversion of the count to an engineering value
+    pub scale: Scale,
+    /// Units of the scaled value
+    pub units: EngineeringUnits,
+    /// Pulses per counted unit
+    pub prescale: Prescale,
+    /// Highest count before the present value wraps to zero
+    pub max_pres_value: u32,
+    /// When the present value last changed
+    pub value_change_time: Option<BacnetDateTime>,
+    /// Count increment that triggers a change of value report
+    pub cov_increment: Option<u32>,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+    /// Prescaled pulses not yet making up a whole count
+    pulse_remainder: u64,
+    /// Present value at the last change of value report
+    cov_reported_value: u32,
+}
+
+impl Accumulator {
+    /// Create a new Accumulator counting every pulse
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::Accumulator, instance),
+            object_name,
+            present_value: 0,
+            description: String::new(),
+            status_flags: StatusFlags::default(),
+            event_state: EventState::Normal,
+            reliability: Reliability::NoFaultDetected,
+            out_of_service: false,
+            scale: Scale::Float(1.0),
+            units: EngineeringUnits::NoUnits,
+            prescale: Prescale::default(),
+            max_pres_value: u32::MAX,
+            value_change_time: None,
+            cov_increment: None,
+            callbacks: PropertyCallbacks::new(),
+            pulse_remainder: 0,
+            cov_reported_value: 0,
+        }
+    }
+
+    /// Count pulses from the meter input
+    ///
+    /// The pulses are prescaled and added to the present value, wrapping past
+    /// Max_Pres_Value. Ignored while out of service.
+    pub fn add_pulses(&mut self, pulses: u32, now: BacnetDateTime) {
+        if self.out_of_service || self.prescale.modulo_divide == 0 {
+            return;
+        }
+        let scaled = self.pulse_remainder + pulses as u64 * self.prescale.multiplier as u64;
+        let modulo = self.prescale.modulo_divide as u64;
+        self.pulse_remainder = scaled % modulo;
+        let counts = scaled / modulo;
+        if counts == 0 {
+            return;
+        }
+        let value = (self.present_value as u64 + counts) % self.modulus();
+        self.set_present_value(value as u32, now);
+    }
+
+    /// Set the present value and record the change time
+    ///
+    /// Present_Value callbacks act as the change of value hook: they fire
+    /// once the count has advanced by at least the COV increment since the
+    /// last report, counting across a rollover, or on any change when no
+    /// increment is set.
+    pub fn set_present_value(&mut self, value: u32, now: BacnetDateTime) {
+        if value != self.present_value {
+            self.value_change_time = Some(now);
+            self.store_present_value(value);
+        }
+    }
+
+    /// Store the present value, reporting a change of value if due
+    fn store_present_value(&mut self, value: u32) {
+        self.present_value = value;
+        let advanced =
+            (value as u64 + self.modulus() - self.cov_reported_value as u64) % self.modulus();
+        if advanced >= self.cov_increment.unwrap_or(1).max(1) as u64 {
+            let reported = core::mem::replace(&mut self.cov_reported_value, value);
+            self.callbacks.notify(
+                self.identifier,
+                PropertyIdentifier::PresentValue,
+                PropertyValue::UnsignedInteger(reported),
+                PropertyValue::UnsignedInteger(value),
+            );
+        }
+    }
+
+    /// Present value converted to engineering units by the scale
+    pub fn scaled_value(&self) -> f32 {
+        self.scale.apply(self.present_value)
+    }
+
+    /// Number of distinct counts before the present value wraps
+    fn modulus(&self) -> u64 {
+        self.max_pres_value as u64 + 1
+    }
+
+    /// Recompute the status flags from the current state
+    fn update_status_flags(&mut self) {
+        self.status_flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+    }
+}
+
+impl BacnetObject for Accumulator {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::Accumulator.as_u16() as u32,
+            )),
+            PropertyIdentifier::PresentValue => {
+                Ok(PropertyValue::UnsignedInteger(self.present_value))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::Scale => constructed_value(|buffer| self.scale.encode(buffer)),
+            PropertyIdentifier::Units => Ok(PropertyValue::Enumerated(u32::from(self.units))),
+            PropertyIdentifier::Prescale => {
+                constructed_value(|buffer| self.prescale.encode(buffer))
+            }
+            PropertyIdentifier::MaxPresValue => {
+                Ok(PropertyValue::UnsignedInteger(self.max_pres_value))
+            }
+            PropertyIdentifier::ValueChangeTime => self
+                .value_change_time
+                .map(|time| {
+                    PropertyValue::List(vec![
+                        PropertyValue::Date(time.date),
+                        PropertyValue::Time(time.time),
+                    ])
+                })
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::CovIncrement => self
+                .cov_increment
+                .map(PropertyValue::UnsignedInteger)
+                .ok_or(ObjectError::UnknownProperty),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match property {
+            PropertyIdentifier::ObjectName => {
+                if let PropertyValue::CharacterString(name) = value {
+                    self.object_name = name;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            // Present_Value follows the meter input unless out of service
+            PropertyIdentifier::PresentValue if self.out_of_service => match value {
+                PropertyValue::UnsignedInteger(count) if count <= self.max_pres_value => {
+                    self.store_present_value(count);
+                    Ok(())
+                }
+                PropertyValue::UnsignedInteger(_) => Err(ObjectError::InvalidValue(
+                    "Count exceeds Max_Pres_Value".to_string(),
+                )),
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Units => {
+                self.units = units_value(value)?;
+                Ok(())
+            }
+            PropertyIdentifier::MaxPresValue => {
+                if let PropertyValue::UnsignedInteger(max) = value {
+                    // Clamp while the old wrap point still applies, so the
+                    // drop is reported like any other change of value
+                    if self.present_value > max {
+                        self.store_present_value(max);
+                    }
+                    self.max_pres_value = max;
+                    self.cov_reported_value = self.cov_reported_value.min(max);
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::CovIncrement => {
+                if let PropertyValue::UnsignedInteger(increment) = value {
+                    self.cov_increment = Some(increment);
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        match property {
+            PropertyIdentifier::PresentValue => self.out_of_service,
+            PropertyIdentifier::ObjectName
+            | PropertyIdentifier::Description
+            | PropertyIdentifier::OutOfService
+            | PropertyIdentifier::Units
+            | PropertyIdentifier::MaxPresValue
+            | PropertyIdentifier::CovIncrement => true,
+            _ => false,
+        }
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        let mut properties = vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Scale,
+            PropertyIdentifier::Units,
+            PropertyIdentifier::Prescale,
+            PropertyIdentifier::MaxPresValue,
+        ];
+        if self.value_change_time.is_some() {
+            properties.push(PropertyIdentifier::ValueChangeTime);
+        }
+        if self.cov_increment.is_some() {
+            properties.push(PropertyIdentifier::CovIncrement);
+        }
+        properties
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{Date, Time};
+
+    #[cfg(feature = "std")]
+    use std::sync::{Arc, Mutex};
+
+    fn at(second: u8) -> BacnetDateTime {
+        BacnetDateTime::new(
+            Date {
+                year: 2025,
+                month: 6,
+                day: 2,
+                weekday: 1,
+            },
+            Time {
+                hour: 12,
+                minute: 0,
+                second,
+                hundredths: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn test_prescale_and_rollover() {
+        let mut meter = Accumulator::new(1, "Water meter".to_string());
+        // Ten pulses per litre, scaled to cubic metres
+        meter.prescale = Prescale {
+            multiplier: 1,
+            modulo_divide: 10,
+        };
+        meter.scale = Scale::Integer(-3);
+        meter.max_pres_value = 9_999;
+
+        meter.add_pulses(25, at(0));
+        assert_eq!(meter.present_value, 2);
+        meter.add_pulses(5, at(1));
+        assert_eq!(meter.present_value, 3);
+        assert_eq!(meter.value_change_time, Some(at(1)));
+
+        meter.present_value = 9_998;
+        meter.add_pulses(30, at(2));
+        assert_eq!(meter.present_value, 1);
+        assert!((meter.scaled_value() - 0.001).abs() < 1e-6);
+
+        // Pulses are not counted while out of service
+        meter.out_of_service = true;
+        meter.add_pulses(100, at(3));
+        assert_eq!(meter.present_value, 1);
+        assert!(matches!(
+            meter.get_property(PropertyIdentifier::PresentValue),
+            Ok(PropertyValue::UnsignedInteger(1))
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_cov_on_count_increment() {
+        let mut meter = Accumulator::new(2, "Energy meter".to_string());
+        meter.max_pres_value = 999;
+        meter.cov_increment = Some(10);
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        meter
+            .callbacks
+            .register(PropertyIdentifier::PresentValue, move |change| {
+                if let PropertyValue::UnsignedInteger(count) = change.new_value {
+                    sink.lock().unwrap().push(count);
+                }
+            });
+
+        meter.add_pulses(9, at(0));
+        meter.add_pulses(1, at(1));
+        meter.add_pulses(985, at(2));
+        // Rolling over from 995 to 5 advances the count by ten
+        meter.add_pulses(10, at(3));
+        assert_eq!(*reports.lock().unwrap(), vec![10, 995, 5]);
+
+        // Lowering Max_Pres_Value below the count clamps it and reports it
+        meter.add_pulses(500, at(4));
+        meter
+            .set_property(
+                PropertyIdentifier::MaxPresValue,
+                PropertyValue::UnsignedInteger(99),
+            )
+            .unwrap();
+        assert_eq!(meter.present_value, 99);
+        assert_eq!(*reports.lock().unwrap(), vec![10, 995, 5, 505, 99]);
+        meter.add_pulses(10, at(5));
+        assert_eq!(*reports.lock().unwrap(), vec![10, 995, 5, 505, 99, 9]);
+    }
+
+    #[test]
+    fn test_accumulator_listed_properties_are_readable() {
+        let mut meter = Accumulator::new(1, "Water meter".to_string());
+        meter.value_change_time = Some(at(0));
+        meter.cov_increment = Some(1);
+        for property in meter.property_list() {
+            assert!(
+                meter.get_property(property).is_ok(),
+                "{:?} is listed but not readable",
+                property
+            );
+        }
+        match meter.get_property(PropertyIdentifier::Scale).unwrap() {
+            PropertyValue::Constructed(data) => assert_eq!(data, [0x0C, 0x3F, 0x80, 0x00, 0x00]),
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+        match meter.get_property(PropertyIdentifier::Prescale).unwrap() {
+            PropertyValue::Constructed(data) => assert_eq!(data, [0x09, 0x01, 0x19, 0x01]),
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_scale_and_prescale_encoding() {
+        let mut buffer = Vec::new();
+        Scale::Float(0.5).encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x0C, 0x3F, 0x00, 0x00, 0x00]);
+        assert_eq!(Scale::decode(&buffer).unwrap(), (Scale::Float(0.5), 5));
+
+        let mut buffer = Vec::new();
+        Scale::Integer(-2).encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x19, 0xFE]);
+        assert_eq!(Scale::decode(&buffer).unwrap(), (Scale::Integer(-2), 2));
+
+        let prescale = Prescale {
+            multiplier: 1,
+            modulo_divide: 100,
+        };
+        let mut buffer = Vec::new();
+        prescale.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x09, 0x01, 0x19, 0x64]);
+        assert_eq!(Prescale::decode(&buffer).unwrap(), (prescale, 4));
+    }
+}
@@ -4,15 +4,27 @@
 //! as defined in ASHRAE 135. These objects represent analog (continuous) values in BACnet.
 
 use crate::object::{
-    engineering_units::EngineeringUnits, BacnetObject, ObjectError, ObjectIdentifier, ObjectType,
+    callback::PropertyCallbacks, engineering_units::EngineeringUnits, priority::PriorityArray,
+    proprietary::ProprietaryProperties, status_flags::StatusFlags, units_value,
+    validate_description, write_rejected, BacnetObject, ObjectError, ObjectIdentifier, ObjectType,
     PropertyIdentifier, PropertyValue, Result,
 };
 
+use crate::datatypes::Clock;
+
+use core::time::Duration;
+
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 /// Analog Input object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnalogInput {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -24,8 +36,8 @@ pub struct AnalogInput {
     pub description: String,
     /// Device type
     pub device_type: String,
-    /// Status flags (4 bits: in_alarm, fault, overridden, out_of_service)
-    pub status_flags: u8,
+    /// Status flags
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -40,12 +52,35 @@ pub struct AnalogInput {
     pub max_pres_value: Option<f32>,
     /// Resolution
     pub resolution: Option<f32>,
-    /// COV increment
+    /// COV increment; without one every change of value is reported
     pub cov_increment: Option<f32>,
+    /// High limit for intrinsic reporting
+    pub high_limit: f32,
+    /// Low limit for intrinsic reporting
+    pub low_limit: f32,
+    /// Deadband a value must clear before a limit alarm returns to normal
+    pub deadband: f32,
+    /// Which limits are checked
+    pub limit_enable: LimitEnable,
+    /// Seconds a limit condition must persist before the event state changes
+    pub time_delay: u32,
+    /// Seconds the return to normal must persist, when it differs from
+    /// `time_delay`
+    pub time_delay_normal: Option<u32>,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+    /// Event state the object is waiting to enter, and since when
+    pending_event: Option<(EventState, Duration)>,
+    /// Present value at the last change of value report
+    cov_reported_value: f32,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 /// Analog Output object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnalogOutput {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -58,7 +93,7 @@ pub struct AnalogOutput {
     /// Device type
     pub device_type: String,
     /// Status flags
-    pub status_flags: u8,
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -71,18 +106,26 @@ pub struct AnalogOutput {
     pub min_pres_value: Option<f32>,
     /// Maximum present value
     pub max_pres_value: Option<f32>,
+    /// How commands outside Min_Pres_Value..Max_Pres_Value are handled
+    pub pres_value_limit: PresentValueLimit,
     /// Resolution
     pub resolution: Option<f32>,
     /// Priority array (16 levels)
-    pub priority_array: [Option<f32>; 16],
+    pub priority_array: PriorityArray<f32>,
     /// Relinquish default
     pub relinquish_default: f32,
     /// COV increment
     pub cov_increment: Option<f32>,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 /// Analog Value object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnalogValue {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -93,7 +136,7 @@ pub struct AnalogValue {
     /// Description
     pub description: String,
     /// Status flags
-    pub status_flags: u8,
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -102,16 +145,28 @@ pub struct AnalogValue {
     pub out_of_service: bool,
     /// Units
     pub units: EngineeringUnits,
+    /// Minimum present value
+    pub min_pres_value: Option<f32>,
+    /// Maximum present value
+    pub max_pres_value: Option<f32>,
+    /// How commands outside Min_Pres_Value..Max_Pres_Value are handled
+    pub pres_value_limit: PresentValueLimit,
     /// Priority array (16 levels)
-    pub priority_array: [Option<f32>; 16],
+    pub priority_array: PriorityArray<f32>,
     /// Relinquish default
     pub relinquish_default: f32,
     /// COV increment
     pub cov_increment: Option<f32>,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 /// Event state enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum EventState {
     Normal = 0,
@@ -122,8 +177,54 @@ pub enum EventState {
     LifeSafetyAlarm = 5,
 }
 
+impl EventState {
+    /// Event state the out-of-range algorithm calls for, from this state
+    ///
+    /// A value beyond an enabled limit is a high or low limit condition. Once
+    /// in one, the condition only clears when the value is back inside the
+    /// limit by more than the deadband, so a value hovering around the limit
+    /// does not toggle the alarm. A disabled limit is `None`.
+    pub fn out_of_range(
+        self,
+        value: f32,
+        low_limit: Option<f32>,
+        high_limit: Option<f32>,
+        deadband: f32,
+    ) -> EventState {
+        match (self, low_limit, high_limit) {
+            (EventState::HighLimit, _, Some(high)) if value >= high - deadband => {
+                EventState::HighLimit
+            }
+            (EventState::LowLimit, Some(low), _) if value <= low + deadband => EventState::LowLimit,
+            (_, _, Some(high)) if value > high => EventState::HighLimit,
+            (_, Some(low), _) if value < low => EventState::LowLimit,
+            _ => EventState::Normal,
+        }
+    }
+}
+
+impl TryFrom<u32> for EventState {
+    type Error = ObjectError;
+
+    fn try_from(value: u32) -> Result<Self> {
+        match value {
+            0 => Ok(EventState::Normal),
+            1 => Ok(EventState::Fault),
+            2 => Ok(EventState::Offnormal),
+            3 => Ok(EventState::HighLimit),
+            4 => Ok(EventState::LowLimit),
+            5 => Ok(EventState::LifeSafetyAlarm),
+            _ => Err(ObjectError::InvalidValue(format!(
+                "Unknown event state value {}",
+                value
+            ))),
+        }
+    }
+}
+
 /// Reliability enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum Reliability {
     NoFaultDetected = 0,
@@ -137,6 +238,184 @@ pub enum Reliability {
     ProcessError = 8,
     MultiStateFault = 9,
     ConfigurationError = 10,
+    MemberFault = 12,
+    CommunicationFailure = 13,
+    Tripped = 14,
+    LampFailure = 15,
+    ActivationFailure = 16,
+    RenewDhcpFailure = 17,
+    RenewFdRegistrationFailure = 18,
+    RestartAutoNegotiationFailure = 19,
+    RestartFailure = 20,
+    ProprietaryCommandFailure = 21,
+    FaultsListed = 22,
+    ReferencedObjectFault = 23,
+    MultiStateOutOfRange = 24,
+}
+
+impl Reliability {
+    /// Check if this value reports a fault, i.e. anything other than no-fault-detected
+    pub fn is_fault(self) -> bool {
+        self != Reliability::NoFaultDetected
+    }
+}
+
+impl TryFrom<u32> for Reliability {
+    type Error = ObjectError;
+
+    fn try_from(value: u32) -> Result<Self> {
+        match value {
+            0 => Ok(Reliability::NoFaultDetected),
+            1 => Ok(Reliability::NoSensor),
+            2 => Ok(Reliability::OverRange),
+            3 => Ok(Reliability::UnderRange),
+            4 => Ok(Reliability::OpenLoop),
+            5 => Ok(Reliability::ShortedLoop),
+            6 => Ok(Reliability::NoOutput),
+            7 => Ok(Reliability::UnreliableOther),
+            8 => Ok(Reliability::ProcessError),
+            9 => Ok(Reliability::MultiStateFault),
+            10 => Ok(Reliability::ConfigurationError),
+            12 => Ok(Reliability::MemberFault),
+            13 => Ok(Reliability::CommunicationFailure),
+            14 => Ok(Reliability::Tripped),
+            15 => Ok(Reliability::LampFailure),
+            16 => Ok(Reliability::ActivationFailure),
+            17 => Ok(Reliability::RenewDhcpFailure),
+            18 => Ok(Reliability::RenewFdRegistrationFailure),
+            19 => Ok(Reliability::RestartAutoNegotiationFailure),
+            20 => Ok(Reliability::RestartFailure),
+            21 => Ok(Reliability::ProprietaryCommandFailure),
+            22 => Ok(Reliability::FaultsListed),
+            23 => Ok(Reliability::ReferencedObjectFault),
+            24 => Ok(Reliability::MultiStateOutOfRange),
+            _ => Err(ObjectError::InvalidValue(format!(
+                "Unknown reliability value {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl From<Reliability> for PropertyValue {
+    fn from(reliability: Reliability) -> Self {
+        PropertyValue::Enumerated(reliability as u32)
+    }
+}
+
+/// Limit_Enable bit string (low-limit-enable, high-limit-enable)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LimitEnable {
+    /// Check the low limit
+    pub low_limit_enable: bool,
+    /// Check the high limit
+    pub high_limit_enable: bool,
+}
+
+impl LimitEnable {
+    /// Bit number of low-limit-enable
+    pub const LOW_LIMIT_ENABLE: usize = 0;
+    /// Bit number of high-limit-enable
+    pub const HIGH_LIMIT_ENABLE: usize = 1;
+
+    /// Enable both limits
+    pub fn both() -> Self {
+        Self {
+            low_limit_enable: true,
+            high_limit_enable: true,
+        }
+    }
+
+    /// Convert to a bit string, low-limit-enable first
+    pub fn to_bitstring(&self) -> Vec<bool> {
+        vec![self.low_limit_enable, self.high_limit_enable]
+    }
+
+    /// Parse from a bit string, low-limit-enable first
+    pub fn from_bitstring(bits: &[bool]) -> Result<Self> {
+        if bits.len() < 2 {
+            return Err(ObjectError::InvalidValue(
+                "Limit enable requires 2 bits".to_string(),
+            ));
+        }
+        Ok(Self {
+            low_limit_enable: bits[Self::LOW_LIMIT_ENABLE],
+            high_limit_enable: bits[Self::HIGH_LIMIT_ENABLE],
+        })
+    }
+}
+
+/// Handling of present value commands outside Min_Pres_Value..Max_Pres_Value
+///
+/// Either way the command leaves the status flags and reliability alone. A
+/// NaN command is never in range and is refused in both modes, as there is
+/// no nearest limit to clamp it to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PresentValueLimit {
+    /// Store the nearest limit in the priority array
+    #[default]
+    Clamp,
+    /// Refuse the write with a value-out-of-range error
+    Reject,
+}
+
+impl PresentValueLimit {
+    /// Apply the limits to a commanded value
+    pub fn apply(self, value: f32, min: Option<f32>, max: Option<f32>) -> Result<f32> {
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                return Err(ObjectError::InvalidConfiguration(
+                    "Minimum present value is above the maximum".to_string(),
+                ));
+            }
+        }
+        if value.is_nan() {
+            return Err(ObjectError::InvalidValue(
+                "Present value NaN is outside the present value limits".to_string(),
+            ));
+        }
+        let below = min.filter(|&min| value < min);
+        let above = max.filter(|&max| value > max);
+        match (self, below.or(above)) {
+            (_, None) => Ok(value),
+            (PresentValueLimit::Clamp, Some(limit)) => Ok(limit),
+            (PresentValueLimit::Reject, Some(_)) => Err(ObjectError::InvalidValue(format!(
+                "Present value {} is outside the present value limits",
+                value
+            ))),
+        }
+    }
+}
+
+/// Check a written Min_Pres_Value or Max_Pres_Value against the other bound
+fn pres_value_bound(value: PropertyValue, min: Option<f32>, max: Option<f32>) -> Result<f32> {
+    let PropertyValue::Real(bound) = value else {
+        return Err(ObjectError::InvalidPropertyType);
+    };
+    if bound.is_nan() || min.is_some_and(|min| bound < min) || max.is_some_and(|max| bound > max) {
+        return Err(ObjectError::InvalidValue(
+            "Minimum present value must not be above the maximum".to_string(),
+        ));
+    }
+    Ok(bound)
+}
+
+/// Narrow a Double present value to the stored Real
+///
+/// Values a Real cannot hold exactly, such as 1e40 or 0.1, are refused
+/// rather than rounded.
+fn real_from_double(value: f64) -> Result<f32> {
+    let real = value as f32;
+    if real as f64 == value {
+        Ok(real)
+    } else {
+        Err(ObjectError::InvalidValue(format!(
+            "Present value {} does not fit a Real",
+            value
+        )))
+    }
 }
 
 // EngineeringUnits enum moved to src/object/engineering_units.rs for complete implementation
@@ -150,7 +429,7 @@ impl AnalogInput {
             present_value: 0.0,
             description: String::new(),
             device_type: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
@@ -159,21 +438,130 @@ impl AnalogInput {
             max_pres_value: None,
             resolution: None,
             cov_increment: None,
+            high_limit: 0.0,
+            low_limit: 0.0,
+            deadband: 0.0,
+            limit_enable: LimitEnable::default(),
+            time_delay: 0,
+            time_delay_normal: None,
+            callbacks: PropertyCallbacks::new(),
+            pending_event: None,
+            cov_reported_value: 0.0,
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
+    /// Start building an Analog Input object
+    pub fn builder(instance: u32) -> AnalogInputBuilder {
+        AnalogInputBuilder::new(instance)
+    }
+
     /// Set the present value
+    ///
+    /// Present_Value callbacks act as the change of value hook: they fire
+    /// once the value has moved by at least the COV increment since the last
+    /// report, or on any change when no increment is set.
     pub fn set_present_value(&mut self, value: f32) {
         self.present_value = value;
+        let delta = (value - self.cov_reported_value).abs();
+        let reportable = match self.cov_increment {
+            Some(increment) => delta > 0.0 && delta >= increment,
+            None => delta > 0.0,
+        };
+        if reportable {
+            self.report_cov();
+        }
+    }
+
+    /// Notify Present_Value callbacks of a change of value report
+    fn report_cov(&mut self) {
+        let reported = core::mem::replace(&mut self.cov_reported_value, self.present_value);
+        self.callbacks.notify(
+            self.identifier,
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Real(reported),
+            PropertyValue::Real(self.present_value),
+        );
+    }
+
+    /// Run the out-of-range event algorithm at a monotonic timestamp
+    ///
+    /// A limit condition must hold for `time_delay` seconds before the event
+    /// state changes, and a return to normal for `time_delay_normal` seconds
+    /// when set; faults take effect immediately. High and low limit alarms
+    /// only clear once the value is back inside the limit by at least the
+    /// deadband. Returns the new event state when a transition occurs.
+    pub fn update_event_state(&mut self, now: Duration) -> Option<EventState> {
+        let target = self.target_event_state();
+        if target == self.event_state {
+            self.pending_event = None;
+            return None;
+        }
+
+        if target != EventState::Fault && self.event_state != EventState::Fault {
+            let since = match self.pending_event {
+                Some((pending, since)) if pending == target => since,
+                _ => {
+                    self.pending_event = Some((target, now));
+                    now
+                }
+            };
+            let time_delay = match target {
+                EventState::Normal => self.time_delay_normal.unwrap_or(self.time_delay),
+                _ => self.time_delay,
+            };
+            if now.saturating_sub(since) < Duration::from_secs(time_delay as u64) {
+                return None;
+            }
+        }
+
+        self.pending_event = None;
+        let old = self.event_state;
+        self.event_state = target;
+        self.callbacks.notify(
+            self.identifier,
+            PropertyIdentifier::EventState,
+            PropertyValue::Enumerated(old as u32),
+            PropertyValue::Enumerated(target as u32),
+        );
+        self.update_status_flags();
+        Some(target)
+    }
+
+    /// Run the out-of-range event algorithm at the time of a clock
+    ///
+    /// Time delays are measured between readings of the clock. Nothing
+    /// happens while the clock has no valid date and time.
+    ///
+    /// The clock is wall time, not monotonic: setting it back, as a time
+    /// synchronization may, holds a pending transition until the clock
+    /// catches up, and setting it forward cuts the delay short. Pass a
+    /// monotonic timestamp to [`update_event_state`](Self::update_event_state) where
+    /// that matters.
+    pub fn update_event_state_from(&mut self, clock: &dyn Clock) -> Option<EventState> {
+        let now = clock.now().since_1900()?;
+        self.update_event_state(now)
+    }
+
+    /// Event state the current value and reliability call for
+    fn target_event_state(&self) -> EventState {
+        if self.reliability.is_fault() {
+            return EventState::Fault;
+        }
+
+        let low = Some(self.low_limit).filter(|_| self.limit_enable.low_limit_enable);
+        let high = Some(self.high_limit).filter(|_| self.limit_enable.high_limit_enable);
+        self.event_state
+            .out_of_range(self.present_value, low, high, self.deadband)
     }
 
     /// Get status flags as individual booleans
     pub fn get_status_flags(&self) -> (bool, bool, bool, bool) {
         (
-            (self.status_flags & 0x08) != 0, // in_alarm
-            (self.status_flags & 0x04) != 0, // fault
-            (self.status_flags & 0x02) != 0, // overridden
-            (self.status_flags & 0x01) != 0, // out_of_service
+            self.status_flags.in_alarm,
+            self.status_flags.fault,
+            self.status_flags.overridden,
+            self.status_flags.out_of_service,
         )
     }
 
@@ -185,19 +573,257 @@ impl AnalogInput {
         overridden: bool,
         out_of_service: bool,
     ) {
-        self.status_flags = 0;
-        if in_alarm {
-            self.status_flags |= 0x08;
+        self.replace_status_flags(StatusFlags::new(
+            in_alarm,
+            fault,
+            overridden,
+            out_of_service,
+        ));
+    }
+
+    /// Set the reliability, updating the fault status flag
+    pub fn set_reliability(&mut self, reliability: Reliability) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::Reliability,
+            &mut self.reliability,
+            reliability,
+        );
+        self.update_status_flags();
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        let flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+        self.replace_status_flags(flags);
+    }
+
+    /// Store new status flags, notifying callbacks if any bit changed
+    fn replace_status_flags(&mut self, flags: StatusFlags) {
+        let changed = self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::StatusFlags,
+            &mut self.status_flags,
+            flags,
+        );
+        if changed {
+            // A status flag change is reported with the current value
+            self.report_cov();
         }
-        if fault {
-            self.status_flags |= 0x04;
+    }
+}
+
+/// Builder for an [`AnalogInput`] object
+///
+/// The configuration is checked as a whole by [`build`](Self::build), so a
+/// limit that is set but not enabled, or enabled but not set, is reported
+/// there instead of silently never alarming. Only Analog Input has a
+/// builder; other objects are configured through their fields after `new`.
+#[derive(Debug, Clone)]
+pub struct AnalogInputBuilder {
+    instance: u32,
+    object_name: Option<String>,
+    description: String,
+    device_type: String,
+    present_value: f32,
+    units: EngineeringUnits,
+    min_pres_value: Option<f32>,
+    max_pres_value: Option<f32>,
+    resolution: Option<f32>,
+    cov_increment: Option<f32>,
+    high_limit: Option<f32>,
+    low_limit: Option<f32>,
+    deadband: f32,
+    limit_enable: LimitEnable,
+    time_delay: u32,
+    time_delay_normal: Option<u32>,
+}
+
+impl AnalogInputBuilder {
+    /// Create a builder for the Analog Input with `instance`
+    pub fn new(instance: u32) -> Self {
+        Self {
+            instance,
+            object_name: None,
+            description: String::new(),
+            device_type: String::new(),
+            present_value: 0.0,
+            units: EngineeringUnits::NoUnits,
+            min_pres_value: None,
+            max_pres_value: None,
+            resolution: None,
+            cov_increment: None,
+            high_limit: None,
+            low_limit: None,
+            deadband: 0.0,
+            limit_enable: LimitEnable::default(),
+            time_delay: 0,
+            time_delay_normal: None,
         }
-        if overridden {
-            self.status_flags |= 0x02;
+    }
+
+    /// Set the object name (required)
+    pub fn name(mut self, object_name: impl Into<String>) -> Self {
+        self.object_name = Some(object_name.into());
+        self
+    }
+
+    /// Set the description
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// Set the device type
+    pub fn device_type(mut self, device_type: impl Into<String>) -> Self {
+        self.device_type = device_type.into();
+        self
+    }
+
+    /// Set the initial present value
+    pub fn present_value(mut self, value: f32) -> Self {
+        self.present_value = value;
+        self
+    }
+
+    /// Set the engineering units
+    pub fn units(mut self, units: EngineeringUnits) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Set the range the present value is expected within
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.min_pres_value = Some(min);
+        self.max_pres_value = Some(max);
+        self
+    }
+
+    /// Set the resolution
+    pub fn resolution(mut self, resolution: f32) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
+    /// Set the COV increment
+    pub fn cov_increment(mut self, increment: f32) -> Self {
+        self.cov_increment = Some(increment);
+        self
+    }
+
+    /// Set the high limit for intrinsic reporting and enable it
+    pub fn high_limit(mut self, limit: f32) -> Self {
+        self.high_limit = Some(limit);
+        self.limit_enable.high_limit_enable = true;
+        self
+    }
+
+    /// Set the low limit for intrinsic reporting and enable it
+    pub fn low_limit(mut self, limit: f32) -> Self {
+        self.low_limit = Some(limit);
+        self.limit_enable.low_limit_enable = true;
+        self
+    }
+
+    /// Set which limits are checked, overriding those enabled by
+    /// [`high_limit`](Self::high_limit) and [`low_limit`](Self::low_limit)
+    pub fn limit_enable(mut self, limit_enable: LimitEnable) -> Self {
+        self.limit_enable = limit_enable;
+        self
+    }
+
+    /// Set the deadband for returning to normal from a limit alarm
+    pub fn deadband(mut self, deadband: f32) -> Self {
+        self.deadband = deadband;
+        self
+    }
+
+    /// Set the seconds a limit condition must persist before it is reported
+    pub fn time_delay(mut self, seconds: u32) -> Self {
+        self.time_delay = seconds;
+        self
+    }
+
+    /// Set the seconds a return to normal must persist before it is reported
+    pub fn time_delay_normal(mut self, seconds: u32) -> Self {
+        self.time_delay_normal = Some(seconds);
+        self
+    }
+
+    /// Build the object, checking the configuration is consistent
+    pub fn build(self) -> Result<AnalogInput> {
+        let invalid = |message: &str| Err(ObjectError::InvalidConfiguration(message.to_string()));
+        let identifier = ObjectIdentifier::try_new(ObjectType::AnalogInput, self.instance)?;
+        let object_name = match self.object_name {
+            Some(name) if !name.is_empty() => name,
+            _ => return invalid("Object name is required"),
+        };
+        validate_description(&self.description)?;
+
+        let values = [
+            Some(self.present_value),
+            Some(self.deadband),
+            self.min_pres_value,
+            self.max_pres_value,
+            self.resolution,
+            self.cov_increment,
+            self.high_limit,
+            self.low_limit,
+        ];
+        if values.into_iter().flatten().any(f32::is_nan) {
+            return invalid("Values must not be NaN");
+        }
+
+        let enable = self.limit_enable;
+        if enable.high_limit_enable != self.high_limit.is_some() {
+            return invalid("High limit must be set exactly when it is enabled");
+        }
+        if enable.low_limit_enable != self.low_limit.is_some() {
+            return invalid("Low limit must be set exactly when it is enabled");
+        }
+        if let (Some(low), Some(high)) = (self.low_limit, self.high_limit) {
+            if low > high {
+                return invalid("Low limit is above the high limit");
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_pres_value, self.max_pres_value) {
+            if min > max {
+                return invalid("Minimum present value is above the maximum");
+            }
+        }
+        if self
+            .min_pres_value
+            .is_some_and(|min| self.present_value < min)
+            || self
+                .max_pres_value
+                .is_some_and(|max| self.present_value > max)
+        {
+            return invalid("Present value is outside the minimum and maximum");
         }
-        if out_of_service {
-            self.status_flags |= 0x01;
+        if self.deadband < 0.0 || self.cov_increment.is_some_and(|increment| increment < 0.0) {
+            return invalid("Deadband and COV increment must not be negative");
         }
+
+        let mut object = AnalogInput::new(self.instance, object_name);
+        object.identifier = identifier;
+        object.description = self.description;
+        object.device_type = self.device_type;
+        object.units = self.units;
+        object.min_pres_value = self.min_pres_value;
+        object.max_pres_value = self.max_pres_value;
+        object.resolution = self.resolution;
+        object.cov_increment = self.cov_increment;
+        object.high_limit = self.high_limit.unwrap_or_default();
+        object.low_limit = self.low_limit.unwrap_or_default();
+        object.deadband = self.deadband;
+        object.limit_enable = enable;
+        object.time_delay = self.time_delay;
+        object.time_delay_normal = self.time_delay_normal;
+        object.present_value = self.present_value;
+        object.cov_reported_value = self.present_value;
+        Ok(object)
     }
 }
 
@@ -210,51 +836,74 @@ impl AnalogOutput {
             present_value: 0.0,
             description: String::new(),
             device_type: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
             units: EngineeringUnits::NoUnits,
             min_pres_value: None,
             max_pres_value: None,
+            pres_value_limit: PresentValueLimit::Clamp,
             resolution: None,
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: 0.0,
             cov_increment: None,
+            callbacks: PropertyCallbacks::new(),
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<f32>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        // Highest priority non-null value, or relinquish default if all are null
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 
     /// Get the effective priority level for current present value
     pub fn get_effective_priority(&self) -> Option<u8> {
-        for (i, priority_value) in self.priority_array.iter().enumerate() {
-            if priority_value.is_some() {
-                return Some((i + 1) as u8);
-            }
-        }
-        None
+        self.priority_array.effective_priority()
+    }
+
+    /// Set the relinquish default and recompute the present value
+    pub fn set_relinquish_default(&mut self, value: f32) {
+        self.relinquish_default = value;
+        self.update_present_value();
+    }
+
+    /// Set the reliability, updating the fault status flag
+    pub fn set_reliability(&mut self, reliability: Reliability) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::Reliability,
+            &mut self.reliability,
+            reliability,
+        );
+        self.update_status_flags();
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        let flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+        self.replace_status_flags(flags);
+    }
+
+    /// Store new status flags, notifying callbacks if any bit changed
+    fn replace_status_flags(&mut self, flags: StatusFlags) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::StatusFlags,
+            &mut self.status_flags,
+            flags,
+        );
     }
 }
 
@@ -266,38 +915,73 @@ impl AnalogValue {
             object_name,
             present_value: 0.0,
             description: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
             units: EngineeringUnits::NoUnits,
-            priority_array: [None; 16],
+            min_pres_value: None,
+            max_pres_value: None,
+            pres_value_limit: PresentValueLimit::Clamp,
+            priority_array: PriorityArray::new(),
             relinquish_default: 0.0,
             cov_increment: None,
+            callbacks: PropertyCallbacks::new(),
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<f32>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        // Highest priority non-null value, or relinquish default if all are null
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
+    }
+
+    /// Get the effective priority level for current present value
+    pub fn get_effective_priority(&self) -> Option<u8> {
+        self.priority_array.effective_priority()
+    }
+
+    /// Set the relinquish default and recompute the present value
+    pub fn set_relinquish_default(&mut self, value: f32) {
+        self.relinquish_default = value;
+        self.update_present_value();
+    }
+
+    /// Set the reliability, updating the fault status flag
+    pub fn set_reliability(&mut self, reliability: Reliability) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::Reliability,
+            &mut self.reliability,
+            reliability,
+        );
+        self.update_status_flags();
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        let flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+        self.replace_status_flags(flags);
+    }
+
+    /// Store new status flags, notifying callbacks if any bit changed
+    fn replace_status_flags(&mut self, flags: StatusFlags) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::StatusFlags,
+            &mut self.status_flags,
+            flags,
+        );
     }
 }
 
@@ -314,12 +998,53 @@ impl BacnetObject for AnalogInput {
             PropertyIdentifier::ObjectName => {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
-            PropertyIdentifier::ObjectType => {
-                Ok(PropertyValue::Enumerated(ObjectType::AnalogInput as u32))
-            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::AnalogInput.as_u16() as u32,
+            )),
             PropertyIdentifier::PresentValue => Ok(PropertyValue::Real(self.present_value)),
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::HighLimit => Ok(PropertyValue::Real(self.high_limit)),
+            PropertyIdentifier::LowLimit => Ok(PropertyValue::Real(self.low_limit)),
+            PropertyIdentifier::Deadband => Ok(PropertyValue::Real(self.deadband)),
+            PropertyIdentifier::LimitEnable => {
+                Ok(PropertyValue::BitString(self.limit_enable.to_bitstring()))
+            }
+            PropertyIdentifier::TimeDelay => Ok(PropertyValue::UnsignedInteger(self.time_delay)),
+            PropertyIdentifier::TimeDelayNormal => self
+                .time_delay_normal
+                .map(PropertyValue::UnsignedInteger)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::Units => Ok(PropertyValue::Enumerated(u32::from(self.units))),
+            PropertyIdentifier::DeviceType => {
+                Ok(PropertyValue::CharacterString(self.device_type.clone()))
+            }
+            PropertyIdentifier::MinPresValue => self
+                .min_pres_value
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::MaxPresValue => self
+                .max_pres_value
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::Resolution => self
+                .resolution
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::CovIncrement => self
+                .cov_increment
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -333,33 +1058,184 @@ impl BacnetObject for AnalogInput {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
+            // Present_Value of an input is writable only while out of service
+            PropertyIdentifier::PresentValue if self.out_of_service => match value {
+                PropertyValue::Real(val) => {
+                    self.set_present_value(val);
+                    Ok(())
+                }
+                PropertyValue::Double(val) => {
+                    self.set_present_value(real_from_double(val)?);
+                    Ok(())
+                }
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
             PropertyIdentifier::OutOfService => {
                 if let PropertyValue::Boolean(oos) = value {
                     self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::HighLimit => {
+                if let PropertyValue::Real(limit) = value {
+                    self.high_limit = limit;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::LowLimit => {
+                if let PropertyValue::Real(limit) = value {
+                    self.low_limit = limit;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Deadband => match value {
+                PropertyValue::Real(deadband) if deadband >= 0.0 => {
+                    self.deadband = deadband;
+                    Ok(())
+                }
+                PropertyValue::Real(_) => Err(ObjectError::InvalidValue(
+                    "Deadband must not be negative".to_string(),
+                )),
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            PropertyIdentifier::LimitEnable => {
+                if let PropertyValue::BitString(bits) = value {
+                    self.limit_enable = LimitEnable::from_bitstring(&bits)?;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::TimeDelay => {
+                if let PropertyValue::UnsignedInteger(seconds) = value {
+                    self.time_delay = seconds;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::TimeDelayNormal => {
+                if let PropertyValue::UnsignedInteger(seconds) = value {
+                    self.time_delay_normal = Some(seconds);
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::Units => {
+                self.units = units_value(value)?;
+                Ok(())
+            }
+            PropertyIdentifier::CovIncrement => match value {
+                PropertyValue::Real(increment) if increment >= 0.0 => {
+                    self.cov_increment = Some(increment);
+                    Ok(())
+                }
+                PropertyValue::Real(_) => Err(ObjectError::InvalidValue(
+                    "COV increment must not be negative".to_string(),
+                )),
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::PresentValue, PropertyValue::Real(val)) => {
+                self.set_present_value(val);
+                Ok(())
+            }
+            (PropertyIdentifier::PresentValue, PropertyValue::Double(val)) => {
+                self.set_present_value(real_from_double(val)?);
+                Ok(())
+            }
+            (PropertyIdentifier::PresentValue, _) => Err(ObjectError::InvalidPropertyType),
+            (PropertyIdentifier::Reliability, PropertyValue::Enumerated(val)) => {
+                self.set_reliability(Reliability::try_from(val)?);
+                Ok(())
+            }
+            (PropertyIdentifier::Reliability, _) => Err(ObjectError::InvalidPropertyType),
+            (property, value) => self.set_property(property, value),
         }
     }
 
     fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
-        matches!(
-            property,
-            PropertyIdentifier::ObjectName | PropertyIdentifier::OutOfService
-        )
+        (property == PropertyIdentifier::PresentValue && self.out_of_service)
+            || matches!(
+                property,
+                PropertyIdentifier::ObjectName
+                    | PropertyIdentifier::Description
+                    | PropertyIdentifier::Units
+                    | PropertyIdentifier::CovIncrement
+                    | PropertyIdentifier::OutOfService
+                    | PropertyIdentifier::HighLimit
+                    | PropertyIdentifier::LowLimit
+                    | PropertyIdentifier::Deadband
+                    | PropertyIdentifier::LimitEnable
+                    | PropertyIdentifier::TimeDelay
+                    | PropertyIdentifier::TimeDelayNormal
+            )
+            || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::DeviceType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
-        ]
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::Units,
+            PropertyIdentifier::HighLimit,
+            PropertyIdentifier::LowLimit,
+            PropertyIdentifier::Deadband,
+            PropertyIdentifier::LimitEnable,
+            PropertyIdentifier::TimeDelay,
+        ];
+        if self.time_delay_normal.is_some() {
+            properties.push(PropertyIdentifier::TimeDelayNormal);
+        }
+        if self.min_pres_value.is_some() {
+            properties.push(PropertyIdentifier::MinPresValue);
+        }
+        if self.max_pres_value.is_some() {
+            properties.push(PropertyIdentifier::MaxPresValue);
+        }
+        if self.resolution.is_some() {
+            properties.push(PropertyIdentifier::Resolution);
+        }
+        if self.cov_increment.is_some() {
+            properties.push(PropertyIdentifier::CovIncrement);
+        }
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -376,23 +1252,48 @@ impl BacnetObject for AnalogOutput {
             PropertyIdentifier::ObjectName => {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
-            PropertyIdentifier::ObjectType => {
-                Ok(PropertyValue::Enumerated(ObjectType::AnalogOutput as u32))
-            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::AnalogOutput.as_u16() as u32,
+            )),
             PropertyIdentifier::PresentValue => Ok(PropertyValue::Real(self.present_value)),
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            PropertyIdentifier::PriorityArray => {
-                let array: Vec<PropertyValue> = self
-                    .priority_array
-                    .iter()
-                    .map(|&v| match v {
-                        Some(val) => PropertyValue::Real(val),
-                        None => PropertyValue::Null,
-                    })
-                    .collect();
-                Ok(PropertyValue::Array(array))
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
             }
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::PriorityArray => Ok(PropertyValue::Array(
+                self.priority_array.to_property_values(PropertyValue::Real),
+            )),
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Real(self.relinquish_default))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::Units => Ok(PropertyValue::Enumerated(u32::from(self.units))),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::DeviceType => {
+                Ok(PropertyValue::CharacterString(self.device_type.clone()))
+            }
+            PropertyIdentifier::MinPresValue => self
+                .min_pres_value
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::MaxPresValue => self
+                .max_pres_value
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::Resolution => self
+                .resolution
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::CovIncrement => self
+                .cov_increment
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -406,23 +1307,97 @@ impl BacnetObject for AnalogOutput {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::PresentValue => {
+            PropertyIdentifier::PresentValue => self.write_property(property, value, None),
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::RelinquishDefault => {
                 if let PropertyValue::Real(val) = value {
-                    // Write to priority 8 (manual operator) by default
-                    self.write_priority(8, Some(val))
+                    self.set_relinquish_default(val);
+                    Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::OutOfService => {
-                if let PropertyValue::Boolean(oos) = value {
-                    self.out_of_service = oos;
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::Units => {
+                self.units = units_value(value)?;
+                Ok(())
+            }
+            PropertyIdentifier::MinPresValue => {
+                self.min_pres_value = Some(pres_value_bound(value, None, self.max_pres_value)?);
+                Ok(())
+            }
+            PropertyIdentifier::MaxPresValue => {
+                self.max_pres_value = Some(pres_value_bound(value, self.min_pres_value, None)?);
+                Ok(())
+            }
+            PropertyIdentifier::CovIncrement => match value {
+                PropertyValue::Real(increment) if increment >= 0.0 => {
+                    self.cov_increment = Some(increment);
+                    Ok(())
+                }
+                PropertyValue::Real(_) => Err(ObjectError::InvalidValue(
+                    "COV increment must not be negative".to_string(),
+                )),
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn write_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        if property != PropertyIdentifier::PresentValue {
+            return self.set_property(property, value);
+        }
+        // Writes without a priority use the lowest priority (16)
+        let priority = priority.unwrap_or(16);
+        let command = match value {
+            PropertyValue::Real(val) => val,
+            // Double precision commands are narrowed to the stored Real
+            PropertyValue::Double(val) => real_from_double(val)?,
+            // NULL relinquishes the command at this priority
+            PropertyValue::Null => return self.write_priority(priority, None),
+            _ => return Err(ObjectError::InvalidPropertyType),
+        };
+        let command =
+            self.pres_value_limit
+                .apply(command, self.min_pres_value, self.max_pres_value)?;
+        self.write_priority(priority, Some(command))
+    }
+
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::Reliability, PropertyValue::Enumerated(val)) => {
+                self.set_reliability(Reliability::try_from(val)?);
+                Ok(())
+            }
+            (PropertyIdentifier::Reliability, _) => Err(ObjectError::InvalidPropertyType),
+            (property, value) => self.set_property(property, value),
         }
     }
 
@@ -430,20 +1405,47 @@ impl BacnetObject for AnalogOutput {
         matches!(
             property,
             PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::Units
+                | PropertyIdentifier::CovIncrement
                 | PropertyIdentifier::PresentValue
                 | PropertyIdentifier::OutOfService
-        )
+                | PropertyIdentifier::RelinquishDefault
+                | PropertyIdentifier::MinPresValue
+                | PropertyIdentifier::MaxPresValue
+        ) || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::DeviceType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::Units,
             PropertyIdentifier::PriorityArray,
-        ]
+            PropertyIdentifier::RelinquishDefault,
+        ];
+        if self.min_pres_value.is_some() {
+            properties.push(PropertyIdentifier::MinPresValue);
+        }
+        if self.max_pres_value.is_some() {
+            properties.push(PropertyIdentifier::MaxPresValue);
+        }
+        if self.resolution.is_some() {
+            properties.push(PropertyIdentifier::Resolution);
+        }
+        if self.cov_increment.is_some() {
+            properties.push(PropertyIdentifier::CovIncrement);
+        }
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -460,23 +1462,41 @@ impl BacnetObject for AnalogValue {
             PropertyIdentifier::ObjectName => {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
-            PropertyIdentifier::ObjectType => {
-                Ok(PropertyValue::Enumerated(ObjectType::AnalogValue as u32))
-            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::AnalogValue.as_u16() as u32,
+            )),
             PropertyIdentifier::PresentValue => Ok(PropertyValue::Real(self.present_value)),
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            PropertyIdentifier::PriorityArray => {
-                let array: Vec<PropertyValue> = self
-                    .priority_array
-                    .iter()
-                    .map(|&v| match v {
-                        Some(val) => PropertyValue::Real(val),
-                        None => PropertyValue::Null,
-                    })
-                    .collect();
-                Ok(PropertyValue::Array(array))
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::PriorityArray => Ok(PropertyValue::Array(
+                self.priority_array.to_property_values(PropertyValue::Real),
+            )),
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Real(self.relinquish_default))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::Units => Ok(PropertyValue::Enumerated(u32::from(self.units))),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
             }
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::MinPresValue => self
+                .min_pres_value
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::MaxPresValue => self
+                .max_pres_value
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            PropertyIdentifier::CovIncrement => self
+                .cov_increment
+                .map(PropertyValue::Real)
+                .ok_or(ObjectError::UnknownProperty),
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -490,23 +1510,97 @@ impl BacnetObject for AnalogValue {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::PresentValue => {
+            PropertyIdentifier::PresentValue => self.write_property(property, value, None),
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::RelinquishDefault => {
                 if let PropertyValue::Real(val) = value {
-                    // Write to priority 8 (manual operator) by default
-                    self.write_priority(8, Some(val))
+                    self.set_relinquish_default(val);
+                    Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::OutOfService => {
-                if let PropertyValue::Boolean(oos) = value {
-                    self.out_of_service = oos;
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::Units => {
+                self.units = units_value(value)?;
+                Ok(())
+            }
+            PropertyIdentifier::MinPresValue => {
+                self.min_pres_value = Some(pres_value_bound(value, None, self.max_pres_value)?);
+                Ok(())
+            }
+            PropertyIdentifier::MaxPresValue => {
+                self.max_pres_value = Some(pres_value_bound(value, self.min_pres_value, None)?);
+                Ok(())
+            }
+            PropertyIdentifier::CovIncrement => match value {
+                PropertyValue::Real(increment) if increment >= 0.0 => {
+                    self.cov_increment = Some(increment);
+                    Ok(())
+                }
+                PropertyValue::Real(_) => Err(ObjectError::InvalidValue(
+                    "COV increment must not be negative".to_string(),
+                )),
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn write_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        if property != PropertyIdentifier::PresentValue {
+            return self.set_property(property, value);
+        }
+        // Writes without a priority use the lowest priority (16)
+        let priority = priority.unwrap_or(16);
+        let command = match value {
+            PropertyValue::Real(val) => val,
+            // Double precision commands are narrowed to the stored Real
+            PropertyValue::Double(val) => real_from_double(val)?,
+            // NULL relinquishes the command at this priority
+            PropertyValue::Null => return self.write_priority(priority, None),
+            _ => return Err(ObjectError::InvalidPropertyType),
+        };
+        let command =
+            self.pres_value_limit
+                .apply(command, self.min_pres_value, self.max_pres_value)?;
+        self.write_priority(priority, Some(command))
+    }
+
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::Reliability, PropertyValue::Enumerated(val)) => {
+                self.set_reliability(Reliability::try_from(val)?);
+                Ok(())
+            }
+            (PropertyIdentifier::Reliability, _) => Err(ObjectError::InvalidPropertyType),
+            (property, value) => self.set_property(property, value),
         }
     }
 
@@ -514,20 +1608,43 @@ impl BacnetObject for AnalogValue {
         matches!(
             property,
             PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::Units
+                | PropertyIdentifier::CovIncrement
                 | PropertyIdentifier::PresentValue
                 | PropertyIdentifier::OutOfService
-        )
+                | PropertyIdentifier::RelinquishDefault
+                | PropertyIdentifier::MinPresValue
+                | PropertyIdentifier::MaxPresValue
+        ) || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::Units,
             PropertyIdentifier::PriorityArray,
-        ]
+            PropertyIdentifier::RelinquishDefault,
+        ];
+        if self.min_pres_value.is_some() {
+            properties.push(PropertyIdentifier::MinPresValue);
+        }
+        if self.max_pres_value.is_some() {
+            properties.push(PropertyIdentifier::MaxPresValue);
+        }
+        if self.cov_increment.is_some() {
+            properties.push(PropertyIdentifier::CovIncrement);
+        }
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -569,6 +1686,28 @@ mod tests {
         assert_eq!(ao.get_effective_priority(), None);
     }
 
+    #[test]
+    fn test_analog_value_relinquish_default() {
+        let mut av = AnalogValue::new(1, "Setpoint".to_string());
+        av.set_property(
+            PropertyIdentifier::RelinquishDefault,
+            PropertyValue::Real(21.0),
+        )
+        .unwrap();
+        assert_eq!(av.present_value, 21.0);
+        assert_eq!(av.get_effective_priority(), None);
+
+        av.write_priority(16, Some(19.5)).unwrap();
+        assert_eq!(av.present_value, 19.5);
+        assert_eq!(av.get_effective_priority(), Some(16));
+
+        // Relinquish default only applies once every slot is released
+        av.set_relinquish_default(22.0);
+        assert_eq!(av.present_value, 19.5);
+        av.write_priority(16, None).unwrap();
+        assert_eq!(av.present_value, 22.0);
+    }
+
     #[test]
     fn test_analog_object_properties() {
         let mut av = AnalogValue::new(1, "Test Value".to_string());
@@ -601,6 +1740,623 @@ mod tests {
         assert!(!fault);
         assert!(overridden);
         assert!(!out_of_service);
-        assert_eq!(ai.status_flags, 0x0A); // 1010 in binary
+        assert_eq!(ai.status_flags, StatusFlags::new(true, false, true, false));
+    }
+
+    #[test]
+    fn test_reliability_sets_fault_flag() {
+        use std::sync::{Arc, Mutex};
+
+        let mut ai = AnalogInput::new(1, "Test".to_string());
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let seen = changes.clone();
+        ai.callbacks
+            .register(PropertyIdentifier::Reliability, move |change| {
+                seen.lock().unwrap().push(change.new_value.clone());
+            });
+
+        ai.set_property_remote(
+            PropertyIdentifier::Reliability,
+            PropertyValue::Enumerated(Reliability::OverRange as u32),
+        )
+        .unwrap();
+        assert_eq!(ai.reliability, Reliability::OverRange);
+        assert!(ai.get_status_flags().1);
+        assert!(matches!(
+            ai.get_property(PropertyIdentifier::Reliability).unwrap(),
+            PropertyValue::Enumerated(2)
+        ));
+
+        // Unchanged values do not notify
+        ai.set_reliability(Reliability::OverRange);
+        ai.set_reliability(Reliability::NoFaultDetected);
+        assert!(!ai.get_status_flags().1);
+
+        let changes = changes.lock().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert!(matches!(changes[1], PropertyValue::Enumerated(0)));
+
+        assert!(Reliability::try_from(11).is_err());
+        assert_eq!(
+            Reliability::try_from(24).unwrap(),
+            Reliability::MultiStateOutOfRange
+        );
+    }
+
+    #[test]
+    fn test_status_flags_follow_state() {
+        use std::sync::{Arc, Mutex};
+
+        let mut av = AnalogValue::new(1, "Setpoint".to_string());
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let seen = changes.clone();
+        av.callbacks
+            .register(PropertyIdentifier::StatusFlags, move |change| {
+                if let PropertyValue::BitString(bits) = &change.new_value {
+                    seen.lock().unwrap().push(bits.clone());
+                }
+            });
+
+        av.set_property(
+            PropertyIdentifier::OutOfService,
+            PropertyValue::Boolean(true),
+        )
+        .unwrap();
+        assert!(av.status_flags.out_of_service);
+        av.set_reliability(Reliability::UnreliableOther);
+        // Re-applying the same state leaves the flags unchanged
+        av.set_property(
+            PropertyIdentifier::OutOfService,
+            PropertyValue::Boolean(true),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            av.get_property(PropertyIdentifier::StatusFlags).unwrap(),
+            PropertyValue::BitString(ref bits) if bits == &[false, true, false, true]
+        ));
+        let changes = changes.lock().unwrap();
+        assert_eq!(
+            *changes,
+            vec![
+                vec![false, false, false, true],
+                vec![false, true, false, true]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_limit_alarm_time_delay_and_deadband() {
+        use std::sync::{Arc, Mutex};
+
+        let mut ai = AnalogInput::new(1, "Zone Temp".to_string());
+        ai.high_limit = 30.0;
+        ai.low_limit = 10.0;
+        ai.deadband = 2.0;
+        ai.limit_enable = LimitEnable::both();
+        ai.time_delay = 5;
+
+        let transitions = Arc::new(Mutex::new(Vec::new()));
+        let seen = transitions.clone();
+        ai.callbacks
+            .register(PropertyIdentifier::EventState, move |change| {
+                if let PropertyValue::Enumerated(state) = change.new_value {
+                    seen.lock().unwrap().push(state);
+                }
+            });
+
+        let secs = Duration::from_secs;
+        ai.set_present_value(31.0);
+        assert_eq!(ai.update_event_state(secs(0)), None);
+        assert_eq!(ai.update_event_state(secs(4)), None);
+        assert_eq!(ai.update_event_state(secs(5)), Some(EventState::HighLimit));
+        assert!(ai.status_flags.in_alarm);
+
+        // Inside the limit but within the deadband keeps the alarm
+        ai.set_present_value(29.0);
+        assert_eq!(ai.update_event_state(secs(20)), None);
+        assert_eq!(ai.event_state, EventState::HighLimit);
+
+        ai.set_present_value(27.5);
+        assert_eq!(ai.update_event_state(secs(30)), None);
+        assert_eq!(ai.update_event_state(secs(35)), Some(EventState::Normal));
+        assert!(!ai.status_flags.in_alarm);
+
+        // A condition that clears before the delay never transitions
+        ai.set_present_value(5.0);
+        assert_eq!(ai.update_event_state(secs(40)), None);
+        ai.set_present_value(15.0);
+        assert_eq!(ai.update_event_state(secs(50)), None);
+        assert_eq!(ai.event_state, EventState::Normal);
+
+        // Faults take effect immediately
+        ai.set_reliability(Reliability::NoSensor);
+        assert_eq!(ai.update_event_state(secs(51)), Some(EventState::Fault));
+
+        assert_eq!(
+            *transitions.lock().unwrap(),
+            vec![
+                EventState::HighLimit as u32,
+                EventState::Normal as u32,
+                EventState::Fault as u32
+            ]
+        );
+    }
+
+    #[test]
+    fn test_limit_properties() {
+        let mut ai = AnalogInput::new(1, "Test".to_string());
+        ai.set_property(
+            PropertyIdentifier::LimitEnable,
+            PropertyValue::BitString(vec![false, true]),
+        )
+        .unwrap();
+        assert!(ai.limit_enable.high_limit_enable);
+        assert!(!ai.limit_enable.low_limit_enable);
+        assert!(ai
+            .set_property(PropertyIdentifier::Deadband, PropertyValue::Real(-1.0))
+            .is_err());
+        ai.set_property(PropertyIdentifier::HighLimit, PropertyValue::Real(50.0))
+            .unwrap();
+        assert!(matches!(
+            ai.get_property(PropertyIdentifier::HighLimit).unwrap(),
+            PropertyValue::Real(v) if v == 50.0
+        ));
+
+        // Disabled limits are not checked
+        ai.set_present_value(-100.0);
+        assert_eq!(ai.update_event_state(Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_standard_property_coverage() {
+        let mut ai = AnalogInput::new(1, "Zone Temp".to_string());
+        ai.description = "Zone 1 temperature".to_string();
+
+        assert!(matches!(
+            ai.get_property(PropertyIdentifier::Description),
+            Ok(PropertyValue::CharacterString(d)) if d == "Zone 1 temperature"
+        ));
+        assert!(matches!(
+            ai.get_property(PropertyIdentifier::Units),
+            Ok(PropertyValue::Enumerated(_))
+        ));
+
+        // Optional properties are only present once configured
+        assert!(!ai
+            .property_list()
+            .contains(&PropertyIdentifier::CovIncrement));
+        assert!(matches!(
+            ai.get_property(PropertyIdentifier::CovIncrement),
+            Err(ObjectError::UnknownProperty)
+        ));
+        ai.set_property(PropertyIdentifier::CovIncrement, PropertyValue::Real(0.5))
+            .unwrap();
+        assert!(ai
+            .property_list()
+            .contains(&PropertyIdentifier::CovIncrement));
+        assert!(ai
+            .set_property(PropertyIdentifier::CovIncrement, PropertyValue::Real(-1.0))
+            .is_err());
+
+        // Every listed property can be read
+        for property in ai.property_list() {
+            assert!(ai.get_property(property).is_ok(), "{:?}", property);
+        }
+
+        // Read-only properties and unsupported ones are distinguished
+        assert!(matches!(
+            ai.set_property(PropertyIdentifier::StatusFlags, PropertyValue::Null),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+        assert!(matches!(
+            ai.set_property(PropertyIdentifier::PriorityArray, PropertyValue::Null),
+            Err(ObjectError::UnknownProperty)
+        ));
+    }
+
+    #[test]
+    fn test_input_present_value_write_access() {
+        let mut ai = AnalogInput::new(1, "Outside Air".to_string());
+
+        assert!(!ai.is_property_writable(PropertyIdentifier::PresentValue));
+        assert!(matches!(
+            ai.write_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Real(5.0),
+                None
+            ),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+        assert!(matches!(
+            ai.set_property_remote(PropertyIdentifier::ObjectType, PropertyValue::Enumerated(0)),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+
+        // Out of service decouples Present_Value from the sensor
+        ai.set_property(
+            PropertyIdentifier::OutOfService,
+            PropertyValue::Boolean(true),
+        )
+        .unwrap();
+        assert!(ai.is_property_writable(PropertyIdentifier::PresentValue));
+        ai.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Real(5.0),
+            None,
+        )
+        .unwrap();
+        assert_eq!(ai.present_value, 5.0);
+    }
+
+    #[test]
+    fn test_cov_increment_reporting() {
+        use std::sync::{Arc, Mutex};
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let mut ai = AnalogInput::new(1, "Supply Temp".to_string());
+        ai.cov_increment = Some(1.0);
+        ai.callbacks
+            .register(PropertyIdentifier::PresentValue, move |change| {
+                if let PropertyValue::Real(value) = change.new_value {
+                    sink.lock().unwrap().push(value);
+                }
+            });
+
+        // Changes below the increment accumulate until they reach it
+        ai.set_present_value(0.5);
+        ai.set_present_value(0.9);
+        ai.set_present_value(1.0);
+        ai.set_present_value(1.5);
+        assert_eq!(*reports.lock().unwrap(), vec![1.0]);
+
+        // A status flag change is reported regardless of the value
+        ai.set_reliability(Reliability::OverRange);
+        assert_eq!(*reports.lock().unwrap(), vec![1.0, 1.5]);
+
+        // Without an increment every change is reported
+        ai.cov_increment = None;
+        ai.set_present_value(1.6);
+        ai.set_present_value(1.6);
+        assert_eq!(*reports.lock().unwrap(), vec![1.0, 1.5, 1.6]);
+    }
+
+    #[test]
+    fn test_null_write_relinquishes_priority() {
+        let mut ao = AnalogOutput::new(1, "Damper".to_string());
+        ao.relinquish_default = 10.0;
+
+        ao.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Real(40.0),
+            Some(8),
+        )
+        .unwrap();
+        ao.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Real(80.0),
+            Some(3),
+        )
+        .unwrap();
+        assert_eq!(ao.present_value, 80.0);
+
+        // Relinquishing the active priority falls back to the next one
+        ao.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Null,
+            Some(3),
+        )
+        .unwrap();
+        assert_eq!(ao.present_value, 40.0);
+        assert_eq!(ao.get_effective_priority(), Some(8));
+
+        ao.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Null,
+            Some(8),
+        )
+        .unwrap();
+        assert_eq!(ao.present_value, 10.0);
+        assert_eq!(ao.get_effective_priority(), None);
+    }
+
+    #[test]
+    fn test_double_present_value_writes() {
+        let mut av = AnalogValue::new(1, "Setpoint".to_string());
+        av.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Double(21.5),
+            Some(10),
+        )
+        .unwrap();
+        assert_eq!(av.present_value, 21.5);
+
+        let mut ai = AnalogInput::new(1, "Zone Temp".to_string());
+        ai.set_property_remote(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Double(19.25),
+        )
+        .unwrap();
+        assert_eq!(ai.present_value, 19.25);
+
+        // Doubles a Real cannot hold exactly are refused, not rounded
+        for value in [1e40, 0.1, f64::NAN] {
+            assert!(matches!(
+                av.write_property(
+                    PropertyIdentifier::PresentValue,
+                    PropertyValue::Double(value),
+                    Some(10),
+                ),
+                Err(ObjectError::InvalidValue(_))
+            ));
+        }
+        assert_eq!(av.present_value, 21.5);
+        let mut ao = AnalogOutput::new(1, "Damper".to_string());
+        assert!(matches!(
+            ao.write_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Double(1e40),
+                Some(8),
+            ),
+            Err(ObjectError::InvalidValue(_))
+        ));
+        assert_eq!(ao.get_effective_priority(), None);
+    }
+
+    #[test]
+    fn test_analog_input_builder() {
+        let ai = AnalogInput::builder(3)
+            .name("Zone Temp")
+            .description("Room 101")
+            .units(EngineeringUnits::DegreesCelsius)
+            .present_value(21.0)
+            .cov_increment(0.5)
+            .high_limit(30.0)
+            .low_limit(15.0)
+            .deadband(1.0)
+            .build()
+            .unwrap();
+        assert_eq!(
+            ai.identifier,
+            ObjectIdentifier::new(ObjectType::AnalogInput, 3)
+        );
+        assert_eq!(ai.object_name, "Zone Temp");
+        assert_eq!(ai.units, EngineeringUnits::DegreesCelsius);
+        assert_eq!(ai.cov_increment, Some(0.5));
+        assert_eq!(ai.limit_enable, LimitEnable::both());
+        assert_eq!((ai.low_limit, ai.high_limit), (15.0, 30.0));
+        assert_eq!(ai.present_value, 21.0);
+
+        let invalid = [
+            AnalogInput::builder(1),
+            AnalogInput::builder(ObjectIdentifier::MAX_INSTANCE + 1).name("Temp"),
+            // A limit that is set but disabled would never alarm
+            AnalogInput::builder(1)
+                .name("Temp")
+                .high_limit(30.0)
+                .limit_enable(LimitEnable::default()),
+            AnalogInput::builder(1)
+                .name("Temp")
+                .limit_enable(LimitEnable::both()),
+            AnalogInput::builder(1)
+                .name("Temp")
+                .high_limit(10.0)
+                .low_limit(20.0),
+            AnalogInput::builder(1).name("Temp").range(50.0, 0.0),
+            AnalogInput::builder(1).name("Temp").deadband(-1.0),
+            AnalogInput::builder(1).name("Temp").deadband(f32::NAN),
+            AnalogInput::builder(1).name("Temp").high_limit(f32::NAN),
+            AnalogInput::builder(1).name("Temp").low_limit(f32::NAN),
+            AnalogInput::builder(1)
+                .name("Temp")
+                .range(0.0, 50.0)
+                .present_value(60.0),
+        ];
+        for builder in invalid {
+            assert!(builder.build().is_err());
+        }
+    }
+
+    #[test]
+    fn test_present_value_limits() {
+        let mut ao = AnalogOutput::new(1, "Valve".to_string());
+        ao.min_pres_value = Some(0.0);
+        ao.max_pres_value = Some(100.0);
+
+        // Out of range commands are clamped by default
+        ao.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Real(120.0),
+            Some(8),
+        )
+        .unwrap();
+        assert_eq!(ao.priority_array.get(8), Some(100.0));
+        assert_eq!(ao.present_value, 100.0);
+        ao.set_property(PropertyIdentifier::PresentValue, PropertyValue::Real(-5.0))
+            .unwrap();
+        assert_eq!(ao.priority_array.get(16), Some(0.0));
+        assert_eq!(ao.status_flags, StatusFlags::default());
+        assert_eq!(ao.reliability, Reliability::NoFaultDetected);
+
+        let mut av = AnalogValue::new(1, "Setpoint".to_string());
+        av.min_pres_value = Some(15.0);
+        av.max_pres_value = Some(30.0);
+        av.pres_value_limit = PresentValueLimit::Reject;
+        assert!(matches!(
+            av.get_property(PropertyIdentifier::MaxPresValue),
+            Ok(PropertyValue::Real(max)) if max == 30.0
+        ));
+        assert!(av
+            .property_list()
+            .contains(&PropertyIdentifier::MinPresValue));
+
+        // Rejected commands leave the priority array untouched
+        assert!(matches!(
+            av.set_property_remote(PropertyIdentifier::PresentValue, PropertyValue::Real(35.0)),
+            Err(ObjectError::InvalidValue(_))
+        ));
+        assert_eq!(av.priority_array.get(16), None);
+        av.set_property_remote(PropertyIdentifier::PresentValue, PropertyValue::Real(21.0))
+            .unwrap();
+        assert_eq!(av.present_value, 21.0);
+        assert_eq!(av.status_flags, StatusFlags::default());
+
+        // NaN is out of range in both modes
+        assert!(av
+            .set_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Real(f32::NAN)
+            )
+            .is_err());
+        assert!(ao
+            .set_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Real(f32::NAN)
+            )
+            .is_err());
+        assert_eq!(ao.priority_array.get(16), Some(0.0));
+
+        // The limits are writable but must stay ordered
+        assert!(av.is_property_writable(PropertyIdentifier::MaxPresValue));
+        av.set_property(PropertyIdentifier::MaxPresValue, PropertyValue::Real(25.0))
+            .unwrap();
+        assert_eq!(av.max_pres_value, Some(25.0));
+        assert!(av
+            .set_property(PropertyIdentifier::MinPresValue, PropertyValue::Real(26.0))
+            .is_err());
+        assert!(ao
+            .set_property(
+                PropertyIdentifier::MaxPresValue,
+                PropertyValue::Real(f32::NAN)
+            )
+            .is_err());
+        assert_eq!(av.min_pres_value, Some(15.0));
+
+        // Limits set out of order through the fields refuse every command
+        ao.min_pres_value = Some(100.0);
+        ao.max_pres_value = Some(0.0);
+        assert!(matches!(
+            ao.set_property(PropertyIdentifier::PresentValue, PropertyValue::Real(50.0)),
+            Err(ObjectError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn test_limit_alarm_does_not_chatter_within_deadband() {
+        let mut ai = AnalogInput::new(1, "Duct Pressure".to_string());
+        ai.high_limit = 100.0;
+        ai.low_limit = 0.0;
+        ai.deadband = 5.0;
+        ai.limit_enable = LimitEnable::both();
+
+        let mut transitions = Vec::new();
+        let mut t = 0;
+        let mut sample = |ai: &mut AnalogInput, value| {
+            ai.set_present_value(value);
+            t += 1;
+            if let Some(state) = ai.update_event_state(Duration::from_secs(t)) {
+                transitions.push(state);
+            }
+        };
+
+        // Hovering just around the high limit raises the alarm once
+        for value in [99.0, 100.5, 99.5, 100.2, 96.0, 101.0, 95.0, 100.0] {
+            sample(&mut ai, value);
+        }
+        assert_eq!(ai.event_state, EventState::HighLimit);
+
+        // Only dropping past the limit by more than the deadband clears it
+        sample(&mut ai, 94.9);
+        sample(&mut ai, 99.9);
+        sample(&mut ai, 100.1);
+        assert_eq!(
+            transitions,
+            [
+                EventState::HighLimit,
+                EventState::Normal,
+                EventState::HighLimit
+            ]
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_with_disabled_limit() {
+        let high = EventState::Normal.out_of_range(120.0, Some(0.0), None, 5.0);
+        assert_eq!(high, EventState::Normal);
+        let low = EventState::LowLimit.out_of_range(4.0, Some(0.0), Some(100.0), 5.0);
+        assert_eq!(low, EventState::LowLimit);
+        let cleared = EventState::LowLimit.out_of_range(5.5, Some(0.0), Some(100.0), 5.0);
+        assert_eq!(cleared, EventState::Normal);
+        // A jump straight across both limits changes the alarm directly
+        let crossed = EventState::HighLimit.out_of_range(-1.0, Some(0.0), Some(100.0), 5.0);
+        assert_eq!(crossed, EventState::LowLimit);
+    }
+
+    #[test]
+    fn test_limit_alarm_delays_with_manual_clock() {
+        use crate::datatypes::ManualClock;
+        use crate::object::{Date, Time};
+        use crate::service::BacnetDateTime;
+
+        let clock = ManualClock::new(BacnetDateTime::new(
+            Date {
+                year: 2024,
+                month: 6,
+                day: 30,
+                weekday: 7,
+            },
+            Time {
+                hour: 23,
+                minute: 59,
+                second: 50,
+                hundredths: 0,
+            },
+        ));
+        let mut ai = AnalogInput::builder(1)
+            .name("Supply Air")
+            .high_limit(30.0)
+            .time_delay(10)
+            .time_delay_normal(60)
+            .build()
+            .unwrap();
+        assert!(ai
+            .property_list()
+            .contains(&PropertyIdentifier::TimeDelayNormal));
+        let secs = Duration::from_secs;
+
+        // A spike shorter than the time delay raises nothing
+        ai.set_present_value(35.0);
+        assert_eq!(ai.update_event_state_from(&clock), None);
+        clock.advance(secs(9));
+        assert_eq!(ai.update_event_state_from(&clock), None);
+        ai.set_present_value(25.0);
+        assert_eq!(ai.update_event_state_from(&clock), None);
+        clock.advance(secs(5));
+        ai.set_present_value(35.0);
+        assert_eq!(ai.update_event_state_from(&clock), None);
+        assert_eq!(ai.event_state, EventState::Normal);
+
+        // A condition held for the delay raises the alarm, across midnight
+        clock.advance(secs(10));
+        assert_eq!(
+            ai.update_event_state_from(&clock),
+            Some(EventState::HighLimit)
+        );
+
+        // The return to normal waits out Time_Delay_Normal instead
+        ai.set_present_value(20.0);
+        assert_eq!(ai.update_event_state_from(&clock), None);
+        clock.advance(secs(30));
+        assert_eq!(ai.update_event_state_from(&clock), None);
+        clock.advance(secs(30));
+        assert_eq!(ai.update_event_state_from(&clock), Some(EventState::Normal));
+
+        ai.set_property(
+            PropertyIdentifier::TimeDelayNormal,
+            PropertyValue::UnsignedInteger(0),
+        )
+        .unwrap();
+        assert_eq!(ai.time_delay_normal, Some(0));
     }
 }
@@ -4,15 +4,22 @@
 //! as defined in ASHRAE 135. These objects represent binary (two-state) values in BACnet.
 
 use crate::object::{
-    BacnetObject, EventState, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier,
-    PropertyValue, Reliability, Result,
+    callback::PropertyCallbacks, priority::PriorityArray, proprietary::ProprietaryProperties,
+    status_flags::StatusFlags, validate_description, write_rejected, BacnetObject, EventState,
+    ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue, Reliability,
+    Result,
 };
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 /// Binary values enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum BinaryPV {
     Inactive = 0,
@@ -35,16 +42,61 @@ impl From<BinaryPV> for bool {
     }
 }
 
+impl TryFrom<u32> for BinaryPV {
+    type Error = ObjectError;
+
+    fn try_from(value: u32) -> Result<Self> {
+        match value {
+            0 => Ok(BinaryPV::Inactive),
+            1 => Ok(BinaryPV::Active),
+            _ => Err(ObjectError::InvalidValue(
+                "Binary value must be 0 or 1".to_string(),
+            )),
+        }
+    }
+}
+
+impl BinaryPV {
+    /// Map between physical state and Present_Value for the given polarity
+    ///
+    /// Reverse polarity inverts the value; the mapping is its own inverse.
+    pub fn with_polarity(self, polarity: Polarity) -> Self {
+        match polarity {
+            Polarity::Normal => self,
+            Polarity::Reverse => match self {
+                BinaryPV::Inactive => BinaryPV::Active,
+                BinaryPV::Active => BinaryPV::Inactive,
+            },
+        }
+    }
+}
+
 /// Polarity enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum Polarity {
     Normal = 0,
     Reverse = 1,
 }
 
+impl TryFrom<u32> for Polarity {
+    type Error = ObjectError;
+
+    fn try_from(value: u32) -> Result<Self> {
+        match value {
+            0 => Ok(Polarity::Normal),
+            1 => Ok(Polarity::Reverse),
+            _ => Err(ObjectError::InvalidValue(
+                "Polarity must be 0 or 1".to_string(),
+            )),
+        }
+    }
+}
+
 /// Binary Input object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BinaryInput {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -56,8 +108,8 @@ pub struct BinaryInput {
     pub description: String,
     /// Device type
     pub device_type: String,
-    /// Status flags (4 bits: in_alarm, fault, overridden, out_of_service)
-    pub status_flags: u8,
+    /// Status flags
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -76,10 +128,16 @@ pub struct BinaryInput {
     pub change_of_state_count: u32,
     /// Time of state count reset
     pub time_of_state_count_reset: Option<crate::object::Time>,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 /// Binary Output object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BinaryOutput {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -92,7 +150,7 @@ pub struct BinaryOutput {
     /// Device type
     pub device_type: String,
     /// Status flags
-    pub status_flags: u8,
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -106,17 +164,23 @@ pub struct BinaryOutput {
     /// Active text
     pub active_text: String,
     /// Priority array (16 levels)
-    pub priority_array: [Option<BinaryPV>; 16],
+    pub priority_array: PriorityArray<BinaryPV>,
     /// Relinquish default
     pub relinquish_default: BinaryPV,
     /// Minimum off time
     pub minimum_off_time: u32,
     /// Minimum on time
     pub minimum_on_time: u32,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 /// Binary Value object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BinaryValue {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -127,7 +191,7 @@ pub struct BinaryValue {
     /// Description
     pub description: String,
     /// Status flags
-    pub status_flags: u8,
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -139,9 +203,14 @@ pub struct BinaryValue {
     /// Active text
     pub active_text: String,
     /// Priority array (16 levels)
-    pub priority_array: [Option<BinaryPV>; 16],
+    pub priority_array: PriorityArray<BinaryPV>,
     /// Relinquish default
     pub relinquish_default: BinaryPV,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 impl BinaryInput {
@@ -153,7 +222,7 @@ impl BinaryInput {
             present_value: BinaryPV::Inactive,
             description: String::new(),
             device_type: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
@@ -163,6 +232,8 @@ impl BinaryInput {
             change_of_state_time: None,
             change_of_state_count: 0,
             time_of_state_count_reset: None,
+            callbacks: PropertyCallbacks::new(),
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
@@ -175,13 +246,18 @@ impl BinaryInput {
         }
     }
 
+    /// Update the present value from the physical input state, applying polarity
+    pub fn set_physical_state(&mut self, active: bool) {
+        self.set_present_value(BinaryPV::from(active).with_polarity(self.polarity));
+    }
+
     /// Get status flags as individual booleans
     pub fn get_status_flags(&self) -> (bool, bool, bool, bool) {
         (
-            (self.status_flags & 0x08) != 0, // in_alarm
-            (self.status_flags & 0x04) != 0, // fault
-            (self.status_flags & 0x02) != 0, // overridden
-            (self.status_flags & 0x01) != 0, // out_of_service
+            self.status_flags.in_alarm,
+            self.status_flags.fault,
+            self.status_flags.overridden,
+            self.status_flags.out_of_service,
         )
     }
 
@@ -193,19 +269,41 @@ impl BinaryInput {
         overridden: bool,
         out_of_service: bool,
     ) {
-        self.status_flags = 0;
-        if in_alarm {
-            self.status_flags |= 0x08;
-        }
-        if fault {
-            self.status_flags |= 0x04;
-        }
-        if overridden {
-            self.status_flags |= 0x02;
-        }
-        if out_of_service {
-            self.status_flags |= 0x01;
-        }
+        self.replace_status_flags(StatusFlags::new(
+            in_alarm,
+            fault,
+            overridden,
+            out_of_service,
+        ));
+    }
+
+    /// Set the reliability, updating the fault status flag
+    pub fn set_reliability(&mut self, reliability: Reliability) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::Reliability,
+            &mut self.reliability,
+            reliability,
+        );
+        self.update_status_flags();
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        let flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+        self.replace_status_flags(flags);
+    }
+
+    /// Store new status flags, notifying callbacks if any bit changed
+    fn replace_status_flags(&mut self, flags: StatusFlags) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::StatusFlags,
+            &mut self.status_flags,
+            flags,
+        );
     }
 }
 
@@ -218,51 +316,72 @@ impl BinaryOutput {
             present_value: BinaryPV::Inactive,
             description: String::new(),
             device_type: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
             polarity: Polarity::Normal,
             inactive_text: "INACTIVE".to_string(),
             active_text: "ACTIVE".to_string(),
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: BinaryPV::Inactive,
             minimum_off_time: 0,
             minimum_on_time: 0,
+            callbacks: PropertyCallbacks::new(),
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<BinaryPV>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        // Highest priority non-null value, or relinquish default if all are null
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 
     /// Get the effective priority level for current present value
     pub fn get_effective_priority(&self) -> Option<u8> {
-        for (i, priority_value) in self.priority_array.iter().enumerate() {
-            if priority_value.is_some() {
-                return Some((i + 1) as u8);
-            }
-        }
-        None
+        self.priority_array.effective_priority()
+    }
+
+    /// Physical output state for the current present value, applying polarity
+    pub fn physical_state(&self) -> bool {
+        self.present_value.with_polarity(self.polarity).into()
+    }
+
+    /// Set the reliability, updating the fault status flag
+    pub fn set_reliability(&mut self, reliability: Reliability) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::Reliability,
+            &mut self.reliability,
+            reliability,
+        );
+        self.update_status_flags();
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        let flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+        self.replace_status_flags(flags);
+    }
+
+    /// Store new status flags, notifying callbacks if any bit changed
+    fn replace_status_flags(&mut self, flags: StatusFlags) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::StatusFlags,
+            &mut self.status_flags,
+            flags,
+        );
     }
 }
 
@@ -274,38 +393,59 @@ impl BinaryValue {
             object_name,
             present_value: BinaryPV::Inactive,
             description: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
             inactive_text: "INACTIVE".to_string(),
             active_text: "ACTIVE".to_string(),
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: BinaryPV::Inactive,
+            callbacks: PropertyCallbacks::new(),
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<BinaryPV>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        // Highest priority non-null value, or relinquish default if all are null
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
+    }
+
+    /// Set the reliability, updating the fault status flag
+    pub fn set_reliability(&mut self, reliability: Reliability) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::Reliability,
+            &mut self.reliability,
+            reliability,
+        );
+        self.update_status_flags();
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        let flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+        self.replace_status_flags(flags);
+    }
+
+    /// Store new status flags, notifying callbacks if any bit changed
+    fn replace_status_flags(&mut self, flags: StatusFlags) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::StatusFlags,
+            &mut self.status_flags,
+            flags,
+        );
     }
 }
 
@@ -322,14 +462,37 @@ impl BacnetObject for BinaryInput {
             PropertyIdentifier::ObjectName => {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
-            PropertyIdentifier::ObjectType => {
-                Ok(PropertyValue::Enumerated(ObjectType::BinaryInput as u32))
-            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::BinaryInput.as_u16() as u32,
+            )),
             PropertyIdentifier::PresentValue => {
                 Ok(PropertyValue::Enumerated(self.present_value as u32))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::ActiveText => {
+                Ok(PropertyValue::CharacterString(self.active_text.clone()))
+            }
+            PropertyIdentifier::InactiveText => {
+                Ok(PropertyValue::CharacterString(self.inactive_text.clone()))
+            }
+            PropertyIdentifier::Polarity => Ok(PropertyValue::Enumerated(self.polarity as u32)),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::DeviceType => {
+                Ok(PropertyValue::CharacterString(self.device_type.clone()))
+            }
+            PropertyIdentifier::ChangeOfStateCount => {
+                Ok(PropertyValue::UnsignedInteger(self.change_of_state_count))
+            }
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -343,33 +506,116 @@ impl BacnetObject for BinaryInput {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
+            // Present_Value of an input is writable only while out of service
+            PropertyIdentifier::PresentValue if self.out_of_service => {
+                if let PropertyValue::Enumerated(val) = value {
+                    self.set_present_value(BinaryPV::try_from(val)?);
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
             PropertyIdentifier::OutOfService => {
                 if let PropertyValue::Boolean(oos) = value {
                     self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::ActiveText => {
+                if let PropertyValue::CharacterString(text) = value {
+                    self.active_text = text;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::InactiveText => {
+                if let PropertyValue::CharacterString(text) = value {
+                    self.inactive_text = text;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Polarity => {
+                if let PropertyValue::Enumerated(val) = value {
+                    self.polarity = Polarity::try_from(val)?;
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::PresentValue, PropertyValue::Enumerated(val)) => {
+                let binary_val = BinaryPV::try_from(val)?;
+                self.set_present_value(binary_val);
+                Ok(())
+            }
+            (PropertyIdentifier::PresentValue, _) => Err(ObjectError::InvalidPropertyType),
+            (PropertyIdentifier::Reliability, PropertyValue::Enumerated(val)) => {
+                self.set_reliability(Reliability::try_from(val)?);
+                Ok(())
+            }
+            (PropertyIdentifier::Reliability, _) => Err(ObjectError::InvalidPropertyType),
+            (property, value) => self.set_property(property, value),
         }
     }
 
     fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
-        matches!(
-            property,
-            PropertyIdentifier::ObjectName | PropertyIdentifier::OutOfService
-        )
+        (property == PropertyIdentifier::PresentValue && self.out_of_service)
+            || matches!(
+                property,
+                PropertyIdentifier::ObjectName
+                    | PropertyIdentifier::Description
+                    | PropertyIdentifier::OutOfService
+                    | PropertyIdentifier::ActiveText
+                    | PropertyIdentifier::InactiveText
+                    | PropertyIdentifier::Polarity
+            )
+            || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::DeviceType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
-        ]
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::ActiveText,
+            PropertyIdentifier::InactiveText,
+            PropertyIdentifier::Polarity,
+            PropertyIdentifier::ChangeOfStateCount,
+        ];
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -386,25 +632,47 @@ impl BacnetObject for BinaryOutput {
             PropertyIdentifier::ObjectName => {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
-            PropertyIdentifier::ObjectType => {
-                Ok(PropertyValue::Enumerated(ObjectType::BinaryOutput as u32))
-            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::BinaryOutput.as_u16() as u32,
+            )),
             PropertyIdentifier::PresentValue => {
                 Ok(PropertyValue::Enumerated(self.present_value as u32))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            PropertyIdentifier::PriorityArray => {
-                let array: Vec<PropertyValue> = self
-                    .priority_array
-                    .iter()
-                    .map(|&v| match v {
-                        Some(val) => PropertyValue::Enumerated(val as u32),
-                        None => PropertyValue::Null,
-                    })
-                    .collect();
-                Ok(PropertyValue::Array(array))
-            }
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::PriorityArray => Ok(PropertyValue::Array(
+                self.priority_array
+                    .to_property_values(|val| PropertyValue::Enumerated(val as u32)),
+            )),
+            PropertyIdentifier::ActiveText => {
+                Ok(PropertyValue::CharacterString(self.active_text.clone()))
+            }
+            PropertyIdentifier::InactiveText => {
+                Ok(PropertyValue::CharacterString(self.inactive_text.clone()))
+            }
+            PropertyIdentifier::Polarity => Ok(PropertyValue::Enumerated(self.polarity as u32)),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::DeviceType => {
+                Ok(PropertyValue::CharacterString(self.device_type.clone()))
+            }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Enumerated(self.relinquish_default as u32))
+            }
+            PropertyIdentifier::MinimumOffTime => {
+                Ok(PropertyValue::UnsignedInteger(self.minimum_off_time))
+            }
+            PropertyIdentifier::MinimumOnTime => {
+                Ok(PropertyValue::UnsignedInteger(self.minimum_on_time))
+            }
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -418,32 +686,112 @@ impl BacnetObject for BinaryOutput {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::PresentValue => {
+            PropertyIdentifier::PresentValue => self.write_property(property, value, None),
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::ActiveText => {
+                if let PropertyValue::CharacterString(text) = value {
+                    self.active_text = text;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::InactiveText => {
+                if let PropertyValue::CharacterString(text) = value {
+                    self.inactive_text = text;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Polarity => {
                 if let PropertyValue::Enumerated(val) = value {
-                    let binary_val = match val {
-                        0 => BinaryPV::Inactive,
-                        1 => BinaryPV::Active,
-                        _ => {
-                            return Err(ObjectError::InvalidValue(
-                                "Binary value must be 0 or 1".to_string(),
-                            ))
-                        }
-                    };
-                    // Write to priority 8 (manual operator) by default
-                    self.write_priority(8, Some(binary_val))
+                    self.polarity = Polarity::try_from(val)?;
+                    Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::OutOfService => {
-                if let PropertyValue::Boolean(oos) = value {
-                    self.out_of_service = oos;
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::RelinquishDefault => {
+                if let PropertyValue::Enumerated(val) = value {
+                    self.relinquish_default = BinaryPV::try_from(val)?;
+                    self.update_present_value();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::MinimumOffTime => {
+                if let PropertyValue::UnsignedInteger(seconds) = value {
+                    self.minimum_off_time = seconds;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::MinimumOnTime => {
+                if let PropertyValue::UnsignedInteger(seconds) = value {
+                    self.minimum_on_time = seconds;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn write_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        if property != PropertyIdentifier::PresentValue {
+            return self.set_property(property, value);
+        }
+        // Writes without a priority use the lowest priority (16)
+        let priority = priority.unwrap_or(16);
+        match value {
+            PropertyValue::Enumerated(val) => {
+                self.write_priority(priority, Some(BinaryPV::try_from(val)?))
+            }
+            // NULL relinquishes the command at this priority
+            PropertyValue::Null => self.write_priority(priority, None),
+            _ => Err(ObjectError::InvalidPropertyType),
+        }
+    }
+
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::Reliability, PropertyValue::Enumerated(val)) => {
+                self.set_reliability(Reliability::try_from(val)?);
+                Ok(())
+            }
+            (PropertyIdentifier::Reliability, _) => Err(ObjectError::InvalidPropertyType),
+            (property, value) => self.set_property(property, value),
         }
     }
 
@@ -451,20 +799,40 @@ impl BacnetObject for BinaryOutput {
         matches!(
             property,
             PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::RelinquishDefault
+                | PropertyIdentifier::MinimumOffTime
+                | PropertyIdentifier::MinimumOnTime
                 | PropertyIdentifier::PresentValue
                 | PropertyIdentifier::OutOfService
-        )
+                | PropertyIdentifier::ActiveText
+                | PropertyIdentifier::InactiveText
+                | PropertyIdentifier::Polarity
+        ) || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::DeviceType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Reliability,
             PropertyIdentifier::PriorityArray,
-        ]
+            PropertyIdentifier::ActiveText,
+            PropertyIdentifier::InactiveText,
+            PropertyIdentifier::Polarity,
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::MinimumOffTime,
+            PropertyIdentifier::MinimumOnTime,
+        ];
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -481,25 +849,37 @@ impl BacnetObject for BinaryValue {
             PropertyIdentifier::ObjectName => {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
-            PropertyIdentifier::ObjectType => {
-                Ok(PropertyValue::Enumerated(ObjectType::BinaryValue as u32))
-            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::BinaryValue.as_u16() as u32,
+            )),
             PropertyIdentifier::PresentValue => {
                 Ok(PropertyValue::Enumerated(self.present_value as u32))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            PropertyIdentifier::PriorityArray => {
-                let array: Vec<PropertyValue> = self
-                    .priority_array
-                    .iter()
-                    .map(|&v| match v {
-                        Some(val) => PropertyValue::Enumerated(val as u32),
-                        None => PropertyValue::Null,
-                    })
-                    .collect();
-                Ok(PropertyValue::Array(array))
-            }
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::PriorityArray => Ok(PropertyValue::Array(
+                self.priority_array
+                    .to_property_values(|val| PropertyValue::Enumerated(val as u32)),
+            )),
+            PropertyIdentifier::ActiveText => {
+                Ok(PropertyValue::CharacterString(self.active_text.clone()))
+            }
+            PropertyIdentifier::InactiveText => {
+                Ok(PropertyValue::CharacterString(self.inactive_text.clone()))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Enumerated(self.relinquish_default as u32))
+            }
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -513,32 +893,88 @@ impl BacnetObject for BinaryValue {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::PresentValue => {
-                if let PropertyValue::Enumerated(val) = value {
-                    let binary_val = match val {
-                        0 => BinaryPV::Inactive,
-                        1 => BinaryPV::Active,
-                        _ => {
-                            return Err(ObjectError::InvalidValue(
-                                "Binary value must be 0 or 1".to_string(),
-                            ))
-                        }
-                    };
-                    // Write to priority 8 (manual operator) by default
-                    self.write_priority(8, Some(binary_val))
+            PropertyIdentifier::PresentValue => self.write_property(property, value, None),
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::OutOfService => {
-                if let PropertyValue::Boolean(oos) = value {
-                    self.out_of_service = oos;
+            PropertyIdentifier::ActiveText => {
+                if let PropertyValue::CharacterString(text) = value {
+                    self.active_text = text;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::InactiveText => {
+                if let PropertyValue::CharacterString(text) = value {
+                    self.inactive_text = text;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::RelinquishDefault => {
+                if let PropertyValue::Enumerated(val) = value {
+                    self.relinquish_default = BinaryPV::try_from(val)?;
+                    self.update_present_value();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn write_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        if property != PropertyIdentifier::PresentValue {
+            return self.set_property(property, value);
+        }
+        // Writes without a priority use the lowest priority (16)
+        let priority = priority.unwrap_or(16);
+        match value {
+            PropertyValue::Enumerated(val) => {
+                self.write_priority(priority, Some(BinaryPV::try_from(val)?))
+            }
+            // NULL relinquishes the command at this priority
+            PropertyValue::Null => self.write_priority(priority, None),
+            _ => Err(ObjectError::InvalidPropertyType),
+        }
+    }
+
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::Reliability, PropertyValue::Enumerated(val)) => {
+                self.set_reliability(Reliability::try_from(val)?);
+                Ok(())
+            }
+            (PropertyIdentifier::Reliability, _) => Err(ObjectError::InvalidPropertyType),
+            (property, value) => self.set_property(property, value),
         }
     }
 
@@ -546,20 +982,33 @@ impl BacnetObject for BinaryValue {
         matches!(
             property,
             PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::RelinquishDefault
                 | PropertyIdentifier::PresentValue
                 | PropertyIdentifier::OutOfService
-        )
+                | PropertyIdentifier::ActiveText
+                | PropertyIdentifier::InactiveText
+        ) || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
             PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Reliability,
             PropertyIdentifier::PriorityArray,
-        ]
+            PropertyIdentifier::ActiveText,
+            PropertyIdentifier::InactiveText,
+            PropertyIdentifier::RelinquishDefault,
+        ];
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -619,6 +1068,45 @@ mod tests {
         assert_eq!(bo.get_effective_priority(), Some(8));
     }
 
+    #[test]
+    fn test_binary_polarity() {
+        let mut bi = BinaryInput::new(1, "Filter Alarm".to_string());
+        bi.set_property(PropertyIdentifier::Polarity, PropertyValue::Enumerated(1))
+            .unwrap();
+        assert_eq!(bi.polarity, Polarity::Reverse);
+
+        // Contact closed with reverse polarity reads as inactive
+        bi.set_physical_state(true);
+        assert_eq!(bi.present_value, BinaryPV::Inactive);
+        bi.set_physical_state(false);
+        assert_eq!(bi.present_value, BinaryPV::Active);
+
+        let mut bo = BinaryOutput::new(1, "Pump".to_string());
+        bo.polarity = Polarity::Reverse;
+        bo.write_priority(8, Some(BinaryPV::Active)).unwrap();
+        assert!(!bo.physical_state());
+
+        assert!(bi
+            .set_property(PropertyIdentifier::Polarity, PropertyValue::Enumerated(2))
+            .is_err());
+    }
+
+    #[test]
+    fn test_binary_state_text_properties() {
+        let mut bv = BinaryValue::new(1, "Occupancy".to_string());
+        bv.set_property(
+            PropertyIdentifier::ActiveText,
+            PropertyValue::CharacterString("OCCUPIED".to_string()),
+        )
+        .unwrap();
+
+        match bv.get_property(PropertyIdentifier::ActiveText).unwrap() {
+            PropertyValue::CharacterString(text) => assert_eq!(text, "OCCUPIED"),
+            _ => panic!("Expected CharacterString"),
+        }
+        assert!(bv.get_property(PropertyIdentifier::Polarity).is_err());
+    }
+
     #[test]
     fn test_binary_object_properties() {
         let mut bv = BinaryValue::new(1, "Test Value".to_string());
@@ -646,4 +1134,105 @@ mod tests {
         );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_binary_reliability() {
+        let mut bi = BinaryInput::new(1, "Door".to_string());
+        bi.set_property_remote(
+            PropertyIdentifier::Reliability,
+            PropertyValue::Enumerated(Reliability::NoSensor as u32),
+        )
+        .unwrap();
+        assert!(bi.get_status_flags().1);
+
+        let mut bo = BinaryOutput::new(1, "Fan".to_string());
+        bo.set_reliability(Reliability::NoOutput);
+        assert!(bo.status_flags.fault);
+        assert!(bo
+            .set_property_remote(
+                PropertyIdentifier::Reliability,
+                PropertyValue::Enumerated(99)
+            )
+            .is_err());
+        assert_eq!(bo.reliability, Reliability::NoOutput);
+    }
+
+    #[test]
+    fn test_binary_relinquish_default_property() {
+        let mut bv = BinaryValue::new(1, "Enable".to_string());
+
+        bv.set_property(
+            PropertyIdentifier::RelinquishDefault,
+            PropertyValue::Enumerated(1),
+        )
+        .unwrap();
+        assert_eq!(bv.present_value, BinaryPV::Active);
+        assert!(bv
+            .set_property(
+                PropertyIdentifier::RelinquishDefault,
+                PropertyValue::Enumerated(2)
+            )
+            .is_err());
+
+        for property in bv.property_list() {
+            assert!(bv.get_property(property).is_ok(), "{:?}", property);
+        }
+        assert!(matches!(
+            bv.set_property(PropertyIdentifier::Polarity, PropertyValue::Enumerated(1)),
+            Err(ObjectError::UnknownProperty)
+        ));
+    }
+
+    #[test]
+    fn test_binary_input_out_of_service_write() {
+        let mut bi = BinaryInput::new(1, "Door".to_string());
+
+        assert!(matches!(
+            bi.set_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Enumerated(1)
+            ),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+
+        bi.set_property(
+            PropertyIdentifier::OutOfService,
+            PropertyValue::Boolean(true),
+        )
+        .unwrap();
+        bi.set_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Enumerated(1),
+        )
+        .unwrap();
+        assert_eq!(bi.present_value, BinaryPV::Active);
+    }
+
+    #[test]
+    fn test_binary_null_write_relinquishes_priority() {
+        let mut bo = BinaryOutput::new(1, "Pump".to_string());
+
+        bo.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Enumerated(0),
+            Some(10),
+        )
+        .unwrap();
+        bo.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Enumerated(1),
+            Some(5),
+        )
+        .unwrap();
+        assert_eq!(bo.present_value, BinaryPV::Active);
+
+        bo.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Null,
+            Some(5),
+        )
+        .unwrap();
+        assert_eq!(bo.present_value, BinaryPV::Inactive);
+        assert_eq!(bo.get_effective_priority(), Some(10));
+    }
 }
@@ -0,0 +1,447 @@
+//! Calendar Object Implementation
+//!
+//! This module implements the Calendar object type as defined in ASHRAE 135.
+//! A Calendar holds a list of dates, date ranges and week-n-day patterns; its
+//! present value is true while the current date matches any of them. Schedule
+//! objects reference calendars for their exception schedules.
+
+use crate::datatypes::{days_in_month, BacnetDate, Month, Weekday, UNSPECIFIED};
+use crate::encoding::{
+    self, advanced::context::encode_closing_tag, advanced::context::encode_opening_tag,
+    EncodingError, TagClass,
+};
+use crate::object::{
+    constructed_value, validate_description, write_rejected, BacnetObject, ObjectError,
+    ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue, Result,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+/// Inclusive range of dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateRange {
+    /// First day of the range; an unspecified date leaves the range open
+    pub start_date: BacnetDate,
+    /// Last day of the range; an unspecified date leaves the range open
+    pub end_date: BacnetDate,
+}
+
+impl DateRange {
+    /// Create a new date range
+    pub fn new(start_date: BacnetDate, end_date: BacnetDate) -> Self {
+        Self {
+            start_date,
+            end_date,
+        }
+    }
+
+    /// Check if a specific date falls within the range
+    pub fn contains(&self, date: &BacnetDate) -> bool {
+        let Some(day) = date.ymd() else {
+            return false;
+        };
+        self.start_date.ymd().is_none_or(|start| start <= day)
+            && self.end_date.ymd().is_none_or(|end| day <= end)
+    }
+}
+
+/// Recurring month, week of month and day of week pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeekNDay {
+    /// Month
+    pub month: Month,
+    /// Week of month: 1-5 for days 1-7 through 29-31, 6 for the last 7 days,
+    /// 7-9 for the 7 day blocks before that, 255 for any week
+    pub week_of_month: u8,
+    /// Day of week, or `None` for any day
+    pub day_of_week: Option<Weekday>,
+}
+
+impl WeekNDay {
+    /// Create a new week-n-day pattern
+    pub fn new(month: Month, week_of_month: u8, day_of_week: Option<Weekday>) -> Self {
+        Self {
+            month,
+            week_of_month,
+            day_of_week,
+        }
+    }
+
+    /// Check if a specific date matches the pattern
+    pub fn matches(&self, date: &BacnetDate) -> bool {
+        let Some((year, month, day)) = date.ymd() else {
+            return false;
+        };
+        let week_matches = match self.week_of_month {
+            1..=5 => (day - 1) / 7 + 1 == self.week_of_month,
+            6..=9 => (days_in_month(year, month) - day) / 7 + 6 == self.week_of_month,
+            UNSPECIFIED => true,
+            _ => false,
+        };
+        self.month.matches(month)
+            && week_matches
+            && self
+                .day_of_week
+                .is_none_or(|expected| Some(expected) == Weekday::of_date(year, month, day))
+    }
+
+    /// Encode to the 3 byte wire format
+    pub fn encode(&self) -> [u8; 3] {
+        [
+            self.month.as_u8(),
+            self.week_of_month,
+            self.day_of_week.map(|day| day as u8).unwrap_or(UNSPECIFIED),
+        ]
+    }
+
+    /// Decode from the 3 byte wire format
+    pub fn decode(bytes: [u8; 3]) -> encoding::Result<Self> {
+        if !matches!(bytes[1], 1..=9 | UNSPECIFIED) {
+            return Err(EncodingError::ValueOutOfRange);
+        }
+        Ok(Self {
+            month: Month::from_u8(bytes[0])?,
+            week_of_month: bytes[1],
+            day_of_week: match bytes[2] {
+                UNSPECIFIED => None,
+                day => Some(Weekday::from_u8(day)?),
+            },
+        })
+    }
+}
+
+/// BACnetCalendarEntry choice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CalendarEntry {
+    /// A date, possibly with wildcards (context tag 0)
+    Date(BacnetDate),
+    /// An inclusive date range (context tag 1)
+    DateRange(DateRange),
+    /// A week-n-day pattern (context tag 2)
+    WeekNDay(WeekNDay),
+}
+
+impl CalendarEntry {
+    /// Check if a specific date matches this entry
+    pub fn is_active(&self, date: &BacnetDate) -> bool {
+        match self {
+            CalendarEntry::Date(pattern) => pattern.matches(date),
+            CalendarEntry::DateRange(range) => range.contains(date),
+            CalendarEntry::WeekNDay(pattern) => pattern.matches(date),
+        }
+    }
+
+    /// Encode the context tagged choice
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        match self {
+            CalendarEntry::Date(date) => {
+                encoding::encode_context_tag(buffer, 0, 4)?;
+                buffer.extend_from_slice(&date.encode());
+            }
+            CalendarEntry::DateRange(range) => {
+                encode_opening_tag(buffer, 1)?;
+                range.start_date.encode_application(buffer)?;
+                range.end_date.encode_application(buffer)?;
+                encode_closing_tag(buffer, 1)?;
+            }
+            CalendarEntry::WeekNDay(pattern) => {
+                encoding::encode_context_tag(buffer, 2, 3)?;
+                buffer.extend_from_slice(&pattern.encode());
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode the context tagged choice, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> encoding::Result<(Self, usize)> {
+        let (tag, header) = encoding::decode_tag(data)?;
+        if tag.class != TagClass::Context {
+            return Err(EncodingError::InvalidTag);
+        }
+
+        match (tag.number, tag.is_opening) {
+            (0, false) => {
+                let bytes = content::<4>(data, header, tag.length)?;
+                Ok((CalendarEntry::Date(BacnetDate::decode(bytes)?), header + 4))
+            }
+            (1, true) => {
+                let mut pos = header;
+                let (start_date, consumed) = BacnetDate::decode_application(&data[pos..])?;
+                pos += consumed;
+                let (end_date, consumed) = BacnetDate::decode_application(&data[pos..])?;
+                pos += consumed;
+                let (closing, closing_len) = encoding::decode_tag(&data[pos..])?;
+                if !closing.is_closing || closing.number != 1 {
+                    return Err(EncodingError::InvalidTag);
+                }
+                let range = DateRange::new(start_date, end_date);
+                Ok((CalendarEntry::DateRange(range), pos + closing_len))
+            }
+            (2, false) => {
+                let bytes = content::<3>(data, header, tag.length)?;
+                Ok((
+                    CalendarEntry::WeekNDay(WeekNDay::decode(bytes)?),
+                    header + 3,
+                ))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
+/// Fixed length content octets following a tag header
+fn content<const N: usize>(data: &[u8], header: usize, length: usize) -> encoding::Result<[u8; N]> {
+    if length != N {
+        return Err(EncodingError::InvalidLength);
+    }
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(
+        data.get(header..header + N)
+            .ok_or(EncodingError::UnexpectedEndOfData)?,
+    );
+    Ok(bytes)
+}
+
+/// Calendar object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Calendar {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Description
+    pub description: String,
+    /// Present value: true when the current date matches the date list
+    pub present_value: bool,
+    /// Dates, ranges and patterns making up the calendar
+    pub date_list: Vec<CalendarEntry>,
+}
+
+impl Calendar {
+    /// Create a new Calendar object with an empty date list
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::Calendar, instance),
+            object_name,
+            description: String::new(),
+            present_value: false,
+            date_list: Vec::new(),
+        }
+    }
+
+    /// Add an entry to the date list
+    pub fn add_entry(&mut self, entry: CalendarEntry) {
+        self.date_list.push(entry);
+    }
+
+    /// Check if a date matches any entry in the date list
+    pub fn is_active(&self, date: BacnetDate) -> bool {
+        self.date_list.iter().any(|entry| entry.is_active(&date))
+    }
+
+    /// Recompute the present value for the current date
+    pub fn update_present_value(&mut self, today: BacnetDate) -> bool {
+        self.present_value = self.is_active(today);
+        self.present_value
+    }
+
+    /// Encode the Date_List as a sequence of calendar entries
+    pub fn encode_date_list(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        for entry in &self.date_list {
+            entry.encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Replace the Date_List from a sequence of encoded calendar entries
+    pub fn decode_date_list(&mut self, data: &[u8]) -> encoding::Result<()> {
+        let mut entries = Vec::new();
+        let mut pos = 0;
+        while pos < data.len() {
+            let (entry, consumed) = CalendarEntry::decode(&data[pos..])?;
+            entries.push(entry);
+            pos += consumed;
+        }
+        self.date_list = entries;
+        Ok(())
+    }
+}
+
+impl BacnetObject for Calendar {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::Calendar.as_u16() as u32,
+            )),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::PresentValue => Ok(PropertyValue::Boolean(self.present_value)),
+            PropertyIdentifier::DateList => {
+                constructed_value(|buffer| self.encode_date_list(buffer))
+            }
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match property {
+            PropertyIdentifier::ObjectName => {
+                if let PropertyValue::CharacterString(name) = value {
+                    self.object_name = name;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName | PropertyIdentifier::Description
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::DateList,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8) -> BacnetDate {
+        BacnetDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_calendar_entry_matching() {
+        // Every December 25th, whatever the year and day of week
+        let christmas = CalendarEntry::Date(BacnetDate {
+            year: None,
+            weekday: None,
+            ..date(2024, 12, 25)
+        });
+        assert!(christmas.is_active(&date(2030, 12, 25)));
+        assert!(!christmas.is_active(&date(2030, 12, 24)));
+
+        let shutdown =
+            CalendarEntry::DateRange(DateRange::new(date(2025, 7, 28), date(2025, 8, 8)));
+        assert!(shutdown.is_active(&date(2025, 8, 1)));
+        assert!(shutdown.is_active(&date(2025, 8, 8)));
+        assert!(!shutdown.is_active(&date(2025, 8, 9)));
+
+        // Last Monday of May
+        let memorial_day =
+            CalendarEntry::WeekNDay(WeekNDay::new(Month::Specific(5), 6, Some(Weekday::Monday)));
+        assert!(memorial_day.is_active(&date(2025, 5, 26)));
+        assert!(!memorial_day.is_active(&date(2025, 5, 19)));
+
+        // First Monday of September
+        let labor_day =
+            CalendarEntry::WeekNDay(WeekNDay::new(Month::Specific(9), 1, Some(Weekday::Monday)));
+        assert!(labor_day.is_active(&date(2025, 9, 1)));
+    }
+
+    #[test]
+    fn test_calendar_entry_encoding() {
+        let entries = [
+            CalendarEntry::Date(date(2025, 1, 1)),
+            CalendarEntry::DateRange(DateRange::new(date(2025, 7, 1), BacnetDate::any())),
+            CalendarEntry::WeekNDay(WeekNDay::new(
+                Month::Any,
+                UNSPECIFIED,
+                Some(Weekday::Sunday),
+            )),
+        ];
+
+        let mut buffer = Vec::new();
+        entries[0].encode(&mut buffer).unwrap();
+        assert_eq!(buffer, [0x0C, 125, 1, 1, 3]);
+
+        buffer.clear();
+        entries[2].encode(&mut buffer).unwrap();
+        assert_eq!(buffer, [0x2B, 255, 255, 7]);
+
+        let mut calendar = Calendar::new(1, "Holidays".to_string());
+        calendar.date_list = entries.to_vec();
+        buffer.clear();
+        calendar.encode_date_list(&mut buffer).unwrap();
+        assert_eq!(buffer[5], 0x1E);
+
+        let mut decoded = Calendar::new(2, "Copy".to_string());
+        decoded.decode_date_list(&buffer).unwrap();
+        assert_eq!(decoded.date_list, entries);
+    }
+
+    #[test]
+    fn test_calendar_present_value() {
+        let mut calendar = Calendar::new(1, "Holidays".to_string());
+        calendar.add_entry(CalendarEntry::Date(date(2025, 12, 25)));
+
+        assert!(calendar.update_present_value(date(2025, 12, 25)));
+        assert!(matches!(
+            calendar
+                .get_property(PropertyIdentifier::PresentValue)
+                .unwrap(),
+            PropertyValue::Boolean(true)
+        ));
+        assert!(!calendar.update_present_value(date(2025, 12, 26)));
+        assert!(!calendar.present_value);
+    }
+
+    #[test]
+    fn test_calendar_listed_properties_are_readable() {
+        let mut calendar = Calendar::new(1, "Holidays".to_string());
+        calendar.add_entry(CalendarEntry::Date(date(2025, 12, 25)));
+        for property in calendar.property_list() {
+            assert!(
+                calendar.get_property(property).is_ok(),
+                "{:?} is listed but not readable",
+                property
+            );
+        }
+
+        let mut expected = Vec::new();
+        calendar.encode_date_list(&mut expected).unwrap();
+        match calendar.get_property(PropertyIdentifier::DateList).unwrap() {
+            PropertyValue::Constructed(data) => assert_eq!(data, expected),
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+    }
+}
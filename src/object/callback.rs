@@ -0,0 +1,182 @@
+//! Property Change Callbacks
+//!
+//! Objects hold a set of callbacks keyed by property identifier and invoke them
+//! when that property changes value, so an application can forward faults and
+//! alarms without polling every object.
+
+use crate::object::{ObjectIdentifier, PropertyIdentifier, PropertyValue};
+use core::fmt;
+
+#[cfg(not(feature = "std"))]
+use alloc::{sync::Arc, vec::Vec};
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+/// A property value change reported to callbacks
+#[derive(Debug, Clone)]
+pub struct PropertyChange {
+    /// Object whose property changed
+    pub object_identifier: ObjectIdentifier,
+    /// Property that changed
+    pub property: PropertyIdentifier,
+    /// Value before the change
+    pub old_value: PropertyValue,
+    /// Value after the change
+    pub new_value: PropertyValue,
+}
+
+/// Callback invoked with a property change
+pub type PropertyCallback = Arc<dyn Fn(&PropertyChange) + Send + Sync>;
+
+/// Callbacks registered on an object, keyed by property
+#[derive(Clone, Default)]
+pub struct PropertyCallbacks {
+    callbacks: Vec<(PropertyIdentifier, PropertyCallback)>,
+}
+
+impl PropertyCallbacks {
+    /// Create an empty callback set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a callback for changes to a property
+    pub fn register<F>(&mut self, property: PropertyIdentifier, callback: F)
+    where
+        F: Fn(&PropertyChange) + Send + Sync + 'static,
+    {
+        self.callbacks.push((property, Arc::new(callback)));
+    }
+
+    /// Remove all callbacks registered for a property
+    pub fn clear(&mut self, property: PropertyIdentifier) {
+        self.callbacks
+            .retain(|(registered, _)| *registered != property);
+    }
+
+    /// Check if any callback is registered for a property
+    pub fn is_registered(&self, property: PropertyIdentifier) -> bool {
+        self.callbacks
+            .iter()
+            .any(|(registered, _)| *registered == property)
+    }
+
+    /// Store a new property value, invoking the callbacks if it changed
+    ///
+    /// Returns whether the value changed.
+    pub fn replace<T>(
+        &self,
+        object_identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+        slot: &mut T,
+        value: T,
+    ) -> bool
+    where
+        T: Copy + PartialEq + Into<PropertyValue>,
+    {
+        let old = core::mem::replace(slot, value);
+        if old == value {
+            return false;
+        }
+        self.notify(object_identifier, property, old.into(), value.into());
+        true
+    }
+
+    /// Invoke the callbacks registered for the changed property
+    pub fn notify(
+        &self,
+        object_identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+        old_value: PropertyValue,
+        new_value: PropertyValue,
+    ) {
+        if !self.is_registered(property) {
+            return;
+        }
+        let change = PropertyChange {
+            object_identifier,
+            property,
+            old_value,
+            new_value,
+        };
+        for (registered, callback) in &self.callbacks {
+            if *registered == property {
+                callback(&change);
+            }
+        }
+    }
+}
+
+impl fmt::Debug for PropertyCallbacks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.callbacks.iter().map(|(property, _)| property))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{ObjectType, Reliability};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_callbacks_filter_by_property() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = calls.clone();
+        let mut callbacks = PropertyCallbacks::new();
+        callbacks.register(PropertyIdentifier::Reliability, move |change| {
+            assert!(matches!(change.new_value, PropertyValue::Enumerated(2)));
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let object = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        callbacks.notify(
+            object,
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Real(0.0),
+            PropertyValue::Real(1.0),
+        );
+        callbacks.notify(
+            object,
+            PropertyIdentifier::Reliability,
+            PropertyValue::Enumerated(0),
+            PropertyValue::Enumerated(2),
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        callbacks.clear(PropertyIdentifier::Reliability);
+        assert!(!callbacks.is_registered(PropertyIdentifier::Reliability));
+    }
+
+    #[test]
+    fn test_replace_notifies_only_on_change() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = calls.clone();
+        let mut callbacks = PropertyCallbacks::new();
+        callbacks.register(PropertyIdentifier::Reliability, move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let object = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let mut reliability = Reliability::NoFaultDetected;
+        for (value, changed) in [
+            (Reliability::OverRange, true),
+            (Reliability::OverRange, false),
+            (Reliability::NoFaultDetected, true),
+        ] {
+            assert_eq!(
+                callbacks.replace(
+                    object,
+                    PropertyIdentifier::Reliability,
+                    &mut reliability,
+                    value
+                ),
+                changed
+            );
+        }
+        assert_eq!(reliability, Reliability::NoFaultDetected);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -2,11 +2,14 @@
 //!
 //! This module provides a database for storing and managing BACnet objects locally.
 //! It supports CRUD operations, property access, and efficient object lookup.
+//! Objects are held behind interior locks, so a server thread and application
+//! threads can share one database through an `Arc`.
 
 #[cfg(feature = "std")]
 use std::{
     collections::HashMap,
-    sync::{Arc, RwLock},
+    ops::Deref,
+    sync::{Arc, RwLock, RwLockReadGuard},
     time::Instant,
 };
 
@@ -14,8 +17,8 @@ use std::{
 use alloc::{boxed::Box, collections::BTreeMap as HashMap, string::String, sync::Arc, vec::Vec};
 
 use super::{
-    BacnetObject, Device, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier,
-    PropertyValue, Result,
+    array_element, validate_object_name, BacnetObject, Device, ObjectError, ObjectIdentifier,
+    ObjectType, PropertyIdentifier, PropertyValue, Result,
 };
 
 /// Object database for managing BACnet objects
@@ -84,6 +87,7 @@ impl ObjectDatabase {
             PropertyValue::CharacterString(name) => name,
             _ => return Err(ObjectError::InvalidPropertyType),
         };
+        validate_object_name(&object_name)?;
 
         // Update all indices and storage atomically
         {
@@ -91,6 +95,11 @@ impl ObjectDatabase {
             let mut type_index = self.type_index.write().unwrap();
             let mut name_index = self.name_index.write().unwrap();
 
+            // Object_Name must be unique within the device (case-sensitive)
+            if name_index.contains_key(&object_name) {
+                return Err(ObjectError::DuplicateObjectName(object_name));
+            }
+
             // Add to type index
             type_index
                 .entry(identifier.object_type)
@@ -154,36 +163,216 @@ impl ObjectDatabase {
     }
 
     /// Get a property value from an object
+    ///
+    /// The device's Object_List is generated from the objects held.
     pub fn get_property(
         &self,
         identifier: ObjectIdentifier,
         property: PropertyIdentifier,
     ) -> Result<PropertyValue> {
-        let objects = self.objects.read().unwrap();
-        match objects.get(&identifier) {
+        let view = self.read();
+        if identifier == self.device_id && property == PropertyIdentifier::ObjectList {
+            return Ok(PropertyValue::Array(
+                view.objects()
+                    .map(|object| PropertyValue::ObjectIdentifier(object.identifier()))
+                    .collect(),
+            ));
+        }
+        match view.object_by_id(identifier) {
             Some(obj) => obj.get_property(property),
             None => Err(ObjectError::NotFound),
         }
     }
 
+    /// Borrow every object for reading
+    ///
+    /// The view holds the database read lock, so writes block until it is
+    /// dropped.
+    pub fn read(&self) -> ObjectsView<'_> {
+        ObjectsView {
+            objects: self.objects.read().unwrap(),
+            device_id: self.device_id,
+        }
+    }
+
     /// Set a property value on an object
     pub fn set_property(
         &self,
         identifier: ObjectIdentifier,
         property: PropertyIdentifier,
         value: PropertyValue,
+    ) -> Result<()> {
+        self.write_property(identifier, property, value, None)
+    }
+
+    /// Write a property value on an object at a command priority
+    ///
+    /// Follows WriteProperty semantics: commandable objects store
+    /// Present_Value writes in their priority array.
+    pub fn write_property(
+        &self,
+        identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        priority: Option<u8>,
     ) -> Result<()> {
         let mut objects = self.objects.write().unwrap();
-        match objects.get_mut(&identifier) {
-            Some(obj) => {
-                let result = obj.set_property(property, value);
-                if result.is_ok() {
+        let obj = match objects.get_mut(&identifier) {
+            Some(obj) => obj,
+            None => return Err(ObjectError::NotFound),
+        };
+
+        // Renaming must keep Object_Name unique and the name index current
+        if let (PropertyIdentifier::ObjectName, PropertyValue::CharacterString(new_name)) =
+            (property, &value)
+        {
+            validate_object_name(new_name)?;
+            let mut name_index = self.name_index.write().unwrap();
+            match name_index.get(new_name) {
+                Some(&owner) if owner != identifier => {
+                    return Err(ObjectError::DuplicateObjectName(new_name.clone()));
+                }
+                Some(_) => {}
+                None => {
+                    let new_name = new_name.clone();
+                    obj.set_property(property, value)?;
+                    name_index.retain(|_, id| *id != identifier);
+                    name_index.insert(new_name, identifier);
                     self.increment_revision();
+                    return Ok(());
                 }
-                result
             }
-            None => Err(ObjectError::NotFound),
         }
+
+        let result = obj.write_property(property, value, priority);
+        if result.is_ok() {
+            self.increment_revision();
+        }
+        result
+    }
+
+    /// Read a property, or one element of it when it is an array
+    ///
+    /// Index 0 reads the number of elements, as for Object_List and
+    /// Priority_Array.
+    pub fn get_property_element(
+        &self,
+        identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+        array_index: Option<u32>,
+    ) -> Result<PropertyValue> {
+        let value = self.get_property(identifier, property)?;
+        match array_index {
+            Some(index) => array_element(value, index),
+            None => Ok(value),
+        }
+    }
+
+    /// Write a property, or one element of it when it is an array
+    ///
+    /// Without an index this is [`ObjectDatabase::write_property`]; writing
+    /// Priority_Array element N commands Present_Value at priority N.
+    pub fn write_property_element(
+        &self,
+        identifier: ObjectIdentifier,
+        property: PropertyIdentifier,
+        array_index: Option<u32>,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        if array_index.is_none() {
+            return self.write_property(identifier, property, value, priority);
+        }
+        if identifier == self.device_id && property == PropertyIdentifier::ObjectList {
+            return Err(ObjectError::WriteAccessDenied);
+        }
+        let mut objects = self.objects.write().unwrap();
+        let obj = objects.get_mut(&identifier).ok_or(ObjectError::NotFound)?;
+        obj.write_property_element(property, array_index, value, priority)?;
+        self.increment_revision();
+        Ok(())
+    }
+
+    /// Borrow one object for reading
+    ///
+    /// The guard holds the database read lock, so writes block until it is
+    /// dropped.
+    pub fn get(&self, identifier: ObjectIdentifier) -> Result<ObjectRef<'_>> {
+        let objects = self.objects.read().unwrap();
+        if !objects.contains_key(&identifier) {
+            return Err(ObjectError::NotFound);
+        }
+        Ok(ObjectRef {
+            objects,
+            identifier,
+        })
+    }
+
+    /// Modify one object under the database write lock
+    ///
+    /// Like [`ObjectDatabase::with_object_mut`], changes made this way bypass
+    /// the name index; rename objects through [`ObjectDatabase::set_property`].
+    pub fn update<R>(
+        &self,
+        identifier: ObjectIdentifier,
+        f: impl FnOnce(&mut dyn BacnetObject) -> R,
+    ) -> Result<R> {
+        let mut objects = self.objects.write().unwrap();
+        let object = objects.get_mut(&identifier).ok_or(ObjectError::NotFound)?;
+        let result = f(object.as_mut());
+        self.increment_revision();
+        Ok(result)
+    }
+
+    /// Access an object as its concrete type
+    ///
+    /// Returns `TypeNotSupported` if the stored object is not a `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bacnet_rs::object::{AnalogInput, Device, ObjectDatabase};
+    ///
+    /// let db = ObjectDatabase::new(Device::new(1, "Controller".to_string()));
+    /// let sensor = AnalogInput::new(1, "Supply Temperature".to_string());
+    /// let id = sensor.identifier;
+    /// db.add_object(Box::new(sensor)).unwrap();
+    ///
+    /// let name = db
+    ///     .with_object(id, |sensor: &AnalogInput| sensor.object_name.clone())
+    ///     .unwrap();
+    /// assert_eq!(name, "Supply Temperature");
+    /// ```
+    pub fn with_object<T: BacnetObject, R>(
+        &self,
+        identifier: ObjectIdentifier,
+        f: impl FnOnce(&T) -> R,
+    ) -> Result<R> {
+        let objects = self.objects.read().unwrap();
+        let object = objects.get(&identifier).ok_or(ObjectError::NotFound)?;
+        object
+            .downcast_ref::<T>()
+            .map(f)
+            .ok_or(ObjectError::TypeNotSupported)
+    }
+
+    /// Modify an object as its concrete type
+    ///
+    /// Changes made this way bypass property validation and the name index,
+    /// but still bump the database revision.
+    pub fn with_object_mut<T: BacnetObject, R>(
+        &self,
+        identifier: ObjectIdentifier,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Result<R> {
+        let mut objects = self.objects.write().unwrap();
+        let object = objects.get_mut(&identifier).ok_or(ObjectError::NotFound)?;
+        let result = object
+            .downcast_mut::<T>()
+            .map(f)
+            .ok_or(ObjectError::TypeNotSupported)?;
+        self.increment_revision();
+        Ok(result)
     }
 
     /// Get an object by name
@@ -325,6 +514,55 @@ impl ObjectDatabase {
     }
 }
 
+/// Read only view of the objects in an [`ObjectDatabase`]
+#[cfg(feature = "std")]
+pub struct ObjectsView<'a> {
+    objects: RwLockReadGuard<'a, HashMap<ObjectIdentifier, Box<dyn BacnetObject>>>,
+    device_id: ObjectIdentifier,
+}
+
+#[cfg(feature = "std")]
+impl ObjectsView<'_> {
+    /// Iterate over all objects in Object_List order
+    ///
+    /// The device comes first, followed by the other objects ordered by
+    /// object type and instance.
+    pub fn objects(&self) -> impl Iterator<Item = &dyn BacnetObject> + '_ {
+        let mut identifiers: Vec<ObjectIdentifier> = self
+            .objects
+            .keys()
+            .copied()
+            .filter(|&id| id != self.device_id)
+            .collect();
+        identifiers.sort_by_key(|id| (id.object_type.as_u16(), id.instance));
+        core::iter::once(self.device_id)
+            .chain(identifiers)
+            .filter_map(move |id| self.object_by_id(id))
+    }
+
+    /// Look up an object by identifier
+    pub fn object_by_id(&self, identifier: ObjectIdentifier) -> Option<&dyn BacnetObject> {
+        self.objects.get(&identifier).map(|object| object.as_ref())
+    }
+}
+
+/// Read guard for one object in an [`ObjectDatabase`]
+#[cfg(feature = "std")]
+pub struct ObjectRef<'a> {
+    objects: RwLockReadGuard<'a, HashMap<ObjectIdentifier, Box<dyn BacnetObject>>>,
+    identifier: ObjectIdentifier,
+}
+
+#[cfg(feature = "std")]
+impl Deref for ObjectRef<'_> {
+    type Target = dyn BacnetObject;
+
+    fn deref(&self) -> &Self::Target {
+        // The object cannot be removed while the read lock is held
+        self.objects[&self.identifier].as_ref()
+    }
+}
+
 /// Database statistics
 #[cfg(feature = "std")]
 #[derive(Debug, Clone)]
@@ -386,6 +624,7 @@ mod tests {
     use crate::object::{
         analog::{AnalogInput, AnalogValue},
         binary::BinaryInput,
+        ObjectNameError,
     };
 
     #[test]
@@ -424,6 +663,57 @@ mod tests {
         assert_eq!(db.object_count_by_type(ObjectType::AnalogInput), 0);
     }
 
+    #[test]
+    fn test_device_object_list_tracks_objects() {
+        let device = Device::new(1234, "Test Device".to_string());
+        let db = ObjectDatabase::new(device);
+        let device_id = db.get_device_id();
+
+        let ai = AnalogInput::new(1, "Temperature".to_string());
+        let ai_id = ai.identifier();
+        db.add_object(Box::new(ai)).unwrap();
+        match db
+            .get_property(device_id, PropertyIdentifier::ObjectList)
+            .unwrap()
+        {
+            PropertyValue::Array(list) => assert_eq!(list.len(), 2),
+            _ => panic!("Expected Array"),
+        }
+
+        db.remove_object(ai_id).unwrap();
+        match db
+            .get_property(device_id, PropertyIdentifier::ObjectList)
+            .unwrap()
+        {
+            PropertyValue::Array(list) => assert_eq!(list.len(), 1),
+            _ => panic!("Expected Array"),
+        }
+    }
+
+    #[test]
+    fn test_objects_view() {
+        let device = Device::new(1234, "Test Device".to_string());
+        let db = ObjectDatabase::new(device);
+        let device_id = db.get_device_id();
+
+        let bi = BinaryInput::new(0, "Door".to_string());
+        let bi_id = bi.identifier();
+        db.add_object(Box::new(bi)).unwrap();
+        let ai = AnalogInput::new(1, "Temperature".to_string());
+        let ai_id = ai.identifier();
+        db.add_object(Box::new(ai)).unwrap();
+
+        // Object_List order: the device, then by type and instance
+        let view = db.read();
+        let ids: Vec<ObjectIdentifier> = view.objects().map(|object| object.identifier()).collect();
+        assert_eq!(ids, vec![device_id, ai_id, bi_id]);
+        let object = view.object_by_id(bi_id).unwrap();
+        assert!(object.downcast_ref::<BinaryInput>().is_some());
+        assert!(view
+            .object_by_id(ObjectIdentifier::new(ObjectType::AnalogInput, 9))
+            .is_none());
+    }
+
     #[test]
     fn test_object_lookup() {
         let device = Device::new(1234, "Test Device".to_string());
@@ -446,6 +736,70 @@ mod tests {
         assert_eq!(objects[0], av_id);
     }
 
+    #[test]
+    fn test_duplicate_object_name_rejected() {
+        let device = Device::new(1234, "Test Device".to_string());
+        let db = ObjectDatabase::new(device);
+
+        db.add_object(Box::new(AnalogInput::new(1, "Zone Temp".to_string())))
+            .unwrap();
+
+        // Same name on a different object type is still a conflict
+        let result = db.add_object(Box::new(BinaryInput::new(1, "Zone Temp".to_string())));
+        assert!(matches!(result, Err(ObjectError::DuplicateObjectName(_))));
+
+        // Names are case-sensitive
+        db.add_object(Box::new(BinaryInput::new(1, "zone temp".to_string())))
+            .unwrap();
+
+        // Renaming onto an existing name is rejected, renaming to a fresh name
+        // moves the index entry
+        let bi_id = ObjectIdentifier::new(ObjectType::BinaryInput, 1);
+        let rename = |name: &str| {
+            db.set_property(
+                bi_id,
+                PropertyIdentifier::ObjectName,
+                PropertyValue::CharacterString(name.to_string()),
+            )
+        };
+        assert!(matches!(
+            rename("Test Device"),
+            Err(ObjectError::DuplicateObjectName(_))
+        ));
+        assert!(matches!(
+            rename(""),
+            Err(ObjectError::InvalidObjectName(ObjectNameError::Empty))
+        ));
+        rename("Door Contact").unwrap();
+        assert_eq!(db.get_object_by_name("Door Contact").unwrap(), bi_id);
+        assert!(!db.contains_name("zone temp"));
+    }
+
+    #[test]
+    fn test_with_object_downcast() {
+        let device = Device::new(1234, "Test Device".to_string());
+        let db = ObjectDatabase::new(device);
+
+        let mut ai = AnalogInput::new(1, "OAT".to_string());
+        ai.present_value = 12.5;
+        db.add_object(Box::new(ai)).unwrap();
+        let ai_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+
+        let value = db.with_object(ai_id, |ai: &AnalogInput| ai.present_value);
+        assert_eq!(value.unwrap(), 12.5);
+
+        db.with_object_mut(ai_id, |ai: &mut AnalogInput| ai.present_value = 13.0)
+            .unwrap();
+        assert_eq!(
+            db.with_object(ai_id, |ai: &AnalogInput| ai.present_value)
+                .unwrap(),
+            13.0
+        );
+
+        let wrong = db.with_object(ai_id, |_: &BinaryInput| ());
+        assert!(matches!(wrong, Err(ObjectError::TypeNotSupported)));
+    }
+
     #[test]
     fn test_property_search() {
         let device = Device::new(1234, "Test Device".to_string());
@@ -500,4 +854,142 @@ mod tests {
         // Next instance should be max + 1
         assert_eq!(db.next_instance(ObjectType::AnalogInput), 11);
     }
+
+    #[test]
+    fn test_get_and_update_across_threads() {
+        let db = Arc::new(ObjectDatabase::new(Device::new(
+            1234,
+            "Test Device".to_string(),
+        )));
+        let sensor = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+        db.add_object(Box::new(AnalogValue::new(1, "Setpoint".to_string())))
+            .unwrap();
+
+        let writers: Vec<_> = (0..4)
+            .map(|_| {
+                let db = db.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..25 {
+                        db.update(sensor, |object| {
+                            let PropertyValue::Real(value) = object
+                                .get_property(PropertyIdentifier::PresentValue)
+                                .unwrap()
+                            else {
+                                panic!("Present_Value is not a Real");
+                            };
+                            object
+                                .set_property(
+                                    PropertyIdentifier::PresentValue,
+                                    PropertyValue::Real(value + 1.0),
+                                )
+                                .unwrap();
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect();
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        // Each update ran under the write lock, so none were lost
+        let object = db.get(sensor).unwrap();
+        assert_eq!(object.identifier(), sensor);
+        assert!(matches!(
+            object.get_property(PropertyIdentifier::PresentValue),
+            Ok(PropertyValue::Real(value)) if value == 100.0
+        ));
+        drop(object);
+
+        let missing = ObjectIdentifier::new(ObjectType::AnalogValue, 2);
+        assert!(matches!(db.get(missing), Err(ObjectError::NotFound)));
+        assert!(matches!(
+            db.update(missing, |_| ()),
+            Err(ObjectError::NotFound)
+        ));
+    }
+
+    #[test]
+    fn test_array_element_access() {
+        let db = ObjectDatabase::new(Device::new(1234, "Test Device".to_string()));
+        let device = ObjectIdentifier::new(ObjectType::Device, 1234);
+        let setpoint = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+        db.add_object(Box::new(AnalogValue::new(1, "Setpoint".to_string())))
+            .unwrap();
+
+        // Index 0 is the element count, then elements count from 1
+        assert!(matches!(
+            db.get_property_element(device, PropertyIdentifier::ObjectList, Some(0)),
+            Ok(PropertyValue::UnsignedInteger(2))
+        ));
+        assert!(matches!(
+            db.get_property_element(setpoint, PropertyIdentifier::PriorityArray, Some(0)),
+            Ok(PropertyValue::UnsignedInteger(16))
+        ));
+        assert!(matches!(
+            db.get_property_element(setpoint, PropertyIdentifier::PriorityArray, Some(17)),
+            Err(ObjectError::InvalidArrayIndex)
+        ));
+        assert!(matches!(
+            db.get_property_element(setpoint, PropertyIdentifier::PresentValue, Some(1)),
+            Err(ObjectError::PropertyIsNotAnArray)
+        ));
+        assert!(matches!(
+            db.get_property_element(setpoint, PropertyIdentifier::PriorityArray, None),
+            Ok(PropertyValue::Array(slots)) if slots.len() == 16
+        ));
+
+        // Writing a Priority_Array slot commands Present_Value at that priority
+        db.write_property_element(
+            setpoint,
+            PropertyIdentifier::PriorityArray,
+            Some(8),
+            PropertyValue::Real(21.5),
+            None,
+        )
+        .unwrap();
+        assert!(matches!(
+            db.get_property_element(setpoint, PropertyIdentifier::PriorityArray, Some(8)),
+            Ok(PropertyValue::Real(value)) if value == 21.5
+        ));
+        assert!(matches!(
+            db.get_property(setpoint, PropertyIdentifier::PresentValue),
+            Ok(PropertyValue::Real(value)) if value == 21.5
+        ));
+
+        db.write_property_element(
+            setpoint,
+            PropertyIdentifier::PriorityArray,
+            Some(8),
+            PropertyValue::Null,
+            None,
+        )
+        .unwrap();
+        assert!(matches!(
+            db.get_property_element(setpoint, PropertyIdentifier::PriorityArray, Some(8)),
+            Ok(PropertyValue::Null)
+        ));
+
+        assert!(matches!(
+            db.write_property_element(
+                setpoint,
+                PropertyIdentifier::PriorityArray,
+                Some(0),
+                PropertyValue::UnsignedInteger(4),
+                None,
+            ),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+        assert!(matches!(
+            db.write_property_element(
+                device,
+                PropertyIdentifier::ObjectList,
+                Some(1),
+                PropertyValue::ObjectIdentifier(setpoint),
+                None,
+            ),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+    }
 }
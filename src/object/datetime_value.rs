@@ -0,0 +1,637 @@
+//! Date and Time Value Object Types Implementation
+//!
+//! This module implements the Date Value, Time Value and DateTime Value
+//! object types as defined in ASHRAE 135. They are writable software values
+//! for configurable timestamps and calendar anchors. Present values may
+//! leave fields unspecified, but the odd, even and last-day patterns that
+//! only make sense in calendar entries are rejected, as are dates that do not
+//! exist such as month 13 or 30 February.
+//!
+//! [`TimeValue`] here is the Time Value object; the time-value pair of a
+//! weekly schedule is [`schedule::TimeValue`](crate::object::schedule::TimeValue).
+
+use crate::datatypes::{days_in_month, BacnetDate, BacnetTime, DayOfMonth, Month, Weekday};
+use crate::object::{
+    analog::{EventState, Reliability},
+    callback::PropertyCallbacks,
+    status_flags::StatusFlags,
+    validate_description, write_rejected, BacnetObject, Date, ObjectError, ObjectIdentifier,
+    ObjectType, PropertyIdentifier, PropertyValue, Result, Time,
+};
+use crate::service::BacnetDateTime;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Date Value object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateValue {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Present value
+    pub present_value: BacnetDate,
+    /// Description
+    pub description: String,
+    /// Status flags
+    pub status_flags: StatusFlags,
+    /// Event state
+    pub event_state: EventState,
+    /// Reliability
+    pub reliability: Reliability,
+    /// Out of service
+    pub out_of_service: bool,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+}
+
+/// Time Value object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeValue {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Present value
+    pub present_value: BacnetTime,
+    /// Description
+    pub description: String,
+    /// Status flags
+    pub status_flags: StatusFlags,
+    /// Event state
+    pub event_state: EventState,
+    /// Reliability
+    pub reliability: Reliability,
+    /// Out of service
+    pub out_of_service: bool,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+}
+
+/// DateTime Value object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateTimeValue {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Present value
+    pub present_value: BacnetDateTime,
+    /// Description
+    pub description: String,
+    /// Status flags
+    pub status_flags: StatusFlags,
+    /// Event state
+    pub event_state: EventState,
+    /// Reliability
+    pub reliability: Reliability,
+    /// Out of service
+    pub out_of_service: bool,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+}
+
+/// Check that a date is a possibly unspecified date rather than a pattern
+fn validate_date(date: &BacnetDate) -> Result<()> {
+    if !matches!(date.month, Month::Specific(_) | Month::Any)
+        || !matches!(date.day, DayOfMonth::Specific(_) | DayOfMonth::Any)
+    {
+        return Err(ObjectError::InvalidValue(
+            "Date patterns are not valid dates".to_string(),
+        ));
+    }
+    if let Some((year, month, day)) = date.ymd() {
+        if day > days_in_month(year, month) {
+            return Err(ObjectError::InvalidValue(
+                "Day is past the end of the month".to_string(),
+            ));
+        }
+        if date
+            .weekday
+            .is_some_and(|weekday| Some(weekday) != Weekday::of_date(year, month, day))
+        {
+            return Err(ObjectError::InvalidValue(
+                "Day of week does not match the date".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Convert and validate a raw date
+fn date_from(date: Date) -> Result<BacnetDate> {
+    let date = BacnetDate::try_from(date)
+        .map_err(|_| ObjectError::InvalidValue("Date field out of range".to_string()))?;
+    validate_date(&date)?;
+    Ok(date)
+}
+
+/// Convert and validate a raw time
+fn time_from(time: Time) -> Result<BacnetTime> {
+    BacnetTime::try_from(time)
+        .map_err(|_| ObjectError::InvalidValue("Time field out of range".to_string()))
+}
+
+/// Date and time of a DateTime Value as a two element list
+fn date_time_property(value: &BacnetDateTime) -> PropertyValue {
+    PropertyValue::List(vec![
+        PropertyValue::Date(value.date),
+        PropertyValue::Time(value.time),
+    ])
+}
+
+impl DateValue {
+    /// Create a new Date Value object with an unspecified present value
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::DateValue, instance),
+            object_name,
+            present_value: BacnetDate::any(),
+            description: String::new(),
+            status_flags: StatusFlags::default(),
+            event_state: EventState::Normal,
+            reliability: Reliability::NoFaultDetected,
+            out_of_service: false,
+            callbacks: PropertyCallbacks::new(),
+        }
+    }
+
+    /// Set the present value, notifying Present_Value callbacks on change
+    pub fn set_present_value(&mut self, value: BacnetDate) -> Result<()> {
+        validate_date(&value)?;
+        let old = core::mem::replace(&mut self.present_value, value);
+        if old != value {
+            self.callbacks.notify(
+                self.identifier,
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Date(old.into()),
+                PropertyValue::Date(value.into()),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl TimeValue {
+    /// Create a new Time Value object with an unspecified present value
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::TimeValue, instance),
+            object_name,
+            present_value: BacnetTime::any(),
+            description: String::new(),
+            status_flags: StatusFlags::default(),
+            event_state: EventState::Normal,
+            reliability: Reliability::NoFaultDetected,
+            out_of_service: false,
+            callbacks: PropertyCallbacks::new(),
+        }
+    }
+
+    /// Set the present value, notifying Present_Value callbacks on change
+    pub fn set_present_value(&mut self, value: BacnetTime) {
+        let old = core::mem::replace(&mut self.present_value, value);
+        if old != value {
+            self.callbacks.notify(
+                self.identifier,
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Time(old.into()),
+                PropertyValue::Time(value.into()),
+            );
+        }
+    }
+}
+
+impl DateTimeValue {
+    /// Create a new DateTime Value object with an unspecified present value
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::DateTimeValue, instance),
+            object_name,
+            present_value: BacnetDateTime::unspecified(),
+            description: String::new(),
+            status_flags: StatusFlags::default(),
+            event_state: EventState::Normal,
+            reliability: Reliability::NoFaultDetected,
+            out_of_service: false,
+            callbacks: PropertyCallbacks::new(),
+        }
+    }
+
+    /// Set the present value, notifying Present_Value callbacks on change
+    pub fn set_present_value(&mut self, value: BacnetDateTime) -> Result<()> {
+        date_from(value.date)?;
+        time_from(value.time)?;
+        let old = core::mem::replace(&mut self.present_value, value);
+        if old != value {
+            self.callbacks.notify(
+                self.identifier,
+                PropertyIdentifier::PresentValue,
+                date_time_property(&old),
+                date_time_property(&value),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl BacnetObject for DateValue {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::DateValue.as_u16() as u32,
+            )),
+            PropertyIdentifier::PresentValue => Ok(PropertyValue::Date(self.present_value.into())),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::ObjectName, PropertyValue::CharacterString(name)) => {
+                self.object_name = name;
+                Ok(())
+            }
+            (PropertyIdentifier::PresentValue, PropertyValue::Date(date)) => {
+                self.set_present_value(date_from(date)?)
+            }
+            (PropertyIdentifier::Description, PropertyValue::CharacterString(description)) => {
+                validate_description(&description)?;
+                self.description = description;
+                Ok(())
+            }
+            (PropertyIdentifier::OutOfService, PropertyValue::Boolean(oos)) => {
+                self.out_of_service = oos;
+                self.status_flags = StatusFlags::derive(
+                    self.event_state,
+                    self.reliability,
+                    self.status_flags.overridden,
+                    oos,
+                );
+                Ok(())
+            }
+            (property, _) if self.is_property_writable(property) => {
+                Err(ObjectError::InvalidPropertyType)
+            }
+            (property, _) => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::PresentValue
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::OutOfService
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::OutOfService,
+        ]
+    }
+}
+
+impl BacnetObject for TimeValue {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::TimeValue.as_u16() as u32,
+            )),
+            PropertyIdentifier::PresentValue => Ok(PropertyValue::Time(self.present_value.into())),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::ObjectName, PropertyValue::CharacterString(name)) => {
+                self.object_name = name;
+                Ok(())
+            }
+            (PropertyIdentifier::PresentValue, PropertyValue::Time(time)) => {
+                self.set_present_value(time_from(time)?);
+                Ok(())
+            }
+            (PropertyIdentifier::Description, PropertyValue::CharacterString(description)) => {
+                validate_description(&description)?;
+                self.description = description;
+                Ok(())
+            }
+            (PropertyIdentifier::OutOfService, PropertyValue::Boolean(oos)) => {
+                self.out_of_service = oos;
+                self.status_flags = StatusFlags::derive(
+                    self.event_state,
+                    self.reliability,
+                    self.status_flags.overridden,
+                    oos,
+                );
+                Ok(())
+            }
+            (property, _) if self.is_property_writable(property) => {
+                Err(ObjectError::InvalidPropertyType)
+            }
+            (property, _) => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::PresentValue
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::OutOfService
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::OutOfService,
+        ]
+    }
+}
+
+impl BacnetObject for DateTimeValue {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::DateTimeValue.as_u16() as u32,
+            )),
+            PropertyIdentifier::PresentValue => Ok(date_time_property(&self.present_value)),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::ObjectName, PropertyValue::CharacterString(name)) => {
+                self.object_name = name;
+                Ok(())
+            }
+            (PropertyIdentifier::PresentValue, PropertyValue::List(elements)) => {
+                match elements.as_slice() {
+                    [PropertyValue::Date(date), PropertyValue::Time(time)] => {
+                        self.set_present_value(BacnetDateTime::new(*date, *time))
+                    }
+                    _ => Err(ObjectError::InvalidPropertyType),
+                }
+            }
+            (PropertyIdentifier::Description, PropertyValue::CharacterString(description)) => {
+                validate_description(&description)?;
+                self.description = description;
+                Ok(())
+            }
+            (PropertyIdentifier::OutOfService, PropertyValue::Boolean(oos)) => {
+                self.out_of_service = oos;
+                self.status_flags = StatusFlags::derive(
+                    self.event_state,
+                    self.reliability,
+                    self.status_flags.overridden,
+                    oos,
+                );
+                Ok(())
+            }
+            (property, _) if self.is_property_writable(property) => {
+                Err(ObjectError::InvalidPropertyType)
+            }
+            (property, _) => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::PresentValue
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::OutOfService
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::OutOfService,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_date(year: u16, month: u8, day: u8) -> Date {
+        Date {
+            year,
+            month,
+            day,
+            weekday: 255,
+        }
+    }
+
+    #[test]
+    fn test_date_value_rejects_invalid_dates() {
+        let mut dv = DateValue::new(1, "Commissioning date".to_string());
+        dv.set_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Date(raw_date(2024, 2, 29)),
+        )
+        .unwrap();
+        assert_eq!(dv.present_value.ymd(), Some((2024, 2, 29)));
+
+        for invalid in [
+            raw_date(2024, 13, 1),
+            raw_date(2023, 2, 29),
+            raw_date(2024, 4, 31),
+            raw_date(2024, 5, 32),
+        ] {
+            assert!(matches!(
+                dv.set_property(
+                    PropertyIdentifier::PresentValue,
+                    PropertyValue::Date(invalid)
+                ),
+                Err(ObjectError::InvalidValue(_))
+            ));
+        }
+        // A wrong day of week is rejected; an unspecified year is allowed
+        let mut tuesday = raw_date(2024, 2, 29);
+        tuesday.weekday = 2;
+        assert!(dv
+            .set_present_value(BacnetDate::try_from(tuesday).unwrap())
+            .is_err());
+        dv.set_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Date(raw_date(255, 12, 25)),
+        )
+        .unwrap();
+        assert_eq!(dv.present_value.year, None);
+    }
+
+    #[test]
+    fn test_time_and_date_time_values() {
+        let mut tv = TimeValue::new(1, "Start time".to_string());
+        let start = Time {
+            hour: 6,
+            minute: 30,
+            second: 0,
+            hundredths: 0,
+        };
+        tv.set_property(PropertyIdentifier::PresentValue, PropertyValue::Time(start))
+            .unwrap();
+        assert!(matches!(
+            tv.get_property(PropertyIdentifier::PresentValue),
+            Ok(PropertyValue::Time(time)) if time == start
+        ));
+        assert!(tv
+            .set_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Time(Time { hour: 24, ..start })
+            )
+            .is_err());
+
+        let mut dtv = DateTimeValue::new(1, "Last service".to_string());
+        let date = raw_date(2025, 1, 15);
+        dtv.set_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::List(vec![PropertyValue::Date(date), PropertyValue::Time(start)]),
+        )
+        .unwrap();
+        assert_eq!(dtv.present_value, BacnetDateTime::new(date, start));
+        assert!(matches!(
+            dtv.get_property(PropertyIdentifier::PresentValue),
+            Ok(PropertyValue::List(elements)) if elements.len() == 2
+        ));
+        assert!(dtv
+            .set_present_value(BacnetDateTime::new(raw_date(2025, 13, 1), start))
+            .is_err());
+        assert!(matches!(
+            dtv.set_property(PropertyIdentifier::PresentValue, PropertyValue::Date(date)),
+            Err(ObjectError::InvalidPropertyType)
+        ));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_present_value_callbacks() {
+        use std::sync::{Arc, Mutex};
+
+        let mut dv = DateValue::new(2, "Holiday".to_string());
+        let changes = Arc::new(Mutex::new(0));
+        let sink = changes.clone();
+        dv.callbacks
+            .register(PropertyIdentifier::PresentValue, move |_| {
+                *sink.lock().unwrap() += 1;
+            });
+
+        let christmas = BacnetDate::new(2025, 12, 25).unwrap();
+        dv.set_present_value(christmas).unwrap();
+        dv.set_present_value(christmas).unwrap();
+        assert!(dv
+            .set_present_value(BacnetDate {
+                month: Month::Odd,
+                ..christmas
+            })
+            .is_err());
+        assert_eq!(*changes.lock().unwrap(), 1);
+    }
+}
@@ -15,7 +15,7 @@
 //! and `Device_Object_Functions()` APIs (bacnet-stack commit 5b7932ee6).
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec, vec::Vec};
 
 use crate::object::{ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue, Result};
 
@@ -323,7 +323,7 @@ impl DeviceObject {
             }
             // Check if writable
             if !(funcs.is_property_writable)(object_id.instance, property) {
-                return Err(crate::object::ObjectError::PropertyNotWritable);
+                return Err(crate::object::ObjectError::WriteAccessDenied);
             }
             // Write the property
             (funcs.write_property)(object_id.instance, property, value)
@@ -336,6 +336,25 @@ impl DeviceObject {
     pub fn total_object_count(&self) -> usize {
         self.object_table.iter().map(|funcs| (funcs.count)()).sum()
     }
+
+    /// Build the Object_List from the registered object functions
+    ///
+    /// The device itself comes first, followed by every instance of each
+    /// registered type in registration order.
+    pub fn object_list(&self) -> Vec<ObjectIdentifier> {
+        let mut list = vec![ObjectIdentifier::new(
+            ObjectType::Device,
+            self.device_instance,
+        )];
+        for funcs in &self.object_table {
+            list.extend(
+                (0..(funcs.count)())
+                    .filter_map(funcs.index_to_instance)
+                    .map(|instance| ObjectIdentifier::new(funcs.object_type, instance)),
+            );
+        }
+        list
+    }
 }
 
 #[cfg(test)]
@@ -420,6 +439,14 @@ mod tests {
         assert!(device
             .find_object_functions(ObjectType::AnalogInput)
             .is_some());
+        assert_eq!(
+            device.object_list(),
+            vec![
+                ObjectIdentifier::new(ObjectType::Device, 123),
+                ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+                ObjectIdentifier::new(ObjectType::AnalogInput, 2),
+            ]
+        );
     }
 
     #[test]
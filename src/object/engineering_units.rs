@@ -6,6 +6,12 @@
 //! The enum includes all standard BACnet engineering units with their exact
 //! numeric values for protocol compatibility.
 
+#[cfg(feature = "std")]
+use std::fmt;
+
+#[cfg(not(feature = "std"))]
+use core::fmt;
+
 /// BACnet Engineering Units enumeration
 ///
 /// Represents all engineering units defined in the BACnet standard.
@@ -13,6 +19,7 @@
 /// Values 256-47807 and 50000-65535 may be used by others and are represented
 /// by the `Other(u32)` variant.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EngineeringUnits {
     // Acceleration
     MetersPerSecondPerSecond,
@@ -323,18 +330,35 @@ pub enum EngineeringUnits {
     GramsPerSquareMeter,
     MinutesPerDegreeKelvin,
 
+    // Brewing
+    DegreesLovibond,
+    AlcoholByVolume,
+    InternationalBitteringUnits,
+    EuropeanBitternessUnits,
+    DegreesPlato,
+    SpecificGravity,
+    EuropeanBrewingConvention,
+
     /// Vendor-specific or future engineering units
     /// Values 256-47807 and 50000-65535 may be used by others
     Other(u32),
 }
 
 impl EngineeringUnits {
-    /// Convert from a u32 value to an EngineeringUnits variant
+    /// Convert from an enumeration value to an EngineeringUnits variant
     ///
     /// For standard ASHRAE-defined units (0-255, 47808-49999), returns the
     /// corresponding variant. For vendor-specific values (256-47807, 50000-65535),
-    /// returns `Other(value)`.
-    pub fn from_u32(value: u32) -> Self {
+    /// returns `Other(value)`. Returns `None` for values outside the 16-bit
+    /// range of the enumeration.
+    pub fn from_u32(value: u32) -> Option<Self> {
+        if value > u16::MAX as u32 {
+            return None;
+        }
+        Some(Self::from_enumeration(value))
+    }
+
+    fn from_enumeration(value: u32) -> Self {
         match value {
             166 => Self::MetersPerSecondPerSecond,
             0 => Self::SquareMeters,
@@ -593,6 +617,13 @@ impl EngineeringUnits {
             231 => Self::MicrosievertsPerHour,
             47814 => Self::Millirems,
             47815 => Self::MilliremsPerHour,
+            47816 => Self::DegreesLovibond,
+            47817 => Self::AlcoholByVolume,
+            47818 => Self::InternationalBitteringUnits,
+            47819 => Self::EuropeanBitternessUnits,
+            47820 => Self::DegreesPlato,
+            47821 => Self::SpecificGravity,
+            47822 => Self::EuropeanBrewingConvention,
             232 => Self::DecibelsA,
             233 => Self::NephelometricTurbidityUnit,
             234 => Self::PH,
@@ -603,8 +634,8 @@ impl EngineeringUnits {
         }
     }
 
-    /// Convert to a u32 value
-    pub fn to_u32(&self) -> u32 {
+    /// Convert to the enumeration value used on the wire
+    pub fn as_u32(&self) -> u32 {
         match self {
             // Acceleration
             Self::MetersPerSecondPerSecond => 166,
@@ -914,14 +945,301 @@ impl EngineeringUnits {
             Self::GramsPerSquareMeter => 235,
             Self::MinutesPerDegreeKelvin => 236,
 
+            // Brewing
+            Self::DegreesLovibond => 47816,
+            Self::AlcoholByVolume => 47817,
+            Self::InternationalBitteringUnits => 47818,
+            Self::EuropeanBitternessUnits => 47819,
+            Self::DegreesPlato => 47820,
+            Self::SpecificGravity => 47821,
+            Self::EuropeanBrewingConvention => 47822,
+
             // Vendor-specific
             Self::Other(value) => *value,
         }
     }
 
+    /// Get the human readable unit symbol, e.g. "°C" or "kWh"
+    ///
+    /// `NoUnits` and vendor-specific units have an empty symbol.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::MetersPerSecondPerSecond => "m/s²",
+            Self::SquareMeters => "m²",
+            Self::SquareCentimeters => "cm²",
+            Self::SquareFeet => "ft²",
+            Self::SquareInches => "in²",
+            Self::Currency1 => "¤1",
+            Self::Currency2 => "¤2",
+            Self::Currency3 => "¤3",
+            Self::Currency4 => "¤4",
+            Self::Currency5 => "¤5",
+            Self::Currency6 => "¤6",
+            Self::Currency7 => "¤7",
+            Self::Currency8 => "¤8",
+            Self::Currency9 => "¤9",
+            Self::Currency10 => "¤10",
+            Self::Milliamperes => "mA",
+            Self::Amperes => "A",
+            Self::AmperesPerMeter => "A/m",
+            Self::AmperesPerSquareMeter => "A/m²",
+            Self::AmpereSquareMeters => "A·m²",
+            Self::Decibels => "dB",
+            Self::DecibelsMillivolt => "dBmV",
+            Self::DecibelsVolt => "dBV",
+            Self::Farads => "F",
+            Self::Henrys => "H",
+            Self::Ohms => "Ω",
+            Self::OhmMeterSquaredPerMeter => "Ω·m²/m",
+            Self::OhmMeters => "Ω·m",
+            Self::Milliohms => "mΩ",
+            Self::Kilohms => "kΩ",
+            Self::Megohms => "MΩ",
+            Self::Microsiemens => "µS",
+            Self::Millisiemens => "mS",
+            Self::Siemens => "S",
+            Self::SiemensPerMeter => "S/m",
+            Self::Teslas => "T",
+            Self::Volts => "V",
+            Self::Millivolts => "mV",
+            Self::Kilovolts => "kV",
+            Self::Megavolts => "MV",
+            Self::VoltAmperes => "VA",
+            Self::KilovoltAmperes => "kVA",
+            Self::MegavoltAmperes => "MVA",
+            Self::VoltAmperesReactive => "var",
+            Self::KilovoltAmperesReactive => "kvar",
+            Self::MegavoltAmperesReactive => "Mvar",
+            Self::VoltsPerDegreeKelvin => "V/K",
+            Self::VoltsPerMeter => "V/m",
+            Self::DegreesPhase => "°",
+            Self::PowerFactor => "PF",
+            Self::Webers => "Wb",
+            Self::AmpereSeconds => "A·s",
+            Self::VoltAmpereHours => "VAh",
+            Self::KilovoltAmpereHours => "kVAh",
+            Self::MegavoltAmpereHours => "MVAh",
+            Self::VoltAmpereHoursReactive => "varh",
+            Self::KilovoltAmpereHoursReactive => "kvarh",
+            Self::MegavoltAmpereHoursReactive => "Mvarh",
+            Self::VoltSquareHours => "V²h",
+            Self::AmpereSquareHours => "A²h",
+            Self::Joules => "J",
+            Self::Kilojoules => "kJ",
+            Self::KilojoulesPerKilogram => "kJ/kg",
+            Self::Megajoules => "MJ",
+            Self::WattHours => "Wh",
+            Self::KilowattHours => "kWh",
+            Self::MegawattHours => "MWh",
+            Self::WattHoursReactive => "varh",
+            Self::KilowattHoursReactive => "kvarh",
+            Self::MegawattHoursReactive => "Mvarh",
+            Self::Btus => "BTU",
+            Self::KiloBtus => "kBTU",
+            Self::MegaBtus => "MBTU",
+            Self::Therms => "thm",
+            Self::TonHours => "ton·h",
+            Self::JoulesPerKilogramDryAir => "J/kg dry air",
+            Self::KilojoulesPerKilogramDryAir => "kJ/kg dry air",
+            Self::MegajoulesPerKilogramDryAir => "MJ/kg dry air",
+            Self::BtusPerPoundDryAir => "BTU/lb dry air",
+            Self::BtusPerPound => "BTU/lb",
+            Self::JoulesPerDegreeKelvin => "J/K",
+            Self::KilojoulesPerDegreeKelvin => "kJ/K",
+            Self::MegajoulesPerDegreeKelvin => "MJ/K",
+            Self::JoulesPerKilogramDegreeKelvin => "J/(kg·K)",
+            Self::Newton => "N",
+            Self::CyclesPerHour => "cph",
+            Self::CyclesPerMinute => "cpm",
+            Self::Hertz => "Hz",
+            Self::Kilohertz => "kHz",
+            Self::Megahertz => "MHz",
+            Self::PerHour => "/h",
+            Self::GramsOfWaterPerKilogramDryAir => "g/kg dry air",
+            Self::PercentRelativeHumidity => "%RH",
+            Self::Micrometers => "µm",
+            Self::Millimeters => "mm",
+            Self::Centimeters => "cm",
+            Self::Kilometers => "km",
+            Self::Meters => "m",
+            Self::Inches => "in",
+            Self::Feet => "ft",
+            Self::Candelas => "cd",
+            Self::CandelasPerSquareMeter => "cd/m²",
+            Self::WattsPerSquareFoot => "W/ft²",
+            Self::WattsPerSquareMeter => "W/m²",
+            Self::Lumens => "lm",
+            Self::Luxes => "lx",
+            Self::FootCandles => "fc",
+            Self::Milligrams => "mg",
+            Self::Grams => "g",
+            Self::Kilograms => "kg",
+            Self::PoundsMass => "lb",
+            Self::Tons => "t",
+            Self::GramsPerSecond => "g/s",
+            Self::GramsPerMinute => "g/min",
+            Self::KilogramsPerSecond => "kg/s",
+            Self::KilogramsPerMinute => "kg/min",
+            Self::KilogramsPerHour => "kg/h",
+            Self::PoundsMassPerSecond => "lb/s",
+            Self::PoundsMassPerMinute => "lb/min",
+            Self::PoundsMassPerHour => "lb/h",
+            Self::TonsPerHour => "t/h",
+            Self::Milliwatts => "mW",
+            Self::Watts => "W",
+            Self::Kilowatts => "kW",
+            Self::Megawatts => "MW",
+            Self::BtusPerHour => "BTU/h",
+            Self::KiloBtusPerHour => "kBTU/h",
+            Self::JoulePerHours => "J/h",
+            Self::Horsepower => "hp",
+            Self::TonsRefrigeration => "TR",
+            Self::Pascals => "Pa",
+            Self::Hectopascals => "hPa",
+            Self::Kilopascals => "kPa",
+            Self::Millibars => "mbar",
+            Self::Bars => "bar",
+            Self::PoundsForcePerSquareInch => "psi",
+            Self::MillimetersOfWater => "mmH₂O",
+            Self::CentimetersOfWater => "cmH₂O",
+            Self::InchesOfWater => "inH₂O",
+            Self::MillimetersOfMercury => "mmHg",
+            Self::CentimetersOfMercury => "cmHg",
+            Self::InchesOfMercury => "inHg",
+            Self::DegreesCelsius => "°C",
+            Self::DegreesKelvin => "K",
+            Self::DegreesKelvinPerHour => "K/h",
+            Self::DegreesKelvinPerMinute => "K/min",
+            Self::DegreesFahrenheit => "°F",
+            Self::DegreeDaysCelsius => "°C·d",
+            Self::DegreeDaysFahrenheit => "°F·d",
+            Self::DeltaDegreesFahrenheit => "Δ°F",
+            Self::DeltaDegreesKelvin => "ΔK",
+            Self::Years => "a",
+            Self::Months => "mo",
+            Self::Weeks => "wk",
+            Self::Days => "d",
+            Self::Hours => "h",
+            Self::Minutes => "min",
+            Self::Seconds => "s",
+            Self::HundredthsSeconds => "cs",
+            Self::Milliseconds => "ms",
+            Self::NewtonMeters => "N·m",
+            Self::MillimetersPerSecond => "mm/s",
+            Self::MillimetersPerMinute => "mm/min",
+            Self::MetersPerSecond => "m/s",
+            Self::MetersPerMinute => "m/min",
+            Self::MetersPerHour => "m/h",
+            Self::KilometersPerHour => "km/h",
+            Self::FeetPerSecond => "ft/s",
+            Self::FeetPerMinute => "ft/min",
+            Self::MilesPerHour => "mph",
+            Self::CubicFeet => "ft³",
+            Self::CubicMeters => "m³",
+            Self::ImperialGallons => "imp gal",
+            Self::Milliliters => "mL",
+            Self::Liters => "L",
+            Self::UsGallons => "gal",
+            Self::CubicFeetPerSecond => "ft³/s",
+            Self::CubicFeetPerMinute => "cfm",
+            Self::MillionStandardCubicFeetPerMinute => "MMSCFM",
+            Self::CubicFeetPerHour => "ft³/h",
+            Self::CubicFeetPerDay => "ft³/d",
+            Self::StandardCubicFeetPerDay => "SCFD",
+            Self::MillionStandardCubicFeetPerDay => "MMSCFD",
+            Self::ThousandCubicFeetPerDay => "MCFD",
+            Self::ThousandStandardCubicFeetPerDay => "MSCFD",
+            Self::PoundsMassPerDay => "lb/d",
+            Self::CubicMetersPerSecond => "m³/s",
+            Self::CubicMetersPerMinute => "m³/min",
+            Self::CubicMetersPerHour => "m³/h",
+            Self::CubicMetersPerDay => "m³/d",
+            Self::ImperialGallonsPerMinute => "imp gal/min",
+            Self::MillilitersPerSecond => "mL/s",
+            Self::LitersPerSecond => "L/s",
+            Self::LitersPerMinute => "L/min",
+            Self::LitersPerHour => "L/h",
+            Self::UsGallonsPerMinute => "gpm",
+            Self::UsGallonsPerHour => "gph",
+            Self::DegreesAngular => "°",
+            Self::DegreesCelsiusPerHour => "°C/h",
+            Self::DegreesCelsiusPerMinute => "°C/min",
+            Self::DegreesFahrenheitPerHour => "°F/h",
+            Self::DegreesFahrenheitPerMinute => "°F/min",
+            Self::JouleSeconds => "J·s",
+            Self::KilogramsPerCubicMeter => "kg/m³",
+            Self::KilowattHoursPerSquareMeter => "kWh/m²",
+            Self::KilowattHoursPerSquareFoot => "kWh/ft²",
+            Self::WattHoursPerCubicMeter => "Wh/m³",
+            Self::JoulesPerCubicMeter => "J/m³",
+            Self::MegajoulesPerSquareMeter => "MJ/m²",
+            Self::MegajoulesPerSquareFoot => "MJ/ft²",
+            Self::MolePercent => "mol%",
+            Self::NoUnits => "",
+            Self::NewtonSeconds => "N·s",
+            Self::NewtonsPerMeter => "N/m",
+            Self::PartsPerMillion => "ppm",
+            Self::PartsPerBillion => "ppb",
+            Self::PascalSeconds => "Pa·s",
+            Self::Percent => "%",
+            Self::PercentObscurationPerFoot => "%/ft",
+            Self::PercentObscurationPerMeter => "%/m",
+            Self::PercentPerSecond => "%/s",
+            Self::PerMinute => "/min",
+            Self::PerSecond => "/s",
+            Self::PsiPerDegreeFahrenheit => "psi/°F",
+            Self::Radians => "rad",
+            Self::RadiansPerSecond => "rad/s",
+            Self::RevolutionsPerMinute => "rpm",
+            Self::SquareMetersPerNewton => "m²/N",
+            Self::WattsPerMeterPerDegreeKelvin => "W/(m·K)",
+            Self::WattsPerSquareMeterDegreeKelvin => "W/(m²·K)",
+            Self::PerMille => "‰",
+            Self::GramsPerGram => "g/g",
+            Self::KilogramsPerKilogram => "kg/kg",
+            Self::GramsPerKilogram => "g/kg",
+            Self::MilligramsPerGram => "mg/g",
+            Self::MilligramsPerKilogram => "mg/kg",
+            Self::GramsPerMilliliter => "g/mL",
+            Self::GramsPerLiter => "g/L",
+            Self::MilligramsPerLiter => "mg/L",
+            Self::MicrogramsPerLiter => "µg/L",
+            Self::GramsPerCubicMeter => "g/m³",
+            Self::MilligramsPerCubicMeter => "mg/m³",
+            Self::MicrogramsPerCubicMeter => "µg/m³",
+            Self::NanogramsPerCubicMeter => "ng/m³",
+            Self::GramsPerCubicCentimeter => "g/cm³",
+            Self::Becquerels => "Bq",
+            Self::Kilobecquerels => "kBq",
+            Self::Megabecquerels => "MBq",
+            Self::Gray => "Gy",
+            Self::Milligray => "mGy",
+            Self::Microgray => "µGy",
+            Self::Sieverts => "Sv",
+            Self::Millisieverts => "mSv",
+            Self::Microsieverts => "µSv",
+            Self::MicrosievertsPerHour => "µSv/h",
+            Self::Millirems => "mrem",
+            Self::MilliremsPerHour => "mrem/h",
+            Self::DecibelsA => "dBA",
+            Self::NephelometricTurbidityUnit => "NTU",
+            Self::PH => "pH",
+            Self::GramsPerSquareMeter => "g/m²",
+            Self::MinutesPerDegreeKelvin => "min/K",
+            Self::DegreesLovibond => "°L",
+            Self::AlcoholByVolume => "% ABV",
+            Self::InternationalBitteringUnits => "IBU",
+            Self::EuropeanBitternessUnits => "EBU",
+            Self::DegreesPlato => "°P",
+            Self::SpecificGravity => "SG",
+            Self::EuropeanBrewingConvention => "EBC",
+            Self::Other(_) => "",
+        }
+    }
+
     /// Get the category of this unit
     pub fn category(&self) -> &'static str {
-        match self.to_u32() {
+        match self.as_u32() {
             166 => "Acceleration",
             0..=1 | 115..=116 => "Area",
             105..=114 => "Currency",
@@ -946,11 +1264,34 @@ impl EngineeringUnits {
             84..=89 | 135..=136 | 142 | 165 | 191..=192 | 198 | 248..=254 | 47808..=47812 => {
                 "Volumetric Flow"
             }
+            47816..=47822 => "Brewing",
             _ => "Other",
         }
     }
 }
 
+impl From<u32> for EngineeringUnits {
+    /// Unknown and vendor-specific values decode to `Other`, so this never fails
+    fn from(value: u32) -> Self {
+        Self::from_enumeration(value)
+    }
+}
+
+impl From<EngineeringUnits> for u32 {
+    fn from(units: EngineeringUnits) -> Self {
+        units.as_u32()
+    }
+}
+
+impl fmt::Display for EngineeringUnits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Other(value) => write!(f, "units({})", value),
+            _ => f.write_str(self.symbol()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -958,45 +1299,52 @@ mod tests {
     #[test]
     fn test_from_u32() {
         // Test standard ASHRAE units
-        assert_eq!(EngineeringUnits::from_u32(95), EngineeringUnits::NoUnits);
+        assert_eq!(
+            EngineeringUnits::from_u32(95),
+            Some(EngineeringUnits::NoUnits)
+        );
         assert_eq!(
             EngineeringUnits::from_u32(62),
-            EngineeringUnits::DegreesCelsius
+            Some(EngineeringUnits::DegreesCelsius)
         );
         assert_eq!(
             EngineeringUnits::from_u32(64),
-            EngineeringUnits::DegreesFahrenheit
+            Some(EngineeringUnits::DegreesFahrenheit)
         );
 
         // Test vendor-specific units (256-47807)
         assert_eq!(
             EngineeringUnits::from_u32(1000),
-            EngineeringUnits::Other(1000)
+            Some(EngineeringUnits::Other(1000))
         );
 
         // Test vendor-specific units (50000-65535)
         assert_eq!(
             EngineeringUnits::from_u32(50000),
-            EngineeringUnits::Other(50000)
+            Some(EngineeringUnits::Other(50000))
         );
+
+        // Outside the enumeration range
+        assert_eq!(EngineeringUnits::from_u32(65536), None);
     }
 
     #[test]
-    fn test_to_u32() {
-        assert_eq!(EngineeringUnits::NoUnits.to_u32(), 95);
-        assert_eq!(EngineeringUnits::DegreesCelsius.to_u32(), 62);
-        assert_eq!(EngineeringUnits::Other(1000).to_u32(), 1000);
+    fn test_as_u32() {
+        assert_eq!(EngineeringUnits::NoUnits.as_u32(), 95);
+        assert_eq!(EngineeringUnits::DegreesCelsius.as_u32(), 62);
+        assert_eq!(EngineeringUnits::Other(1000).as_u32(), 1000);
     }
 
     #[test]
     fn test_roundtrip() {
         // Standard units should roundtrip
-        let celsius = EngineeringUnits::DegreesCelsius;
-        assert_eq!(EngineeringUnits::from_u32(celsius.to_u32()), celsius);
+        let celsius = EngineeringUnits::from_u32(62).unwrap();
+        assert_eq!(celsius, EngineeringUnits::DegreesCelsius);
+        assert_eq!(celsius.as_u32(), 62);
 
         // Vendor-specific units should roundtrip
         let custom = EngineeringUnits::Other(12345);
-        assert_eq!(EngineeringUnits::from_u32(custom.to_u32()), custom);
+        assert_eq!(EngineeringUnits::from_u32(custom.as_u32()), Some(custom));
     }
 
     #[test]
@@ -1011,4 +1359,27 @@ mod tests {
     fn test_default() {
         assert_eq!(EngineeringUnits::default(), EngineeringUnits::NoUnits);
     }
+
+    #[test]
+    fn test_symbols_and_display() {
+        assert_eq!(EngineeringUnits::DegreesCelsius.symbol(), "°C");
+        assert_eq!(EngineeringUnits::KilowattHours.to_string(), "kWh");
+        assert_eq!(EngineeringUnits::CandelasPerSquareMeter.symbol(), "cd/m²");
+        assert_eq!(EngineeringUnits::NoUnits.to_string(), "");
+        assert_eq!(EngineeringUnits::Other(300).to_string(), "units(300)");
+    }
+
+    #[test]
+    fn test_conversion_never_fails() {
+        assert_eq!(
+            EngineeringUnits::from(47817u32),
+            EngineeringUnits::AlcoholByVolume
+        );
+        assert_eq!(EngineeringUnits::from(256u32), EngineeringUnits::Other(256));
+        assert_eq!(
+            u32::from(EngineeringUnits::EuropeanBrewingConvention),
+            47822
+        );
+        assert_eq!(EngineeringUnits::DegreesPlato.category(), "Brewing");
+    }
 }
@@ -0,0 +1,690 @@
+//! Event Enrollment Object Implementation
+//!
+//! This module implements the Event Enrollment object type as defined in
+//! ASHRAE 135. An Event Enrollment applies an event algorithm to a property
+//! of another object, giving algorithmic reporting to objects without
+//! intrinsic reporting. The resulting transitions are routed through the
+//! notification class the enrollment names.
+//!
+//! The out-of-range and change-of-state algorithms are implemented; other
+//! event types can be described by [`EventType`] but are not evaluated.
+
+use crate::datatypes::Clock;
+use crate::encoding::{
+    self,
+    advanced::context::{encode_closing_tag, encode_opening_tag},
+    EncodingError,
+};
+use crate::object::{
+    constructed_value, trend_log::ObjectPropertyReference, validate_description, write_rejected,
+    BacnetObject, EventState, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier,
+    PropertyValue, Result,
+};
+use crate::service::{encode_context_value, NotifyType};
+
+use core::time::Duration;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// BACnetEventType: the event algorithm of an enrollment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum EventType {
+    ChangeOfBitstring = 0,
+    ChangeOfState = 1,
+    ChangeOfValue = 2,
+    CommandFailure = 3,
+    FloatingLimit = 4,
+    OutOfRange = 5,
+    ChangeOfLifeSafety = 8,
+    Extended = 9,
+    BufferReady = 10,
+    UnsignedRange = 11,
+}
+
+/// Parameters of the event algorithm applied to the monitored value
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EventParameters {
+    /// Offnormal while the value equals one of `alarm_values`
+    ChangeOfState {
+        /// Seconds a new state must hold before the transition
+        time_delay: u32,
+        /// Values that are offnormal
+        alarm_values: Vec<PropertyValue>,
+    },
+    /// High or low limit while a Real value is outside the limits
+    OutOfRange {
+        /// Seconds a new state must hold before the transition
+        time_delay: u32,
+        /// Values below this are in low limit alarm
+        low_limit: f32,
+        /// Values above this are in high limit alarm
+        high_limit: f32,
+        /// Margin inside a limit the value must return by to clear it
+        deadband: f32,
+    },
+}
+
+impl EventParameters {
+    /// Event type these parameters belong to
+    pub fn event_type(&self) -> EventType {
+        match self {
+            EventParameters::ChangeOfState { .. } => EventType::ChangeOfState,
+            EventParameters::OutOfRange { .. } => EventType::OutOfRange,
+        }
+    }
+
+    /// Seconds a new state must hold before the transition
+    pub fn time_delay(&self) -> u32 {
+        match self {
+            EventParameters::ChangeOfState { time_delay, .. }
+            | EventParameters::OutOfRange { time_delay, .. } => *time_delay,
+        }
+    }
+
+    /// Encode as a BACnetEventParameter
+    ///
+    /// Change-of-state alarm values are sent as the boolean, binary-value
+    /// or unsigned-value choice of BACnetPropertyStates, matching their type.
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        let tag_number = self.event_type() as u8;
+        encode_opening_tag(buffer, tag_number)?;
+        match self {
+            EventParameters::ChangeOfState {
+                time_delay,
+                alarm_values,
+            } => {
+                encode_context_value(buffer, 0, &PropertyValue::UnsignedInteger(*time_delay))?;
+                encode_opening_tag(buffer, 1)?;
+                for value in alarm_values {
+                    let choice = match value {
+                        PropertyValue::Boolean(_) => 0,
+                        PropertyValue::Enumerated(_) => 1,
+                        PropertyValue::UnsignedInteger(_) => 11,
+                        _ => {
+                            return Err(EncodingError::InvalidFormat(
+                                "Alarm value is not a property state".to_string(),
+                            ))
+                        }
+                    };
+                    encode_context_value(buffer, choice, value)?;
+                }
+                encode_closing_tag(buffer, 1)?;
+            }
+            EventParameters::OutOfRange {
+                time_delay,
+                low_limit,
+                high_limit,
+                deadband,
+            } => {
+                encode_context_value(buffer, 0, &PropertyValue::UnsignedInteger(*time_delay))?;
+                encode_context_value(buffer, 1, &PropertyValue::Real(*low_limit))?;
+                encode_context_value(buffer, 2, &PropertyValue::Real(*high_limit))?;
+                encode_context_value(buffer, 3, &PropertyValue::Real(*deadband))?;
+            }
+        }
+        encode_closing_tag(buffer, tag_number)
+    }
+}
+
+/// Event Enrollment object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventEnrollment {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Description
+    pub description: String,
+    /// Event algorithm and its parameters
+    pub event_parameters: EventParameters,
+    /// Property whose value the algorithm monitors
+    pub object_property_reference: ObjectPropertyReference,
+    /// Current event state
+    pub event_state: EventState,
+    /// Whether transitions are reported as alarms or events
+    pub notify_type: NotifyType,
+    /// To-offnormal, to-fault and to-normal transitions reported
+    pub event_enable: [bool; 3],
+    /// Notification class that routes the transitions
+    pub notification_class: u32,
+    /// Seconds a return to normal must persist, when it differs from the
+    /// time delay of the event parameters
+    pub time_delay_normal: Option<u32>,
+    /// State waiting out the time delay, and when it was first seen
+    pending_event: Option<(EventState, Duration)>,
+}
+
+impl EventEnrollment {
+    /// Create a new Event Enrollment monitoring a property
+    pub fn new(
+        instance: u32,
+        object_name: String,
+        object_property_reference: ObjectPropertyReference,
+        event_parameters: EventParameters,
+        notification_class: u32,
+    ) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::EventEnrollment, instance),
+            object_name,
+            description: String::new(),
+            event_parameters,
+            object_property_reference,
+            event_state: EventState::Normal,
+            notify_type: NotifyType::Alarm,
+            event_enable: [true; 3],
+            notification_class,
+            time_delay_normal: None,
+            pending_event: None,
+        }
+    }
+
+    /// Event type of the configured algorithm
+    pub fn event_type(&self) -> EventType {
+        self.event_parameters.event_type()
+    }
+
+    /// Check if this enrollment monitors a property of an object
+    pub fn monitors(&self, object: ObjectIdentifier, property: PropertyIdentifier) -> bool {
+        self.object_property_reference.object_identifier == object
+            && self.object_property_reference.property_identifier == property
+    }
+
+    /// Run the event algorithm on an update of the monitored value
+    ///
+    /// `now` is a monotonic timestamp. A new state must hold for the time
+    /// delay before the event state changes, and a return to normal for
+    /// `time_delay_normal` when set; a value of the wrong datatype
+    /// puts the enrollment in fault immediately. Returns the new event state
+    /// when a transition occurs.
+    pub fn update_monitored_value(
+        &mut self,
+        value: &PropertyValue,
+        now: Duration,
+    ) -> Option<EventState> {
+        let target = self.target_event_state(value);
+        if target == self.event_state {
+            self.pending_event = None;
+            return None;
+        }
+
+        if target != EventState::Fault && self.event_state != EventState::Fault {
+            let since = match self.pending_event {
+                Some((pending, since)) if pending == target => since,
+                _ => {
+                    self.pending_event = Some((target, now));
+                    now
+                }
+            };
+            let time_delay = match target {
+                EventState::Normal => self
+                    .time_delay_normal
+                    .unwrap_or(self.event_parameters.time_delay()),
+                _ => self.event_parameters.time_delay(),
+            };
+            if now.saturating_sub(since) < Duration::from_secs(time_delay as u64) {
+                return None;
+            }
+        }
+
+        self.pending_event = None;
+        self.event_state = target;
+        Some(target)
+    }
+
+    /// Run the event algorithm at the time of a clock
+    ///
+    /// Time delays are measured between readings of the clock. Nothing
+    /// happens while the clock has no valid date and time.
+    ///
+    /// The clock is wall time, not monotonic: setting it back, as a time
+    /// synchronization may, holds a pending transition until the clock
+    /// catches up, and setting it forward cuts the delay short. Pass a
+    /// monotonic timestamp to [`update_monitored_value`](Self::update_monitored_value) where
+    /// that matters.
+    pub fn update_monitored_value_from(
+        &mut self,
+        value: &PropertyValue,
+        clock: &dyn Clock,
+    ) -> Option<EventState> {
+        let now = clock.now().since_1900()?;
+        self.update_monitored_value(value, now)
+    }
+
+    /// Event state the monitored value calls for
+    fn target_event_state(&self, value: &PropertyValue) -> EventState {
+        match &self.event_parameters {
+            EventParameters::ChangeOfState { alarm_values, .. } => {
+                match alarm_values
+                    .iter()
+                    .map(|alarm| same_state(alarm, value))
+                    .try_fold(false, |found, matches| Some(found || matches?))
+                {
+                    Some(true) => EventState::Offnormal,
+                    Some(false) => EventState::Normal,
+                    None => EventState::Fault,
+                }
+            }
+            EventParameters::OutOfRange {
+                low_limit,
+                high_limit,
+                deadband,
+                ..
+            } => {
+                let PropertyValue::Real(value) = *value else {
+                    return EventState::Fault;
+                };
+                self.event_state
+                    .out_of_range(value, Some(*low_limit), Some(*high_limit), *deadband)
+            }
+        }
+    }
+}
+
+/// Compare a change-of-state alarm value with the monitored value
+///
+/// Returns `None` when the value is not of the alarm value's datatype.
+fn same_state(alarm: &PropertyValue, value: &PropertyValue) -> Option<bool> {
+    match (alarm, value) {
+        (PropertyValue::Boolean(alarm), PropertyValue::Boolean(value)) => Some(alarm == value),
+        (PropertyValue::Enumerated(alarm), PropertyValue::Enumerated(value))
+        | (PropertyValue::UnsignedInteger(alarm), PropertyValue::UnsignedInteger(value)) => {
+            Some(alarm == value)
+        }
+        _ => None,
+    }
+}
+
+impl BacnetObject for EventEnrollment {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::EventEnrollment.as_u16() as u32,
+            )),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::EventType => {
+                Ok(PropertyValue::Enumerated(self.event_type() as u32))
+            }
+            PropertyIdentifier::NotifyType => {
+                Ok(PropertyValue::Enumerated(self.notify_type as u32))
+            }
+            PropertyIdentifier::EventParameters => {
+                constructed_value(|buffer| self.event_parameters.encode(buffer))
+            }
+            PropertyIdentifier::ObjectPropertyReference => {
+                constructed_value(|buffer| self.object_property_reference.encode(buffer))
+            }
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::EventEnable => {
+                Ok(PropertyValue::BitString(self.event_enable.to_vec()))
+            }
+            PropertyIdentifier::NotificationClass => {
+                Ok(PropertyValue::UnsignedInteger(self.notification_class))
+            }
+            PropertyIdentifier::TimeDelayNormal => self
+                .time_delay_normal
+                .map(PropertyValue::UnsignedInteger)
+                .ok_or(ObjectError::UnknownProperty),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::ObjectName, PropertyValue::CharacterString(name)) => {
+                self.object_name = name;
+                Ok(())
+            }
+            (PropertyIdentifier::Description, PropertyValue::CharacterString(description)) => {
+                validate_description(&description)?;
+                self.description = description;
+                Ok(())
+            }
+            (PropertyIdentifier::NotifyType, PropertyValue::Enumerated(value)) => {
+                self.notify_type = NotifyType::try_from(value).map_err(|_| {
+                    ObjectError::InvalidValue(format!("Unknown notify type {}", value))
+                })?;
+                Ok(())
+            }
+            (PropertyIdentifier::EventEnable, PropertyValue::BitString(bits)) => {
+                let bit = |n: usize| bits.get(n).copied().unwrap_or(false);
+                self.event_enable = [bit(0), bit(1), bit(2)];
+                Ok(())
+            }
+            (PropertyIdentifier::NotificationClass, PropertyValue::UnsignedInteger(class)) => {
+                self.notification_class = class;
+                Ok(())
+            }
+            (PropertyIdentifier::TimeDelayNormal, PropertyValue::UnsignedInteger(seconds)) => {
+                self.time_delay_normal = Some(seconds);
+                Ok(())
+            }
+            (property, _) if self.is_property_writable(property) => {
+                Err(ObjectError::InvalidPropertyType)
+            }
+            (property, _) => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::NotifyType
+                | PropertyIdentifier::EventEnable
+                | PropertyIdentifier::NotificationClass
+                | PropertyIdentifier::TimeDelayNormal
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        let mut properties = vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::EventType,
+            PropertyIdentifier::NotifyType,
+            PropertyIdentifier::EventParameters,
+            PropertyIdentifier::ObjectPropertyReference,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::EventEnable,
+            PropertyIdentifier::NotificationClass,
+        ];
+        if self.time_delay_normal.is_some() {
+            properties.push(PropertyIdentifier::TimeDelayNormal);
+        }
+        properties
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reference(object_type: ObjectType) -> ObjectPropertyReference {
+        ObjectPropertyReference::new(
+            ObjectIdentifier::new(object_type, 1),
+            PropertyIdentifier::PresentValue,
+        )
+    }
+
+    #[test]
+    fn test_out_of_range_algorithm() {
+        let mut enrollment = EventEnrollment::new(
+            1,
+            "Zone temperature".to_string(),
+            reference(ObjectType::AnalogValue),
+            EventParameters::OutOfRange {
+                time_delay: 10,
+                low_limit: 15.0,
+                high_limit: 25.0,
+                deadband: 1.0,
+            },
+            5,
+        );
+        assert_eq!(enrollment.event_type(), EventType::OutOfRange);
+        assert!(enrollment.monitors(
+            ObjectIdentifier::new(ObjectType::AnalogValue, 1),
+            PropertyIdentifier::PresentValue
+        ));
+
+        let at = Duration::from_secs;
+        let real = PropertyValue::Real;
+        // The limit must be exceeded for the whole time delay
+        assert_eq!(enrollment.update_monitored_value(&real(26.0), at(0)), None);
+        assert_eq!(enrollment.update_monitored_value(&real(26.5), at(9)), None);
+        assert_eq!(
+            enrollment.update_monitored_value(&real(26.5), at(10)),
+            Some(EventState::HighLimit)
+        );
+
+        // Inside the deadband the alarm holds
+        assert_eq!(enrollment.update_monitored_value(&real(24.5), at(20)), None);
+        assert_eq!(enrollment.update_monitored_value(&real(23.5), at(30)), None);
+        assert_eq!(
+            enrollment.update_monitored_value(&real(23.5), at(40)),
+            Some(EventState::Normal)
+        );
+
+        // A value of the wrong datatype is a fault straight away
+        assert_eq!(
+            enrollment.update_monitored_value(&PropertyValue::Boolean(true), at(41)),
+            Some(EventState::Fault)
+        );
+    }
+
+    #[test]
+    fn test_event_enrollment_listed_properties_are_readable() {
+        let enrollment = EventEnrollment::new(
+            1,
+            "Zone temperature".to_string(),
+            reference(ObjectType::AnalogValue),
+            EventParameters::OutOfRange {
+                time_delay: 10,
+                low_limit: 15.0,
+                high_limit: 25.0,
+                deadband: 1.0,
+            },
+            5,
+        );
+        for property in enrollment.property_list() {
+            assert!(
+                enrollment.get_property(property).is_ok(),
+                "{:?} is listed but not readable",
+                property
+            );
+        }
+
+        match enrollment
+            .get_property(PropertyIdentifier::EventParameters)
+            .unwrap()
+        {
+            PropertyValue::Constructed(data) => assert_eq!(
+                data,
+                [
+                    0x5E, 0x09, 0x0A, 0x1C, 0x41, 0x70, 0x00, 0x00, 0x2C, 0x41, 0xC8, 0x00, 0x00,
+                    0x3C, 0x3F, 0x80, 0x00, 0x00, 0x5F,
+                ]
+            ),
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+        match enrollment
+            .get_property(PropertyIdentifier::ObjectPropertyReference)
+            .unwrap()
+        {
+            PropertyValue::Constructed(data) => {
+                assert_eq!(data, [0x0C, 0x00, 0x80, 0x00, 0x01, 0x19, 0x55])
+            }
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+
+        let parameters = EventParameters::ChangeOfState {
+            time_delay: 0,
+            alarm_values: vec![PropertyValue::Enumerated(1), PropertyValue::Real(1.0)],
+        };
+        assert!(parameters.encode(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_deadband_hysteresis() {
+        let mut enrollment = EventEnrollment::new(
+            3,
+            "Chilled water supply".to_string(),
+            reference(ObjectType::AnalogInput),
+            EventParameters::OutOfRange {
+                time_delay: 0,
+                low_limit: 4.0,
+                high_limit: 10.0,
+                deadband: 0.5,
+            },
+            5,
+        );
+
+        let mut transitions = Vec::new();
+        for (t, value) in [9.9, 10.1, 9.8, 10.05, 9.6, 10.2, 9.55, 9.4, 9.9, 10.0]
+            .into_iter()
+            .enumerate()
+        {
+            let now = Duration::from_secs(t as u64);
+            if let Some(state) = enrollment.update_monitored_value(&PropertyValue::Real(value), now)
+            {
+                transitions.push((t, state));
+            }
+        }
+
+        // Raised at 10.1, held within 9.5..10, cleared at 9.4 and not
+        // raised again until the limit itself is exceeded
+        assert_eq!(
+            transitions,
+            [(1, EventState::HighLimit), (7, EventState::Normal)]
+        );
+    }
+
+    #[test]
+    fn test_time_delay_normal_with_manual_clock() {
+        use crate::datatypes::ManualClock;
+        use crate::object::{Date, Time};
+        use crate::service::BacnetDateTime;
+
+        let clock = ManualClock::new(BacnetDateTime::new(
+            Date {
+                year: 2025,
+                month: 1,
+                day: 15,
+                weekday: 3,
+            },
+            Time {
+                hour: 8,
+                minute: 0,
+                second: 0,
+                hundredths: 0,
+            },
+        ));
+        let mut enrollment = EventEnrollment::new(
+            4,
+            "Freezer temperature".to_string(),
+            reference(ObjectType::AnalogInput),
+            EventParameters::OutOfRange {
+                time_delay: 120,
+                low_limit: -30.0,
+                high_limit: -15.0,
+                deadband: 1.0,
+            },
+            5,
+        );
+        enrollment
+            .set_property(
+                PropertyIdentifier::TimeDelayNormal,
+                PropertyValue::UnsignedInteger(300),
+            )
+            .unwrap();
+        let real = PropertyValue::Real;
+        let minutes = |m: u64| Duration::from_secs(m * 60);
+
+        // A door opening for a minute is not an alarm
+        assert_eq!(
+            enrollment.update_monitored_value_from(&real(-10.0), &clock),
+            None
+        );
+        clock.advance(minutes(1));
+        assert_eq!(
+            enrollment.update_monitored_value_from(&real(-20.0), &clock),
+            None
+        );
+        clock.advance(minutes(2));
+        assert_eq!(
+            enrollment.update_monitored_value_from(&real(-20.0), &clock),
+            None
+        );
+
+        assert_eq!(
+            enrollment.update_monitored_value_from(&real(-12.0), &clock),
+            None
+        );
+        clock.advance(minutes(2));
+        assert_eq!(
+            enrollment.update_monitored_value_from(&real(-12.0), &clock),
+            Some(EventState::HighLimit)
+        );
+
+        // Normal only after Time_Delay_Normal, not the two minute delay
+        assert_eq!(
+            enrollment.update_monitored_value_from(&real(-20.0), &clock),
+            None
+        );
+        clock.advance(minutes(2));
+        assert_eq!(
+            enrollment.update_monitored_value_from(&real(-20.0), &clock),
+            None
+        );
+        clock.advance(minutes(3));
+        assert_eq!(
+            enrollment.update_monitored_value_from(&real(-20.0), &clock),
+            Some(EventState::Normal)
+        );
+        assert!(matches!(
+            enrollment.get_property(PropertyIdentifier::TimeDelayNormal),
+            Ok(PropertyValue::UnsignedInteger(300))
+        ));
+    }
+
+    #[test]
+    fn test_change_of_state_algorithm() {
+        let mut enrollment = EventEnrollment::new(
+            2,
+            "Fan status".to_string(),
+            reference(ObjectType::MultiStateInput),
+            EventParameters::ChangeOfState {
+                time_delay: 0,
+                alarm_values: vec![PropertyValue::Enumerated(3), PropertyValue::Enumerated(4)],
+            },
+            5,
+        );
+        assert!(matches!(
+            enrollment.get_property(PropertyIdentifier::EventType),
+            Ok(PropertyValue::Enumerated(1))
+        ));
+
+        let at = Duration::from_secs;
+        let state = PropertyValue::Enumerated;
+        assert_eq!(enrollment.update_monitored_value(&state(1), at(0)), None);
+        assert_eq!(
+            enrollment.update_monitored_value(&state(4), at(1)),
+            Some(EventState::Offnormal)
+        );
+        assert_eq!(enrollment.update_monitored_value(&state(3), at(2)), None);
+        assert_eq!(
+            enrollment.update_monitored_value(&state(2), at(3)),
+            Some(EventState::Normal)
+        );
+        assert!(matches!(
+            enrollment.get_property(PropertyIdentifier::EventState),
+            Ok(PropertyValue::Enumerated(0))
+        ));
+    }
+}
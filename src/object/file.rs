@@ -2,18 +2,29 @@
 //!
 //! This module implements the File object type as defined in ASHRAE 135.
 //! File objects represent files that can be accessed using the AtomicReadFile
-//! and AtomicWriteFile services.
+//! and AtomicWriteFile services. [`File::atomic_read`] and
+//! [`File::atomic_write`] serve those requests from the in-memory contents, so
+//! a device can take part in backup and restore.
 
 use crate::object::{
-    BacnetObject, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue,
-    Result,
+    write_rejected, BacnetObject, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier,
+    PropertyValue, Result,
+};
+use crate::service::{
+    self, AtomicReadFileRequest, AtomicReadFileResponse, AtomicWriteFileRequest,
+    AtomicWriteFileResponse, FileWriteAccessMethod,
 };
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 /// File access method enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum FileAccessMethod {
     RecordAccess = 0,
@@ -22,6 +33,7 @@ pub enum FileAccessMethod {
 
 /// File object implementation
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct File {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -86,7 +98,7 @@ impl File {
     /// Read data from file at specified position
     pub fn read_data(&self, start_position: u32, requested_count: u32) -> Result<Vec<u8>> {
         let start = start_position as usize;
-        let end = (start_position + requested_count) as usize;
+        let end = start_position.saturating_add(requested_count) as usize;
 
         if start >= self.file_data.len() {
             return Ok(Vec::new()); // EOF
@@ -97,12 +109,18 @@ impl File {
     }
 
     /// Write data to file at specified position
+    ///
+    /// The position may be at most the current length, so a write can
+    /// overwrite or append but never leave a gap.
     pub fn write_data(&mut self, start_position: u32, data: &[u8]) -> Result<()> {
         if self.read_only {
             return Err(ObjectError::WriteAccessDenied);
         }
 
         let start = start_position as usize;
+        if start > self.file_data.len() {
+            return Err(ObjectError::InvalidFileStartPosition);
+        }
         let data_len = data.len();
         let required_len = start + data_len;
 
@@ -135,7 +153,7 @@ impl File {
         let lines: Vec<&str> = file_str.lines().collect();
 
         let start_idx = start_record as usize;
-        let end_idx = (start_record + record_count) as usize;
+        let end_idx = start_record.saturating_add(record_count) as usize;
 
         for line in lines.iter().take(end_idx.min(lines.len())).skip(start_idx) {
             records.push(line.as_bytes().to_vec());
@@ -145,6 +163,9 @@ impl File {
     }
 
     /// Write records to file (for record access method)
+    ///
+    /// The start record may be at most the current record count, so records
+    /// are overwritten or appended but never padded with empty ones.
     pub fn write_records(&mut self, start_record: u32, records: &[Vec<u8>]) -> Result<()> {
         if self.read_only {
             return Err(ObjectError::WriteAccessDenied);
@@ -162,6 +183,9 @@ impl File {
         let mut lines: Vec<String> = file_str.lines().map(|s| s.to_string()).collect();
 
         let start_idx = start_record as usize;
+        if start_idx > lines.len() {
+            return Err(ObjectError::InvalidFileStartPosition);
+        }
 
         // Extend lines vector if necessary
         while lines.len() < start_idx + records.len() {
@@ -182,6 +206,111 @@ impl File {
 
         Ok(())
     }
+
+    /// Number of records held, for the record access method
+    fn records_held(&self) -> u32 {
+        String::from_utf8_lossy(&self.file_data).lines().count() as u32
+    }
+
+    /// Check the request uses the file's access method
+    fn check_access_method(&self, record_access: bool) -> Result<()> {
+        let expected = if record_access {
+            FileAccessMethod::RecordAccess
+        } else {
+            FileAccessMethod::StreamAccess
+        };
+        if self.file_access_method != expected {
+            return Err(ObjectError::InvalidValue(
+                "Request does not match the file access method".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Serve an AtomicReadFile request
+    pub fn atomic_read(&self, request: &AtomicReadFileRequest) -> Result<AtomicReadFileResponse> {
+        if request.file_identifier != self.identifier {
+            return Err(ObjectError::InstanceNotFound);
+        }
+        match request.access_method {
+            service::FileAccessMethod::StreamAccess {
+                file_start_position,
+                requested_octet_count,
+            } => {
+                self.check_access_method(false)?;
+                let start = u32::try_from(file_start_position).map_err(|_| {
+                    ObjectError::InvalidValue("Negative file start position".to_string())
+                })?;
+                let data = self.read_data(start, requested_octet_count)?;
+                let end_of_file = start as usize + data.len() >= self.file_data.len();
+                Ok(AtomicReadFileResponse::new_stream_access(
+                    end_of_file,
+                    file_start_position,
+                    data,
+                ))
+            }
+            service::FileAccessMethod::RecordAccess {
+                file_start_record,
+                requested_record_count,
+            } => {
+                self.check_access_method(true)?;
+                let start = u32::try_from(file_start_record).map_err(|_| {
+                    ObjectError::InvalidValue("Negative file start record".to_string())
+                })?;
+                let records = self.read_records(start, requested_record_count)?;
+                let end_of_file = start + records.len() as u32 >= self.records_held();
+                Ok(AtomicReadFileResponse::new_record_access(
+                    end_of_file,
+                    file_start_record,
+                    records,
+                ))
+            }
+        }
+    }
+
+    /// Serve an AtomicWriteFile request
+    ///
+    /// A start position or record of -1 appends to the file. The response
+    /// echoes where the data was actually written.
+    pub fn atomic_write(
+        &mut self,
+        request: &AtomicWriteFileRequest,
+    ) -> Result<AtomicWriteFileResponse> {
+        if request.file_identifier != self.identifier {
+            return Err(ObjectError::InstanceNotFound);
+        }
+        match &request.access_method {
+            FileWriteAccessMethod::StreamAccess {
+                file_start_position,
+                file_data,
+            } => {
+                self.check_access_method(false)?;
+                let start = match *file_start_position {
+                    -1 => self.file_data.len() as u32,
+                    start => u32::try_from(start).map_err(|_| {
+                        ObjectError::InvalidValue("Invalid file start position".to_string())
+                    })?,
+                };
+                self.write_data(start, file_data)?;
+                Ok(AtomicWriteFileResponse::new_stream_access(start as i32))
+            }
+            FileWriteAccessMethod::RecordAccess {
+                file_start_record,
+                file_record_data,
+                ..
+            } => {
+                self.check_access_method(true)?;
+                let start = match *file_start_record {
+                    -1 => self.records_held(),
+                    start => u32::try_from(start).map_err(|_| {
+                        ObjectError::InvalidValue("Invalid file start record".to_string())
+                    })?,
+                };
+                self.write_records(start, file_record_data)?;
+                Ok(AtomicWriteFileResponse::new_record_access(start as i32))
+            }
+        }
+    }
 }
 
 impl BacnetObject for File {
@@ -198,9 +327,28 @@ impl BacnetObject for File {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
             PropertyIdentifier::ObjectType => {
-                Ok(PropertyValue::Enumerated(ObjectType::File as u32))
+                Ok(PropertyValue::Enumerated(ObjectType::File.as_u16() as u32))
             }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::FileType => {
+                Ok(PropertyValue::CharacterString(self.file_type.clone()))
+            }
+            PropertyIdentifier::FileSize => Ok(PropertyValue::UnsignedInteger(self.file_size)),
+            PropertyIdentifier::ModificationDate => Ok(PropertyValue::Date(self.modification_date)),
             PropertyIdentifier::Archive => Ok(PropertyValue::Boolean(self.archive)),
+            PropertyIdentifier::ReadOnly => Ok(PropertyValue::Boolean(self.read_only)),
+            PropertyIdentifier::FileAccessMethod => {
+                Ok(PropertyValue::Enumerated(self.file_access_method as u32))
+            }
+            PropertyIdentifier::RecordCount
+                if self.file_access_method == FileAccessMethod::RecordAccess =>
+            {
+                Ok(PropertyValue::UnsignedInteger(
+                    self.record_count.unwrap_or_else(|| self.records_held()),
+                ))
+            }
             _ => Err(ObjectError::UnknownProperty),
         }
     }
@@ -223,7 +371,7 @@ impl BacnetObject for File {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            _ => Err(write_rejected(self, property)),
         }
     }
 
@@ -235,12 +383,22 @@ impl BacnetObject for File {
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::FileType,
+            PropertyIdentifier::FileSize,
+            PropertyIdentifier::ModificationDate,
             PropertyIdentifier::Archive,
-        ]
+            PropertyIdentifier::ReadOnly,
+            PropertyIdentifier::FileAccessMethod,
+        ];
+        if self.file_access_method == FileAccessMethod::RecordAccess {
+            properties.push(PropertyIdentifier::RecordCount);
+        }
+        properties
     }
 }
 
@@ -328,6 +486,87 @@ mod tests {
         assert!(file.archive);
     }
 
+    #[test]
+    fn test_atomic_file_services() {
+        let mut file = File::new(
+            1,
+            "backup.bin".to_string(),
+            "application/octet-stream".to_string(),
+        );
+        file.set_file_data(b"0123456789".to_vec());
+
+        let request = AtomicReadFileRequest::new_stream_access(file.identifier, 6, 10);
+        let response = file.atomic_read(&request).unwrap();
+        assert_eq!(
+            response,
+            AtomicReadFileResponse::new_stream_access(true, 6, b"6789".to_vec())
+        );
+
+        // Appending echoes the actual start position
+        let request =
+            AtomicWriteFileRequest::new_stream_access(file.identifier, -1, b"AB".to_vec());
+        let response = file.atomic_write(&request).unwrap();
+        assert_eq!(response, AtomicWriteFileResponse::new_stream_access(10));
+        assert_eq!(file.get_file_data(), b"0123456789AB");
+
+        // Record access is rejected on a stream access file
+        let request = AtomicReadFileRequest::new_record_access(file.identifier, 0, 1);
+        assert!(file.atomic_read(&request).is_err());
+
+        file.file_access_method = FileAccessMethod::RecordAccess;
+        file.set_file_data(b"first\nsecond".to_vec());
+        let request =
+            AtomicWriteFileRequest::new_record_access(file.identifier, -1, vec![b"third".to_vec()]);
+        let response = file.atomic_write(&request).unwrap();
+        assert_eq!(response, AtomicWriteFileResponse::new_record_access(2));
+
+        let request = AtomicReadFileRequest::new_record_access(file.identifier, 1, 5);
+        let response = file.atomic_read(&request).unwrap();
+        assert_eq!(
+            response,
+            AtomicReadFileResponse::new_record_access(
+                true,
+                1,
+                vec![b"second".to_vec(), b"third".to_vec()]
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_past_end_of_file_rejected() {
+        let mut file = File::new(
+            1,
+            "backup.bin".to_string(),
+            "application/octet-stream".to_string(),
+        );
+        file.set_file_data(b"0123".to_vec());
+
+        // A start position past EOF must not grow the file
+        let request =
+            AtomicWriteFileRequest::new_stream_access(file.identifier, i32::MAX, b"AB".to_vec());
+        assert!(matches!(
+            file.atomic_write(&request),
+            Err(ObjectError::InvalidFileStartPosition)
+        ));
+        assert_eq!(file.get_file_data(), b"0123");
+
+        // Writing exactly at EOF appends
+        file.write_data(4, b"45").unwrap();
+        assert_eq!(file.get_file_data(), b"012345");
+
+        file.file_access_method = FileAccessMethod::RecordAccess;
+        file.set_file_data(b"first\nsecond".to_vec());
+        let request =
+            AtomicWriteFileRequest::new_record_access(file.identifier, 3, vec![b"x".to_vec()]);
+        assert!(matches!(
+            file.atomic_write(&request),
+            Err(ObjectError::InvalidFileStartPosition)
+        ));
+        assert_eq!(file.records_held(), 2);
+        file.write_records(2, &[b"third".to_vec()]).unwrap();
+        assert_eq!(file.records_held(), 3);
+    }
+
     #[test]
     fn test_read_only_protection() {
         let mut file = File::new(1, "readonly.txt".to_string(), "text/plain".to_string());
@@ -0,0 +1,395 @@
+//! Large Analog Value Object Implementation
+//!
+//! This module implements the Large Analog Value object type as defined in
+//! ASHRAE 135. A Large Analog Value is a commandable software variable
+//! holding a double precision value, for quantities such as lifetime energy
+//! totals whose range or resolution exceeds a 32-bit Real.
+
+use crate::object::{
+    analog::{EventState, Reliability},
+    callback::PropertyCallbacks,
+    engineering_units::EngineeringUnits,
+    priority::PriorityArray,
+    status_flags::StatusFlags,
+    units_value, validate_description, write_rejected, BacnetObject, ObjectError, ObjectIdentifier,
+    ObjectType, PropertyIdentifier, PropertyValue, Result,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Large Analog Value object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LargeAnalogValue {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Present value
+    pub present_value: f64,
+    /// Description
+    pub description: String,
+    /// Status flags
+    pub status_flags: StatusFlags,
+    /// Event state
+    pub event_state: EventState,
+    /// Reliability
+    pub reliability: Reliability,
+    /// Out of service
+    pub out_of_service: bool,
+    /// Units
+    pub units: EngineeringUnits,
+    /// Priority array (16 levels)
+    pub priority_array: PriorityArray<f64>,
+    /// Relinquish default
+    pub relinquish_default: f64,
+    /// COV increment
+    pub cov_increment: Option<f64>,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+    /// Present value at the last change of value report
+    cov_reported_value: f64,
+}
+
+impl LargeAnalogValue {
+    /// Create a new Large Analog Value object
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::LargeAnalogValue, instance),
+            object_name,
+            present_value: 0.0,
+            description: String::new(),
+            status_flags: StatusFlags::default(),
+            event_state: EventState::Normal,
+            reliability: Reliability::NoFaultDetected,
+            out_of_service: false,
+            units: EngineeringUnits::NoUnits,
+            priority_array: PriorityArray::new(),
+            relinquish_default: 0.0,
+            cov_increment: None,
+            callbacks: PropertyCallbacks::new(),
+            cov_reported_value: 0.0,
+        }
+    }
+
+    /// Write to priority array at specified priority level (1-16)
+    pub fn write_priority(&mut self, priority: u8, value: Option<f64>) -> Result<()> {
+        self.priority_array.set(priority, value)?;
+        self.update_present_value();
+        Ok(())
+    }
+
+    /// Update present value based on priority array
+    ///
+    /// Present_Value callbacks act as the change of value hook: they fire
+    /// once the value has moved by at least the COV increment since the last
+    /// report, or on any change when no increment is set.
+    fn update_present_value(&mut self) {
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
+        let delta = (self.present_value - self.cov_reported_value).abs();
+        let reportable = match self.cov_increment {
+            Some(increment) => delta > 0.0 && delta >= increment,
+            None => delta > 0.0,
+        };
+        if reportable {
+            self.report_cov();
+        }
+    }
+
+    /// Notify Present_Value callbacks of a change of value report
+    fn report_cov(&mut self) {
+        let reported = core::mem::replace(&mut self.cov_reported_value, self.present_value);
+        self.callbacks.notify(
+            self.identifier,
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Double(reported),
+            PropertyValue::Double(self.present_value),
+        );
+    }
+
+    /// Get the effective priority level for current present value
+    pub fn get_effective_priority(&self) -> Option<u8> {
+        self.priority_array.effective_priority()
+    }
+
+    /// Set the relinquish default and recompute the present value
+    pub fn set_relinquish_default(&mut self, value: f64) {
+        self.relinquish_default = value;
+        self.update_present_value();
+    }
+
+    /// Set the reliability, updating the fault status flag
+    pub fn set_reliability(&mut self, reliability: Reliability) {
+        self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::Reliability,
+            &mut self.reliability,
+            reliability,
+        );
+        self.update_status_flags();
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        let flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+        self.replace_status_flags(flags);
+    }
+
+    /// Store new status flags, notifying callbacks if any bit changed
+    fn replace_status_flags(&mut self, flags: StatusFlags) {
+        let changed = self.callbacks.replace(
+            self.identifier,
+            PropertyIdentifier::StatusFlags,
+            &mut self.status_flags,
+            flags,
+        );
+        if changed {
+            // A status flag change is reported with the current value
+            self.report_cov();
+        }
+    }
+}
+
+impl BacnetObject for LargeAnalogValue {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::LargeAnalogValue.as_u16() as u32,
+            )),
+            PropertyIdentifier::PresentValue => Ok(PropertyValue::Double(self.present_value)),
+            PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::PriorityArray => Ok(PropertyValue::Array(
+                self.priority_array
+                    .to_property_values(PropertyValue::Double),
+            )),
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::Double(self.relinquish_default))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::Units => Ok(PropertyValue::Enumerated(u32::from(self.units))),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::CovIncrement => self
+                .cov_increment
+                .map(PropertyValue::Double)
+                .ok_or(ObjectError::UnknownProperty),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match property {
+            PropertyIdentifier::ObjectName => {
+                if let PropertyValue::CharacterString(name) = value {
+                    self.object_name = name;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::PresentValue => self.write_property(property, value, None),
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::RelinquishDefault => {
+                if let PropertyValue::Double(val) = value {
+                    self.set_relinquish_default(val);
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Units => {
+                self.units = units_value(value)?;
+                Ok(())
+            }
+            PropertyIdentifier::CovIncrement => match value {
+                PropertyValue::Double(increment) if increment >= 0.0 => {
+                    self.cov_increment = Some(increment);
+                    Ok(())
+                }
+                PropertyValue::Double(_) => Err(ObjectError::InvalidValue(
+                    "COV increment must not be negative".to_string(),
+                )),
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn write_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        if property != PropertyIdentifier::PresentValue {
+            return self.set_property(property, value);
+        }
+        // Writes without a priority use the lowest priority (16)
+        let priority = priority.unwrap_or(16);
+        match value {
+            PropertyValue::Double(val) => self.write_priority(priority, Some(val)),
+            // Single precision commands widen losslessly
+            PropertyValue::Real(val) => self.write_priority(priority, Some(val as f64)),
+            // NULL relinquishes the command at this priority
+            PropertyValue::Null => self.write_priority(priority, None),
+            _ => Err(ObjectError::InvalidPropertyType),
+        }
+    }
+
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::Reliability, PropertyValue::Enumerated(val)) => {
+                self.set_reliability(Reliability::try_from(val)?);
+                Ok(())
+            }
+            (PropertyIdentifier::Reliability, _) => Err(ObjectError::InvalidPropertyType),
+            (property, value) => self.set_property(property, value),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::Units
+                | PropertyIdentifier::CovIncrement
+                | PropertyIdentifier::PresentValue
+                | PropertyIdentifier::OutOfService
+                | PropertyIdentifier::RelinquishDefault
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        let mut properties = vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::OutOfService,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::Units,
+            PropertyIdentifier::PriorityArray,
+            PropertyIdentifier::RelinquishDefault,
+        ];
+        if self.cov_increment.is_some() {
+            properties.push(PropertyIdentifier::CovIncrement);
+        }
+        properties
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "std")]
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_large_analog_value_keeps_double_precision() {
+        let mut lav = LargeAnalogValue::new(1, "Lifetime energy".to_string());
+        // Beyond the 24-bit mantissa of a Real
+        let total = 123_456_789.125;
+        lav.write_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::Double(total),
+            None,
+        )
+        .unwrap();
+        assert!(matches!(
+            lav.get_property(PropertyIdentifier::PresentValue),
+            Ok(PropertyValue::Double(value)) if value == total
+        ));
+        assert_eq!(lav.get_effective_priority(), Some(16));
+
+        let mut buffer = Vec::new();
+        lav.get_property(PropertyIdentifier::PresentValue)
+            .unwrap()
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(buffer[0], 0x55);
+        assert_eq!(buffer.len(), 10);
+
+        assert!(lav
+            .set_property(
+                PropertyIdentifier::CovIncrement,
+                PropertyValue::Double(-1.0)
+            )
+            .is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_large_analog_value_reports_changes_of_value() {
+        let mut lav = LargeAnalogValue::new(1, "Energy total".to_string());
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        lav.callbacks
+            .register(PropertyIdentifier::PresentValue, move |change| {
+                if let PropertyValue::Double(value) = change.new_value {
+                    sink.lock().unwrap().push(value);
+                }
+            });
+
+        lav.write_priority(8, Some(1.5)).unwrap();
+        lav.write_priority(8, Some(1.5)).unwrap();
+        lav.set_relinquish_default(2.0);
+        // Out of service sets a status flag, reported with the current value
+        lav.set_property(
+            PropertyIdentifier::OutOfService,
+            PropertyValue::Boolean(true),
+        )
+        .unwrap();
+        assert_eq!(*reports.lock().unwrap(), vec![1.5, 1.5]);
+    }
+}
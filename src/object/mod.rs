@@ -21,6 +21,7 @@
 //! - [`AnalogInput`](ObjectType::AnalogInput): Represents analog sensor readings (temperature, pressure, etc.)
 //! - [`BinaryInput`](ObjectType::BinaryInput): Represents digital sensor states (on/off, open/closed)
 //! - [`MultiStateInput`](ObjectType::MultiStateInput): Represents enumerated sensor states
+//! - [`Accumulator`](ObjectType::Accumulator): Counts pulses from energy and water meters
 //!
 //! ## Output Objects  
 //! - [`AnalogOutput`](ObjectType::AnalogOutput): Controls analog actuators (valve position, damper angle)
@@ -31,12 +32,20 @@
 //! - [`AnalogValue`](ObjectType::AnalogValue): Software variables for calculations and setpoints
 //! - [`BinaryValue`](ObjectType::BinaryValue): Software flags and status indicators
 //! - [`MultiStateValue`](ObjectType::MultiStateValue): Software enumerated values
+//! - [`IntegerValue`](ObjectType::IntegerValue): Software signed integer values
+//! - [`LargeAnalogValue`](ObjectType::LargeAnalogValue): Software double precision values
+//! - [`CharacterStringValue`](ObjectType::CharacterStringValue): Software text values
+//! - [`OctetStringValue`](ObjectType::OctetStringValue): Software binary values
+//! - [`DateValue`](ObjectType::DateValue), [`TimeValue`](datetime_value::TimeValue) and
+//!   [`DateTimeValue`](ObjectType::DateTimeValue): Software timestamps and calendar anchors
 //!
 //! ## System Objects
 //! - [`Device`](ObjectType::Device): Represents a BACnet device (required in every device)
 //! - [`Schedule`](ObjectType::Schedule): Time-based control schedules
 //! - [`Calendar`](ObjectType::Calendar): Date-based event definitions
 //! - [`TrendLog`](ObjectType::TrendLog): Historical data logging
+//! - [`NotificationClass`](ObjectType::NotificationClass): Routing and priorities for event notifications
+//! - [`EventEnrollment`](ObjectType::EventEnrollment): Algorithmic event reporting on another object's property
 //!
 //! # Property System
 //!
@@ -73,7 +82,7 @@
 //! // Property identifiers for common properties
 //! let present_value = PropertyIdentifier::PresentValue;
 //! let object_name = PropertyIdentifier::ObjectName;
-//! let units = PropertyIdentifier::OutputUnits;
+//! let units = PropertyIdentifier::Units;
 //!
 //! // Property values can represent different data types
 //! let temperature = PropertyValue::Real(23.5);
@@ -127,7 +136,16 @@ use std::fmt;
 use core::fmt;
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use core::any::Any;
+
+use crate::encoding::{self, ApplicationTag, CharacterString, EncodingError};
 
 /// Result type for object operations
 #[cfg(feature = "std")]
@@ -159,6 +177,16 @@ pub enum ObjectError {
     WriteAccessDenied,
     /// Invalid object configuration
     InvalidConfiguration(String),
+    /// Object_Name already used by another object in the device
+    DuplicateObjectName(String),
+    /// Object_Name is not a valid name
+    InvalidObjectName(ObjectNameError),
+    /// Array index is past the end of the array
+    InvalidArrayIndex,
+    /// Array index given for a property that is not an array
+    PropertyIsNotAnArray,
+    /// File start position or record is past the end of the file
+    InvalidFileStartPosition,
 }
 
 impl fmt::Display for ObjectError {
@@ -174,6 +202,13 @@ impl fmt::Display for ObjectError {
             ObjectError::InvalidValue(msg) => write!(f, "Invalid value: {}", msg),
             ObjectError::WriteAccessDenied => write!(f, "Write access denied"),
             ObjectError::InvalidConfiguration(msg) => write!(f, "Invalid configuration: {}", msg),
+            ObjectError::DuplicateObjectName(name) => {
+                write!(f, "Object name '{}' is already in use", name)
+            }
+            ObjectError::InvalidObjectName(err) => write!(f, "Invalid object name: {}", err),
+            ObjectError::InvalidArrayIndex => write!(f, "Invalid array index"),
+            ObjectError::PropertyIsNotAnArray => write!(f, "Property is not an array"),
+            ObjectError::InvalidFileStartPosition => write!(f, "Invalid file start position"),
         }
     }
 }
@@ -181,8 +216,135 @@ impl fmt::Display for ObjectError {
 #[cfg(feature = "std")]
 impl Error for ObjectError {}
 
+/// Reasons an Object_Name can be rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectNameError {
+    /// Name has no characters
+    Empty,
+    /// Name contains a control (non-printable) character
+    NonPrintable(char),
+    /// Name does not fit in a fixed-capacity buffer of the given size (bytes)
+    TooLong(usize),
+}
+
+impl fmt::Display for ObjectNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjectNameError::Empty => write!(f, "name must be at least one character"),
+            ObjectNameError::NonPrintable(c) => {
+                write!(f, "name contains non-printable character {:?}", c)
+            }
+            ObjectNameError::TooLong(max) => write!(f, "name exceeds {} bytes", max),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ObjectNameError {}
+
+impl From<ObjectNameError> for ObjectError {
+    fn from(err: ObjectNameError) -> Self {
+        ObjectError::InvalidObjectName(err)
+    }
+}
+
+/// Check that a name satisfies the Object_Name rules
+///
+/// Object_Name must be at least one character long and consist of printable
+/// characters only.
+pub fn validate_object_name(name: &str) -> core::result::Result<(), ObjectNameError> {
+    if name.is_empty() {
+        return Err(ObjectNameError::Empty);
+    }
+    match name.chars().find(|c| c.is_control()) {
+        Some(c) => Err(ObjectNameError::NonPrintable(c)),
+        None => Ok(()),
+    }
+}
+
+/// Check that a Description consists of printable characters only
+///
+/// Unlike Object_Name, a Description may be empty.
+pub fn validate_description(description: &str) -> Result<()> {
+    match description.chars().find(|c| c.is_control()) {
+        Some(c) => Err(ObjectError::InvalidValue(format!(
+            "Description contains non-printable character {:?}",
+            c
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Fixed-capacity Object_Name stored inline, for devices without a heap
+///
+/// Holds at most `N` bytes of UTF-8 text. Names compare and hash by their
+/// text alone, whatever is left in the buffer past the end of the name.
+#[derive(Clone, Copy)]
+pub struct ArrayObjectName<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ArrayObjectName<N> {
+    /// Create a name, validating it and checking that it fits
+    pub fn new(name: &str) -> core::result::Result<Self, ObjectNameError> {
+        let mut object_name = Self {
+            bytes: [0; N],
+            len: 0,
+        };
+        object_name.update(name)?;
+        Ok(object_name)
+    }
+
+    /// Replace the name, leaving it unchanged on error
+    pub fn update(&mut self, name: &str) -> core::result::Result<(), ObjectNameError> {
+        validate_object_name(name)?;
+        if name.len() > N {
+            return Err(ObjectNameError::TooLong(N));
+        }
+        self.bytes[..name.len()].copy_from_slice(name.as_bytes());
+        self.len = name.len();
+        Ok(())
+    }
+
+    /// Get the name as a string slice
+    pub fn as_str(&self) -> &str {
+        // Only ever filled from a &str in update()
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl<const N: usize> PartialEq for ArrayObjectName<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayObjectName<N> {}
+
+impl<const N: usize> core::hash::Hash for ArrayObjectName<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArrayObjectName<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ArrayObjectName")
+            .field(&self.as_str())
+            .finish()
+    }
+}
+
+impl<const N: usize> fmt::Display for ArrayObjectName<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// BACnet object types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u16)]
 pub enum ObjectType {
     AnalogInput = 0,
@@ -216,8 +378,163 @@ pub enum ObjectType {
     LoadControl = 28,
     StructuredView = 29,
     AccessDoor = 30,
-    // ... many more standard types
-    // Vendor specific range starts at 128
+    Timer = 31,
+    AccessCredential = 32,
+    AccessPoint = 33,
+    AccessRights = 34,
+    AccessUser = 35,
+    AccessZone = 36,
+    CredentialDataInput = 37,
+    NetworkSecurity = 38,
+    BitStringValue = 39,
+    CharacterStringValue = 40,
+    DatePatternValue = 41,
+    DateValue = 42,
+    DateTimePatternValue = 43,
+    DateTimeValue = 44,
+    IntegerValue = 45,
+    LargeAnalogValue = 46,
+    OctetStringValue = 47,
+    PositiveIntegerValue = 48,
+    TimePatternValue = 49,
+    TimeValue = 50,
+    NotificationForwarder = 51,
+    AlertEnrollment = 52,
+    Channel = 53,
+    LightingOutput = 54,
+    BinaryLightingOutput = 55,
+    NetworkPort = 56,
+    ElevatorGroup = 57,
+    Escalator = 58,
+    Lift = 59,
+    Staging = 60,
+    AuditLog = 61,
+    AuditReporter = 62,
+    Color = 63,
+    ColorTemperature = 64,
+    /// Standard object type reserved for a later revision (65-127)
+    ///
+    /// Devices implementing a newer revision may report these, so they
+    /// decode to this variant, keeping the number, rather than failing.
+    Reserved(u16),
+    /// Vendor-specific object type (128-1023)
+    ///
+    /// Build it with [`ObjectType::proprietary`] to check the range.
+    Proprietary(u16),
+}
+
+impl ObjectType {
+    /// Get the 10-bit object type value used in object identifiers
+    pub fn as_u16(&self) -> u16 {
+        match self {
+            ObjectType::AnalogInput => 0,
+            ObjectType::AnalogOutput => 1,
+            ObjectType::AnalogValue => 2,
+            ObjectType::BinaryInput => 3,
+            ObjectType::BinaryOutput => 4,
+            ObjectType::BinaryValue => 5,
+            ObjectType::Calendar => 6,
+            ObjectType::Command => 7,
+            ObjectType::Device => 8,
+            ObjectType::EventEnrollment => 9,
+            ObjectType::File => 10,
+            ObjectType::Group => 11,
+            ObjectType::Loop => 12,
+            ObjectType::MultiStateInput => 13,
+            ObjectType::MultiStateOutput => 14,
+            ObjectType::NotificationClass => 15,
+            ObjectType::Program => 16,
+            ObjectType::Schedule => 17,
+            ObjectType::Averaging => 18,
+            ObjectType::MultiStateValue => 19,
+            ObjectType::TrendLog => 20,
+            ObjectType::LifeSafetyPoint => 21,
+            ObjectType::LifeSafetyZone => 22,
+            ObjectType::Accumulator => 23,
+            ObjectType::PulseConverter => 24,
+            ObjectType::EventLog => 25,
+            ObjectType::GlobalGroup => 26,
+            ObjectType::TrendLogMultiple => 27,
+            ObjectType::LoadControl => 28,
+            ObjectType::StructuredView => 29,
+            ObjectType::AccessDoor => 30,
+            ObjectType::Timer => 31,
+            ObjectType::AccessCredential => 32,
+            ObjectType::AccessPoint => 33,
+            ObjectType::AccessRights => 34,
+            ObjectType::AccessUser => 35,
+            ObjectType::AccessZone => 36,
+            ObjectType::CredentialDataInput => 37,
+            ObjectType::NetworkSecurity => 38,
+            ObjectType::BitStringValue => 39,
+            ObjectType::CharacterStringValue => 40,
+            ObjectType::DatePatternValue => 41,
+            ObjectType::DateValue => 42,
+            ObjectType::DateTimePatternValue => 43,
+            ObjectType::DateTimeValue => 44,
+            ObjectType::IntegerValue => 45,
+            ObjectType::LargeAnalogValue => 46,
+            ObjectType::OctetStringValue => 47,
+            ObjectType::PositiveIntegerValue => 48,
+            ObjectType::TimePatternValue => 49,
+            ObjectType::TimeValue => 50,
+            ObjectType::NotificationForwarder => 51,
+            ObjectType::AlertEnrollment => 52,
+            ObjectType::Channel => 53,
+            ObjectType::LightingOutput => 54,
+            ObjectType::BinaryLightingOutput => 55,
+            ObjectType::NetworkPort => 56,
+            ObjectType::ElevatorGroup => 57,
+            ObjectType::Escalator => 58,
+            ObjectType::Lift => 59,
+            ObjectType::Staging => 60,
+            ObjectType::AuditLog => 61,
+            ObjectType::AuditReporter => 62,
+            ObjectType::Color => 63,
+            ObjectType::ColorTemperature => 64,
+            ObjectType::Reserved(value) | ObjectType::Proprietary(value) => *value,
+        }
+    }
+
+    /// Standard object type values reserved for later revisions
+    pub const RESERVED_RANGE: core::ops::RangeInclusive<u16> = 65..=127;
+
+    /// Vendor-specific object type values
+    pub const PROPRIETARY_RANGE: core::ops::RangeInclusive<u16> = 128..=1023;
+
+    /// Convert from an object type value
+    ///
+    /// Reserved values (65-127) convert to `Reserved` and vendor-specific
+    /// values to `Proprietary`; only values that do not fit in 10 bits fail.
+    pub fn try_from_u16(value: u16) -> Result<Self> {
+        Self::try_from(value)
+    }
+
+    /// Create a vendor-specific object type, checking it is in 128-1023
+    pub fn proprietary(value: u16) -> Result<Self> {
+        if !Self::PROPRIETARY_RANGE.contains(&value) {
+            return Err(ObjectError::InvalidValue(format!(
+                "Object type {} is not a vendor-specific type (128-1023)",
+                value
+            )));
+        }
+        Ok(ObjectType::Proprietary(value))
+    }
+
+    /// Whether the object type is standard, or a reserved or vendor-specific
+    /// type in its range
+    pub fn is_valid(&self) -> bool {
+        match self {
+            ObjectType::Reserved(value) => Self::RESERVED_RANGE.contains(value),
+            ObjectType::Proprietary(value) => Self::PROPRIETARY_RANGE.contains(value),
+            _ => true,
+        }
+    }
+
+    /// Whether this object type is outside the enumerated standard set
+    pub fn is_proprietary(&self) -> bool {
+        matches!(self, ObjectType::Proprietary(_))
+    }
 }
 
 impl TryFrom<u16> for ObjectType {
@@ -256,19 +573,56 @@ impl TryFrom<u16> for ObjectType {
             28 => Ok(ObjectType::LoadControl),
             29 => Ok(ObjectType::StructuredView),
             30 => Ok(ObjectType::AccessDoor),
-            _ => Err(ObjectError::InvalidValue(format!(
-                "Unknown object type: {}",
-                value
-            ))),
+            31 => Ok(ObjectType::Timer),
+            32 => Ok(ObjectType::AccessCredential),
+            33 => Ok(ObjectType::AccessPoint),
+            34 => Ok(ObjectType::AccessRights),
+            35 => Ok(ObjectType::AccessUser),
+            36 => Ok(ObjectType::AccessZone),
+            37 => Ok(ObjectType::CredentialDataInput),
+            38 => Ok(ObjectType::NetworkSecurity),
+            39 => Ok(ObjectType::BitStringValue),
+            40 => Ok(ObjectType::CharacterStringValue),
+            41 => Ok(ObjectType::DatePatternValue),
+            42 => Ok(ObjectType::DateValue),
+            43 => Ok(ObjectType::DateTimePatternValue),
+            44 => Ok(ObjectType::DateTimeValue),
+            45 => Ok(ObjectType::IntegerValue),
+            46 => Ok(ObjectType::LargeAnalogValue),
+            47 => Ok(ObjectType::OctetStringValue),
+            48 => Ok(ObjectType::PositiveIntegerValue),
+            49 => Ok(ObjectType::TimePatternValue),
+            50 => Ok(ObjectType::TimeValue),
+            51 => Ok(ObjectType::NotificationForwarder),
+            52 => Ok(ObjectType::AlertEnrollment),
+            53 => Ok(ObjectType::Channel),
+            54 => Ok(ObjectType::LightingOutput),
+            55 => Ok(ObjectType::BinaryLightingOutput),
+            56 => Ok(ObjectType::NetworkPort),
+            57 => Ok(ObjectType::ElevatorGroup),
+            58 => Ok(ObjectType::Escalator),
+            59 => Ok(ObjectType::Lift),
+            60 => Ok(ObjectType::Staging),
+            61 => Ok(ObjectType::AuditLog),
+            62 => Ok(ObjectType::AuditReporter),
+            63 => Ok(ObjectType::Color),
+            64 => Ok(ObjectType::ColorTemperature),
+            65..=127 => Ok(ObjectType::Reserved(value)),
+            _ => ObjectType::proprietary(value).map_err(|_| {
+                ObjectError::InvalidValue(format!("Object type {} exceeds 1023", value))
+            }),
         }
     }
 }
 
 /// BACnet property identifiers
+///
+/// Values 0-511 and 4194304-4194815 are reserved for ASHRAE; any other value,
+/// and any reserved value not listed here, is carried as `Proprietary`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum PropertyIdentifier {
-    AcceptedModes = 175,
     AckedTransitions = 0,
     AckRequired = 1,
     Action = 2,
@@ -286,57 +640,1479 @@ pub enum PropertyIdentifier {
     Bias = 14,
     ChangeOfStateCount = 15,
     ChangeOfStateTime = 16,
-    // ... many more properties
-    DatabaseRevision = 155,
+    NotificationClass = 17,
+    ControlledVariableReference = 19,
+    ControlledVariableUnits = 20,
+    ControlledVariableValue = 21,
+    CovIncrement = 22,
+    DateList = 23,
+    DaylightSavingsStatus = 24,
+    Deadband = 25,
+    DerivativeConstant = 26,
+    DerivativeConstantUnits = 27,
+    Description = 28,
+    DescriptionOfHalt = 29,
+    DeviceAddressBinding = 30,
+    DeviceType = 31,
+    EffectivePeriod = 32,
+    ElapsedActiveTime = 33,
+    ErrorLimit = 34,
+    EventEnable = 35,
+    EventState = 36,
+    EventType = 37,
+    ExceptionSchedule = 38,
+    FaultValues = 39,
+    FeedbackValue = 40,
+    FileAccessMethod = 41,
+    FileSize = 42,
+    FileType = 43,
     FirmwareRevision = 44,
+    HighLimit = 45,
+    InactiveText = 46,
+    InProcess = 47,
+    InstanceOf = 48,
+    IntegralConstant = 49,
+    IntegralConstantUnits = 50,
+    LimitEnable = 52,
+    ListOfGroupMembers = 53,
+    ListOfObjectPropertyReferences = 54,
+    LocalDate = 56,
+    LocalTime = 57,
+    Location = 58,
+    LowLimit = 59,
+    ManipulatedVariableReference = 60,
+    MaximumOutput = 61,
     MaxApduLengthAccepted = 62,
+    MaxInfoFrames = 63,
+    MaxMaster = 64,
+    MaxPresValue = 65,
+    MinimumOffTime = 66,
+    MinimumOnTime = 67,
+    MinimumOutput = 68,
+    MinPresValue = 69,
     ModelName = 70,
+    ModificationDate = 71,
+    NotifyType = 72,
+    NumberOfApduRetries = 73,
+    NumberOfStates = 74,
     ObjectIdentifier = 75,
     ObjectList = 76,
     ObjectName = 77,
+    ObjectPropertyReference = 78,
     ObjectType = 79,
+    Optional = 80,
     OutOfService = 81,
     OutputUnits = 82,
+    EventParameters = 83,
+    Polarity = 84,
     PresentValue = 85,
-    ProtocolRevision = 139,
-    ProtocolVersion = 98,
-    SegmentationSupported = 107,
-    SystemStatus = 112,
-    VendorIdentifier = 120,
-    VendorName = 121,
     Priority = 86,
     PriorityArray = 87,
+    PriorityForWriting = 88,
     ProcessIdentifier = 89,
     ProgramChange = 90,
     ProgramLocation = 91,
     ProgramState = 92,
     ProportionalConstant = 93,
     ProportionalConstantUnits = 94,
-    // Protocol Revision 30 - Authentication/Authorization Properties
+    ProtocolObjectTypesSupported = 96,
+    ProtocolServicesSupported = 97,
+    ProtocolVersion = 98,
+    ReadOnly = 99,
+    ReasonForHalt = 100,
+    RecipientList = 102,
+    Reliability = 103,
+    RelinquishDefault = 104,
+    Required = 105,
+    Resolution = 106,
+    SegmentationSupported = 107,
+    Setpoint = 108,
+    SetpointReference = 109,
+    StateText = 110,
+    StatusFlags = 111,
+    SystemStatus = 112,
+    TimeDelay = 113,
+    TimeOfActiveTimeReset = 114,
+    TimeOfStateCountReset = 115,
+    TimeSynchronizationRecipients = 116,
+    Units = 117,
+    UpdateInterval = 118,
+    UtcOffset = 119,
+    VendorIdentifier = 120,
+    VendorName = 121,
+    VtClassesSupported = 122,
+    WeeklySchedule = 123,
+    AttemptedSamples = 124,
+    AverageValue = 125,
+    BufferSize = 126,
+    ClientCovIncrement = 127,
+    CovResubscriptionInterval = 128,
+    EventTimeStamps = 130,
+    LogBuffer = 131,
+    LogDeviceObjectProperty = 132,
+    Enable = 133,
+    LogInterval = 134,
+    MaximumValue = 135,
+    MinimumValue = 136,
+    NotificationThreshold = 137,
+    ProtocolRevision = 139,
+    RecordsSinceNotification = 140,
+    RecordCount = 141,
+    StartTime = 142,
+    StopTime = 143,
+    StopWhenFull = 144,
+    TotalRecordCount = 145,
+    ValidSamples = 146,
+    WindowInterval = 147,
+    WindowSamples = 148,
+    MaximumValueTimestamp = 149,
+    MinimumValueTimestamp = 150,
+    VarianceValue = 151,
+    ActiveCovSubscriptions = 152,
+    BackupFailureTimeout = 153,
+    ConfigurationFiles = 154,
+    DatabaseRevision = 155,
+    DirectReading = 156,
+    LastRestoreTime = 157,
+    MaintenanceRequired = 158,
+    MemberOf = 159,
+    Mode = 160,
+    OperationExpected = 161,
+    Setting = 162,
+    Silenced = 163,
+    TrackingValue = 164,
+    ZoneMembers = 165,
+    LifeSafetyAlarmValues = 166,
+    MaxSegmentsAccepted = 167,
+    ProfileName = 168,
+    AutoSlaveDiscovery = 169,
+    ManualSlaveAddressBinding = 170,
+    SlaveAddressBinding = 171,
+    SlaveProxyEnable = 172,
+    LastNotifyRecord = 173,
+    ScheduleDefault = 174,
+    AcceptedModes = 175,
+    AdjustValue = 176,
+    Count = 177,
+    CountBeforeChange = 178,
+    CountChangeTime = 179,
+    CovPeriod = 180,
+    InputReference = 181,
+    LimitMonitoringInterval = 182,
+    LoggingObject = 183,
+    LoggingRecord = 184,
+    Prescale = 185,
+    PulseRate = 186,
+    Scale = 187,
+    ScaleFactor = 188,
+    UpdateTime = 189,
+    ValueBeforeChange = 190,
+    ValueSet = 191,
+    ValueChangeTime = 192,
+    AlignIntervals = 193,
+    IntervalOffset = 195,
+    LastRestartReason = 196,
+    LoggingType = 197,
+    RestartNotificationRecipients = 202,
+    TimeOfDeviceRestart = 203,
+    TimeSynchronizationInterval = 204,
+    Trigger = 205,
+    UtcTimeSynchronizationRecipients = 206,
+    NodeSubtype = 207,
+    NodeType = 208,
+    StructuredObjectList = 209,
+    SubordinateAnnotations = 210,
+    SubordinateList = 211,
+    ActualShedLevel = 212,
+    DutyWindow = 213,
+    ExpectedShedLevel = 214,
+    FullDutyBaseline = 215,
+    RequestedShedLevel = 218,
+    ShedDuration = 219,
+    ShedLevelDescriptions = 220,
+    ShedLevels = 221,
+    StateDescription = 222,
+    DoorAlarmState = 226,
+    DoorExtendedPulseTime = 227,
+    DoorMembers = 228,
+    DoorOpenTooLongTime = 229,
+    DoorPulseTime = 230,
+    DoorStatus = 231,
+    DoorUnlockDelayTime = 232,
+    LockStatus = 233,
+    MaskedAlarmValues = 234,
+    SecuredStatus = 235,
+    AbsenteeLimit = 244,
+    AccessAlarmEvents = 245,
+    AccessDoors = 246,
+    AccessEvent = 247,
+    AccessEventAuthenticationFactor = 248,
+    AccessEventCredential = 249,
+    AccessEventTime = 250,
+    AccessTransactionEvents = 251,
+    Accompaniment = 252,
+    AccompanimentTime = 253,
+    ActivationTime = 254,
+    ActiveAuthenticationPolicy = 255,
+    AssignedAccessRights = 256,
     AuthenticationFactors = 257,
     AuthenticationPolicyList = 258,
     AuthenticationPolicyNames = 259,
     AuthenticationStatus = 260,
     AuthorizationMode = 261,
+    BelongsTo = 262,
+    CredentialDisable = 263,
+    CredentialStatus = 264,
+    Credentials = 265,
+    CredentialsInZone = 266,
+    DaysRemaining = 267,
+    EntryPoints = 268,
+    ExitPoints = 269,
+    ExpirationTime = 270,
+    ExtendedTimeEnable = 271,
+    FailedAttemptEvents = 272,
+    FailedAttempts = 273,
+    FailedAttemptsTime = 274,
+    LastAccessEvent = 275,
+    LastAccessPoint = 276,
+    LastCredentialAdded = 277,
+    LastCredentialAddedTime = 278,
+    LastCredentialRemoved = 279,
+    LastCredentialRemovedTime = 280,
+    LastUseTime = 281,
+    Lockout = 282,
+    LockoutRelinquishTime = 283,
+    MaxFailedAttempts = 285,
+    Members = 286,
+    MusterPoint = 287,
+    NegativeAccessRules = 288,
+    NumberOfAuthenticationPolicies = 289,
+    OccupancyCount = 290,
+    OccupancyCountAdjust = 291,
+    OccupancyCountEnable = 292,
+    OccupancyLowerLimit = 294,
+    OccupancyLowerLimitEnforced = 295,
+    OccupancyState = 296,
+    OccupancyUpperLimit = 297,
+    OccupancyUpperLimitEnforced = 298,
+    PassbackMode = 300,
+    PassbackTimeout = 301,
+    PositiveAccessRules = 302,
+    ReasonForDisable = 303,
+    SupportedFormats = 304,
+    SupportedFormatClasses = 305,
+    ThreatAuthority = 306,
+    ThreatLevel = 307,
+    TraceFlag = 308,
+    TransactionNotificationClass = 309,
+    UserExternalIdentifier = 310,
+    UserInformationReference = 311,
+    UserName = 317,
+    UserType = 318,
+    UsesRemaining = 319,
+    ZoneFrom = 320,
+    ZoneTo = 321,
+    AccessEventTag = 322,
+    GlobalIdentifier = 323,
+    VerificationTime = 326,
+    BaseDeviceSecurityPolicy = 327,
+    DistributionKeyRevision = 328,
+    DoNotHide = 329,
+    KeySets = 330,
+    LastKeyServer = 331,
+    NetworkAccessSecurityPolicies = 332,
+    PacketReorderTime = 333,
+    SecurityPduTimeout = 334,
+    SecurityTimeWindow = 335,
+    SupportedSecurityAlgorithms = 336,
+    UpdateKeySetTimeout = 337,
+    BackupAndRestoreState = 338,
+    BackupPreparationTime = 339,
+    RestoreCompletionTime = 340,
+    RestorePreparationTime = 341,
+    BitMask = 342,
+    BitText = 343,
+    IsUtc = 344,
+    GroupMembers = 345,
+    GroupMemberNames = 346,
+    MemberStatusFlags = 347,
+    RequestedUpdateInterval = 348,
+    CovuPeriod = 349,
+    CovuRecipients = 350,
+    EventMessageTexts = 351,
+    EventMessageTextsConfig = 352,
+    EventDetectionEnable = 353,
+    EventAlgorithmInhibit = 354,
+    EventAlgorithmInhibitRef = 355,
+    TimeDelayNormal = 356,
+    ReliabilityEvaluationInhibit = 357,
+    FaultParameters = 358,
+    FaultType = 359,
+    LocalForwardingOnly = 360,
+    ProcessIdentifierFilter = 361,
+    SubscribedRecipients = 362,
+    PortFilter = 363,
     AuthorizationExemptions = 364,
-    // Reserved range properties (Protocol Revision 30)
+    AllowGroupDelayInhibit = 365,
+    ChannelNumber = 366,
+    ControlGroups = 367,
+    ExecutionDelay = 368,
+    LastPriority = 369,
+    WriteStatus = 370,
+    PropertyList = 371,
+    SerialNumber = 372,
+    BlinkWarnEnable = 373,
+    DefaultFadeTime = 374,
+    DefaultRampRate = 375,
+    DefaultStepIncrement = 376,
+    EgressTime = 377,
+    InProgress = 378,
+    InstantaneousPower = 379,
+    LightingCommand = 380,
+    LightingCommandDefaultPriority = 381,
+    MaxActualValue = 382,
+    MinActualValue = 383,
+    Power = 384,
+    Transition = 385,
+    EgressActive = 386,
+    InterfaceValue = 387,
+    FaultHighLimit = 388,
+    FaultLowLimit = 389,
+    LowDiffLimit = 390,
+    StrikeCount = 391,
+    TimeOfStrikeCountReset = 392,
+    DefaultTimeout = 393,
+    InitialTimeout = 394,
+    LastStateChange = 395,
+    StateChangeValues = 396,
+    TimerRunning = 397,
+    TimerState = 398,
+    ApduLength = 399,
+    IpAddress = 400,
+    IpDefaultGateway = 401,
+    IpDhcpEnable = 402,
+    IpDhcpLeaseTime = 403,
+    IpDhcpLeaseTimeRemaining = 404,
+    IpDhcpServer = 405,
+    IpDnsServer = 406,
+    BacnetIpGlobalAddress = 407,
+    BacnetIpMode = 408,
+    BacnetIpMulticastAddress = 409,
+    BacnetIpNatTraversal = 410,
+    IpSubnetMask = 411,
+    BacnetIpUdpPort = 412,
+    BbmdAcceptFdRegistrations = 413,
+    BbmdBroadcastDistributionTable = 414,
+    BbmdForeignDeviceTable = 415,
+    ChangesPending = 416,
+    Command = 417,
+    FdBbmdAddress = 418,
+    FdSubscriptionLifetime = 419,
+    LinkSpeed = 420,
+    LinkSpeeds = 421,
+    LinkSpeedAutonegotiate = 422,
+    MacAddress = 423,
+    NetworkInterfaceName = 424,
+    NetworkNumber = 425,
+    NetworkNumberQuality = 426,
+    NetworkType = 427,
+    RoutingTable = 428,
+    VirtualMacAddressTable = 429,
+    CommandTimeArray = 430,
+    CurrentCommandPriority = 431,
+    LastCommandTime = 432,
+    ValueSource = 433,
+    ValueSourceArray = 434,
+    BacnetIpv6Mode = 435,
+    Ipv6Address = 436,
+    Ipv6PrefixLength = 437,
+    BacnetIpv6UdpPort = 438,
+    Ipv6DefaultGateway = 439,
+    BacnetIpv6MulticastAddress = 440,
+    Ipv6DnsServer = 441,
+    Ipv6AutoAddressingEnable = 442,
+    Ipv6DhcpLeaseTime = 443,
+    Ipv6DhcpLeaseTimeRemaining = 444,
+    Ipv6DhcpServer = 445,
+    Ipv6ZoneIndex = 446,
+    AssignedLandingCalls = 447,
+    CarAssignedDirection = 448,
+    CarDoorCommand = 449,
+    CarDoorStatus = 450,
+    CarDoorText = 451,
+    CarDoorZone = 452,
+    CarDriveStatus = 453,
+    CarLoad = 454,
+    CarLoadUnits = 455,
+    CarMode = 456,
+    CarMovingDirection = 457,
+    CarPosition = 458,
+    ElevatorGroup = 459,
+    EnergyMeter = 460,
+    EnergyMeterRef = 461,
+    EscalatorMode = 462,
+    FaultSignals = 463,
+    FloorText = 464,
+    GroupId = 465,
+    GroupMode = 467,
+    HigherDeck = 468,
+    InstallationId = 469,
+    LandingCalls = 470,
+    LandingCallControl = 471,
+    LandingDoorStatus = 472,
+    LowerDeck = 473,
+    MachineRoomId = 474,
+    MakingCarCall = 475,
+    NextStoppingFloor = 476,
+    OperationDirection = 477,
+    PassengerAlarm = 478,
+    PowerMode = 479,
+    RegisteredCarCall = 480,
+    ActiveCovMultipleSubscriptions = 481,
+    ProtocolLevel = 482,
+    ReferencePort = 483,
+    DeployedProfileLocation = 484,
+    ProfileLocation = 485,
+    Tags = 486,
+    SubordinateNodeTypes = 487,
+    SubordinateTags = 488,
+    SubordinateRelationships = 489,
+    DefaultSubordinateRelationship = 490,
+    Represents = 491,
     AuthorizationCache = 4194343,
     AuthorizationGroups = 4194344,
     AuthorizationPolicy = 4194345,
     AuthorizationScope = 4194346,
     AuthorizationServer = 4194347,
     AuthorizationStatus = 4194348,
-    // ... continues with many more properties
+    /// Vendor-specific or not yet enumerated property identifier
+    Proprietary(u32),
+}
+
+impl PropertyIdentifier {
+    /// Get the property identifier value used on the wire
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            PropertyIdentifier::AckedTransitions => 0,
+            PropertyIdentifier::AckRequired => 1,
+            PropertyIdentifier::Action => 2,
+            PropertyIdentifier::ActionText => 3,
+            PropertyIdentifier::ActiveText => 4,
+            PropertyIdentifier::ActiveVtSessions => 5,
+            PropertyIdentifier::AlarmValue => 6,
+            PropertyIdentifier::AlarmValues => 7,
+            PropertyIdentifier::All => 8,
+            PropertyIdentifier::AllWritesSuccessful => 9,
+            PropertyIdentifier::ApduSegmentTimeout => 10,
+            PropertyIdentifier::ApduTimeout => 11,
+            PropertyIdentifier::ApplicationSoftwareVersion => 12,
+            PropertyIdentifier::Archive => 13,
+            PropertyIdentifier::Bias => 14,
+            PropertyIdentifier::ChangeOfStateCount => 15,
+            PropertyIdentifier::ChangeOfStateTime => 16,
+            PropertyIdentifier::NotificationClass => 17,
+            PropertyIdentifier::ControlledVariableReference => 19,
+            PropertyIdentifier::ControlledVariableUnits => 20,
+            PropertyIdentifier::ControlledVariableValue => 21,
+            PropertyIdentifier::CovIncrement => 22,
+            PropertyIdentifier::DateList => 23,
+            PropertyIdentifier::DaylightSavingsStatus => 24,
+            PropertyIdentifier::Deadband => 25,
+            PropertyIdentifier::DerivativeConstant => 26,
+            PropertyIdentifier::DerivativeConstantUnits => 27,
+            PropertyIdentifier::Description => 28,
+            PropertyIdentifier::DescriptionOfHalt => 29,
+            PropertyIdentifier::DeviceAddressBinding => 30,
+            PropertyIdentifier::DeviceType => 31,
+            PropertyIdentifier::EffectivePeriod => 32,
+            PropertyIdentifier::ElapsedActiveTime => 33,
+            PropertyIdentifier::ErrorLimit => 34,
+            PropertyIdentifier::EventEnable => 35,
+            PropertyIdentifier::EventState => 36,
+            PropertyIdentifier::EventType => 37,
+            PropertyIdentifier::ExceptionSchedule => 38,
+            PropertyIdentifier::FaultValues => 39,
+            PropertyIdentifier::FeedbackValue => 40,
+            PropertyIdentifier::FileAccessMethod => 41,
+            PropertyIdentifier::FileSize => 42,
+            PropertyIdentifier::FileType => 43,
+            PropertyIdentifier::FirmwareRevision => 44,
+            PropertyIdentifier::HighLimit => 45,
+            PropertyIdentifier::InactiveText => 46,
+            PropertyIdentifier::InProcess => 47,
+            PropertyIdentifier::InstanceOf => 48,
+            PropertyIdentifier::IntegralConstant => 49,
+            PropertyIdentifier::IntegralConstantUnits => 50,
+            PropertyIdentifier::LimitEnable => 52,
+            PropertyIdentifier::ListOfGroupMembers => 53,
+            PropertyIdentifier::ListOfObjectPropertyReferences => 54,
+            PropertyIdentifier::LocalDate => 56,
+            PropertyIdentifier::LocalTime => 57,
+            PropertyIdentifier::Location => 58,
+            PropertyIdentifier::LowLimit => 59,
+            PropertyIdentifier::ManipulatedVariableReference => 60,
+            PropertyIdentifier::MaximumOutput => 61,
+            PropertyIdentifier::MaxApduLengthAccepted => 62,
+            PropertyIdentifier::MaxInfoFrames => 63,
+            PropertyIdentifier::MaxMaster => 64,
+            PropertyIdentifier::MaxPresValue => 65,
+            PropertyIdentifier::MinimumOffTime => 66,
+            PropertyIdentifier::MinimumOnTime => 67,
+            PropertyIdentifier::MinimumOutput => 68,
+            PropertyIdentifier::MinPresValue => 69,
+            PropertyIdentifier::ModelName => 70,
+            PropertyIdentifier::ModificationDate => 71,
+            PropertyIdentifier::NotifyType => 72,
+            PropertyIdentifier::NumberOfApduRetries => 73,
+            PropertyIdentifier::NumberOfStates => 74,
+            PropertyIdentifier::ObjectIdentifier => 75,
+            PropertyIdentifier::ObjectList => 76,
+            PropertyIdentifier::ObjectName => 77,
+            PropertyIdentifier::ObjectPropertyReference => 78,
+            PropertyIdentifier::ObjectType => 79,
+            PropertyIdentifier::Optional => 80,
+            PropertyIdentifier::OutOfService => 81,
+            PropertyIdentifier::OutputUnits => 82,
+            PropertyIdentifier::EventParameters => 83,
+            PropertyIdentifier::Polarity => 84,
+            PropertyIdentifier::PresentValue => 85,
+            PropertyIdentifier::Priority => 86,
+            PropertyIdentifier::PriorityArray => 87,
+            PropertyIdentifier::PriorityForWriting => 88,
+            PropertyIdentifier::ProcessIdentifier => 89,
+            PropertyIdentifier::ProgramChange => 90,
+            PropertyIdentifier::ProgramLocation => 91,
+            PropertyIdentifier::ProgramState => 92,
+            PropertyIdentifier::ProportionalConstant => 93,
+            PropertyIdentifier::ProportionalConstantUnits => 94,
+            PropertyIdentifier::ProtocolObjectTypesSupported => 96,
+            PropertyIdentifier::ProtocolServicesSupported => 97,
+            PropertyIdentifier::ProtocolVersion => 98,
+            PropertyIdentifier::ReadOnly => 99,
+            PropertyIdentifier::ReasonForHalt => 100,
+            PropertyIdentifier::RecipientList => 102,
+            PropertyIdentifier::Reliability => 103,
+            PropertyIdentifier::RelinquishDefault => 104,
+            PropertyIdentifier::Required => 105,
+            PropertyIdentifier::Resolution => 106,
+            PropertyIdentifier::SegmentationSupported => 107,
+            PropertyIdentifier::Setpoint => 108,
+            PropertyIdentifier::SetpointReference => 109,
+            PropertyIdentifier::StateText => 110,
+            PropertyIdentifier::StatusFlags => 111,
+            PropertyIdentifier::SystemStatus => 112,
+            PropertyIdentifier::TimeDelay => 113,
+            PropertyIdentifier::TimeOfActiveTimeReset => 114,
+            PropertyIdentifier::TimeOfStateCountReset => 115,
+            PropertyIdentifier::TimeSynchronizationRecipients => 116,
+            PropertyIdentifier::Units => 117,
+            PropertyIdentifier::UpdateInterval => 118,
+            PropertyIdentifier::UtcOffset => 119,
+            PropertyIdentifier::VendorIdentifier => 120,
+            PropertyIdentifier::VendorName => 121,
+            PropertyIdentifier::VtClassesSupported => 122,
+            PropertyIdentifier::WeeklySchedule => 123,
+            PropertyIdentifier::AttemptedSamples => 124,
+            PropertyIdentifier::AverageValue => 125,
+            PropertyIdentifier::BufferSize => 126,
+            PropertyIdentifier::ClientCovIncrement => 127,
+            PropertyIdentifier::CovResubscriptionInterval => 128,
+            PropertyIdentifier::EventTimeStamps => 130,
+            PropertyIdentifier::LogBuffer => 131,
+            PropertyIdentifier::LogDeviceObjectProperty => 132,
+            PropertyIdentifier::Enable => 133,
+            PropertyIdentifier::LogInterval => 134,
+            PropertyIdentifier::MaximumValue => 135,
+            PropertyIdentifier::MinimumValue => 136,
+            PropertyIdentifier::NotificationThreshold => 137,
+            PropertyIdentifier::ProtocolRevision => 139,
+            PropertyIdentifier::RecordsSinceNotification => 140,
+            PropertyIdentifier::RecordCount => 141,
+            PropertyIdentifier::StartTime => 142,
+            PropertyIdentifier::StopTime => 143,
+            PropertyIdentifier::StopWhenFull => 144,
+            PropertyIdentifier::TotalRecordCount => 145,
+            PropertyIdentifier::ValidSamples => 146,
+            PropertyIdentifier::WindowInterval => 147,
+            PropertyIdentifier::WindowSamples => 148,
+            PropertyIdentifier::MaximumValueTimestamp => 149,
+            PropertyIdentifier::MinimumValueTimestamp => 150,
+            PropertyIdentifier::VarianceValue => 151,
+            PropertyIdentifier::ActiveCovSubscriptions => 152,
+            PropertyIdentifier::BackupFailureTimeout => 153,
+            PropertyIdentifier::ConfigurationFiles => 154,
+            PropertyIdentifier::DatabaseRevision => 155,
+            PropertyIdentifier::DirectReading => 156,
+            PropertyIdentifier::LastRestoreTime => 157,
+            PropertyIdentifier::MaintenanceRequired => 158,
+            PropertyIdentifier::MemberOf => 159,
+            PropertyIdentifier::Mode => 160,
+            PropertyIdentifier::OperationExpected => 161,
+            PropertyIdentifier::Setting => 162,
+            PropertyIdentifier::Silenced => 163,
+            PropertyIdentifier::TrackingValue => 164,
+            PropertyIdentifier::ZoneMembers => 165,
+            PropertyIdentifier::LifeSafetyAlarmValues => 166,
+            PropertyIdentifier::MaxSegmentsAccepted => 167,
+            PropertyIdentifier::ProfileName => 168,
+            PropertyIdentifier::AutoSlaveDiscovery => 169,
+            PropertyIdentifier::ManualSlaveAddressBinding => 170,
+            PropertyIdentifier::SlaveAddressBinding => 171,
+            PropertyIdentifier::SlaveProxyEnable => 172,
+            PropertyIdentifier::LastNotifyRecord => 173,
+            PropertyIdentifier::ScheduleDefault => 174,
+            PropertyIdentifier::AcceptedModes => 175,
+            PropertyIdentifier::AdjustValue => 176,
+            PropertyIdentifier::Count => 177,
+            PropertyIdentifier::CountBeforeChange => 178,
+            PropertyIdentifier::CountChangeTime => 179,
+            PropertyIdentifier::CovPeriod => 180,
+            PropertyIdentifier::InputReference => 181,
+            PropertyIdentifier::LimitMonitoringInterval => 182,
+            PropertyIdentifier::LoggingObject => 183,
+            PropertyIdentifier::LoggingRecord => 184,
+            PropertyIdentifier::Prescale => 185,
+            PropertyIdentifier::PulseRate => 186,
+            PropertyIdentifier::Scale => 187,
+            PropertyIdentifier::ScaleFactor => 188,
+            PropertyIdentifier::UpdateTime => 189,
+            PropertyIdentifier::ValueBeforeChange => 190,
+            PropertyIdentifier::ValueSet => 191,
+            PropertyIdentifier::ValueChangeTime => 192,
+            PropertyIdentifier::AlignIntervals => 193,
+            PropertyIdentifier::IntervalOffset => 195,
+            PropertyIdentifier::LastRestartReason => 196,
+            PropertyIdentifier::LoggingType => 197,
+            PropertyIdentifier::RestartNotificationRecipients => 202,
+            PropertyIdentifier::TimeOfDeviceRestart => 203,
+            PropertyIdentifier::TimeSynchronizationInterval => 204,
+            PropertyIdentifier::Trigger => 205,
+            PropertyIdentifier::UtcTimeSynchronizationRecipients => 206,
+            PropertyIdentifier::NodeSubtype => 207,
+            PropertyIdentifier::NodeType => 208,
+            PropertyIdentifier::StructuredObjectList => 209,
+            PropertyIdentifier::SubordinateAnnotations => 210,
+            PropertyIdentifier::SubordinateList => 211,
+            PropertyIdentifier::ActualShedLevel => 212,
+            PropertyIdentifier::DutyWindow => 213,
+            PropertyIdentifier::ExpectedShedLevel => 214,
+            PropertyIdentifier::FullDutyBaseline => 215,
+            PropertyIdentifier::RequestedShedLevel => 218,
+            PropertyIdentifier::ShedDuration => 219,
+            PropertyIdentifier::ShedLevelDescriptions => 220,
+            PropertyIdentifier::ShedLevels => 221,
+            PropertyIdentifier::StateDescription => 222,
+            PropertyIdentifier::DoorAlarmState => 226,
+            PropertyIdentifier::DoorExtendedPulseTime => 227,
+            PropertyIdentifier::DoorMembers => 228,
+            PropertyIdentifier::DoorOpenTooLongTime => 229,
+            PropertyIdentifier::DoorPulseTime => 230,
+            PropertyIdentifier::DoorStatus => 231,
+            PropertyIdentifier::DoorUnlockDelayTime => 232,
+            PropertyIdentifier::LockStatus => 233,
+            PropertyIdentifier::MaskedAlarmValues => 234,
+            PropertyIdentifier::SecuredStatus => 235,
+            PropertyIdentifier::AbsenteeLimit => 244,
+            PropertyIdentifier::AccessAlarmEvents => 245,
+            PropertyIdentifier::AccessDoors => 246,
+            PropertyIdentifier::AccessEvent => 247,
+            PropertyIdentifier::AccessEventAuthenticationFactor => 248,
+            PropertyIdentifier::AccessEventCredential => 249,
+            PropertyIdentifier::AccessEventTime => 250,
+            PropertyIdentifier::AccessTransactionEvents => 251,
+            PropertyIdentifier::Accompaniment => 252,
+            PropertyIdentifier::AccompanimentTime => 253,
+            PropertyIdentifier::ActivationTime => 254,
+            PropertyIdentifier::ActiveAuthenticationPolicy => 255,
+            PropertyIdentifier::AssignedAccessRights => 256,
+            PropertyIdentifier::AuthenticationFactors => 257,
+            PropertyIdentifier::AuthenticationPolicyList => 258,
+            PropertyIdentifier::AuthenticationPolicyNames => 259,
+            PropertyIdentifier::AuthenticationStatus => 260,
+            PropertyIdentifier::AuthorizationMode => 261,
+            PropertyIdentifier::BelongsTo => 262,
+            PropertyIdentifier::CredentialDisable => 263,
+            PropertyIdentifier::CredentialStatus => 264,
+            PropertyIdentifier::Credentials => 265,
+            PropertyIdentifier::CredentialsInZone => 266,
+            PropertyIdentifier::DaysRemaining => 267,
+            PropertyIdentifier::EntryPoints => 268,
+            PropertyIdentifier::ExitPoints => 269,
+            PropertyIdentifier::ExpirationTime => 270,
+            PropertyIdentifier::ExtendedTimeEnable => 271,
+            PropertyIdentifier::FailedAttemptEvents => 272,
+            PropertyIdentifier::FailedAttempts => 273,
+            PropertyIdentifier::FailedAttemptsTime => 274,
+            PropertyIdentifier::LastAccessEvent => 275,
+            PropertyIdentifier::LastAccessPoint => 276,
+            PropertyIdentifier::LastCredentialAdded => 277,
+            PropertyIdentifier::LastCredentialAddedTime => 278,
+            PropertyIdentifier::LastCredentialRemoved => 279,
+            PropertyIdentifier::LastCredentialRemovedTime => 280,
+            PropertyIdentifier::LastUseTime => 281,
+            PropertyIdentifier::Lockout => 282,
+            PropertyIdentifier::LockoutRelinquishTime => 283,
+            PropertyIdentifier::MaxFailedAttempts => 285,
+            PropertyIdentifier::Members => 286,
+            PropertyIdentifier::MusterPoint => 287,
+            PropertyIdentifier::NegativeAccessRules => 288,
+            PropertyIdentifier::NumberOfAuthenticationPolicies => 289,
+            PropertyIdentifier::OccupancyCount => 290,
+            PropertyIdentifier::OccupancyCountAdjust => 291,
+            PropertyIdentifier::OccupancyCountEnable => 292,
+            PropertyIdentifier::OccupancyLowerLimit => 294,
+            PropertyIdentifier::OccupancyLowerLimitEnforced => 295,
+            PropertyIdentifier::OccupancyState => 296,
+            PropertyIdentifier::OccupancyUpperLimit => 297,
+            PropertyIdentifier::OccupancyUpperLimitEnforced => 298,
+            PropertyIdentifier::PassbackMode => 300,
+            PropertyIdentifier::PassbackTimeout => 301,
+            PropertyIdentifier::PositiveAccessRules => 302,
+            PropertyIdentifier::ReasonForDisable => 303,
+            PropertyIdentifier::SupportedFormats => 304,
+            PropertyIdentifier::SupportedFormatClasses => 305,
+            PropertyIdentifier::ThreatAuthority => 306,
+            PropertyIdentifier::ThreatLevel => 307,
+            PropertyIdentifier::TraceFlag => 308,
+            PropertyIdentifier::TransactionNotificationClass => 309,
+            PropertyIdentifier::UserExternalIdentifier => 310,
+            PropertyIdentifier::UserInformationReference => 311,
+            PropertyIdentifier::UserName => 317,
+            PropertyIdentifier::UserType => 318,
+            PropertyIdentifier::UsesRemaining => 319,
+            PropertyIdentifier::ZoneFrom => 320,
+            PropertyIdentifier::ZoneTo => 321,
+            PropertyIdentifier::AccessEventTag => 322,
+            PropertyIdentifier::GlobalIdentifier => 323,
+            PropertyIdentifier::VerificationTime => 326,
+            PropertyIdentifier::BaseDeviceSecurityPolicy => 327,
+            PropertyIdentifier::DistributionKeyRevision => 328,
+            PropertyIdentifier::DoNotHide => 329,
+            PropertyIdentifier::KeySets => 330,
+            PropertyIdentifier::LastKeyServer => 331,
+            PropertyIdentifier::NetworkAccessSecurityPolicies => 332,
+            PropertyIdentifier::PacketReorderTime => 333,
+            PropertyIdentifier::SecurityPduTimeout => 334,
+            PropertyIdentifier::SecurityTimeWindow => 335,
+            PropertyIdentifier::SupportedSecurityAlgorithms => 336,
+            PropertyIdentifier::UpdateKeySetTimeout => 337,
+            PropertyIdentifier::BackupAndRestoreState => 338,
+            PropertyIdentifier::BackupPreparationTime => 339,
+            PropertyIdentifier::RestoreCompletionTime => 340,
+            PropertyIdentifier::RestorePreparationTime => 341,
+            PropertyIdentifier::BitMask => 342,
+            PropertyIdentifier::BitText => 343,
+            PropertyIdentifier::IsUtc => 344,
+            PropertyIdentifier::GroupMembers => 345,
+            PropertyIdentifier::GroupMemberNames => 346,
+            PropertyIdentifier::MemberStatusFlags => 347,
+            PropertyIdentifier::RequestedUpdateInterval => 348,
+            PropertyIdentifier::CovuPeriod => 349,
+            PropertyIdentifier::CovuRecipients => 350,
+            PropertyIdentifier::EventMessageTexts => 351,
+            PropertyIdentifier::EventMessageTextsConfig => 352,
+            PropertyIdentifier::EventDetectionEnable => 353,
+            PropertyIdentifier::EventAlgorithmInhibit => 354,
+            PropertyIdentifier::EventAlgorithmInhibitRef => 355,
+            PropertyIdentifier::TimeDelayNormal => 356,
+            PropertyIdentifier::ReliabilityEvaluationInhibit => 357,
+            PropertyIdentifier::FaultParameters => 358,
+            PropertyIdentifier::FaultType => 359,
+            PropertyIdentifier::LocalForwardingOnly => 360,
+            PropertyIdentifier::ProcessIdentifierFilter => 361,
+            PropertyIdentifier::SubscribedRecipients => 362,
+            PropertyIdentifier::PortFilter => 363,
+            PropertyIdentifier::AuthorizationExemptions => 364,
+            PropertyIdentifier::AllowGroupDelayInhibit => 365,
+            PropertyIdentifier::ChannelNumber => 366,
+            PropertyIdentifier::ControlGroups => 367,
+            PropertyIdentifier::ExecutionDelay => 368,
+            PropertyIdentifier::LastPriority => 369,
+            PropertyIdentifier::WriteStatus => 370,
+            PropertyIdentifier::PropertyList => 371,
+            PropertyIdentifier::SerialNumber => 372,
+            PropertyIdentifier::BlinkWarnEnable => 373,
+            PropertyIdentifier::DefaultFadeTime => 374,
+            PropertyIdentifier::DefaultRampRate => 375,
+            PropertyIdentifier::DefaultStepIncrement => 376,
+            PropertyIdentifier::EgressTime => 377,
+            PropertyIdentifier::InProgress => 378,
+            PropertyIdentifier::InstantaneousPower => 379,
+            PropertyIdentifier::LightingCommand => 380,
+            PropertyIdentifier::LightingCommandDefaultPriority => 381,
+            PropertyIdentifier::MaxActualValue => 382,
+            PropertyIdentifier::MinActualValue => 383,
+            PropertyIdentifier::Power => 384,
+            PropertyIdentifier::Transition => 385,
+            PropertyIdentifier::EgressActive => 386,
+            PropertyIdentifier::InterfaceValue => 387,
+            PropertyIdentifier::FaultHighLimit => 388,
+            PropertyIdentifier::FaultLowLimit => 389,
+            PropertyIdentifier::LowDiffLimit => 390,
+            PropertyIdentifier::StrikeCount => 391,
+            PropertyIdentifier::TimeOfStrikeCountReset => 392,
+            PropertyIdentifier::DefaultTimeout => 393,
+            PropertyIdentifier::InitialTimeout => 394,
+            PropertyIdentifier::LastStateChange => 395,
+            PropertyIdentifier::StateChangeValues => 396,
+            PropertyIdentifier::TimerRunning => 397,
+            PropertyIdentifier::TimerState => 398,
+            PropertyIdentifier::ApduLength => 399,
+            PropertyIdentifier::IpAddress => 400,
+            PropertyIdentifier::IpDefaultGateway => 401,
+            PropertyIdentifier::IpDhcpEnable => 402,
+            PropertyIdentifier::IpDhcpLeaseTime => 403,
+            PropertyIdentifier::IpDhcpLeaseTimeRemaining => 404,
+            PropertyIdentifier::IpDhcpServer => 405,
+            PropertyIdentifier::IpDnsServer => 406,
+            PropertyIdentifier::BacnetIpGlobalAddress => 407,
+            PropertyIdentifier::BacnetIpMode => 408,
+            PropertyIdentifier::BacnetIpMulticastAddress => 409,
+            PropertyIdentifier::BacnetIpNatTraversal => 410,
+            PropertyIdentifier::IpSubnetMask => 411,
+            PropertyIdentifier::BacnetIpUdpPort => 412,
+            PropertyIdentifier::BbmdAcceptFdRegistrations => 413,
+            PropertyIdentifier::BbmdBroadcastDistributionTable => 414,
+            PropertyIdentifier::BbmdForeignDeviceTable => 415,
+            PropertyIdentifier::ChangesPending => 416,
+            PropertyIdentifier::Command => 417,
+            PropertyIdentifier::FdBbmdAddress => 418,
+            PropertyIdentifier::FdSubscriptionLifetime => 419,
+            PropertyIdentifier::LinkSpeed => 420,
+            PropertyIdentifier::LinkSpeeds => 421,
+            PropertyIdentifier::LinkSpeedAutonegotiate => 422,
+            PropertyIdentifier::MacAddress => 423,
+            PropertyIdentifier::NetworkInterfaceName => 424,
+            PropertyIdentifier::NetworkNumber => 425,
+            PropertyIdentifier::NetworkNumberQuality => 426,
+            PropertyIdentifier::NetworkType => 427,
+            PropertyIdentifier::RoutingTable => 428,
+            PropertyIdentifier::VirtualMacAddressTable => 429,
+            PropertyIdentifier::CommandTimeArray => 430,
+            PropertyIdentifier::CurrentCommandPriority => 431,
+            PropertyIdentifier::LastCommandTime => 432,
+            PropertyIdentifier::ValueSource => 433,
+            PropertyIdentifier::ValueSourceArray => 434,
+            PropertyIdentifier::BacnetIpv6Mode => 435,
+            PropertyIdentifier::Ipv6Address => 436,
+            PropertyIdentifier::Ipv6PrefixLength => 437,
+            PropertyIdentifier::BacnetIpv6UdpPort => 438,
+            PropertyIdentifier::Ipv6DefaultGateway => 439,
+            PropertyIdentifier::BacnetIpv6MulticastAddress => 440,
+            PropertyIdentifier::Ipv6DnsServer => 441,
+            PropertyIdentifier::Ipv6AutoAddressingEnable => 442,
+            PropertyIdentifier::Ipv6DhcpLeaseTime => 443,
+            PropertyIdentifier::Ipv6DhcpLeaseTimeRemaining => 444,
+            PropertyIdentifier::Ipv6DhcpServer => 445,
+            PropertyIdentifier::Ipv6ZoneIndex => 446,
+            PropertyIdentifier::AssignedLandingCalls => 447,
+            PropertyIdentifier::CarAssignedDirection => 448,
+            PropertyIdentifier::CarDoorCommand => 449,
+            PropertyIdentifier::CarDoorStatus => 450,
+            PropertyIdentifier::CarDoorText => 451,
+            PropertyIdentifier::CarDoorZone => 452,
+            PropertyIdentifier::CarDriveStatus => 453,
+            PropertyIdentifier::CarLoad => 454,
+            PropertyIdentifier::CarLoadUnits => 455,
+            PropertyIdentifier::CarMode => 456,
+            PropertyIdentifier::CarMovingDirection => 457,
+            PropertyIdentifier::CarPosition => 458,
+            PropertyIdentifier::ElevatorGroup => 459,
+            PropertyIdentifier::EnergyMeter => 460,
+            PropertyIdentifier::EnergyMeterRef => 461,
+            PropertyIdentifier::EscalatorMode => 462,
+            PropertyIdentifier::FaultSignals => 463,
+            PropertyIdentifier::FloorText => 464,
+            PropertyIdentifier::GroupId => 465,
+            PropertyIdentifier::GroupMode => 467,
+            PropertyIdentifier::HigherDeck => 468,
+            PropertyIdentifier::InstallationId => 469,
+            PropertyIdentifier::LandingCalls => 470,
+            PropertyIdentifier::LandingCallControl => 471,
+            PropertyIdentifier::LandingDoorStatus => 472,
+            PropertyIdentifier::LowerDeck => 473,
+            PropertyIdentifier::MachineRoomId => 474,
+            PropertyIdentifier::MakingCarCall => 475,
+            PropertyIdentifier::NextStoppingFloor => 476,
+            PropertyIdentifier::OperationDirection => 477,
+            PropertyIdentifier::PassengerAlarm => 478,
+            PropertyIdentifier::PowerMode => 479,
+            PropertyIdentifier::RegisteredCarCall => 480,
+            PropertyIdentifier::ActiveCovMultipleSubscriptions => 481,
+            PropertyIdentifier::ProtocolLevel => 482,
+            PropertyIdentifier::ReferencePort => 483,
+            PropertyIdentifier::DeployedProfileLocation => 484,
+            PropertyIdentifier::ProfileLocation => 485,
+            PropertyIdentifier::Tags => 486,
+            PropertyIdentifier::SubordinateNodeTypes => 487,
+            PropertyIdentifier::SubordinateTags => 488,
+            PropertyIdentifier::SubordinateRelationships => 489,
+            PropertyIdentifier::DefaultSubordinateRelationship => 490,
+            PropertyIdentifier::Represents => 491,
+            PropertyIdentifier::AuthorizationCache => 4194343,
+            PropertyIdentifier::AuthorizationGroups => 4194344,
+            PropertyIdentifier::AuthorizationPolicy => 4194345,
+            PropertyIdentifier::AuthorizationScope => 4194346,
+            PropertyIdentifier::AuthorizationServer => 4194347,
+            PropertyIdentifier::AuthorizationStatus => 4194348,
+            PropertyIdentifier::Proprietary(value) => *value,
+        }
+    }
+
+    /// Whether this identifier is outside the enumerated standard set
+    pub fn is_proprietary(&self) -> bool {
+        matches!(self, PropertyIdentifier::Proprietary(_))
+    }
+
+    /// Whether the property holds a BACnetARRAY or BACnetLIST
+    ///
+    /// A whole-property read of one of these stays a list even when it has
+    /// zero or one element.
+    pub fn is_array_or_list(&self) -> bool {
+        matches!(
+            self,
+            PropertyIdentifier::Action
+                | PropertyIdentifier::ActionText
+                | PropertyIdentifier::ActiveCovSubscriptions
+                | PropertyIdentifier::ActiveVtSessions
+                | PropertyIdentifier::AlarmValues
+                | PropertyIdentifier::ConfigurationFiles
+                | PropertyIdentifier::DateList
+                | PropertyIdentifier::DeviceAddressBinding
+                | PropertyIdentifier::EventMessageTexts
+                | PropertyIdentifier::EventTimeStamps
+                | PropertyIdentifier::ExceptionSchedule
+                | PropertyIdentifier::FaultValues
+                | PropertyIdentifier::ListOfGroupMembers
+                | PropertyIdentifier::ListOfObjectPropertyReferences
+                | PropertyIdentifier::LogBuffer
+                | PropertyIdentifier::ManualSlaveAddressBinding
+                | PropertyIdentifier::MemberOf
+                | PropertyIdentifier::ObjectList
+                | PropertyIdentifier::PriorityArray
+                | PropertyIdentifier::PropertyList
+                | PropertyIdentifier::RecipientList
+                | PropertyIdentifier::RestartNotificationRecipients
+                | PropertyIdentifier::SlaveAddressBinding
+                | PropertyIdentifier::StateText
+                | PropertyIdentifier::StructuredObjectList
+                | PropertyIdentifier::SubordinateList
+                | PropertyIdentifier::TimeSynchronizationRecipients
+                | PropertyIdentifier::UtcTimeSynchronizationRecipients
+                | PropertyIdentifier::VtClassesSupported
+                | PropertyIdentifier::WeeklySchedule
+                | PropertyIdentifier::ZoneMembers
+        )
+    }
+}
+
+impl From<u32> for PropertyIdentifier {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => PropertyIdentifier::AckedTransitions,
+            1 => PropertyIdentifier::AckRequired,
+            2 => PropertyIdentifier::Action,
+            3 => PropertyIdentifier::ActionText,
+            4 => PropertyIdentifier::ActiveText,
+            5 => PropertyIdentifier::ActiveVtSessions,
+            6 => PropertyIdentifier::AlarmValue,
+            7 => PropertyIdentifier::AlarmValues,
+            8 => PropertyIdentifier::All,
+            9 => PropertyIdentifier::AllWritesSuccessful,
+            10 => PropertyIdentifier::ApduSegmentTimeout,
+            11 => PropertyIdentifier::ApduTimeout,
+            12 => PropertyIdentifier::ApplicationSoftwareVersion,
+            13 => PropertyIdentifier::Archive,
+            14 => PropertyIdentifier::Bias,
+            15 => PropertyIdentifier::ChangeOfStateCount,
+            16 => PropertyIdentifier::ChangeOfStateTime,
+            17 => PropertyIdentifier::NotificationClass,
+            19 => PropertyIdentifier::ControlledVariableReference,
+            20 => PropertyIdentifier::ControlledVariableUnits,
+            21 => PropertyIdentifier::ControlledVariableValue,
+            22 => PropertyIdentifier::CovIncrement,
+            23 => PropertyIdentifier::DateList,
+            24 => PropertyIdentifier::DaylightSavingsStatus,
+            25 => PropertyIdentifier::Deadband,
+            26 => PropertyIdentifier::DerivativeConstant,
+            27 => PropertyIdentifier::DerivativeConstantUnits,
+            28 => PropertyIdentifier::Description,
+            29 => PropertyIdentifier::DescriptionOfHalt,
+            30 => PropertyIdentifier::DeviceAddressBinding,
+            31 => PropertyIdentifier::DeviceType,
+            32 => PropertyIdentifier::EffectivePeriod,
+            33 => PropertyIdentifier::ElapsedActiveTime,
+            34 => PropertyIdentifier::ErrorLimit,
+            35 => PropertyIdentifier::EventEnable,
+            36 => PropertyIdentifier::EventState,
+            37 => PropertyIdentifier::EventType,
+            38 => PropertyIdentifier::ExceptionSchedule,
+            39 => PropertyIdentifier::FaultValues,
+            40 => PropertyIdentifier::FeedbackValue,
+            41 => PropertyIdentifier::FileAccessMethod,
+            42 => PropertyIdentifier::FileSize,
+            43 => PropertyIdentifier::FileType,
+            44 => PropertyIdentifier::FirmwareRevision,
+            45 => PropertyIdentifier::HighLimit,
+            46 => PropertyIdentifier::InactiveText,
+            47 => PropertyIdentifier::InProcess,
+            48 => PropertyIdentifier::InstanceOf,
+            49 => PropertyIdentifier::IntegralConstant,
+            50 => PropertyIdentifier::IntegralConstantUnits,
+            52 => PropertyIdentifier::LimitEnable,
+            53 => PropertyIdentifier::ListOfGroupMembers,
+            54 => PropertyIdentifier::ListOfObjectPropertyReferences,
+            56 => PropertyIdentifier::LocalDate,
+            57 => PropertyIdentifier::LocalTime,
+            58 => PropertyIdentifier::Location,
+            59 => PropertyIdentifier::LowLimit,
+            60 => PropertyIdentifier::ManipulatedVariableReference,
+            61 => PropertyIdentifier::MaximumOutput,
+            62 => PropertyIdentifier::MaxApduLengthAccepted,
+            63 => PropertyIdentifier::MaxInfoFrames,
+            64 => PropertyIdentifier::MaxMaster,
+            65 => PropertyIdentifier::MaxPresValue,
+            66 => PropertyIdentifier::MinimumOffTime,
+            67 => PropertyIdentifier::MinimumOnTime,
+            68 => PropertyIdentifier::MinimumOutput,
+            69 => PropertyIdentifier::MinPresValue,
+            70 => PropertyIdentifier::ModelName,
+            71 => PropertyIdentifier::ModificationDate,
+            72 => PropertyIdentifier::NotifyType,
+            73 => PropertyIdentifier::NumberOfApduRetries,
+            74 => PropertyIdentifier::NumberOfStates,
+            75 => PropertyIdentifier::ObjectIdentifier,
+            76 => PropertyIdentifier::ObjectList,
+            77 => PropertyIdentifier::ObjectName,
+            78 => PropertyIdentifier::ObjectPropertyReference,
+            79 => PropertyIdentifier::ObjectType,
+            80 => PropertyIdentifier::Optional,
+            81 => PropertyIdentifier::OutOfService,
+            82 => PropertyIdentifier::OutputUnits,
+            83 => PropertyIdentifier::EventParameters,
+            84 => PropertyIdentifier::Polarity,
+            85 => PropertyIdentifier::PresentValue,
+            86 => PropertyIdentifier::Priority,
+            87 => PropertyIdentifier::PriorityArray,
+            88 => PropertyIdentifier::PriorityForWriting,
+            89 => PropertyIdentifier::ProcessIdentifier,
+            90 => PropertyIdentifier::ProgramChange,
+            91 => PropertyIdentifier::ProgramLocation,
+            92 => PropertyIdentifier::ProgramState,
+            93 => PropertyIdentifier::ProportionalConstant,
+            94 => PropertyIdentifier::ProportionalConstantUnits,
+            96 => PropertyIdentifier::ProtocolObjectTypesSupported,
+            97 => PropertyIdentifier::ProtocolServicesSupported,
+            98 => PropertyIdentifier::ProtocolVersion,
+            99 => PropertyIdentifier::ReadOnly,
+            100 => PropertyIdentifier::ReasonForHalt,
+            102 => PropertyIdentifier::RecipientList,
+            103 => PropertyIdentifier::Reliability,
+            104 => PropertyIdentifier::RelinquishDefault,
+            105 => PropertyIdentifier::Required,
+            106 => PropertyIdentifier::Resolution,
+            107 => PropertyIdentifier::SegmentationSupported,
+            108 => PropertyIdentifier::Setpoint,
+            109 => PropertyIdentifier::SetpointReference,
+            110 => PropertyIdentifier::StateText,
+            111 => PropertyIdentifier::StatusFlags,
+            112 => PropertyIdentifier::SystemStatus,
+            113 => PropertyIdentifier::TimeDelay,
+            114 => PropertyIdentifier::TimeOfActiveTimeReset,
+            115 => PropertyIdentifier::TimeOfStateCountReset,
+            116 => PropertyIdentifier::TimeSynchronizationRecipients,
+            117 => PropertyIdentifier::Units,
+            118 => PropertyIdentifier::UpdateInterval,
+            119 => PropertyIdentifier::UtcOffset,
+            120 => PropertyIdentifier::VendorIdentifier,
+            121 => PropertyIdentifier::VendorName,
+            122 => PropertyIdentifier::VtClassesSupported,
+            123 => PropertyIdentifier::WeeklySchedule,
+            124 => PropertyIdentifier::AttemptedSamples,
+            125 => PropertyIdentifier::AverageValue,
+            126 => PropertyIdentifier::BufferSize,
+            127 => PropertyIdentifier::ClientCovIncrement,
+            128 => PropertyIdentifier::CovResubscriptionInterval,
+            130 => PropertyIdentifier::EventTimeStamps,
+            131 => PropertyIdentifier::LogBuffer,
+            132 => PropertyIdentifier::LogDeviceObjectProperty,
+            133 => PropertyIdentifier::Enable,
+            134 => PropertyIdentifier::LogInterval,
+            135 => PropertyIdentifier::MaximumValue,
+            136 => PropertyIdentifier::MinimumValue,
+            137 => PropertyIdentifier::NotificationThreshold,
+            139 => PropertyIdentifier::ProtocolRevision,
+            140 => PropertyIdentifier::RecordsSinceNotification,
+            141 => PropertyIdentifier::RecordCount,
+            142 => PropertyIdentifier::StartTime,
+            143 => PropertyIdentifier::StopTime,
+            144 => PropertyIdentifier::StopWhenFull,
+            145 => PropertyIdentifier::TotalRecordCount,
+            146 => PropertyIdentifier::ValidSamples,
+            147 => PropertyIdentifier::WindowInterval,
+            148 => PropertyIdentifier::WindowSamples,
+            149 => PropertyIdentifier::MaximumValueTimestamp,
+            150 => PropertyIdentifier::MinimumValueTimestamp,
+            151 => PropertyIdentifier::VarianceValue,
+            152 => PropertyIdentifier::ActiveCovSubscriptions,
+            153 => PropertyIdentifier::BackupFailureTimeout,
+            154 => PropertyIdentifier::ConfigurationFiles,
+            155 => PropertyIdentifier::DatabaseRevision,
+            156 => PropertyIdentifier::DirectReading,
+            157 => PropertyIdentifier::LastRestoreTime,
+            158 => PropertyIdentifier::MaintenanceRequired,
+            159 => PropertyIdentifier::MemberOf,
+            160 => PropertyIdentifier::Mode,
+            161 => PropertyIdentifier::OperationExpected,
+            162 => PropertyIdentifier::Setting,
+            163 => PropertyIdentifier::Silenced,
+            164 => PropertyIdentifier::TrackingValue,
+            165 => PropertyIdentifier::ZoneMembers,
+            166 => PropertyIdentifier::LifeSafetyAlarmValues,
+            167 => PropertyIdentifier::MaxSegmentsAccepted,
+            168 => PropertyIdentifier::ProfileName,
+            169 => PropertyIdentifier::AutoSlaveDiscovery,
+            170 => PropertyIdentifier::ManualSlaveAddressBinding,
+            171 => PropertyIdentifier::SlaveAddressBinding,
+            172 => PropertyIdentifier::SlaveProxyEnable,
+            173 => PropertyIdentifier::LastNotifyRecord,
+            174 => PropertyIdentifier::ScheduleDefault,
+            175 => PropertyIdentifier::AcceptedModes,
+            176 => PropertyIdentifier::AdjustValue,
+            177 => PropertyIdentifier::Count,
+            178 => PropertyIdentifier::CountBeforeChange,
+            179 => PropertyIdentifier::CountChangeTime,
+            180 => PropertyIdentifier::CovPeriod,
+            181 => PropertyIdentifier::InputReference,
+            182 => PropertyIdentifier::LimitMonitoringInterval,
+            183 => PropertyIdentifier::LoggingObject,
+            184 => PropertyIdentifier::LoggingRecord,
+            185 => PropertyIdentifier::Prescale,
+            186 => PropertyIdentifier::PulseRate,
+            187 => PropertyIdentifier::Scale,
+            188 => PropertyIdentifier::ScaleFactor,
+            189 => PropertyIdentifier::UpdateTime,
+            190 => PropertyIdentifier::ValueBeforeChange,
+            191 => PropertyIdentifier::ValueSet,
+            192 => PropertyIdentifier::ValueChangeTime,
+            193 => PropertyIdentifier::AlignIntervals,
+            195 => PropertyIdentifier::IntervalOffset,
+            196 => PropertyIdentifier::LastRestartReason,
+            197 => PropertyIdentifier::LoggingType,
+            202 => PropertyIdentifier::RestartNotificationRecipients,
+            203 => PropertyIdentifier::TimeOfDeviceRestart,
+            204 => PropertyIdentifier::TimeSynchronizationInterval,
+            205 => PropertyIdentifier::Trigger,
+            206 => PropertyIdentifier::UtcTimeSynchronizationRecipients,
+            207 => PropertyIdentifier::NodeSubtype,
+            208 => PropertyIdentifier::NodeType,
+            209 => PropertyIdentifier::StructuredObjectList,
+            210 => PropertyIdentifier::SubordinateAnnotations,
+            211 => PropertyIdentifier::SubordinateList,
+            212 => PropertyIdentifier::ActualShedLevel,
+            213 => PropertyIdentifier::DutyWindow,
+            214 => PropertyIdentifier::ExpectedShedLevel,
+            215 => PropertyIdentifier::FullDutyBaseline,
+            218 => PropertyIdentifier::RequestedShedLevel,
+            219 => PropertyIdentifier::ShedDuration,
+            220 => PropertyIdentifier::ShedLevelDescriptions,
+            221 => PropertyIdentifier::ShedLevels,
+            222 => PropertyIdentifier::StateDescription,
+            226 => PropertyIdentifier::DoorAlarmState,
+            227 => PropertyIdentifier::DoorExtendedPulseTime,
+            228 => PropertyIdentifier::DoorMembers,
+            229 => PropertyIdentifier::DoorOpenTooLongTime,
+            230 => PropertyIdentifier::DoorPulseTime,
+            231 => PropertyIdentifier::DoorStatus,
+            232 => PropertyIdentifier::DoorUnlockDelayTime,
+            233 => PropertyIdentifier::LockStatus,
+            234 => PropertyIdentifier::MaskedAlarmValues,
+            235 => PropertyIdentifier::SecuredStatus,
+            244 => PropertyIdentifier::AbsenteeLimit,
+            245 => PropertyIdentifier::AccessAlarmEvents,
+            246 => PropertyIdentifier::AccessDoors,
+            247 => PropertyIdentifier::AccessEvent,
+            248 => PropertyIdentifier::AccessEventAuthenticationFactor,
+            249 => PropertyIdentifier::AccessEventCredential,
+            250 => PropertyIdentifier::AccessEventTime,
+            251 => PropertyIdentifier::AccessTransactionEvents,
+            252 => PropertyIdentifier::Accompaniment,
+            253 => PropertyIdentifier::AccompanimentTime,
+            254 => PropertyIdentifier::ActivationTime,
+            255 => PropertyIdentifier::ActiveAuthenticationPolicy,
+            256 => PropertyIdentifier::AssignedAccessRights,
+            257 => PropertyIdentifier::AuthenticationFactors,
+            258 => PropertyIdentifier::AuthenticationPolicyList,
+            259 => PropertyIdentifier::AuthenticationPolicyNames,
+            260 => PropertyIdentifier::AuthenticationStatus,
+            261 => PropertyIdentifier::AuthorizationMode,
+            262 => PropertyIdentifier::BelongsTo,
+            263 => PropertyIdentifier::CredentialDisable,
+            264 => PropertyIdentifier::CredentialStatus,
+            265 => PropertyIdentifier::Credentials,
+            266 => PropertyIdentifier::CredentialsInZone,
+            267 => PropertyIdentifier::DaysRemaining,
+            268 => PropertyIdentifier::EntryPoints,
+            269 => PropertyIdentifier::ExitPoints,
+            270 => PropertyIdentifier::ExpirationTime,
+            271 => PropertyIdentifier::ExtendedTimeEnable,
+            272 => PropertyIdentifier::FailedAttemptEvents,
+            273 => PropertyIdentifier::FailedAttempts,
+            274 => PropertyIdentifier::FailedAttemptsTime,
+            275 => PropertyIdentifier::LastAccessEvent,
+            276 => PropertyIdentifier::LastAccessPoint,
+            277 => PropertyIdentifier::LastCredentialAdded,
+            278 => PropertyIdentifier::LastCredentialAddedTime,
+            279 => PropertyIdentifier::LastCredentialRemoved,
+            280 => PropertyIdentifier::LastCredentialRemovedTime,
+            281 => PropertyIdentifier::LastUseTime,
+            282 => PropertyIdentifier::Lockout,
+            283 => PropertyIdentifier::LockoutRelinquishTime,
+            285 => PropertyIdentifier::MaxFailedAttempts,
+            286 => PropertyIdentifier::Members,
+            287 => PropertyIdentifier::MusterPoint,
+            288 => PropertyIdentifier::NegativeAccessRules,
+            289 => PropertyIdentifier::NumberOfAuthenticationPolicies,
+            290 => PropertyIdentifier::OccupancyCount,
+            291 => PropertyIdentifier::OccupancyCountAdjust,
+            292 => PropertyIdentifier::OccupancyCountEnable,
+            294 => PropertyIdentifier::OccupancyLowerLimit,
+            295 => PropertyIdentifier::OccupancyLowerLimitEnforced,
+            296 => PropertyIdentifier::OccupancyState,
+            297 => PropertyIdentifier::OccupancyUpperLimit,
+            298 => PropertyIdentifier::OccupancyUpperLimitEnforced,
+            300 => PropertyIdentifier::PassbackMode,
+            301 => PropertyIdentifier::PassbackTimeout,
+            302 => PropertyIdentifier::PositiveAccessRules,
+            303 => PropertyIdentifier::ReasonForDisable,
+            304 => PropertyIdentifier::SupportedFormats,
+            305 => PropertyIdentifier::SupportedFormatClasses,
+            306 => PropertyIdentifier::ThreatAuthority,
+            307 => PropertyIdentifier::ThreatLevel,
+            308 => PropertyIdentifier::TraceFlag,
+            309 => PropertyIdentifier::TransactionNotificationClass,
+            310 => PropertyIdentifier::UserExternalIdentifier,
+            311 => PropertyIdentifier::UserInformationReference,
+            317 => PropertyIdentifier::UserName,
+            318 => PropertyIdentifier::UserType,
+            319 => PropertyIdentifier::UsesRemaining,
+            320 => PropertyIdentifier::ZoneFrom,
+            321 => PropertyIdentifier::ZoneTo,
+            322 => PropertyIdentifier::AccessEventTag,
+            323 => PropertyIdentifier::GlobalIdentifier,
+            326 => PropertyIdentifier::VerificationTime,
+            327 => PropertyIdentifier::BaseDeviceSecurityPolicy,
+            328 => PropertyIdentifier::DistributionKeyRevision,
+            329 => PropertyIdentifier::DoNotHide,
+            330 => PropertyIdentifier::KeySets,
+            331 => PropertyIdentifier::LastKeyServer,
+            332 => PropertyIdentifier::NetworkAccessSecurityPolicies,
+            333 => PropertyIdentifier::PacketReorderTime,
+            334 => PropertyIdentifier::SecurityPduTimeout,
+            335 => PropertyIdentifier::SecurityTimeWindow,
+            336 => PropertyIdentifier::SupportedSecurityAlgorithms,
+            337 => PropertyIdentifier::UpdateKeySetTimeout,
+            338 => PropertyIdentifier::BackupAndRestoreState,
+            339 => PropertyIdentifier::BackupPreparationTime,
+            340 => PropertyIdentifier::RestoreCompletionTime,
+            341 => PropertyIdentifier::RestorePreparationTime,
+            342 => PropertyIdentifier::BitMask,
+            343 => PropertyIdentifier::BitText,
+            344 => PropertyIdentifier::IsUtc,
+            345 => PropertyIdentifier::GroupMembers,
+            346 => PropertyIdentifier::GroupMemberNames,
+            347 => PropertyIdentifier::MemberStatusFlags,
+            348 => PropertyIdentifier::RequestedUpdateInterval,
+            349 => PropertyIdentifier::CovuPeriod,
+            350 => PropertyIdentifier::CovuRecipients,
+            351 => PropertyIdentifier::EventMessageTexts,
+            352 => PropertyIdentifier::EventMessageTextsConfig,
+            353 => PropertyIdentifier::EventDetectionEnable,
+            354 => PropertyIdentifier::EventAlgorithmInhibit,
+            355 => PropertyIdentifier::EventAlgorithmInhibitRef,
+            356 => PropertyIdentifier::TimeDelayNormal,
+            357 => PropertyIdentifier::ReliabilityEvaluationInhibit,
+            358 => PropertyIdentifier::FaultParameters,
+            359 => PropertyIdentifier::FaultType,
+            360 => PropertyIdentifier::LocalForwardingOnly,
+            361 => PropertyIdentifier::ProcessIdentifierFilter,
+            362 => PropertyIdentifier::SubscribedRecipients,
+            363 => PropertyIdentifier::PortFilter,
+            364 => PropertyIdentifier::AuthorizationExemptions,
+            365 => PropertyIdentifier::AllowGroupDelayInhibit,
+            366 => PropertyIdentifier::ChannelNumber,
+            367 => PropertyIdentifier::ControlGroups,
+            368 => PropertyIdentifier::ExecutionDelay,
+            369 => PropertyIdentifier::LastPriority,
+            370 => PropertyIdentifier::WriteStatus,
+            371 => PropertyIdentifier::PropertyList,
+            372 => PropertyIdentifier::SerialNumber,
+            373 => PropertyIdentifier::BlinkWarnEnable,
+            374 => PropertyIdentifier::DefaultFadeTime,
+            375 => PropertyIdentifier::DefaultRampRate,
+            376 => PropertyIdentifier::DefaultStepIncrement,
+            377 => PropertyIdentifier::EgressTime,
+            378 => PropertyIdentifier::InProgress,
+            379 => PropertyIdentifier::InstantaneousPower,
+            380 => PropertyIdentifier::LightingCommand,
+            381 => PropertyIdentifier::LightingCommandDefaultPriority,
+            382 => PropertyIdentifier::MaxActualValue,
+            383 => PropertyIdentifier::MinActualValue,
+            384 => PropertyIdentifier::Power,
+            385 => PropertyIdentifier::Transition,
+            386 => PropertyIdentifier::EgressActive,
+            387 => PropertyIdentifier::InterfaceValue,
+            388 => PropertyIdentifier::FaultHighLimit,
+            389 => PropertyIdentifier::FaultLowLimit,
+            390 => PropertyIdentifier::LowDiffLimit,
+            391 => PropertyIdentifier::StrikeCount,
+            392 => PropertyIdentifier::TimeOfStrikeCountReset,
+            393 => PropertyIdentifier::DefaultTimeout,
+            394 => PropertyIdentifier::InitialTimeout,
+            395 => PropertyIdentifier::LastStateChange,
+            396 => PropertyIdentifier::StateChangeValues,
+            397 => PropertyIdentifier::TimerRunning,
+            398 => PropertyIdentifier::TimerState,
+            399 => PropertyIdentifier::ApduLength,
+            400 => PropertyIdentifier::IpAddress,
+            401 => PropertyIdentifier::IpDefaultGateway,
+            402 => PropertyIdentifier::IpDhcpEnable,
+            403 => PropertyIdentifier::IpDhcpLeaseTime,
+            404 => PropertyIdentifier::IpDhcpLeaseTimeRemaining,
+            405 => PropertyIdentifier::IpDhcpServer,
+            406 => PropertyIdentifier::IpDnsServer,
+            407 => PropertyIdentifier::BacnetIpGlobalAddress,
+            408 => PropertyIdentifier::BacnetIpMode,
+            409 => PropertyIdentifier::BacnetIpMulticastAddress,
+            410 => PropertyIdentifier::BacnetIpNatTraversal,
+            411 => PropertyIdentifier::IpSubnetMask,
+            412 => PropertyIdentifier::BacnetIpUdpPort,
+            413 => PropertyIdentifier::BbmdAcceptFdRegistrations,
+            414 => PropertyIdentifier::BbmdBroadcastDistributionTable,
+            415 => PropertyIdentifier::BbmdForeignDeviceTable,
+            416 => PropertyIdentifier::ChangesPending,
+            417 => PropertyIdentifier::Command,
+            418 => PropertyIdentifier::FdBbmdAddress,
+            419 => PropertyIdentifier::FdSubscriptionLifetime,
+            420 => PropertyIdentifier::LinkSpeed,
+            421 => PropertyIdentifier::LinkSpeeds,
+            422 => PropertyIdentifier::LinkSpeedAutonegotiate,
+            423 => PropertyIdentifier::MacAddress,
+            424 => PropertyIdentifier::NetworkInterfaceName,
+            425 => PropertyIdentifier::NetworkNumber,
+            426 => PropertyIdentifier::NetworkNumberQuality,
+            427 => PropertyIdentifier::NetworkType,
+            428 => PropertyIdentifier::RoutingTable,
+            429 => PropertyIdentifier::VirtualMacAddressTable,
+            430 => PropertyIdentifier::CommandTimeArray,
+            431 => PropertyIdentifier::CurrentCommandPriority,
+            432 => PropertyIdentifier::LastCommandTime,
+            433 => PropertyIdentifier::ValueSource,
+            434 => PropertyIdentifier::ValueSourceArray,
+            435 => PropertyIdentifier::BacnetIpv6Mode,
+            436 => PropertyIdentifier::Ipv6Address,
+            437 => PropertyIdentifier::Ipv6PrefixLength,
+            438 => PropertyIdentifier::BacnetIpv6UdpPort,
+            439 => PropertyIdentifier::Ipv6DefaultGateway,
+            440 => PropertyIdentifier::BacnetIpv6MulticastAddress,
+            441 => PropertyIdentifier::Ipv6DnsServer,
+            442 => PropertyIdentifier::Ipv6AutoAddressingEnable,
+            443 => PropertyIdentifier::Ipv6DhcpLeaseTime,
+            444 => PropertyIdentifier::Ipv6DhcpLeaseTimeRemaining,
+            445 => PropertyIdentifier::Ipv6DhcpServer,
+            446 => PropertyIdentifier::Ipv6ZoneIndex,
+            447 => PropertyIdentifier::AssignedLandingCalls,
+            448 => PropertyIdentifier::CarAssignedDirection,
+            449 => PropertyIdentifier::CarDoorCommand,
+            450 => PropertyIdentifier::CarDoorStatus,
+            451 => PropertyIdentifier::CarDoorText,
+            452 => PropertyIdentifier::CarDoorZone,
+            453 => PropertyIdentifier::CarDriveStatus,
+            454 => PropertyIdentifier::CarLoad,
+            455 => PropertyIdentifier::CarLoadUnits,
+            456 => PropertyIdentifier::CarMode,
+            457 => PropertyIdentifier::CarMovingDirection,
+            458 => PropertyIdentifier::CarPosition,
+            459 => PropertyIdentifier::ElevatorGroup,
+            460 => PropertyIdentifier::EnergyMeter,
+            461 => PropertyIdentifier::EnergyMeterRef,
+            462 => PropertyIdentifier::EscalatorMode,
+            463 => PropertyIdentifier::FaultSignals,
+            464 => PropertyIdentifier::FloorText,
+            465 => PropertyIdentifier::GroupId,
+            467 => PropertyIdentifier::GroupMode,
+            468 => PropertyIdentifier::HigherDeck,
+            469 => PropertyIdentifier::InstallationId,
+            470 => PropertyIdentifier::LandingCalls,
+            471 => PropertyIdentifier::LandingCallControl,
+            472 => PropertyIdentifier::LandingDoorStatus,
+            473 => PropertyIdentifier::LowerDeck,
+            474 => PropertyIdentifier::MachineRoomId,
+            475 => PropertyIdentifier::MakingCarCall,
+            476 => PropertyIdentifier::NextStoppingFloor,
+            477 => PropertyIdentifier::OperationDirection,
+            478 => PropertyIdentifier::PassengerAlarm,
+            479 => PropertyIdentifier::PowerMode,
+            480 => PropertyIdentifier::RegisteredCarCall,
+            481 => PropertyIdentifier::ActiveCovMultipleSubscriptions,
+            482 => PropertyIdentifier::ProtocolLevel,
+            483 => PropertyIdentifier::ReferencePort,
+            484 => PropertyIdentifier::DeployedProfileLocation,
+            485 => PropertyIdentifier::ProfileLocation,
+            486 => PropertyIdentifier::Tags,
+            487 => PropertyIdentifier::SubordinateNodeTypes,
+            488 => PropertyIdentifier::SubordinateTags,
+            489 => PropertyIdentifier::SubordinateRelationships,
+            490 => PropertyIdentifier::DefaultSubordinateRelationship,
+            491 => PropertyIdentifier::Represents,
+            4194343 => PropertyIdentifier::AuthorizationCache,
+            4194344 => PropertyIdentifier::AuthorizationGroups,
+            4194345 => PropertyIdentifier::AuthorizationPolicy,
+            4194346 => PropertyIdentifier::AuthorizationScope,
+            4194347 => PropertyIdentifier::AuthorizationServer,
+            4194348 => PropertyIdentifier::AuthorizationStatus,
+            _ => PropertyIdentifier::Proprietary(value),
+        }
+    }
+}
+
+impl From<PropertyIdentifier> for u32 {
+    fn from(property: PropertyIdentifier) -> Self {
+        property.as_u32()
+    }
 }
 
 /// Object identifier (type + instance number)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ObjectIdentifierFields"))]
 pub struct ObjectIdentifier {
     pub object_type: ObjectType,
     pub instance: u32,
 }
 
+/// Unchecked fields of a deserialized object identifier
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ObjectIdentifierFields {
+    object_type: ObjectType,
+    instance: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ObjectIdentifierFields> for ObjectIdentifier {
+    type Error = ObjectError;
+
+    fn try_from(fields: ObjectIdentifierFields) -> Result<Self> {
+        Self::try_new(fields.object_type, fields.instance)
+    }
+}
+
 impl ObjectIdentifier {
+    /// Largest instance number that fits in the 22-bit field; 4194303 is
+    /// also the wildcard instance used to address "this device"
+    pub const MAX_INSTANCE: u32 = 0x3FFFFF;
+
     /// Create a new object identifier
     pub fn new(object_type: ObjectType, instance: u32) -> Self {
         Self {
@@ -345,14 +2121,85 @@ impl ObjectIdentifier {
         }
     }
 
-    /// Check if instance number is valid (0-4194302)
+    /// Create an object identifier, checking the instance fits in 22 bits
+    /// and a vendor-specific object type is in range
+    pub fn try_new(object_type: ObjectType, instance: u32) -> Result<Self> {
+        let identifier = Self::new(object_type, instance);
+        identifier.validate()?;
+        Ok(identifier)
+    }
+
+    /// Check if instance number is valid (0-4194302) and a reserved or
+    /// vendor-specific object type is in range
     pub fn is_valid(&self) -> bool {
-        self.instance <= 0x3FFFFF
+        self.instance <= Self::MAX_INSTANCE && self.object_type.is_valid()
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let ObjectType::Proprietary(value) = self.object_type {
+            ObjectType::proprietary(value)?;
+        }
+        if !self.object_type.is_valid() {
+            return Err(ObjectError::InvalidValue(format!(
+                "Object type {} is not a reserved type (65-127)",
+                self.object_type.as_u16()
+            )));
+        }
+        if self.instance > Self::MAX_INSTANCE {
+            return Err(ObjectError::InvalidValue(format!(
+                "Instance {} exceeds {}",
+                self.instance,
+                Self::MAX_INSTANCE
+            )));
+        }
+        Ok(())
+    }
+
+    /// Encode to the 4-byte wire form: 10-bit object type, 22-bit instance
+    pub fn encode(&self) -> Result<[u8; 4]> {
+        self.validate()?;
+        let value = ((self.object_type.as_u16() as u32) << 22) | self.instance;
+        Ok(value.to_be_bytes())
+    }
+
+    /// Decode from the 4-byte wire form
+    pub fn decode(bytes: [u8; 4]) -> Result<Self> {
+        Ok(Self::from_u32(u32::from_be_bytes(bytes)))
+    }
+
+    /// Unpack from the 32-bit form: 10-bit object type, 22-bit instance
+    ///
+    /// Every value decodes; reserved object types become `Reserved` and
+    /// vendor-specific ones `Proprietary`.
+    pub fn from_u32(value: u32) -> Self {
+        let object_type = (value >> 22) as u16;
+        // Any 10-bit object type converts, so the fallback is never taken
+        let object_type =
+            ObjectType::try_from(object_type).unwrap_or(ObjectType::Proprietary(object_type));
+        Self::new(object_type, value & Self::MAX_INSTANCE)
+    }
+
+    /// Pack into the 32-bit form; instance bits above 22 are dropped
+    pub fn to_u32(&self) -> u32 {
+        ((self.object_type.as_u16() as u32 & 0x3FF) << 22) | (self.instance & Self::MAX_INSTANCE)
+    }
+
+    /// Encode as an application tagged object identifier (tag 12)
+    pub fn encode_application(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        encoding::encode_object_identifier(buffer, self.object_type.as_u16(), self.instance)
+    }
+
+    /// Decode an application tagged object identifier, returning it and the bytes consumed
+    pub fn decode_application(data: &[u8]) -> encoding::Result<(Self, usize)> {
+        let ((object_type, instance), consumed) = encoding::decode_object_identifier(data)?;
+        let object_type =
+            ObjectType::try_from(object_type).map_err(|_| EncodingError::ValueOutOfRange)?;
+        Ok((Self::new(object_type, instance), consumed))
     }
 }
 
 /// Trait for all BACnet objects
-pub trait BacnetObject: Send + Sync {
+pub trait BacnetObject: Any + Send + Sync {
     /// Get the object identifier
     fn identifier(&self) -> ObjectIdentifier;
 
@@ -367,10 +2214,163 @@ pub trait BacnetObject: Send + Sync {
 
     /// Get list of all properties
     fn property_list(&self) -> Vec<PropertyIdentifier>;
+
+    /// Write a property at a command priority (WriteProperty semantics)
+    ///
+    /// Commandable objects route Present_Value writes into their priority
+    /// array, using the lowest priority (16) when none is given. Other
+    /// objects and properties ignore the priority.
+    fn write_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        _priority: Option<u8>,
+    ) -> Result<()> {
+        self.set_property(property, value)
+    }
+
+    /// Update a property from a value reported by a remote device
+    ///
+    /// Used to mirror remote objects, e.g. from COV notifications. Inputs
+    /// accept their monitored values here even though those are not
+    /// writable over the network; the default forwards to `set_property`.
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        self.set_property(property, value)
+    }
+
+    /// Read a property, or one element of it when it is an array
+    ///
+    /// Index 0 reads the number of elements and indices from 1 read a
+    /// single element; without an index the whole value is returned.
+    fn get_property_element(
+        &self,
+        property: PropertyIdentifier,
+        array_index: Option<u32>,
+    ) -> Result<PropertyValue> {
+        let value = self.get_property(property)?;
+        match array_index {
+            Some(index) => array_element(value, index),
+            None => Ok(value),
+        }
+    }
+
+    /// Write a property, or one element of it when it is an array
+    ///
+    /// Writing Priority_Array element N commands Present_Value at priority
+    /// N, with NULL relinquishing. Other arrays have the element replaced
+    /// and the whole array written back; their size cannot be written.
+    fn write_property_element(
+        &mut self,
+        property: PropertyIdentifier,
+        array_index: Option<u32>,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        let Some(index) = array_index else {
+            return self.write_property(property, value, priority);
+        };
+        let PropertyValue::Array(mut elements) = self.get_property(property)? else {
+            return Err(ObjectError::PropertyIsNotAnArray);
+        };
+        if index == 0 {
+            return Err(ObjectError::WriteAccessDenied);
+        }
+        if index as usize > elements.len() {
+            return Err(ObjectError::InvalidArrayIndex);
+        }
+        if property == PropertyIdentifier::PriorityArray {
+            return self.write_property(PropertyIdentifier::PresentValue, value, Some(index as u8));
+        }
+        elements[index as usize - 1] = value;
+        self.set_property(property, PropertyValue::Array(elements))
+    }
+
+    /// Set the Object_Name from a BACnet character string
+    fn set_object_name(&mut self, name: &CharacterString) -> Result<()> {
+        let text = name.to_text().ok_or_else(|| {
+            ObjectError::InvalidValue("Unsupported character set for object name".to_string())
+        })?;
+        validate_object_name(&text)?;
+        self.set_property(
+            PropertyIdentifier::ObjectName,
+            PropertyValue::CharacterString(text),
+        )
+    }
+}
+
+/// Error for a write an object does not accept
+///
+/// Writing a property the object has is denied as read only; any other
+/// property is unknown to the object.
+pub(crate) fn write_rejected(
+    object: &dyn BacnetObject,
+    property: PropertyIdentifier,
+) -> ObjectError {
+    if object.property_list().contains(&property) {
+        ObjectError::WriteAccessDenied
+    } else {
+        ObjectError::UnknownProperty
+    }
+}
+
+/// Read a property that only has a context tagged encoding
+///
+/// The encoder fills a buffer that becomes `PropertyValue::Constructed`;
+/// an encoding failure means the object holds an unrepresentable value.
+pub(crate) fn constructed_value(
+    encode: impl FnOnce(&mut Vec<u8>) -> encoding::Result<()>,
+) -> Result<PropertyValue> {
+    let mut buffer = Vec::new();
+    encode(&mut buffer).map_err(|e| ObjectError::InvalidConfiguration(e.to_string()))?;
+    Ok(PropertyValue::Constructed(buffer))
+}
+
+/// Decode a written Units value
+///
+/// Values past the 16-bit range of the engineering units enumeration are
+/// out of range.
+pub(crate) fn units_value(value: PropertyValue) -> Result<EngineeringUnits> {
+    let PropertyValue::Enumerated(units) = value else {
+        return Err(ObjectError::InvalidPropertyType);
+    };
+    EngineeringUnits::from_u32(units)
+        .ok_or_else(|| ObjectError::InvalidValue(format!("Invalid engineering units: {}", units)))
+}
+
+/// Select an element of an array value, or its length for index 0
+pub(crate) fn array_element(value: PropertyValue, index: u32) -> Result<PropertyValue> {
+    let PropertyValue::Array(mut elements) = value else {
+        return Err(ObjectError::PropertyIsNotAnArray);
+    };
+    if index == 0 {
+        return Ok(PropertyValue::UnsignedInteger(elements.len() as u32));
+    }
+    if index as usize > elements.len() {
+        return Err(ObjectError::InvalidArrayIndex);
+    }
+    Ok(elements.swap_remove(index as usize - 1))
+}
+
+impl dyn BacnetObject {
+    /// Recover the concrete object type behind a trait object
+    pub fn downcast_ref<T: BacnetObject>(&self) -> Option<&T> {
+        (self as &dyn Any).downcast_ref::<T>()
+    }
+
+    /// Recover the concrete object type behind a trait object, mutably
+    pub fn downcast_mut<T: BacnetObject>(&mut self) -> Option<&mut T> {
+        (self as &mut dyn Any).downcast_mut::<T>()
+    }
 }
 
 /// Property values can be of various types
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value"))]
 pub enum PropertyValue {
     Null,
     Boolean(bool),
@@ -387,10 +2387,136 @@ pub enum PropertyValue {
     ObjectIdentifier(ObjectIdentifier),
     Array(Vec<PropertyValue>),
     List(Vec<PropertyValue>),
+    /// Context tagged data, such as a Date_List or Weekly_Schedule, kept in
+    /// its encoded form because it has no application tagged equivalent
+    Constructed(Vec<u8>),
+}
+
+impl PropertyValue {
+    /// Encode as application tagged data
+    ///
+    /// Arrays and lists are encoded as the concatenation of their elements.
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        match self {
+            PropertyValue::Null => {
+                encoding::encode_application_tag(buffer, ApplicationTag::Null, 0)
+            }
+            PropertyValue::Boolean(value) => encoding::encode_boolean(buffer, *value),
+            PropertyValue::UnsignedInteger(value) => encoding::encode_unsigned(buffer, *value),
+            PropertyValue::SignedInt(value) => encoding::encode_signed(buffer, *value),
+            PropertyValue::Real(value) => encoding::encode_real(buffer, *value),
+            PropertyValue::Double(value) => encoding::encode_double(buffer, *value),
+            PropertyValue::OctetString(value) => encoding::encode_octet_string(buffer, value),
+            PropertyValue::CharacterString(value) => {
+                encoding::encode_character_string(buffer, value)
+            }
+            PropertyValue::BitString(bits) => encoding::encode_bit_string(buffer, bits),
+            PropertyValue::Enumerated(value) => encoding::encode_enumerated(buffer, *value),
+            PropertyValue::Date(date) => {
+                encoding::encode_date(buffer, date.year, date.month, date.day, date.weekday)
+            }
+            PropertyValue::Time(time) => {
+                encoding::encode_time(buffer, time.hour, time.minute, time.second, time.hundredths)
+            }
+            PropertyValue::ObjectIdentifier(id) => {
+                encoding::encode_object_identifier(buffer, id.object_type.as_u16(), id.instance)
+            }
+            PropertyValue::Array(values) | PropertyValue::List(values) => {
+                for value in values {
+                    value.encode(buffer)?;
+                }
+                Ok(())
+            }
+            PropertyValue::Constructed(data) => {
+                buffer.extend_from_slice(data);
+                Ok(())
+            }
+        }
+    }
+
+    /// Decode one application tagged value, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> encoding::Result<(PropertyValue, usize)> {
+        let (tag, _, header) = encoding::decode_application_tag(data)?;
+        if data[0] & 0x08 != 0 {
+            // Context tags carry no type information
+            return Err(EncodingError::InvalidTag);
+        }
+
+        let value = match tag {
+            ApplicationTag::Null => (PropertyValue::Null, header),
+            ApplicationTag::Boolean => {
+                let (value, consumed) = encoding::decode_boolean(data)?;
+                (PropertyValue::Boolean(value), consumed)
+            }
+            ApplicationTag::UnsignedInt => {
+                let (value, consumed) = encoding::decode_unsigned(data)?;
+                (PropertyValue::UnsignedInteger(value), consumed)
+            }
+            ApplicationTag::SignedInt => {
+                let (value, consumed) = encoding::decode_signed(data)?;
+                (PropertyValue::SignedInt(value), consumed)
+            }
+            ApplicationTag::Real => {
+                let (value, consumed) = encoding::decode_real(data)?;
+                (PropertyValue::Real(value), consumed)
+            }
+            ApplicationTag::Double => {
+                let (value, consumed) = encoding::decode_double(data)?;
+                (PropertyValue::Double(value), consumed)
+            }
+            ApplicationTag::OctetString => {
+                let (value, consumed) = encoding::decode_octet_string(data)?;
+                (PropertyValue::OctetString(value), consumed)
+            }
+            ApplicationTag::CharacterString => {
+                let (value, consumed) = encoding::decode_character_string(data)?;
+                (PropertyValue::CharacterString(value), consumed)
+            }
+            ApplicationTag::BitString => {
+                let (bits, consumed) = encoding::decode_bit_string(data)?;
+                (PropertyValue::BitString(bits), consumed)
+            }
+            ApplicationTag::Enumerated => {
+                let (value, consumed) = encoding::decode_enumerated(data)?;
+                (PropertyValue::Enumerated(value), consumed)
+            }
+            ApplicationTag::Date => {
+                let ((year, month, day, weekday), consumed) = encoding::decode_date(data)?;
+                let date = Date {
+                    year,
+                    month,
+                    day,
+                    weekday,
+                };
+                (PropertyValue::Date(date), consumed)
+            }
+            ApplicationTag::Time => {
+                let ((hour, minute, second, hundredths), consumed) = encoding::decode_time(data)?;
+                let time = Time {
+                    hour,
+                    minute,
+                    second,
+                    hundredths,
+                };
+                (PropertyValue::Time(time), consumed)
+            }
+            ApplicationTag::ObjectIdentifier => {
+                let ((object_type, instance), consumed) = encoding::decode_object_identifier(data)?;
+                let object_type = ObjectType::try_from(object_type)
+                    .map_err(|_| EncodingError::ValueOutOfRange)?;
+                let id = ObjectIdentifier::new(object_type, instance);
+                (PropertyValue::ObjectIdentifier(id), consumed)
+            }
+            _ => return Err(EncodingError::InvalidTag),
+        };
+
+        Ok(value)
+    }
 }
 
 /// BACnet date representation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Date {
     pub year: u16,   // 1900-2155, 255 = unspecified
     pub month: u8,   // 1-12, 13 = odd months, 14 = even months, 255 = unspecified
@@ -400,6 +2526,7 @@ pub struct Date {
 
 /// BACnet time representation
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Time {
     pub hour: u8,       // 0-23, 255 = unspecified
     pub minute: u8,     // 0-59, 255 = unspecified
@@ -409,6 +2536,7 @@ pub struct Time {
 
 /// Device object implementation
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Device {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -416,6 +2544,8 @@ pub struct Device {
     pub object_name: String,
     /// Object type (always Device)
     pub object_type: ObjectType,
+    /// Description
+    pub description: String,
     /// System status
     pub system_status: DeviceStatus,
     /// Vendor name
@@ -433,17 +2563,23 @@ pub struct Device {
     /// Protocol revision
     pub protocol_revision: u8,
     /// Protocol services supported
-    pub protocol_services_supported: ProtocolServicesSupported,
+    pub protocol_services_supported: ServicesSupported,
     /// Object types supported
-    pub object_types_supported: Vec<ObjectType>,
+    pub object_types_supported: ObjectTypesSupported,
     /// Maximum APDU length accepted
     pub max_apdu_length_accepted: u16,
     /// Segmentation support
     pub segmentation_supported: Segmentation,
+    /// APDU timeout in milliseconds
+    pub apdu_timeout: u32,
+    /// Number of APDU retries
+    pub number_of_apdu_retries: u8,
     /// Device address binding (for routing)
     pub device_address_binding: Vec<AddressBinding>,
     /// Database revision
     pub database_revision: u32,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 impl Device {
@@ -453,6 +2589,7 @@ impl Device {
             identifier: ObjectIdentifier::new(ObjectType::Device, instance),
             object_name,
             object_type: ObjectType::Device,
+            description: String::new(),
             system_status: DeviceStatus::Operational,
             vendor_name: String::from("BACnet-RS"),
             vendor_identifier: 999, // Reserved for ASHRAE - appropriate for open-source implementations
@@ -461,20 +2598,21 @@ impl Device {
             application_software_version: String::from("0.2.1"),
             protocol_version: 1,
             protocol_revision: 22, // Current BACnet protocol revision
-            protocol_services_supported: ProtocolServicesSupported::default(),
-            object_types_supported: vec![ObjectType::Device],
+            protocol_services_supported: ServicesSupported::new().with(Service::ReadProperty),
+            object_types_supported: ObjectTypesSupported::new().with(ObjectType::Device),
             max_apdu_length_accepted: 1476,
             segmentation_supported: Segmentation::Both,
+            apdu_timeout: 3000,
+            number_of_apdu_retries: 3,
             device_address_binding: Vec::new(),
             database_revision: 1,
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
     /// Add an object type to the supported list
     pub fn add_supported_object_type(&mut self, object_type: ObjectType) {
-        if !self.object_types_supported.contains(&object_type) {
-            self.object_types_supported.push(object_type);
-        }
+        self.object_types_supported.set(object_type, true);
     }
 
     /// Get the vendor information for this device
@@ -534,7 +2672,10 @@ impl BacnetObject for Device {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
             PropertyIdentifier::ObjectType => {
-                Ok(PropertyValue::Enumerated(self.object_type as u32))
+                Ok(PropertyValue::Enumerated(self.object_type.as_u16() as u32))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
             }
             PropertyIdentifier::SystemStatus => {
                 Ok(PropertyValue::Enumerated(self.system_status as u32))
@@ -560,16 +2701,35 @@ impl BacnetObject for Device {
             PropertyIdentifier::ProtocolRevision => Ok(PropertyValue::UnsignedInteger(
                 self.protocol_revision as u32,
             )),
+            PropertyIdentifier::ProtocolServicesSupported => {
+                Ok(self.protocol_services_supported.into())
+            }
+            PropertyIdentifier::ProtocolObjectTypesSupported => {
+                Ok(self.object_types_supported.into())
+            }
             PropertyIdentifier::MaxApduLengthAccepted => Ok(PropertyValue::UnsignedInteger(
                 self.max_apdu_length_accepted as u32,
             )),
             PropertyIdentifier::SegmentationSupported => Ok(PropertyValue::Enumerated(
                 self.segmentation_supported as u32,
             )),
+            PropertyIdentifier::ApduTimeout => {
+                Ok(PropertyValue::UnsignedInteger(self.apdu_timeout))
+            }
+            PropertyIdentifier::NumberOfApduRetries => Ok(PropertyValue::UnsignedInteger(
+                self.number_of_apdu_retries as u32,
+            )),
+            // On its own the device holds only itself; an ObjectDatabase
+            // answers Object_List from the objects it holds
+            PropertyIdentifier::ObjectList => {
+                Ok(PropertyValue::Array(vec![PropertyValue::ObjectIdentifier(
+                    self.identifier,
+                )]))
+            }
             PropertyIdentifier::DatabaseRevision => {
                 Ok(PropertyValue::UnsignedInteger(self.database_revision))
             }
-            _ => Err(ObjectError::UnknownProperty),
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -583,6 +2743,15 @@ impl BacnetObject for Device {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
             PropertyIdentifier::VendorName => {
                 if let PropertyValue::CharacterString(name) = value {
                     self.vendor_name = name;
@@ -623,7 +2792,25 @@ impl BacnetObject for Device {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::ApduTimeout => {
+                if let PropertyValue::UnsignedInteger(timeout) = value {
+                    self.apdu_timeout = timeout;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::NumberOfApduRetries => match value {
+                PropertyValue::UnsignedInteger(retries) => {
+                    self.number_of_apdu_retries = u8::try_from(retries).map_err(|_| {
+                        ObjectError::InvalidValue("APDU retries must be 0-255".to_string())
+                    })?;
+                    Ok(())
+                }
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
         }
     }
 
@@ -631,19 +2818,23 @@ impl BacnetObject for Device {
         matches!(
             property,
             PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
                 | PropertyIdentifier::VendorName
                 | PropertyIdentifier::ModelName
                 | PropertyIdentifier::FirmwareRevision
                 | PropertyIdentifier::ApplicationSoftwareVersion
                 | PropertyIdentifier::DatabaseRevision
-        )
+                | PropertyIdentifier::ApduTimeout
+                | PropertyIdentifier::NumberOfApduRetries
+        ) || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
             PropertyIdentifier::SystemStatus,
             PropertyIdentifier::VendorName,
             PropertyIdentifier::VendorIdentifier,
@@ -652,15 +2843,23 @@ impl BacnetObject for Device {
             PropertyIdentifier::ApplicationSoftwareVersion,
             PropertyIdentifier::ProtocolVersion,
             PropertyIdentifier::ProtocolRevision,
+            PropertyIdentifier::ProtocolServicesSupported,
+            PropertyIdentifier::ProtocolObjectTypesSupported,
             PropertyIdentifier::MaxApduLengthAccepted,
             PropertyIdentifier::SegmentationSupported,
+            PropertyIdentifier::ApduTimeout,
+            PropertyIdentifier::NumberOfApduRetries,
+            PropertyIdentifier::ObjectList,
             PropertyIdentifier::DatabaseRevision,
-        ]
+        ];
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
 /// Device status enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum DeviceStatus {
     Operational = 0,
@@ -673,6 +2872,7 @@ pub enum DeviceStatus {
 
 /// Segmentation support enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum Segmentation {
     Both = 0,
@@ -681,75 +2881,246 @@ pub enum Segmentation {
     NoSegmentation = 3,
 }
 
-/// Protocol services supported bitfield
-#[derive(Debug, Clone, Default)]
-pub struct ProtocolServicesSupported {
-    pub bits: [u8; 5], // 40 bits for all BACnet services
-}
-
-impl ProtocolServicesSupported {
-    /// Set a service as supported
-    pub fn set_service(&mut self, service: u8, supported: bool) {
-        if service < 40 {
-            let byte_index = service / 8;
-            let bit_index = service % 8;
-            if supported {
-                self.bits[byte_index as usize] |= 1 << bit_index;
-            } else {
-                self.bits[byte_index as usize] &= !(1 << bit_index);
-            }
-        }
-    }
+impl TryFrom<u32> for Segmentation {
+    type Error = ObjectError;
 
-    /// Check if a service is supported
-    pub fn is_service_supported(&self, service: u8) -> bool {
-        if service < 40 {
-            let byte_index = service / 8;
-            let bit_index = service % 8;
-            (self.bits[byte_index as usize] & (1 << bit_index)) != 0
-        } else {
-            false
+    fn try_from(value: u32) -> Result<Self> {
+        match value {
+            0 => Ok(Segmentation::Both),
+            1 => Ok(Segmentation::Transmit),
+            2 => Ok(Segmentation::Receive),
+            3 => Ok(Segmentation::NoSegmentation),
+            _ => Err(ObjectError::InvalidValue(format!(
+                "Invalid segmentation value: {}",
+                value
+            ))),
         }
     }
 }
 
 /// Address binding for device routing
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AddressBinding {
     pub device_identifier: ObjectIdentifier,
     pub network_address: Vec<u8>,
 }
 
+/// Accumulator object type
+pub mod accumulator;
 /// Analog object types (AI, AO, AV)
 pub mod analog;
 /// Binary object types (BI, BO, BV)
 pub mod binary;
+/// Calendar object type
+pub mod calendar;
+/// Property change callbacks
+pub mod callback;
 /// Object database for managing BACnet objects
 #[cfg(feature = "std")]
 pub mod database;
+/// Date, Time and DateTime Value object types
+pub mod datetime_value;
 /// Device object and object functions API
 pub mod device;
 /// Engineering units enumeration
 pub mod engineering_units;
+/// Event Enrollment object type
+pub mod event_enrollment;
 /// File object type
 pub mod file;
+/// Integer Value object type
+pub mod integer_value;
+/// Large Analog Value object type
+pub mod large_analog_value;
 /// Multi-state object types (MSI, MSO, MSV)
 pub mod multistate;
+/// Notification Class object type
+pub mod notification_class;
+/// Command priority array for commandable objects
+pub mod priority;
+/// Vendor-specific properties of standard objects
+pub mod proprietary;
+/// Schedule object type
+pub mod schedule;
+/// Status_Flags bit string
+pub mod status_flags;
+/// CharacterString and OctetString Value object types
+pub mod string_value;
+/// Protocol services and object types supported bit strings
+pub mod supported;
+/// Trend Log object type
+pub mod trend_log;
 
-pub use analog::{AnalogInput, AnalogOutput, AnalogValue, EventState, Reliability};
+pub use accumulator::{Accumulator, Prescale, Scale};
+pub use analog::{
+    AnalogInput, AnalogInputBuilder, AnalogOutput, AnalogValue, EventState, LimitEnable,
+    PresentValueLimit, Reliability,
+};
 pub use binary::{BinaryInput, BinaryOutput, BinaryPV, BinaryValue, Polarity};
+pub use calendar::{Calendar, CalendarEntry, DateRange, WeekNDay};
+pub use callback::{PropertyCallback, PropertyCallbacks, PropertyChange};
+pub use datetime_value::{DateTimeValue, DateValue};
 pub use device::{DeviceObject, ObjectFunctions};
 pub use engineering_units::EngineeringUnits;
+pub use event_enrollment::{EventEnrollment, EventParameters, EventType};
 pub use file::{File, FileAccessMethod};
+pub use integer_value::IntegerValue;
+pub use large_analog_value::LargeAnalogValue;
 pub use multistate::{MultiStateInput, MultiStateOutput, MultiStateValue};
+pub use notification_class::NotificationClass;
+pub use priority::PriorityArray;
+pub use proprietary::ProprietaryProperties;
+pub use schedule::{Schedule, SpecialEvent, SpecialEventPeriod, TimeValue};
+pub use status_flags::StatusFlags;
+pub use string_value::{CharacterStringValue, OctetStringValue};
+pub use supported::{ObjectTypesSupported, Service, ServicesSupported};
+pub use trend_log::{LogRecord, ObjectPropertyReference, TrendLog};
 
 #[cfg(feature = "std")]
-pub use database::{DatabaseBuilder, DatabaseStatistics, ObjectDatabase};
+pub use database::{DatabaseBuilder, DatabaseStatistics, ObjectDatabase, ObjectsView};
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_object_type_values() {
+        assert_eq!(ObjectType::Schedule.as_u16(), 17);
+        assert_eq!(ObjectType::TrendLog.as_u16(), 20);
+        assert_eq!(ObjectType::NetworkPort.as_u16(), 56);
+        assert_eq!(ObjectType::try_from_u16(6).unwrap(), ObjectType::Calendar);
+        assert_eq!(
+            ObjectType::try_from_u16(64).unwrap(),
+            ObjectType::ColorTemperature
+        );
+        // Reserved and vendor types are carried, not rejected
+        assert_eq!(
+            ObjectType::try_from_u16(128).unwrap(),
+            ObjectType::Proprietary(128)
+        );
+        assert_eq!(
+            ObjectType::try_from_u16(65).unwrap(),
+            ObjectType::Reserved(65)
+        );
+        assert_eq!(ObjectType::try_from_u16(127).unwrap().as_u16(), 127);
+        assert!(!ObjectType::Reserved(127).is_proprietary());
+        assert!(!ObjectType::Reserved(128).is_valid());
+        assert_eq!(ObjectType::proprietary(700).unwrap().as_u16(), 700);
+        assert!(ObjectType::proprietary(128).unwrap().is_proprietary());
+        assert!(ObjectType::proprietary(8).is_err());
+        assert!(ObjectType::proprietary(1024).is_err());
+        assert!(!ObjectType::Proprietary(8).is_valid());
+        assert!(!ObjectType::Device.is_proprietary());
+        assert!(ObjectType::try_from_u16(1024).is_err());
+    }
+
+    #[test]
+    fn test_object_identifier_wire_form() {
+        let id = ObjectIdentifier::try_new(ObjectType::AnalogInput, 1).unwrap();
+        assert_eq!(id.encode().unwrap(), [0x00, 0x00, 0x00, 0x01]);
+
+        let device = ObjectIdentifier::new(ObjectType::Device, 123456);
+        let bytes = device.encode().unwrap();
+        assert_eq!(bytes, [0x02, 0x01, 0xE2, 0x40]);
+        assert_eq!(ObjectIdentifier::decode(bytes).unwrap(), device);
+
+        let max = ObjectIdentifier::try_new(ObjectType::Device, ObjectIdentifier::MAX_INSTANCE);
+        assert_eq!(max.unwrap().encode().unwrap(), [0x02, 0x3F, 0xFF, 0xFF]);
+
+        // Instance must fit in 22 bits
+        assert!(ObjectIdentifier::try_new(ObjectType::Device, 0x400000).is_err());
+        // A proprietary type must be a vendor-specific value
+        assert!(ObjectIdentifier::try_new(ObjectType::Proprietary(8), 1).is_err());
+        assert!(ObjectIdentifier::new(ObjectType::Proprietary(100), 1)
+            .encode()
+            .is_err());
+        assert!(ObjectIdentifier::try_new(ObjectType::Reserved(300), 1).is_err());
+        assert_eq!(
+            ObjectIdentifier::try_new(ObjectType::Reserved(100), 1)
+                .unwrap()
+                .encode()
+                .unwrap(),
+            [0x19, 0x00, 0x00, 0x01]
+        );
+        assert!(ObjectIdentifier::new(ObjectType::Device, 0x400000)
+            .encode()
+            .is_err());
+    }
+
+    #[test]
+    fn test_object_identifier_packed_form() {
+        let device = ObjectIdentifier::new(ObjectType::Device, 123456);
+        assert_eq!(device.to_u32(), 0x0201E240);
+        assert_eq!(ObjectIdentifier::from_u32(0x0201E240), device);
+
+        // Vendor object type 513, instance 7
+        let vendor = ObjectIdentifier::from_u32((513 << 22) | 7);
+        assert_eq!(vendor.object_type, ObjectType::Proprietary(513));
+        assert_eq!(vendor.instance, 7);
+        assert_eq!(vendor.to_u32(), (513 << 22) | 7);
+
+        let max = ObjectIdentifier::from_u32(u32::MAX);
+        assert_eq!(max.object_type, ObjectType::Proprietary(1023));
+        assert_eq!(max.instance, ObjectIdentifier::MAX_INSTANCE);
+
+        // Reserved object types are carried, not mistaken for vendor types
+        let reserved = ObjectIdentifier::from_u32((100 << 22) | 1);
+        assert_eq!(reserved.object_type, ObjectType::Reserved(100));
+        assert_eq!(reserved.to_u32(), (100 << 22) | 1);
+        let (decoded, _) =
+            ObjectIdentifier::decode_application(&[0xC4, 0x19, 0x00, 0x00, 0x01]).unwrap();
+        assert_eq!(decoded, reserved);
+        let mut buffer = Vec::new();
+        decoded.encode_application(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0xC4, 0x19, 0x00, 0x00, 0x01]);
+
+        let mut buffer = Vec::new();
+        vendor.encode_application(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0xC4, 0x80, 0x40, 0x00, 0x07]);
+        assert_eq!(
+            ObjectIdentifier::decode_application(&buffer).unwrap(),
+            (vendor, 5)
+        );
+        assert!(matches!(
+            PropertyValue::decode(&buffer).unwrap().0,
+            PropertyValue::ObjectIdentifier(id) if id == vendor
+        ));
+        assert!(ObjectIdentifier::decode_application(&[0xC4, 0x80, 0x40]).is_err());
+    }
+
+    #[test]
+    fn test_property_identifier_values() {
+        assert_eq!(PropertyIdentifier::PresentValue.as_u32(), 85);
+        assert_eq!(PropertyIdentifier::Units.as_u32(), 117);
+        assert_eq!(PropertyIdentifier::CovIncrement.as_u32(), 22);
+        assert_eq!(PropertyIdentifier::EventState.as_u32(), 36);
+        assert_eq!(PropertyIdentifier::PropertyList.as_u32(), 371);
+
+        assert_eq!(
+            PropertyIdentifier::from(28),
+            PropertyIdentifier::Description
+        );
+        assert_eq!(
+            PropertyIdentifier::from(111),
+            PropertyIdentifier::StatusFlags
+        );
+        assert_eq!(
+            PropertyIdentifier::from(103),
+            PropertyIdentifier::Reliability
+        );
+
+        // Vendor and unassigned identifiers survive a round trip
+        let vendor = PropertyIdentifier::from(5000);
+        assert_eq!(vendor, PropertyIdentifier::Proprietary(5000));
+        assert!(vendor.is_proprietary());
+        assert_eq!(u32::from(vendor), 5000);
+        assert_eq!(
+            PropertyIdentifier::from(18),
+            PropertyIdentifier::Proprietary(18)
+        );
+    }
+
     #[test]
     fn test_device_creation() {
         let device = Device::new(123, "Test Device".to_string());
@@ -786,19 +3157,371 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_device_object_list() {
+        let device = Device::new(789, "List Test".to_string());
+
+        match device.get_property(PropertyIdentifier::ObjectList).unwrap() {
+            PropertyValue::Array(list) => {
+                assert_eq!(list.len(), 1);
+                assert!(
+                    matches!(list[0], PropertyValue::ObjectIdentifier(id) if id == device.identifier)
+                );
+            }
+            _ => panic!("Expected Array"),
+        }
+        assert!(matches!(
+            device.get_property(PropertyIdentifier::ApduTimeout),
+            Ok(PropertyValue::UnsignedInteger(3000))
+        ));
+    }
+
+    #[test]
+    fn test_set_object_name_from_character_string() {
+        let mut device = Device::new(456, "Old Name".to_string());
+        device
+            .set_object_name(&CharacterString::from("Boiler Plant"))
+            .unwrap();
+        assert_eq!(device.object_name, "Boiler Plant");
+
+        // A Latin-1 name is stored as UTF-8
+        let name =
+            CharacterString::new(encoding::CharacterSet::Iso8859_1, b"B\xfcro 1".to_vec()).unwrap();
+        device.set_object_name(&name).unwrap();
+        assert_eq!(device.object_name, "Büro 1");
+
+        // A name received from the wire is checked again before it is used
+        let mut buffer = Vec::new();
+        PropertyValue::CharacterString("温度\u{7}".to_string())
+            .encode(&mut buffer)
+            .unwrap();
+        let (name, _) = CharacterString::decode(&buffer).unwrap();
+        assert!(device.set_object_name(&name).is_err());
+        assert!(device
+            .set_object_name(&CharacterString::from("温度\u{7}"))
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_object_name() {
+        assert!(validate_object_name("AHU-1 Supply Temp").is_ok());
+        assert_eq!(validate_object_name(""), Err(ObjectNameError::Empty));
+        assert_eq!(
+            validate_object_name("Zone\t1"),
+            Err(ObjectNameError::NonPrintable('\t'))
+        );
+
+        // Unicode letters are printable, control codepoints are not
+        assert!(validate_object_name("Büro 1").is_ok());
+        assert!(validate_object_name("温度").is_ok());
+        assert_eq!(
+            validate_object_name("Zone\u{85}1"),
+            Err(ObjectNameError::NonPrintable('\u{85}'))
+        );
+    }
+
+    #[test]
+    fn test_unicode_object_name_round_trip() {
+        let mut ai = AnalogInput::new(1, "温度".to_string());
+        let mut buffer = Vec::new();
+        ai.get_property(PropertyIdentifier::ObjectName)
+            .unwrap()
+            .encode(&mut buffer)
+            .unwrap();
+        assert_eq!(
+            buffer,
+            [0x75, 0x07, 0x00, 0xE6, 0xB8, 0xA9, 0xE5, 0xBA, 0xA6]
+        );
+
+        let (value, consumed) = PropertyValue::decode(&buffer).unwrap();
+        assert_eq!(consumed, buffer.len());
+        ai.set_property(PropertyIdentifier::ObjectName, value)
+            .unwrap();
+        assert_eq!(ai.object_name, "温度");
+
+        // Multibyte names are limited by their encoded length
+        assert_eq!(
+            ArrayObjectName::<5>::new("温度"),
+            Err(ObjectNameError::TooLong(5))
+        );
+        assert_eq!(ArrayObjectName::<6>::new("温度").unwrap().as_str(), "温度");
+    }
+
+    #[test]
+    fn test_array_object_name() {
+        let mut name = ArrayObjectName::<8>::new("AHU-1").unwrap();
+        assert_eq!(name.as_str(), "AHU-1");
+
+        assert_eq!(name.update("Supply Fan"), Err(ObjectNameError::TooLong(8)));
+        assert_eq!(name.as_str(), "AHU-1");
+
+        name.update("SF-1").unwrap();
+        assert_eq!(name.to_string(), "SF-1");
+        assert_eq!(ArrayObjectName::<8>::new(""), Err(ObjectNameError::Empty));
+
+        // Bytes left over from "AHU-1" do not affect equality or hashing
+        let fresh = ArrayObjectName::<8>::new("SF-1").unwrap();
+        assert_eq!(name, fresh);
+        #[cfg(feature = "std")]
+        {
+            use std::hash::BuildHasher;
+            let hasher = std::collections::hash_map::RandomState::new();
+            assert_eq!(hasher.hash_one(name), hasher.hash_one(fresh));
+        }
+    }
+
+    #[test]
+    fn test_property_value_round_trip() {
+        let values = [
+            PropertyValue::Real(72.5),
+            PropertyValue::UnsignedInteger(0),
+            PropertyValue::UnsignedInteger(70_000),
+            PropertyValue::Boolean(true),
+            PropertyValue::Boolean(false),
+            PropertyValue::Enumerated(62),
+            PropertyValue::CharacterString("Zone Temp".to_string()),
+        ];
+
+        for value in values {
+            let mut buffer = Vec::new();
+            value.encode(&mut buffer).unwrap();
+            let (decoded, consumed) = PropertyValue::decode(&buffer).unwrap();
+            assert_eq!(consumed, buffer.len());
+            assert_eq!(format!("{:?}", decoded), format!("{:?}", value));
+        }
+
+        // Real 72.5 on the wire
+        let mut buffer = Vec::new();
+        PropertyValue::Real(72.5).encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x44, 0x42, 0x91, 0x00, 0x00]);
+
+        // A decoded unspecified year encodes back to 255
+        let (date, _) = PropertyValue::decode(&[0xA4, 0xFF, 0x0C, 0x19, 0xFF]).unwrap();
+        buffer.clear();
+        date.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0xA4, 0xFF, 0x0C, 0x19, 0xFF]);
+
+        // Reserved object type 100 decodes instead of failing
+        let (id, _) = PropertyValue::decode(&[0xC4, 0x19, 0x00, 0x00, 0x01]).unwrap();
+        assert!(matches!(
+            id,
+            PropertyValue::ObjectIdentifier(ObjectIdentifier {
+                object_type: ObjectType::Reserved(100),
+                instance: 1,
+            })
+        ));
+
+        // Years before 1900 have no encoding
+        let date = PropertyValue::Date(Date {
+            year: 1800,
+            month: 1,
+            day: 1,
+            weekday: 3,
+        });
+        assert!(date.encode(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_property_value_decode_rejects_context_and_truncation() {
+        // Context tag 1, length 1
+        assert!(PropertyValue::decode(&[0x19, 0x05]).is_err());
+        // Real missing its last byte
+        assert!(PropertyValue::decode(&[0x44, 0x42, 0x91, 0x00]).is_err());
+        assert!(PropertyValue::decode(&[]).is_err());
+    }
+
+    #[test]
+    fn test_property_value_double_round_trip() {
+        let mut buffer = Vec::new();
+        PropertyValue::Double(72.5).encode(&mut buffer).unwrap();
+        // Tag 5 with length 8, then the IEEE-754 double
+        assert_eq!(
+            buffer,
+            vec![0x55, 0x08, 0x40, 0x52, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+
+        for value in [72.5, f64::INFINITY, f64::NEG_INFINITY, f64::MIN_POSITIVE] {
+            let mut buffer = Vec::new();
+            PropertyValue::Double(value).encode(&mut buffer).unwrap();
+            let (decoded, consumed) = PropertyValue::decode(&buffer).unwrap();
+            assert_eq!(consumed, buffer.len());
+            assert!(matches!(decoded, PropertyValue::Double(v) if v == value));
+        }
+
+        let mut buffer = Vec::new();
+        PropertyValue::Double(f64::NAN).encode(&mut buffer).unwrap();
+        let (decoded, _) = PropertyValue::decode(&buffer).unwrap();
+        assert!(matches!(decoded, PropertyValue::Double(v) if v.is_nan()));
+
+        // Real NaN and infinity survive the single precision encoding too
+        let mut buffer = Vec::new();
+        PropertyValue::Real(f32::NAN).encode(&mut buffer).unwrap();
+        let (decoded, _) = PropertyValue::decode(&buffer).unwrap();
+        assert!(matches!(decoded, PropertyValue::Real(v) if v.is_nan()));
+    }
+
     #[test]
     fn test_protocol_services_supported() {
-        let mut services = ProtocolServicesSupported::default();
-
-        // Set some services as supported
-        services.set_service(0, true); // Acknowledge-Alarm
-        services.set_service(12, true); // Read-Property
-        services.set_service(15, true); // Write-Property
-
-        assert!(services.is_service_supported(0));
-        assert!(services.is_service_supported(12));
-        assert!(services.is_service_supported(15));
-        assert!(!services.is_service_supported(1));
-        assert!(!services.is_service_supported(13));
+        let mut device = Device::new(1, "Services Test".to_string());
+        device
+            .protocol_services_supported
+            .set(Service::ReadPropertyMultiple, true);
+        device.add_supported_object_type(ObjectType::AnalogInput);
+
+        match device
+            .get_property(PropertyIdentifier::ProtocolServicesSupported)
+            .unwrap()
+        {
+            PropertyValue::BitString(bits) => {
+                let services = ServicesSupported::from_bitstring(&bits);
+                assert!(services.supports(Service::ReadProperty));
+                assert!(services.supports(Service::ReadPropertyMultiple));
+                assert!(!services.supports(Service::WriteProperty));
+            }
+            _ => panic!("Expected BitString"),
+        }
+
+        match device
+            .get_property(PropertyIdentifier::ProtocolObjectTypesSupported)
+            .unwrap()
+        {
+            PropertyValue::BitString(bits) => {
+                let object_types = ObjectTypesSupported::from_bitstring(&bits);
+                assert!(object_types.supports(ObjectType::Device));
+                assert!(object_types.supports(ObjectType::AnalogInput));
+            }
+            _ => panic!("Expected BitString"),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let value = PropertyValue::Array(vec![
+            PropertyValue::Null,
+            PropertyValue::Real(21.5),
+            PropertyValue::ObjectIdentifier(ObjectIdentifier::new(ObjectType::AnalogInput, 3)),
+        ]);
+        let json = serde_json::to_string(&value).unwrap();
+        assert!(json.starts_with(r#"{"type":"Array","value":[{"type":"Null"}"#));
+        match serde_json::from_str::<PropertyValue>(&json).unwrap() {
+            PropertyValue::Array(elements) => {
+                assert!(matches!(elements[0], PropertyValue::Null));
+                assert!(matches!(elements[1], PropertyValue::Real(v) if v == 21.5));
+                assert!(matches!(
+                    elements[2],
+                    PropertyValue::ObjectIdentifier(id)
+                        if id.object_type == ObjectType::AnalogInput && id.instance == 3
+                ));
+            }
+            other => panic!("Expected Array, got {:?}", other),
+        }
+
+        let mut ai = AnalogInput::new(7, "Zone Temp".to_string());
+        ai.present_value = 19.0;
+        ai.units = EngineeringUnits::DegreesCelsius;
+        ai.callbacks
+            .register(PropertyIdentifier::PresentValue, |_| {});
+        let restored: AnalogInput =
+            serde_json::from_str(&serde_json::to_string(&ai).unwrap()).unwrap();
+        assert_eq!(restored.identifier, ai.identifier);
+        assert_eq!(restored.object_name, "Zone Temp");
+        assert_eq!(restored.present_value, 19.0);
+        assert_eq!(restored.units, EngineeringUnits::DegreesCelsius);
+        assert!(!restored
+            .callbacks
+            .is_registered(PropertyIdentifier::PresentValue));
+
+        // Instances past 22 bits are refused rather than restored
+        let json = r#"{"object_type":"AnalogInput","instance":4194304}"#;
+        assert!(serde_json::from_str::<ObjectIdentifier>(json).is_err());
+        let json = r#"{"object_type":"AnalogInput","instance":4194303}"#;
+        assert!(serde_json::from_str::<ObjectIdentifier>(json).is_ok());
+        let json = r#"{"object_type":{"Proprietary":8},"instance":1}"#;
+        assert!(serde_json::from_str::<ObjectIdentifier>(json).is_err());
+    }
+
+    #[test]
+    fn test_description_and_units_properties() {
+        let mut objects: Vec<Box<dyn BacnetObject>> = vec![
+            Box::new(AnalogInput::new(1, "AI".to_string())),
+            Box::new(AnalogOutput::new(1, "AO".to_string())),
+            Box::new(AnalogValue::new(1, "AV".to_string())),
+            Box::new(IntegerValue::new(1, "IV".to_string())),
+            Box::new(LargeAnalogValue::new(1, "LAV".to_string())),
+            Box::new(Accumulator::new(1, "ACC".to_string())),
+        ];
+        for object in objects.iter_mut() {
+            object
+                .set_property(
+                    PropertyIdentifier::Description,
+                    PropertyValue::CharacterString("Zone 1".to_string()),
+                )
+                .unwrap();
+            object
+                .set_property(PropertyIdentifier::Units, PropertyValue::Enumerated(62))
+                .unwrap();
+
+            let mut buffer = Vec::new();
+            object
+                .get_property(PropertyIdentifier::Description)
+                .unwrap()
+                .encode(&mut buffer)
+                .unwrap();
+            assert_eq!(buffer, b"\x75\x07\x00Zone 1");
+            buffer.clear();
+            object
+                .get_property(PropertyIdentifier::Units)
+                .unwrap()
+                .encode(&mut buffer)
+                .unwrap();
+            assert_eq!(buffer, [0x91, 62]);
+
+            assert!(matches!(
+                object.set_property(PropertyIdentifier::Units, PropertyValue::Enumerated(70_000)),
+                Err(ObjectError::InvalidValue(_))
+            ));
+            assert!(matches!(
+                object.set_property(PropertyIdentifier::Description, PropertyValue::Real(1.0)),
+                Err(ObjectError::InvalidPropertyType)
+            ));
+            let units = object.get_property(PropertyIdentifier::Units).unwrap();
+            assert!(matches!(units, PropertyValue::Enumerated(62)));
+
+            // Descriptions are checked like object names but may be empty
+            assert!(matches!(
+                object.set_property(
+                    PropertyIdentifier::Description,
+                    PropertyValue::CharacterString("Zone\n1".to_string()),
+                ),
+                Err(ObjectError::InvalidValue(_))
+            ));
+            object
+                .set_property(
+                    PropertyIdentifier::Description,
+                    PropertyValue::CharacterString(String::new()),
+                )
+                .unwrap();
+        }
+
+        let mut device = Device::new(1, "Device".to_string());
+        assert!(device.is_property_writable(PropertyIdentifier::Description));
+        device
+            .set_property(
+                PropertyIdentifier::Description,
+                PropertyValue::CharacterString("Plant room".to_string()),
+            )
+            .unwrap();
+        assert_eq!(device.description, "Plant room");
+        assert!(device
+            .set_property(
+                PropertyIdentifier::Description,
+                PropertyValue::CharacterString("Plant\u{7}room".to_string()),
+            )
+            .is_err());
+        assert_eq!(device.description, "Plant room");
+        assert!(device
+            .property_list()
+            .contains(&PropertyIdentifier::Description));
     }
 }
@@ -4,15 +4,17 @@
 //! object types as defined in ASHRAE 135. These objects represent multi-position values.
 
 use crate::object::{
-    BacnetObject, EventState, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier,
-    PropertyValue, Reliability, Result,
+    priority::PriorityArray, proprietary::ProprietaryProperties, validate_description,
+    write_rejected, BacnetObject, EventState, ObjectError, ObjectIdentifier, ObjectType,
+    PropertyIdentifier, PropertyValue, Reliability, Result, StatusFlags,
 };
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{format, string::String, vec, vec::Vec};
 
 /// Multi-state Input object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MultiStateInput {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -24,8 +26,8 @@ pub struct MultiStateInput {
     pub description: String,
     /// Device type
     pub device_type: String,
-    /// Status flags (4 bits: in_alarm, fault, overridden, out_of_service)
-    pub status_flags: u8,
+    /// Status flags
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -36,10 +38,13 @@ pub struct MultiStateInput {
     pub number_of_states: u32,
     /// State text array
     pub state_text: Vec<String>,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 /// Multi-state Output object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MultiStateOutput {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -52,7 +57,7 @@ pub struct MultiStateOutput {
     /// Device type
     pub device_type: String,
     /// Status flags
-    pub status_flags: u8,
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -64,13 +69,16 @@ pub struct MultiStateOutput {
     /// State text array
     pub state_text: Vec<String>,
     /// Priority array (16 levels)
-    pub priority_array: [Option<u32>; 16],
+    pub priority_array: PriorityArray<u32>,
     /// Relinquish default
     pub relinquish_default: u32,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 /// Multi-state Value object
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MultiStateValue {
     /// Object identifier
     pub identifier: ObjectIdentifier,
@@ -81,7 +89,7 @@ pub struct MultiStateValue {
     /// Description
     pub description: String,
     /// Status flags
-    pub status_flags: u8,
+    pub status_flags: StatusFlags,
     /// Event state
     pub event_state: EventState,
     /// Reliability
@@ -93,9 +101,11 @@ pub struct MultiStateValue {
     /// State text array
     pub state_text: Vec<String>,
     /// Priority array (16 levels)
-    pub priority_array: [Option<u32>; 16],
+    pub priority_array: PriorityArray<u32>,
     /// Relinquish default
     pub relinquish_default: u32,
+    /// Vendor-specific properties
+    pub proprietary: ProprietaryProperties,
 }
 
 impl MultiStateInput {
@@ -112,15 +122,31 @@ impl MultiStateInput {
             present_value: 1,
             description: String::new(),
             device_type: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
             number_of_states,
             state_text,
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
+    /// Create a new Multi-state Input object from a list of state texts
+    ///
+    /// The number of states is taken from the number of texts supplied.
+    pub fn with_state_text(
+        instance: u32,
+        object_name: String,
+        state_text: impl IntoIterator<Item = String>,
+    ) -> Self {
+        let state_text: Vec<String> = state_text.into_iter().collect();
+        let mut object = Self::new(instance, object_name, 0);
+        object.number_of_states = state_text.len() as u32;
+        object.state_text = state_text;
+        object
+    }
+
     /// Set the present value (validates range)
     pub fn set_present_value(&mut self, value: u32) -> Result<()> {
         if value < 1 || value > self.number_of_states {
@@ -153,6 +179,34 @@ impl MultiStateInput {
         self.state_text[(state - 1) as usize] = text;
         Ok(())
     }
+
+    /// Get the state text for a 1-based present value
+    pub fn get_text_for_value(&self, present_value: u32) -> Result<&str> {
+        state_text_for_value(&self.state_text, present_value)
+    }
+
+    /// Insert a state at a 1-based position, shifting later states up
+    pub fn insert_state(&mut self, state: u32, text: String) -> Result<()> {
+        insert_state_text(&mut self.state_text, state, text)?;
+        self.number_of_states = self.state_text.len() as u32;
+        Ok(())
+    }
+
+    /// Remove the state at a 1-based position, shifting later states down
+    ///
+    /// Fails if the present value would then be past the last state.
+    pub fn remove_state(&mut self, state: u32) -> Result<String> {
+        let text = remove_state_text(&mut self.state_text, state, [self.present_value])?;
+        self.number_of_states = self.state_text.len() as u32;
+        Ok(text)
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        self.status_flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+    }
 }
 
 impl MultiStateOutput {
@@ -169,25 +223,35 @@ impl MultiStateOutput {
             present_value: 1,
             description: String::new(),
             device_type: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
             number_of_states,
             state_text,
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: 1,
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
+    /// Create a new Multi-state Output object from a list of state texts
+    ///
+    /// The number of states is taken from the number of texts supplied.
+    pub fn with_state_text(
+        instance: u32,
+        object_name: String,
+        state_text: impl IntoIterator<Item = String>,
+    ) -> Self {
+        let state_text: Vec<String> = state_text.into_iter().collect();
+        let mut object = Self::new(instance, object_name, 0);
+        object.number_of_states = state_text.len() as u32;
+        object.state_text = state_text;
+        object
+    }
+
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<u32>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-
         if let Some(val) = value {
             if val < 1 || val > self.number_of_states {
                 return Err(ObjectError::InvalidValue(format!(
@@ -197,30 +261,67 @@ impl MultiStateOutput {
             }
         }
 
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
+        self.update_present_value();
+        Ok(())
+    }
+
+    /// Set the relinquish default (validates range) and recompute the present value
+    pub fn set_relinquish_default(&mut self, value: u32) -> Result<()> {
+        if value < 1 || value > self.number_of_states {
+            return Err(ObjectError::InvalidValue(format!(
+                "Value must be between 1 and {}",
+                self.number_of_states
+            )));
+        }
+        self.relinquish_default = value;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        // Highest priority non-null value, or relinquish default if all are null
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
     }
 
     /// Get the effective priority level for current present value
     pub fn get_effective_priority(&self) -> Option<u8> {
-        for (i, priority_value) in self.priority_array.iter().enumerate() {
-            if priority_value.is_some() {
-                return Some((i + 1) as u8);
-            }
-        }
-        None
+        self.priority_array.effective_priority()
+    }
+
+    /// Get the state text for a 1-based present value
+    pub fn get_text_for_value(&self, present_value: u32) -> Result<&str> {
+        state_text_for_value(&self.state_text, present_value)
+    }
+
+    /// Insert a state at a 1-based position, shifting later states up
+    pub fn insert_state(&mut self, state: u32, text: String) -> Result<()> {
+        insert_state_text(&mut self.state_text, state, text)?;
+        self.number_of_states = self.state_text.len() as u32;
+        Ok(())
+    }
+
+    /// Remove the state at a 1-based position, shifting later states down
+    ///
+    /// Fails if the relinquish default or a commanded priority would then be
+    /// past the last state.
+    pub fn remove_state(&mut self, state: u32) -> Result<String> {
+        let in_use = self.priority_array.slots().iter().flatten().copied();
+        let text = remove_state_text(
+            &mut self.state_text,
+            state,
+            in_use.chain([self.relinquish_default, self.present_value]),
+        )?;
+        self.number_of_states = self.state_text.len() as u32;
+        Ok(text)
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        self.status_flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
     }
 }
 
@@ -237,25 +338,35 @@ impl MultiStateValue {
             object_name,
             present_value: 1,
             description: String::new(),
-            status_flags: 0,
+            status_flags: StatusFlags::default(),
             event_state: EventState::Normal,
             reliability: Reliability::NoFaultDetected,
             out_of_service: false,
             number_of_states,
             state_text,
-            priority_array: [None; 16],
+            priority_array: PriorityArray::new(),
             relinquish_default: 1,
+            proprietary: ProprietaryProperties::new(),
         }
     }
 
+    /// Create a new Multi-state Value object from a list of state texts
+    ///
+    /// The number of states is taken from the number of texts supplied.
+    pub fn with_state_text(
+        instance: u32,
+        object_name: String,
+        state_text: impl IntoIterator<Item = String>,
+    ) -> Self {
+        let state_text: Vec<String> = state_text.into_iter().collect();
+        let mut object = Self::new(instance, object_name, 0);
+        object.number_of_states = state_text.len() as u32;
+        object.state_text = state_text;
+        object
+    }
+
     /// Write to priority array at specified priority level (1-16)
     pub fn write_priority(&mut self, priority: u8, value: Option<u32>) -> Result<()> {
-        if !(1..=16).contains(&priority) {
-            return Err(ObjectError::InvalidValue(
-                "Priority must be 1-16".to_string(),
-            ));
-        }
-
         if let Some(val) = value {
             if val < 1 || val > self.number_of_states {
                 return Err(ObjectError::InvalidValue(format!(
@@ -265,21 +376,128 @@ impl MultiStateValue {
             }
         }
 
-        self.priority_array[(priority - 1) as usize] = value;
+        self.priority_array.set(priority, value)?;
+        self.update_present_value();
+        Ok(())
+    }
+
+    /// Set the relinquish default (validates range) and recompute the present value
+    pub fn set_relinquish_default(&mut self, value: u32) -> Result<()> {
+        if value < 1 || value > self.number_of_states {
+            return Err(ObjectError::InvalidValue(format!(
+                "Value must be between 1 and {}",
+                self.number_of_states
+            )));
+        }
+        self.relinquish_default = value;
         self.update_present_value();
         Ok(())
     }
 
     /// Update present value based on priority array
     fn update_present_value(&mut self) {
-        // Find highest priority non-null value
-        if let Some(value) = self.priority_array.iter().flatten().next() {
-            self.present_value = *value;
-            return;
-        }
-        // If all priorities are null, use relinquish default
-        self.present_value = self.relinquish_default;
+        // Highest priority non-null value, or relinquish default if all are null
+        self.present_value = self.priority_array.effective_value(self.relinquish_default);
+    }
+
+    /// Get the state text for a 1-based present value
+    pub fn get_text_for_value(&self, present_value: u32) -> Result<&str> {
+        state_text_for_value(&self.state_text, present_value)
+    }
+
+    /// Insert a state at a 1-based position, shifting later states up
+    pub fn insert_state(&mut self, state: u32, text: String) -> Result<()> {
+        insert_state_text(&mut self.state_text, state, text)?;
+        self.number_of_states = self.state_text.len() as u32;
+        Ok(())
+    }
+
+    /// Remove the state at a 1-based position, shifting later states down
+    ///
+    /// Fails if the relinquish default or a commanded priority would then be
+    /// past the last state.
+    pub fn remove_state(&mut self, state: u32) -> Result<String> {
+        let in_use = self.priority_array.slots().iter().flatten().copied();
+        let text = remove_state_text(
+            &mut self.state_text,
+            state,
+            in_use.chain([self.relinquish_default, self.present_value]),
+        )?;
+        self.number_of_states = self.state_text.len() as u32;
+        Ok(text)
+    }
+
+    /// Recompute the status flags from the object state
+    fn update_status_flags(&mut self) {
+        self.status_flags =
+            self.status_flags
+                .refreshed(self.event_state, self.reliability, self.out_of_service);
+    }
+}
+
+/// Map a 1-based multi-state present value onto the state text array
+fn state_text_for_value(state_text: &[String], present_value: u32) -> Result<&str> {
+    if present_value == 0 || present_value as usize > state_text.len() {
+        return Err(ObjectError::InvalidValue(format!(
+            "State must be between 1 and {}",
+            state_text.len()
+        )));
+    }
+    Ok(&state_text[(present_value - 1) as usize])
+}
+
+/// State_Text property value for a state text array
+fn state_text_array(state_text: &[String]) -> PropertyValue {
+    PropertyValue::Array(
+        state_text
+            .iter()
+            .cloned()
+            .map(PropertyValue::CharacterString)
+            .collect(),
+    )
+}
+
+/// Insert a state text at a 1-based position (up to one past the end)
+fn insert_state_text(state_text: &mut Vec<String>, state: u32, text: String) -> Result<()> {
+    if state == 0 || state as usize > state_text.len() + 1 {
+        return Err(ObjectError::InvalidValue(format!(
+            "State must be between 1 and {}",
+            state_text.len() + 1
+        )));
+    }
+    state_text.insert((state - 1) as usize, text);
+    Ok(())
+}
+
+/// Check the values in use still name a state once the last one is removed
+fn check_states_in_use(state_text: &[String], in_use: impl IntoIterator<Item = u32>) -> Result<()> {
+    let remaining = state_text.len().saturating_sub(1) as u32;
+    match in_use.into_iter().find(|&value| value > remaining) {
+        Some(value) => Err(ObjectError::InvalidValue(format!(
+            "State {} is in use and would be past the last of {} states",
+            value, remaining
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Remove the state text at a 1-based position
+///
+/// The position is checked first, then that no value `in_use` would be
+/// past the last remaining state.
+fn remove_state_text(
+    state_text: &mut Vec<String>,
+    state: u32,
+    in_use: impl IntoIterator<Item = u32>,
+) -> Result<String> {
+    if state == 0 || state as usize > state_text.len() {
+        return Err(ObjectError::InvalidValue(format!(
+            "State must be between 1 and {}",
+            state_text.len()
+        )));
     }
+    check_states_in_use(state_text, in_use)?;
+    Ok(state_text.remove((state - 1) as usize))
 }
 
 impl BacnetObject for MultiStateInput {
@@ -296,13 +514,30 @@ impl BacnetObject for MultiStateInput {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
             PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
-                ObjectType::MultiStateInput as u32,
+                ObjectType::MultiStateInput.as_u16() as u32,
             )),
             PropertyIdentifier::PresentValue => {
                 Ok(PropertyValue::UnsignedInteger(self.present_value))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::DeviceType => {
+                Ok(PropertyValue::CharacterString(self.device_type.clone()))
+            }
+            PropertyIdentifier::NumberOfStates => {
+                Ok(PropertyValue::UnsignedInteger(self.number_of_states))
+            }
+            PropertyIdentifier::StateText => Ok(state_text_array(&self.state_text)),
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -316,33 +551,79 @@ impl BacnetObject for MultiStateInput {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
+            // Present_Value of an input is writable only while out of service
+            PropertyIdentifier::PresentValue if self.out_of_service => {
+                if let PropertyValue::UnsignedInteger(val) = value {
+                    self.set_present_value(val)
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
             PropertyIdentifier::OutOfService => {
                 if let PropertyValue::Boolean(oos) = value {
                     self.out_of_service = oos;
+                    self.update_status_flags();
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn set_property_remote(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        match (property, value) {
+            (PropertyIdentifier::PresentValue, PropertyValue::UnsignedInteger(val)) => {
+                self.set_present_value(val)
+            }
+            (PropertyIdentifier::PresentValue, _) => Err(ObjectError::InvalidPropertyType),
+            (property, value) => self.set_property(property, value),
         }
     }
 
     fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
-        matches!(
-            property,
-            PropertyIdentifier::ObjectName | PropertyIdentifier::OutOfService
-        )
+        (property == PropertyIdentifier::PresentValue && self.out_of_service)
+            || matches!(
+                property,
+                PropertyIdentifier::ObjectName
+                    | PropertyIdentifier::Description
+                    | PropertyIdentifier::OutOfService
+            )
+            || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::DeviceType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
             PropertyIdentifier::OutOfService,
-        ]
+            PropertyIdentifier::NumberOfStates,
+            PropertyIdentifier::StateText,
+        ];
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -360,24 +641,37 @@ impl BacnetObject for MultiStateOutput {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
             PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
-                ObjectType::MultiStateOutput as u32,
+                ObjectType::MultiStateOutput.as_u16() as u32,
             )),
             PropertyIdentifier::PresentValue => {
                 Ok(PropertyValue::UnsignedInteger(self.present_value))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            PropertyIdentifier::PriorityArray => {
-                let array: Vec<PropertyValue> = self
-                    .priority_array
-                    .iter()
-                    .map(|&v| match v {
-                        Some(val) => PropertyValue::UnsignedInteger(val),
-                        None => PropertyValue::Null,
-                    })
-                    .collect();
-                Ok(PropertyValue::Array(array))
-            }
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::PriorityArray => Ok(PropertyValue::Array(
+                self.priority_array
+                    .to_property_values(PropertyValue::UnsignedInteger),
+            )),
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::UnsignedInteger(self.relinquish_default))
+            }
+            PropertyIdentifier::NumberOfStates => {
+                Ok(PropertyValue::UnsignedInteger(self.number_of_states))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::DeviceType => {
+                Ok(PropertyValue::CharacterString(self.device_type.clone()))
+            }
+            PropertyIdentifier::StateText => Ok(state_text_array(&self.state_text)),
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -391,23 +685,53 @@ impl BacnetObject for MultiStateOutput {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::PresentValue => {
+            PropertyIdentifier::PresentValue => self.write_property(property, value, None),
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::RelinquishDefault => {
                 if let PropertyValue::UnsignedInteger(val) = value {
-                    // Write to priority 8 (manual operator) by default
-                    self.write_priority(8, Some(val))
+                    self.set_relinquish_default(val)
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::OutOfService => {
-                if let PropertyValue::Boolean(oos) = value {
-                    self.out_of_service = oos;
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn write_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        if property != PropertyIdentifier::PresentValue {
+            return self.set_property(property, value);
+        }
+        // Writes without a priority use the lowest priority (16)
+        let priority = priority.unwrap_or(16);
+        match value {
+            PropertyValue::UnsignedInteger(val) => self.write_priority(priority, Some(val)),
+            // NULL relinquishes the command at this priority
+            PropertyValue::Null => self.write_priority(priority, None),
+            _ => Err(ObjectError::InvalidPropertyType),
         }
     }
 
@@ -415,20 +739,32 @@ impl BacnetObject for MultiStateOutput {
         matches!(
             property,
             PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
                 | PropertyIdentifier::PresentValue
                 | PropertyIdentifier::OutOfService
-        )
+                | PropertyIdentifier::RelinquishDefault
+        ) || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::DeviceType,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
             PropertyIdentifier::OutOfService,
             PropertyIdentifier::PriorityArray,
-        ]
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::NumberOfStates,
+            PropertyIdentifier::StateText,
+        ];
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -446,24 +782,34 @@ impl BacnetObject for MultiStateValue {
                 Ok(PropertyValue::CharacterString(self.object_name.clone()))
             }
             PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
-                ObjectType::MultiStateValue as u32,
+                ObjectType::MultiStateValue.as_u16() as u32,
             )),
             PropertyIdentifier::PresentValue => {
                 Ok(PropertyValue::UnsignedInteger(self.present_value))
             }
             PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
-            PropertyIdentifier::PriorityArray => {
-                let array: Vec<PropertyValue> = self
-                    .priority_array
-                    .iter()
-                    .map(|&v| match v {
-                        Some(val) => PropertyValue::UnsignedInteger(val),
-                        None => PropertyValue::Null,
-                    })
-                    .collect();
-                Ok(PropertyValue::Array(array))
-            }
-            _ => Err(ObjectError::UnknownProperty),
+            PropertyIdentifier::PriorityArray => Ok(PropertyValue::Array(
+                self.priority_array
+                    .to_property_values(PropertyValue::UnsignedInteger),
+            )),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::RelinquishDefault => {
+                Ok(PropertyValue::UnsignedInteger(self.relinquish_default))
+            }
+            PropertyIdentifier::NumberOfStates => {
+                Ok(PropertyValue::UnsignedInteger(self.number_of_states))
+            }
+            PropertyIdentifier::StateText => Ok(state_text_array(&self.state_text)),
+            _ => self.proprietary.get_property(property),
         }
     }
 
@@ -477,23 +823,53 @@ impl BacnetObject for MultiStateValue {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::PresentValue => {
-                if let PropertyValue::UnsignedInteger(val) = value {
-                    // Write to priority 8 (manual operator) by default
-                    self.write_priority(8, Some(val))
+            PropertyIdentifier::PresentValue => self.write_property(property, value, None),
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    self.update_status_flags();
+                    Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            PropertyIdentifier::OutOfService => {
-                if let PropertyValue::Boolean(oos) = value {
-                    self.out_of_service = oos;
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
                     Ok(())
                 } else {
                     Err(ObjectError::InvalidPropertyType)
                 }
             }
-            _ => Err(ObjectError::PropertyNotWritable),
+            PropertyIdentifier::RelinquishDefault => {
+                if let PropertyValue::UnsignedInteger(val) = value {
+                    self.set_relinquish_default(val)
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Proprietary(_) => self.proprietary.set_property(property, value),
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn write_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+        priority: Option<u8>,
+    ) -> Result<()> {
+        if property != PropertyIdentifier::PresentValue {
+            return self.set_property(property, value);
+        }
+        // Writes without a priority use the lowest priority (16)
+        let priority = priority.unwrap_or(16);
+        match value {
+            PropertyValue::UnsignedInteger(val) => self.write_priority(priority, Some(val)),
+            // NULL relinquishes the command at this priority
+            PropertyValue::Null => self.write_priority(priority, None),
+            _ => Err(ObjectError::InvalidPropertyType),
         }
     }
 
@@ -501,20 +877,31 @@ impl BacnetObject for MultiStateValue {
         matches!(
             property,
             PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
                 | PropertyIdentifier::PresentValue
                 | PropertyIdentifier::OutOfService
-        )
+                | PropertyIdentifier::RelinquishDefault
+        ) || self.proprietary.is_writable(property)
     }
 
     fn property_list(&self) -> Vec<PropertyIdentifier> {
-        vec![
+        let mut properties = vec![
             PropertyIdentifier::ObjectIdentifier,
             PropertyIdentifier::ObjectName,
             PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
             PropertyIdentifier::PresentValue,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
             PropertyIdentifier::OutOfService,
             PropertyIdentifier::PriorityArray,
-        ]
+            PropertyIdentifier::RelinquishDefault,
+            PropertyIdentifier::NumberOfStates,
+            PropertyIdentifier::StateText,
+        ];
+        properties.extend(self.proprietary.property_list());
+        properties
     }
 }
 
@@ -550,6 +937,98 @@ mod tests {
         assert!(msi.set_present_value(4).is_err());
     }
 
+    #[test]
+    fn test_text_for_value_is_one_based() {
+        let mut msv = MultiStateValue::new(1, "Fan Speed".to_string(), 3);
+        msv.state_text = vec!["LOW".to_string(), "MED".to_string(), "HIGH".to_string()];
+
+        assert_eq!(msv.get_text_for_value(1).unwrap(), msv.state_text[0]);
+        assert_eq!(msv.get_text_for_value(3).unwrap(), "HIGH");
+        assert!(msv.get_text_for_value(0).is_err());
+        assert!(msv.get_text_for_value(4).is_err());
+    }
+
+    #[test]
+    fn test_multistate_with_state_text() {
+        let texts = ["OFF", "HEAT", "COOL"].iter().map(|t| t.to_string());
+        let mso = MultiStateOutput::with_state_text(2, "HVAC Mode".to_string(), texts);
+
+        assert_eq!(mso.number_of_states, 3);
+        assert_eq!(mso.state_text.len(), 3);
+        assert_eq!(mso.get_text_for_value(2).unwrap(), "HEAT");
+    }
+
+    #[test]
+    fn test_multistate_insert_remove_state() {
+        let mut msi = MultiStateInput::with_state_text(
+            1,
+            "Mode".to_string(),
+            vec!["OFF".to_string(), "ON".to_string()],
+        );
+
+        msi.insert_state(2, "STANDBY".to_string()).unwrap();
+        assert_eq!(msi.number_of_states, 3);
+        assert_eq!(msi.state_text, vec!["OFF", "STANDBY", "ON"]);
+
+        msi.insert_state(4, "FAULT".to_string()).unwrap();
+        assert!(msi.insert_state(6, "BAD".to_string()).is_err());
+
+        assert_eq!(msi.remove_state(1).unwrap(), "OFF");
+        assert_eq!(msi.number_of_states, 3);
+        assert_eq!(msi.get_text_for_value(1).unwrap(), "STANDBY");
+        assert!(msi.remove_state(0).is_err());
+        assert!(msi.remove_state(4).is_err());
+
+        // The present value must still name a state afterwards
+        msi.set_present_value(3).unwrap();
+        assert!(msi.remove_state(1).is_err());
+        assert_eq!(msi.number_of_states, 3);
+    }
+
+    #[test]
+    fn test_multistate_remove_state_in_use_by_commands() {
+        let mut msv = MultiStateValue::new(1, "Mode".to_string(), 4);
+        msv.write_priority(8, Some(4)).unwrap();
+        assert!(msv.remove_state(2).is_err());
+        assert_eq!(msv.number_of_states, 4);
+        assert_eq!(msv.state_text.len(), 4);
+
+        // Once the command is relinquished the state can go
+        msv.write_priority(8, None).unwrap();
+        assert_eq!(msv.remove_state(2).unwrap(), "State 2");
+        assert_eq!(msv.number_of_states, 3);
+
+        let mut mso = MultiStateOutput::new(1, "Stage".to_string(), 3);
+        mso.set_relinquish_default(3).unwrap();
+        assert!(mso.remove_state(1).is_err());
+        mso.set_relinquish_default(2).unwrap();
+        mso.remove_state(1).unwrap();
+        assert_eq!(mso.present_value, 2);
+        assert!(mso.remove_state(1).is_err());
+    }
+
+    #[test]
+    fn test_multistate_remove_state_out_of_range_first() {
+        let out_of_range = |result: Result<String>| {
+            matches!(result, Err(ObjectError::InvalidValue(message))
+                if message == "State must be between 1 and 3")
+        };
+
+        // The last state is in use, but the position is checked first
+        let mut msi = MultiStateInput::new(1, "Mode".to_string(), 3);
+        msi.set_present_value(3).unwrap();
+        assert!(out_of_range(msi.remove_state(msi.number_of_states + 1)));
+
+        let mut msv = MultiStateValue::new(1, "Mode".to_string(), 3);
+        msv.write_priority(8, Some(3)).unwrap();
+        assert!(out_of_range(msv.remove_state(msv.number_of_states + 1)));
+
+        let mut mso = MultiStateOutput::new(1, "Stage".to_string(), 3);
+        mso.set_relinquish_default(3).unwrap();
+        assert!(out_of_range(mso.remove_state(mso.number_of_states + 1)));
+        assert_eq!(mso.state_text.len(), 3);
+    }
+
     #[test]
     fn test_multistate_output_priority() {
         let mut mso = MultiStateOutput::new(1, "Sequence Control".to_string(), 4);
@@ -572,6 +1051,41 @@ mod tests {
         assert_eq!(mso.present_value, 3); // Back to priority 8 value
     }
 
+    #[test]
+    fn test_multistate_output_rejects_out_of_range_writes() {
+        let mut mso = MultiStateOutput::new(1, "Fan Speed".to_string(), 3);
+        mso.write_priority(8, Some(2)).unwrap();
+
+        let result = mso.set_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::UnsignedInteger(4),
+        );
+        assert!(matches!(result, Err(ObjectError::InvalidValue(_))));
+        assert!(mso
+            .set_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::UnsignedInteger(0)
+            )
+            .is_err());
+        // Rejected writes leave the commanded value untouched
+        assert_eq!(mso.present_value, 2);
+        assert_eq!(mso.priority_array.get(8), Some(2));
+
+        assert!(mso.set_relinquish_default(4).is_err());
+        mso.set_property(
+            PropertyIdentifier::RelinquishDefault,
+            PropertyValue::UnsignedInteger(3),
+        )
+        .unwrap();
+        mso.write_priority(8, None).unwrap();
+        assert_eq!(mso.present_value, 3);
+
+        match mso.get_property(PropertyIdentifier::StateText).unwrap() {
+            PropertyValue::Array(texts) => assert_eq!(texts.len(), 3),
+            _ => panic!("Expected Array"),
+        }
+    }
+
     #[test]
     fn test_multistate_properties() {
         let mut msv = MultiStateValue::new(1, "Operating Mode".to_string(), 4);
@@ -592,4 +1106,39 @@ mod tests {
         .unwrap();
         assert_eq!(msv.present_value, 3);
     }
+
+    #[test]
+    fn test_multistate_standard_properties() {
+        let mut msi = MultiStateInput::new(1, "Mode".to_string(), 3);
+
+        for property in msi.property_list() {
+            assert!(msi.get_property(property).is_ok(), "{:?}", property);
+        }
+
+        msi.set_property(
+            PropertyIdentifier::OutOfService,
+            PropertyValue::Boolean(true),
+        )
+        .unwrap();
+        assert!(msi.status_flags.out_of_service);
+        assert!(matches!(
+            msi.set_property(
+                PropertyIdentifier::NumberOfStates,
+                PropertyValue::UnsignedInteger(4)
+            ),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+        assert!(matches!(
+            msi.get_property(PropertyIdentifier::PriorityArray),
+            Err(ObjectError::UnknownProperty)
+        ));
+
+        let mut msv = MultiStateValue::new(2, "Speed".to_string(), 3);
+        msv.set_property(
+            PropertyIdentifier::RelinquishDefault,
+            PropertyValue::UnsignedInteger(2),
+        )
+        .unwrap();
+        assert_eq!(msv.present_value, 2);
+    }
 }
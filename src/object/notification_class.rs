@@ -0,0 +1,360 @@
+//! Notification Class Object Implementation
+//!
+//! This module implements the Notification Class object type as defined in
+//! ASHRAE 135. Event-generating objects name a notification class, which
+//! supplies the priority and acknowledgement policy of each transition and
+//! the list of destinations its notifications are routed to.
+
+use crate::datatypes::{Clock, Destination, EventTransition};
+use crate::encoding;
+use crate::object::{
+    constructed_value, validate_description, write_rejected, BacnetObject, ObjectError,
+    ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue, Result,
+};
+use crate::service::BacnetDateTime;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Notification Class object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NotificationClass {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Description
+    pub description: String,
+    /// Notification class number referenced by event-generating objects
+    pub notification_class: u32,
+    /// Priorities of to-offnormal, to-fault and to-normal notifications
+    pub priority: [u8; 3],
+    /// Whether to-offnormal, to-fault and to-normal transitions need acknowledging
+    pub ack_required: [bool; 3],
+    /// Destinations notifications are routed to
+    pub recipient_list: Vec<Destination>,
+}
+
+impl NotificationClass {
+    /// Create a new Notification Class object
+    ///
+    /// The notification class number defaults to the instance number, and
+    /// every transition is sent at the lowest priority (255) without needing
+    /// acknowledgement.
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::NotificationClass, instance),
+            object_name,
+            description: String::new(),
+            notification_class: instance,
+            priority: [255; 3],
+            ack_required: [false; 3],
+            recipient_list: Vec::new(),
+        }
+    }
+
+    /// Add a destination to the recipient list
+    pub fn add_destination(&mut self, destination: Destination) {
+        self.recipient_list.push(destination);
+    }
+
+    /// Priority of notifications for a transition
+    pub fn priority_for(&self, transition: EventTransition) -> u8 {
+        self.priority[transition as usize]
+    }
+
+    /// Check if a transition needs acknowledging
+    pub fn is_ack_required(&self, transition: EventTransition) -> bool {
+        self.ack_required[transition as usize]
+    }
+
+    /// Destinations to notify of a transition at `now`
+    ///
+    /// Only destinations that want the transition and whose valid days and
+    /// time window include `now` are returned.
+    pub fn recipients_for(
+        &self,
+        transition: EventTransition,
+        now: &BacnetDateTime,
+    ) -> Vec<&Destination> {
+        self.recipient_list
+            .iter()
+            .filter(|destination| destination.should_notify(transition, now))
+            .collect()
+    }
+
+    /// Destinations to notify of a transition happening now by `clock`
+    pub fn current_recipients(
+        &self,
+        transition: EventTransition,
+        clock: &dyn Clock,
+    ) -> Vec<&Destination> {
+        self.recipients_for(transition, &clock.now())
+    }
+
+    /// Encode the Recipient_List as a sequence of destinations
+    pub fn encode_recipient_list(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        for destination in &self.recipient_list {
+            destination.encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Replace the Recipient_List from a sequence of encoded destinations
+    pub fn decode_recipient_list(&mut self, data: &[u8]) -> encoding::Result<()> {
+        let mut destinations = Vec::new();
+        let mut pos = 0;
+        while pos < data.len() {
+            let (destination, consumed) = Destination::decode(&data[pos..])?;
+            destinations.push(destination);
+            pos += consumed;
+        }
+        self.recipient_list = destinations;
+        Ok(())
+    }
+}
+
+impl BacnetObject for NotificationClass {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::NotificationClass.as_u16() as u32,
+            )),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::NotificationClass => {
+                Ok(PropertyValue::UnsignedInteger(self.notification_class))
+            }
+            PropertyIdentifier::Priority => Ok(PropertyValue::Array(
+                self.priority
+                    .iter()
+                    .map(|&priority| PropertyValue::UnsignedInteger(priority as u32))
+                    .collect(),
+            )),
+            PropertyIdentifier::AckRequired => {
+                Ok(PropertyValue::BitString(self.ack_required.to_vec()))
+            }
+            PropertyIdentifier::RecipientList => {
+                constructed_value(|buffer| self.encode_recipient_list(buffer))
+            }
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match property {
+            PropertyIdentifier::ObjectName => {
+                if let PropertyValue::CharacterString(name) = value {
+                    self.object_name = name;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Priority => {
+                let PropertyValue::Array(elements) = value else {
+                    return Err(ObjectError::InvalidPropertyType);
+                };
+                if elements.len() != 3 {
+                    return Err(ObjectError::InvalidValue(
+                        "Priority needs one value per transition".to_string(),
+                    ));
+                }
+                let mut priority = [0u8; 3];
+                for (slot, element) in priority.iter_mut().zip(elements) {
+                    let PropertyValue::UnsignedInteger(value) = element else {
+                        return Err(ObjectError::InvalidPropertyType);
+                    };
+                    *slot = u8::try_from(value).map_err(|_| {
+                        ObjectError::InvalidValue(format!("Priority {} exceeds 255", value))
+                    })?;
+                }
+                self.priority = priority;
+                Ok(())
+            }
+            PropertyIdentifier::AckRequired => {
+                let PropertyValue::BitString(bits) = value else {
+                    return Err(ObjectError::InvalidPropertyType);
+                };
+                let bit = |n: usize| bits.get(n).copied().unwrap_or(false);
+                self.ack_required = [bit(0), bit(1), bit(2)];
+                Ok(())
+            }
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::Priority
+                | PropertyIdentifier::AckRequired
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::NotificationClass,
+            PropertyIdentifier::Priority,
+            PropertyIdentifier::AckRequired,
+            PropertyIdentifier::RecipientList,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datatypes::{BacnetTime, Recipient};
+    use crate::object::{Date, Time};
+
+    fn monday(hour: u8) -> BacnetDateTime {
+        BacnetDateTime::new(
+            Date {
+                year: 2025,
+                month: 3,
+                day: 10,
+                weekday: 1,
+            },
+            Time {
+                hour,
+                minute: 0,
+                second: 0,
+                hundredths: 0,
+            },
+        )
+    }
+
+    #[test]
+    fn test_recipients_for_transition() {
+        let mut class = NotificationClass::new(5, "Critical alarms".to_string());
+        let mut day_shift = Destination::new(
+            Recipient::Device(ObjectIdentifier::new(ObjectType::Device, 10)),
+            1,
+        );
+        day_shift.from_time = BacnetTime::new(8, 0, 0, 0).unwrap();
+        day_shift.to_time = BacnetTime::new(18, 0, 0, 0).unwrap();
+        let mut faults_only = Destination::new(
+            Recipient::Device(ObjectIdentifier::new(ObjectType::Device, 20)),
+            2,
+        );
+        faults_only.transitions = [false, true, false];
+        class.add_destination(day_shift);
+        class.add_destination(faults_only);
+
+        let recipients = class.recipients_for(EventTransition::ToOffnormal, &monday(9));
+        assert_eq!(recipients.len(), 1);
+        assert_eq!(recipients[0].process_identifier, 1);
+        assert_eq!(
+            class
+                .recipients_for(EventTransition::ToFault, &monday(9))
+                .len(),
+            2
+        );
+        // Outside the day shift only the fault recipient remains
+        let recipients = class.recipients_for(EventTransition::ToFault, &monday(22));
+        assert_eq!(recipients.len(), 1);
+        assert_eq!(recipients[0].process_identifier, 2);
+        assert!(class
+            .recipients_for(EventTransition::ToNormal, &monday(22))
+            .is_empty());
+
+        // The recipient list survives a round trip through its encoding
+        let mut buffer = Vec::new();
+        class.encode_recipient_list(&mut buffer).unwrap();
+        let mut copy = NotificationClass::new(6, "Copy".to_string());
+        copy.decode_recipient_list(&buffer).unwrap();
+        assert_eq!(copy.recipient_list, class.recipient_list);
+
+        for property in class.property_list() {
+            assert!(
+                class.get_property(property).is_ok(),
+                "{:?} is listed but not readable",
+                property
+            );
+        }
+        match class
+            .get_property(PropertyIdentifier::RecipientList)
+            .unwrap()
+        {
+            PropertyValue::Constructed(data) => assert_eq!(data, buffer),
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_priority_and_ack_required_properties() {
+        let mut class = NotificationClass::new(5, "Critical alarms".to_string());
+        assert!(matches!(
+            class.get_property(PropertyIdentifier::NotificationClass),
+            Ok(PropertyValue::UnsignedInteger(5))
+        ));
+
+        class
+            .set_property(
+                PropertyIdentifier::Priority,
+                PropertyValue::Array(vec![
+                    PropertyValue::UnsignedInteger(10),
+                    PropertyValue::UnsignedInteger(20),
+                    PropertyValue::UnsignedInteger(200),
+                ]),
+            )
+            .unwrap();
+        assert_eq!(class.priority_for(EventTransition::ToFault), 20);
+        assert!(class
+            .set_property(
+                PropertyIdentifier::Priority,
+                PropertyValue::Array(vec![PropertyValue::UnsignedInteger(10)]),
+            )
+            .is_err());
+
+        class
+            .set_property(
+                PropertyIdentifier::AckRequired,
+                PropertyValue::BitString(vec![true, true, false]),
+            )
+            .unwrap();
+        assert!(class.is_ack_required(EventTransition::ToOffnormal));
+        assert!(!class.is_ack_required(EventTransition::ToNormal));
+
+        assert!(matches!(
+            class.set_property(
+                PropertyIdentifier::NotificationClass,
+                PropertyValue::UnsignedInteger(9)
+            ),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+//! Priority Array Implementation
+//!
+//! Commandable objects (outputs and values) arbitrate writes through a 16 level
+//! priority array as defined in ASHRAE 135 Clause 19. Priority 1 is the highest;
+//! the present value is taken from the highest priority non-null slot, or from
+//! Relinquish_Default when every slot is null.
+
+use crate::encoding;
+use crate::object::{ObjectError, PropertyValue, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::ToString, vec::Vec};
+
+/// Number of command priority levels
+pub const PRIORITY_LEVELS: usize = 16;
+
+/// 16 level command priority array
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriorityArray<T> {
+    slots: [Option<T>; PRIORITY_LEVELS],
+}
+
+impl<T: Copy> PriorityArray<T> {
+    /// Create an empty (fully relinquished) priority array
+    pub fn new() -> Self {
+        Self {
+            slots: [None; PRIORITY_LEVELS],
+        }
+    }
+
+    /// Command or relinquish (`None`) the slot at a priority level (1-16)
+    pub fn set(&mut self, priority: u8, value: Option<T>) -> Result<()> {
+        let index = Self::index(priority)?;
+        self.slots[index] = value;
+        Ok(())
+    }
+
+    /// Relinquish the slot at a priority level (1-16)
+    pub fn relinquish(&mut self, priority: u8) -> Result<()> {
+        self.set(priority, None)
+    }
+
+    /// Get the value commanded at a priority level, if any
+    pub fn get(&self, priority: u8) -> Option<T> {
+        Self::index(priority)
+            .ok()
+            .and_then(|index| self.slots[index])
+    }
+
+    /// Value of the highest priority non-null slot, or the relinquish default
+    pub fn effective_value(&self, relinquish_default: T) -> T {
+        self.slots
+            .iter()
+            .flatten()
+            .next()
+            .copied()
+            .unwrap_or(relinquish_default)
+    }
+
+    /// Priority level currently in control, if any slot is commanded
+    pub fn effective_priority(&self) -> Option<u8> {
+        self.slots
+            .iter()
+            .position(Option::is_some)
+            .map(|index| (index + 1) as u8)
+    }
+
+    /// Get all slots, highest priority first
+    pub fn slots(&self) -> &[Option<T>; PRIORITY_LEVELS] {
+        &self.slots
+    }
+
+    /// Convert to property values, with relinquished slots as Null
+    pub fn to_property_values(&self, convert: impl Fn(T) -> PropertyValue) -> Vec<PropertyValue> {
+        self.slots
+            .iter()
+            .map(|slot| match slot {
+                Some(value) => convert(*value),
+                None => PropertyValue::Null,
+            })
+            .collect()
+    }
+
+    /// Encode all 16 slots as application tagged values, highest priority first
+    ///
+    /// Relinquished slots are encoded as NULL, so a reader always receives
+    /// exactly [`PRIORITY_LEVELS`] elements.
+    pub fn encode(
+        &self,
+        buffer: &mut Vec<u8>,
+        convert: impl Fn(T) -> PropertyValue,
+    ) -> encoding::Result<()> {
+        for value in self.to_property_values(convert) {
+            value.encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    fn index(priority: u8) -> Result<usize> {
+        if !(1..=PRIORITY_LEVELS as u8).contains(&priority) {
+            return Err(ObjectError::InvalidValue(
+                "Priority must be 1-16".to_string(),
+            ));
+        }
+        Ok((priority - 1) as usize)
+    }
+}
+
+impl<T: Copy> Default for PriorityArray<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_priority_array_effective_value() {
+        let mut array = PriorityArray::new();
+        assert_eq!(array.effective_value(0.0), 0.0);
+        assert_eq!(array.effective_priority(), None);
+
+        array.set(10, Some(55.0)).unwrap();
+        array.set(4, Some(20.0)).unwrap();
+        assert_eq!(array.effective_value(0.0), 20.0);
+        assert_eq!(array.effective_priority(), Some(4));
+
+        array.relinquish(4).unwrap();
+        assert_eq!(array.effective_value(0.0), 55.0);
+        assert_eq!(array.get(10), Some(55.0));
+        assert_eq!(array.get(4), None);
+    }
+
+    #[test]
+    fn test_priority_array_range() {
+        let mut array = PriorityArray::<u32>::new();
+        assert!(array.set(0, Some(1)).is_err());
+        assert!(array.set(17, Some(1)).is_err());
+        assert!(array.relinquish(0).is_err());
+        assert!(array.set(1, Some(1)).is_ok());
+        assert!(array.set(16, Some(2)).is_ok());
+        assert_eq!(array.get(17), None);
+    }
+
+    #[test]
+    fn test_priority_array_property_values() {
+        let mut array = PriorityArray::new();
+        array.set(8, Some(3u32)).unwrap();
+
+        let values = array.to_property_values(PropertyValue::UnsignedInteger);
+        assert_eq!(values.len(), 16);
+        assert!(matches!(values[7], PropertyValue::UnsignedInteger(3)));
+        assert!(matches!(values[0], PropertyValue::Null));
+    }
+
+    #[test]
+    fn test_priority_array_encode() {
+        let mut array = PriorityArray::new();
+        array.set(1, Some(true)).unwrap();
+        array.set(16, Some(false)).unwrap();
+
+        let mut buffer = Vec::new();
+        array
+            .encode(&mut buffer, |active| {
+                PropertyValue::Enumerated(active as u32)
+            })
+            .unwrap();
+        let mut expected = vec![0x91, 0x01];
+        expected.extend_from_slice(&[0x00; 14]);
+        expected.extend_from_slice(&[0x91, 0x00]);
+        assert_eq!(buffer, expected);
+
+        // A Priority_Array read encodes the same 16 values
+        let slots = array.to_property_values(|active| PropertyValue::Enumerated(active as u32));
+        let mut read = Vec::new();
+        PropertyValue::Array(slots).encode(&mut read).unwrap();
+        assert_eq!(read, buffer);
+    }
+
+    #[test]
+    fn test_priority_array_read_by_index() {
+        use crate::object::{AnalogOutput, BacnetObject, PropertyIdentifier};
+
+        let mut ao = AnalogOutput::new(1, "Damper".to_string());
+        ao.write_priority(8, Some(40.0)).unwrap();
+        let element = |index| {
+            ao.get_property_element(PropertyIdentifier::PriorityArray, index)
+                .unwrap()
+        };
+
+        assert!(matches!(
+            element(Some(0)),
+            PropertyValue::UnsignedInteger(16)
+        ));
+        assert!(matches!(element(Some(8)), PropertyValue::Real(v) if v == 40.0));
+        assert!(matches!(element(Some(16)), PropertyValue::Null));
+        let PropertyValue::Array(slots) = element(None) else {
+            panic!("Priority_Array should be an array");
+        };
+        assert_eq!(slots.len(), 16);
+
+        // The whole array encodes as 15 NULLs and the commanded Real
+        let mut buffer = Vec::new();
+        PropertyValue::Array(slots).encode(&mut buffer).unwrap();
+        assert_eq!(buffer.len(), 15 + 5);
+        assert_eq!(buffer[..7], [0x00; 7]);
+        assert_eq!(buffer[7..12], [0x44, 0x42, 0x20, 0x00, 0x00]);
+        assert_eq!(buffer[12..], [0x00; 8]);
+        assert!(matches!(
+            ao.get_property_element(PropertyIdentifier::PriorityArray, Some(17)),
+            Err(ObjectError::InvalidArrayIndex)
+        ));
+    }
+}
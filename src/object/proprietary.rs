@@ -0,0 +1,173 @@
+//! Proprietary Properties
+//!
+//! Vendors extend standard objects with properties of their own, identified
+//! by property identifiers from 512 upward. Objects carry these in a
+//! [`ProprietaryProperties`] map, which their `get_property` and
+//! `set_property` fall back to for [`PropertyIdentifier::Proprietary`]
+//! identifiers, so ReadProperty and WriteProperty reach them like any other
+//! property.
+
+use crate::object::{ObjectError, PropertyIdentifier, PropertyValue, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{collections::BTreeMap, format, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+/// First property identifier reserved for vendor use
+pub const FIRST_PROPRIETARY_PROPERTY: u32 = 512;
+
+/// Last property identifier reserved for vendor use
+pub const LAST_PROPRIETARY_PROPERTY: u32 = 4_194_303;
+
+/// Vendor-specific property values of an object
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProprietaryProperties {
+    /// Value and writability of each property, by identifier
+    properties: BTreeMap<u32, (PropertyValue, bool)>,
+}
+
+impl ProprietaryProperties {
+    /// Create an empty set of proprietary properties
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add or replace a read-only proprietary property
+    pub fn insert(&mut self, id: u32, value: PropertyValue) -> Result<()> {
+        self.insert_with_access(id, value, false)
+    }
+
+    /// Add or replace a proprietary property that WriteProperty may change
+    pub fn insert_writable(&mut self, id: u32, value: PropertyValue) -> Result<()> {
+        self.insert_with_access(id, value, true)
+    }
+
+    fn insert_with_access(&mut self, id: u32, value: PropertyValue, writable: bool) -> Result<()> {
+        if !(FIRST_PROPRIETARY_PROPERTY..=LAST_PROPRIETARY_PROPERTY).contains(&id) {
+            return Err(ObjectError::InvalidValue(format!(
+                "Property {} is not in the proprietary range",
+                id
+            )));
+        }
+        self.properties.insert(id, (value, writable));
+        Ok(())
+    }
+
+    /// Remove a proprietary property, returning its value
+    pub fn remove(&mut self, id: u32) -> Option<PropertyValue> {
+        self.properties.remove(&id).map(|(value, _)| value)
+    }
+
+    /// Get the value of a proprietary property
+    pub fn get(&self, id: u32) -> Option<&PropertyValue> {
+        self.properties.get(&id).map(|(value, _)| value)
+    }
+
+    /// Check if there are no proprietary properties
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    /// Read a property, for an object's `get_property` fallback
+    ///
+    /// Standard and unknown properties are reported as unknown.
+    pub fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::Proprietary(id) => {
+                self.get(id).cloned().ok_or(ObjectError::UnknownProperty)
+            }
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    /// Write a property, for an object's `set_property` fallback
+    ///
+    /// Read-only properties deny the write; standard and unknown properties
+    /// are reported as unknown.
+    pub fn set_property(
+        &mut self,
+        property: PropertyIdentifier,
+        value: PropertyValue,
+    ) -> Result<()> {
+        let PropertyIdentifier::Proprietary(id) = property else {
+            return Err(ObjectError::UnknownProperty);
+        };
+        match self.properties.get_mut(&id) {
+            Some((stored, true)) => {
+                *stored = value;
+                Ok(())
+            }
+            Some((_, false)) => Err(ObjectError::WriteAccessDenied),
+            None => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    /// Check if a property is a writable proprietary property
+    pub fn is_writable(&self, property: PropertyIdentifier) -> bool {
+        match property {
+            PropertyIdentifier::Proprietary(id) => {
+                matches!(self.properties.get(&id), Some((_, true)))
+            }
+            _ => false,
+        }
+    }
+
+    /// Identifiers of the proprietary properties, for an object's property list
+    pub fn property_list(&self) -> Vec<PropertyIdentifier> {
+        self.properties
+            .keys()
+            .map(|&id| PropertyIdentifier::Proprietary(id))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{AnalogInput, BacnetObject};
+
+    #[test]
+    fn test_proprietary_properties_on_an_object() {
+        let mut ai = AnalogInput::new(1, "Zone Temp".to_string());
+        ai.proprietary
+            .insert(600, PropertyValue::CharacterString("ACME-42".to_string()))
+            .unwrap();
+        ai.proprietary
+            .insert_writable(601, PropertyValue::UnsignedInteger(3))
+            .unwrap();
+        assert!(ai
+            .proprietary
+            .insert(85, PropertyValue::UnsignedInteger(0))
+            .is_err());
+
+        assert!(matches!(
+            ai.get_property(PropertyIdentifier::from(600)),
+            Ok(PropertyValue::CharacterString(serial)) if serial == "ACME-42"
+        ));
+        assert!(matches!(
+            ai.get_property(PropertyIdentifier::from(602)),
+            Err(ObjectError::UnknownProperty)
+        ));
+        assert!(ai
+            .property_list()
+            .ends_with(&[PropertyIdentifier::from(600), PropertyIdentifier::from(601)]));
+
+        ai.set_property(
+            PropertyIdentifier::from(601),
+            PropertyValue::UnsignedInteger(7),
+        )
+        .unwrap();
+        assert!(matches!(
+            ai.proprietary.get(601),
+            Some(PropertyValue::UnsignedInteger(7))
+        ));
+        assert!(ai.is_property_writable(PropertyIdentifier::from(601)));
+        assert!(!ai.is_property_writable(PropertyIdentifier::from(600)));
+        assert!(matches!(
+            ai.set_property(PropertyIdentifier::from(600), PropertyValue::Boolean(true)),
+            Err(ObjectError::WriteAccessDenied)
+        ));
+    }
+}
@@ -0,0 +1,681 @@
+//! Schedule Object Implementation
+//!
+//! This module implements the Schedule object type as defined in ASHRAE 135.
+//! A Schedule computes its present value from a weekly schedule of time-value
+//! pairs, overridden by prioritized exception schedules that apply on calendar
+//! dates. Evaluation takes the date and time as arguments, or reads them from
+//! a [`Clock`] with [`Schedule::update_present_value_from`], so schedules can
+//! be tested without the system clock.
+
+#[cfg(feature = "std")]
+use crate::datatypes::SystemClock;
+use crate::datatypes::{BacnetDate, BacnetTime, Clock, Weekday};
+use crate::encoding::{
+    self,
+    advanced::context::{encode_closing_tag, encode_opening_tag},
+};
+use crate::object::{
+    calendar::{CalendarEntry, DateRange},
+    constructed_value, validate_description, write_rejected, BacnetObject, ObjectError,
+    ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue, Result,
+};
+use crate::service::BacnetDateTime;
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Number of daily schedules in a weekly schedule
+pub const DAYS_PER_WEEK: usize = 7;
+
+/// Lowest exception schedule priority
+pub const LOWEST_EVENT_PRIORITY: u8 = 16;
+
+/// A value that takes effect at a time of day
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeValue {
+    /// Time the value takes effect
+    pub time: BacnetTime,
+    /// Scheduled value; Null relinquishes to the next lower schedule
+    pub value: PropertyValue,
+}
+
+impl TimeValue {
+    /// Create a new time-value pair
+    pub fn new(time: BacnetTime, value: PropertyValue) -> Self {
+        Self { time, value }
+    }
+
+    /// Encode as the application tagged time followed by the value
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        self.time.encode_application(buffer)?;
+        self.value.encode(buffer)
+    }
+}
+
+/// Encode a list of time-values wrapped in a context tag
+fn encode_time_values(
+    buffer: &mut Vec<u8>,
+    tag_number: u8,
+    time_values: &[TimeValue],
+) -> encoding::Result<()> {
+    encode_opening_tag(buffer, tag_number)?;
+    for time_value in time_values {
+        time_value.encode(buffer)?;
+    }
+    encode_closing_tag(buffer, tag_number)
+}
+
+/// Days an exception schedule applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpecialEventPeriod {
+    /// An inline calendar entry
+    CalendarEntry(CalendarEntry),
+    /// A Calendar object; applies while that calendar is active
+    CalendarReference(ObjectIdentifier),
+}
+
+/// Exception schedule entry
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpecialEvent {
+    /// Days the event applies to
+    pub period: SpecialEventPeriod,
+    /// Values for the day, overriding the weekly schedule
+    pub list_of_time_values: Vec<TimeValue>,
+    /// Priority (1-16, 1 highest) among overlapping events
+    pub event_priority: u8,
+}
+
+impl SpecialEvent {
+    /// Create a new special event
+    pub fn new(
+        period: SpecialEventPeriod,
+        list_of_time_values: Vec<TimeValue>,
+        event_priority: u8,
+    ) -> Result<Self> {
+        if !(1..=LOWEST_EVENT_PRIORITY).contains(&event_priority) {
+            return Err(ObjectError::InvalidValue(
+                "Event priority must be 1-16".to_string(),
+            ));
+        }
+        Ok(Self {
+            period,
+            list_of_time_values,
+            event_priority,
+        })
+    }
+
+    /// Encode as a BACnetSpecialEvent
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        match &self.period {
+            SpecialEventPeriod::CalendarEntry(entry) => {
+                encode_opening_tag(buffer, 0)?;
+                entry.encode(buffer)?;
+                encode_closing_tag(buffer, 0)?;
+            }
+            SpecialEventPeriod::CalendarReference(calendar) => {
+                buffer.extend_from_slice(&encoding::encode_context_object_id(
+                    calendar.object_type.as_u16(),
+                    calendar.instance,
+                    1,
+                )?);
+            }
+        }
+        encode_time_values(buffer, 2, &self.list_of_time_values)?;
+        buffer.extend_from_slice(&encoding::encode_context_unsigned(
+            self.event_priority as u32,
+            3,
+        )?);
+        Ok(())
+    }
+}
+
+/// Schedule object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Schedule {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Description
+    pub description: String,
+    /// Present value, as of the last evaluation
+    pub present_value: PropertyValue,
+    /// Dates within which the schedule is active
+    pub effective_period: DateRange,
+    /// Daily schedules, Monday first
+    pub weekly_schedule: [Vec<TimeValue>; DAYS_PER_WEEK],
+    /// Exception schedules overriding the weekly schedule
+    pub exception_schedule: Vec<SpecialEvent>,
+    /// Value when no schedule entry applies
+    pub schedule_default: PropertyValue,
+    /// Priority used when writing the present value to referenced properties
+    pub priority_for_writing: u8,
+    /// Out of service
+    pub out_of_service: bool,
+}
+
+impl Schedule {
+    /// Create a new Schedule object with empty schedules
+    pub fn new(instance: u32, object_name: String, schedule_default: PropertyValue) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::Schedule, instance),
+            object_name,
+            description: String::new(),
+            present_value: schedule_default.clone(),
+            effective_period: DateRange::new(BacnetDate::any(), BacnetDate::any()),
+            weekly_schedule: Default::default(),
+            exception_schedule: Vec::new(),
+            schedule_default,
+            priority_for_writing: LOWEST_EVENT_PRIORITY,
+            out_of_service: false,
+        }
+    }
+
+    /// Daily schedule for a day of the week
+    pub fn daily_schedule(&self, day: Weekday) -> &[TimeValue] {
+        &self.weekly_schedule[day as usize - 1]
+    }
+
+    /// Replace the daily schedule for a day of the week
+    pub fn set_daily_schedule(&mut self, day: Weekday, time_values: Vec<TimeValue>) {
+        self.weekly_schedule[day as usize - 1] = time_values;
+    }
+
+    /// Add an exception schedule entry
+    pub fn add_exception(&mut self, event: SpecialEvent) {
+        self.exception_schedule.push(event);
+    }
+
+    /// Encode the Weekly_Schedule as seven daily schedules, Monday first
+    pub fn encode_weekly_schedule(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        for daily_schedule in &self.weekly_schedule {
+            encode_time_values(buffer, 0, daily_schedule)?;
+        }
+        Ok(())
+    }
+
+    /// Encode the Exception_Schedule as a sequence of special events
+    pub fn encode_exception_schedule(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        for event in &self.exception_schedule {
+            event.encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Evaluate the schedule at a date and time
+    ///
+    /// Exceptions referencing Calendar objects never apply; use
+    /// [`Schedule::evaluate_with_calendars`] to resolve them.
+    pub fn evaluate(&self, date: &BacnetDate, time: &BacnetTime) -> PropertyValue {
+        self.evaluate_with_calendars(date, time, |_| false)
+    }
+
+    /// Evaluate the schedule, resolving calendar references through a callback
+    ///
+    /// The highest priority exception active on `date` with a non-Null value
+    /// at `time` wins; otherwise the weekly schedule for the day applies, and
+    /// failing that the schedule default. Dates outside the effective period
+    /// always evaluate to the schedule default.
+    pub fn evaluate_with_calendars(
+        &self,
+        date: &BacnetDate,
+        time: &BacnetTime,
+        is_calendar_active: impl Fn(ObjectIdentifier) -> bool,
+    ) -> PropertyValue {
+        let Some((year, month, day)) = date.ymd() else {
+            return self.schedule_default.clone();
+        };
+        if !self.effective_period.contains(date) {
+            return self.schedule_default.clone();
+        }
+
+        let mut exception: Option<(u8, &PropertyValue)> = None;
+        for event in &self.exception_schedule {
+            let applies = match event.period {
+                SpecialEventPeriod::CalendarEntry(entry) => entry.is_active(date),
+                SpecialEventPeriod::CalendarReference(calendar) => is_calendar_active(calendar),
+            };
+            if !applies {
+                continue;
+            }
+            if let Some(value) = scheduled_value(&event.list_of_time_values, time) {
+                if exception.is_none_or(|(priority, _)| event.event_priority < priority) {
+                    exception = Some((event.event_priority, value));
+                }
+            }
+        }
+        if let Some((_, value)) = exception {
+            return value.clone();
+        }
+
+        Weekday::of_date(year, month, day)
+            .and_then(|weekday| scheduled_value(self.daily_schedule(weekday), time))
+            .unwrap_or(&self.schedule_default)
+            .clone()
+    }
+
+    /// Evaluate the schedule at a timestamp
+    ///
+    /// Timestamps that are not valid dates and times evaluate to the schedule
+    /// default.
+    pub fn evaluate_at(&self, now: &BacnetDateTime) -> PropertyValue {
+        match (
+            BacnetDate::try_from(now.date),
+            BacnetTime::try_from(now.time),
+        ) {
+            (Ok(date), Ok(time)) => self.evaluate(&date, &time),
+            _ => self.schedule_default.clone(),
+        }
+    }
+
+    /// Evaluate the schedule at a timestamp and store the result as the present value
+    ///
+    /// Out of service schedules keep their present value.
+    pub fn update_present_value(&mut self, now: &BacnetDateTime) -> &PropertyValue {
+        if !self.out_of_service {
+            self.present_value = self.evaluate_at(now);
+        }
+        &self.present_value
+    }
+
+    /// Evaluate the schedule at the time of a clock and store the result
+    pub fn update_present_value_from(&mut self, clock: &dyn Clock) -> &PropertyValue {
+        self.update_present_value(&clock.now())
+    }
+
+    /// Evaluate the schedule at the local system time
+    #[cfg(feature = "std")]
+    pub fn update_present_value_now(&mut self) -> &PropertyValue {
+        self.update_present_value_from(&SystemClock)
+    }
+}
+
+/// Value of the latest entry at or before `time`, unless it is Null
+fn scheduled_value<'a>(
+    time_values: &'a [TimeValue],
+    time: &BacnetTime,
+) -> Option<&'a PropertyValue> {
+    let now = time_key(time);
+    time_values
+        .iter()
+        .filter(|entry| time_key(&entry.time) <= now)
+        .max_by_key(|entry| time_key(&entry.time))
+        .map(|entry| &entry.value)
+        .filter(|value| !matches!(value, PropertyValue::Null))
+}
+
+/// Sort key for a time of day, with unspecified fields as zero
+fn time_key(time: &BacnetTime) -> (u8, u8, u8, u8) {
+    (
+        time.hour.unwrap_or(0),
+        time.minute.unwrap_or(0),
+        time.second.unwrap_or(0),
+        time.hundredths.unwrap_or(0),
+    )
+}
+
+impl BacnetObject for Schedule {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::Schedule.as_u16() as u32,
+            )),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::PresentValue => Ok(self.present_value.clone()),
+            PropertyIdentifier::EffectivePeriod => Ok(PropertyValue::List(vec![
+                PropertyValue::Date(self.effective_period.start_date.into()),
+                PropertyValue::Date(self.effective_period.end_date.into()),
+            ])),
+            PropertyIdentifier::WeeklySchedule => {
+                constructed_value(|buffer| self.encode_weekly_schedule(buffer))
+            }
+            PropertyIdentifier::ExceptionSchedule => {
+                constructed_value(|buffer| self.encode_exception_schedule(buffer))
+            }
+            PropertyIdentifier::ScheduleDefault => Ok(self.schedule_default.clone()),
+            PropertyIdentifier::PriorityForWriting => Ok(PropertyValue::UnsignedInteger(
+                self.priority_for_writing as u32,
+            )),
+            PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match property {
+            PropertyIdentifier::ObjectName => {
+                if let PropertyValue::CharacterString(name) = value {
+                    self.object_name = name;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            // Present value is only writable while out of service
+            PropertyIdentifier::PresentValue if self.out_of_service => {
+                self.present_value = value;
+                Ok(())
+            }
+            PropertyIdentifier::ScheduleDefault => {
+                self.schedule_default = value;
+                Ok(())
+            }
+            PropertyIdentifier::PriorityForWriting => match value {
+                PropertyValue::UnsignedInteger(priority)
+                    if (1..=LOWEST_EVENT_PRIORITY as u32).contains(&priority) =>
+                {
+                    self.priority_for_writing = priority as u8;
+                    Ok(())
+                }
+                PropertyValue::UnsignedInteger(_) => Err(ObjectError::InvalidValue(
+                    "Priority must be 1-16".to_string(),
+                )),
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.out_of_service = oos;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        match property {
+            PropertyIdentifier::PresentValue => self.out_of_service,
+            _ => matches!(
+                property,
+                PropertyIdentifier::ObjectName
+                    | PropertyIdentifier::Description
+                    | PropertyIdentifier::ScheduleDefault
+                    | PropertyIdentifier::PriorityForWriting
+                    | PropertyIdentifier::OutOfService
+            ),
+        }
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::EffectivePeriod,
+            PropertyIdentifier::WeeklySchedule,
+            PropertyIdentifier::ExceptionSchedule,
+            PropertyIdentifier::ScheduleDefault,
+            PropertyIdentifier::PriorityForWriting,
+            PropertyIdentifier::OutOfService,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u8, minute: u8) -> BacnetTime {
+        BacnetTime::new(hour, minute, 0, 0).unwrap()
+    }
+
+    fn date(year: u16, month: u8, day: u8) -> BacnetDate {
+        BacnetDate::new(year, month, day).unwrap()
+    }
+
+    fn timestamp(date: BacnetDate, time: BacnetTime) -> BacnetDateTime {
+        BacnetDateTime::new(date.into(), time.into())
+    }
+
+    fn office_hours() -> Schedule {
+        let mut schedule = Schedule::new(1, "Occupancy".to_string(), PropertyValue::Enumerated(0));
+        for day in [
+            Weekday::Monday,
+            Weekday::Tuesday,
+            Weekday::Wednesday,
+            Weekday::Thursday,
+            Weekday::Friday,
+        ] {
+            schedule.set_daily_schedule(
+                day,
+                vec![
+                    TimeValue::new(at(7, 0), PropertyValue::Enumerated(1)),
+                    TimeValue::new(at(18, 0), PropertyValue::Null),
+                ],
+            );
+        }
+        schedule
+    }
+
+    #[test]
+    fn test_weekly_schedule() {
+        let schedule = office_hours();
+        // 2025-10-13 is a Monday
+        let monday = date(2025, 10, 13);
+        assert!(matches!(
+            schedule.evaluate(&monday, &at(6, 59)),
+            PropertyValue::Enumerated(0)
+        ));
+        assert!(matches!(
+            schedule.evaluate(&monday, &at(7, 0)),
+            PropertyValue::Enumerated(1)
+        ));
+        // Null relinquishes to the schedule default
+        assert!(matches!(
+            schedule.evaluate(&monday, &at(18, 30)),
+            PropertyValue::Enumerated(0)
+        ));
+        assert!(matches!(
+            schedule.evaluate(&date(2025, 10, 18), &at(12, 0)),
+            PropertyValue::Enumerated(0)
+        ));
+    }
+
+    #[test]
+    fn test_exceptions_override_by_priority() {
+        let mut schedule = office_hours();
+        let holiday = CalendarEntry::Date(date(2025, 12, 25));
+        schedule.add_exception(
+            SpecialEvent::new(
+                SpecialEventPeriod::CalendarEntry(holiday),
+                vec![TimeValue::new(at(0, 0), PropertyValue::Enumerated(0))],
+                10,
+            )
+            .unwrap(),
+        );
+        schedule.add_exception(
+            SpecialEvent::new(
+                SpecialEventPeriod::CalendarEntry(holiday),
+                vec![TimeValue::new(at(9, 0), PropertyValue::Enumerated(2))],
+                5,
+            )
+            .unwrap(),
+        );
+
+        let christmas = date(2025, 12, 25);
+        assert!(matches!(
+            schedule.evaluate(&christmas, &at(8, 0)),
+            PropertyValue::Enumerated(0)
+        ));
+        assert!(matches!(
+            schedule.evaluate(&christmas, &at(9, 30)),
+            PropertyValue::Enumerated(2)
+        ));
+        assert!(SpecialEvent::new(SpecialEventPeriod::CalendarEntry(holiday), vec![], 0).is_err());
+    }
+
+    #[test]
+    fn test_calendar_reference_and_effective_period() {
+        let mut schedule = office_hours();
+        let calendar = ObjectIdentifier::new(ObjectType::Calendar, 3);
+        schedule.add_exception(
+            SpecialEvent::new(
+                SpecialEventPeriod::CalendarReference(calendar),
+                vec![TimeValue::new(at(0, 0), PropertyValue::Enumerated(3))],
+                1,
+            )
+            .unwrap(),
+        );
+
+        let monday = date(2025, 10, 13);
+        assert!(matches!(
+            schedule.evaluate(&monday, &at(10, 0)),
+            PropertyValue::Enumerated(1)
+        ));
+        assert!(matches!(
+            schedule.evaluate_with_calendars(&monday, &at(10, 0), |id| id == calendar),
+            PropertyValue::Enumerated(3)
+        ));
+
+        schedule.effective_period = DateRange::new(date(2026, 1, 1), BacnetDate::any());
+        assert!(matches!(
+            schedule.update_present_value(&timestamp(monday, at(10, 0))),
+            PropertyValue::Enumerated(0)
+        ));
+    }
+
+    #[test]
+    fn test_schedule_listed_properties_are_readable() {
+        let mut schedule = office_hours();
+        schedule.add_exception(
+            SpecialEvent::new(
+                SpecialEventPeriod::CalendarReference(ObjectIdentifier::new(
+                    ObjectType::Calendar,
+                    3,
+                )),
+                vec![TimeValue::new(at(0, 0), PropertyValue::Enumerated(3))],
+                1,
+            )
+            .unwrap(),
+        );
+        for property in schedule.property_list() {
+            assert!(
+                schedule.get_property(property).is_ok(),
+                "{:?} is listed but not readable",
+                property
+            );
+        }
+
+        match schedule
+            .get_property(PropertyIdentifier::ExceptionSchedule)
+            .unwrap()
+        {
+            PropertyValue::Constructed(data) => assert_eq!(
+                data,
+                [
+                    0x1C, 0x01, 0x80, 0x00, 0x03, // [1] calendar reference
+                    0x2E, 0xB4, 0x00, 0x00, 0x00, 0x00, 0x91, 0x03, 0x2F, // [2] time values
+                    0x39, 0x01, // [3] event priority
+                ]
+            ),
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+        assert!(matches!(
+            schedule.get_property(PropertyIdentifier::EffectivePeriod),
+            Ok(PropertyValue::List(ref dates)) if dates.len() == 2
+        ));
+    }
+
+    #[test]
+    fn test_transitions_across_midnight_and_dst() {
+        let mut schedule = office_hours();
+        schedule.set_daily_schedule(
+            Weekday::Friday,
+            vec![TimeValue::new(at(22, 0), PropertyValue::Enumerated(4))],
+        );
+        schedule.set_daily_schedule(
+            Weekday::Sunday,
+            vec![TimeValue::new(at(2, 30), PropertyValue::Enumerated(5))],
+        );
+
+        // Friday 2025-10-17 into Saturday; Friday's entry ends at midnight
+        let friday_night = timestamp(date(2025, 10, 17), BacnetTime::new(23, 59, 59, 99).unwrap());
+        assert!(matches!(
+            schedule.update_present_value(&friday_night),
+            PropertyValue::Enumerated(4)
+        ));
+        assert!(matches!(
+            schedule.update_present_value(&timestamp(date(2025, 10, 18), at(0, 0))),
+            PropertyValue::Enumerated(0)
+        ));
+
+        // Clocks jump from 02:00 to 03:00 on 2026-03-29; an entry in the
+        // skipped hour takes effect at the first local time after it
+        let spring_forward = date(2026, 3, 29);
+        assert!(matches!(
+            schedule.evaluate_at(&timestamp(spring_forward, at(1, 59))),
+            PropertyValue::Enumerated(0)
+        ));
+        assert!(matches!(
+            schedule.evaluate_at(&timestamp(spring_forward, at(3, 0))),
+            PropertyValue::Enumerated(5)
+        ));
+
+        // Timestamps with unspecified fields fall back to the default
+        assert!(matches!(
+            schedule.evaluate_at(&BacnetDateTime::unspecified()),
+            PropertyValue::Enumerated(0)
+        ));
+    }
+
+    #[test]
+    fn test_update_present_value_from_clock() {
+        use crate::datatypes::ManualClock;
+        use core::time::Duration;
+
+        let mut schedule = office_hours();
+        // Friday 2024-03-15 just before the end of the working day
+        let clock = ManualClock::new(timestamp(date(2024, 3, 15), at(17, 59)));
+        assert!(matches!(
+            schedule.update_present_value_from(&clock),
+            PropertyValue::Enumerated(1)
+        ));
+
+        clock.advance(Duration::from_secs(60));
+        assert!(matches!(
+            schedule.update_present_value_from(&clock),
+            PropertyValue::Enumerated(0)
+        ));
+
+        // Monday morning, after the weekend
+        clock.advance(Duration::from_secs(((2 * 24 + 13) * 60 + 30) * 60));
+        assert_eq!(clock.now().date.weekday, Weekday::Monday as u8);
+        assert!(matches!(
+            schedule.update_present_value_from(&clock),
+            PropertyValue::Enumerated(1)
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+//! Status Flags Implementation
+//!
+//! Status_Flags is a four bit summary of an object's health, encoded as a BACnet
+//! bit string in the order in-alarm, fault, overridden, out-of-service. Objects
+//! derive it from their event state, reliability and out-of-service property.
+
+use crate::datatypes::BitString;
+use crate::object::{EventState, ObjectError, PropertyValue, Reliability, Result};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::ToString, vec, vec::Vec};
+
+/// Number of bits in the Status_Flags bit string
+pub const STATUS_FLAGS_BITS: usize = 4;
+
+/// Status_Flags property value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StatusFlags {
+    /// Event state is other than normal
+    pub in_alarm: bool,
+    /// Reliability reports a fault
+    pub fault: bool,
+    /// Value is overridden by a local mechanism
+    pub overridden: bool,
+    /// Object is out of service
+    pub out_of_service: bool,
+}
+
+impl StatusFlags {
+    /// Bit number of the in-alarm flag
+    pub const IN_ALARM: usize = 0;
+    /// Bit number of the fault flag
+    pub const FAULT: usize = 1;
+    /// Bit number of the overridden flag
+    pub const OVERRIDDEN: usize = 2;
+    /// Bit number of the out-of-service flag
+    pub const OUT_OF_SERVICE: usize = 3;
+
+    /// Create status flags from individual booleans
+    pub fn new(in_alarm: bool, fault: bool, overridden: bool, out_of_service: bool) -> Self {
+        Self {
+            in_alarm,
+            fault,
+            overridden,
+            out_of_service,
+        }
+    }
+
+    /// Derive the flags from the object state they summarize
+    pub fn derive(
+        event_state: EventState,
+        reliability: Reliability,
+        overridden: bool,
+        out_of_service: bool,
+    ) -> Self {
+        Self {
+            in_alarm: event_state != EventState::Normal,
+            fault: reliability.is_fault(),
+            overridden,
+            out_of_service,
+        }
+    }
+
+    /// Flags derived from the object state, keeping the overridden flag
+    pub fn refreshed(
+        self,
+        event_state: EventState,
+        reliability: Reliability,
+        out_of_service: bool,
+    ) -> Self {
+        Self::derive(event_state, reliability, self.overridden, out_of_service)
+    }
+
+    /// Check if no flag is set
+    pub fn is_normal(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Convert to a bit string, in-alarm first
+    pub fn to_bitstring(&self) -> Vec<bool> {
+        vec![
+            self.in_alarm,
+            self.fault,
+            self.overridden,
+            self.out_of_service,
+        ]
+    }
+
+    /// Parse from a bit string, in-alarm first
+    pub fn from_bitstring(bits: &[bool]) -> Result<Self> {
+        if bits.len() < STATUS_FLAGS_BITS {
+            return Err(ObjectError::InvalidValue(
+                "Status flags require 4 bits".to_string(),
+            ));
+        }
+        Ok(Self::new(
+            bits[Self::IN_ALARM],
+            bits[Self::FAULT],
+            bits[Self::OVERRIDDEN],
+            bits[Self::OUT_OF_SERVICE],
+        ))
+    }
+}
+
+impl From<StatusFlags> for BitString {
+    fn from(flags: StatusFlags) -> Self {
+        BitString::from(flags.to_bitstring())
+    }
+}
+
+impl TryFrom<&BitString> for StatusFlags {
+    type Error = ObjectError;
+
+    fn try_from(bits: &BitString) -> Result<Self> {
+        Self::from_bitstring(bits.bits())
+    }
+}
+
+impl From<StatusFlags> for PropertyValue {
+    fn from(flags: StatusFlags) -> Self {
+        PropertyValue::BitString(flags.to_bitstring())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_flags_bitstring() {
+        let flags = StatusFlags::new(true, false, true, false);
+        assert_eq!(flags.to_bitstring(), vec![true, false, true, false]);
+        assert_eq!(
+            StatusFlags::from_bitstring(&flags.to_bitstring()).unwrap(),
+            flags
+        );
+        assert!(StatusFlags::from_bitstring(&[true, false]).is_err());
+        assert!(StatusFlags::default().is_normal());
+    }
+
+    #[test]
+    fn test_status_flags_derive() {
+        let flags = StatusFlags::derive(EventState::HighLimit, Reliability::OverRange, false, true);
+        assert_eq!(flags, StatusFlags::new(true, true, false, true));
+        assert!(StatusFlags::derive(
+            EventState::Normal,
+            Reliability::NoFaultDetected,
+            false,
+            false
+        )
+        .is_normal());
+
+        let overridden = StatusFlags::new(false, false, true, false);
+        assert_eq!(
+            overridden.refreshed(EventState::Normal, Reliability::NoSensor, false),
+            StatusFlags::new(false, true, true, false)
+        );
+    }
+
+    #[test]
+    fn test_status_flags_named_bits() {
+        let flags = StatusFlags::new(false, true, false, true);
+        let bits = BitString::from(flags);
+        assert!(bits.get(StatusFlags::FAULT));
+        assert!(bits.get(StatusFlags::OUT_OF_SERVICE));
+        assert!(!bits.get(StatusFlags::IN_ALARM));
+        assert_eq!(StatusFlags::try_from(&bits).unwrap(), flags);
+    }
+}
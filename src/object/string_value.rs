@@ -0,0 +1,451 @@
+//! String Value Object Types Implementation
+//!
+//! This module implements the CharacterString Value and OctetString Value
+//! object types as defined in ASHRAE 135. They are writable software values
+//! for textual and binary configuration such as tenant names or serial
+//! blobs. A CharacterString Value set through
+//! [`CharacterStringValue::set_present_value`] keeps its character set, and
+//! [`CharacterStringValue::encode_present_value`] encodes it with the same
+//! tag. The property interface carries plain text, so values written through
+//! `set_property` are stored and read back as UTF-8.
+
+use crate::encoding::{self, CharacterString};
+use crate::object::{
+    analog::{EventState, Reliability},
+    callback::PropertyCallbacks,
+    status_flags::StatusFlags,
+    validate_description, write_rejected, BacnetObject, ObjectError, ObjectIdentifier, ObjectType,
+    PropertyIdentifier, PropertyValue, Result,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec, vec::Vec};
+
+/// CharacterString Value object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharacterStringValue {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Present value
+    pub present_value: CharacterString,
+    /// Description
+    pub description: String,
+    /// Status flags
+    pub status_flags: StatusFlags,
+    /// Event state
+    pub event_state: EventState,
+    /// Reliability
+    pub reliability: Reliability,
+    /// Out of service
+    pub out_of_service: bool,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+}
+
+/// OctetString Value object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OctetStringValue {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Present value
+    pub present_value: Vec<u8>,
+    /// Description
+    pub description: String,
+    /// Status flags
+    pub status_flags: StatusFlags,
+    /// Event state
+    pub event_state: EventState,
+    /// Reliability
+    pub reliability: Reliability,
+    /// Out of service
+    pub out_of_service: bool,
+    /// Property change callbacks
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub callbacks: PropertyCallbacks,
+}
+
+/// Text of a character string, replacing characters that cannot be decoded
+fn text_of(string: &CharacterString) -> String {
+    string
+        .to_text()
+        .unwrap_or_else(|| String::from_utf8_lossy(string.as_bytes()).into_owned())
+}
+
+impl CharacterStringValue {
+    /// Create a new CharacterString Value object with an empty present value
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::CharacterStringValue, instance),
+            object_name,
+            present_value: CharacterString::from(""),
+            description: String::new(),
+            status_flags: StatusFlags::default(),
+            event_state: EventState::Normal,
+            reliability: Reliability::NoFaultDetected,
+            out_of_service: false,
+            callbacks: PropertyCallbacks::new(),
+        }
+    }
+
+    /// Set the present value
+    ///
+    /// Present_Value callbacks fire when the characters or the character
+    /// set change.
+    pub fn set_present_value(&mut self, value: CharacterString) {
+        if value == self.present_value {
+            return;
+        }
+        let old = core::mem::replace(&mut self.present_value, value);
+        self.callbacks.notify(
+            self.identifier,
+            PropertyIdentifier::PresentValue,
+            PropertyValue::CharacterString(text_of(&old)),
+            PropertyValue::CharacterString(text_of(&self.present_value)),
+        );
+    }
+
+    /// Encode the present value with its character set
+    pub fn encode_present_value(&self, buffer: &mut Vec<u8>) -> encoding::Result<()> {
+        self.present_value.encode(buffer)
+    }
+
+    /// Set out of service, updating the status flags
+    pub fn set_out_of_service(&mut self, out_of_service: bool) {
+        self.out_of_service = out_of_service;
+        self.status_flags = StatusFlags::derive(
+            self.event_state,
+            self.reliability,
+            self.status_flags.overridden,
+            self.out_of_service,
+        );
+    }
+}
+
+impl OctetStringValue {
+    /// Create a new OctetString Value object with an empty present value
+    pub fn new(instance: u32, object_name: String) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::OctetStringValue, instance),
+            object_name,
+            present_value: Vec::new(),
+            description: String::new(),
+            status_flags: StatusFlags::default(),
+            event_state: EventState::Normal,
+            reliability: Reliability::NoFaultDetected,
+            out_of_service: false,
+            callbacks: PropertyCallbacks::new(),
+        }
+    }
+
+    /// Set the present value, notifying Present_Value callbacks on change
+    pub fn set_present_value(&mut self, value: Vec<u8>) {
+        if value == self.present_value {
+            return;
+        }
+        let old = core::mem::replace(&mut self.present_value, value);
+        self.callbacks.notify(
+            self.identifier,
+            PropertyIdentifier::PresentValue,
+            PropertyValue::OctetString(old),
+            PropertyValue::OctetString(self.present_value.clone()),
+        );
+    }
+
+    /// Set out of service, updating the status flags
+    pub fn set_out_of_service(&mut self, out_of_service: bool) {
+        self.out_of_service = out_of_service;
+        self.status_flags = StatusFlags::derive(
+            self.event_state,
+            self.reliability,
+            self.status_flags.overridden,
+            self.out_of_service,
+        );
+    }
+}
+
+impl BacnetObject for CharacterStringValue {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::CharacterStringValue.as_u16() as u32,
+            )),
+            PropertyIdentifier::PresentValue => {
+                Ok(PropertyValue::CharacterString(text_of(&self.present_value)))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match property {
+            PropertyIdentifier::ObjectName => {
+                if let PropertyValue::CharacterString(name) = value {
+                    self.object_name = name;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::PresentValue => {
+                if let PropertyValue::CharacterString(text) = value {
+                    // Any text is a valid present value, unlike an object name
+                    self.set_present_value(CharacterString::from_text(&text));
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.set_out_of_service(oos);
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::PresentValue
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::OutOfService
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::OutOfService,
+        ]
+    }
+}
+
+impl BacnetObject for OctetStringValue {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::OctetStringValue.as_u16() as u32,
+            )),
+            PropertyIdentifier::PresentValue => {
+                Ok(PropertyValue::OctetString(self.present_value.clone()))
+            }
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::StatusFlags => Ok(self.status_flags.into()),
+            PropertyIdentifier::EventState => {
+                Ok(PropertyValue::Enumerated(self.event_state as u32))
+            }
+            PropertyIdentifier::Reliability => {
+                Ok(PropertyValue::Enumerated(self.reliability as u32))
+            }
+            PropertyIdentifier::OutOfService => Ok(PropertyValue::Boolean(self.out_of_service)),
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match property {
+            PropertyIdentifier::ObjectName => {
+                if let PropertyValue::CharacterString(name) = value {
+                    self.object_name = name;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::PresentValue => {
+                if let PropertyValue::OctetString(octets) = value {
+                    self.set_present_value(octets);
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::OutOfService => {
+                if let PropertyValue::Boolean(oos) = value {
+                    self.set_out_of_service(oos);
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::PresentValue
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::OutOfService
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::PresentValue,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::StatusFlags,
+            PropertyIdentifier::EventState,
+            PropertyIdentifier::Reliability,
+            PropertyIdentifier::OutOfService,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::CharacterSet;
+
+    #[cfg(feature = "std")]
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_character_string_value_keeps_charset() {
+        let mut csv = CharacterStringValue::new(1, "Tenant".to_string());
+        csv.set_present_value(
+            CharacterString::new(CharacterSet::Iso8859_1, b"Caf\xE9 Nord".to_vec()).unwrap(),
+        );
+        assert!(matches!(
+            csv.get_property(PropertyIdentifier::PresentValue),
+            Ok(PropertyValue::CharacterString(text)) if text == "Café Nord"
+        ));
+
+        let mut buffer = Vec::new();
+        csv.encode_present_value(&mut buffer).unwrap();
+        assert_eq!(&buffer[..3], &[0x75, 0x0A, CharacterSet::Iso8859_1 as u8]);
+
+        csv.set_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::CharacterString("Acme Ltd".to_string()),
+        )
+        .unwrap();
+        assert_eq!(csv.present_value.charset(), CharacterSet::Utf8);
+
+        // Control characters and empty text are ordinary present values
+        for text in ["line1\nline2", ""] {
+            csv.set_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::CharacterString(text.to_string()),
+            )
+            .unwrap();
+            assert!(matches!(
+                csv.get_property(PropertyIdentifier::PresentValue),
+                Ok(PropertyValue::CharacterString(value)) if value == text
+            ));
+        }
+        assert!(csv
+            .set_property(PropertyIdentifier::PresentValue, PropertyValue::Real(1.0))
+            .is_err());
+
+        csv.set_property(
+            PropertyIdentifier::OutOfService,
+            PropertyValue::Boolean(true),
+        )
+        .unwrap();
+        assert!(csv.status_flags.out_of_service);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_octet_string_value_change_callbacks() {
+        let mut osv = OctetStringValue::new(1, "Serial".to_string());
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let sink = changes.clone();
+        osv.callbacks
+            .register(PropertyIdentifier::PresentValue, move |change| {
+                if let PropertyValue::OctetString(octets) = &change.new_value {
+                    sink.lock().unwrap().push(octets.clone());
+                }
+            });
+
+        let serial = vec![0xDE, 0xAD, 0xBE, 0xEF];
+        osv.set_property(
+            PropertyIdentifier::PresentValue,
+            PropertyValue::OctetString(serial.clone()),
+        )
+        .unwrap();
+        // Writing the same value again is not a change
+        osv.set_present_value(serial.clone());
+        assert_eq!(*changes.lock().unwrap(), vec![serial.clone()]);
+        assert!(matches!(
+            osv.get_property(PropertyIdentifier::PresentValue),
+            Ok(PropertyValue::OctetString(octets)) if octets == serial
+        ));
+    }
+}
@@ -0,0 +1,456 @@
+//! Protocol Services and Object Types Supported
+//!
+//! The Device object advertises the services it executes in
+//! Protocol_Services_Supported and the object types it can hold in
+//! Protocol_Object_Types_Supported. Both are bit strings: a service's bit is
+//! its position in BACnetServicesSupported, which differs from its service
+//! choice, and an object type's bit is its enumeration value.
+
+use crate::encoding::{EncodingError, Result as EncodingResult};
+use crate::object::{ObjectError, ObjectType, PropertyValue, Result};
+use crate::service::{ConfirmedServiceChoice, UnconfirmedServiceChoice};
+
+#[cfg(not(feature = "std"))]
+use alloc::{format, string::ToString, vec::Vec};
+
+/// Number of bits in Protocol_Services_Supported
+pub const SERVICES_SUPPORTED_BITS: usize = 49;
+
+/// Number of bits in Protocol_Object_Types_Supported
+pub const OBJECT_TYPES_SUPPORTED_BITS: usize = 65;
+
+/// Service, numbered by its BACnetServicesSupported bit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum Service {
+    AcknowledgeAlarm = 0,
+    ConfirmedCOVNotification = 1,
+    ConfirmedEventNotification = 2,
+    GetAlarmSummary = 3,
+    GetEnrollmentSummary = 4,
+    SubscribeCOV = 5,
+    AtomicReadFile = 6,
+    AtomicWriteFile = 7,
+    AddListElement = 8,
+    RemoveListElement = 9,
+    CreateObject = 10,
+    DeleteObject = 11,
+    ReadProperty = 12,
+    ReadPropertyConditional = 13,
+    ReadPropertyMultiple = 14,
+    WriteProperty = 15,
+    WritePropertyMultiple = 16,
+    DeviceCommunicationControl = 17,
+    ConfirmedPrivateTransfer = 18,
+    ConfirmedTextMessage = 19,
+    ReinitializeDevice = 20,
+    VtOpen = 21,
+    VtClose = 22,
+    VtData = 23,
+    Authenticate = 24,
+    RequestKey = 25,
+    IAm = 26,
+    IHave = 27,
+    UnconfirmedCOVNotification = 28,
+    UnconfirmedEventNotification = 29,
+    UnconfirmedPrivateTransfer = 30,
+    UnconfirmedTextMessage = 31,
+    TimeSynchronization = 32,
+    WhoHas = 33,
+    WhoIs = 34,
+    ReadRange = 35,
+    UtcTimeSynchronization = 36,
+    LifeSafetyOperation = 37,
+    SubscribeCOVProperty = 38,
+    GetEventInformation = 39,
+    WriteGroup = 40,
+    SubscribeCOVPropertyMultiple = 41,
+    ConfirmedCOVNotificationMultiple = 42,
+    UnconfirmedCOVNotificationMultiple = 43,
+    ConfirmedAuditNotification = 44,
+    AuditLogQuery = 45,
+    UnconfirmedAuditNotification = 46,
+    WhoAmI = 47,
+    YouAre = 48,
+}
+
+impl TryFrom<u8> for Service {
+    type Error = ObjectError;
+
+    fn try_from(bit: u8) -> Result<Self> {
+        let service = match bit {
+            0 => Service::AcknowledgeAlarm,
+            1 => Service::ConfirmedCOVNotification,
+            2 => Service::ConfirmedEventNotification,
+            3 => Service::GetAlarmSummary,
+            4 => Service::GetEnrollmentSummary,
+            5 => Service::SubscribeCOV,
+            6 => Service::AtomicReadFile,
+            7 => Service::AtomicWriteFile,
+            8 => Service::AddListElement,
+            9 => Service::RemoveListElement,
+            10 => Service::CreateObject,
+            11 => Service::DeleteObject,
+            12 => Service::ReadProperty,
+            13 => Service::ReadPropertyConditional,
+            14 => Service::ReadPropertyMultiple,
+            15 => Service::WriteProperty,
+            16 => Service::WritePropertyMultiple,
+            17 => Service::DeviceCommunicationControl,
+            18 => Service::ConfirmedPrivateTransfer,
+            19 => Service::ConfirmedTextMessage,
+            20 => Service::ReinitializeDevice,
+            21 => Service::VtOpen,
+            22 => Service::VtClose,
+            23 => Service::VtData,
+            24 => Service::Authenticate,
+            25 => Service::RequestKey,
+            26 => Service::IAm,
+            27 => Service::IHave,
+            28 => Service::UnconfirmedCOVNotification,
+            29 => Service::UnconfirmedEventNotification,
+            30 => Service::UnconfirmedPrivateTransfer,
+            31 => Service::UnconfirmedTextMessage,
+            32 => Service::TimeSynchronization,
+            33 => Service::WhoHas,
+            34 => Service::WhoIs,
+            35 => Service::ReadRange,
+            36 => Service::UtcTimeSynchronization,
+            37 => Service::LifeSafetyOperation,
+            38 => Service::SubscribeCOVProperty,
+            39 => Service::GetEventInformation,
+            40 => Service::WriteGroup,
+            41 => Service::SubscribeCOVPropertyMultiple,
+            42 => Service::ConfirmedCOVNotificationMultiple,
+            43 => Service::UnconfirmedCOVNotificationMultiple,
+            44 => Service::ConfirmedAuditNotification,
+            45 => Service::AuditLogQuery,
+            46 => Service::UnconfirmedAuditNotification,
+            47 => Service::WhoAmI,
+            48 => Service::YouAre,
+            _ => {
+                return Err(ObjectError::InvalidValue(format!(
+                    "Invalid services supported bit: {}",
+                    bit
+                )))
+            }
+        };
+        Ok(service)
+    }
+}
+
+impl TryFrom<ConfirmedServiceChoice> for Service {
+    type Error = ObjectError;
+
+    fn try_from(choice: ConfirmedServiceChoice) -> Result<Self> {
+        let service = match choice {
+            ConfirmedServiceChoice::AcknowledgeAlarm => Service::AcknowledgeAlarm,
+            ConfirmedServiceChoice::ConfirmedCOVNotification => Service::ConfirmedCOVNotification,
+            ConfirmedServiceChoice::ConfirmedEventNotification => {
+                Service::ConfirmedEventNotification
+            }
+            ConfirmedServiceChoice::GetAlarmSummary => Service::GetAlarmSummary,
+            ConfirmedServiceChoice::GetEnrollmentSummary => Service::GetEnrollmentSummary,
+            ConfirmedServiceChoice::GetEventInformation => Service::GetEventInformation,
+            ConfirmedServiceChoice::AtomicReadFile => Service::AtomicReadFile,
+            ConfirmedServiceChoice::AtomicWriteFile => Service::AtomicWriteFile,
+            ConfirmedServiceChoice::AddListElement => Service::AddListElement,
+            ConfirmedServiceChoice::RemoveListElement => Service::RemoveListElement,
+            ConfirmedServiceChoice::CreateObject => Service::CreateObject,
+            ConfirmedServiceChoice::DeleteObject => Service::DeleteObject,
+            ConfirmedServiceChoice::ReadProperty => Service::ReadProperty,
+            ConfirmedServiceChoice::ReadPropertyMultiple => Service::ReadPropertyMultiple,
+            ConfirmedServiceChoice::WriteProperty => Service::WriteProperty,
+            ConfirmedServiceChoice::WritePropertyMultiple => Service::WritePropertyMultiple,
+            ConfirmedServiceChoice::DeviceCommunicationControl => {
+                Service::DeviceCommunicationControl
+            }
+            ConfirmedServiceChoice::ConfirmedPrivateTransfer => Service::ConfirmedPrivateTransfer,
+            ConfirmedServiceChoice::ReinitializeDevice => Service::ReinitializeDevice,
+            ConfirmedServiceChoice::VtOpen => Service::VtOpen,
+            ConfirmedServiceChoice::VtClose => Service::VtClose,
+            ConfirmedServiceChoice::VtData => Service::VtData,
+            ConfirmedServiceChoice::Authenticate => Service::Authenticate,
+            ConfirmedServiceChoice::RequestKey => Service::RequestKey,
+            ConfirmedServiceChoice::ReadRange => Service::ReadRange,
+            ConfirmedServiceChoice::SubscribeCOV => Service::SubscribeCOV,
+            ConfirmedServiceChoice::SubscribeCOVProperty => Service::SubscribeCOVProperty,
+            ConfirmedServiceChoice::AuthRequest => {
+                return Err(ObjectError::InvalidValue(
+                    "Auth-Request has no services supported bit".to_string(),
+                ))
+            }
+        };
+        Ok(service)
+    }
+}
+
+impl From<UnconfirmedServiceChoice> for Service {
+    fn from(choice: UnconfirmedServiceChoice) -> Self {
+        match choice {
+            UnconfirmedServiceChoice::IAm => Service::IAm,
+            UnconfirmedServiceChoice::IHave => Service::IHave,
+            UnconfirmedServiceChoice::UnconfirmedCOVNotification => {
+                Service::UnconfirmedCOVNotification
+            }
+            UnconfirmedServiceChoice::UnconfirmedEventNotification => {
+                Service::UnconfirmedEventNotification
+            }
+            UnconfirmedServiceChoice::UnconfirmedPrivateTransfer => {
+                Service::UnconfirmedPrivateTransfer
+            }
+            UnconfirmedServiceChoice::UnconfirmedTextMessage => Service::UnconfirmedTextMessage,
+            UnconfirmedServiceChoice::TimeSynchronization => Service::TimeSynchronization,
+            UnconfirmedServiceChoice::WhoHas => Service::WhoHas,
+            UnconfirmedServiceChoice::WhoIs => Service::WhoIs,
+            UnconfirmedServiceChoice::UtcTimeSynchronization => Service::UtcTimeSynchronization,
+            UnconfirmedServiceChoice::WriteGroup => Service::WriteGroup,
+            UnconfirmedServiceChoice::UnconfirmedCOVNotificationMultiple => {
+                Service::UnconfirmedCOVNotificationMultiple
+            }
+            UnconfirmedServiceChoice::UnconfirmedAuditNotification => {
+                Service::UnconfirmedAuditNotification
+            }
+            UnconfirmedServiceChoice::WhoAmI => Service::WhoAmI,
+            UnconfirmedServiceChoice::YouAre => Service::YouAre,
+        }
+    }
+}
+
+/// Protocol_Services_Supported bit string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServicesSupported {
+    bits: u64,
+}
+
+impl ServicesSupported {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark a service as supported or not
+    pub fn set(&mut self, service: Service, supported: bool) {
+        if supported {
+            self.bits |= 1 << service as u8;
+        } else {
+            self.bits &= !(1 << service as u8);
+        }
+    }
+
+    /// Add a supported service, builder style
+    pub fn with(mut self, service: Service) -> Self {
+        self.set(service, true);
+        self
+    }
+
+    /// Check if a service is supported
+    pub fn supports(&self, service: Service) -> bool {
+        self.bits & (1 << service as u8) != 0
+    }
+
+    /// Supported services, in bit order
+    pub fn services(&self) -> impl Iterator<Item = Service> + '_ {
+        (0..SERVICES_SUPPORTED_BITS as u8)
+            .filter_map(|bit| Service::try_from(bit).ok())
+            .filter(|service| self.supports(*service))
+    }
+
+    /// Convert to a bit string of [`SERVICES_SUPPORTED_BITS`] bits
+    pub fn to_bitstring(&self) -> Vec<bool> {
+        (0..SERVICES_SUPPORTED_BITS)
+            .map(|bit| self.bits & (1 << bit) != 0)
+            .collect()
+    }
+
+    /// Parse from a bit string
+    ///
+    /// Older devices send fewer bits; bits past the known services are
+    /// ignored.
+    pub fn from_bitstring(bits: &[bool]) -> Self {
+        let bits = bits
+            .iter()
+            .take(SERVICES_SUPPORTED_BITS)
+            .enumerate()
+            .filter(|(_, set)| **set)
+            .fold(0u64, |acc, (bit, _)| acc | 1 << bit);
+        Self { bits }
+    }
+
+    /// Encode as an application tagged bit string
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        PropertyValue::from(*self).encode(buffer)
+    }
+
+    /// Decode an application tagged bit string
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        match PropertyValue::decode(data)? {
+            (PropertyValue::BitString(bits), consumed) => {
+                Ok((Self::from_bitstring(&bits), consumed))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
+impl From<ServicesSupported> for PropertyValue {
+    fn from(services: ServicesSupported) -> Self {
+        PropertyValue::BitString(services.to_bitstring())
+    }
+}
+
+/// Protocol_Object_Types_Supported bit string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectTypesSupported {
+    bits: u128,
+}
+
+impl ObjectTypesSupported {
+    /// Create an empty set
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark an object type as supported or not
+    ///
+    /// Proprietary object types have no bit and are ignored.
+    pub fn set(&mut self, object_type: ObjectType, supported: bool) {
+        if object_type.is_proprietary() {
+            return;
+        }
+        if supported {
+            self.bits |= 1 << object_type.as_u16();
+        } else {
+            self.bits &= !(1 << object_type.as_u16());
+        }
+    }
+
+    /// Add a supported object type, builder style
+    pub fn with(mut self, object_type: ObjectType) -> Self {
+        self.set(object_type, true);
+        self
+    }
+
+    /// Check if an object type is supported
+    pub fn supports(&self, object_type: ObjectType) -> bool {
+        !object_type.is_proprietary() && self.bits & (1 << object_type.as_u16()) != 0
+    }
+
+    /// Supported object types, in enumeration order
+    pub fn object_types(&self) -> impl Iterator<Item = ObjectType> + '_ {
+        (0..OBJECT_TYPES_SUPPORTED_BITS as u16)
+            .filter_map(|bit| ObjectType::try_from(bit).ok())
+            .filter(|object_type| self.supports(*object_type))
+    }
+
+    /// Convert to a bit string of [`OBJECT_TYPES_SUPPORTED_BITS`] bits
+    pub fn to_bitstring(&self) -> Vec<bool> {
+        (0..OBJECT_TYPES_SUPPORTED_BITS)
+            .map(|bit| self.bits & (1 << bit) != 0)
+            .collect()
+    }
+
+    /// Parse from a bit string
+    ///
+    /// Bits past the known object types are ignored.
+    pub fn from_bitstring(bits: &[bool]) -> Self {
+        let bits = bits
+            .iter()
+            .take(OBJECT_TYPES_SUPPORTED_BITS)
+            .enumerate()
+            .filter(|(_, set)| **set)
+            .fold(0u128, |acc, (bit, _)| acc | 1 << bit);
+        Self { bits }
+    }
+
+    /// Encode as an application tagged bit string
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        PropertyValue::from(*self).encode(buffer)
+    }
+
+    /// Decode an application tagged bit string
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        match PropertyValue::decode(data)? {
+            (PropertyValue::BitString(bits), consumed) => {
+                Ok((Self::from_bitstring(&bits), consumed))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
+impl From<ObjectTypesSupported> for PropertyValue {
+    fn from(object_types: ObjectTypesSupported) -> Self {
+        PropertyValue::BitString(object_types.to_bitstring())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_services_supported() {
+        let mut services = ServicesSupported::new()
+            .with(Service::ReadProperty)
+            .with(Service::WhoIs);
+        services.set(Service::ReadPropertyMultiple, true);
+        assert!(services.supports(Service::ReadPropertyMultiple));
+        assert!(!services.supports(Service::WriteProperty));
+
+        let mut buffer = Vec::new();
+        services.encode(&mut buffer).unwrap();
+        let (decoded, consumed) = ServicesSupported::decode(&buffer).unwrap();
+        assert_eq!(consumed, buffer.len());
+        assert_eq!(decoded, services);
+        assert_eq!(
+            decoded.services().collect::<Vec<_>>(),
+            vec![
+                Service::ReadProperty,
+                Service::ReadPropertyMultiple,
+                Service::WhoIs
+            ]
+        );
+
+        services.set(Service::WhoIs, false);
+        assert!(!services.supports(Service::WhoIs));
+    }
+
+    #[test]
+    fn test_service_choice_bits() {
+        // Service choices and services supported bits diverge after Request-Key
+        assert_eq!(
+            Service::try_from(ConfirmedServiceChoice::ReadRange).unwrap(),
+            Service::ReadRange
+        );
+        assert_eq!(Service::ReadRange as u8, 35);
+        assert_eq!(Service::from(UnconfirmedServiceChoice::WhoIs) as u8, 34);
+        assert!(Service::try_from(ConfirmedServiceChoice::AuthRequest).is_err());
+    }
+
+    #[test]
+    fn test_object_types_supported() {
+        let object_types = ObjectTypesSupported::new()
+            .with(ObjectType::Device)
+            .with(ObjectType::ColorTemperature);
+        let bits = object_types.to_bitstring();
+        assert_eq!(bits.len(), OBJECT_TYPES_SUPPORTED_BITS);
+        assert!(bits[8] && bits[64]);
+
+        let mut buffer = Vec::new();
+        object_types.encode(&mut buffer).unwrap();
+        let (decoded, _) = ObjectTypesSupported::decode(&buffer).unwrap();
+        assert!(decoded.supports(ObjectType::ColorTemperature));
+        assert!(!decoded.supports(ObjectType::AnalogInput));
+
+        // A short bit string from an older device still parses
+        let short = ObjectTypesSupported::from_bitstring(&[true, false, true]);
+        assert_eq!(
+            short.object_types().collect::<Vec<_>>(),
+            vec![ObjectType::AnalogInput, ObjectType::AnalogValue]
+        );
+    }
+}
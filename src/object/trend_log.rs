@@ -0,0 +1,920 @@
+//! Trend Log Object Implementation
+//!
+//! This module implements the Trend Log object type as defined in ASHRAE 135.
+//! A Trend Log samples a referenced property into a fixed capacity buffer of
+//! timestamped records. When the buffer is full, the oldest record is evicted,
+//! or logging stops if Stop_When_Full is set. Every record is numbered with
+//! the Total_Record_Count at the time it was added, so the log can serve the
+//! ReadRange service by position, by sequence number, or by time.
+
+use crate::datatypes::Clock;
+use crate::encoding::{
+    advanced::context::{encode_closing_tag, encode_opening_tag},
+    decode_tag, encode_context_enumerated, encode_context_object_id, ApplicationTag, EncodingError,
+    Result as EncodingResult,
+};
+use crate::object::{
+    constructed_value, status_flags::StatusFlags, validate_description, write_rejected,
+    BacnetObject, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier, PropertyValue,
+    Result,
+};
+use crate::service::{
+    decode_context_value, encode_context_value, BacnetDateTime, ReadRange, ReadRangeAck,
+    ReadRangeRequest, ResultFlags,
+};
+
+#[cfg(not(feature = "std"))]
+use alloc::{
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+/// Reference to a property of an object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectPropertyReference {
+    /// Referenced object
+    pub object_identifier: ObjectIdentifier,
+    /// Referenced property
+    pub property_identifier: PropertyIdentifier,
+}
+
+impl ObjectPropertyReference {
+    /// Create a new object property reference
+    pub fn new(
+        object_identifier: ObjectIdentifier,
+        property_identifier: PropertyIdentifier,
+    ) -> Self {
+        Self {
+            object_identifier,
+            property_identifier,
+        }
+    }
+
+    /// Encode as a BACnetObjectPropertyReference
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.object_identifier.object_type.as_u16(),
+            self.object_identifier.instance,
+            0,
+        )?);
+        buffer.extend_from_slice(&encode_context_enumerated(
+            u32::from(self.property_identifier),
+            1,
+        )?);
+        Ok(())
+    }
+}
+
+/// Trend log record
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogRecord {
+    /// Time the sample was taken
+    pub timestamp: BacnetDateTime,
+    /// Sampled value
+    pub value: PropertyValue,
+    /// Status flags of the monitored object, if it has them
+    pub status_flags: Option<StatusFlags>,
+}
+
+impl LogRecord {
+    /// Encode as a BACnetLogRecord
+    ///
+    /// Primitive values use their dedicated log-datum choice; any other
+    /// value is sent as any-value.
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Timestamp - context tag 0
+        encode_opening_tag(buffer, 0)?;
+        self.timestamp.encode(buffer)?;
+        encode_closing_tag(buffer, 0)?;
+
+        // Log datum - context tag 1
+        encode_opening_tag(buffer, 1)?;
+        match &self.value {
+            PropertyValue::Boolean(_) => encode_context_value(buffer, 1, &self.value)?,
+            PropertyValue::Real(_) => encode_context_value(buffer, 2, &self.value)?,
+            PropertyValue::Enumerated(_) => encode_context_value(buffer, 3, &self.value)?,
+            PropertyValue::UnsignedInteger(_) => encode_context_value(buffer, 4, &self.value)?,
+            PropertyValue::SignedInt(_) => encode_context_value(buffer, 5, &self.value)?,
+            PropertyValue::BitString(_) => encode_context_value(buffer, 6, &self.value)?,
+            PropertyValue::Null => encode_context_value(buffer, 7, &self.value)?,
+            value => {
+                encode_opening_tag(buffer, 10)?;
+                value.encode(buffer)?;
+                encode_closing_tag(buffer, 10)?;
+            }
+        }
+        encode_closing_tag(buffer, 1)?;
+
+        // Status flags - context tag 2 (optional)
+        if let Some(flags) = self.status_flags {
+            encode_context_value(buffer, 2, &flags.into())?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode a BACnetLogRecord, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        // Timestamp - context tag 0
+        let (tag, header) = decode_tag(data)?;
+        if !tag.is_opening || tag.number != 0 {
+            return Err(EncodingError::InvalidTag);
+        }
+        let (timestamp, consumed) = BacnetDateTime::decode(&data[header..])?;
+        let mut pos = header + consumed;
+        pos += expect_closing_tag(&data[pos..], 0)?;
+
+        // Log datum - context tag 1
+        let (tag, header) = decode_tag(&data[pos..])?;
+        if !tag.is_opening || tag.number != 1 {
+            return Err(EncodingError::InvalidTag);
+        }
+        pos += header;
+        let (choice, header) = decode_tag(&data[pos..])?;
+        let datatype = match choice.number {
+            1 => ApplicationTag::Boolean,
+            2 => ApplicationTag::Real,
+            3 => ApplicationTag::Enumerated,
+            4 => ApplicationTag::UnsignedInt,
+            5 => ApplicationTag::SignedInt,
+            6 => ApplicationTag::BitString,
+            7 => ApplicationTag::Null,
+            10 if choice.is_opening => ApplicationTag::Null,
+            _ => {
+                return Err(EncodingError::InvalidFormat(format!(
+                    "Unsupported log datum choice {}",
+                    choice.number
+                )))
+            }
+        };
+        let value = if choice.number == 10 {
+            pos += header;
+            let (value, consumed) = PropertyValue::decode(&data[pos..])?;
+            pos += consumed;
+            pos += expect_closing_tag(&data[pos..], 10)?;
+            value
+        } else {
+            let (value, consumed) = decode_context_value(&data[pos..], choice.number, datatype)?;
+            pos += consumed;
+            value
+        };
+        pos += expect_closing_tag(&data[pos..], 1)?;
+
+        // Status flags - context tag 2 (optional)
+        let mut status_flags = None;
+        if let Ok((tag, _)) = decode_tag(&data[pos..]) {
+            if tag.number == 2 && !tag.is_opening && !tag.is_closing {
+                let (flags, consumed) =
+                    decode_context_value(&data[pos..], 2, ApplicationTag::BitString)?;
+                if let PropertyValue::BitString(bits) = flags {
+                    status_flags = Some(
+                        StatusFlags::from_bitstring(&bits)
+                            .map_err(|_| EncodingError::InvalidLength)?,
+                    );
+                }
+                pos += consumed;
+            }
+        }
+
+        Ok((
+            Self {
+                timestamp,
+                value,
+                status_flags,
+            },
+            pos,
+        ))
+    }
+
+    /// Decode the item data of a ReadRange acknowledgement of a log buffer
+    pub fn decode_list(data: &[u8]) -> EncodingResult<Vec<Self>> {
+        let mut records = Vec::new();
+        let mut pos = 0;
+        while pos < data.len() {
+            let (record, consumed) = Self::decode(&data[pos..])?;
+            records.push(record);
+            pos += consumed;
+        }
+        Ok(records)
+    }
+}
+
+/// Consume the closing tag `tag_number`, returning its length
+fn expect_closing_tag(data: &[u8], tag_number: u8) -> EncodingResult<usize> {
+    let (tag, header) = decode_tag(data)?;
+    if !tag.is_closing || tag.number != tag_number {
+        return Err(EncodingError::InvalidTag);
+    }
+    Ok(header)
+}
+
+/// Trend Log object
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrendLog {
+    /// Object identifier
+    pub identifier: ObjectIdentifier,
+    /// Object name
+    pub object_name: String,
+    /// Description
+    pub description: String,
+    /// Property being logged
+    pub log_device_object_property: Option<ObjectPropertyReference>,
+    /// Logging enabled
+    pub enable: bool,
+    /// Polling interval in hundredths of a second (0 disables polling)
+    pub log_interval: u32,
+    /// Stop logging instead of evicting the oldest record when full
+    pub stop_when_full: bool,
+    /// Records ever added, wrapping from 2^32 - 1 back to 1
+    pub total_record_count: u32,
+    buffer_size: u32,
+    log_buffer: VecDeque<(u32, LogRecord)>,
+    last_sample: Option<BacnetDateTime>,
+}
+
+impl TrendLog {
+    /// Create a new, enabled Trend Log holding up to `buffer_size` records
+    pub fn new(instance: u32, object_name: String, buffer_size: u32) -> Self {
+        Self {
+            identifier: ObjectIdentifier::new(ObjectType::TrendLog, instance),
+            object_name,
+            description: String::new(),
+            log_device_object_property: None,
+            enable: true,
+            log_interval: 0,
+            stop_when_full: false,
+            total_record_count: 0,
+            buffer_size,
+            log_buffer: VecDeque::with_capacity(buffer_size as usize),
+            last_sample: None,
+        }
+    }
+
+    /// Maximum number of records held
+    pub fn buffer_size(&self) -> u32 {
+        self.buffer_size
+    }
+
+    /// Number of records currently held
+    pub fn record_count(&self) -> u32 {
+        self.log_buffer.len() as u32
+    }
+
+    /// Records currently held, oldest first
+    pub fn records(&self) -> impl Iterator<Item = &LogRecord> {
+        self.log_buffer.iter().map(|(_, record)| record)
+    }
+
+    /// Encode every record held, oldest first, as the Log_Buffer list
+    pub fn encode_log_buffer(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        for record in self.records() {
+            record.encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Remove all records
+    pub fn clear(&mut self) {
+        self.log_buffer.clear();
+    }
+
+    /// Add a record to the log
+    ///
+    /// Returns false if logging is disabled, or the buffer is full and
+    /// Stop_When_Full is set; filling the buffer then also disables logging.
+    pub fn add_record(&mut self, record: LogRecord) -> bool {
+        if !self.enable || self.buffer_size == 0 {
+            return false;
+        }
+        if self.log_buffer.len() >= self.buffer_size as usize {
+            if self.stop_when_full {
+                self.enable = false;
+                return false;
+            }
+            self.log_buffer.pop_front();
+        }
+
+        self.total_record_count = match self.total_record_count {
+            u32::MAX => 1,
+            count => count + 1,
+        };
+        self.log_buffer.push_back((self.total_record_count, record));
+        if self.stop_when_full && self.log_buffer.len() >= self.buffer_size as usize {
+            self.enable = false;
+        }
+        true
+    }
+
+    /// Sample the monitored property at the time of a clock
+    ///
+    /// See [`TrendLog::poll`].
+    pub fn poll_clock(&mut self, clock: &dyn Clock, source: &dyn BacnetObject) -> Result<bool> {
+        self.poll(clock.now(), source)
+    }
+
+    /// Sample the monitored property if the log interval has elapsed
+    ///
+    /// `source` must be the object named by `log_device_object_property`.
+    /// Returns whether a record was added.
+    pub fn poll(&mut self, now: BacnetDateTime, source: &dyn BacnetObject) -> Result<bool> {
+        let reference = match self.log_device_object_property {
+            Some(reference) if self.enable && self.log_interval > 0 => reference,
+            _ => return Ok(false),
+        };
+        if source.identifier() != reference.object_identifier {
+            return Err(ObjectError::InvalidConfiguration(
+                "Source is not the logged object".to_string(),
+            ));
+        }
+        if let Some(last) = self.last_sample {
+            let elapsed = hundredths_between(&last, &now);
+            if elapsed.is_some_and(|elapsed| elapsed < self.log_interval as i64) {
+                return Ok(false);
+            }
+        }
+
+        let value = source.get_property(reference.property_identifier)?;
+        let status_flags = match source.get_property(PropertyIdentifier::StatusFlags) {
+            Ok(PropertyValue::BitString(bits)) => StatusFlags::from_bitstring(&bits).ok(),
+            _ => None,
+        };
+        self.last_sample = Some(now);
+        Ok(self.add_record(LogRecord {
+            timestamp: now,
+            value,
+            status_flags,
+        }))
+    }
+
+    /// Read records by position, as ReadRange does
+    ///
+    /// `reference_index` is 1 for the oldest record held. A positive count
+    /// reads forward from the reference record, a negative count reads
+    /// backward ending at it. Records are returned oldest first.
+    pub fn read_by_position(&self, reference_index: u32, count: i32) -> Vec<LogRecord> {
+        self.collect(self.position_range(reference_index, count))
+    }
+
+    /// Read records by sequence number, as ReadRange does
+    ///
+    /// The count is applied relative to the record numbered
+    /// `reference_sequence_number`, as for [`Self::read_by_position`].
+    pub fn read_by_sequence_number(
+        &self,
+        reference_sequence_number: u32,
+        count: i32,
+    ) -> Vec<LogRecord> {
+        self.collect(self.sequence_range(reference_sequence_number, count))
+    }
+
+    /// Read records by time, as ReadRange does
+    ///
+    /// A positive count reads records newer than `reference_time`, oldest
+    /// first; a negative count reads records older than it, ending with the
+    /// newest of those. Records are returned oldest first.
+    pub fn read_by_time(&self, reference_time: &BacnetDateTime, count: i32) -> Vec<LogRecord> {
+        self.collect(self.time_range(reference_time, count))
+    }
+
+    /// Serve a ReadRange request for the log buffer
+    pub fn read_range(&self, request: &ReadRangeRequest) -> Result<ReadRangeAck> {
+        if request.object_identifier != self.identifier {
+            return Err(ObjectError::InstanceNotFound);
+        }
+        if request.property_identifier != u32::from(PropertyIdentifier::LogBuffer) {
+            return Err(ObjectError::UnknownProperty);
+        }
+        if request.property_array_index.is_some() {
+            return Err(ObjectError::InvalidValue(
+                "Log buffer is not an array".to_string(),
+            ));
+        }
+
+        let len = self.log_buffer.len();
+        let (start, end, more_items) = match request.range {
+            None => (0, len, false),
+            Some(ReadRange::ByPosition {
+                reference_index,
+                count,
+            }) => self.position_range(reference_index, count),
+            Some(ReadRange::BySequenceNumber {
+                reference_sequence_number,
+                count,
+            }) => self.sequence_range(reference_sequence_number, count),
+            Some(ReadRange::ByTime {
+                reference_time,
+                count,
+            }) => self.time_range(&reference_time, count),
+        };
+
+        let mut item_data = Vec::new();
+        for (_, record) in self.log_buffer.range(start..end) {
+            record
+                .encode(&mut item_data)
+                .map_err(|e| ObjectError::InvalidValue(format!("{:?}", e)))?;
+        }
+
+        let first_sequence_number = match request.range {
+            Some(ReadRange::BySequenceNumber { .. }) | Some(ReadRange::ByTime { .. })
+                if start < end =>
+            {
+                Some(self.log_buffer[start].0)
+            }
+            _ => None,
+        };
+
+        Ok(ReadRangeAck {
+            object_identifier: self.identifier,
+            property_identifier: request.property_identifier,
+            property_array_index: None,
+            result_flags: ResultFlags {
+                first_item: start < end && start == 0,
+                last_item: start < end && end == len,
+                more_items,
+            },
+            item_count: (end - start) as u32,
+            item_data,
+            first_sequence_number,
+        })
+    }
+
+    fn collect(&self, (start, end, _): (usize, usize, bool)) -> Vec<LogRecord> {
+        self.log_buffer
+            .range(start..end)
+            .map(|(_, record)| record.clone())
+            .collect()
+    }
+
+    /// Buffer index range for `count` records relative to the record at
+    /// `index`, with whether matching records were left out
+    fn relative_range(&self, index: Option<usize>, count: i32) -> (usize, usize, bool) {
+        let len = self.log_buffer.len();
+        match index {
+            Some(index) if index < len && count > 0 => {
+                let end = (index + count as usize).min(len);
+                (index, end, end < len)
+            }
+            Some(index) if index < len && count < 0 => {
+                let start = (index + 1).saturating_sub(count.unsigned_abs() as usize);
+                (start, index + 1, start > 0)
+            }
+            _ => (0, 0, false),
+        }
+    }
+
+    fn position_range(&self, reference_index: u32, count: i32) -> (usize, usize, bool) {
+        let index = (reference_index as usize).checked_sub(1);
+        self.relative_range(index, count)
+    }
+
+    fn sequence_range(&self, reference_sequence_number: u32, count: i32) -> (usize, usize, bool) {
+        let index = self
+            .log_buffer
+            .iter()
+            .position(|(sequence_number, _)| *sequence_number == reference_sequence_number);
+        self.relative_range(index, count)
+    }
+
+    /// Records are held oldest first, so the records newer or older than the
+    /// reference time form a contiguous run at the end or start of the buffer
+    fn time_range(&self, reference_time: &BacnetDateTime, count: i32) -> (usize, usize, bool) {
+        let reference = timestamp_key(reference_time);
+        if count > 0 {
+            let first_newer = self
+                .log_buffer
+                .partition_point(|(_, record)| timestamp_key(&record.timestamp) <= reference);
+            self.relative_range(Some(first_newer), count)
+        } else if count < 0 {
+            let older = self
+                .log_buffer
+                .partition_point(|(_, record)| timestamp_key(&record.timestamp) < reference);
+            self.relative_range(older.checked_sub(1), count)
+        } else {
+            (0, 0, false)
+        }
+    }
+}
+
+/// Sort key for a timestamp
+fn timestamp_key(timestamp: &BacnetDateTime) -> (u16, u8, u8, u8, u8, u8, u8) {
+    let (date, time) = (timestamp.date, timestamp.time);
+    (
+        date.year,
+        date.month,
+        date.day,
+        time.hour,
+        time.minute,
+        time.second,
+        time.hundredths,
+    )
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Hundredths of a second from `start` to `end`, if both are fully specified
+fn hundredths_between(start: &BacnetDateTime, end: &BacnetDateTime) -> Option<i64> {
+    fn hundredths(timestamp: &BacnetDateTime) -> Option<i64> {
+        let (date, time) = (timestamp.date, timestamp.time);
+        if date.year == 255 || date.month > 12 || date.day > 31 || time.hour > 23 {
+            return None;
+        }
+        if time.minute > 59 || time.second > 59 || time.hundredths > 99 {
+            return None;
+        }
+        let days = days_from_civil(date.year as i64, date.month as i64, date.day as i64);
+        let seconds =
+            days * 86_400 + time.hour as i64 * 3_600 + time.minute as i64 * 60 + time.second as i64;
+        Some(seconds * 100 + time.hundredths as i64)
+    }
+    Some(hundredths(end)? - hundredths(start)?)
+}
+
+impl BacnetObject for TrendLog {
+    fn identifier(&self) -> ObjectIdentifier {
+        self.identifier
+    }
+
+    fn get_property(&self, property: PropertyIdentifier) -> Result<PropertyValue> {
+        match property {
+            PropertyIdentifier::ObjectIdentifier => {
+                Ok(PropertyValue::ObjectIdentifier(self.identifier))
+            }
+            PropertyIdentifier::ObjectName => {
+                Ok(PropertyValue::CharacterString(self.object_name.clone()))
+            }
+            PropertyIdentifier::ObjectType => Ok(PropertyValue::Enumerated(
+                ObjectType::TrendLog.as_u16() as u32,
+            )),
+            PropertyIdentifier::Description => {
+                Ok(PropertyValue::CharacterString(self.description.clone()))
+            }
+            PropertyIdentifier::Enable => Ok(PropertyValue::Boolean(self.enable)),
+            // Null until a property to log has been configured
+            PropertyIdentifier::LogDeviceObjectProperty => match self.log_device_object_property {
+                Some(reference) => constructed_value(|buffer| reference.encode(buffer)),
+                None => Ok(PropertyValue::Null),
+            },
+            PropertyIdentifier::LogInterval => {
+                Ok(PropertyValue::UnsignedInteger(self.log_interval))
+            }
+            PropertyIdentifier::StopWhenFull => Ok(PropertyValue::Boolean(self.stop_when_full)),
+            PropertyIdentifier::BufferSize => Ok(PropertyValue::UnsignedInteger(self.buffer_size)),
+            PropertyIdentifier::LogBuffer => {
+                constructed_value(|buffer| self.encode_log_buffer(buffer))
+            }
+            PropertyIdentifier::RecordCount => {
+                Ok(PropertyValue::UnsignedInteger(self.record_count()))
+            }
+            PropertyIdentifier::TotalRecordCount => {
+                Ok(PropertyValue::UnsignedInteger(self.total_record_count))
+            }
+            _ => Err(ObjectError::UnknownProperty),
+        }
+    }
+
+    fn set_property(&mut self, property: PropertyIdentifier, value: PropertyValue) -> Result<()> {
+        match property {
+            PropertyIdentifier::ObjectName => {
+                if let PropertyValue::CharacterString(name) = value {
+                    self.object_name = name;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Description => {
+                if let PropertyValue::CharacterString(description) = value {
+                    validate_description(&description)?;
+                    self.description = description;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::Enable => {
+                if let PropertyValue::Boolean(enable) = value {
+                    self.enable = enable;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::LogInterval => {
+                if let PropertyValue::UnsignedInteger(interval) = value {
+                    self.log_interval = interval;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            PropertyIdentifier::StopWhenFull => {
+                if let PropertyValue::Boolean(stop) = value {
+                    self.stop_when_full = stop;
+                    Ok(())
+                } else {
+                    Err(ObjectError::InvalidPropertyType)
+                }
+            }
+            // Writing zero to Record_Count clears the buffer
+            PropertyIdentifier::RecordCount => match value {
+                PropertyValue::UnsignedInteger(0) => {
+                    self.clear();
+                    Ok(())
+                }
+                PropertyValue::UnsignedInteger(_) => Err(ObjectError::InvalidValue(
+                    "Record count can only be written to zero".to_string(),
+                )),
+                _ => Err(ObjectError::InvalidPropertyType),
+            },
+            _ => Err(write_rejected(self, property)),
+        }
+    }
+
+    fn is_property_writable(&self, property: PropertyIdentifier) -> bool {
+        matches!(
+            property,
+            PropertyIdentifier::ObjectName
+                | PropertyIdentifier::Description
+                | PropertyIdentifier::Enable
+                | PropertyIdentifier::LogInterval
+                | PropertyIdentifier::StopWhenFull
+                | PropertyIdentifier::RecordCount
+        )
+    }
+
+    fn property_list(&self) -> Vec<PropertyIdentifier> {
+        vec![
+            PropertyIdentifier::ObjectIdentifier,
+            PropertyIdentifier::ObjectName,
+            PropertyIdentifier::ObjectType,
+            PropertyIdentifier::Description,
+            PropertyIdentifier::Enable,
+            PropertyIdentifier::LogDeviceObjectProperty,
+            PropertyIdentifier::LogInterval,
+            PropertyIdentifier::StopWhenFull,
+            PropertyIdentifier::BufferSize,
+            PropertyIdentifier::LogBuffer,
+            PropertyIdentifier::RecordCount,
+            PropertyIdentifier::TotalRecordCount,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::object::{AnalogInput, Date, Time};
+
+    fn timestamp(minute: u8, second: u8) -> BacnetDateTime {
+        BacnetDateTime::new(
+            Date {
+                year: 2025,
+                month: 3,
+                day: 1,
+                weekday: 6,
+            },
+            Time {
+                hour: 12,
+                minute,
+                second,
+                hundredths: 0,
+            },
+        )
+    }
+
+    fn record(minute: u8, value: f32) -> LogRecord {
+        LogRecord {
+            timestamp: timestamp(minute, 0),
+            value: PropertyValue::Real(value),
+            status_flags: None,
+        }
+    }
+
+    fn values(records: &[LogRecord]) -> Vec<f32> {
+        records
+            .iter()
+            .map(|record| match record.value {
+                PropertyValue::Real(value) => value,
+                _ => f32::NAN,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_trend_log_wraps_when_full() {
+        let mut log = TrendLog::new(1, "Zone Temp Log".to_string(), 3);
+        for minute in 0..5 {
+            assert!(log.add_record(record(minute, minute as f32)));
+        }
+        assert_eq!(log.record_count(), 3);
+        assert_eq!(log.total_record_count, 5);
+        let records: Vec<LogRecord> = log.records().cloned().collect();
+        assert_eq!(values(&records), vec![2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_trend_log_listed_properties_are_readable() {
+        let mut log = TrendLog::new(1, "Zone Temp Log".to_string(), 3);
+        log.add_record(record(0, 21.5));
+        for property in log.property_list() {
+            assert!(
+                log.get_property(property).is_ok(),
+                "{:?} is listed but not readable",
+                property
+            );
+        }
+
+        log.log_device_object_property = Some(ObjectPropertyReference::new(
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1),
+            PropertyIdentifier::PresentValue,
+        ));
+        match log
+            .get_property(PropertyIdentifier::LogDeviceObjectProperty)
+            .unwrap()
+        {
+            PropertyValue::Constructed(data) => {
+                assert_eq!(data, [0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55])
+            }
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+
+        let mut expected = Vec::new();
+        record(0, 21.5).encode(&mut expected).unwrap();
+        match log.get_property(PropertyIdentifier::LogBuffer).unwrap() {
+            PropertyValue::Constructed(data) => assert_eq!(data, expected),
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_trend_log_stop_when_full() {
+        let mut log = TrendLog::new(1, "Zone Temp Log".to_string(), 2);
+        log.stop_when_full = true;
+        assert!(log.add_record(record(0, 0.0)));
+        assert!(log.add_record(record(1, 1.0)));
+        assert!(!log.enable);
+        assert!(!log.add_record(record(2, 2.0)));
+        assert_eq!(log.total_record_count, 2);
+
+        log.set_property(
+            PropertyIdentifier::RecordCount,
+            PropertyValue::UnsignedInteger(0),
+        )
+        .unwrap();
+        assert_eq!(log.record_count(), 0);
+    }
+
+    #[test]
+    fn test_trend_log_read_range() {
+        let mut log = TrendLog::new(1, "Zone Temp Log".to_string(), 10);
+        for minute in 0..6 {
+            log.add_record(record(minute, minute as f32));
+        }
+
+        assert_eq!(values(&log.read_by_position(2, 3)), vec![1.0, 2.0, 3.0]);
+        assert_eq!(values(&log.read_by_position(2, -3)), vec![0.0, 1.0]);
+        assert_eq!(values(&log.read_by_position(5, 10)), vec![4.0, 5.0]);
+        assert!(log.read_by_position(7, 1).is_empty());
+
+        let reference = timestamp(2, 0);
+        assert_eq!(values(&log.read_by_time(&reference, 2)), vec![3.0, 4.0]);
+        assert_eq!(values(&log.read_by_time(&reference, -5)), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_trend_log_read_range_service() {
+        let mut log = TrendLog::new(1, "Zone Temp Log".to_string(), 4);
+        for minute in 0..6 {
+            log.add_record(record(minute, minute as f32));
+        }
+        // Records 3 to 6 remain after wrapping
+        let request = |range| {
+            ReadRangeRequest::new(
+                log.identifier,
+                u32::from(PropertyIdentifier::LogBuffer),
+                range,
+            )
+        };
+
+        let ack = log
+            .read_range(&request(Some(ReadRange::BySequenceNumber {
+                reference_sequence_number: 4,
+                count: 2,
+            })))
+            .unwrap();
+        assert_eq!(ack.item_count, 2);
+        assert_eq!(ack.first_sequence_number, Some(4));
+        assert_eq!(
+            ack.result_flags,
+            ResultFlags {
+                first_item: false,
+                last_item: false,
+                more_items: true,
+            }
+        );
+        let records = LogRecord::decode_list(&ack.item_data).unwrap();
+        assert_eq!(values(&records), vec![3.0, 4.0]);
+
+        // Reading backward from the second record stops at the first
+        let ack = log
+            .read_range(&request(Some(ReadRange::ByPosition {
+                reference_index: 2,
+                count: -5,
+            })))
+            .unwrap();
+        assert_eq!(ack.item_count, 2);
+        assert_eq!(ack.first_sequence_number, None);
+        assert!(ack.result_flags.first_item && !ack.result_flags.more_items);
+
+        let ack = log
+            .read_range(&request(Some(ReadRange::ByTime {
+                reference_time: timestamp(4, 0),
+                count: -1,
+            })))
+            .unwrap();
+        assert_eq!(ack.first_sequence_number, Some(4));
+        assert!(ack.result_flags.more_items);
+
+        let ack = log.read_range(&request(None)).unwrap();
+        assert_eq!(ack.item_count, 4);
+        assert!(ack.result_flags.first_item && ack.result_flags.last_item);
+
+        let mut bad = request(None);
+        bad.property_identifier = u32::from(PropertyIdentifier::PresentValue);
+        assert!(log.read_range(&bad).is_err());
+    }
+
+    #[test]
+    fn test_log_record_encoding() {
+        let records = vec![
+            LogRecord {
+                timestamp: timestamp(1, 30),
+                value: PropertyValue::Real(21.5),
+                status_flags: Some(StatusFlags::new(true, false, false, false)),
+            },
+            LogRecord {
+                timestamp: timestamp(2, 0),
+                value: PropertyValue::Boolean(true),
+                status_flags: None,
+            },
+            LogRecord {
+                timestamp: timestamp(3, 0),
+                value: PropertyValue::CharacterString("text".to_string()),
+                status_flags: None,
+            },
+        ];
+        let mut buffer = Vec::new();
+        for record in &records {
+            record.encode(&mut buffer).unwrap();
+        }
+
+        let decoded = LogRecord::decode_list(&buffer).unwrap();
+        assert_eq!(decoded.len(), 3);
+        assert_eq!(decoded[0].timestamp, timestamp(1, 30));
+        assert!(matches!(decoded[0].value, PropertyValue::Real(v) if v == 21.5));
+        assert_eq!(decoded[0].status_flags, records[0].status_flags);
+        assert!(matches!(decoded[1].value, PropertyValue::Boolean(true)));
+        assert!(matches!(&decoded[2].value, PropertyValue::CharacterString(s) if s == "text"));
+    }
+
+    #[test]
+    fn test_trend_log_polling() {
+        let mut ai = AnalogInput::new(7, "Zone Temp".to_string());
+        ai.set_present_value(21.5);
+
+        let mut log = TrendLog::new(1, "Zone Temp Log".to_string(), 10);
+        log.log_device_object_property = Some(ObjectPropertyReference::new(
+            ai.identifier,
+            PropertyIdentifier::PresentValue,
+        ));
+        log.log_interval = 6000; // one minute
+
+        assert!(log.poll(timestamp(0, 0), &ai).unwrap());
+        assert!(!log.poll(timestamp(0, 59), &ai).unwrap());
+        ai.set_present_value(22.0);
+        assert!(log.poll(timestamp(1, 0), &ai).unwrap());
+
+        let records: Vec<LogRecord> = log.records().cloned().collect();
+        assert_eq!(values(&records), vec![21.5, 22.0]);
+        assert_eq!(records[0].status_flags, Some(StatusFlags::default()));
+
+        let other = AnalogInput::new(8, "Other".to_string());
+        assert!(log.poll(timestamp(5, 0), &other).is_err());
+    }
+}
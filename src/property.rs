@@ -4,7 +4,11 @@
 //! from their encoded representations into typed Rust values.
 
 #[cfg(not(feature = "std"))]
-use alloc::{string::String, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use crate::object::ObjectType;
 
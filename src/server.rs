@@ -0,0 +1,1452 @@
+//! BACnet Device Server
+//!
+//! This module provides the device side of BACnet communication: a server
+//! that receives requests from a data link, answers Who-Is with I-Am,
+//! serves ReadProperty, WriteProperty and WritePropertyMultiple from the
+//! objects of an
+//! [`ObjectDatabase`] and hands time synchronization to a [`ClockHook`].
+//! Vendor services are handed to [`PrivateTransferHook`]s.
+//! DeviceCommunicationControl silences the server; the application checks
+//! [`DeviceServer::communication_state`] before initiating messages itself.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use bacnet_rs::datalink::bip::BacnetIpDataLink;
+//! use bacnet_rs::object::{database::ObjectDatabase, Device};
+//! use bacnet_rs::server::DeviceServer;
+//! use std::sync::atomic::AtomicBool;
+//!
+//! # fn example() -> Result<(), Box<dyn std::error::Error>> {
+//! let datalink = BacnetIpDataLink::new("0.0.0.0:47808")?;
+//! let database = ObjectDatabase::new(Device::new(1234, "Rust Device".to_string()));
+//! let mut server = DeviceServer::new(datalink, database);
+//!
+//! let running = AtomicBool::new(true);
+//! server.run(&running)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    io::ErrorKind,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    app::{Apdu, MaxApdu},
+    datalink::{DataLink, DataLinkAddress, DataLinkError},
+    encoding::EncodingError,
+    network::{BacnetAddress, Npdu},
+    object::{
+        database::ObjectDatabase, Device, ObjectIdentifier, PropertyIdentifier, PropertyValue,
+    },
+    service::{
+        AbortReason, AcknowledgeAlarmRequest, BacnetDateTime, BacnetError,
+        ConfirmedPrivateTransferAck, ConfirmedPrivateTransferError, ConfirmedServiceChoice,
+        DeviceCommunicationControlRequest, EnableDisable, ErrorClass, ErrorCode, IAmRequest,
+        PrivateTransferRequest, ReadPropertyAck, ReadPropertyRequest, ReinitializeDeviceRequest,
+        ReinitializedState, RejectReason, TimeSynchronizationRequest, UnconfirmedServiceChoice,
+        UtcTimeSynchronizationRequest, WhoIsRequest, WritePropertyMultipleError,
+        WritePropertyMultipleRequest, WritePropertyRequest,
+    },
+};
+
+/// A WriteProperty request about to be applied
+#[derive(Debug, Clone)]
+pub struct PropertyWrite {
+    /// Object written to
+    pub object_identifier: ObjectIdentifier,
+    /// Property written
+    pub property: PropertyIdentifier,
+    /// Array index, if a single element is written
+    pub property_array_index: Option<u32>,
+    /// Value to write
+    pub value: PropertyValue,
+    /// Command priority (1-16)
+    pub priority: Option<u8>,
+}
+
+/// Hook consulted before a WriteProperty request is applied
+///
+/// Each write of a WritePropertyMultiple request is passed to the hook in
+/// turn.
+///
+/// Returning an error rejects the write with that error. The hook may also
+/// change the write before it reaches the object, e.g. to clamp a value.
+pub trait WriteHook: Send {
+    /// Authorize, adjust or reject a write from `source`
+    fn before_write(
+        &mut self,
+        source: &BacnetAddress,
+        write: &mut PropertyWrite,
+    ) -> Result<(), BacnetError>;
+}
+
+impl<F> WriteHook for F
+where
+    F: FnMut(&BacnetAddress, &mut PropertyWrite) -> Result<(), BacnetError> + Send,
+{
+    fn before_write(
+        &mut self,
+        source: &BacnetAddress,
+        write: &mut PropertyWrite,
+    ) -> Result<(), BacnetError> {
+        self(source, write)
+    }
+}
+
+/// Time received in a time synchronization request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeSync {
+    /// Local time, from TimeSynchronization
+    Local(BacnetDateTime),
+    /// Coordinated universal time, from UTCTimeSynchronization
+    Utc(BacnetDateTime),
+}
+
+/// Hook that sets the device clock when a time synchronization arrives
+pub trait ClockHook: Send {
+    /// Set the clock to the time sent by `source`
+    fn set_clock(&mut self, source: &BacnetAddress, time: TimeSync);
+}
+
+impl<F> ClockHook for F
+where
+    F: FnMut(&BacnetAddress, TimeSync) + Send,
+{
+    fn set_clock(&mut self, source: &BacnetAddress, time: TimeSync) {
+        self(source, time)
+    }
+}
+
+/// Hook that carries out ReinitializeDevice requests
+///
+/// Returning an error answers the request with it; states the device cannot
+/// enter are best refused with
+/// `BacnetError::Reject(RejectReason::ParameterOutOfRange)`.
+pub trait ReinitializeHook: Send {
+    /// Restart the device or change its backup/restore state
+    fn on_reinitialize(&mut self, state: ReinitializedState) -> Result<(), BacnetError>;
+}
+
+impl<F> ReinitializeHook for F
+where
+    F: FnMut(ReinitializedState) -> Result<(), BacnetError> + Send,
+{
+    fn on_reinitialize(&mut self, state: ReinitializedState) -> Result<(), BacnetError> {
+        self(state)
+    }
+}
+
+/// Hook that records AcknowledgeAlarm requests
+///
+/// The hook marks the acknowledged transition of the event object,
+/// answering with `ErrorCode::InvalidEventState` or
+/// `ErrorCode::InvalidTimeStamp` when the request does not match the
+/// transition the object last reported.
+pub trait AlarmAckHook: Send {
+    /// Acknowledge a transition on behalf of `source`
+    fn acknowledge_alarm(
+        &mut self,
+        source: &BacnetAddress,
+        request: &AcknowledgeAlarmRequest,
+    ) -> Result<(), BacnetError>;
+}
+
+impl<F> AlarmAckHook for F
+where
+    F: FnMut(&BacnetAddress, &AcknowledgeAlarmRequest) -> Result<(), BacnetError> + Send,
+{
+    fn acknowledge_alarm(
+        &mut self,
+        source: &BacnetAddress,
+        request: &AcknowledgeAlarmRequest,
+    ) -> Result<(), BacnetError> {
+        self(source, request)
+    }
+}
+
+/// Hook that carries out one vendor's private transfer service
+///
+/// The hook receives the encoded service parameters and returns the encoded
+/// result block sent back in the ConfirmedPrivateTransfer acknowledgement.
+/// Results of UnconfirmedPrivateTransfer and errors from it are dropped.
+pub trait PrivateTransferHook: Send {
+    /// Carry out a transfer requested by `source`
+    fn on_private_transfer(
+        &mut self,
+        source: &BacnetAddress,
+        parameters: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>, BacnetError>;
+}
+
+impl<F> PrivateTransferHook for F
+where
+    F: FnMut(&BacnetAddress, Option<&[u8]>) -> Result<Option<Vec<u8>>, BacnetError> + Send,
+{
+    fn on_private_transfer(
+        &mut self,
+        source: &BacnetAddress,
+        parameters: Option<&[u8]>,
+    ) -> Result<Option<Vec<u8>>, BacnetError> {
+        self(source, parameters)
+    }
+}
+
+/// BACnet device answering requests received on a data link
+pub struct DeviceServer<D: DataLink> {
+    datalink: D,
+    database: ObjectDatabase,
+    write_hook: Option<Box<dyn WriteHook>>,
+    clock_hook: Option<Box<dyn ClockHook>>,
+    reinitialize_hook: Option<Box<dyn ReinitializeHook>>,
+    alarm_ack_hook: Option<Box<dyn AlarmAckHook>>,
+    private_transfer_hooks: HashMap<(u16, u32), Box<dyn PrivateTransferHook>>,
+    password: Option<String>,
+    communication: EnableDisable,
+    communication_until: Option<Instant>,
+}
+
+impl<D: DataLink> DeviceServer<D> {
+    /// Create a server for the device and objects held in `database`
+    pub fn new(datalink: D, database: ObjectDatabase) -> Self {
+        Self {
+            datalink,
+            database,
+            write_hook: None,
+            clock_hook: None,
+            reinitialize_hook: None,
+            alarm_ack_hook: None,
+            private_transfer_hooks: HashMap::new(),
+            password: None,
+            communication: EnableDisable::Enable,
+            communication_until: None,
+        }
+    }
+
+    /// Set the hook that authorizes WriteProperty requests
+    pub fn with_write_hook(mut self, hook: impl WriteHook + 'static) -> Self {
+        self.write_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Set the hook that applies time synchronization requests
+    ///
+    /// Without a hook, time synchronization requests are ignored.
+    pub fn with_clock_hook(mut self, hook: impl ClockHook + 'static) -> Self {
+        self.clock_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Set the hook that carries out ReinitializeDevice requests
+    ///
+    /// Without a hook, ReinitializeDevice is answered with an Error PDU.
+    pub fn with_reinitialize_hook(mut self, hook: impl ReinitializeHook + 'static) -> Self {
+        self.reinitialize_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Set the hook that records AcknowledgeAlarm requests
+    ///
+    /// Without a hook, AcknowledgeAlarm is answered with an Error PDU.
+    pub fn with_alarm_ack_hook(mut self, hook: impl AlarmAckHook + 'static) -> Self {
+        self.alarm_ack_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Set the hook carrying out service `service_number` of vendor `vendor_id`
+    ///
+    /// Private transfers without a hook are refused with an Error PDU.
+    pub fn with_private_transfer_hook(
+        mut self,
+        vendor_id: u16,
+        service_number: u32,
+        hook: impl PrivateTransferHook + 'static,
+    ) -> Self {
+        self.private_transfer_hooks
+            .insert((vendor_id, service_number), Box::new(hook));
+        self
+    }
+
+    /// Require a password for DeviceCommunicationControl and ReinitializeDevice
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Get the communication state set by DeviceCommunicationControl
+    ///
+    /// A timed state reverts to [`EnableDisable::Enable`] once its duration
+    /// has elapsed.
+    pub fn communication_state(&self) -> EnableDisable {
+        match self.communication_until {
+            Some(until) if Instant::now() >= until => EnableDisable::Enable,
+            _ => self.communication,
+        }
+    }
+
+    /// Get the objects served by this device
+    pub fn database(&self) -> &ObjectDatabase {
+        &self.database
+    }
+
+    /// Serve requests until `running` is cleared
+    pub fn run(&mut self, running: &AtomicBool) -> Result<(), DataLinkError> {
+        while running.load(Ordering::SeqCst) {
+            self.poll()?;
+        }
+        Ok(())
+    }
+
+    /// Receive one frame and send the response, if any
+    ///
+    /// Returns `Ok(false)` when the data link timed out or delivered a frame
+    /// without an NPDU.
+    pub fn poll(&mut self) -> Result<bool, DataLinkError> {
+        let (frame, source) = match self.datalink.receive_frame() {
+            Ok(received) => received,
+            Err(DataLinkError::IoError(e))
+                if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) =>
+            {
+                return Ok(false)
+            }
+            Err(DataLinkError::InvalidFrame) => return Ok(false),
+            Err(e) => return Err(e),
+        };
+
+        if let Some(response) = self.handle_frame(&frame, &source) {
+            self.datalink.send_frame(&response, &source)?;
+        }
+        Ok(true)
+    }
+
+    /// Process a received NPDU, returning the NPDU to send back to `source`
+    ///
+    /// Network layer messages and undecodable frames are ignored.
+    pub fn handle_frame(&mut self, frame: &[u8], source: &DataLinkAddress) -> Option<Vec<u8>> {
+        let (npdu, npdu_len) = Npdu::decode(frame).ok()?;
+        if npdu.control.network_message {
+            return None;
+        }
+        let apdu = Apdu::decode(&frame[npdu_len..]).ok()?;
+        let source = BacnetAddress::npdu_source(&npdu, source, self.datalink.link_type());
+        let response = self.handle_apdu(&apdu, &source)?;
+
+        // Route the response back to a device behind a router
+        let mut buffer = Npdu::to_address(&source).encode();
+        buffer.extend_from_slice(&response.encode());
+        Some(buffer)
+    }
+
+    /// Process a received APDU, returning the response APDU, if any
+    ///
+    /// While communication is disabled only DeviceCommunicationControl and
+    /// ReinitializeDevice requests are answered.
+    pub fn handle_apdu(&mut self, apdu: &Apdu, source: &BacnetAddress) -> Option<Apdu> {
+        if self.communication_state() == EnableDisable::Disable
+            && !matches!(
+                apdu,
+                Apdu::ConfirmedRequest {
+                    service_choice: ConfirmedServiceChoice::DeviceCommunicationControl
+                        | ConfirmedServiceChoice::ReinitializeDevice,
+                    ..
+                }
+            )
+        {
+            return None;
+        }
+
+        match apdu {
+            Apdu::ConfirmedRequest {
+                segmented: true,
+                invoke_id,
+                ..
+            } => Some(Apdu::Abort {
+                server: true,
+                invoke_id: *invoke_id,
+                abort_reason: AbortReason::SegmentationNotSupported as u8,
+            }),
+            Apdu::ConfirmedRequest {
+                invoke_id,
+                service_choice,
+                service_data,
+                ..
+            } => Some(self.handle_confirmed(*invoke_id, *service_choice, service_data, source)),
+            Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::WhoIs,
+                service_data,
+            } => self.handle_who_is(service_data),
+            Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::TimeSynchronization,
+                service_data,
+            } => {
+                if let Ok(request) = TimeSynchronizationRequest::decode(service_data) {
+                    self.set_clock(source, TimeSync::Local(request.date_time));
+                }
+                None
+            }
+            Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::UtcTimeSynchronization,
+                service_data,
+            } => {
+                if let Ok(request) = UtcTimeSynchronizationRequest::decode(service_data) {
+                    self.set_clock(source, TimeSync::Utc(request.utc_date_time));
+                }
+                None
+            }
+            Apdu::UnconfirmedRequest {
+                service_choice: UnconfirmedServiceChoice::UnconfirmedPrivateTransfer,
+                service_data,
+            } => {
+                if let Ok(request) = PrivateTransferRequest::decode(service_data) {
+                    let _ = self.private_transfer(&request, source);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+
+    /// Pass a received time to the clock hook, if one is set
+    fn set_clock(&mut self, source: &BacnetAddress, time: TimeSync) {
+        if let Some(hook) = self.clock_hook.as_mut() {
+            hook.set_clock(source, time);
+        }
+    }
+
+    /// Answer a confirmed request with an ACK, Error or Reject PDU
+    fn handle_confirmed(
+        &mut self,
+        invoke_id: u8,
+        service_choice: ConfirmedServiceChoice,
+        service_data: &[u8],
+        source: &BacnetAddress,
+    ) -> Apdu {
+        // Services whose errors carry parameters build their own Error PDU
+        match service_choice {
+            ConfirmedServiceChoice::WritePropertyMultiple => {
+                return self.write_property_multiple(invoke_id, service_data, source)
+            }
+            ConfirmedServiceChoice::ConfirmedPrivateTransfer => {
+                return self.confirmed_private_transfer(invoke_id, service_data, source)
+            }
+            _ => {}
+        }
+
+        let result = match service_choice {
+            ConfirmedServiceChoice::ReadProperty => self.read_property(service_data).map(Some),
+            ConfirmedServiceChoice::WriteProperty => {
+                self.write_property(service_data, source).map(|()| None)
+            }
+            ConfirmedServiceChoice::DeviceCommunicationControl => self
+                .device_communication_control(service_data)
+                .map(|()| None),
+            ConfirmedServiceChoice::ReinitializeDevice => {
+                self.reinitialize_device(service_data).map(|()| None)
+            }
+            ConfirmedServiceChoice::AcknowledgeAlarm => {
+                self.acknowledge_alarm(service_data, source).map(|()| None)
+            }
+            _ => Err(BacnetError::Reject(RejectReason::UnrecognizedService)),
+        };
+        confirmed_response(invoke_id, service_choice, result)
+    }
+
+    /// Read a property, returning the encoded ReadProperty-ACK
+    fn read_property(&self, service_data: &[u8]) -> Result<Vec<u8>, BacnetError> {
+        let request = ReadPropertyRequest::decode(service_data)
+            .map_err(|_| BacnetError::Reject(RejectReason::InvalidTag))?;
+        let property = PropertyIdentifier::from(request.property_identifier);
+        let value = self.database.get_property_element(
+            request.object_identifier,
+            property,
+            request.property_array_index,
+        )?;
+
+        let mut ack = ReadPropertyAck::new(
+            request.object_identifier,
+            request.property_identifier,
+            value,
+        );
+        ack.property_array_index = request.property_array_index;
+        let mut buffer = Vec::new();
+        ack.encode(&mut buffer)
+            .map_err(|_| BacnetError::Abort(AbortReason::Other))?;
+        Ok(buffer)
+    }
+
+    /// Apply a WriteProperty request after the write hook accepts it
+    fn write_property(
+        &mut self,
+        service_data: &[u8],
+        source: &BacnetAddress,
+    ) -> Result<(), BacnetError> {
+        let request = WritePropertyRequest::decode(service_data)
+            .map_err(|_| BacnetError::Reject(RejectReason::InvalidTag))?;
+        let value = request
+            .value()
+            .map_err(|_| BacnetError::Reject(RejectReason::InvalidParameterDataType))?;
+        self.apply_write(
+            PropertyWrite {
+                object_identifier: request.object_identifier,
+                property: PropertyIdentifier::from(request.property_identifier),
+                property_array_index: request.property_array_index,
+                value,
+                priority: request.priority,
+            },
+            source,
+        )
+    }
+
+    /// Apply the writes of a WritePropertyMultiple request in order
+    ///
+    /// The first write that fails stops the request and is reported in a
+    /// WritePropertyMultiple-Error.
+    fn write_property_multiple(
+        &mut self,
+        invoke_id: u8,
+        service_data: &[u8],
+        source: &BacnetAddress,
+    ) -> Apdu {
+        let service_choice = ConfirmedServiceChoice::WritePropertyMultiple;
+        let Ok(request) = WritePropertyMultipleRequest::decode(service_data) else {
+            let reject = BacnetError::Reject(RejectReason::InvalidTag);
+            return confirmed_response(invoke_id, service_choice, Err(reject));
+        };
+
+        for spec in request.write_access_specifications {
+            for property in spec.list_of_properties {
+                let write = PropertyWrite {
+                    object_identifier: spec.object_identifier,
+                    property: PropertyIdentifier::from(property.property_identifier),
+                    property_array_index: property.property_array_index,
+                    value: property.value,
+                    priority: property.priority,
+                };
+                let (error_class, error_code) = match self.apply_write(write, source) {
+                    Ok(()) => continue,
+                    Err(BacnetError::Error { class, code }) => (class as u32, u32::from(code)),
+                    Err(BacnetError::ProprietaryError { class, code }) => (class, code),
+                    Err(error) => return confirmed_response(invoke_id, service_choice, Err(error)),
+                };
+                let error = WritePropertyMultipleError {
+                    error_class,
+                    error_code,
+                    object_identifier: spec.object_identifier,
+                    property_identifier: property.property_identifier,
+                    property_array_index: property.property_array_index,
+                };
+                return error.to_apdu(invoke_id).unwrap_or(Apdu::Abort {
+                    server: true,
+                    invoke_id,
+                    abort_reason: AbortReason::Other as u8,
+                });
+            }
+        }
+        confirmed_response(invoke_id, service_choice, Ok(None))
+    }
+
+    /// Answer a ConfirmedPrivateTransfer with the hook's result block
+    fn confirmed_private_transfer(
+        &mut self,
+        invoke_id: u8,
+        service_data: &[u8],
+        source: &BacnetAddress,
+    ) -> Apdu {
+        let service_choice = ConfirmedServiceChoice::ConfirmedPrivateTransfer;
+        let Ok(request) = PrivateTransferRequest::decode(service_data) else {
+            let reject = BacnetError::Reject(RejectReason::InvalidTag);
+            return confirmed_response(invoke_id, service_choice, Err(reject));
+        };
+
+        let (error_class, error_code) = match self.private_transfer(&request, source) {
+            Ok(result_block) => {
+                let ack = ConfirmedPrivateTransferAck {
+                    vendor_id: request.vendor_id,
+                    service_number: request.service_number,
+                    result_block,
+                };
+                let mut buffer = Vec::new();
+                let result = ack
+                    .encode(&mut buffer)
+                    .map(|()| Some(buffer))
+                    .map_err(|_| BacnetError::Abort(AbortReason::Other));
+                return confirmed_response(invoke_id, service_choice, result);
+            }
+            Err(BacnetError::Error { class, code }) => (class as u32, u32::from(code)),
+            Err(BacnetError::ProprietaryError { class, code }) => (class, code),
+            Err(error) => return confirmed_response(invoke_id, service_choice, Err(error)),
+        };
+        let error = ConfirmedPrivateTransferError {
+            error_class,
+            error_code,
+            vendor_id: request.vendor_id,
+            service_number: request.service_number,
+            error_parameters: None,
+        };
+        error.to_apdu(invoke_id).unwrap_or(Apdu::Abort {
+            server: true,
+            invoke_id,
+            abort_reason: AbortReason::Other as u8,
+        })
+    }
+
+    /// Pass a private transfer to the hook for its vendor and service
+    fn private_transfer(
+        &mut self,
+        request: &PrivateTransferRequest,
+        source: &BacnetAddress,
+    ) -> Result<Option<Vec<u8>>, BacnetError> {
+        let key = (request.vendor_id, request.service_number);
+        match self.private_transfer_hooks.get_mut(&key) {
+            Some(hook) => hook.on_private_transfer(source, request.service_parameters.as_deref()),
+            None => Err(BacnetError::Error {
+                class: ErrorClass::Services,
+                code: ErrorCode::OptionalFunctionalityNotSupported,
+            }),
+        }
+    }
+
+    /// Pass a write through the write hook and apply it to the database
+    fn apply_write(
+        &mut self,
+        mut write: PropertyWrite,
+        source: &BacnetAddress,
+    ) -> Result<(), BacnetError> {
+        if let Some(hook) = self.write_hook.as_mut() {
+            hook.before_write(source, &mut write)?;
+        }
+        self.database.write_property_element(
+            write.object_identifier,
+            write.property,
+            write.property_array_index,
+            write.value,
+            write.priority,
+        )?;
+        Ok(())
+    }
+
+    /// Change the communication state after checking the password
+    fn device_communication_control(&mut self, service_data: &[u8]) -> Result<(), BacnetError> {
+        let request = DeviceCommunicationControlRequest::decode(service_data)
+            .map_err(|_| BacnetError::Reject(RejectReason::InvalidTag))?;
+        self.check_password(&request.password)?;
+
+        self.communication = request.enable_disable;
+        // Enable has no duration; otherwise zero or no minutes is indefinite
+        self.communication_until = match (request.enable_disable, request.time_duration) {
+            (EnableDisable::Enable, _) | (_, None | Some(0)) => None,
+            (_, Some(minutes)) => {
+                Some(Instant::now() + Duration::from_secs(u64::from(minutes) * 60))
+            }
+        };
+        Ok(())
+    }
+
+    /// Pass a ReinitializeDevice request to the hook after checking the password
+    fn reinitialize_device(&mut self, service_data: &[u8]) -> Result<(), BacnetError> {
+        let request = ReinitializeDeviceRequest::decode(service_data).map_err(|e| match e {
+            EncodingError::ValueOutOfRange => {
+                BacnetError::Reject(RejectReason::UndefinedEnumeration)
+            }
+            _ => BacnetError::Reject(RejectReason::InvalidTag),
+        })?;
+        self.check_password(&request.password)?;
+
+        match self.reinitialize_hook.as_mut() {
+            Some(hook) => hook.on_reinitialize(request.reinitialized_state),
+            None => Err(BacnetError::Error {
+                class: ErrorClass::Services,
+                code: ErrorCode::OptionalFunctionalityNotSupported,
+            }),
+        }
+    }
+
+    /// Pass an AcknowledgeAlarm request for a known object to the hook
+    fn acknowledge_alarm(
+        &mut self,
+        service_data: &[u8],
+        source: &BacnetAddress,
+    ) -> Result<(), BacnetError> {
+        let request = AcknowledgeAlarmRequest::decode(service_data).map_err(|e| match e {
+            EncodingError::ValueOutOfRange => {
+                BacnetError::Reject(RejectReason::UndefinedEnumeration)
+            }
+            _ => BacnetError::Reject(RejectReason::InvalidTag),
+        })?;
+        self.database.get(request.event_object_identifier)?;
+
+        match self.alarm_ack_hook.as_mut() {
+            Some(hook) => hook.acknowledge_alarm(source, &request),
+            None => Err(BacnetError::Error {
+                class: ErrorClass::Services,
+                code: ErrorCode::OptionalFunctionalityNotSupported,
+            }),
+        }
+    }
+
+    /// Check a request's password against the configured one, if any
+    fn check_password(&self, password: &Option<String>) -> Result<(), BacnetError> {
+        if self.password.is_some() && *password != self.password {
+            return Err(BacnetError::Error {
+                class: ErrorClass::Security,
+                code: ErrorCode::PasswordFailure,
+            });
+        }
+        Ok(())
+    }
+
+    /// Build the I-Am answering a Who-Is that matches this device
+    fn handle_who_is(&self, service_data: &[u8]) -> Option<Apdu> {
+        let request = WhoIsRequest::decode(service_data).ok()?;
+        let device_id = self.database.get_device_id();
+        if !request.matches(device_id.instance) {
+            return None;
+        }
+
+        let i_am = self
+            .database
+            .with_object::<Device, _>(device_id, |device| {
+                // Advertise a standard size no larger than the configured one
+                let max_apdu = MaxApdu::from_length(device.max_apdu_length_accepted as u32);
+                IAmRequest::new(
+                    device.identifier,
+                    max_apdu.as_bytes() as u32,
+                    device.segmentation_supported,
+                    device.vendor_identifier as u32,
+                )
+            })
+            .ok()?;
+        let mut service_data = Vec::new();
+        i_am.encode(&mut service_data).ok()?;
+        Some(Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::IAm,
+            service_data,
+        })
+    }
+}
+
+/// Answer a confirmed request with an ACK, or with the error it failed with
+fn confirmed_response(
+    invoke_id: u8,
+    service_choice: ConfirmedServiceChoice,
+    result: Result<Option<Vec<u8>>, BacnetError>,
+) -> Apdu {
+    match result {
+        Ok(Some(service_data)) => Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: service_choice as u8,
+            service_data,
+        },
+        Ok(None) => Apdu::SimpleAck {
+            invoke_id,
+            service_choice: service_choice as u8,
+        },
+        Err(BacnetError::Error { class, code }) => Apdu::Error {
+            invoke_id,
+            service_choice: service_choice as u8,
+            error_class: class as u32,
+            error_code: u32::from(code),
+            error_data: Vec::new(),
+        },
+        Err(BacnetError::ProprietaryError { class, code }) => Apdu::Error {
+            invoke_id,
+            service_choice: service_choice as u8,
+            error_class: class,
+            error_code: code,
+            error_data: Vec::new(),
+        },
+        Err(BacnetError::Reject(reason)) => Apdu::Reject {
+            invoke_id,
+            reject_reason: reason as u8,
+        },
+        Err(BacnetError::Abort(reason)) => Apdu::Abort {
+            server: true,
+            invoke_id,
+            abort_reason: reason as u8,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::datalink::{DataLinkType, Result as DataLinkResult};
+    use crate::network::MacAddress;
+    use crate::object::{analog::AnalogOutput, ObjectType};
+    use crate::service::{BacnetPropertyValue, WriteAccessSpecification};
+    use std::collections::VecDeque;
+
+    /// Data link replaying queued frames and recording what is sent
+    #[derive(Default)]
+    struct Loopback {
+        inbound: VecDeque<(Vec<u8>, DataLinkAddress)>,
+        outbound: Vec<(Vec<u8>, DataLinkAddress)>,
+    }
+
+    impl DataLink for Loopback {
+        fn send_frame(&mut self, frame: &[u8], dest: &DataLinkAddress) -> DataLinkResult<()> {
+            self.outbound.push((frame.to_vec(), dest.clone()));
+            Ok(())
+        }
+
+        fn receive_frame(&mut self) -> DataLinkResult<(Vec<u8>, DataLinkAddress)> {
+            self.inbound.pop_front().ok_or(DataLinkError::InvalidFrame)
+        }
+
+        fn link_type(&self) -> DataLinkType {
+            DataLinkType::BacnetIp
+        }
+
+        fn local_address(&self) -> DataLinkAddress {
+            DataLinkAddress::MsTP(1)
+        }
+    }
+
+    fn server() -> DeviceServer<Loopback> {
+        let database = ObjectDatabase::new(Device::new(1234, "Server".to_string()));
+        database
+            .add_object(Box::new(AnalogOutput::new(1, "Valve".to_string())))
+            .unwrap();
+        DeviceServer::new(Loopback::default(), database)
+    }
+
+    fn confirmed(service_choice: ConfirmedServiceChoice, service_data: Vec<u8>) -> Apdu {
+        Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: false,
+            max_segments: crate::app::MaxSegments::Unspecified,
+            max_response_size: MaxApdu::Up1476,
+            invoke_id: 7,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice,
+            service_data,
+        }
+    }
+
+    #[test]
+    fn test_who_is_answered_with_i_am() {
+        let mut server = server();
+        // Not a standard size: the I-Am advertises the next size down
+        let device_id = server.database.get_device_id();
+        server
+            .database
+            .with_object_mut::<Device, _>(device_id, |device| {
+                device.max_apdu_length_accepted = 1000;
+            })
+            .unwrap();
+        let mut service_data = Vec::new();
+        WhoIsRequest::for_range(1000, 2000)
+            .encode(&mut service_data)
+            .unwrap();
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoIs,
+            service_data,
+        };
+
+        let mut frame = Npdu::new().encode();
+        frame.extend_from_slice(&apdu.encode());
+        let source = DataLinkAddress::MsTP(5);
+        server.datalink.inbound.push_back((frame, source.clone()));
+        assert!(server.poll().unwrap());
+
+        let (response, dest) = &server.datalink.outbound[0];
+        assert_eq!(dest, &source);
+        let (_, npdu_len) = Npdu::decode(response).unwrap();
+        let Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::IAm,
+            service_data,
+        } = Apdu::decode(&response[npdu_len..]).unwrap()
+        else {
+            panic!("Expected I-Am");
+        };
+        let i_am = IAmRequest::decode(&service_data).unwrap();
+        assert_eq!(i_am.device_identifier.instance, 1234);
+        assert_eq!(i_am.max_apdu_length_accepted, 480);
+        assert_eq!(i_am.max_apdu(), MaxApdu::Up480);
+
+        // A Who-Is for other devices is not answered
+        assert!(server
+            .handle_apdu(
+                &Apdu::UnconfirmedRequest {
+                    service_choice: UnconfirmedServiceChoice::WhoIs,
+                    service_data: vec![0x09, 0x01, 0x19, 0x02],
+                },
+                &BacnetAddress::from(&source),
+            )
+            .is_none());
+    }
+
+    #[test]
+    fn test_read_and_write_property() {
+        let mut server = server();
+        let source = BacnetAddress::local(MacAddress::MsTp(5));
+        let valve = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+
+        let mut service_data = Vec::new();
+        WritePropertyRequest::with_value(valve, 85, &PropertyValue::Real(42.0))
+            .unwrap()
+            .encode(&mut service_data)
+            .unwrap();
+        let response = server.handle_apdu(
+            &confirmed(ConfirmedServiceChoice::WriteProperty, service_data),
+            &source,
+        );
+        assert!(matches!(
+            response,
+            Some(Apdu::SimpleAck { invoke_id: 7, .. })
+        ));
+
+        let mut service_data = Vec::new();
+        ReadPropertyRequest::new(valve, 85)
+            .encode(&mut service_data)
+            .unwrap();
+        let Some(Apdu::ComplexAck { service_data, .. }) = server.handle_apdu(
+            &confirmed(ConfirmedServiceChoice::ReadProperty, service_data),
+            &source,
+        ) else {
+            panic!("Expected ReadProperty-ACK");
+        };
+        let ack = ReadPropertyAck::decode(&service_data).unwrap();
+        assert!(matches!(ack.value, PropertyValue::Real(v) if v == 42.0));
+
+        // Unknown objects and read-only properties are reported as errors
+        let mut service_data = Vec::new();
+        ReadPropertyRequest::new(ObjectIdentifier::new(ObjectType::AnalogInput, 9), 85)
+            .encode(&mut service_data)
+            .unwrap();
+        assert!(matches!(
+            server.handle_apdu(
+                &confirmed(ConfirmedServiceChoice::ReadProperty, service_data),
+                &source,
+            ),
+            Some(Apdu::Error {
+                error_class: 1,
+                error_code: 31,
+                ..
+            })
+        ));
+
+        let mut service_data = Vec::new();
+        WritePropertyRequest::with_value(valve, 79, &PropertyValue::Enumerated(0))
+            .unwrap()
+            .encode(&mut service_data)
+            .unwrap();
+        assert!(matches!(
+            server.handle_apdu(
+                &confirmed(ConfirmedServiceChoice::WriteProperty, service_data),
+                &source,
+            ),
+            Some(Apdu::Error {
+                error_class: 2,
+                error_code: 40,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_write_property_multiple() {
+        let mut server = server();
+        let source = BacnetAddress::local(MacAddress::MsTp(5));
+        let valve = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+        let write = |id, value, priority| {
+            let mut property = BacnetPropertyValue::new(id, value);
+            property.priority = priority;
+            property
+        };
+        let present_value = |server: &DeviceServer<Loopback>| {
+            server
+                .database
+                .get_property(valve, PropertyIdentifier::PresentValue)
+                .unwrap()
+        };
+
+        let mut service_data = Vec::new();
+        WritePropertyMultipleRequest::new(vec![WriteAccessSpecification::new(
+            valve,
+            vec![
+                write(85, PropertyValue::Real(20.0), Some(8)),
+                write(
+                    77,
+                    PropertyValue::CharacterString("Damper".to_string()),
+                    None,
+                ),
+            ],
+        )])
+        .encode(&mut service_data)
+        .unwrap();
+        assert!(matches!(
+            server.handle_apdu(
+                &confirmed(ConfirmedServiceChoice::WritePropertyMultiple, service_data),
+                &source,
+            ),
+            Some(Apdu::SimpleAck {
+                invoke_id: 7,
+                service_choice: 16
+            })
+        ));
+        assert!(matches!(present_value(&server), PropertyValue::Real(v) if v == 20.0));
+
+        // Writes stop at the first failure, which is reported
+        let mut service_data = Vec::new();
+        WritePropertyMultipleRequest::new(vec![
+            WriteAccessSpecification::new(
+                valve,
+                vec![write(85, PropertyValue::Real(30.0), Some(8))],
+            ),
+            WriteAccessSpecification::new(
+                valve,
+                vec![
+                    write(79, PropertyValue::Enumerated(0), None),
+                    write(85, PropertyValue::Real(40.0), Some(8)),
+                ],
+            ),
+        ])
+        .encode(&mut service_data)
+        .unwrap();
+        let response = server
+            .handle_apdu(
+                &confirmed(ConfirmedServiceChoice::WritePropertyMultiple, service_data),
+                &source,
+            )
+            .unwrap();
+        let error =
+            WritePropertyMultipleError::from_apdu(&Apdu::decode(&response.encode()).unwrap())
+                .unwrap();
+        assert_eq!(
+            error,
+            WritePropertyMultipleError {
+                error_class: 2,
+                error_code: 40,
+                object_identifier: valve,
+                property_identifier: 79,
+                property_array_index: None,
+            }
+        );
+        assert!(matches!(present_value(&server), PropertyValue::Real(v) if v == 30.0));
+    }
+
+    #[test]
+    fn test_write_priority_array_element() {
+        let mut server = server();
+        let source = BacnetAddress::local(MacAddress::MsTp(5));
+        let valve = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+
+        let mut request =
+            WritePropertyRequest::with_value(valve, 87, &PropertyValue::Real(55.0)).unwrap();
+        request.property_array_index = Some(3);
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data).unwrap();
+        assert!(matches!(
+            server.handle_apdu(
+                &confirmed(ConfirmedServiceChoice::WriteProperty, service_data),
+                &source,
+            ),
+            Some(Apdu::SimpleAck { .. })
+        ));
+
+        let mut request = ReadPropertyRequest::new(valve, 87);
+        request.property_array_index = Some(3);
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data).unwrap();
+        let Some(Apdu::ComplexAck { service_data, .. }) = server.handle_apdu(
+            &confirmed(ConfirmedServiceChoice::ReadProperty, service_data),
+            &source,
+        ) else {
+            panic!("Expected ReadProperty-ACK");
+        };
+        let ack = ReadPropertyAck::decode(&service_data).unwrap();
+        assert_eq!(ack.property_array_index, Some(3));
+        assert!(matches!(ack.value, PropertyValue::Real(v) if v == 55.0));
+
+        // Past the sixteen priorities is an invalid index
+        let mut request = ReadPropertyRequest::new(valve, 87);
+        request.property_array_index = Some(17);
+        let mut service_data = Vec::new();
+        request.encode(&mut service_data).unwrap();
+        assert!(matches!(
+            server.handle_apdu(
+                &confirmed(ConfirmedServiceChoice::ReadProperty, service_data),
+                &source,
+            ),
+            Some(Apdu::Error {
+                error_class: 2,
+                error_code: 42,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_write_hook_rejects_writes() {
+        let mut server =
+            server().with_write_hook(|source: &BacnetAddress, _write: &mut PropertyWrite| {
+                if *source == BacnetAddress::local(MacAddress::MsTp(5)) {
+                    Ok(())
+                } else {
+                    Err(BacnetError::Error {
+                        class: ErrorClass::Security,
+                        code: ErrorCode::PasswordFailure,
+                    })
+                }
+            });
+        let valve = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+        let mut service_data = Vec::new();
+        WritePropertyRequest::with_value(valve, 85, &PropertyValue::Real(10.0))
+            .unwrap()
+            .encode(&mut service_data)
+            .unwrap();
+        let request = confirmed(ConfirmedServiceChoice::WriteProperty, service_data);
+
+        assert!(matches!(
+            server.handle_apdu(&request, &DataLinkAddress::MsTP(6).into()),
+            Some(Apdu::Error { error_class: 4, .. })
+        ));
+        assert!(matches!(
+            server.handle_apdu(&request, &DataLinkAddress::MsTP(5).into()),
+            Some(Apdu::SimpleAck { .. })
+        ));
+    }
+
+    #[test]
+    fn test_time_synchronization_invokes_clock_hook() {
+        let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = received.clone();
+        let mut server = server().with_clock_hook(move |_: &BacnetAddress, time: TimeSync| {
+            log.lock().unwrap().push(time);
+        });
+
+        let now = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2025,
+                month: 10,
+                day: 13,
+                weekday: 1,
+            },
+            crate::object::Time {
+                hour: 12,
+                minute: 0,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+        let source = BacnetAddress::local(MacAddress::MsTp(9));
+
+        let mut service_data = Vec::new();
+        TimeSynchronizationRequest::new(now)
+            .encode(&mut service_data)
+            .unwrap();
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::TimeSynchronization,
+            service_data: service_data.clone(),
+        };
+        assert!(server.handle_apdu(&apdu, &source).is_none());
+
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::UtcTimeSynchronization,
+            service_data,
+        };
+        assert!(server.handle_apdu(&apdu, &source).is_none());
+
+        // Undecodable requests never reach the hook
+        let apdu = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::TimeSynchronization,
+            service_data: vec![0xA4],
+        };
+        assert!(server.handle_apdu(&apdu, &source).is_none());
+
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec![TimeSync::Local(now), TimeSync::Utc(now)]
+        );
+    }
+
+    #[test]
+    fn test_device_communication_control() {
+        let mut server = server().with_password("secret");
+        let source = BacnetAddress::local(MacAddress::MsTp(9));
+        let dcc = |request: DeviceCommunicationControlRequest| {
+            let mut service_data = Vec::new();
+            request.encode(&mut service_data).unwrap();
+            confirmed(
+                ConfirmedServiceChoice::DeviceCommunicationControl,
+                service_data,
+            )
+        };
+        let mut who_is = Vec::new();
+        WhoIsRequest::new().encode(&mut who_is).unwrap();
+        let who_is = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::WhoIs,
+            service_data: who_is,
+        };
+
+        // A wrong password leaves communication enabled
+        let request = DeviceCommunicationControlRequest::new(EnableDisable::Disable, Some(5));
+        assert!(matches!(
+            server.handle_apdu(&dcc(request.clone().with_password("guess")), &source),
+            Some(Apdu::Error {
+                error_class: 4,
+                error_code: 26,
+                ..
+            })
+        ));
+        assert_eq!(server.communication_state(), EnableDisable::Enable);
+
+        assert!(matches!(
+            server.handle_apdu(&dcc(request.with_password("secret")), &source),
+            Some(Apdu::SimpleAck { .. })
+        ));
+        assert_eq!(server.communication_state(), EnableDisable::Disable);
+        assert!(server.handle_apdu(&who_is, &source).is_none());
+
+        // The state lapses once the duration has elapsed
+        server.communication_until = Some(Instant::now());
+        assert_eq!(server.communication_state(), EnableDisable::Enable);
+        assert!(server.handle_apdu(&who_is, &source).is_some());
+
+        // Disable-initiation still answers requests
+        let request =
+            DeviceCommunicationControlRequest::new(EnableDisable::DisableInitiation, None)
+                .with_password("secret");
+        server.handle_apdu(&dcc(request), &source).unwrap();
+        assert_eq!(
+            server.communication_state(),
+            EnableDisable::DisableInitiation
+        );
+        assert!(server.handle_apdu(&who_is, &source).is_some());
+    }
+
+    #[test]
+    fn test_reinitialize_device() {
+        let restarts = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = restarts.clone();
+        let mut server = server().with_password("secret").with_reinitialize_hook(
+            move |state: ReinitializedState| match state {
+                ReinitializedState::ColdStart | ReinitializedState::WarmStart => {
+                    log.lock().unwrap().push(state);
+                    Ok(())
+                }
+                _ => Err(BacnetError::Reject(RejectReason::ParameterOutOfRange)),
+            },
+        );
+        let source = BacnetAddress::local(MacAddress::MsTp(9));
+        let reinitialize = |request: ReinitializeDeviceRequest| {
+            let mut service_data = Vec::new();
+            request.encode(&mut service_data).unwrap();
+            confirmed(ConfirmedServiceChoice::ReinitializeDevice, service_data)
+        };
+
+        assert!(matches!(
+            server.handle_apdu(
+                &reinitialize(ReinitializeDeviceRequest::new(
+                    ReinitializedState::WarmStart
+                )),
+                &source
+            ),
+            Some(Apdu::Error {
+                error_class: 4,
+                error_code: 26,
+                ..
+            })
+        ));
+        assert!(matches!(
+            server.handle_apdu(
+                &reinitialize(
+                    ReinitializeDeviceRequest::new(ReinitializedState::WarmStart)
+                        .with_password("secret")
+                ),
+                &source
+            ),
+            Some(Apdu::SimpleAck { .. })
+        ));
+        assert!(matches!(
+            server.handle_apdu(
+                &reinitialize(
+                    ReinitializeDeviceRequest::new(ReinitializedState::StartBackup)
+                        .with_password("secret")
+                ),
+                &source
+            ),
+            Some(Apdu::Reject {
+                reject_reason: 6,
+                ..
+            })
+        ));
+        // Undefined states are rejected before reaching the hook
+        assert!(matches!(
+            server.handle_apdu(
+                &confirmed(ConfirmedServiceChoice::ReinitializeDevice, vec![0x09, 0x09]),
+                &source
+            ),
+            Some(Apdu::Reject {
+                reject_reason: 8,
+                ..
+            })
+        ));
+        assert_eq!(
+            *restarts.lock().unwrap(),
+            vec![ReinitializedState::WarmStart]
+        );
+    }
+
+    #[test]
+    fn test_acknowledge_alarm_hook() {
+        use crate::object::EventState;
+        use crate::service::TimeStamp;
+
+        let acknowledged = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = acknowledged.clone();
+        let mut server = server().with_alarm_ack_hook(
+            move |_: &BacnetAddress, request: &AcknowledgeAlarmRequest| {
+                if request.time_stamp != TimeStamp::SequenceNumber(4) {
+                    return Err(BacnetError::Error {
+                        class: ErrorClass::Services,
+                        code: ErrorCode::InvalidTimeStamp,
+                    });
+                }
+                log.lock().unwrap().push((
+                    request.event_object_identifier,
+                    request.event_state_acknowledged,
+                ));
+                Ok(())
+            },
+        );
+        let source = BacnetAddress::local(MacAddress::MsTp(9));
+        let acknowledge = |object_id, sequence_number| {
+            let mut service_data = Vec::new();
+            AcknowledgeAlarmRequest::new(
+                1,
+                object_id,
+                EventState::HighLimit,
+                TimeStamp::SequenceNumber(sequence_number),
+                "operator",
+                TimeStamp::SequenceNumber(5),
+            )
+            .encode(&mut service_data)
+            .unwrap();
+            confirmed(ConfirmedServiceChoice::AcknowledgeAlarm, service_data)
+        };
+        let valve = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+
+        assert!(matches!(
+            server.handle_apdu(&acknowledge(valve, 4), &source),
+            Some(Apdu::SimpleAck {
+                service_choice: 0,
+                ..
+            })
+        ));
+        assert!(matches!(
+            server.handle_apdu(&acknowledge(valve, 3), &source),
+            Some(Apdu::Error {
+                error_class: 5,
+                error_code: 14,
+                ..
+            })
+        ));
+        // Unknown objects never reach the hook
+        assert!(matches!(
+            server.handle_apdu(
+                &acknowledge(ObjectIdentifier::new(ObjectType::AnalogInput, 8), 4),
+                &source
+            ),
+            Some(Apdu::Error {
+                error_class: 1,
+                error_code: 31,
+                ..
+            })
+        ));
+        assert_eq!(
+            *acknowledged.lock().unwrap(),
+            vec![(valve, EventState::HighLimit)]
+        );
+    }
+
+    #[test]
+    fn test_private_transfer_hooks() {
+        let notified = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let log = notified.clone();
+        let mut server = server()
+            .with_private_transfer_hook(260, 7, |_: &BacnetAddress, parameters: Option<&[u8]>| {
+                let mut result = parameters.unwrap_or_default().to_vec();
+                result.extend_from_slice(&[0x21, 0x03]);
+                Ok(Some(result))
+            })
+            .with_private_transfer_hook(
+                260,
+                8,
+                move |_: &BacnetAddress, parameters: Option<&[u8]>| {
+                    log.lock().unwrap().push(parameters.map(<[u8]>::to_vec));
+                    Ok(None)
+                },
+            );
+        let source = BacnetAddress::local(MacAddress::MsTp(5));
+
+        let mut service_data = Vec::new();
+        PrivateTransferRequest::new(260, 7)
+            .with_parameters(vec![0x21, 0x01, 0x21, 0x02])
+            .encode(&mut service_data)
+            .unwrap();
+        let Some(Apdu::ComplexAck { service_data, .. }) = server.handle_apdu(
+            &confirmed(
+                ConfirmedServiceChoice::ConfirmedPrivateTransfer,
+                service_data,
+            ),
+            &source,
+        ) else {
+            panic!("Expected ConfirmedPrivateTransfer-ACK");
+        };
+        assert_eq!(
+            ConfirmedPrivateTransferAck::decode(&service_data).unwrap(),
+            ConfirmedPrivateTransferAck {
+                vendor_id: 260,
+                service_number: 7,
+                result_block: Some(vec![0x21, 0x01, 0x21, 0x02, 0x21, 0x03]),
+            }
+        );
+
+        let mut service_data = Vec::new();
+        PrivateTransferRequest::new(260, 8)
+            .encode(&mut service_data)
+            .unwrap();
+        let notification = Apdu::UnconfirmedRequest {
+            service_choice: UnconfirmedServiceChoice::UnconfirmedPrivateTransfer,
+            service_data,
+        };
+        assert!(server.handle_apdu(&notification, &source).is_none());
+        assert_eq!(*notified.lock().unwrap(), vec![None]);
+
+        // Services without a hook are refused with a ConfirmedPrivateTransfer-Error
+        let mut service_data = Vec::new();
+        PrivateTransferRequest::new(99, 1)
+            .encode(&mut service_data)
+            .unwrap();
+        let response = server
+            .handle_apdu(
+                &confirmed(
+                    ConfirmedServiceChoice::ConfirmedPrivateTransfer,
+                    service_data,
+                ),
+                &source,
+            )
+            .unwrap();
+        let error = ConfirmedPrivateTransferError::from_apdu(&response).unwrap();
+        assert_eq!((error.vendor_id, error.service_number), (99, 1));
+        assert!(matches!(
+            error.error(),
+            BacnetError::Error {
+                class: ErrorClass::Services,
+                code: ErrorCode::OptionalFunctionalityNotSupported
+            }
+        ));
+    }
+}
@@ -90,7 +90,7 @@
 //!
 //! // Create a read property request
 //! let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
-//! let request = ReadPropertyRequest::new(object_id, PropertyIdentifier::PresentValue as u32);
+//! let request = ReadPropertyRequest::new(object_id, PropertyIdentifier::PresentValue.as_u32());
 //!
 //! // This would be sent as a confirmed service
 //! let service_choice = ConfirmedServiceChoice::ReadProperty;
@@ -117,9 +117,9 @@
 //! // Create a read property multiple request
 //! let object_id = ObjectIdentifier::new(ObjectType::Device, 12345);
 //! let property_refs = vec![
-//!     PropertyReference::new(PropertyIdentifier::ObjectName as u32),
+//!     PropertyReference::new(PropertyIdentifier::ObjectName.as_u32()),
 //!     PropertyReference::new(70), // ModelName
-//!     PropertyReference::new(PropertyIdentifier::VendorName as u32),
+//!     PropertyReference::new(PropertyIdentifier::VendorName.as_u32()),
 //! ];
 //! let spec = ReadAccessSpecification::new(object_id, property_refs);
 //!
@@ -170,7 +170,11 @@ use std::fmt;
 use core::fmt;
 
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 
 /// Result type for service operations
 #[cfg(feature = "std")]
@@ -223,6 +227,7 @@ impl Error for ServiceError {}
 pub enum ConfirmedServiceChoice {
     // Alarm and Event Services
     AcknowledgeAlarm = 0,
+    ConfirmedCOVNotification = 1,
     ConfirmedEventNotification = 2,
     GetAlarmSummary = 3,
     GetEnrollmentSummary = 4,
@@ -244,6 +249,7 @@ pub enum ConfirmedServiceChoice {
 
     // Remote Device Management Services
     DeviceCommunicationControl = 17,
+    ConfirmedPrivateTransfer = 18,
     ReinitializeDevice = 20,
 
     // Virtual Terminal Services
@@ -270,6 +276,7 @@ impl TryFrom<u8> for ConfirmedServiceChoice {
     fn try_from(value: u8) -> Result<Self> {
         match value {
             0 => Ok(Self::AcknowledgeAlarm),
+            1 => Ok(Self::ConfirmedCOVNotification),
             2 => Ok(Self::ConfirmedEventNotification),
             3 => Ok(Self::GetAlarmSummary),
             4 => Ok(Self::GetEnrollmentSummary),
@@ -285,6 +292,7 @@ impl TryFrom<u8> for ConfirmedServiceChoice {
             15 => Ok(Self::WriteProperty),
             16 => Ok(Self::WritePropertyMultiple),
             17 => Ok(Self::DeviceCommunicationControl),
+            18 => Ok(Self::ConfirmedPrivateTransfer),
             20 => Ok(Self::ReinitializeDevice),
             21 => Ok(Self::VtOpen),
             22 => Ok(Self::VtClose),
@@ -360,6 +368,24 @@ pub enum RejectReason {
     UnrecognizedService = 9,
 }
 
+impl From<u8> for RejectReason {
+    /// Map a reject reason; proprietary and unknown reasons map to `Other`
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::BufferOverflow,
+            2 => Self::InconsistentParameters,
+            3 => Self::InvalidParameterDataType,
+            4 => Self::InvalidTag,
+            5 => Self::MissingRequiredParameter,
+            6 => Self::ParameterOutOfRange,
+            7 => Self::TooManyArguments,
+            8 => Self::UndefinedEnumeration,
+            9 => Self::UnrecognizedService,
+            _ => Self::Other,
+        }
+    }
+}
+
 /// Abort reason codes
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AbortReason {
@@ -368,19 +394,359 @@ pub enum AbortReason {
     InvalidApduInThisState = 2,
     PreemptedByHigherPriorityTask = 3,
     SegmentationNotSupported = 4,
+    SecurityError = 5,
+    InsufficientSecurity = 6,
+    WindowSizeOutOfRange = 7,
+    ApplicationExceededReplyTime = 8,
+    OutOfResources = 9,
+    TsmTimeout = 10,
+    ApduTooLong = 11,
+}
+
+impl From<u8> for AbortReason {
+    /// Map an abort reason; proprietary and unknown reasons map to `Other`
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::BufferOverflow,
+            2 => Self::InvalidApduInThisState,
+            3 => Self::PreemptedByHigherPriorityTask,
+            4 => Self::SegmentationNotSupported,
+            5 => Self::SecurityError,
+            6 => Self::InsufficientSecurity,
+            7 => Self::WindowSizeOutOfRange,
+            8 => Self::ApplicationExceededReplyTime,
+            9 => Self::OutOfResources,
+            10 => Self::TsmTimeout,
+            11 => Self::ApduTooLong,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Error class codes carried in Error PDUs and per-property results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ErrorClass {
+    Device = 0,
+    Object = 1,
+    Property = 2,
+    Resources = 3,
+    Security = 4,
+    Services = 5,
+    Vt = 6,
+    Communication = 7,
+}
+
+impl TryFrom<u32> for ErrorClass {
+    type Error = EncodingError;
+
+    fn try_from(value: u32) -> EncodingResult<Self> {
+        match value {
+            0 => Ok(Self::Device),
+            1 => Ok(Self::Object),
+            2 => Ok(Self::Property),
+            3 => Ok(Self::Resources),
+            4 => Ok(Self::Security),
+            5 => Ok(Self::Services),
+            6 => Ok(Self::Vt),
+            7 => Ok(Self::Communication),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+}
+
+/// Error code values carried in Error PDUs and per-property results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    Other,
+    AuthenticationFailed,
+    ConfigurationInProgress,
+    DeviceBusy,
+    DynamicCreationNotSupported,
+    FileAccessDenied,
+    IncompatibleSecurityLevels,
+    InconsistentParameters,
+    InconsistentSelectionCriterion,
+    InvalidDataType,
+    InvalidFileAccessMethod,
+    InvalidFileStartPosition,
+    InvalidOperatorName,
+    InvalidParameterDataType,
+    InvalidTimeStamp,
+    KeyGenerationError,
+    MissingRequiredParameter,
+    NoObjectsOfSpecifiedType,
+    NoSpaceForObject,
+    NoSpaceToAddListElement,
+    NoSpaceToWriteProperty,
+    NoVtSessionsAvailable,
+    PropertyIsNotAList,
+    ObjectDeletionNotPermitted,
+    ObjectIdentifierAlreadyExists,
+    OperationalProblem,
+    PasswordFailure,
+    ReadAccessDenied,
+    SecurityNotSupported,
+    ServiceRequestDenied,
+    Timeout,
+    UnknownObject,
+    UnknownProperty,
+    UnknownVtClass,
+    UnknownVtSession,
+    UnsupportedObjectType,
+    ValueOutOfRange,
+    VtSessionAlreadyClosed,
+    VtSessionTerminationFailure,
+    WriteAccessDenied,
+    CharacterSetNotSupported,
+    InvalidArrayIndex,
+    CovSubscriptionFailed,
+    NotCovProperty,
+    OptionalFunctionalityNotSupported,
+    InvalidConfigurationData,
+    DatatypeNotSupported,
+    DuplicateName,
+    DuplicateObjectId,
+    PropertyIsNotAnArray,
+    InvalidEventState,
+    /// Error code without a variant here, including proprietary codes
+    Unknown(u32),
+}
+
+impl From<u32> for ErrorCode {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => Self::Other,
+            1 => Self::AuthenticationFailed,
+            2 => Self::ConfigurationInProgress,
+            3 => Self::DeviceBusy,
+            4 => Self::DynamicCreationNotSupported,
+            5 => Self::FileAccessDenied,
+            6 => Self::IncompatibleSecurityLevels,
+            7 => Self::InconsistentParameters,
+            8 => Self::InconsistentSelectionCriterion,
+            9 => Self::InvalidDataType,
+            10 => Self::InvalidFileAccessMethod,
+            11 => Self::InvalidFileStartPosition,
+            12 => Self::InvalidOperatorName,
+            13 => Self::InvalidParameterDataType,
+            14 => Self::InvalidTimeStamp,
+            15 => Self::KeyGenerationError,
+            16 => Self::MissingRequiredParameter,
+            17 => Self::NoObjectsOfSpecifiedType,
+            18 => Self::NoSpaceForObject,
+            19 => Self::NoSpaceToAddListElement,
+            20 => Self::NoSpaceToWriteProperty,
+            21 => Self::NoVtSessionsAvailable,
+            22 => Self::PropertyIsNotAList,
+            23 => Self::ObjectDeletionNotPermitted,
+            24 => Self::ObjectIdentifierAlreadyExists,
+            25 => Self::OperationalProblem,
+            26 => Self::PasswordFailure,
+            27 => Self::ReadAccessDenied,
+            28 => Self::SecurityNotSupported,
+            29 => Self::ServiceRequestDenied,
+            30 => Self::Timeout,
+            31 => Self::UnknownObject,
+            32 => Self::UnknownProperty,
+            34 => Self::UnknownVtClass,
+            35 => Self::UnknownVtSession,
+            36 => Self::UnsupportedObjectType,
+            37 => Self::ValueOutOfRange,
+            38 => Self::VtSessionAlreadyClosed,
+            39 => Self::VtSessionTerminationFailure,
+            40 => Self::WriteAccessDenied,
+            41 => Self::CharacterSetNotSupported,
+            42 => Self::InvalidArrayIndex,
+            43 => Self::CovSubscriptionFailed,
+            44 => Self::NotCovProperty,
+            45 => Self::OptionalFunctionalityNotSupported,
+            46 => Self::InvalidConfigurationData,
+            47 => Self::DatatypeNotSupported,
+            48 => Self::DuplicateName,
+            49 => Self::DuplicateObjectId,
+            50 => Self::PropertyIsNotAnArray,
+            73 => Self::InvalidEventState,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl From<ErrorCode> for u32 {
+    fn from(code: ErrorCode) -> Self {
+        match code {
+            ErrorCode::Other => 0,
+            ErrorCode::AuthenticationFailed => 1,
+            ErrorCode::ConfigurationInProgress => 2,
+            ErrorCode::DeviceBusy => 3,
+            ErrorCode::DynamicCreationNotSupported => 4,
+            ErrorCode::FileAccessDenied => 5,
+            ErrorCode::IncompatibleSecurityLevels => 6,
+            ErrorCode::InconsistentParameters => 7,
+            ErrorCode::InconsistentSelectionCriterion => 8,
+            ErrorCode::InvalidDataType => 9,
+            ErrorCode::InvalidFileAccessMethod => 10,
+            ErrorCode::InvalidFileStartPosition => 11,
+            ErrorCode::InvalidOperatorName => 12,
+            ErrorCode::InvalidParameterDataType => 13,
+            ErrorCode::InvalidTimeStamp => 14,
+            ErrorCode::KeyGenerationError => 15,
+            ErrorCode::MissingRequiredParameter => 16,
+            ErrorCode::NoObjectsOfSpecifiedType => 17,
+            ErrorCode::NoSpaceForObject => 18,
+            ErrorCode::NoSpaceToAddListElement => 19,
+            ErrorCode::NoSpaceToWriteProperty => 20,
+            ErrorCode::NoVtSessionsAvailable => 21,
+            ErrorCode::PropertyIsNotAList => 22,
+            ErrorCode::ObjectDeletionNotPermitted => 23,
+            ErrorCode::ObjectIdentifierAlreadyExists => 24,
+            ErrorCode::OperationalProblem => 25,
+            ErrorCode::PasswordFailure => 26,
+            ErrorCode::ReadAccessDenied => 27,
+            ErrorCode::SecurityNotSupported => 28,
+            ErrorCode::ServiceRequestDenied => 29,
+            ErrorCode::Timeout => 30,
+            ErrorCode::UnknownObject => 31,
+            ErrorCode::UnknownProperty => 32,
+            ErrorCode::UnknownVtClass => 34,
+            ErrorCode::UnknownVtSession => 35,
+            ErrorCode::UnsupportedObjectType => 36,
+            ErrorCode::ValueOutOfRange => 37,
+            ErrorCode::VtSessionAlreadyClosed => 38,
+            ErrorCode::VtSessionTerminationFailure => 39,
+            ErrorCode::WriteAccessDenied => 40,
+            ErrorCode::CharacterSetNotSupported => 41,
+            ErrorCode::InvalidArrayIndex => 42,
+            ErrorCode::CovSubscriptionFailed => 43,
+            ErrorCode::NotCovProperty => 44,
+            ErrorCode::OptionalFunctionalityNotSupported => 45,
+            ErrorCode::InvalidConfigurationData => 46,
+            ErrorCode::DatatypeNotSupported => 47,
+            ErrorCode::DuplicateName => 48,
+            ErrorCode::DuplicateObjectId => 49,
+            ErrorCode::PropertyIsNotAnArray => 50,
+            ErrorCode::InvalidEventState => 73,
+            ErrorCode::Unknown(value) => value,
+        }
+    }
+}
+
+/// Negative response to a confirmed request
+///
+/// Decoded from the Error, Reject and Abort PDUs so that a refusal by the
+/// device can be told apart from a transport failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BacnetError {
+    /// Error PDU with a standard error class
+    Error {
+        /// Error class
+        class: ErrorClass,
+        /// Error code
+        code: ErrorCode,
+    },
+    /// Error PDU with a proprietary error class (64 and above)
+    ProprietaryError {
+        /// Raw error class
+        class: u32,
+        /// Raw error code
+        code: u32,
+    },
+    /// Reject PDU
+    Reject(RejectReason),
+    /// Abort PDU
+    Abort(AbortReason),
+}
+
+impl BacnetError {
+    /// Build from the raw error class and code of an Error PDU
+    pub fn from_error(class: u32, code: u32) -> Self {
+        match ErrorClass::try_from(class) {
+            Ok(class) => BacnetError::Error {
+                class,
+                code: ErrorCode::from(code),
+            },
+            Err(_) => BacnetError::ProprietaryError { class, code },
+        }
+    }
+}
+
+impl fmt::Display for BacnetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BacnetError::Error { class, code } => write!(f, "Error: {:?}, {:?}", class, code),
+            BacnetError::ProprietaryError { class, code } => {
+                write!(f, "Error: class {}, code {}", class, code)
+            }
+            BacnetError::Reject(reason) => write!(f, "Rejected: {:?}", reason),
+            BacnetError::Abort(reason) => write!(f, "Aborted: {:?}", reason),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for BacnetError {}
+
+impl From<ObjectError> for BacnetError {
+    /// Error class and code a server reports for a failed object access
+    fn from(error: ObjectError) -> Self {
+        let (class, code) = match error {
+            ObjectError::NotFound | ObjectError::InstanceNotFound => {
+                (ErrorClass::Object, ErrorCode::UnknownObject)
+            }
+            ObjectError::TypeNotSupported => (ErrorClass::Object, ErrorCode::UnsupportedObjectType),
+            ObjectError::PropertyNotFound | ObjectError::UnknownProperty => {
+                (ErrorClass::Property, ErrorCode::UnknownProperty)
+            }
+            ObjectError::PropertyNotWritable | ObjectError::WriteAccessDenied => {
+                (ErrorClass::Property, ErrorCode::WriteAccessDenied)
+            }
+            ObjectError::InvalidPropertyType => (ErrorClass::Property, ErrorCode::InvalidDataType),
+            ObjectError::InvalidValue(_) | ObjectError::InvalidObjectName(_) => {
+                (ErrorClass::Property, ErrorCode::ValueOutOfRange)
+            }
+            ObjectError::InvalidConfiguration(_) => {
+                (ErrorClass::Property, ErrorCode::InvalidConfigurationData)
+            }
+            ObjectError::DuplicateObjectName(_) => (ErrorClass::Property, ErrorCode::DuplicateName),
+            ObjectError::InvalidArrayIndex => (ErrorClass::Property, ErrorCode::InvalidArrayIndex),
+            ObjectError::PropertyIsNotAnArray => {
+                (ErrorClass::Property, ErrorCode::PropertyIsNotAnArray)
+            }
+            ObjectError::InvalidFileStartPosition => {
+                (ErrorClass::Services, ErrorCode::InvalidFileStartPosition)
+            }
+        };
+        BacnetError::Error { class, code }
+    }
 }
 
+use crate::app::{Apdu, MaxApdu};
 use crate::encoding::{
+    self,
+    advanced::context::{encode_closing_tag, encode_opening_tag},
     decode_context_enumerated, decode_context_object_id, decode_context_unsigned,
-    decode_enumerated, decode_object_identifier, decode_unsigned, encode_context_enumerated,
-    encode_context_object_id, encode_context_unsigned, encode_enumerated, encode_object_identifier,
-    encode_unsigned, Result as EncodingResult,
+    decode_enumerated, decode_object_identifier, decode_tag, decode_unsigned,
+    encode_context_enumerated, encode_context_object_id, encode_context_unsigned,
+    encode_enumerated, encode_object_identifier, encode_unsigned, ApplicationTag, DecodeLimits,
+    EncodingError, Result as EncodingResult, TagClass,
+};
+use crate::object::{
+    BacnetObject, EventState, ObjectError, ObjectIdentifier, ObjectType, PropertyIdentifier,
+    PropertyValue, Segmentation,
 };
-use crate::object::{ObjectIdentifier, PropertyValue};
 
 /// Special array index value indicating all elements
 pub const BACNET_ARRAY_ALL: u32 = 0xFFFFFFFF;
 
+/// Special property identifier requesting all properties (ReadPropertyMultiple)
+pub const PROPERTY_ALL: u32 = 8;
+
+/// Special property identifier requesting optional properties (ReadPropertyMultiple)
+pub const PROPERTY_OPTIONAL: u32 = 80;
+
+/// Special property identifier requesting required properties (ReadPropertyMultiple)
+pub const PROPERTY_REQUIRED: u32 = 105;
+
 /// Who-Is request (unconfirmed service)
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct WhoIsRequest {
@@ -449,17 +815,15 @@ impl WhoIsRequest {
                     pos += consumed;
 
                     // If we have low limit, we must have high limit
-                    if pos < data.len() {
-                        match decode_context_unsigned(&data[pos..], 1) {
-                            Ok((high, _consumed)) => {
-                                request.device_instance_range_high_limit = Some(high);
-                            }
-                            Err(_) => {
-                                // Invalid format - low without high
-                                return Err(crate::encoding::EncodingError::InvalidFormat(
-                                    "Who-Is request has low limit without high limit".to_string(),
-                                ));
-                            }
+                    match decode_context_unsigned(&data[pos..], 1) {
+                        Ok((high, _consumed)) => {
+                            request.device_instance_range_high_limit = Some(high);
+                        }
+                        Err(_) => {
+                            // Invalid format - low without high
+                            return Err(crate::encoding::EncodingError::InvalidFormat(
+                                "Who-Is request has low limit without high limit".to_string(),
+                            ));
                         }
                     }
                 }
@@ -494,7 +858,7 @@ pub struct IAmRequest {
     /// Maximum APDU length accepted
     pub max_apdu_length_accepted: u32,
     /// Segmentation supported
-    pub segmentation_supported: u32,
+    pub segmentation_supported: Segmentation,
     /// Vendor identifier
     pub vendor_identifier: u32,
 }
@@ -504,7 +868,7 @@ impl IAmRequest {
     pub fn new(
         device_identifier: ObjectIdentifier,
         max_apdu_length_accepted: u32,
-        segmentation_supported: u32,
+        segmentation_supported: Segmentation,
         vendor_identifier: u32,
     ) -> Self {
         Self {
@@ -515,12 +879,17 @@ impl IAmRequest {
         }
     }
 
+    /// Get the largest standard APDU size within the advertised length
+    pub fn max_apdu(&self) -> MaxApdu {
+        MaxApdu::from_length(self.max_apdu_length_accepted)
+    }
+
     /// Encode the I-Am request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
         // Device identifier (object identifier) - application tag
         encode_object_identifier(
             buffer,
-            self.device_identifier.object_type as u16,
+            self.device_identifier.object_type.as_u16(),
             self.device_identifier.instance,
         )?;
 
@@ -528,7 +897,7 @@ impl IAmRequest {
         encode_unsigned(buffer, self.max_apdu_length_accepted)?;
 
         // Segmentation supported - application tag (enumerated)
-        encode_enumerated(buffer, self.segmentation_supported)?;
+        encode_enumerated(buffer, self.segmentation_supported as u32)?;
 
         // Vendor identifier - application tag
         encode_unsigned(buffer, self.vendor_identifier)?;
@@ -542,11 +911,7 @@ impl IAmRequest {
 
         // Decode device identifier - application tag
         let ((object_type, instance), consumed) = decode_object_identifier(&data[pos..])?;
-        let device_identifier = ObjectIdentifier {
-            object_type: crate::object::ObjectType::try_from(object_type)
-                .unwrap_or(crate::object::ObjectType::Device),
-            instance,
-        };
+        let device_identifier = object_identifier_from_parts(object_type, instance)?;
         pos += consumed;
 
         // Decode max APDU length accepted - application tag
@@ -554,7 +919,9 @@ impl IAmRequest {
         pos += consumed;
 
         // Decode segmentation supported - application tag (enumerated)
-        let (segmentation_supported, consumed) = decode_enumerated(&data[pos..])?;
+        let (segmentation, consumed) = decode_enumerated(&data[pos..])?;
+        let segmentation_supported =
+            Segmentation::try_from(segmentation).map_err(|_| EncodingError::ValueOutOfRange)?;
         pos += consumed;
 
         // Decode vendor identifier - application tag
@@ -569,6 +936,143 @@ impl IAmRequest {
     }
 }
 
+/// Build an object identifier from decoded (type, instance) parts
+fn object_identifier_from_parts(
+    object_type: u16,
+    instance: u32,
+) -> EncodingResult<ObjectIdentifier> {
+    let object_type =
+        ObjectType::try_from(object_type).map_err(|_| EncodingError::ValueOutOfRange)?;
+    Ok(ObjectIdentifier::new(object_type, instance))
+}
+
+/// Find the closing tag matching the opening tag at the start of `data`
+///
+/// Returns the offset of the closing tag. Nested constructed values and
+/// primitive contents are skipped by walking tag headers, so content bytes
+/// that happen to look like a closing tag are not mistaken for one.
+fn find_closing_tag(data: &[u8], tag_number: u8) -> EncodingResult<usize> {
+    let (opening, mut pos) = decode_tag(data)?;
+    if !opening.is_opening || opening.number != tag_number {
+        return Err(EncodingError::InvalidTag);
+    }
+
+    let mut depth = 1usize;
+    while pos < data.len() {
+        let (tag, header) = decode_tag(&data[pos..])?;
+        if tag.is_opening {
+            depth += 1;
+        } else if tag.is_closing {
+            depth -= 1;
+            if depth == 0 {
+                return if tag.number == tag_number {
+                    Ok(pos)
+                } else {
+                    Err(EncodingError::InvalidTag)
+                };
+            }
+        }
+        pos += header;
+        // Application booleans keep their value in the length field
+        let is_boolean =
+            tag.class == TagClass::Application && tag.number == ApplicationTag::Boolean as u8;
+        if !tag.is_opening && !tag.is_closing && !is_boolean {
+            pos += tag.length;
+        }
+    }
+
+    Err(EncodingError::UnexpectedEndOfData)
+}
+
+/// Encode a property value wrapped in opening/closing context tags
+fn encode_property_value(
+    buffer: &mut Vec<u8>,
+    tag_number: u8,
+    value: &PropertyValue,
+) -> EncodingResult<()> {
+    encode_opening_tag(buffer, tag_number)?;
+    value.encode(buffer)?;
+    encode_closing_tag(buffer, tag_number)
+}
+
+/// Decode a property value wrapped in opening/closing context tags
+///
+/// `list` says whether the value is a whole array or list, as given by
+/// [`is_whole_list`].
+fn decode_property_value(
+    data: &[u8],
+    tag_number: u8,
+    list: bool,
+    limits: &DecodeLimits,
+) -> EncodingResult<(PropertyValue, usize)> {
+    let closing = find_closing_tag(data, tag_number)?;
+    let value = decode_property_contents(&data[1..closing], list, limits)?;
+    Ok((value, closing + 1))
+}
+
+/// Whether a property reference addresses a whole array or list
+fn is_whole_list(property_identifier: u32, property_array_index: Option<u32>) -> bool {
+    property_array_index.is_none()
+        && PropertyIdentifier::from(property_identifier).is_array_or_list()
+}
+
+/// Decode the application-tagged contents of a property value
+///
+/// Several elements decode to `PropertyValue::Array`. When `list` is set
+/// the contents always decode to an array, so an empty list or a list of
+/// one element keeps its shape; otherwise a single element decodes to that
+/// value and empty contents are an error. Contents holding context tags are
+/// kept whole as `PropertyValue::Constructed`.
+fn decode_property_contents(
+    data: &[u8],
+    list: bool,
+    limits: &DecodeLimits,
+) -> EncodingResult<PropertyValue> {
+    let mut pos = 0;
+    let mut values = Vec::new();
+    while pos < data.len() {
+        if matches!(decode_tag(&data[pos..]), Ok((tag, _)) if tag.class == TagClass::Context) {
+            return Ok(PropertyValue::Constructed(data.to_vec()));
+        }
+        let (value, consumed) = PropertyValue::decode(&data[pos..])?;
+        limits.check_elements(values.len() + 1)?;
+        values.push(value);
+        pos += consumed;
+    }
+
+    match values.len() {
+        _ if list => Ok(PropertyValue::Array(values)),
+        0 => Err(EncodingError::UnexpectedEndOfData),
+        1 => Ok(values.remove(0)),
+        _ => Ok(PropertyValue::Array(values)),
+    }
+}
+
+/// Check whether `data` starts with a primitive context tag of the given number
+fn is_context_tag(data: &[u8], tag_number: u8) -> bool {
+    matches!(
+        decode_tag(data),
+        Ok((tag, _)) if tag.class == TagClass::Context
+            && tag.number == tag_number
+            && !tag.is_opening
+            && !tag.is_closing
+    )
+}
+
+/// Check whether `data` starts with an opening tag of the given number
+fn is_opening_tag(data: &[u8], tag_number: u8) -> bool {
+    matches!(decode_tag(data), Ok((tag, _)) if tag.is_opening && tag.number == tag_number)
+}
+
+/// Check a command priority is within 1-16
+fn validate_priority(priority: u8) -> EncodingResult<()> {
+    if (1..=16).contains(&priority) {
+        Ok(())
+    } else {
+        Err(EncodingError::ValueOutOfRange)
+    }
+}
+
 /// Read Property request (confirmed service)
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadPropertyRequest {
@@ -607,7 +1111,7 @@ impl ReadPropertyRequest {
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
         // Object identifier - context tag 0
         let obj_id_bytes = encode_context_object_id(
-            self.object_identifier.object_type as u16,
+            self.object_identifier.object_type.as_u16(),
             self.object_identifier.instance,
             0,
         )?;
@@ -625,6 +1129,34 @@ impl ReadPropertyRequest {
 
         Ok(())
     }
+
+    /// Decode a Read Property request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Object identifier - context tag 0
+        let ((object_type, instance), consumed) = decode_context_object_id(data, 0)?;
+        let object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Property identifier - context tag 1
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Property array index - context tag 2 (optional)
+        let property_array_index = if pos < data.len() {
+            let (array_index, _) = decode_context_unsigned(&data[pos..], 2)?;
+            Some(array_index)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            object_identifier,
+            property_identifier,
+            property_array_index,
+        })
+    }
 }
 
 /// Read Property response (confirmed service)
@@ -689,29 +1221,104 @@ impl ReadPropertyResponse {
         if pos >= data.len() || data[pos] != 0x3E {
             return Err(crate::encoding::EncodingError::InvalidTag);
         }
-        pos += 1;
+        let value_end = pos + find_closing_tag(&data[pos..], 3)?;
+        let property_value = data[pos + 1..value_end].to_vec();
 
-        // Find closing tag
-        let value_start = pos;
-        let mut value_end = pos;
-        while value_end < data.len() {
-            if data[value_end] == 0x3F {
-                break;
-            }
-            value_end += 1;
+        Ok(ReadPropertyResponse {
+            object_identifier,
+            property_identifier,
+            property_array_index,
+            property_value,
+        })
+    }
+}
+
+/// Read Property acknowledgement with a decoded value (confirmed service)
+#[derive(Debug, Clone)]
+pub struct ReadPropertyAck {
+    /// Object identifier that was read
+    pub object_identifier: ObjectIdentifier,
+    /// Property identifier that was read
+    pub property_identifier: u32,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Property value
+    pub value: PropertyValue,
+}
+
+impl ReadPropertyAck {
+    /// Create a new Read Property acknowledgement
+    pub fn new(
+        object_identifier: ObjectIdentifier,
+        property_identifier: u32,
+        value: PropertyValue,
+    ) -> Self {
+        Self {
+            object_identifier,
+            property_identifier,
+            property_array_index: None,
+            value,
         }
+    }
 
-        if value_end >= data.len() {
-            return Err(crate::encoding::EncodingError::InvalidTag);
+    /// Encode the Read Property acknowledgement
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.object_identifier.object_type.as_u16(),
+            self.object_identifier.instance,
+            0,
+        )?);
+
+        // Property identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(self.property_identifier, 1)?);
+
+        // Property array index - context tag 2 (only when present)
+        if let Some(array_index) = self.property_array_index {
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 2)?);
         }
 
-        let property_value = data[value_start..value_end].to_vec();
+        // Property value - context tag 3
+        encode_property_value(buffer, 3, &self.value)
+    }
+
+    /// Decode a Read Property acknowledgement
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        Self::decode_with_limits(data, &DecodeLimits::default())
+    }
 
-        Ok(ReadPropertyResponse {
+    /// Decode a Read Property acknowledgement, bounding the elements of an
+    /// array or list value
+    pub fn decode_with_limits(data: &[u8], limits: &DecodeLimits) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Object identifier - context tag 0
+        let ((object_type, instance), consumed) = decode_context_object_id(data, 0)?;
+        let object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Property identifier - context tag 1
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Property array index - context tag 2 (optional)
+        let property_array_index = match decode_context_unsigned(&data[pos..], 2) {
+            Ok((array_index, consumed)) => {
+                pos += consumed;
+                Some(array_index)
+            }
+            Err(_) => None,
+        };
+
+        // Property value - context tag 3
+        let list = is_whole_list(property_identifier, property_array_index);
+        let (value, _) = decode_property_value(&data[pos..], 3, list, limits)?;
+
+        Ok(Self {
             object_identifier,
             property_identifier,
             property_array_index,
-            property_value,
+            value,
         })
     }
 }
@@ -779,38 +1386,57 @@ impl WritePropertyRequest {
         }
     }
 
+    /// Create a new Write Property request from a typed value
+    pub fn with_value(
+        object_identifier: ObjectIdentifier,
+        property_identifier: u32,
+        value: &PropertyValue,
+    ) -> EncodingResult<Self> {
+        let mut property_value = Vec::new();
+        value.encode(&mut property_value)?;
+        Ok(Self::new(
+            object_identifier,
+            property_identifier,
+            property_value,
+        ))
+    }
+
+    /// Decode the raw property value into a typed value
+    pub fn value(&self) -> EncodingResult<PropertyValue> {
+        let list = is_whole_list(self.property_identifier, self.property_array_index);
+        decode_property_contents(&self.property_value, list, &DecodeLimits::default())
+    }
+
     /// Encode the Write Property request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
         // Object identifier - context tag 0
-        let object_id = crate::util::encode_object_id(
-            self.object_identifier.object_type as u16,
+        let obj_id_bytes = encode_context_object_id(
+            self.object_identifier.object_type.as_u16(),
             self.object_identifier.instance,
-        )
-        .ok_or(crate::encoding::EncodingError::InvalidFormat(
-            "Invalid object identifier".to_string(),
-        ))?;
-        buffer.push(0x0C); // Context tag 0, length 4
-        buffer.extend_from_slice(&object_id.to_be_bytes());
+            0,
+        )?;
+        buffer.extend_from_slice(&obj_id_bytes);
 
-        // Property identifier - context tag 1
-        buffer.push(0x19); // Context tag 1, length 1
-        buffer.push(self.property_identifier as u8);
+        // Property identifier - context tag 1 (as enumerated)
+        let prop_id_bytes = encode_context_enumerated(self.property_identifier, 1)?;
+        buffer.extend_from_slice(&prop_id_bytes);
 
         // Property array index - context tag 2 (optional)
         if let Some(array_index) = self.property_array_index {
-            buffer.push(0x29); // Context tag 2, length 1
-            buffer.push(array_index as u8);
+            let array_bytes = encode_context_unsigned(array_index, 2)?;
+            buffer.extend_from_slice(&array_bytes);
         }
 
-        // Property value - context tag 3 (opening tag)
-        buffer.push(0x3E); // Context tag 3, opening tag
+        // Property value - context tag 3
+        encode_opening_tag(buffer, 3)?;
         buffer.extend_from_slice(&self.property_value);
-        buffer.push(0x3F); // Context tag 3, closing tag
+        encode_closing_tag(buffer, 3)?;
 
         // Priority - context tag 4 (optional)
         if let Some(priority) = self.priority {
-            buffer.push(0x49); // Context tag 4, length 1
-            buffer.push(priority);
+            validate_priority(priority)?;
+            let priority_bytes = encode_context_unsigned(priority as u32, 4)?;
+            buffer.extend_from_slice(&priority_bytes);
         }
 
         Ok(())
@@ -820,71 +1446,35 @@ impl WritePropertyRequest {
     pub fn decode(data: &[u8]) -> EncodingResult<Self> {
         let mut pos = 0;
 
-        // Decode object identifier - context tag 0
-        if pos + 5 > data.len() || data[pos] != 0x0C {
-            return Err(crate::encoding::EncodingError::InvalidTag);
-        }
-        pos += 1;
+        // Object identifier - context tag 0
+        let ((object_type, instance), consumed) = decode_context_object_id(data, 0)?;
+        let object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
 
-        let object_id_bytes = [data[pos], data[pos + 1], data[pos + 2], data[pos + 3]];
-        let object_id = u32::from_be_bytes(object_id_bytes);
-        let (object_type, instance) = crate::util::decode_object_id(object_id);
-        let object_identifier = ObjectIdentifier {
-            object_type: crate::object::ObjectType::try_from(object_type)
-                .unwrap_or(crate::object::ObjectType::Device),
-            instance,
+        // Property identifier - context tag 1
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Property array index - context tag 2 (optional)
+        let property_array_index = match decode_context_unsigned(&data[pos..], 2) {
+            Ok((array_index, consumed)) => {
+                pos += consumed;
+                Some(array_index)
+            }
+            Err(_) => None,
         };
-        pos += 4;
 
-        // Decode property identifier - context tag 1
-        if pos + 2 > data.len() || data[pos] != 0x19 {
-            return Err(crate::encoding::EncodingError::InvalidTag);
-        }
-        pos += 1;
-        let property_identifier = data[pos] as u32;
-        pos += 1;
+        // Property value - context tag 3
+        let closing = pos + find_closing_tag(&data[pos..], 3)?;
+        let property_value = data[pos + 1..closing].to_vec();
+        pos = closing + 1;
 
-        // Property array index - context tag 2 (optional)
-        let property_array_index = if pos < data.len() && data[pos] == 0x29 {
-            pos += 1;
-            let array_index = data[pos] as u32;
-            pos += 1;
-            Some(array_index)
-        } else {
-            None
-        };
-
-        // Property value - context tag 3 (opening tag)
-        if pos >= data.len() || data[pos] != 0x3E {
-            return Err(crate::encoding::EncodingError::InvalidTag);
-        }
-        pos += 1;
-
-        // Find closing tag
-        let value_start = pos;
-        let mut value_end = pos;
-        while value_end < data.len() {
-            if data[value_end] == 0x3F {
-                break;
-            }
-            value_end += 1;
-        }
-
-        if value_end >= data.len() {
-            return Err(crate::encoding::EncodingError::InvalidTag);
-        }
-
-        let property_value = data[value_start..value_end].to_vec();
-        pos = value_end + 1;
-
-        // Priority - context tag 4 (optional)
-        let priority = if pos < data.len() && data[pos] == 0x49 {
-            pos += 1;
-            if pos < data.len() {
-                Some(data[pos])
-            } else {
-                None
-            }
+        // Priority - context tag 4 (optional)
+        let priority = if pos < data.len() {
+            let (priority, _) = decode_context_unsigned(&data[pos..], 4)?;
+            let priority = u8::try_from(priority).map_err(|_| EncodingError::ValueOutOfRange)?;
+            validate_priority(priority)?;
+            Some(priority)
         } else {
             None
         };
@@ -914,7 +1504,7 @@ pub struct ReadAccessSpecification {
     pub property_references: Vec<PropertyReference>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PropertyReference {
     /// Property identifier
     pub property_identifier: u32,
@@ -934,6 +1524,91 @@ impl ReadPropertyMultipleRequest {
     pub fn add_specification(&mut self, spec: ReadAccessSpecification) {
         self.read_access_specifications.push(spec);
     }
+
+    /// Encode the Read Property Multiple request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        for spec in &self.read_access_specifications {
+            // Object identifier - context tag 0
+            let obj_id_bytes = encode_context_object_id(
+                spec.object_identifier.object_type.as_u16(),
+                spec.object_identifier.instance,
+                0,
+            )?;
+            buffer.extend_from_slice(&obj_id_bytes);
+
+            // List of property references - context tag 1
+            encode_opening_tag(buffer, 1)?;
+            for reference in &spec.property_references {
+                let prop_id_bytes = encode_context_enumerated(reference.property_identifier, 0)?;
+                buffer.extend_from_slice(&prop_id_bytes);
+
+                if let Some(array_index) = reference.property_array_index {
+                    let array_bytes = encode_context_unsigned(array_index, 1)?;
+                    buffer.extend_from_slice(&array_bytes);
+                }
+            }
+            encode_closing_tag(buffer, 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Read Property Multiple request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        Self::decode_with_limits(data, &DecodeLimits::default())
+    }
+
+    /// Decode a Read Property Multiple request, bounding the number of
+    /// specifications and of property references in each
+    pub fn decode_with_limits(data: &[u8], limits: &DecodeLimits) -> EncodingResult<Self> {
+        let mut pos = 0;
+        let mut read_access_specifications = Vec::new();
+
+        while pos < data.len() {
+            // Object identifier - context tag 0
+            let ((object_type, instance), consumed) = decode_context_object_id(&data[pos..], 0)?;
+            let object_identifier = object_identifier_from_parts(object_type, instance)?;
+            pos += consumed;
+
+            // List of property references - context tag 1
+            let closing = pos + find_closing_tag(&data[pos..], 1)?;
+            pos += 1;
+
+            let mut property_references = Vec::new();
+            while pos < closing {
+                let (property_identifier, consumed) =
+                    decode_context_enumerated(&data[pos..closing], 0)?;
+                pos += consumed;
+
+                let property_array_index = if is_context_tag(&data[pos..closing], 1) {
+                    let (array_index, consumed) = decode_context_unsigned(&data[pos..closing], 1)?;
+                    pos += consumed;
+                    Some(array_index)
+                } else {
+                    None
+                };
+
+                limits.check_elements(property_references.len() + 1)?;
+                property_references.push(PropertyReference {
+                    property_identifier,
+                    property_array_index,
+                });
+            }
+            pos = closing + 1;
+
+            limits.check_elements(read_access_specifications.len() + 1)?;
+            read_access_specifications.push(ReadAccessSpecification::new(
+                object_identifier,
+                property_references,
+            ));
+        }
+
+        if read_access_specifications.is_empty() {
+            return Err(EncodingError::UnexpectedEndOfData);
+        }
+
+        Ok(Self::new(read_access_specifications))
+    }
 }
 
 impl ReadAccessSpecification {
@@ -970,6 +1645,400 @@ impl PropertyReference {
             property_array_index: Some(array_index),
         }
     }
+
+    /// Reference to all properties of an object
+    pub fn all() -> Self {
+        Self::new(PROPERTY_ALL)
+    }
+
+    /// Reference to the required properties of an object
+    pub fn required() -> Self {
+        Self::new(PROPERTY_REQUIRED)
+    }
+
+    /// Reference to the optional properties of an object
+    pub fn optional() -> Self {
+        Self::new(PROPERTY_OPTIONAL)
+    }
+
+    /// Check whether this references ALL, REQUIRED or OPTIONAL
+    ///
+    /// The responding device expands these into one result per property.
+    pub fn is_special(&self) -> bool {
+        matches!(
+            self.property_identifier,
+            PROPERTY_ALL | PROPERTY_REQUIRED | PROPERTY_OPTIONAL
+        )
+    }
+}
+
+/// Error returned for a single property in a ReadPropertyMultiple result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropertyAccessError {
+    /// Error class
+    pub error_class: ErrorClass,
+    /// Error code
+    pub error_code: u32,
+}
+
+/// Result of reading one property within a ReadPropertyMultiple ack
+#[derive(Debug, Clone)]
+pub struct ReadResult {
+    /// Property identifier that was read
+    pub property_identifier: u32,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Property value, or the error returned for this property
+    pub value: core::result::Result<PropertyValue, PropertyAccessError>,
+}
+
+/// Results for one object within a ReadPropertyMultiple ack
+#[derive(Debug, Clone)]
+pub struct ReadAccessResult {
+    /// Object identifier that was read
+    pub object_identifier: ObjectIdentifier,
+    /// Per-property results
+    pub results: Vec<ReadResult>,
+}
+
+/// Read Property Multiple acknowledgment (complex ack)
+#[derive(Debug, Clone)]
+pub struct ReadPropertyMultipleAck {
+    /// Results for each object read
+    pub read_access_results: Vec<ReadAccessResult>,
+}
+
+impl ReadPropertyMultipleAck {
+    /// Create a new Read Property Multiple ack
+    pub fn new(read_access_results: Vec<ReadAccessResult>) -> Self {
+        Self {
+            read_access_results,
+        }
+    }
+
+    /// Encode the Read Property Multiple ack
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        for access_result in &self.read_access_results {
+            // Object identifier - context tag 0
+            let obj_id_bytes = encode_context_object_id(
+                access_result.object_identifier.object_type.as_u16(),
+                access_result.object_identifier.instance,
+                0,
+            )?;
+            buffer.extend_from_slice(&obj_id_bytes);
+
+            // List of results - context tag 1
+            encode_opening_tag(buffer, 1)?;
+            for result in &access_result.results {
+                // Property identifier - context tag 2
+                let prop_id_bytes = encode_context_enumerated(result.property_identifier, 2)?;
+                buffer.extend_from_slice(&prop_id_bytes);
+
+                // Property array index - context tag 3 (optional)
+                if let Some(array_index) = result.property_array_index {
+                    let array_bytes = encode_context_unsigned(array_index, 3)?;
+                    buffer.extend_from_slice(&array_bytes);
+                }
+
+                match &result.value {
+                    // Property value - context tag 4
+                    Ok(value) => encode_property_value(buffer, 4, value)?,
+                    // Property access error - context tag 5
+                    Err(error) => {
+                        encode_opening_tag(buffer, 5)?;
+                        encode_enumerated(buffer, error.error_class as u32)?;
+                        encode_enumerated(buffer, error.error_code)?;
+                        encode_closing_tag(buffer, 5)?;
+                    }
+                }
+            }
+            encode_closing_tag(buffer, 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Read Property Multiple ack
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        Self::decode_with_limits(data, &DecodeLimits::default())
+    }
+
+    /// Decode a Read Property Multiple ack, bounding the number of results,
+    /// the results for each object and the elements of each value
+    pub fn decode_with_limits(data: &[u8], limits: &DecodeLimits) -> EncodingResult<Self> {
+        let mut pos = 0;
+        let mut read_access_results = Vec::new();
+
+        while pos < data.len() {
+            // Object identifier - context tag 0
+            let ((object_type, instance), consumed) = decode_context_object_id(&data[pos..], 0)?;
+            let object_identifier = object_identifier_from_parts(object_type, instance)?;
+            pos += consumed;
+
+            // List of results - context tag 1
+            let closing = pos + find_closing_tag(&data[pos..], 1)?;
+            pos += 1;
+
+            let mut results = Vec::new();
+            while pos < closing {
+                let (property_identifier, consumed) =
+                    decode_context_enumerated(&data[pos..closing], 2)?;
+                pos += consumed;
+
+                let property_array_index = if is_context_tag(&data[pos..closing], 3) {
+                    let (array_index, consumed) = decode_context_unsigned(&data[pos..closing], 3)?;
+                    pos += consumed;
+                    Some(array_index)
+                } else {
+                    None
+                };
+
+                let value = if is_opening_tag(&data[pos..closing], 4) {
+                    let list = is_whole_list(property_identifier, property_array_index);
+                    let (value, consumed) =
+                        decode_property_value(&data[pos..closing], 4, list, limits)?;
+                    pos += consumed;
+                    Ok(value)
+                } else if is_opening_tag(&data[pos..closing], 5) {
+                    let error_end = pos + find_closing_tag(&data[pos..closing], 5)?;
+                    let (error_class, consumed) = decode_enumerated(&data[pos + 1..error_end])?;
+                    let (error_code, _) = decode_enumerated(&data[pos + 1 + consumed..error_end])?;
+                    pos = error_end + 1;
+                    Err(PropertyAccessError {
+                        error_class: ErrorClass::try_from(error_class)?,
+                        error_code,
+                    })
+                } else {
+                    return Err(EncodingError::InvalidTag);
+                };
+
+                limits.check_elements(results.len() + 1)?;
+                results.push(ReadResult {
+                    property_identifier,
+                    property_array_index,
+                    value,
+                });
+            }
+            pos = closing + 1;
+
+            limits.check_elements(read_access_results.len() + 1)?;
+            read_access_results.push(ReadAccessResult {
+                object_identifier,
+                results,
+            });
+        }
+
+        Ok(Self::new(read_access_results))
+    }
+}
+
+/// Write Property Multiple request (confirmed service)
+#[derive(Debug, Clone)]
+pub struct WritePropertyMultipleRequest {
+    /// List of objects and the properties to write to each
+    pub write_access_specifications: Vec<WriteAccessSpecification>,
+}
+
+/// Properties to write to one object in a WritePropertyMultiple request
+#[derive(Debug, Clone)]
+pub struct WriteAccessSpecification {
+    /// Object identifier
+    pub object_identifier: ObjectIdentifier,
+    /// Property values to write, with their array indices and priorities
+    pub list_of_properties: Vec<BacnetPropertyValue>,
+}
+
+impl WritePropertyMultipleRequest {
+    /// Create a new Write Property Multiple request
+    pub fn new(write_access_specifications: Vec<WriteAccessSpecification>) -> Self {
+        Self {
+            write_access_specifications,
+        }
+    }
+
+    /// Add a write access specification
+    pub fn add_specification(&mut self, spec: WriteAccessSpecification) {
+        self.write_access_specifications.push(spec);
+    }
+
+    /// Encode the Write Property Multiple request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        for spec in &self.write_access_specifications {
+            // Object identifier - context tag 0
+            let obj_id_bytes = encode_context_object_id(
+                spec.object_identifier.object_type.as_u16(),
+                spec.object_identifier.instance,
+                0,
+            )?;
+            buffer.extend_from_slice(&obj_id_bytes);
+
+            // List of properties - context tag 1
+            encode_opening_tag(buffer, 1)?;
+            for property in &spec.list_of_properties {
+                property.encode(buffer)?;
+            }
+            encode_closing_tag(buffer, 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Write Property Multiple request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        Self::decode_with_limits(data, &DecodeLimits::default())
+    }
+
+    /// Decode a Write Property Multiple request, bounding the number of
+    /// specifications and of property values in each
+    pub fn decode_with_limits(data: &[u8], limits: &DecodeLimits) -> EncodingResult<Self> {
+        let mut pos = 0;
+        let mut write_access_specifications = Vec::new();
+
+        while pos < data.len() {
+            // Object identifier - context tag 0
+            let ((object_type, instance), consumed) = decode_context_object_id(&data[pos..], 0)?;
+            let object_identifier = object_identifier_from_parts(object_type, instance)?;
+            pos += consumed;
+
+            // List of properties - context tag 1
+            let closing = pos + find_closing_tag(&data[pos..], 1)?;
+            pos += 1;
+
+            let mut list_of_properties = Vec::new();
+            while pos < closing {
+                let (property, consumed) = BacnetPropertyValue::decode(&data[pos..closing])?;
+                pos += consumed;
+
+                limits.check_elements(list_of_properties.len() + 1)?;
+                list_of_properties.push(property);
+            }
+            pos = closing + 1;
+
+            limits.check_elements(write_access_specifications.len() + 1)?;
+            write_access_specifications.push(WriteAccessSpecification::new(
+                object_identifier,
+                list_of_properties,
+            ));
+        }
+
+        if write_access_specifications.is_empty() {
+            return Err(EncodingError::UnexpectedEndOfData);
+        }
+
+        Ok(Self::new(write_access_specifications))
+    }
+}
+
+impl WriteAccessSpecification {
+    /// Create a new write access specification
+    pub fn new(
+        object_identifier: ObjectIdentifier,
+        list_of_properties: Vec<BacnetPropertyValue>,
+    ) -> Self {
+        Self {
+            object_identifier,
+            list_of_properties,
+        }
+    }
+
+    /// Add a property value to write
+    pub fn add_property(&mut self, property: BacnetPropertyValue) {
+        self.list_of_properties.push(property);
+    }
+}
+
+/// WritePropertyMultiple-Error: why a request failed and at which write
+///
+/// Writes before the failed one have been applied; the ones after it have
+/// not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WritePropertyMultipleError {
+    /// Error class
+    pub error_class: u32,
+    /// Error code
+    pub error_code: u32,
+    /// Object of the first write that failed
+    pub object_identifier: ObjectIdentifier,
+    /// Property of the first write that failed
+    pub property_identifier: u32,
+    /// Array index of the first write that failed (optional)
+    pub property_array_index: Option<u32>,
+}
+
+impl WritePropertyMultipleError {
+    /// Get the error as a [`BacnetError`]
+    pub fn error(&self) -> BacnetError {
+        BacnetError::from_error(self.error_class, self.error_code)
+    }
+
+    /// Build the Error PDU answering the request with `invoke_id`
+    pub fn to_apdu(&self, invoke_id: u8) -> EncodingResult<Apdu> {
+        // First failed write attempt - context tag 1
+        let mut error_data = Vec::new();
+        encode_opening_tag(&mut error_data, 1)?;
+        let obj_id_bytes = encode_context_object_id(
+            self.object_identifier.object_type.as_u16(),
+            self.object_identifier.instance,
+            0,
+        )?;
+        error_data.extend_from_slice(&obj_id_bytes);
+        let prop_id_bytes = encode_context_enumerated(self.property_identifier, 1)?;
+        error_data.extend_from_slice(&prop_id_bytes);
+        if let Some(array_index) = self.property_array_index {
+            let array_bytes = encode_context_unsigned(array_index, 2)?;
+            error_data.extend_from_slice(&array_bytes);
+        }
+        encode_closing_tag(&mut error_data, 1)?;
+
+        Ok(Apdu::Error {
+            invoke_id,
+            service_choice: ConfirmedServiceChoice::WritePropertyMultiple as u8,
+            error_class: self.error_class,
+            error_code: self.error_code,
+            error_data,
+        })
+    }
+
+    /// Decode from the Error PDU answering a WritePropertyMultiple request
+    pub fn from_apdu(apdu: &Apdu) -> EncodingResult<Self> {
+        let Apdu::Error {
+            error_class,
+            error_code,
+            error_data,
+            ..
+        } = apdu
+        else {
+            return Err(EncodingError::InvalidFormat("Not an Error PDU".to_string()));
+        };
+        if !is_opening_tag(error_data, 1) {
+            return Err(EncodingError::InvalidTag);
+        }
+        let closing = find_closing_tag(error_data, 1)?;
+        let data = &error_data[1..closing];
+
+        // Object identifier - context tag 0
+        let ((object_type, instance), mut pos) = decode_context_object_id(data, 0)?;
+        let object_identifier = object_identifier_from_parts(object_type, instance)?;
+
+        // Property identifier - context tag 1
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Property array index - context tag 2 (optional)
+        let property_array_index = if is_context_tag(&data[pos..], 2) {
+            Some(decode_context_unsigned(&data[pos..], 2)?.0)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            error_class: *error_class,
+            error_code: *error_code,
+            object_identifier,
+            property_identifier,
+            property_array_index,
+        })
+    }
 }
 
 /// Subscribe COV request (confirmed service)
@@ -1027,37 +2096,79 @@ impl SubscribeCovRequest {
         }
     }
 
+    /// Check whether this request cancels an existing subscription
+    ///
+    /// A request without the confirmation flag and lifetime is a cancellation.
+    pub fn is_cancellation(&self) -> bool {
+        self.issue_confirmed_notifications.is_none() && self.lifetime.is_none()
+    }
+
     /// Encode the Subscribe COV request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
         // Subscriber process identifier - context tag 0
-        buffer.push(0x09); // Context tag 0, length 1
-        buffer.push(self.subscriber_process_identifier as u8);
+        let process_bytes = encode_context_unsigned(self.subscriber_process_identifier, 0)?;
+        buffer.extend_from_slice(&process_bytes);
 
         // Monitored object identifier - context tag 1
-        let object_id = crate::util::encode_object_id(
-            self.monitored_object_identifier.object_type as u16,
+        let obj_id_bytes = encode_context_object_id(
+            self.monitored_object_identifier.object_type.as_u16(),
             self.monitored_object_identifier.instance,
-        )
-        .ok_or(crate::encoding::EncodingError::InvalidFormat(
-            "Invalid object identifier".to_string(),
-        ))?;
-        buffer.push(0x1C); // Context tag 1, length 4
-        buffer.extend_from_slice(&object_id.to_be_bytes());
+            1,
+        )?;
+        buffer.extend_from_slice(&obj_id_bytes);
 
-        // Issue confirmed notifications - context tag 2 (optional)
+        // Issue confirmed notifications - context tag 2 (optional boolean)
         if let Some(confirmed) = self.issue_confirmed_notifications {
-            buffer.push(0x22); // Context tag 2, length 2
-            buffer.push(if confirmed { 1 } else { 0 });
+            let confirmed_bytes = encode_context_unsigned(confirmed as u32, 2)?;
+            buffer.extend_from_slice(&confirmed_bytes);
         }
 
         // Lifetime - context tag 3 (optional)
         if let Some(lifetime) = self.lifetime {
-            buffer.push(0x39); // Context tag 3, length 1
-            buffer.push(lifetime as u8);
+            let lifetime_bytes = encode_context_unsigned(lifetime, 3)?;
+            buffer.extend_from_slice(&lifetime_bytes);
         }
 
         Ok(())
     }
+
+    /// Decode a Subscribe COV request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Subscriber process identifier - context tag 0
+        let (subscriber_process_identifier, consumed) = decode_context_unsigned(data, 0)?;
+        pos += consumed;
+
+        // Monitored object identifier - context tag 1
+        let ((object_type, instance), consumed) = decode_context_object_id(&data[pos..], 1)?;
+        let monitored_object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Issue confirmed notifications - context tag 2 (optional boolean)
+        let issue_confirmed_notifications = if is_context_tag(&data[pos..], 2) {
+            let (confirmed, consumed) = decode_context_unsigned(&data[pos..], 2)?;
+            pos += consumed;
+            Some(confirmed != 0)
+        } else {
+            None
+        };
+
+        // Lifetime - context tag 3 (optional)
+        let lifetime = if pos < data.len() {
+            let (lifetime, _) = decode_context_unsigned(&data[pos..], 3)?;
+            Some(lifetime)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            subscriber_process_identifier,
+            monitored_object_identifier,
+            issue_confirmed_notifications,
+            lifetime,
+        })
+    }
 }
 
 /// Subscribe COV Property request (confirmed service)
@@ -1099,80 +2210,387 @@ impl SubscribeCovPropertyRequest {
         self.cov_increment = Some(increment);
         self
     }
-}
-
-/// COV Notification request (unconfirmed service)
-#[derive(Debug, Clone)]
-pub struct CovNotificationRequest {
-    /// Subscriber process identifier
-    pub subscriber_process_identifier: u32,
-    /// Initiating device identifier
-    pub initiating_device_identifier: ObjectIdentifier,
-    /// Monitored object identifier
-    pub monitored_object_identifier: ObjectIdentifier,
-    /// Time remaining (seconds)
-    pub time_remaining: u32,
-    /// List of values (property-value pairs)
-    pub list_of_values: Vec<PropertyValue>,
-}
 
-impl CovNotificationRequest {
-    /// Create a new COV Notification request
-    pub fn new(
-        subscriber_process_identifier: u32,
-        initiating_device_identifier: ObjectIdentifier,
-        monitored_object_identifier: ObjectIdentifier,
-        time_remaining: u32,
-        list_of_values: Vec<PropertyValue>,
-    ) -> Self {
-        Self {
-            subscriber_process_identifier,
-            initiating_device_identifier,
-            monitored_object_identifier,
-            time_remaining,
-            list_of_values,
-        }
+    /// Check whether this request cancels an existing subscription
+    ///
+    /// A request without the confirmation flag and lifetime is a cancellation.
+    pub fn is_cancellation(&self) -> bool {
+        self.issue_confirmed_notifications.is_none() && self.lifetime.is_none()
+    }
+
+    /// Encode the Subscribe COV Property request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Subscriber process identifier - context tag 0
+        let process_bytes = encode_context_unsigned(self.subscriber_process_identifier, 0)?;
+        buffer.extend_from_slice(&process_bytes);
+
+        // Monitored object identifier - context tag 1
+        let obj_id_bytes = encode_context_object_id(
+            self.monitored_object_identifier.object_type.as_u16(),
+            self.monitored_object_identifier.instance,
+            1,
+        )?;
+        buffer.extend_from_slice(&obj_id_bytes);
+
+        // Issue confirmed notifications - context tag 2 (optional boolean)
+        if let Some(confirmed) = self.issue_confirmed_notifications {
+            encode_context_value(buffer, 2, &PropertyValue::Boolean(confirmed))?;
+        }
+
+        // Lifetime - context tag 3 (optional)
+        if let Some(lifetime) = self.lifetime {
+            let lifetime_bytes = encode_context_unsigned(lifetime, 3)?;
+            buffer.extend_from_slice(&lifetime_bytes);
+        }
+
+        // Monitored property reference - context tag 4
+        encode_opening_tag(buffer, 4)?;
+        let prop_id_bytes =
+            encode_context_enumerated(self.monitored_property.property_identifier, 0)?;
+        buffer.extend_from_slice(&prop_id_bytes);
+        if let Some(array_index) = self.monitored_property.property_array_index {
+            let array_bytes = encode_context_unsigned(array_index, 1)?;
+            buffer.extend_from_slice(&array_bytes);
+        }
+        encode_closing_tag(buffer, 4)?;
+
+        // COV increment - context tag 5 (optional)
+        if let Some(increment) = self.cov_increment {
+            encode_context_value(buffer, 5, &PropertyValue::Real(increment))?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Subscribe COV Property request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Subscriber process identifier - context tag 0
+        let (subscriber_process_identifier, consumed) = decode_context_unsigned(data, 0)?;
+        pos += consumed;
+
+        // Monitored object identifier - context tag 1
+        let ((object_type, instance), consumed) = decode_context_object_id(&data[pos..], 1)?;
+        let monitored_object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Issue confirmed notifications - context tag 2 (optional boolean)
+        let issue_confirmed_notifications = if is_context_tag(&data[pos..], 2) {
+            let (confirmed, consumed) =
+                decode_context_value(&data[pos..], 2, ApplicationTag::Boolean)?;
+            pos += consumed;
+            Some(matches!(confirmed, PropertyValue::Boolean(true)))
+        } else {
+            None
+        };
+
+        // Lifetime - context tag 3 (optional)
+        let lifetime = if is_context_tag(&data[pos..], 3) {
+            let (lifetime, consumed) = decode_context_unsigned(&data[pos..], 3)?;
+            pos += consumed;
+            Some(lifetime)
+        } else {
+            None
+        };
+
+        // Monitored property reference - context tag 4
+        let closing = pos + find_closing_tag(&data[pos..], 4)?;
+        pos += 1;
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..closing], 0)?;
+        pos += consumed;
+        let property_array_index = if pos < closing {
+            let (index, consumed) = decode_context_unsigned(&data[pos..closing], 1)?;
+            pos += consumed;
+            Some(index)
+        } else {
+            None
+        };
+        if pos != closing {
+            return Err(EncodingError::InvalidTag);
+        }
+        pos += 1;
+
+        // COV increment - context tag 5 (optional)
+        let cov_increment = if pos < data.len() {
+            match decode_context_value(&data[pos..], 5, ApplicationTag::Real)? {
+                (PropertyValue::Real(increment), _) => Some(increment),
+                _ => return Err(EncodingError::InvalidTag),
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            subscriber_process_identifier,
+            monitored_object_identifier,
+            issue_confirmed_notifications,
+            lifetime,
+            monitored_property: PropertyReference {
+                property_identifier,
+                property_array_index,
+            },
+            cov_increment,
+        })
+    }
+}
+
+/// Property value with its identifier (BACnetPropertyValue)
+///
+/// Used in COV notifications and other services that carry a list of
+/// property values.
+#[derive(Debug, Clone)]
+pub struct BacnetPropertyValue {
+    /// Property identifier
+    pub property_identifier: u32,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Property value
+    pub value: PropertyValue,
+    /// Priority (optional, 1-16)
+    pub priority: Option<u8>,
+}
+
+impl BacnetPropertyValue {
+    /// Create a new property value entry
+    pub fn new(property_identifier: u32, value: PropertyValue) -> Self {
+        Self {
+            property_identifier,
+            property_array_index: None,
+            value,
+            priority: None,
+        }
+    }
+
+    /// Encode the property value entry
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Property identifier - context tag 0
+        let prop_id_bytes = encode_context_enumerated(self.property_identifier, 0)?;
+        buffer.extend_from_slice(&prop_id_bytes);
+
+        // Property array index - context tag 1 (optional)
+        if let Some(array_index) = self.property_array_index {
+            let array_bytes = encode_context_unsigned(array_index, 1)?;
+            buffer.extend_from_slice(&array_bytes);
+        }
+
+        // Property value - context tag 2
+        encode_property_value(buffer, 2, &self.value)?;
+
+        // Priority - context tag 3 (optional)
+        if let Some(priority) = self.priority {
+            validate_priority(priority)?;
+            let priority_bytes = encode_context_unsigned(priority as u32, 3)?;
+            buffer.extend_from_slice(&priority_bytes);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a property value entry, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let mut pos = 0;
+
+        // Property identifier - context tag 0
+        let (property_identifier, consumed) = decode_context_enumerated(data, 0)?;
+        pos += consumed;
+
+        // Property array index - context tag 1 (optional)
+        let property_array_index = if is_context_tag(&data[pos..], 1) {
+            let (array_index, consumed) = decode_context_unsigned(&data[pos..], 1)?;
+            pos += consumed;
+            Some(array_index)
+        } else {
+            None
+        };
+
+        // Property value - context tag 2
+        let list = is_whole_list(property_identifier, property_array_index);
+        let (value, consumed) =
+            decode_property_value(&data[pos..], 2, list, &DecodeLimits::default())?;
+        pos += consumed;
+
+        // Priority - context tag 3 (optional)
+        let priority = if is_context_tag(&data[pos..], 3) {
+            let (priority, consumed) = decode_context_unsigned(&data[pos..], 3)?;
+            pos += consumed;
+            let priority = u8::try_from(priority).map_err(|_| EncodingError::ValueOutOfRange)?;
+            validate_priority(priority)?;
+            Some(priority)
+        } else {
+            None
+        };
+
+        Ok((
+            Self {
+                property_identifier,
+                property_array_index,
+                value,
+                priority,
+            },
+            pos,
+        ))
+    }
+}
+
+/// COV Notification request (confirmed or unconfirmed service)
+#[derive(Debug, Clone)]
+pub struct CovNotificationRequest {
+    /// Subscriber process identifier
+    pub subscriber_process_identifier: u32,
+    /// Initiating device identifier
+    pub initiating_device_identifier: ObjectIdentifier,
+    /// Monitored object identifier
+    pub monitored_object_identifier: ObjectIdentifier,
+    /// Time remaining (seconds)
+    pub time_remaining: u32,
+    /// List of values (property-value pairs)
+    pub list_of_values: Vec<BacnetPropertyValue>,
+}
+
+impl CovNotificationRequest {
+    /// Create a new COV Notification request
+    pub fn new(
+        subscriber_process_identifier: u32,
+        initiating_device_identifier: ObjectIdentifier,
+        monitored_object_identifier: ObjectIdentifier,
+        time_remaining: u32,
+        list_of_values: Vec<BacnetPropertyValue>,
+    ) -> Self {
+        Self {
+            subscriber_process_identifier,
+            initiating_device_identifier,
+            monitored_object_identifier,
+            time_remaining,
+            list_of_values,
+        }
     }
 
     /// Encode the COV Notification request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
         // Subscriber process identifier - context tag 0
-        buffer.push(0x09); // Context tag 0, length 1
-        buffer.push(self.subscriber_process_identifier as u8);
+        let process_bytes = encode_context_unsigned(self.subscriber_process_identifier, 0)?;
+        buffer.extend_from_slice(&process_bytes);
 
         // Initiating device identifier - context tag 1
-        let device_id = crate::util::encode_object_id(
-            self.initiating_device_identifier.object_type as u16,
+        let device_id_bytes = encode_context_object_id(
+            self.initiating_device_identifier.object_type.as_u16(),
             self.initiating_device_identifier.instance,
-        )
-        .ok_or(crate::encoding::EncodingError::InvalidFormat(
-            "Invalid device identifier".to_string(),
-        ))?;
-        buffer.push(0x1C); // Context tag 1, length 4
-        buffer.extend_from_slice(&device_id.to_be_bytes());
+            1,
+        )?;
+        buffer.extend_from_slice(&device_id_bytes);
 
         // Monitored object identifier - context tag 2
-        let object_id = crate::util::encode_object_id(
-            self.monitored_object_identifier.object_type as u16,
+        let obj_id_bytes = encode_context_object_id(
+            self.monitored_object_identifier.object_type.as_u16(),
             self.monitored_object_identifier.instance,
-        )
-        .ok_or(crate::encoding::EncodingError::InvalidFormat(
-            "Invalid object identifier".to_string(),
-        ))?;
-        buffer.push(0x2C); // Context tag 2, length 4
-        buffer.extend_from_slice(&object_id.to_be_bytes());
+            2,
+        )?;
+        buffer.extend_from_slice(&obj_id_bytes);
 
         // Time remaining - context tag 3
-        buffer.push(0x39); // Context tag 3, length 1
-        buffer.push(self.time_remaining as u8);
+        let time_bytes = encode_context_unsigned(self.time_remaining, 3)?;
+        buffer.extend_from_slice(&time_bytes);
 
-        // List of values would be encoded here in a real implementation
-        // This is complex as it involves encoding property-value pairs
+        // List of values - context tag 4
+        encode_opening_tag(buffer, 4)?;
+        for value in &self.list_of_values {
+            value.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 4)?;
 
         Ok(())
     }
+
+    /// Decode a COV Notification request
+    ///
+    /// Confirmed and unconfirmed notifications share the same service data.
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Subscriber process identifier - context tag 0
+        let (subscriber_process_identifier, consumed) = decode_context_unsigned(data, 0)?;
+        pos += consumed;
+
+        // Initiating device identifier - context tag 1
+        let ((object_type, instance), consumed) = decode_context_object_id(&data[pos..], 1)?;
+        let initiating_device_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Monitored object identifier - context tag 2
+        let ((object_type, instance), consumed) = decode_context_object_id(&data[pos..], 2)?;
+        let monitored_object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Time remaining - context tag 3
+        let (time_remaining, consumed) = decode_context_unsigned(&data[pos..], 3)?;
+        pos += consumed;
+
+        // List of values - context tag 4
+        let closing = pos + find_closing_tag(&data[pos..], 4)?;
+        pos += 1;
+        let mut list_of_values = Vec::new();
+        while pos < closing {
+            let (value, consumed) = BacnetPropertyValue::decode(&data[pos..closing])?;
+            list_of_values.push(value);
+            pos += consumed;
+        }
+
+        Ok(Self::new(
+            subscriber_process_identifier,
+            initiating_device_identifier,
+            monitored_object_identifier,
+            time_remaining,
+            list_of_values,
+        ))
+    }
+
+    /// Iterate the notified values by property, for per-property dispatch
+    pub fn values(&self) -> impl Iterator<Item = (PropertyIdentifier, &PropertyValue)> {
+        self.list_of_values.iter().map(|entry| {
+            (
+                PropertyIdentifier::from(entry.property_identifier),
+                &entry.value,
+            )
+        })
+    }
+
+    /// Apply the notified values to a local copy of the monitored object
+    ///
+    /// Values are applied through `set_property_remote`. Properties the
+    /// object does not know or does not accept are skipped; returns the
+    /// number of values applied.
+    pub fn apply_to(
+        &self,
+        object: &mut dyn BacnetObject,
+    ) -> core::result::Result<usize, ObjectError> {
+        if object.identifier() != self.monitored_object_identifier {
+            return Err(ObjectError::NotFound);
+        }
+
+        let mut applied = 0;
+        for entry in &self.list_of_values {
+            let property = PropertyIdentifier::from(entry.property_identifier);
+            match object.set_property_remote(property, entry.value.clone()) {
+                Ok(()) => applied += 1,
+                Err(
+                    ObjectError::UnknownProperty
+                    | ObjectError::PropertyNotWritable
+                    | ObjectError::WriteAccessDenied,
+                ) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(applied)
+    }
 }
 
+/// ConfirmedCOVNotification request
+///
+/// Carries the same service data as the unconfirmed notification; the
+/// subscriber answers it with a Simple-ACK.
+pub type ConfirmedCovNotificationRequest = CovNotificationRequest;
+
 /// COV Subscription information
 #[derive(Debug, Clone)]
 pub struct CovSubscription {
@@ -1192,6 +2610,13 @@ pub struct CovSubscription {
     pub time_remaining: u32,
     /// COV increment (for analog properties)
     pub cov_increment: Option<f32>,
+    /// Value last notified, the Present_Value for whole-object subscriptions
+    pub last_notified: Option<PropertyValue>,
+    /// Seconds since the last notification, `None` before the first
+    pub seconds_since_notification: Option<u32>,
+    /// Send a final notification, with no time remaining, when the
+    /// subscription expires
+    pub notify_on_expiry: bool,
 }
 
 impl CovSubscription {
@@ -1211,6 +2636,55 @@ impl CovSubscription {
             lifetime,
             time_remaining: lifetime,
             cov_increment: None,
+            last_notified: None,
+            seconds_since_notification: None,
+            notify_on_expiry: false,
+        }
+    }
+
+    /// Create a COV Property subscription from a SubscribeCOVProperty request
+    ///
+    /// Returns `None` for a cancellation.
+    pub fn for_property(
+        subscriber_device_identifier: ObjectIdentifier,
+        request: &SubscribeCovPropertyRequest,
+    ) -> Option<Self> {
+        if request.is_cancellation() {
+            return None;
+        }
+        let mut subscription = Self::new(
+            request.subscriber_process_identifier,
+            subscriber_device_identifier,
+            request.monitored_object_identifier,
+            request.lifetime.unwrap_or(0),
+        );
+        subscription.issue_confirmed_notifications =
+            request.issue_confirmed_notifications.unwrap_or(false);
+        subscription.monitored_property = Some(request.monitored_property.clone());
+        subscription.cov_increment = request.cov_increment;
+        Some(subscription)
+    }
+
+    /// Check whether a new value of the monitored property is reportable
+    ///
+    /// The first value is always reported. After that a Real must move by at
+    /// least the COV increment from the value last notified; without an
+    /// increment, or for other datatypes, any change is reported.
+    pub fn is_reportable(&self, value: &PropertyValue) -> bool {
+        let Some(last) = &self.last_notified else {
+            return true;
+        };
+        match (last, value, self.cov_increment) {
+            (PropertyValue::Real(last), PropertyValue::Real(value), Some(increment)) => {
+                (value - last).abs() >= increment
+            }
+            _ => {
+                let mut last_encoded = Vec::new();
+                let mut encoded = Vec::new();
+                last.encode(&mut last_encoded).is_err()
+                    || value.encode(&mut encoded).is_err()
+                    || last_encoded != encoded
+            }
         }
     }
 
@@ -1224,6 +2698,41 @@ impl CovSubscription {
         if self.lifetime > 0 {
             self.time_remaining = self.time_remaining.saturating_sub(elapsed_seconds);
         }
+        if let Some(seconds) = &mut self.seconds_since_notification {
+            *seconds = seconds.saturating_add(elapsed_seconds);
+        }
+    }
+
+    /// Record a value as notified to the subscriber
+    fn record_notification(&mut self, value: &PropertyValue) {
+        self.last_notified = Some(value.clone());
+        self.seconds_since_notification = Some(0);
+    }
+
+    /// Final notification for an expired subscription
+    ///
+    /// Repeats the value last notified with no time remaining. Returns `None`
+    /// unless the subscription asked for it and a value has been notified.
+    pub fn cancellation_notification(
+        &self,
+        initiating_device_identifier: ObjectIdentifier,
+    ) -> Option<CovNotificationRequest> {
+        let value = self
+            .last_notified
+            .clone()
+            .filter(|_| self.notify_on_expiry)?;
+        let mut property_value = BacnetPropertyValue::new(85, value);
+        if let Some(property) = &self.monitored_property {
+            property_value.property_identifier = property.property_identifier;
+            property_value.property_array_index = property.property_array_index;
+        }
+        Some(CovNotificationRequest::new(
+            self.subscriber_process_identifier,
+            initiating_device_identifier,
+            self.monitored_object_identifier,
+            0,
+            vec![property_value],
+        ))
     }
 }
 
@@ -1243,12 +2752,15 @@ impl CovSubscriptionManager {
     }
 
     /// Add a new subscription
+    ///
+    /// Replaces an existing subscription from the same subscriber for the
+    /// same object, or for the same property of a COV Property subscription.
     pub fn add_subscription(&mut self, subscription: CovSubscription) {
-        // Remove any existing subscription for the same object and subscriber
         self.subscriptions.retain(|s| {
             !(s.subscriber_device_identifier == subscription.subscriber_device_identifier
                 && s.subscriber_process_identifier == subscription.subscriber_process_identifier
-                && s.monitored_object_identifier == subscription.monitored_object_identifier)
+                && s.monitored_object_identifier == subscription.monitored_object_identifier
+                && s.monitored_property == subscription.monitored_property)
         });
 
         self.subscriptions.push(subscription);
@@ -1268,6 +2780,78 @@ impl CovSubscriptionManager {
         });
     }
 
+    /// Remove a COV Property subscription
+    pub fn remove_property_subscription(
+        &mut self,
+        subscriber_device: ObjectIdentifier,
+        subscriber_process: u32,
+        monitored_object: ObjectIdentifier,
+        monitored_property: &PropertyReference,
+    ) {
+        self.subscriptions.retain(|s| {
+            !(s.subscriber_device_identifier == subscriber_device
+                && s.subscriber_process_identifier == subscriber_process
+                && s.monitored_object_identifier == monitored_object
+                && s.monitored_property.as_ref() == Some(monitored_property))
+        });
+    }
+
+    /// Report a new value of a property, returning the COV Property
+    /// subscriptions to notify
+    ///
+    /// Only subscriptions to that property whose change test passes are
+    /// returned, and each records the value as last notified.
+    pub fn property_changed(
+        &mut self,
+        object_id: ObjectIdentifier,
+        property_reference: &PropertyReference,
+        value: &PropertyValue,
+    ) -> Vec<CovSubscription> {
+        let mut notify = Vec::new();
+        for subscription in &mut self.subscriptions {
+            if subscription.monitored_object_identifier != object_id
+                || subscription.monitored_property.as_ref() != Some(property_reference)
+                || subscription.is_expired()
+                || !subscription.is_reportable(value)
+            {
+                continue;
+            }
+            subscription.record_notification(value);
+            notify.push(subscription.clone());
+        }
+        notify
+    }
+
+    /// Report a new Present_Value of an object, returning the whole-object
+    /// subscriptions to notify
+    ///
+    /// Uses the same change test as [`property_changed`](Self::property_changed),
+    /// with the COV increment of each subscription.
+    pub fn object_changed(
+        &mut self,
+        object_id: ObjectIdentifier,
+        present_value: &PropertyValue,
+    ) -> Vec<CovSubscription> {
+        let mut notify = Vec::new();
+        for subscription in &mut self.subscriptions {
+            if subscription.monitored_object_identifier != object_id
+                || subscription.monitored_property.is_some()
+                || subscription.is_expired()
+                || !subscription.is_reportable(present_value)
+            {
+                continue;
+            }
+            subscription.record_notification(present_value);
+            notify.push(subscription.clone());
+        }
+        notify
+    }
+
+    /// All subscriptions, with their last notified value and remaining lifetime
+    pub fn subscriptions(&self) -> &[CovSubscription] {
+        &self.subscriptions
+    }
+
     /// Get all subscriptions for a monitored object
     pub fn get_subscriptions_for_object(
         &self,
@@ -1285,10 +2869,22 @@ impl CovSubscriptionManager {
     }
 
     /// Update all subscription timers
-    pub fn update_timers(&mut self, elapsed_seconds: u32) {
+    ///
+    /// Subscriptions whose lifetime runs out are removed and returned, so
+    /// their [cancellation notifications](CovSubscription::cancellation_notification)
+    /// can be sent.
+    pub fn update_timers(&mut self, elapsed_seconds: u32) -> Vec<CovSubscription> {
         for subscription in &mut self.subscriptions {
             subscription.update_time(elapsed_seconds);
         }
+        let mut expired = Vec::new();
+        self.subscriptions.retain(|s| {
+            if s.is_expired() {
+                expired.push(s.clone());
+            }
+            !s.is_expired()
+        });
+        expired
     }
 
     /// Get total number of active subscriptions
@@ -1300,20 +2896,529 @@ impl CovSubscriptionManager {
     }
 }
 
-/// Atomic Read File request (confirmed service)
-#[derive(Debug, Clone)]
-pub struct AtomicReadFileRequest {
-    /// File object identifier
-    pub file_identifier: ObjectIdentifier,
-    /// Access method specification
-    pub access_method: FileAccessMethod,
+/// BACnetNotifyType: whether a notification is an alarm or an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u32)]
+pub enum NotifyType {
+    Alarm = 0,
+    Event = 1,
+    AckNotification = 2,
 }
 
-/// File access method for atomic read/write
-#[derive(Debug, Clone)]
-pub enum FileAccessMethod {
-    /// Stream access - read/write bytes at position
-    StreamAccess {
+impl TryFrom<u32> for NotifyType {
+    type Error = EncodingError;
+
+    fn try_from(value: u32) -> EncodingResult<Self> {
+        match value {
+            0 => Ok(NotifyType::Alarm),
+            1 => Ok(NotifyType::Event),
+            2 => Ok(NotifyType::AckNotification),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+}
+
+/// Decode a BACnetEventTransitionBits value: to-offnormal, to-fault, to-normal
+fn transition_bits(value: PropertyValue) -> EncodingResult<[bool; 3]> {
+    let PropertyValue::BitString(bits) = value else {
+        return Err(EncodingError::InvalidTag);
+    };
+    let bit = |n: usize| bits.get(n).copied().unwrap_or(false);
+    Ok([bit(0), bit(1), bit(2)])
+}
+
+/// Decode an application tagged event state
+fn decode_event_state(data: &[u8]) -> EncodingResult<(EventState, usize)> {
+    match PropertyValue::decode(data)? {
+        (PropertyValue::Enumerated(state), consumed) => Ok((
+            EventState::try_from(state).map_err(|_| EncodingError::ValueOutOfRange)?,
+            consumed,
+        )),
+        _ => Err(EncodingError::InvalidTag),
+    }
+}
+
+/// GetAlarmSummary request (confirmed service)
+///
+/// The request has no parameters; the device answers with every object
+/// whose event state is not normal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetAlarmSummaryRequest;
+
+impl GetAlarmSummaryRequest {
+    /// Encode the GetAlarmSummary request
+    pub fn encode(&self, _buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        Ok(())
+    }
+
+    /// Decode a GetAlarmSummary request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        if !data.is_empty() {
+            return Err(EncodingError::InvalidLength);
+        }
+        Ok(Self)
+    }
+}
+
+/// One active alarm in a GetAlarmSummary acknowledgement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlarmSummary {
+    /// Object in alarm
+    pub object_identifier: ObjectIdentifier,
+    /// Current event state of the object
+    pub alarm_state: EventState,
+    /// Acknowledged to-offnormal, to-fault and to-normal transitions
+    pub acknowledged_transitions: [bool; 3],
+}
+
+/// GetAlarmSummary acknowledgement
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GetAlarmSummaryAck {
+    /// Objects in alarm
+    pub alarm_summaries: Vec<AlarmSummary>,
+}
+
+impl GetAlarmSummaryAck {
+    /// Create a new GetAlarmSummary acknowledgement
+    pub fn new(alarm_summaries: Vec<AlarmSummary>) -> Self {
+        Self { alarm_summaries }
+    }
+
+    /// Encode the GetAlarmSummary acknowledgement
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Each summary is a sequence of application tagged values
+        for summary in &self.alarm_summaries {
+            PropertyValue::ObjectIdentifier(summary.object_identifier).encode(buffer)?;
+            PropertyValue::Enumerated(summary.alarm_state as u32).encode(buffer)?;
+            PropertyValue::BitString(summary.acknowledged_transitions.to_vec()).encode(buffer)?;
+        }
+        Ok(())
+    }
+
+    /// Decode a GetAlarmSummary acknowledgement
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+        let mut alarm_summaries = Vec::new();
+        while pos < data.len() {
+            let (PropertyValue::ObjectIdentifier(object_identifier), consumed) =
+                PropertyValue::decode(&data[pos..])?
+            else {
+                return Err(EncodingError::InvalidTag);
+            };
+            pos += consumed;
+
+            let (alarm_state, consumed) = decode_event_state(&data[pos..])?;
+            pos += consumed;
+
+            let (bits, consumed) = PropertyValue::decode(&data[pos..])?;
+            let acknowledged_transitions = transition_bits(bits)?;
+            pos += consumed;
+
+            alarm_summaries.push(AlarmSummary {
+                object_identifier,
+                alarm_state,
+                acknowledged_transitions,
+            });
+        }
+        Ok(Self { alarm_summaries })
+    }
+}
+
+/// GetEventInformation request (confirmed service)
+///
+/// Summaries are returned in pages; a follow-up request names the last
+/// object received to continue after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GetEventInformationRequest {
+    /// Last object identifier received in the previous page (context tag 0)
+    pub last_received_object_identifier: Option<ObjectIdentifier>,
+}
+
+impl GetEventInformationRequest {
+    /// Create a request for the first page
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a request for the page after `last_received`
+    pub fn after(last_received: ObjectIdentifier) -> Self {
+        Self {
+            last_received_object_identifier: Some(last_received),
+        }
+    }
+
+    /// Encode the GetEventInformation request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        if let Some(object_id) = self.last_received_object_identifier {
+            let obj_id_bytes =
+                encode_context_object_id(object_id.object_type.as_u16(), object_id.instance, 0)?;
+            buffer.extend_from_slice(&obj_id_bytes);
+        }
+        Ok(())
+    }
+
+    /// Decode a GetEventInformation request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        if data.is_empty() {
+            return Ok(Self::new());
+        }
+        let ((object_type, instance), _) = decode_context_object_id(data, 0)?;
+        Ok(Self::after(object_identifier_from_parts(
+            object_type,
+            instance,
+        )?))
+    }
+}
+
+/// One object's event information in a GetEventInformation acknowledgement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventSummary {
+    /// Object reporting events
+    pub object_identifier: ObjectIdentifier,
+    /// Current event state of the object
+    pub event_state: EventState,
+    /// Acknowledged to-offnormal, to-fault and to-normal transitions
+    pub acknowledged_transitions: [bool; 3],
+    /// Times of the last to-offnormal, to-fault and to-normal transitions
+    pub event_time_stamps: [TimeStamp; 3],
+    /// Whether the object reports alarms or events
+    pub notify_type: NotifyType,
+    /// Transitions enabled for reporting
+    pub event_enable: [bool; 3],
+    /// Priorities of to-offnormal, to-fault and to-normal notifications
+    pub event_priorities: [u8; 3],
+}
+
+impl EventSummary {
+    /// Encode the event summary
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Object identifier - context tag 0
+        let obj_id_bytes = encode_context_object_id(
+            self.object_identifier.object_type.as_u16(),
+            self.object_identifier.instance,
+            0,
+        )?;
+        buffer.extend_from_slice(&obj_id_bytes);
+
+        // Event state - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(self.event_state as u32, 1)?);
+
+        // Acknowledged transitions - context tag 2
+        encode_context_value(
+            buffer,
+            2,
+            &PropertyValue::BitString(self.acknowledged_transitions.to_vec()),
+        )?;
+
+        // Event time stamps - context tag 3
+        encode_opening_tag(buffer, 3)?;
+        for time_stamp in &self.event_time_stamps {
+            time_stamp.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 3)?;
+
+        // Notify type - context tag 4
+        buffer.extend_from_slice(&encode_context_enumerated(self.notify_type as u32, 4)?);
+
+        // Event enable - context tag 5
+        encode_context_value(
+            buffer,
+            5,
+            &PropertyValue::BitString(self.event_enable.to_vec()),
+        )?;
+
+        // Event priorities - context tag 6
+        encode_opening_tag(buffer, 6)?;
+        for priority in self.event_priorities {
+            encode_unsigned(buffer, priority as u32)?;
+        }
+        encode_closing_tag(buffer, 6)
+    }
+
+    /// Decode an event summary, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let mut pos = 0;
+
+        // Object identifier - context tag 0
+        let ((object_type, instance), consumed) = decode_context_object_id(data, 0)?;
+        let object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Event state - context tag 1
+        let (state, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        let event_state =
+            EventState::try_from(state).map_err(|_| EncodingError::ValueOutOfRange)?;
+        pos += consumed;
+
+        // Acknowledged transitions - context tag 2
+        let (bits, consumed) = decode_context_value(&data[pos..], 2, ApplicationTag::BitString)?;
+        let acknowledged_transitions = transition_bits(bits)?;
+        pos += consumed;
+
+        // Event time stamps - context tag 3
+        let closing = pos + find_closing_tag(&data[pos..], 3)?;
+        pos += 1;
+        let mut event_time_stamps = [TimeStamp::SequenceNumber(0); 3];
+        for time_stamp in &mut event_time_stamps {
+            let (decoded, consumed) = TimeStamp::decode(&data[pos..closing])?;
+            *time_stamp = decoded;
+            pos += consumed;
+        }
+        if pos != closing {
+            return Err(EncodingError::InvalidLength);
+        }
+        pos += 1;
+
+        // Notify type - context tag 4
+        let (notify_type, consumed) = decode_context_enumerated(&data[pos..], 4)?;
+        let notify_type = NotifyType::try_from(notify_type)?;
+        pos += consumed;
+
+        // Event enable - context tag 5
+        let (bits, consumed) = decode_context_value(&data[pos..], 5, ApplicationTag::BitString)?;
+        let event_enable = transition_bits(bits)?;
+        pos += consumed;
+
+        // Event priorities - context tag 6
+        let closing = pos + find_closing_tag(&data[pos..], 6)?;
+        pos += 1;
+        let mut event_priorities = [0u8; 3];
+        for priority in &mut event_priorities {
+            let (value, consumed) = decode_unsigned(&data[pos..closing])?;
+            *priority = u8::try_from(value).map_err(|_| EncodingError::ValueOutOfRange)?;
+            pos += consumed;
+        }
+        if pos != closing {
+            return Err(EncodingError::InvalidLength);
+        }
+        pos += 1;
+
+        Ok((
+            Self {
+                object_identifier,
+                event_state,
+                acknowledged_transitions,
+                event_time_stamps,
+                notify_type,
+                event_enable,
+                event_priorities,
+            },
+            pos,
+        ))
+    }
+}
+
+/// GetEventInformation acknowledgement
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GetEventInformationAck {
+    /// Objects with event information on this page
+    pub list_of_event_summaries: Vec<EventSummary>,
+    /// More summaries follow; request them with the last object received
+    pub more_events: bool,
+}
+
+impl GetEventInformationAck {
+    /// Create a new GetEventInformation acknowledgement
+    pub fn new(list_of_event_summaries: Vec<EventSummary>, more_events: bool) -> Self {
+        Self {
+            list_of_event_summaries,
+            more_events,
+        }
+    }
+
+    /// Request for the page following this one, if there is one
+    pub fn next_request(&self) -> Option<GetEventInformationRequest> {
+        if !self.more_events {
+            return None;
+        }
+        self.list_of_event_summaries
+            .last()
+            .map(|summary| GetEventInformationRequest::after(summary.object_identifier))
+    }
+
+    /// Encode the GetEventInformation acknowledgement
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // List of event summaries - context tag 0
+        encode_opening_tag(buffer, 0)?;
+        for summary in &self.list_of_event_summaries {
+            summary.encode(buffer)?;
+        }
+        encode_closing_tag(buffer, 0)?;
+
+        // More events - context tag 1
+        encode_context_value(buffer, 1, &PropertyValue::Boolean(self.more_events))
+    }
+
+    /// Decode a GetEventInformation acknowledgement
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // List of event summaries - context tag 0
+        let closing = find_closing_tag(data, 0)?;
+        let mut pos = 1;
+        let mut list_of_event_summaries = Vec::new();
+        while pos < closing {
+            let (summary, consumed) = EventSummary::decode(&data[pos..closing])?;
+            list_of_event_summaries.push(summary);
+            pos += consumed;
+        }
+        pos += 1;
+
+        // More events - context tag 1
+        let (more_events, _) = decode_context_value(&data[pos..], 1, ApplicationTag::Boolean)?;
+        Ok(Self::new(
+            list_of_event_summaries,
+            matches!(more_events, PropertyValue::Boolean(true)),
+        ))
+    }
+}
+
+/// AcknowledgeAlarm request (confirmed service)
+///
+/// An operator acknowledges the transition of an object into
+/// `event_state_acknowledged` that was stamped `time_stamp`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcknowledgeAlarmRequest {
+    /// Process acknowledging the alarm
+    pub acknowledging_process_identifier: u32,
+    /// Object whose transition is acknowledged
+    pub event_object_identifier: ObjectIdentifier,
+    /// Event state entered by the acknowledged transition
+    pub event_state_acknowledged: EventState,
+    /// Time stamp of the acknowledged transition
+    pub time_stamp: TimeStamp,
+    /// Operator or process acknowledging, for the record
+    pub acknowledgment_source: String,
+    /// When the acknowledgement was made
+    pub time_of_acknowledgment: TimeStamp,
+}
+
+impl AcknowledgeAlarmRequest {
+    /// Create a new AcknowledgeAlarm request
+    pub fn new(
+        acknowledging_process_identifier: u32,
+        event_object_identifier: ObjectIdentifier,
+        event_state_acknowledged: EventState,
+        time_stamp: TimeStamp,
+        acknowledgment_source: impl Into<String>,
+        time_of_acknowledgment: TimeStamp,
+    ) -> Self {
+        Self {
+            acknowledging_process_identifier,
+            event_object_identifier,
+            event_state_acknowledged,
+            time_stamp,
+            acknowledgment_source: acknowledgment_source.into(),
+            time_of_acknowledgment,
+        }
+    }
+
+    /// Encode the AcknowledgeAlarm request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Acknowledging process identifier - context tag 0
+        let process_bytes = encode_context_unsigned(self.acknowledging_process_identifier, 0)?;
+        buffer.extend_from_slice(&process_bytes);
+
+        // Event object identifier - context tag 1
+        let obj_id_bytes = encode_context_object_id(
+            self.event_object_identifier.object_type.as_u16(),
+            self.event_object_identifier.instance,
+            1,
+        )?;
+        buffer.extend_from_slice(&obj_id_bytes);
+
+        // Event state acknowledged - context tag 2
+        let state_bytes = encode_context_enumerated(self.event_state_acknowledged as u32, 2)?;
+        buffer.extend_from_slice(&state_bytes);
+
+        // Time stamp - context tag 3
+        encode_opening_tag(buffer, 3)?;
+        self.time_stamp.encode(buffer)?;
+        encode_closing_tag(buffer, 3)?;
+
+        // Acknowledgment source - context tag 4
+        encode_context_value(
+            buffer,
+            4,
+            &PropertyValue::CharacterString(self.acknowledgment_source.clone()),
+        )?;
+
+        // Time of acknowledgment - context tag 5
+        encode_opening_tag(buffer, 5)?;
+        self.time_of_acknowledgment.encode(buffer)?;
+        encode_closing_tag(buffer, 5)
+    }
+
+    /// Decode an AcknowledgeAlarm request
+    ///
+    /// An event state outside the standard range is `ValueOutOfRange`.
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Acknowledging process identifier - context tag 0
+        let (acknowledging_process_identifier, consumed) = decode_context_unsigned(data, 0)?;
+        pos += consumed;
+
+        // Event object identifier - context tag 1
+        let ((object_type, instance), consumed) = decode_context_object_id(&data[pos..], 1)?;
+        let event_object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Event state acknowledged - context tag 2
+        let (state, consumed) = decode_context_enumerated(&data[pos..], 2)?;
+        let event_state_acknowledged =
+            EventState::try_from(state).map_err(|_| EncodingError::ValueOutOfRange)?;
+        pos += consumed;
+
+        // Time stamp - context tag 3
+        let (time_stamp, consumed) = decode_wrapped_time_stamp(&data[pos..], 3)?;
+        pos += consumed;
+
+        // Acknowledgment source - context tag 4
+        let (source, consumed) =
+            decode_context_value(&data[pos..], 4, ApplicationTag::CharacterString)?;
+        let PropertyValue::CharacterString(acknowledgment_source) = source else {
+            return Err(EncodingError::InvalidTag);
+        };
+        pos += consumed;
+
+        // Time of acknowledgment - context tag 5
+        let (time_of_acknowledgment, _) = decode_wrapped_time_stamp(&data[pos..], 5)?;
+
+        Ok(Self {
+            acknowledging_process_identifier,
+            event_object_identifier,
+            event_state_acknowledged,
+            time_stamp,
+            acknowledgment_source,
+            time_of_acknowledgment,
+        })
+    }
+}
+
+/// Decode a time stamp enclosed in opening/closing context tags
+fn decode_wrapped_time_stamp(data: &[u8], tag_number: u8) -> EncodingResult<(TimeStamp, usize)> {
+    let closing = find_closing_tag(data, tag_number)?;
+    let (time_stamp, consumed) = TimeStamp::decode(&data[1..closing])?;
+    if consumed + 1 != closing {
+        return Err(EncodingError::InvalidLength);
+    }
+    Ok((time_stamp, closing + 1))
+}
+
+/// Atomic Read File request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomicReadFileRequest {
+    /// File object identifier
+    pub file_identifier: ObjectIdentifier,
+    /// Access method specification
+    pub access_method: FileAccessMethod,
+}
+
+/// File access method for atomic read/write
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileAccessMethod {
+    /// Stream access - read/write bytes at position
+    StreamAccess {
         /// File position to start reading/writing
         file_start_position: i32,
         /// Number of octets to read (for read operations)
@@ -1361,65 +3466,61 @@ impl AtomicReadFileRequest {
 
     /// Encode the Atomic Read File request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        // File identifier - context tag 0
-        let file_id = crate::util::encode_object_id(
-            self.file_identifier.object_type as u16,
+        // File identifier - application tagged
+        encode_object_identifier(
+            buffer,
+            self.file_identifier.object_type.as_u16(),
             self.file_identifier.instance,
-        )
-        .ok_or(crate::encoding::EncodingError::InvalidFormat(
-            "Invalid file identifier".to_string(),
-        ))?;
-        buffer.push(0x0C); // Context tag 0, length 4
-        buffer.extend_from_slice(&file_id.to_be_bytes());
-
-        // Access method - context tag 1 (opening tag)
-        buffer.push(0x1E); // Context tag 1, opening tag
+        )?;
 
-        match &self.access_method {
+        // Access method - context tag 0 (stream) or 1 (record)
+        let (choice, start, count) = match self.access_method {
             FileAccessMethod::StreamAccess {
                 file_start_position,
                 requested_octet_count,
-            } => {
-                // Stream access - context tag 0 (opening tag)
-                buffer.push(0x0E); // Context tag 0, opening tag
-
-                // File start position - context tag 0
-                buffer.push(0x09); // Context tag 0, length 1
-                buffer.extend_from_slice(&file_start_position.to_be_bytes());
-
-                // Requested octet count - context tag 1
-                buffer.push(0x19); // Context tag 1, length 1
-                buffer.extend_from_slice(&requested_octet_count.to_be_bytes());
-
-                buffer.push(0x0F); // Context tag 0, closing tag
-            }
+            } => (0, file_start_position, requested_octet_count),
             FileAccessMethod::RecordAccess {
                 file_start_record,
                 requested_record_count,
-            } => {
-                // Record access - context tag 1 (opening tag)
-                buffer.push(0x1E); // Context tag 1, opening tag
+            } => (1, file_start_record, requested_record_count),
+        };
+        encode_opening_tag(buffer, choice)?;
+        encoding::encode_signed(buffer, start)?;
+        encode_unsigned(buffer, count)?;
+        encode_closing_tag(buffer, choice)?;
 
-                // File start record - context tag 0
-                buffer.push(0x09); // Context tag 0, length 1
-                buffer.extend_from_slice(&file_start_record.to_be_bytes());
+        Ok(())
+    }
 
-                // Requested record count - context tag 1
-                buffer.push(0x19); // Context tag 1, length 1
-                buffer.extend_from_slice(&requested_record_count.to_be_bytes());
+    /// Decode an Atomic Read File request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let ((object_type, instance), consumed) = decode_object_identifier(data)?;
+        let file_identifier = object_identifier_from_parts(object_type, instance)?;
 
-                buffer.push(0x1F); // Context tag 1, closing tag
+        let (choice, contents, _) = decode_file_access_choice(&data[consumed..])?;
+        let (start, consumed) = encoding::decode_signed(contents)?;
+        let (count, _) = decode_unsigned(&contents[consumed..])?;
+        let access_method = if choice == 0 {
+            FileAccessMethod::StreamAccess {
+                file_start_position: start,
+                requested_octet_count: count,
             }
-        }
-
-        buffer.push(0x1F); // Context tag 1, closing tag
+        } else {
+            FileAccessMethod::RecordAccess {
+                file_start_record: start,
+                requested_record_count: count,
+            }
+        };
 
-        Ok(())
+        Ok(Self {
+            file_identifier,
+            access_method,
+        })
     }
 }
 
 /// Atomic Read File response (confirmed service)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AtomicReadFileResponse {
     /// End of file flag
     pub end_of_file: bool,
@@ -1428,11 +3529,11 @@ pub struct AtomicReadFileResponse {
 }
 
 /// File access method result for atomic read response
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum FileAccessMethodResult {
     /// Stream access result
     StreamAccess {
-        /// File position after read
+        /// File position of the first octet returned
         file_start_position: i32,
         /// File data read
         file_data: Vec<u8>,
@@ -1472,39 +3573,105 @@ impl AtomicReadFileResponse {
             },
         }
     }
-}
-
-/// Atomic Write File request (confirmed service)
-#[derive(Debug, Clone)]
-pub struct AtomicWriteFileRequest {
-    /// File object identifier
-    pub file_identifier: ObjectIdentifier,
-    /// Access method and data
-    pub access_method: FileWriteAccessMethod,
-}
 
-/// File write access method for atomic write
-#[derive(Debug, Clone)]
-pub enum FileWriteAccessMethod {
-    /// Stream access - write bytes at position
-    StreamAccess {
-        /// File position to start writing
-        file_start_position: i32,
-        /// Data to write
-        file_data: Vec<u8>,
-    },
-    /// Record access - write records
-    RecordAccess {
-        /// Starting record number
-        file_start_record: i32,
-        /// Number of records to write
-        record_count: u32,
-        /// Record data to write
-        file_record_data: Vec<Vec<u8>>,
-    },
-}
+    /// Encode the Atomic Read File acknowledgement
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        encoding::encode_boolean(buffer, self.end_of_file)?;
 
-impl AtomicWriteFileRequest {
+        match &self.access_method_result {
+            FileAccessMethodResult::StreamAccess {
+                file_start_position,
+                file_data,
+            } => {
+                encode_opening_tag(buffer, 0)?;
+                encoding::encode_signed(buffer, *file_start_position)?;
+                encoding::encode_octet_string(buffer, file_data)?;
+                encode_closing_tag(buffer, 0)?;
+            }
+            FileAccessMethodResult::RecordAccess {
+                file_start_record,
+                record_count,
+                file_record_data,
+            } => {
+                encode_opening_tag(buffer, 1)?;
+                encoding::encode_signed(buffer, *file_start_record)?;
+                encode_unsigned(buffer, *record_count)?;
+                for record in file_record_data {
+                    encoding::encode_octet_string(buffer, record)?;
+                }
+                encode_closing_tag(buffer, 1)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode an Atomic Read File acknowledgement
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        Self::decode_with_limits(data, &DecodeLimits::default())
+    }
+
+    /// Decode an Atomic Read File acknowledgement, bounding the record count
+    pub fn decode_with_limits(data: &[u8], limits: &DecodeLimits) -> EncodingResult<Self> {
+        let (end_of_file, consumed) = encoding::decode_boolean(data)?;
+
+        let (choice, contents, _) = decode_file_access_choice(&data[consumed..])?;
+        let (start, mut pos) = encoding::decode_signed(contents)?;
+        let access_method_result = if choice == 0 {
+            let (file_data, _) = encoding::decode_octet_string(&contents[pos..])?;
+            FileAccessMethodResult::StreamAccess {
+                file_start_position: start,
+                file_data,
+            }
+        } else {
+            let (record_count, consumed) = decode_unsigned(&contents[pos..])?;
+            pos += consumed;
+            let file_record_data = decode_file_records(&contents[pos..], record_count, limits)?;
+            FileAccessMethodResult::RecordAccess {
+                file_start_record: start,
+                record_count,
+                file_record_data,
+            }
+        };
+
+        Ok(Self {
+            end_of_file,
+            access_method_result,
+        })
+    }
+}
+
+/// Atomic Write File request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomicWriteFileRequest {
+    /// File object identifier
+    pub file_identifier: ObjectIdentifier,
+    /// Access method and data
+    pub access_method: FileWriteAccessMethod,
+}
+
+/// File write access method for atomic write
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileWriteAccessMethod {
+    /// Stream access - write bytes at position
+    StreamAccess {
+        /// File position to start writing, or -1 to append
+        file_start_position: i32,
+        /// Data to write
+        file_data: Vec<u8>,
+    },
+    /// Record access - write records
+    RecordAccess {
+        /// Starting record number, or -1 to append
+        file_start_record: i32,
+        /// Number of records to write
+        record_count: u32,
+        /// Record data to write
+        file_record_data: Vec<Vec<u8>>,
+    },
+}
+
+impl AtomicWriteFileRequest {
     /// Create a new Atomic Write File request with stream access
     pub fn new_stream_access(
         file_identifier: ObjectIdentifier,
@@ -1539,202 +3706,692 @@ impl AtomicWriteFileRequest {
 
     /// Encode the Atomic Write File request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        // File identifier - context tag 0
-        let file_id = crate::util::encode_object_id(
-            self.file_identifier.object_type as u16,
+        // File identifier - application tagged
+        encode_object_identifier(
+            buffer,
+            self.file_identifier.object_type.as_u16(),
             self.file_identifier.instance,
-        )
-        .ok_or(crate::encoding::EncodingError::InvalidFormat(
-            "Invalid file identifier".to_string(),
-        ))?;
-        buffer.push(0x0C); // Context tag 0, length 4
-        buffer.extend_from_slice(&file_id.to_be_bytes());
-
-        // Access method - context tag 1 (opening tag)
-        buffer.push(0x1E); // Context tag 1, opening tag
+        )?;
 
         match &self.access_method {
             FileWriteAccessMethod::StreamAccess {
                 file_start_position,
                 file_data,
             } => {
-                // Stream access - context tag 0 (opening tag)
-                buffer.push(0x0E); // Context tag 0, opening tag
-
-                // File start position - context tag 0
-                buffer.push(0x09); // Context tag 0, length 1
-                buffer.extend_from_slice(&file_start_position.to_be_bytes());
-
-                // File data - context tag 1 (opening tag)
-                buffer.push(0x1E); // Context tag 1, opening tag
-                buffer.extend_from_slice(file_data);
-                buffer.push(0x1F); // Context tag 1, closing tag
-
-                buffer.push(0x0F); // Context tag 0, closing tag
+                encode_opening_tag(buffer, 0)?;
+                encoding::encode_signed(buffer, *file_start_position)?;
+                encoding::encode_octet_string(buffer, file_data)?;
+                encode_closing_tag(buffer, 0)?;
             }
             FileWriteAccessMethod::RecordAccess {
                 file_start_record,
                 record_count: _,
                 file_record_data,
             } => {
-                // Record access - context tag 1 (opening tag)
-                buffer.push(0x1E); // Context tag 1, opening tag
-
-                // File start record - context tag 0
-                buffer.push(0x09); // Context tag 0, length 1
-                buffer.extend_from_slice(&file_start_record.to_be_bytes());
-
-                // Record count - context tag 1
-                let record_count = file_record_data.len() as u32;
-                buffer.push(0x19); // Context tag 1, length 1
-                buffer.extend_from_slice(&record_count.to_be_bytes());
-
-                // File record data - context tag 2 (opening tag)
-                buffer.push(0x2E); // Context tag 2, opening tag
+                encode_opening_tag(buffer, 1)?;
+                encoding::encode_signed(buffer, *file_start_record)?;
+                // The count always reflects the records actually sent
+                encode_unsigned(buffer, file_record_data.len() as u32)?;
                 for record in file_record_data {
-                    // Each record as octet string
-                    buffer.push(0x65); // Application tag 6 (OctetString), length depends on record size
-                    buffer.push(record.len() as u8);
-                    buffer.extend_from_slice(record);
+                    encoding::encode_octet_string(buffer, record)?;
                 }
-                buffer.push(0x2F); // Context tag 2, closing tag
-
-                buffer.push(0x1F); // Context tag 1, closing tag
+                encode_closing_tag(buffer, 1)?;
             }
         }
 
-        buffer.push(0x1F); // Context tag 1, closing tag
-
         Ok(())
     }
+
+    /// Decode an Atomic Write File request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        Self::decode_with_limits(data, &DecodeLimits::default())
+    }
+
+    /// Decode an Atomic Write File request, bounding the record count
+    pub fn decode_with_limits(data: &[u8], limits: &DecodeLimits) -> EncodingResult<Self> {
+        let ((object_type, instance), consumed) = decode_object_identifier(data)?;
+        let file_identifier = object_identifier_from_parts(object_type, instance)?;
+
+        let (choice, contents, _) = decode_file_access_choice(&data[consumed..])?;
+        let (start, mut pos) = encoding::decode_signed(contents)?;
+        let access_method = if choice == 0 {
+            let (file_data, _) = encoding::decode_octet_string(&contents[pos..])?;
+            FileWriteAccessMethod::StreamAccess {
+                file_start_position: start,
+                file_data,
+            }
+        } else {
+            let (record_count, consumed) = decode_unsigned(&contents[pos..])?;
+            pos += consumed;
+            let file_record_data = decode_file_records(&contents[pos..], record_count, limits)?;
+            FileWriteAccessMethod::RecordAccess {
+                file_start_record: start,
+                record_count,
+                file_record_data,
+            }
+        };
+
+        Ok(Self {
+            file_identifier,
+            access_method,
+        })
+    }
 }
 
 /// Atomic Write File response (confirmed service)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct AtomicWriteFileResponse {
     /// File start position (for stream access) or start record (for record access)
     pub file_start_position: i32,
+    /// The write used record access
+    pub record_access: bool,
 }
 
-/// Time Synchronization request (unconfirmed service)
-#[derive(Debug, Clone)]
-pub struct TimeSynchronizationRequest {
-    /// Date and time to synchronize to
-    pub date_time: BacnetDateTime,
-}
+impl AtomicWriteFileResponse {
+    /// Create a new stream access response
+    pub fn new_stream_access(file_start_position: i32) -> Self {
+        Self {
+            file_start_position,
+            record_access: false,
+        }
+    }
 
-/// UTC Time Synchronization request (unconfirmed service)
-#[derive(Debug, Clone)]
-pub struct UtcTimeSynchronizationRequest {
-    /// UTC date and time to synchronize to
-    pub utc_date_time: BacnetDateTime,
+    /// Create a new record access response
+    pub fn new_record_access(file_start_record: i32) -> Self {
+        Self {
+            file_start_position: file_start_record,
+            record_access: true,
+        }
+    }
+
+    /// Encode the Atomic Write File acknowledgement
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // File start position - context tag 0, or file start record - context tag 1
+        let tag_number = if self.record_access { 1 } else { 0 };
+        encode_context_value(
+            buffer,
+            tag_number,
+            &PropertyValue::SignedInt(self.file_start_position),
+        )
+    }
+
+    /// Decode an Atomic Write File acknowledgement
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (tag, _) = decode_tag(data)?;
+        if tag.class != TagClass::Context || tag.number > 1 {
+            return Err(EncodingError::InvalidTag);
+        }
+        match decode_context_value(data, tag.number, ApplicationTag::SignedInt)? {
+            (PropertyValue::SignedInt(file_start_position), _) => Ok(Self {
+                file_start_position,
+                record_access: tag.number == 1,
+            }),
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
 }
 
-/// BACnet Date and Time structure
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct BacnetDateTime {
-    /// Date component
-    pub date: crate::object::Date,
-    /// Time component
-    pub time: crate::object::Time,
+/// Decode the stream (0) or record (1) access choice of the atomic file
+/// services, returning the choice, its contents and the bytes consumed
+fn decode_file_access_choice(data: &[u8]) -> EncodingResult<(u8, &[u8], usize)> {
+    let (tag, header) = decode_tag(data)?;
+    if tag.class != TagClass::Context || !tag.is_opening || tag.number > 1 {
+        return Err(EncodingError::InvalidTag);
+    }
+    let closing = find_closing_tag(data, tag.number)?;
+    Ok((tag.number, &data[header..closing], closing + 1))
 }
 
-impl BacnetDateTime {
-    /// Create a new BACnet DateTime from Date and Time components
-    pub fn new(date: crate::object::Date, time: crate::object::Time) -> Self {
-        Self { date, time }
+/// Decode `count` octet string records
+///
+/// The count comes off the wire, so it is checked against the limits and
+/// the remaining data before anything is reserved for it.
+fn decode_file_records(
+    data: &[u8],
+    count: u32,
+    limits: &DecodeLimits,
+) -> EncodingResult<Vec<Vec<u8>>> {
+    let count = usize::try_from(count).map_err(|_| EncodingError::TooManyElements)?;
+    limits.check_elements(count)?;
+    // Every record takes at least its tag octet
+    if count > data.len() {
+        return Err(EncodingError::UnexpectedEndOfData);
+    }
+    let mut records = Vec::with_capacity(count);
+    let mut pos = 0;
+    for _ in 0..count {
+        let (record, consumed) = encoding::decode_octet_string(&data[pos..])?;
+        records.push(record);
+        pos += consumed;
     }
+    Ok(records)
+}
 
-    /// Create from current system time (requires std feature)
-    #[cfg(feature = "std")]
-    pub fn now() -> Self {
-        use chrono::{Datelike, Local, Timelike};
+/// Encode a primitive value with a context tag instead of its application tag
+///
+/// Context tagged booleans carry their value in a content octet rather than
+/// in the length field.
+pub(crate) fn encode_context_value(
+    buffer: &mut Vec<u8>,
+    tag_number: u8,
+    value: &PropertyValue,
+) -> EncodingResult<()> {
+    if let PropertyValue::Boolean(value) = value {
+        encoding::encode_context_tag(buffer, tag_number, 1)?;
+        buffer.push(*value as u8);
+        return Ok(());
+    }
 
-        let now = Local::now();
-        let year = now.year() as u16;
-        let month = now.month() as u8;
-        let day = now.day() as u8;
-        let weekday = now.weekday().number_from_monday() as u8; // BACnet uses 1=Monday
-        let hour = now.hour() as u8;
-        let minute = now.minute() as u8;
-        let second = now.second() as u8;
-        let hundredths = (now.nanosecond() / 10_000_000) as u8;
+    let mut application = Vec::new();
+    value.encode(&mut application)?;
+    let (tag, header) = decode_tag(&application)?;
+    if tag.class != TagClass::Application {
+        return Err(EncodingError::InvalidTag);
+    }
+    encoding::encode_context_tag(buffer, tag_number, application.len() - header)?;
+    buffer.extend_from_slice(&application[header..]);
+    Ok(())
+}
 
-        let date = crate::object::Date {
-            year,
-            month,
-            day,
-            weekday,
-        };
-        let time = crate::object::Time {
-            hour,
-            minute,
-            second,
-            hundredths,
-        };
-        Self::new(date, time)
+/// Decode a context tagged primitive whose datatype is implied by its tag
+///
+/// Returns the value and the number of bytes consumed.
+pub(crate) fn decode_context_value(
+    data: &[u8],
+    tag_number: u8,
+    datatype: ApplicationTag,
+) -> EncodingResult<(PropertyValue, usize)> {
+    let (tag, header) = decode_tag(data)?;
+    if tag.class != TagClass::Context || tag.number != tag_number || tag.is_opening {
+        return Err(EncodingError::InvalidTag);
+    }
+    let content = data
+        .get(header..header + tag.length)
+        .ok_or(EncodingError::UnexpectedEndOfData)?;
+
+    let mut application = Vec::new();
+    if datatype == ApplicationTag::Boolean {
+        let value = *content.first().ok_or(EncodingError::InvalidLength)?;
+        encoding::encode_application_tag(&mut application, datatype, value as usize)?;
+    } else {
+        encoding::encode_application_tag(&mut application, datatype, content.len())?;
+        application.extend_from_slice(content);
     }
+    let (value, _) = PropertyValue::decode(&application)?;
+    Ok((value, header + tag.length))
+}
 
-    /// Create unspecified time (all 255 values)
-    pub fn unspecified() -> Self {
+/// Range of items requested by ReadRange
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadRange {
+    /// Items counted from a 1-based position in the list (context tag 3)
+    ByPosition { reference_index: u32, count: i32 },
+    /// Items counted from a sequence number (context tag 6)
+    BySequenceNumber {
+        reference_sequence_number: u32,
+        count: i32,
+    },
+    /// Items newer (positive count) or older (negative count) than a time
+    /// (context tag 7)
+    ByTime {
+        reference_time: BacnetDateTime,
+        count: i32,
+    },
+}
+
+/// Read Range request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadRangeRequest {
+    /// Object identifier to read from
+    pub object_identifier: ObjectIdentifier,
+    /// Property identifier to read, usually Log_Buffer
+    pub property_identifier: u32,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Range to read; all items when absent
+    pub range: Option<ReadRange>,
+}
+
+impl ReadRangeRequest {
+    /// Create a new Read Range request
+    pub fn new(
+        object_identifier: ObjectIdentifier,
+        property_identifier: u32,
+        range: Option<ReadRange>,
+    ) -> Self {
         Self {
-            date: crate::object::Date {
-                year: 255,
-                month: 255,
-                day: 255,
-                weekday: 255,
-            },
-            time: crate::object::Time {
-                hour: 255,
-                minute: 255,
-                second: 255,
-                hundredths: 255,
-            },
+            object_identifier,
+            property_identifier,
+            property_array_index: None,
+            range,
         }
     }
 
-    /// Check if this datetime is unspecified
-    pub fn is_unspecified(&self) -> bool {
-        self.date.year == 255
-            && self.date.month == 255
-            && self.date.day == 255
-            && self.date.weekday == 255
-            && self.time.hour == 255
-            && self.time.minute == 255
-            && self.time.second == 255
-            && self.time.hundredths == 255
-    }
-
-    /// Encode BACnet DateTime
+    /// Encode the Read Range request
     pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
-        use crate::encoding::{encode_date, encode_time};
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.object_identifier.object_type.as_u16(),
+            self.object_identifier.instance,
+            0,
+        )?);
 
-        // Encode date
-        encode_date(
-            buffer,
-            self.date.year,
-            self.date.month,
-            self.date.day,
-            self.date.weekday,
-        )?;
+        // Property identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(self.property_identifier, 1)?);
 
-        // Encode time
-        encode_time(
-            buffer,
-            self.time.hour,
-            self.time.minute,
-            self.time.second,
-            self.time.hundredths,
-        )?;
+        // Property array index - context tag 2 (only when present)
+        if let Some(array_index) = self.property_array_index {
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 2)?);
+        }
+
+        match self.range {
+            Some(ReadRange::ByPosition {
+                reference_index,
+                count,
+            }) => {
+                encode_opening_tag(buffer, 3)?;
+                encode_unsigned(buffer, reference_index)?;
+                encoding::encode_signed(buffer, count)?;
+                encode_closing_tag(buffer, 3)?;
+            }
+            Some(ReadRange::BySequenceNumber {
+                reference_sequence_number,
+                count,
+            }) => {
+                encode_opening_tag(buffer, 6)?;
+                encode_unsigned(buffer, reference_sequence_number)?;
+                encoding::encode_signed(buffer, count)?;
+                encode_closing_tag(buffer, 6)?;
+            }
+            Some(ReadRange::ByTime {
+                reference_time,
+                count,
+            }) => {
+                encode_opening_tag(buffer, 7)?;
+                reference_time.encode(buffer)?;
+                encoding::encode_signed(buffer, count)?;
+                encode_closing_tag(buffer, 7)?;
+            }
+            None => {}
+        }
 
         Ok(())
     }
 
-    /// Decode BACnet DateTime
-    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
-        use crate::encoding::{decode_date, decode_time};
+    /// Decode a Read Range request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
 
-        // Decode date (4 bytes)
+        // Object identifier - context tag 0
+        let ((object_type, instance), consumed) = decode_context_object_id(data, 0)?;
+        let object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Property identifier - context tag 1
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Property array index - context tag 2 (optional)
+        let property_array_index = if is_context_tag(&data[pos..], 2) {
+            let (array_index, consumed) = decode_context_unsigned(&data[pos..], 2)?;
+            pos += consumed;
+            Some(array_index)
+        } else {
+            None
+        };
+
+        // Range - context tag 3, 6 or 7 (optional)
+        let range = if pos < data.len() {
+            let (tag, header) = decode_tag(&data[pos..])?;
+            if !tag.is_opening {
+                return Err(EncodingError::InvalidTag);
+            }
+            let closing = find_closing_tag(&data[pos..], tag.number)?;
+            let contents = &data[pos + header..pos + closing];
+            let range = match tag.number {
+                3 => {
+                    let (reference_index, consumed) = decode_unsigned(contents)?;
+                    let (count, _) = encoding::decode_signed(&contents[consumed..])?;
+                    ReadRange::ByPosition {
+                        reference_index,
+                        count,
+                    }
+                }
+                6 => {
+                    let (reference_sequence_number, consumed) = decode_unsigned(contents)?;
+                    let (count, _) = encoding::decode_signed(&contents[consumed..])?;
+                    ReadRange::BySequenceNumber {
+                        reference_sequence_number,
+                        count,
+                    }
+                }
+                7 => {
+                    let (reference_time, consumed) = BacnetDateTime::decode(contents)?;
+                    let (count, _) = encoding::decode_signed(&contents[consumed..])?;
+                    ReadRange::ByTime {
+                        reference_time,
+                        count,
+                    }
+                }
+                _ => return Err(EncodingError::InvalidTag),
+            };
+            Some(range)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            object_identifier,
+            property_identifier,
+            property_array_index,
+            range,
+        })
+    }
+}
+
+/// BACnetResultFlags of a Read Range acknowledgement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResultFlags {
+    /// The first returned item is the first item in the list
+    pub first_item: bool,
+    /// The last returned item is the last item in the list
+    pub last_item: bool,
+    /// More items matched the request than were returned
+    pub more_items: bool,
+}
+
+impl ResultFlags {
+    /// Convert to a bit string, first-item first
+    pub fn to_bitstring(&self) -> Vec<bool> {
+        vec![self.first_item, self.last_item, self.more_items]
+    }
+
+    /// Parse from a bit string, first-item first; missing bits are false
+    pub fn from_bitstring(bits: &[bool]) -> Self {
+        let bit = |index: usize| bits.get(index).copied().unwrap_or(false);
+        Self {
+            first_item: bit(0),
+            last_item: bit(1),
+            more_items: bit(2),
+        }
+    }
+}
+
+/// Read Range acknowledgement (confirmed service)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadRangeAck {
+    /// Object identifier that was read
+    pub object_identifier: ObjectIdentifier,
+    /// Property identifier that was read
+    pub property_identifier: u32,
+    /// Property array index (optional)
+    pub property_array_index: Option<u32>,
+    /// Result flags
+    pub result_flags: ResultFlags,
+    /// Number of items returned
+    pub item_count: u32,
+    /// Encoded items, e.g. BACnetLogRecord values
+    pub item_data: Vec<u8>,
+    /// Sequence number of the first item, for by-sequence and by-time reads
+    pub first_sequence_number: Option<u32>,
+}
+
+impl ReadRangeAck {
+    /// Encode the Read Range acknowledgement
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Object identifier - context tag 0
+        buffer.extend_from_slice(&encode_context_object_id(
+            self.object_identifier.object_type.as_u16(),
+            self.object_identifier.instance,
+            0,
+        )?);
+
+        // Property identifier - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(self.property_identifier, 1)?);
+
+        // Property array index - context tag 2 (only when present)
+        if let Some(array_index) = self.property_array_index {
+            buffer.extend_from_slice(&encode_context_unsigned(array_index, 2)?);
+        }
+
+        // Result flags - context tag 3
+        encode_context_value(
+            buffer,
+            3,
+            &PropertyValue::BitString(self.result_flags.to_bitstring()),
+        )?;
+
+        // Item count - context tag 4
+        buffer.extend_from_slice(&encode_context_unsigned(self.item_count, 4)?);
+
+        // Item data - context tag 5
+        encode_opening_tag(buffer, 5)?;
+        buffer.extend_from_slice(&self.item_data);
+        encode_closing_tag(buffer, 5)?;
+
+        // First sequence number - context tag 6 (only when present)
+        if let Some(sequence_number) = self.first_sequence_number {
+            buffer.extend_from_slice(&encode_context_unsigned(sequence_number, 6)?);
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Read Range acknowledgement
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Object identifier - context tag 0
+        let ((object_type, instance), consumed) = decode_context_object_id(data, 0)?;
+        let object_identifier = object_identifier_from_parts(object_type, instance)?;
+        pos += consumed;
+
+        // Property identifier - context tag 1
+        let (property_identifier, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        pos += consumed;
+
+        // Property array index - context tag 2 (optional)
+        let property_array_index = if is_context_tag(&data[pos..], 2) {
+            let (array_index, consumed) = decode_context_unsigned(&data[pos..], 2)?;
+            pos += consumed;
+            Some(array_index)
+        } else {
+            None
+        };
+
+        // Result flags - context tag 3
+        let (flags, consumed) = decode_context_value(&data[pos..], 3, ApplicationTag::BitString)?;
+        let result_flags = match flags {
+            PropertyValue::BitString(bits) => ResultFlags::from_bitstring(&bits),
+            _ => return Err(EncodingError::InvalidTag),
+        };
+        pos += consumed;
+
+        // Item count - context tag 4
+        let (item_count, consumed) = decode_context_unsigned(&data[pos..], 4)?;
+        pos += consumed;
+
+        // Item data - context tag 5
+        let closing = find_closing_tag(&data[pos..], 5)?;
+        let item_data = data[pos + 1..pos + closing].to_vec();
+        pos += closing + 1;
+
+        // First sequence number - context tag 6 (optional)
+        let first_sequence_number = if is_context_tag(&data[pos..], 6) {
+            let (sequence_number, _) = decode_context_unsigned(&data[pos..], 6)?;
+            Some(sequence_number)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            object_identifier,
+            property_identifier,
+            property_array_index,
+            result_flags,
+            item_count,
+            item_data,
+            first_sequence_number,
+        })
+    }
+}
+
+/// Time Synchronization request (unconfirmed service)
+#[derive(Debug, Clone)]
+pub struct TimeSynchronizationRequest {
+    /// Date and time to synchronize to
+    pub date_time: BacnetDateTime,
+}
+
+/// UTC Time Synchronization request (unconfirmed service)
+#[derive(Debug, Clone)]
+pub struct UtcTimeSynchronizationRequest {
+    /// UTC date and time to synchronize to
+    pub utc_date_time: BacnetDateTime,
+}
+
+/// BACnet Date and Time structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BacnetDateTime {
+    /// Date component
+    pub date: crate::object::Date,
+    /// Time component
+    pub time: crate::object::Time,
+}
+
+impl BacnetDateTime {
+    /// Create a new BACnet DateTime from Date and Time components
+    pub fn new(date: crate::object::Date, time: crate::object::Time) -> Self {
+        Self { date, time }
+    }
+
+    /// Create from current system time (requires std feature)
+    #[cfg(feature = "std")]
+    pub fn now() -> Self {
+        use chrono::{Datelike, Local, Timelike};
+
+        let now = Local::now();
+        let year = now.year() as u16;
+        let month = now.month() as u8;
+        let day = now.day() as u8;
+        let weekday = now.weekday().number_from_monday() as u8; // BACnet uses 1=Monday
+        let hour = now.hour() as u8;
+        let minute = now.minute() as u8;
+        let second = now.second() as u8;
+        let hundredths = (now.nanosecond() / 10_000_000) as u8;
+
+        let date = crate::object::Date {
+            year,
+            month,
+            day,
+            weekday,
+        };
+        let time = crate::object::Time {
+            hour,
+            minute,
+            second,
+            hundredths,
+        };
+        Self::new(date, time)
+    }
+
+    /// Create unspecified time (all 255 values)
+    pub fn unspecified() -> Self {
+        Self {
+            date: crate::object::Date {
+                year: 255,
+                month: 255,
+                day: 255,
+                weekday: 255,
+            },
+            time: crate::object::Time {
+                hour: 255,
+                minute: 255,
+                second: 255,
+                hundredths: 255,
+            },
+        }
+    }
+
+    /// Time since 1900-01-01 00:00, for measuring intervals between two
+    /// readings of a clock
+    ///
+    /// Returns `None` unless every date and time field is specified and in
+    /// range.
+    pub fn since_1900(&self) -> Option<core::time::Duration> {
+        let crate::object::Date {
+            year, month, day, ..
+        } = self.date;
+        let time = self.time;
+        if !(1900..=2154).contains(&year)
+            || !(1..=12).contains(&month)
+            || day == 0
+            || day > crate::datatypes::days_in_month(year, month)
+            || time.hour > 23
+            || time.minute > 59
+            || time.second > 59
+            || time.hundredths > 99
+        {
+            return None;
+        }
+
+        let leap_days = |year: u64| year / 4 - year / 100 + year / 400;
+        let mut days = (year as u64 - 1900) * 365 + leap_days(year as u64 - 1) - leap_days(1899);
+        days += (1..month)
+            .map(|month| crate::datatypes::days_in_month(year, month) as u64)
+            .sum::<u64>();
+        days += day as u64 - 1;
+        let seconds =
+            ((days * 24 + time.hour as u64) * 60 + time.minute as u64) * 60 + time.second as u64;
+        Some(
+            core::time::Duration::from_secs(seconds)
+                + core::time::Duration::from_millis(time.hundredths as u64 * 10),
+        )
+    }
+
+    /// Check if this datetime is unspecified
+    pub fn is_unspecified(&self) -> bool {
+        self.date.year == 255
+            && self.date.month == 255
+            && self.date.day == 255
+            && self.date.weekday == 255
+            && self.time.hour == 255
+            && self.time.minute == 255
+            && self.time.second == 255
+            && self.time.hundredths == 255
+    }
+
+    /// Encode BACnet DateTime
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        use crate::encoding::{encode_date, encode_time};
+
+        // Encode date
+        encode_date(
+            buffer,
+            self.date.year,
+            self.date.month,
+            self.date.day,
+            self.date.weekday,
+        )?;
+
+        // Encode time
+        encode_time(
+            buffer,
+            self.time.hour,
+            self.time.minute,
+            self.time.second,
+            self.time.hundredths,
+        )?;
+
+        Ok(())
+    }
+
+    /// Decode BACnet DateTime
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        use crate::encoding::{decode_date, decode_time};
+
+        // Decode date (4 bytes)
         let ((year, month, day, weekday), consumed_date) = decode_date(data)?;
 
         // Decode time (4 bytes)
@@ -1760,6 +4417,63 @@ impl BacnetDateTime {
     }
 }
 
+/// BACnetTimeStamp: when an event happened
+///
+/// Devices may stamp events with a time of day, a sequence number or a full
+/// date and time; the choice is carried by context tags 0, 1 and 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeStamp {
+    /// Time of day (context tag 0)
+    Time(crate::object::Time),
+    /// Sequence number, 0-65535 (context tag 1)
+    SequenceNumber(u16),
+    /// Date and time (context tag 2)
+    DateTime(BacnetDateTime),
+}
+
+impl TimeStamp {
+    /// Encode the time stamp choice
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        match self {
+            TimeStamp::Time(time) => encode_context_value(buffer, 0, &PropertyValue::Time(*time)),
+            TimeStamp::SequenceNumber(number) => {
+                buffer.extend_from_slice(&encode_context_unsigned(*number as u32, 1)?);
+                Ok(())
+            }
+            TimeStamp::DateTime(date_time) => {
+                encode_opening_tag(buffer, 2)?;
+                date_time.encode(buffer)?;
+                encode_closing_tag(buffer, 2)
+            }
+        }
+    }
+
+    /// Decode a time stamp choice, returning it and the bytes consumed
+    pub fn decode(data: &[u8]) -> EncodingResult<(Self, usize)> {
+        let (tag, _) = decode_tag(data)?;
+        if tag.class != TagClass::Context {
+            return Err(EncodingError::InvalidTag);
+        }
+        match tag.number {
+            0 => match decode_context_value(data, 0, ApplicationTag::Time)? {
+                (PropertyValue::Time(time), consumed) => Ok((TimeStamp::Time(time), consumed)),
+                _ => Err(EncodingError::InvalidTag),
+            },
+            1 => {
+                let (number, consumed) = decode_context_unsigned(data, 1)?;
+                let number = u16::try_from(number).map_err(|_| EncodingError::ValueOutOfRange)?;
+                Ok((TimeStamp::SequenceNumber(number), consumed))
+            }
+            2 => {
+                let closing = find_closing_tag(data, 2)?;
+                let (date_time, _) = BacnetDateTime::decode(&data[1..closing])?;
+                Ok((TimeStamp::DateTime(date_time), closing + 1))
+            }
+            _ => Err(EncodingError::InvalidTag),
+        }
+    }
+}
+
 impl TimeSynchronizationRequest {
     /// Create a new Time Synchronization request
     pub fn new(date_time: BacnetDateTime) -> Self {
@@ -1833,11 +4547,547 @@ impl UtcTimeSynchronizationRequest {
     }
 }
 
+/// Communication state requested by DeviceCommunicationControl
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum EnableDisable {
+    /// Communicate normally
+    Enable = 0,
+    /// Respond only to DeviceCommunicationControl and ReinitializeDevice
+    Disable = 1,
+    /// Respond to requests, but initiate nothing except I-Am answers to Who-Is
+    DisableInitiation = 2,
+}
+
+impl TryFrom<u32> for EnableDisable {
+    type Error = EncodingError;
+
+    fn try_from(value: u32) -> EncodingResult<Self> {
+        match value {
+            0 => Ok(Self::Enable),
+            1 => Ok(Self::Disable),
+            2 => Ok(Self::DisableInitiation),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+}
+
+/// Device Communication Control request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceCommunicationControlRequest {
+    /// Minutes until communication is re-enabled; `None` lasts indefinitely
+    pub time_duration: Option<u16>,
+    /// Requested communication state
+    pub enable_disable: EnableDisable,
+    /// Password, if the device requires one
+    pub password: Option<String>,
+}
+
+impl DeviceCommunicationControlRequest {
+    /// Create a new Device Communication Control request without a password
+    pub fn new(enable_disable: EnableDisable, time_duration: Option<u16>) -> Self {
+        Self {
+            time_duration,
+            enable_disable,
+            password: None,
+        }
+    }
+
+    /// Set the password sent with the request
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Encode the Device Communication Control request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Time duration - context tag 0 (optional)
+        if let Some(minutes) = self.time_duration {
+            buffer.extend_from_slice(&encode_context_unsigned(minutes as u32, 0)?);
+        }
+
+        // Enable/disable - context tag 1
+        buffer.extend_from_slice(&encode_context_enumerated(self.enable_disable as u32, 1)?);
+
+        // Password - context tag 2 (optional)
+        if let Some(password) = &self.password {
+            encode_context_value(buffer, 2, &PropertyValue::CharacterString(password.clone()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Device Communication Control request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let mut pos = 0;
+
+        // Time duration - context tag 0 (optional)
+        let time_duration = if is_context_tag(data, 0) {
+            let (minutes, consumed) = decode_context_unsigned(data, 0)?;
+            pos += consumed;
+            Some(u16::try_from(minutes).map_err(|_| EncodingError::ValueOutOfRange)?)
+        } else {
+            None
+        };
+
+        // Enable/disable - context tag 1
+        let (enable_disable, consumed) = decode_context_enumerated(&data[pos..], 1)?;
+        let enable_disable = EnableDisable::try_from(enable_disable)?;
+        pos += consumed;
+
+        // Password - context tag 2 (optional)
+        let password = if pos < data.len() {
+            match decode_context_value(&data[pos..], 2, ApplicationTag::CharacterString)? {
+                (PropertyValue::CharacterString(password), _) => Some(password),
+                _ => return Err(EncodingError::InvalidTag),
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            time_duration,
+            enable_disable,
+            password,
+        })
+    }
+}
+
+/// State requested by ReinitializeDevice
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum ReinitializedState {
+    ColdStart = 0,
+    WarmStart = 1,
+    StartBackup = 2,
+    EndBackup = 3,
+    StartRestore = 4,
+    EndRestore = 5,
+    AbortRestore = 6,
+    ActivateChanges = 7,
+}
+
+impl TryFrom<u32> for ReinitializedState {
+    type Error = EncodingError;
+
+    fn try_from(value: u32) -> EncodingResult<Self> {
+        match value {
+            0 => Ok(Self::ColdStart),
+            1 => Ok(Self::WarmStart),
+            2 => Ok(Self::StartBackup),
+            3 => Ok(Self::EndBackup),
+            4 => Ok(Self::StartRestore),
+            5 => Ok(Self::EndRestore),
+            6 => Ok(Self::AbortRestore),
+            7 => Ok(Self::ActivateChanges),
+            _ => Err(EncodingError::ValueOutOfRange),
+        }
+    }
+}
+
+/// Reinitialize Device request (confirmed service)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReinitializeDeviceRequest {
+    /// Requested state
+    pub reinitialized_state: ReinitializedState,
+    /// Password, if the device requires one
+    pub password: Option<String>,
+}
+
+impl ReinitializeDeviceRequest {
+    /// Create a new Reinitialize Device request without a password
+    pub fn new(reinitialized_state: ReinitializedState) -> Self {
+        Self {
+            reinitialized_state,
+            password: None,
+        }
+    }
+
+    /// Set the password sent with the request
+    pub fn with_password(mut self, password: impl Into<String>) -> Self {
+        self.password = Some(password.into());
+        self
+    }
+
+    /// Encode the Reinitialize Device request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        // Reinitialized state - context tag 0
+        buffer.extend_from_slice(&encode_context_enumerated(
+            self.reinitialized_state as u32,
+            0,
+        )?);
+
+        // Password - context tag 1 (optional)
+        if let Some(password) = &self.password {
+            encode_context_value(buffer, 1, &PropertyValue::CharacterString(password.clone()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Decode a Reinitialize Device request
+    ///
+    /// Unknown states fail with [`EncodingError::ValueOutOfRange`].
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        // Reinitialized state - context tag 0
+        let (state, pos) = decode_context_enumerated(data, 0)?;
+        let reinitialized_state = ReinitializedState::try_from(state)?;
+
+        // Password - context tag 1 (optional)
+        let password = if pos < data.len() {
+            match decode_context_value(&data[pos..], 1, ApplicationTag::CharacterString)? {
+                (PropertyValue::CharacterString(password), _) => Some(password),
+                _ => return Err(EncodingError::InvalidTag),
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            reinitialized_state,
+            password,
+        })
+    }
+}
+
+/// Encode the vendor ID, service number and optional block of the private
+/// transfer services, tagged from `first_tag` upward
+fn encode_private_transfer(
+    buffer: &mut Vec<u8>,
+    first_tag: u8,
+    vendor_id: u16,
+    service_number: u32,
+    block: Option<&[u8]>,
+) -> EncodingResult<()> {
+    buffer.extend_from_slice(&encode_context_unsigned(vendor_id as u32, first_tag)?);
+    buffer.extend_from_slice(&encode_context_unsigned(service_number, first_tag + 1)?);
+    if let Some(block) = block {
+        encode_opening_tag(buffer, first_tag + 2)?;
+        buffer.extend_from_slice(block);
+        encode_closing_tag(buffer, first_tag + 2)?;
+    }
+    Ok(())
+}
+
+/// Decode the vendor ID, service number and optional block of the private
+/// transfer services, tagged from `first_tag` upward
+///
+/// The block is kept as the raw bytes between its opening and closing tags.
+fn decode_private_transfer(
+    data: &[u8],
+    first_tag: u8,
+) -> EncodingResult<(u16, u32, Option<Vec<u8>>)> {
+    let (vendor_id, mut pos) = decode_context_unsigned(data, first_tag)?;
+    let vendor_id = u16::try_from(vendor_id).map_err(|_| EncodingError::ValueOutOfRange)?;
+    let (service_number, consumed) = decode_context_unsigned(&data[pos..], first_tag + 1)?;
+    pos += consumed;
+
+    let block = if is_opening_tag(&data[pos..], first_tag + 2) {
+        let closing = pos + find_closing_tag(&data[pos..], first_tag + 2)?;
+        Some(data[pos + 1..closing].to_vec())
+    } else {
+        None
+    };
+    Ok((vendor_id, service_number, block))
+}
+
+/// Private Transfer request (confirmed or unconfirmed service)
+///
+/// Invokes a vendor's proprietary service. The parameters are kept as the
+/// encoded application-tagged data, for the vendor's own codec to interpret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrivateTransferRequest {
+    /// Vendor defining the service
+    pub vendor_id: u16,
+    /// Service number within the vendor's services
+    pub service_number: u32,
+    /// Encoded service parameters (optional)
+    pub service_parameters: Option<Vec<u8>>,
+}
+
+impl PrivateTransferRequest {
+    /// Create a new Private Transfer request without parameters
+    pub fn new(vendor_id: u16, service_number: u32) -> Self {
+        Self {
+            vendor_id,
+            service_number,
+            service_parameters: None,
+        }
+    }
+
+    /// Set the encoded service parameters
+    pub fn with_parameters(mut self, service_parameters: Vec<u8>) -> Self {
+        self.service_parameters = Some(service_parameters);
+        self
+    }
+
+    /// Encode the Private Transfer request
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        encode_private_transfer(
+            buffer,
+            0,
+            self.vendor_id,
+            self.service_number,
+            self.service_parameters.as_deref(),
+        )
+    }
+
+    /// Decode a Private Transfer request
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (vendor_id, service_number, service_parameters) = decode_private_transfer(data, 0)?;
+        Ok(Self {
+            vendor_id,
+            service_number,
+            service_parameters,
+        })
+    }
+}
+
+/// ConfirmedPrivateTransfer acknowledgement (complex ack)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmedPrivateTransferAck {
+    /// Vendor defining the service
+    pub vendor_id: u16,
+    /// Service number within the vendor's services
+    pub service_number: u32,
+    /// Encoded result block (optional)
+    pub result_block: Option<Vec<u8>>,
+}
+
+impl ConfirmedPrivateTransferAck {
+    /// Encode the ConfirmedPrivateTransfer acknowledgement
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> EncodingResult<()> {
+        encode_private_transfer(
+            buffer,
+            0,
+            self.vendor_id,
+            self.service_number,
+            self.result_block.as_deref(),
+        )
+    }
+
+    /// Decode a ConfirmedPrivateTransfer acknowledgement
+    pub fn decode(data: &[u8]) -> EncodingResult<Self> {
+        let (vendor_id, service_number, result_block) = decode_private_transfer(data, 0)?;
+        Ok(Self {
+            vendor_id,
+            service_number,
+            result_block,
+        })
+    }
+}
+
+/// ConfirmedPrivateTransfer-Error: why a vendor's service failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmedPrivateTransferError {
+    /// Error class
+    pub error_class: u32,
+    /// Error code
+    pub error_code: u32,
+    /// Vendor defining the service
+    pub vendor_id: u16,
+    /// Service number within the vendor's services
+    pub service_number: u32,
+    /// Encoded error parameters (optional)
+    pub error_parameters: Option<Vec<u8>>,
+}
+
+impl ConfirmedPrivateTransferError {
+    /// Get the error as a [`BacnetError`]
+    pub fn error(&self) -> BacnetError {
+        BacnetError::from_error(self.error_class, self.error_code)
+    }
+
+    /// Build the Error PDU answering the request with `invoke_id`
+    pub fn to_apdu(&self, invoke_id: u8) -> EncodingResult<Apdu> {
+        // Vendor ID, service number and error parameters - context tags 1-3
+        let mut error_data = Vec::new();
+        encode_private_transfer(
+            &mut error_data,
+            1,
+            self.vendor_id,
+            self.service_number,
+            self.error_parameters.as_deref(),
+        )?;
+
+        Ok(Apdu::Error {
+            invoke_id,
+            service_choice: ConfirmedServiceChoice::ConfirmedPrivateTransfer as u8,
+            error_class: self.error_class,
+            error_code: self.error_code,
+            error_data,
+        })
+    }
+
+    /// Decode from the Error PDU answering a ConfirmedPrivateTransfer request
+    pub fn from_apdu(apdu: &Apdu) -> EncodingResult<Self> {
+        let Apdu::Error {
+            error_class,
+            error_code,
+            error_data,
+            ..
+        } = apdu
+        else {
+            return Err(EncodingError::InvalidFormat("Not an Error PDU".to_string()));
+        };
+        let (vendor_id, service_number, error_parameters) = decode_private_transfer(error_data, 1)?;
+        Ok(Self {
+            error_class: *error_class,
+            error_code: *error_code,
+            vendor_id,
+            service_number,
+            error_parameters,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::object::{ObjectIdentifier, ObjectType};
 
+    #[test]
+    fn test_read_property_request_round_trip() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let request = ReadPropertyRequest::new(object_id, 85);
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        // No array index means no context tag 2
+        assert_eq!(buffer, vec![0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55]);
+        assert_eq!(ReadPropertyRequest::decode(&buffer).unwrap(), request);
+
+        let indexed = ReadPropertyRequest::with_array_index(object_id, 87, 8);
+        buffer.clear();
+        indexed.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[7..], &[0x29, 0x08]);
+        assert_eq!(ReadPropertyRequest::decode(&buffer).unwrap(), indexed);
+    }
+
+    #[test]
+    fn test_reserved_object_type_decodes() {
+        // Object type 100 is reserved for a later revision of the standard
+        let buffer = [0x0C, 0x19, 0x00, 0x00, 0x01, 0x19, 0x55];
+        let request = ReadPropertyRequest::decode(&buffer).unwrap();
+        assert_eq!(
+            request.object_identifier.object_type,
+            ObjectType::Reserved(100)
+        );
+        assert_eq!(request.object_identifier.instance, 1);
+
+        let mut encoded = Vec::new();
+        request.encode(&mut encoded).unwrap();
+        assert_eq!(encoded, buffer);
+    }
+
+    #[test]
+    fn test_read_property_ack_captured_analog_input() {
+        // ReadProperty-ACK for analog-input,1 present-value = 72.25 as seen on the wire
+        let apdu = [
+            0x30, 0x01, 0x0C, 0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55, 0x3E, 0x44, 0x42, 0x90,
+            0x80, 0x00, 0x3F,
+        ];
+        let service_data = match crate::app::Apdu::decode(&apdu).unwrap() {
+            crate::app::Apdu::ComplexAck {
+                invoke_id,
+                service_choice,
+                service_data,
+                ..
+            } => {
+                assert_eq!(invoke_id, 1);
+                assert_eq!(service_choice, ConfirmedServiceChoice::ReadProperty as u8);
+                service_data
+            }
+            other => panic!("Expected ComplexAck, got {:?}", other),
+        };
+
+        let ack = ReadPropertyAck::decode(&service_data).unwrap();
+        assert_eq!(
+            ack.object_identifier,
+            ObjectIdentifier::new(ObjectType::AnalogInput, 1)
+        );
+        assert_eq!(ack.property_identifier, 85);
+        assert_eq!(ack.property_array_index, None);
+        assert!(matches!(ack.value, PropertyValue::Real(v) if v == 72.25));
+
+        let mut encoded = Vec::new();
+        ack.encode(&mut encoded).unwrap();
+        assert_eq!(encoded, &apdu[3..]);
+    }
+
+    #[test]
+    fn test_read_property_ack_empty_and_single_element_lists() {
+        // Active_COV_Subscriptions of device,1 with no subscriptions: 3E 3F
+        let data = [0x0C, 0x02, 0x00, 0x00, 0x01, 0x19, 0x98, 0x3E, 0x3F];
+        let ack = ReadPropertyAck::decode(&data).unwrap();
+        assert!(matches!(&ack.value, PropertyValue::Array(values) if values.is_empty()));
+        let mut encoded = Vec::new();
+        ack.encode(&mut encoded).unwrap();
+        assert_eq!(encoded, data);
+
+        // An Object_List holding only the device stays an array
+        let ack = ReadPropertyAck::new(
+            ObjectIdentifier::new(ObjectType::Device, 1),
+            PropertyIdentifier::ObjectList.as_u32(),
+            PropertyValue::Array(vec![PropertyValue::ObjectIdentifier(
+                ObjectIdentifier::new(ObjectType::Device, 1),
+            )]),
+        );
+        let mut encoded = Vec::new();
+        ack.encode(&mut encoded).unwrap();
+        assert!(matches!(
+            ReadPropertyAck::decode(&encoded).unwrap().value,
+            PropertyValue::Array(values) if matches!(
+                values.as_slice(),
+                [PropertyValue::ObjectIdentifier(id)] if id.object_type == ObjectType::Device
+            )
+        ));
+
+        // A single element of an array read by index is a scalar
+        let mut ack = ack;
+        ack.property_array_index = Some(1);
+        ack.value = PropertyValue::ObjectIdentifier(ObjectIdentifier::new(ObjectType::Device, 1));
+        let mut encoded = Vec::new();
+        ack.encode(&mut encoded).unwrap();
+        assert!(matches!(
+            ReadPropertyAck::decode(&encoded).unwrap().value,
+            PropertyValue::ObjectIdentifier(_)
+        ));
+
+        // Empty contents of a scalar property are still malformed
+        let data = [0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55, 0x3E, 0x3F];
+        assert!(matches!(
+            ReadPropertyAck::decode(&data),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+    }
+
+    #[test]
+    fn test_read_property_ack_context_tagged_value() {
+        // Date_List of calendar,1 holding the date entry 2025-12-25 (Thursday)
+        let date_list = vec![0x0C, 125, 12, 25, 4];
+        let ack = ReadPropertyAck::new(
+            ObjectIdentifier::new(ObjectType::Calendar, 1),
+            PropertyIdentifier::DateList.as_u32(),
+            PropertyValue::Constructed(date_list.clone()),
+        );
+        let mut encoded = Vec::new();
+        ack.encode(&mut encoded).unwrap();
+        match ReadPropertyAck::decode(&encoded).unwrap().value {
+            PropertyValue::Constructed(data) => assert_eq!(data, date_list),
+            other => panic!("Expected Constructed, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_read_property_response_value_containing_closing_tag_byte() {
+        // Unsigned 0x3F inside the value must not end the value early
+        let data = [
+            0x0C, 0x00, 0x00, 0x00, 0x01, 0x19, 0x55, 0x3E, 0x21, 0x3F, 0x3F,
+        ];
+        let response = ReadPropertyResponse::decode(&data).unwrap();
+        assert_eq!(response.property_value, vec![0x21, 0x3F]);
+    }
+
     #[test]
     fn test_whois_request() {
         // Test Who-Is for all devices
@@ -1880,7 +5130,7 @@ mod tests {
     #[test]
     fn test_iam_request() {
         let device_id = ObjectIdentifier::new(ObjectType::Device, 123);
-        let iam = IAmRequest::new(device_id, 1476, 0, 999);
+        let iam = IAmRequest::new(device_id, 1476, Segmentation::Both, 999);
 
         assert_eq!(iam.device_identifier.instance, 123);
         assert_eq!(iam.max_apdu_length_accepted, 1476);
@@ -1888,43 +5138,282 @@ mod tests {
     }
 
     #[test]
-    fn test_read_property_request() {
-        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
-        let read_prop = ReadPropertyRequest::new(object_id, 85); // Present Value
+    fn test_iam_segmentation_decoding() {
+        // I-Am from device 260001, max APDU 480, no segmentation, vendor 260
+        let data = [
+            0xC4, 0x02, 0x03, 0xF7, 0xA1, 0x22, 0x01, 0xE0, 0x91, 0x03, 0x22, 0x01, 0x04,
+        ];
+        let iam = IAmRequest::decode(&data).unwrap();
+        assert_eq!(
+            iam.device_identifier,
+            ObjectIdentifier::new(ObjectType::Device, 260001)
+        );
+        assert_eq!(iam.max_apdu_length_accepted, 480);
+        assert_eq!(iam.segmentation_supported, Segmentation::NoSegmentation);
+        assert_eq!(iam.vendor_identifier, 260);
+
+        let mut buffer = Vec::new();
+        iam.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
+
+        // Segmentation values beyond no-segmentation are rejected
+        let mut invalid = data;
+        invalid[9] = 0x04;
+        assert!(IAmRequest::decode(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_whois_low_limit_without_high_limit() {
+        assert!(WhoIsRequest::decode(&[0x09, 0x64]).is_err());
+    }
+
+    #[test]
+    fn test_read_property_request() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let read_prop = ReadPropertyRequest::new(object_id, 85); // Present Value
+
+        assert_eq!(read_prop.object_identifier.instance, 1);
+        assert_eq!(read_prop.property_identifier, 85);
+        assert_eq!(read_prop.property_array_index, None);
+
+        let read_prop_array = ReadPropertyRequest::with_array_index(object_id, 85, 0);
+        assert_eq!(read_prop_array.property_array_index, Some(0));
+    }
+
+    #[test]
+    fn test_write_property_request() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+        let property_value = vec![0x44, 0x42, 0x20, 0x00, 0x00]; // Real 40.0
+        let write_prop = WritePropertyRequest::new(object_id, 85, property_value.clone());
+
+        assert_eq!(write_prop.object_identifier.instance, 1);
+        assert_eq!(write_prop.property_identifier, 85);
+        assert_eq!(write_prop.property_value, property_value);
+        assert_eq!(write_prop.priority, None);
+
+        // Test with priority
+        let write_prop_priority =
+            WritePropertyRequest::with_priority(object_id, 85, property_value.clone(), 8);
+        assert_eq!(write_prop_priority.priority, Some(8));
+
+        // Test encoding/decoding
+        let mut buffer = Vec::new();
+        write_prop.encode(&mut buffer).unwrap();
+        assert!(!buffer.is_empty());
+
+        let decoded = WritePropertyRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.object_identifier.instance, 1);
+        assert_eq!(decoded.property_identifier, 85);
+        assert_eq!(decoded.property_value, property_value);
+    }
+
+    #[test]
+    fn test_write_property_priority_round_trip() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 7);
+        let mut request =
+            WritePropertyRequest::with_value(object_id, 85, &PropertyValue::Real(21.5)).unwrap();
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        // No priority tag when none is given
+        assert_eq!(buffer.last(), Some(&0x3F));
+
+        request.priority = Some(16);
+        request.property_array_index = Some(3);
+        buffer.clear();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[buffer.len() - 2..], &[0x49, 16]);
+
+        let decoded = WritePropertyRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.object_identifier, object_id);
+        assert_eq!(decoded.property_array_index, Some(3));
+        assert_eq!(decoded.priority, Some(16));
+        assert!(matches!(decoded.value().unwrap(), PropertyValue::Real(v) if v == 21.5));
+    }
+
+    #[test]
+    fn test_write_property_invalid_priority() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+        let mut request =
+            WritePropertyRequest::with_value(object_id, 85, &PropertyValue::Real(1.0)).unwrap();
+
+        for priority in [0, 17] {
+            request.priority = Some(priority);
+            let mut buffer = Vec::new();
+            assert!(matches!(
+                request.encode(&mut buffer),
+                Err(EncodingError::ValueOutOfRange)
+            ));
+        }
+
+        request.priority = None;
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        buffer.extend_from_slice(&[0x49, 0]);
+        assert!(matches!(
+            WritePropertyRequest::decode(&buffer),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn test_write_property_large_property_identifier() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogValue, 1);
+        let request =
+            WritePropertyRequest::with_value(object_id, 512, &PropertyValue::Boolean(true))
+                .unwrap();
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        let decoded = WritePropertyRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.property_identifier, 512);
+        assert!(matches!(
+            decoded.value().unwrap(),
+            PropertyValue::Boolean(true)
+        ));
+    }
+
+    #[test]
+    fn test_write_property_default_priority() {
+        use crate::object::{AnalogOutput, BacnetObject, PropertyIdentifier};
+
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
+        let mut request = WritePropertyRequest::with_value(
+            object_id,
+            PropertyIdentifier::PresentValue.as_u32(),
+            &PropertyValue::Real(42.0),
+        )
+        .unwrap();
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        let decoded = WritePropertyRequest::decode(&buffer).unwrap();
+
+        let mut output = AnalogOutput::new(1, "AO-1".to_string());
+        output
+            .write_property(
+                PropertyIdentifier::PresentValue,
+                decoded.value().unwrap(),
+                decoded.priority,
+            )
+            .unwrap();
+        assert_eq!(output.priority_array.get(16), Some(42.0));
+        assert_eq!(output.present_value, 42.0);
+
+        request.priority = Some(8);
+        buffer.clear();
+        request.encode(&mut buffer).unwrap();
+        let decoded = WritePropertyRequest::decode(&buffer).unwrap();
+        output
+            .write_property(
+                PropertyIdentifier::PresentValue,
+                PropertyValue::Real(10.0),
+                decoded.priority,
+            )
+            .unwrap();
+        assert_eq!(output.priority_array.get(8), Some(10.0));
+        assert_eq!(output.present_value, 10.0);
+    }
+
+    #[test]
+    fn test_read_property_multiple_request_round_trip() {
+        let device = ObjectIdentifier::new(ObjectType::Device, 1234);
+        let analog = ObjectIdentifier::new(ObjectType::AnalogInput, 3);
+        let request = ReadPropertyMultipleRequest::new(vec![
+            ReadAccessSpecification::new(device, vec![PropertyReference::all()]),
+            ReadAccessSpecification::new(
+                analog,
+                vec![
+                    PropertyReference::new(85),
+                    PropertyReference::with_array_index(87, 16),
+                    PropertyReference::required(),
+                ],
+            ),
+        ]);
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            &buffer[..9],
+            &[0x0C, 0x02, 0x00, 0x04, 0xD2, 0x1E, 0x09, 0x08, 0x1F]
+        );
+
+        let decoded = ReadPropertyMultipleRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.read_access_specifications.len(), 2);
+        assert!(decoded.read_access_specifications[0].property_references[0].is_special());
+        let references = &decoded.read_access_specifications[1].property_references;
+        assert_eq!(references.len(), 3);
+        assert_eq!(references[1].property_array_index, Some(16));
+        assert_eq!(references[2].property_identifier, PROPERTY_REQUIRED);
+        assert!(!references[0].is_special());
+    }
+
+    #[test]
+    fn test_read_property_multiple_ack_with_errors() {
+        // Ack for AI:1 { Present_Value = 72.5, Units = error(property, unknown-property) }
+        let data = [
+            0x0C, 0x00, 0x00, 0x00, 0x01, // Object identifier AI:1
+            0x1E, // Opening tag 1
+            0x29, 0x55, // Present_Value
+            0x4E, 0x44, 0x42, 0x91, 0x00, 0x00, 0x4F, // Real 72.5
+            0x29, 0x75, // Units
+            0x5E, 0x91, 0x02, 0x91, 0x20, 0x5F, // Error: property / unknown-property
+            0x1F, // Closing tag 1
+        ];
 
-        assert_eq!(read_prop.object_identifier.instance, 1);
-        assert_eq!(read_prop.property_identifier, 85);
-        assert_eq!(read_prop.property_array_index, None);
+        let ack = ReadPropertyMultipleAck::decode(&data).unwrap();
+        assert_eq!(ack.read_access_results.len(), 1);
+        let results = &ack.read_access_results[0].results;
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[0].value, Ok(PropertyValue::Real(v)) if v == 72.5));
+        assert_eq!(
+            results[1].value.as_ref().unwrap_err(),
+            &PropertyAccessError {
+                error_class: ErrorClass::Property,
+                error_code: 32,
+            }
+        );
 
-        let read_prop_array = ReadPropertyRequest::with_array_index(object_id, 85, 0);
-        assert_eq!(read_prop_array.property_array_index, Some(0));
+        let mut buffer = Vec::new();
+        ack.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, data);
     }
 
     #[test]
-    fn test_write_property_request() {
-        let object_id = ObjectIdentifier::new(ObjectType::AnalogOutput, 1);
-        let property_value = vec![0x44, 0x42, 0x20, 0x00, 0x00]; // Real 40.0
-        let write_prop = WritePropertyRequest::new(object_id, 85, property_value.clone());
-
-        assert_eq!(write_prop.object_identifier.instance, 1);
-        assert_eq!(write_prop.property_identifier, 85);
-        assert_eq!(write_prop.property_value, property_value);
-        assert_eq!(write_prop.priority, None);
+    fn test_read_property_multiple_ack_expanded_all() {
+        let object_id = ObjectIdentifier::new(ObjectType::BinaryValue, 2);
+        let ack = ReadPropertyMultipleAck::new(vec![ReadAccessResult {
+            object_identifier: object_id,
+            results: vec![
+                ReadResult {
+                    property_identifier: 77,
+                    property_array_index: None,
+                    value: Ok(PropertyValue::CharacterString("BV-2".to_string())),
+                },
+                ReadResult {
+                    property_identifier: 87,
+                    property_array_index: Some(1),
+                    value: Ok(PropertyValue::Null),
+                },
+                ReadResult {
+                    property_identifier: 85,
+                    property_array_index: None,
+                    value: Ok(PropertyValue::Enumerated(1)),
+                },
+            ],
+        }]);
 
-        // Test with priority
-        let write_prop_priority =
-            WritePropertyRequest::with_priority(object_id, 85, property_value.clone(), 8);
-        assert_eq!(write_prop_priority.priority, Some(8));
-
-        // Test encoding/decoding
         let mut buffer = Vec::new();
-        write_prop.encode(&mut buffer).unwrap();
-        assert!(!buffer.is_empty());
-
-        let decoded = WritePropertyRequest::decode(&buffer).unwrap();
-        assert_eq!(decoded.object_identifier.instance, 1);
-        assert_eq!(decoded.property_identifier, 85);
-        assert_eq!(decoded.property_value, property_value);
+        ack.encode(&mut buffer).unwrap();
+        let decoded = ReadPropertyMultipleAck::decode(&buffer).unwrap();
+        let results = &decoded.read_access_results[0].results;
+        assert_eq!(decoded.read_access_results[0].object_identifier, object_id);
+        assert_eq!(results.len(), 3);
+        assert!(
+            matches!(&results[0].value, Ok(PropertyValue::CharacterString(name)) if name == "BV-2")
+        );
+        assert_eq!(results[1].property_array_index, Some(1));
+        assert!(matches!(results[1].value, Ok(PropertyValue::Null)));
+        assert!(matches!(results[2].value, Ok(PropertyValue::Enumerated(1))));
     }
 
     #[test]
@@ -2013,13 +5502,32 @@ mod tests {
         assert_eq!(manager.subscriptions.len(), 0);
     }
 
+    #[test]
+    fn test_cov_subscription_manager_returns_expired() {
+        let mut manager = CovSubscriptionManager::new();
+        let device_id = ObjectIdentifier::new(ObjectType::Device, 1);
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        manager.add_subscription(CovSubscription::new(123, device_id, object_id, 3600));
+        manager.add_subscription(CovSubscription::new(124, device_id, object_id, 7200));
+
+        assert!(manager.update_timers(1800).is_empty());
+        let expired = manager.update_timers(1800);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].subscriber_process_identifier, 123);
+        assert_eq!(manager.subscriptions.len(), 1);
+
+        // Nothing is left for cleanup_expired to remove
+        manager.cleanup_expired();
+        assert_eq!(manager.subscriptions.len(), 1);
+    }
+
     #[test]
     fn test_cov_notification_request() {
         let device_id = ObjectIdentifier::new(ObjectType::Device, 1);
         let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
         let values = vec![
-            crate::object::PropertyValue::Real(25.5), // Present Value
-            crate::object::PropertyValue::Boolean(false), // Status Flags
+            BacnetPropertyValue::new(85, PropertyValue::Real(25.5)), // Present Value
+            BacnetPropertyValue::new(81, PropertyValue::Boolean(false)), // Out Of Service
         ];
 
         let notification = CovNotificationRequest::new(123, device_id, object_id, 3600, values);
@@ -2036,6 +5544,331 @@ mod tests {
         assert!(!buffer.is_empty());
     }
 
+    #[test]
+    fn test_subscribe_cov_round_trip() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 10);
+        let mut request = SubscribeCovRequest::with_confirmation(300, object_id, true);
+        request.lifetime = Some(3600);
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            [0x0A, 0x01, 0x2C, 0x1C, 0x00, 0x00, 0x00, 0x0A, 0x29, 0x01, 0x3A, 0x0E, 0x10]
+        );
+
+        let decoded = SubscribeCovRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.subscriber_process_identifier, 300);
+        assert_eq!(decoded.monitored_object_identifier, object_id);
+        assert_eq!(decoded.issue_confirmed_notifications, Some(true));
+        assert_eq!(decoded.lifetime, Some(3600));
+        assert!(!decoded.is_cancellation());
+
+        // Cancellation carries only the process and object identifiers
+        let cancel = SubscribeCovRequest::new(300, object_id);
+        buffer.clear();
+        cancel.encode(&mut buffer).unwrap();
+        assert!(SubscribeCovRequest::decode(&buffer)
+            .unwrap()
+            .is_cancellation());
+    }
+
+    #[test]
+    fn test_subscribe_cov_property_round_trip() {
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let reference = PropertyReference {
+            property_identifier: 85,
+            property_array_index: None,
+        };
+        let mut request = SubscribeCovPropertyRequest::new(18, object_id, reference.clone())
+            .with_cov_increment(0.5);
+        request.issue_confirmed_notifications = Some(true);
+        request.lifetime = Some(600);
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            buffer,
+            vec![
+                0x09, 0x12, // Subscriber process identifier 18
+                0x1C, 0x00, 0x00, 0x00, 0x01, // Monitored object AI:1
+                0x29, 0x01, // Issue confirmed notifications
+                0x3A, 0x02, 0x58, // Lifetime 600
+                0x4E, 0x09, 0x55, 0x4F, // Present_Value
+                0x5C, 0x3F, 0x00, 0x00, 0x00, // COV increment 0.5
+            ]
+        );
+
+        let decoded = SubscribeCovPropertyRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.subscriber_process_identifier, 18);
+        assert_eq!(decoded.monitored_object_identifier, object_id);
+        assert_eq!(decoded.issue_confirmed_notifications, Some(true));
+        assert_eq!(decoded.lifetime, Some(600));
+        assert_eq!(decoded.monitored_property, reference);
+        assert_eq!(decoded.cov_increment, Some(0.5));
+
+        // A cancellation carries only the identifiers and the property
+        let mut request = SubscribeCovPropertyRequest::new(
+            18,
+            object_id,
+            PropertyReference {
+                property_identifier: 87,
+                property_array_index: Some(8),
+            },
+        );
+        request.cov_increment = None;
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        let decoded = SubscribeCovPropertyRequest::decode(&buffer).unwrap();
+        assert!(decoded.is_cancellation());
+        assert_eq!(decoded.monitored_property.property_array_index, Some(8));
+        assert_eq!(decoded.cov_increment, None);
+    }
+
+    #[test]
+    fn test_cov_property_subscriptions_respect_increment() {
+        let mut manager = CovSubscriptionManager::new();
+        let device_id = ObjectIdentifier::new(ObjectType::Device, 1);
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let present_value = PropertyReference {
+            property_identifier: 85,
+            property_array_index: None,
+        };
+        let out_of_service = PropertyReference {
+            property_identifier: 81,
+            property_array_index: None,
+        };
+
+        // Whole-object and property subscriptions are kept apart
+        manager.add_subscription(CovSubscription::new(7, device_id, object_id, 0));
+        let mut request = SubscribeCovPropertyRequest::new(7, object_id, present_value.clone())
+            .with_cov_increment(1.0);
+        request.lifetime = Some(0);
+        manager.add_subscription(CovSubscription::for_property(device_id, &request).unwrap());
+        let mut request = SubscribeCovPropertyRequest::new(7, object_id, out_of_service.clone());
+        request.lifetime = Some(0);
+        manager.add_subscription(CovSubscription::for_property(device_id, &request).unwrap());
+        assert_eq!(manager.active_count(), 3);
+
+        let notify =
+            manager.property_changed(object_id, &present_value, &PropertyValue::Real(20.0));
+        assert_eq!(notify.len(), 1);
+        assert_eq!(notify[0].monitored_property, Some(present_value.clone()));
+
+        // Changes smaller than the increment are not reported
+        assert!(manager
+            .property_changed(object_id, &present_value, &PropertyValue::Real(20.6))
+            .is_empty());
+        assert_eq!(
+            manager
+                .property_changed(object_id, &present_value, &PropertyValue::Real(21.0))
+                .len(),
+            1
+        );
+
+        // Other datatypes report any change
+        let changed = |manager: &mut CovSubscriptionManager, value| {
+            manager
+                .property_changed(object_id, &out_of_service, &PropertyValue::Boolean(value))
+                .len()
+        };
+        assert_eq!(changed(&mut manager, false), 1);
+        assert_eq!(changed(&mut manager, false), 0);
+        assert_eq!(changed(&mut manager, true), 1);
+
+        manager.remove_property_subscription(device_id, 7, object_id, &present_value);
+        assert_eq!(manager.active_count(), 2);
+    }
+
+    #[test]
+    fn test_cov_notification_decode_and_apply() {
+        use crate::object::AnalogInput;
+
+        // Unconfirmed COV notification from device 1 for AI:1,
+        // Present_Value = 25.5 and Status_Flags (unknown to the local copy)
+        let data = [
+            0x09, 0x7B, // Subscriber process identifier 123
+            0x1C, 0x02, 0x00, 0x00, 0x01, // Initiating device 1
+            0x2C, 0x00, 0x00, 0x00, 0x01, // Monitored object AI:1
+            0x39, 0x3C, // Time remaining 60
+            0x4E, // Opening tag 4
+            0x09, 0x55, 0x2E, 0x44, 0x41, 0xCC, 0x00, 0x00, 0x2F, // Present_Value
+            0x09, 0x6F, 0x2E, 0x82, 0x04, 0x00, 0x2F, // Status_Flags
+            0x4F, // Closing tag 4
+        ];
+
+        let notification = CovNotificationRequest::decode(&data).unwrap();
+        assert_eq!(notification.subscriber_process_identifier, 123);
+        assert_eq!(notification.time_remaining, 60);
+        assert_eq!(notification.list_of_values.len(), 2);
+        assert_eq!(notification.list_of_values[1].property_identifier, 111);
+
+        let mut mirror = AnalogInput::new(1, "Remote AI".to_string());
+        assert_eq!(notification.apply_to(&mut mirror).unwrap(), 1);
+        assert_eq!(mirror.present_value, 25.5);
+
+        let mut other = AnalogInput::new(2, "Other AI".to_string());
+        assert!(notification.apply_to(&mut other).is_err());
+    }
+
+    #[test]
+    fn test_time_stamp_choices() {
+        let time = crate::object::Time {
+            hour: 14,
+            minute: 30,
+            second: 0,
+            hundredths: 0,
+        };
+        let date_time = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2025,
+                month: 3,
+                day: 10,
+                weekday: 1,
+            },
+            time,
+        );
+
+        let cases = [
+            (TimeStamp::Time(time), vec![0x0C, 14, 30, 0, 0]),
+            (TimeStamp::SequenceNumber(300), vec![0x1A, 0x01, 0x2C]),
+            (
+                TimeStamp::DateTime(date_time),
+                vec![0x2E, 0xA4, 125, 3, 10, 1, 0xB4, 14, 30, 0, 0, 0x2F],
+            ),
+        ];
+        for (time_stamp, expected) in cases {
+            let mut buffer = Vec::new();
+            time_stamp.encode(&mut buffer).unwrap();
+            assert_eq!(buffer, expected);
+            assert_eq!(
+                TimeStamp::decode(&buffer).unwrap(),
+                (time_stamp, buffer.len())
+            );
+        }
+
+        // Sequence numbers are limited to 16 bits
+        assert!(TimeStamp::decode(&[0x1B, 0x01, 0x00, 0x00]).is_err());
+        assert!(TimeStamp::decode(&[0x39, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_get_alarm_summary_ack() {
+        let ack = GetAlarmSummaryAck::new(vec![
+            AlarmSummary {
+                object_identifier: ObjectIdentifier::new(ObjectType::AnalogInput, 3),
+                alarm_state: EventState::HighLimit,
+                acknowledged_transitions: [false, true, true],
+            },
+            AlarmSummary {
+                object_identifier: ObjectIdentifier::new(ObjectType::BinaryInput, 1),
+                alarm_state: EventState::Offnormal,
+                acknowledged_transitions: [true, true, true],
+            },
+        ]);
+
+        let mut buffer = Vec::new();
+        ack.encode(&mut buffer).unwrap();
+        assert_eq!(
+            &buffer[..10],
+            &[0xC4, 0x00, 0x00, 0x00, 0x03, 0x91, 0x03, 0x82, 0x05, 0x60]
+        );
+        assert_eq!(GetAlarmSummaryAck::decode(&buffer).unwrap(), ack);
+        assert_eq!(GetAlarmSummaryAck::decode(&[]).unwrap(), Default::default());
+
+        let mut request = Vec::new();
+        GetAlarmSummaryRequest.encode(&mut request).unwrap();
+        assert!(request.is_empty());
+        assert!(GetAlarmSummaryRequest::decode(&[0x09, 0x01]).is_err());
+    }
+
+    #[test]
+    fn test_get_event_information_paging() {
+        let summary = |instance| EventSummary {
+            object_identifier: ObjectIdentifier::new(ObjectType::AnalogValue, instance),
+            event_state: EventState::LowLimit,
+            acknowledged_transitions: [false, true, true],
+            event_time_stamps: [
+                TimeStamp::SequenceNumber(12),
+                TimeStamp::DateTime(BacnetDateTime::unspecified()),
+                TimeStamp::SequenceNumber(9),
+            ],
+            notify_type: NotifyType::Alarm,
+            event_enable: [true, true, true],
+            event_priorities: [100, 50, 200],
+        };
+        let ack = GetEventInformationAck::new(vec![summary(1), summary(2)], true);
+
+        let mut buffer = Vec::new();
+        ack.encode(&mut buffer).unwrap();
+        assert_eq!(buffer[0], 0x0E);
+        assert_eq!(&buffer[buffer.len() - 3..], &[0x0F, 0x19, 0x01]);
+        let decoded = GetEventInformationAck::decode(&buffer).unwrap();
+        assert_eq!(decoded, ack);
+
+        // The next page starts after the last object received
+        let next = decoded.next_request().unwrap();
+        let mut request = Vec::new();
+        next.encode(&mut request).unwrap();
+        assert_eq!(request, vec![0x0C, 0x00, 0x80, 0x00, 0x02]);
+        assert_eq!(GetEventInformationRequest::decode(&request).unwrap(), next);
+        assert_eq!(
+            GetEventInformationRequest::decode(&[]).unwrap(),
+            GetEventInformationRequest::new()
+        );
+
+        let last_page = GetEventInformationAck::new(vec![summary(3)], false);
+        assert!(last_page.next_request().is_none());
+    }
+
+    #[test]
+    fn test_acknowledge_alarm_round_trip() {
+        let time_of_acknowledgment = TimeStamp::DateTime(BacnetDateTime::new(
+            crate::object::Date {
+                year: 2025,
+                month: 3,
+                day: 10,
+                weekday: 1,
+            },
+            crate::object::Time {
+                hour: 9,
+                minute: 15,
+                second: 0,
+                hundredths: 0,
+            },
+        ));
+        let request = AcknowledgeAlarmRequest::new(
+            3,
+            ObjectIdentifier::new(ObjectType::AnalogInput, 2),
+            EventState::HighLimit,
+            TimeStamp::SequenceNumber(17),
+            "Console 1",
+            time_of_acknowledgment,
+        );
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(
+            &buffer[..16],
+            &[
+                0x09, 0x03, // Acknowledging process 3
+                0x1C, 0x00, 0x00, 0x00, 0x02, // AI:2
+                0x29, 0x03, // High limit
+                0x3E, 0x19, 0x11, 0x3F, // Sequence number 17
+                0x4D, 0x0A, 0x00, // Character string, UTF-8
+            ]
+        );
+        assert_eq!(AcknowledgeAlarmRequest::decode(&buffer).unwrap(), request);
+
+        // Event states outside the standard range are reported as such
+        let mut bad_state = buffer.clone();
+        bad_state[8] = 0x40;
+        assert!(matches!(
+            AcknowledgeAlarmRequest::decode(&bad_state),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+    }
+
     #[test]
     fn test_atomic_read_file_request() {
         let file_id = ObjectIdentifier::new(ObjectType::File, 1);
@@ -2067,9 +5900,11 @@ mod tests {
         }
 
         // Test encoding
-        let mut buffer = Vec::new();
-        read_stream.encode(&mut buffer).unwrap();
-        assert!(!buffer.is_empty());
+        for request in [read_stream, read_record] {
+            let mut buffer = Vec::new();
+            request.encode(&mut buffer).unwrap();
+            assert_eq!(AtomicReadFileRequest::decode(&buffer).unwrap(), request);
+        }
     }
 
     #[test]
@@ -2107,6 +5942,13 @@ mod tests {
             }
             _ => panic!("Expected RecordAccess result"),
         }
+
+        // Test encoding
+        for response in [response_stream, response_record] {
+            let mut buffer = Vec::new();
+            response.encode(&mut buffer).unwrap();
+            assert_eq!(AtomicReadFileResponse::decode(&buffer).unwrap(), response);
+        }
     }
 
     #[test]
@@ -2148,17 +5990,142 @@ mod tests {
         }
 
         // Test encoding
-        let mut buffer = Vec::new();
-        write_stream.encode(&mut buffer).unwrap();
-        assert!(!buffer.is_empty());
+        for request in [write_stream, write_record] {
+            let mut buffer = Vec::new();
+            request.encode(&mut buffer).unwrap();
+            assert_eq!(AtomicWriteFileRequest::decode(&buffer).unwrap(), request);
+        }
     }
 
     #[test]
     fn test_atomic_write_file_response() {
-        let response = AtomicWriteFileResponse {
-            file_start_position: 150,
-        };
+        let response = AtomicWriteFileResponse::new_stream_access(150);
         assert_eq!(response.file_start_position, 150);
+
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x0A, 0x00, 0x96]);
+        assert_eq!(AtomicWriteFileResponse::decode(&buffer).unwrap(), response);
+
+        let response = AtomicWriteFileResponse::new_record_access(-1);
+        let mut buffer = Vec::new();
+        response.encode(&mut buffer).unwrap();
+        assert_eq!(AtomicWriteFileResponse::decode(&buffer).unwrap(), response);
+    }
+
+    #[test]
+    fn test_read_range_request() {
+        let object_id = ObjectIdentifier::new(ObjectType::TrendLog, 3);
+        let reference_time = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2024,
+                month: 3,
+                day: 15,
+                weekday: 5,
+            },
+            crate::object::Time {
+                hour: 14,
+                minute: 30,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+        let ranges = [
+            None,
+            Some(ReadRange::ByPosition {
+                reference_index: 10,
+                count: -5,
+            }),
+            Some(ReadRange::BySequenceNumber {
+                reference_sequence_number: 70_000,
+                count: 20,
+            }),
+            Some(ReadRange::ByTime {
+                reference_time,
+                count: -200,
+            }),
+        ];
+
+        for range in ranges {
+            let mut request = ReadRangeRequest::new(object_id, 131, range);
+            request.property_array_index = range.map(|_| 1);
+            let mut buffer = Vec::new();
+            request.encode(&mut buffer).unwrap();
+            assert_eq!(ReadRangeRequest::decode(&buffer).unwrap(), request);
+        }
+    }
+
+    #[test]
+    fn test_read_range_ack() {
+        let ack = ReadRangeAck {
+            object_identifier: ObjectIdentifier::new(ObjectType::TrendLog, 3),
+            property_identifier: 131,
+            property_array_index: None,
+            result_flags: ResultFlags {
+                first_item: true,
+                last_item: false,
+                more_items: true,
+            },
+            item_count: 2,
+            item_data: vec![0x21, 0x05, 0x21, 0x06],
+            first_sequence_number: Some(12),
+        };
+
+        let mut buffer = Vec::new();
+        ack.encode(&mut buffer).unwrap();
+        assert_eq!(ReadRangeAck::decode(&buffer).unwrap(), ack);
+
+        let ack = ReadRangeAck {
+            first_sequence_number: None,
+            item_data: Vec::new(),
+            item_count: 0,
+            result_flags: ResultFlags::default(),
+            ..ack
+        };
+        let mut buffer = Vec::new();
+        ack.encode(&mut buffer).unwrap();
+        assert_eq!(ReadRangeAck::decode(&buffer).unwrap(), ack);
+    }
+
+    #[test]
+    fn test_bacnet_datetime_since_1900() {
+        assert_eq!(BacnetDateTime::unspecified().since_1900(), None);
+
+        // The Unix epoch is the NTP era offset after 1900
+        let epoch = BacnetDateTime::new(
+            crate::object::Date {
+                year: 1970,
+                month: 1,
+                day: 1,
+                weekday: 4,
+            },
+            crate::object::Time {
+                hour: 0,
+                minute: 0,
+                second: 0,
+                hundredths: 0,
+            },
+        );
+        assert_eq!(
+            epoch.since_1900(),
+            Some(core::time::Duration::from_secs(2_208_988_800))
+        );
+        let datetime = BacnetDateTime::new(
+            crate::object::Date {
+                year: 2024,
+                month: 3,
+                day: 15,
+                weekday: 5,
+            },
+            crate::object::Time {
+                hour: 14,
+                minute: 30,
+                second: 45,
+                hundredths: 50,
+            },
+        );
+        let later = datetime.since_1900().unwrap() - epoch.since_1900().unwrap();
+        assert_eq!(later, core::time::Duration::from_millis(1_710_513_045_500));
     }
 
     #[test]
@@ -2277,4 +6244,285 @@ mod tests {
         assert!(now_sync.date_time.time.second <= 59);
         assert!(now_sync.date_time.time.hundredths <= 99);
     }
+
+    #[test]
+    fn test_bacnet_error_mapping() {
+        assert_eq!(
+            BacnetError::from_error(2, 32),
+            BacnetError::Error {
+                class: ErrorClass::Property,
+                code: ErrorCode::UnknownProperty,
+            }
+        );
+        assert_eq!(
+            BacnetError::from_error(64, 512),
+            BacnetError::ProprietaryError {
+                class: 64,
+                code: 512,
+            }
+        );
+        assert_eq!(ErrorCode::from(256), ErrorCode::Unknown(256));
+        assert_eq!(u32::from(ErrorCode::WriteAccessDenied), 40);
+        assert_eq!(u32::from(ErrorCode::from(34)), 34);
+
+        assert_eq!(RejectReason::from(9), RejectReason::UnrecognizedService);
+        assert_eq!(RejectReason::from(200), RejectReason::Other);
+        assert_eq!(AbortReason::from(11), AbortReason::ApduTooLong);
+        assert_eq!(
+            BacnetError::Reject(RejectReason::InvalidTag).to_string(),
+            "Rejected: InvalidTag"
+        );
+    }
+
+    #[test]
+    fn test_device_communication_control_round_trip() {
+        let request = DeviceCommunicationControlRequest::new(EnableDisable::Disable, Some(60))
+            .with_password("secret");
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        // Duration 60, Disable, then the password with its character set byte
+        assert_eq!(&buffer[..4], &[0x09, 0x3C, 0x19, 0x01]);
+        assert_eq!(&buffer[4..6], &[0x2D, 0x07]);
+        assert_eq!(
+            DeviceCommunicationControlRequest::decode(&buffer).unwrap(),
+            request
+        );
+
+        let request = DeviceCommunicationControlRequest::new(EnableDisable::Enable, None);
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x19, 0x00]);
+        assert_eq!(
+            DeviceCommunicationControlRequest::decode(&buffer).unwrap(),
+            request
+        );
+
+        // Unknown states and out of range durations are rejected
+        assert!(DeviceCommunicationControlRequest::decode(&[0x19, 0x03]).is_err());
+        assert!(
+            DeviceCommunicationControlRequest::decode(&[0x0B, 0x01, 0x00, 0x00, 0x19, 0x00])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_reinitialize_device_round_trip() {
+        let request =
+            ReinitializeDeviceRequest::new(ReinitializedState::WarmStart).with_password("secret");
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[..4], &[0x09, 0x01, 0x1D, 0x07]);
+        assert_eq!(ReinitializeDeviceRequest::decode(&buffer).unwrap(), request);
+
+        let request = ReinitializeDeviceRequest::new(ReinitializedState::ActivateChanges);
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(buffer, vec![0x09, 0x07]);
+        assert_eq!(ReinitializeDeviceRequest::decode(&buffer).unwrap(), request);
+
+        assert!(matches!(
+            ReinitializeDeviceRequest::decode(&[0x09, 0x08]),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+    }
+
+    #[test]
+    fn test_decode_limits_reject_crafted_counts() {
+        // Atomic Write File for File:1 claiming 4 billion records
+        let data = [
+            0xC4, 0x02, 0x80, 0x00, 0x01, // File:1
+            0x1E, // Opening tag 1 (record access)
+            0x31, 0x00, // File start record 0
+            0x24, 0xFF, 0xFF, 0xFF, 0xFF, // Record count
+            0x1F, // Closing tag 1
+        ];
+        assert!(matches!(
+            AtomicWriteFileRequest::decode(&data),
+            Err(EncodingError::TooManyElements)
+        ));
+
+        // Within the limits, but more records than bytes to hold them
+        let data = [
+            0xC4, 0x02, 0x80, 0x00, 0x01, 0x1E, 0x31, 0x00, 0x21, 0x09, 0x1F,
+        ];
+        assert!(matches!(
+            AtomicWriteFileRequest::decode(&data),
+            Err(EncodingError::UnexpectedEndOfData)
+        ));
+
+        let analog = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+        let request = ReadPropertyMultipleRequest::new(vec![ReadAccessSpecification::new(
+            analog,
+            vec![PropertyReference::new(85); 3],
+        )]);
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        let limits = DecodeLimits { max_elements: 2 };
+        assert!(matches!(
+            ReadPropertyMultipleRequest::decode_with_limits(&buffer, &limits),
+            Err(EncodingError::TooManyElements)
+        ));
+        assert!(ReadPropertyMultipleRequest::decode(&buffer).is_ok());
+    }
+
+    #[test]
+    fn test_write_property_multiple_request_round_trip() {
+        let valve = ObjectIdentifier::new(ObjectType::AnalogOutput, 3);
+        let mut setpoint = BacnetPropertyValue::new(85, PropertyValue::Real(21.5));
+        setpoint.priority = Some(8);
+        let mut element = BacnetPropertyValue::new(87, PropertyValue::Null);
+        element.property_array_index = Some(8);
+        let request = WritePropertyMultipleRequest::new(vec![
+            WriteAccessSpecification::new(valve, vec![setpoint, element]),
+            WriteAccessSpecification::new(
+                ObjectIdentifier::new(ObjectType::AnalogValue, 1),
+                vec![BacnetPropertyValue::new(
+                    28,
+                    PropertyValue::CharacterString("Setpoint".to_string()),
+                )],
+            ),
+        ]);
+
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        let decoded = WritePropertyMultipleRequest::decode(&buffer).unwrap();
+        assert_eq!(decoded.write_access_specifications.len(), 2);
+        let first = &decoded.write_access_specifications[0];
+        assert_eq!(first.object_identifier, valve);
+        assert_eq!(first.list_of_properties[0].priority, Some(8));
+        assert!(matches!(
+            first.list_of_properties[0].value,
+            PropertyValue::Real(v) if v == 21.5
+        ));
+        assert_eq!(first.list_of_properties[1].property_array_index, Some(8));
+        assert!(matches!(
+            &decoded.write_access_specifications[1].list_of_properties[0].value,
+            PropertyValue::CharacterString(s) if s == "Setpoint"
+        ));
+
+        assert!(WritePropertyMultipleRequest::decode(&[]).is_err());
+        let limits = DecodeLimits { max_elements: 1 };
+        assert!(matches!(
+            WritePropertyMultipleRequest::decode_with_limits(&buffer, &limits),
+            Err(EncodingError::TooManyElements)
+        ));
+
+        // The error names the failed write after the wrapped error class and code
+        let error = WritePropertyMultipleError {
+            error_class: ErrorClass::Property as u32,
+            error_code: u32::from(ErrorCode::WriteAccessDenied),
+            object_identifier: valve,
+            property_identifier: 87,
+            property_array_index: Some(8),
+        };
+        let encoded = error.to_apdu(4).unwrap().encode();
+        assert_eq!(
+            &encoded[..10],
+            &[0x50, 4, 16, 0x0E, 0x91, 2, 0x91, 40, 0x0F, 0x1E]
+        );
+        let decoded =
+            WritePropertyMultipleError::from_apdu(&crate::app::Apdu::decode(&encoded).unwrap())
+                .unwrap();
+        assert_eq!(decoded, error);
+        assert!(matches!(
+            decoded.error(),
+            BacnetError::Error {
+                class: ErrorClass::Property,
+                code: ErrorCode::WriteAccessDenied
+            }
+        ));
+    }
+
+    #[test]
+    fn test_private_transfer_round_trip() {
+        // Parameters are carried as opaque application-tagged data
+        let parameters = vec![0x21, 0x05, 0x75, 0x03, 0x00, 0x68, 0x69];
+        let request = PrivateTransferRequest::new(260, 7).with_parameters(parameters.clone());
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(&buffer[..7], &[0x0A, 0x01, 0x04, 0x19, 0x07, 0x2E, 0x21]);
+        assert_eq!(buffer.last(), Some(&0x2F));
+        assert_eq!(PrivateTransferRequest::decode(&buffer).unwrap(), request);
+
+        let request = PrivateTransferRequest::new(260, 8);
+        let mut buffer = Vec::new();
+        request.encode(&mut buffer).unwrap();
+        assert_eq!(PrivateTransferRequest::decode(&buffer).unwrap(), request);
+        assert!(matches!(
+            PrivateTransferRequest::decode(&[0x0B, 0x01, 0x00, 0x00, 0x19, 0x07]),
+            Err(EncodingError::ValueOutOfRange)
+        ));
+
+        let ack = ConfirmedPrivateTransferAck {
+            vendor_id: 260,
+            service_number: 7,
+            result_block: Some(vec![0x91, 0x02]),
+        };
+        let mut buffer = Vec::new();
+        ack.encode(&mut buffer).unwrap();
+        assert_eq!(ConfirmedPrivateTransferAck::decode(&buffer).unwrap(), ack);
+
+        let error = ConfirmedPrivateTransferError {
+            error_class: ErrorClass::Services as u32,
+            error_code: u32::from(ErrorCode::OptionalFunctionalityNotSupported),
+            vendor_id: 260,
+            service_number: 7,
+            error_parameters: None,
+        };
+        let encoded = error.to_apdu(9).unwrap().encode();
+        assert_eq!(&encoded[..4], &[0x50, 9, 18, 0x0E]);
+        let decoded =
+            ConfirmedPrivateTransferError::from_apdu(&crate::app::Apdu::decode(&encoded).unwrap())
+                .unwrap();
+        assert_eq!(decoded, error);
+    }
+
+    #[test]
+    fn test_cov_subscription_status_and_expiry() {
+        let mut manager = CovSubscriptionManager::new();
+        let device_id = ObjectIdentifier::new(ObjectType::Device, 1);
+        let object_id = ObjectIdentifier::new(ObjectType::AnalogInput, 1);
+
+        let mut subscription = CovSubscription::new(9, device_id, object_id, 300);
+        subscription.cov_increment = Some(0.5);
+        subscription.notify_on_expiry = true;
+        manager.add_subscription(subscription);
+        manager.add_subscription(CovSubscription::new(10, device_id, object_id, 0));
+
+        assert_eq!(
+            manager
+                .object_changed(object_id, &PropertyValue::Real(20.0))
+                .len(),
+            2
+        );
+        assert!(manager.update_timers(60).is_empty());
+        let status = &manager.subscriptions()[0];
+        assert!(matches!(status.last_notified, Some(PropertyValue::Real(v)) if v == 20.0));
+        assert_eq!(status.seconds_since_notification, Some(60));
+        assert_eq!(status.time_remaining, 240);
+
+        // Only the subscription without an increment sees the small change
+        let notify = manager.object_changed(object_id, &PropertyValue::Real(20.2));
+        assert_eq!(notify.len(), 1);
+        assert_eq!(notify[0].subscriber_process_identifier, 10);
+        assert_eq!(
+            manager.subscriptions()[1].seconds_since_notification,
+            Some(0)
+        );
+
+        // The timed subscription expires and asks for a final notification
+        let expired = manager.update_timers(240);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(manager.subscriptions().len(), 1);
+        let notification = expired[0].cancellation_notification(device_id).unwrap();
+        assert_eq!(notification.time_remaining, 0);
+        assert_eq!(notification.list_of_values[0].property_identifier, 85);
+        assert!(matches!(
+            notification.list_of_values[0].value,
+            PropertyValue::Real(v) if v == 20.0
+        ));
+        assert!(manager.subscriptions()[0]
+            .cancellation_notification(device_id)
+            .is_none());
+    }
 }
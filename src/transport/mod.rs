@@ -47,7 +47,7 @@ use std::{
 use core::fmt;
 
 #[cfg(not(feature = "std"))]
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 
 #[cfg(not(feature = "std"))]
 use core::time::Duration;
@@ -180,12 +180,15 @@
 //! }
 //! ```
 
-// Debug formatting utilities
 #[cfg(not(feature = "std"))]
-use core::fmt;
-
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 #[cfg(not(feature = "std"))]
-use alloc::{format, string::String, vec::Vec};
+use core::time::Duration;
 
 #[cfg(feature = "std")]
 use std::{
@@ -595,6 +598,7 @@ pub mod performance {
 
 /// Statistics collection helpers
 pub mod statistics {
+    #[cfg(feature = "std")]
     use super::*;
 
     /// BACnet communication statistics
@@ -663,6 +667,7 @@ pub mod statistics {
     }
 
     /// Device-specific statistics
+    #[cfg(feature = "std")]
     #[derive(Debug, Clone)]
     pub struct DeviceStats {
         pub device_id: u32,
@@ -881,7 +886,7 @@ impl RetryConfig {
         let delay_ms = if attempt == 0 {
             self.initial_delay_ms
         } else {
-            let delay = self.initial_delay_ms as f64 * self.backoff_multiplier.powi(attempt as i32);
+            let delay = self.initial_delay_ms as f64 * powi(self.backoff_multiplier, attempt);
             delay.min(self.max_delay_ms as f64) as u64
         };
 
@@ -889,6 +894,19 @@ impl RetryConfig {
     }
 }
 
+/// Raise to an integer power by squaring; `f64::powi` needs std
+fn powi(mut base: f64, mut exponent: u32) -> f64 {
+    let mut result = 1.0;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result *= base;
+        }
+        base *= base;
+        exponent >>= 1;
+    }
+    result
+}
+
 /// Circular buffer for maintaining history
 #[derive(Debug, Clone)]
 pub struct CircularBuffer<T> {
@@ -963,6 +981,13 @@ impl<T: Clone> CircularBuffer<T> {
 /// Debug formatting utilities for BACnet data structures and protocol analysis
 pub mod debug {
     use super::*;
+    use crate::app::Apdu;
+    use crate::object::{ObjectIdentifier, PropertyIdentifier, PropertyValue};
+    use crate::service::{
+        AbortReason, ConfirmedServiceChoice, ErrorClass, ErrorCode, IAmRequest, ReadPropertyAck,
+        ReadPropertyRequest, RejectReason, UnconfirmedServiceChoice, WhoIsRequest,
+        WritePropertyRequest,
+    };
 
     /// Format a BACnet property value for debugging
     pub fn format_property_value(data: &[u8]) -> String {
@@ -1291,8 +1316,7 @@ pub mod debug {
     /// Create a detailed hex dump with annotations
     pub fn annotated_hex_dump(data: &[u8], annotations: &[(usize, String)]) -> String {
         let mut result = String::new();
-        let mut annotation_map: std::collections::HashMap<usize, String> =
-            annotations.iter().cloned().collect();
+        let mut annotation_map: HashMap<usize, String> = annotations.iter().cloned().collect();
 
         for (i, chunk) in data.chunks(16).enumerate() {
             let offset = i * 16;
@@ -1570,6 +1594,320 @@ pub mod debug {
 
         result
     }
+
+    /// Describe a decoded APDU as a JSON object for debugging
+    ///
+    /// Services, object types, properties and error values are given by
+    /// name. ReadProperty, WriteProperty, Who-Is and I-Am service data is
+    /// decoded into its fields; other service data, and service data that
+    /// fails to decode, is given as hex.
+    pub fn format_apdu_json(apdu: &Apdu) -> String {
+        let mut fields: Vec<(&str, String)> = Vec::new();
+        match apdu {
+            Apdu::ConfirmedRequest {
+                invoke_id,
+                service_choice,
+                service_data,
+                ..
+            } => {
+                fields.push(("pdu_type", json_string("ConfirmedRequest")));
+                fields.push(("invoke_id", invoke_id.to_string()));
+                fields.push(("service", json_string(&format!("{:?}", service_choice))));
+                push_confirmed_request_fields(&mut fields, *service_choice, service_data);
+            }
+            Apdu::UnconfirmedRequest {
+                service_choice,
+                service_data,
+            } => {
+                fields.push(("pdu_type", json_string("UnconfirmedRequest")));
+                fields.push(("service", json_string(&format!("{:?}", service_choice))));
+                push_unconfirmed_request_fields(&mut fields, *service_choice, service_data);
+            }
+            Apdu::SimpleAck {
+                invoke_id,
+                service_choice,
+            } => {
+                fields.push(("pdu_type", json_string("SimpleAck")));
+                fields.push(("invoke_id", invoke_id.to_string()));
+                fields.push(("service", confirmed_service_name(*service_choice)));
+            }
+            Apdu::ComplexAck {
+                invoke_id,
+                service_choice,
+                service_data,
+                ..
+            } => {
+                fields.push(("pdu_type", json_string("ComplexAck")));
+                fields.push(("invoke_id", invoke_id.to_string()));
+                fields.push(("service", confirmed_service_name(*service_choice)));
+                let ack = (*service_choice == ConfirmedServiceChoice::ReadProperty as u8)
+                    .then(|| ReadPropertyAck::decode(service_data).ok())
+                    .flatten();
+                match ack {
+                    Some(ack) => {
+                        fields.push(("object", object_identifier_json(&ack.object_identifier)));
+                        fields.push(("property", property_name(ack.property_identifier)));
+                        if let Some(index) = ack.property_array_index {
+                            fields.push(("array_index", index.to_string()));
+                        }
+                        fields.push(("value", property_value_json(&ack.value)));
+                    }
+                    None => fields.push(("service_data", json_string(&hex_string(service_data)))),
+                }
+            }
+            Apdu::SegmentAck {
+                negative,
+                server,
+                invoke_id,
+                sequence_number,
+                window_size,
+            } => {
+                fields.push(("pdu_type", json_string("SegmentAck")));
+                fields.push(("invoke_id", invoke_id.to_string()));
+                fields.push(("sequence_number", sequence_number.to_string()));
+                fields.push(("window_size", window_size.to_string()));
+                fields.push(("negative", negative.to_string()));
+                fields.push(("server", server.to_string()));
+            }
+            Apdu::Error {
+                invoke_id,
+                service_choice,
+                error_class,
+                error_code,
+                ..
+            } => {
+                fields.push(("pdu_type", json_string("Error")));
+                fields.push(("invoke_id", invoke_id.to_string()));
+                fields.push(("service", confirmed_service_name(*service_choice)));
+                let class = match ErrorClass::try_from(*error_class) {
+                    Ok(class) => format!("{:?}", class),
+                    Err(_) => format!("Unknown({})", error_class),
+                };
+                fields.push(("error_class", json_string(&class)));
+                let code = format!("{:?}", ErrorCode::from(*error_code));
+                fields.push(("error_code", json_string(&code)));
+            }
+            Apdu::Reject {
+                invoke_id,
+                reject_reason,
+            } => {
+                fields.push(("pdu_type", json_string("Reject")));
+                fields.push(("invoke_id", invoke_id.to_string()));
+                let reason = format!("{:?}", RejectReason::from(*reject_reason));
+                fields.push(("reason", json_string(&reason)));
+            }
+            Apdu::Abort {
+                server,
+                invoke_id,
+                abort_reason,
+            } => {
+                fields.push(("pdu_type", json_string("Abort")));
+                fields.push(("invoke_id", invoke_id.to_string()));
+                fields.push(("server", server.to_string()));
+                let reason = format!("{:?}", AbortReason::from(*abort_reason));
+                fields.push(("reason", json_string(&reason)));
+            }
+        }
+        json_object(&fields)
+    }
+
+    /// Format a property value as a JSON object tagged with its type
+    ///
+    /// Uses the same `type`/`value` form as the serde representation of
+    /// [`PropertyValue`]; dates and times are given as readable strings.
+    pub fn property_value_json(value: &PropertyValue) -> String {
+        let (name, content) = match value {
+            PropertyValue::Null => return json_object(&[("type", json_string("Null"))]),
+            PropertyValue::Boolean(value) => ("Boolean", value.to_string()),
+            PropertyValue::UnsignedInteger(value) => ("UnsignedInteger", value.to_string()),
+            PropertyValue::SignedInt(value) => ("SignedInt", value.to_string()),
+            PropertyValue::Real(value) => ("Real", json_number(*value as f64)),
+            PropertyValue::Double(value) => ("Double", json_number(*value)),
+            PropertyValue::OctetString(bytes) => ("OctetString", json_string(&hex_string(bytes))),
+            PropertyValue::CharacterString(text) => ("CharacterString", json_string(text)),
+            PropertyValue::BitString(bits) => {
+                let bits: Vec<String> = bits.iter().map(|bit| bit.to_string()).collect();
+                ("BitString", format!("[{}]", bits.join(",")))
+            }
+            PropertyValue::Enumerated(value) => ("Enumerated", value.to_string()),
+            PropertyValue::Date(date) => (
+                "Date",
+                json_string(&bacnet_date_to_string(
+                    date.year,
+                    date.month,
+                    date.day,
+                    date.weekday,
+                )),
+            ),
+            PropertyValue::Time(time) => (
+                "Time",
+                json_string(&bacnet_time_to_string(
+                    time.hour,
+                    time.minute,
+                    time.second,
+                    time.hundredths,
+                )),
+            ),
+            PropertyValue::ObjectIdentifier(id) => ("ObjectIdentifier", object_identifier_json(id)),
+            PropertyValue::Array(elements) => ("Array", property_values_json(elements)),
+            PropertyValue::List(elements) => ("List", property_values_json(elements)),
+            PropertyValue::Constructed(data) => ("Constructed", json_string(&hex_string(data))),
+        };
+        json_object(&[("type", json_string(name)), ("value", content)])
+    }
+
+    fn push_confirmed_request_fields(
+        fields: &mut Vec<(&str, String)>,
+        service_choice: ConfirmedServiceChoice,
+        data: &[u8],
+    ) {
+        match service_choice {
+            ConfirmedServiceChoice::ReadProperty => {
+                if let Ok(request) = ReadPropertyRequest::decode(data) {
+                    fields.push(("object", object_identifier_json(&request.object_identifier)));
+                    fields.push(("property", property_name(request.property_identifier)));
+                    if let Some(index) = request.property_array_index {
+                        fields.push(("array_index", index.to_string()));
+                    }
+                    return;
+                }
+            }
+            ConfirmedServiceChoice::WriteProperty => {
+                if let Ok(request) = WritePropertyRequest::decode(data) {
+                    fields.push(("object", object_identifier_json(&request.object_identifier)));
+                    fields.push(("property", property_name(request.property_identifier)));
+                    if let Some(index) = request.property_array_index {
+                        fields.push(("array_index", index.to_string()));
+                    }
+                    let value = match decode_property_values(&request.property_value) {
+                        Some(value) => property_value_json(&value),
+                        None => json_string(&hex_string(&request.property_value)),
+                    };
+                    fields.push(("value", value));
+                    if let Some(priority) = request.priority {
+                        fields.push(("priority", priority.to_string()));
+                    }
+                    return;
+                }
+            }
+            _ => {}
+        }
+        fields.push(("service_data", json_string(&hex_string(data))));
+    }
+
+    fn push_unconfirmed_request_fields(
+        fields: &mut Vec<(&str, String)>,
+        service_choice: UnconfirmedServiceChoice,
+        data: &[u8],
+    ) {
+        match service_choice {
+            UnconfirmedServiceChoice::IAm => {
+                if let Ok(request) = IAmRequest::decode(data) {
+                    fields.push(("object", object_identifier_json(&request.device_identifier)));
+                    fields.push((
+                        "max_apdu_length_accepted",
+                        request.max_apdu_length_accepted.to_string(),
+                    ));
+                    let segmentation = format!("{:?}", request.segmentation_supported);
+                    fields.push(("segmentation_supported", json_string(&segmentation)));
+                    fields.push(("vendor_id", request.vendor_identifier.to_string()));
+                    return;
+                }
+            }
+            UnconfirmedServiceChoice::WhoIs => {
+                if let Ok(request) = WhoIsRequest::decode(data) {
+                    if let (Some(low), Some(high)) = (
+                        request.device_instance_range_low_limit,
+                        request.device_instance_range_high_limit,
+                    ) {
+                        fields.push(("low_limit", low.to_string()));
+                        fields.push(("high_limit", high.to_string()));
+                    }
+                    return;
+                }
+            }
+            _ => {}
+        }
+        fields.push(("service_data", json_string(&hex_string(data))));
+    }
+
+    /// Decode a property value; several values become an array
+    fn decode_property_values(mut data: &[u8]) -> Option<PropertyValue> {
+        let mut values = Vec::new();
+        while !data.is_empty() {
+            let (value, consumed) = PropertyValue::decode(data).ok()?;
+            values.push(value);
+            data = &data[consumed..];
+        }
+        match values.len() {
+            1 => values.pop(),
+            _ => Some(PropertyValue::Array(values)),
+        }
+    }
+
+    fn confirmed_service_name(service_choice: u8) -> String {
+        match ConfirmedServiceChoice::try_from(service_choice) {
+            Ok(service) => json_string(&format!("{:?}", service)),
+            Err(_) => json_string(&format!("Unknown({})", service_choice)),
+        }
+    }
+
+    fn property_name(property_identifier: u32) -> String {
+        json_string(&format!(
+            "{:?}",
+            PropertyIdentifier::from(property_identifier)
+        ))
+    }
+
+    fn object_identifier_json(id: &ObjectIdentifier) -> String {
+        json_object(&[
+            ("type", json_string(&format!("{:?}", id.object_type))),
+            ("instance", id.instance.to_string()),
+        ])
+    }
+
+    fn property_values_json(values: &[PropertyValue]) -> String {
+        let values: Vec<String> = values.iter().map(property_value_json).collect();
+        format!("[{}]", values.join(","))
+    }
+
+    fn json_object(fields: &[(&str, String)]) -> String {
+        let fields: Vec<String> = fields
+            .iter()
+            .map(|(key, value)| format!("{}:{}", json_string(key), value))
+            .collect();
+        format!("{{{}}}", fields.join(","))
+    }
+
+    /// JSON has no NaN or infinity, so those are given as strings
+    fn json_number(value: f64) -> String {
+        if value.is_finite() {
+            value.to_string()
+        } else {
+            json_string(&value.to_string())
+        }
+    }
+
+    fn json_string(text: &str) -> String {
+        let mut result = String::from("\"");
+        for c in text.chars() {
+            match c {
+                '"' => result.push_str("\\\""),
+                '\\' => result.push_str("\\\\"),
+                '\n' => result.push_str("\\n"),
+                '\r' => result.push_str("\\r"),
+                '\t' => result.push_str("\\t"),
+                c if (c as u32) < 0x20 => result.push_str(&format!("\\u{:04x}", c as u32)),
+                c => result.push(c),
+            }
+        }
+        result.push('"');
+        result
+    }
+
+    fn hex_string(data: &[u8]) -> String {
+        data.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
 }
 
 #[cfg(test)]
@@ -1698,4 +2036,94 @@ mod tests {
         assert!(result.contains("01 02 03 04"));
         assert!(result.contains("Start") || result.contains("Middle"));
     }
+
+    #[test]
+    fn test_format_apdu_json() {
+        use crate::app::{Apdu, MaxApdu, MaxSegments};
+        use crate::object::{ObjectIdentifier, ObjectType, PropertyValue};
+        use crate::service::{ConfirmedServiceChoice, ReadPropertyAck, WritePropertyRequest};
+
+        let mut value = Vec::new();
+        PropertyValue::Real(21.5).encode(&mut value).unwrap();
+        let mut service_data = Vec::new();
+        WritePropertyRequest::with_priority(
+            ObjectIdentifier::new(ObjectType::AnalogValue, 2),
+            85,
+            value,
+            8,
+        )
+        .encode(&mut service_data)
+        .unwrap();
+        let request = Apdu::ConfirmedRequest {
+            segmented: false,
+            more_follows: false,
+            segmented_response_accepted: false,
+            max_segments: MaxSegments::Unspecified,
+            max_response_size: MaxApdu::Up1476,
+            invoke_id: 4,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::WriteProperty,
+            service_data,
+        };
+        assert_eq!(
+            request.to_json(),
+            concat!(
+                r#"{"pdu_type":"ConfirmedRequest","invoke_id":4,"service":"WriteProperty","#,
+                r#""object":{"type":"AnalogValue","instance":2},"property":"PresentValue","#,
+                r#""value":{"type":"Real","value":21.5},"priority":8}"#
+            )
+        );
+
+        let mut service_data = Vec::new();
+        ReadPropertyAck::new(
+            ObjectIdentifier::new(ObjectType::Device, 1),
+            77,
+            PropertyValue::CharacterString("Plant \"A\"".to_string()),
+        )
+        .encode(&mut service_data)
+        .unwrap();
+        let ack = Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id: 4,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: ConfirmedServiceChoice::ReadProperty as u8,
+            service_data,
+        };
+        let json: serde_json::Value = serde_json::from_str(&ack.to_json()).unwrap();
+        assert_eq!(json["service"], "ReadProperty");
+        assert_eq!(json["object"]["type"], "Device");
+        assert_eq!(json["property"], "ObjectName");
+        assert_eq!(json["value"]["value"], "Plant \"A\"");
+
+        let error = Apdu::Error {
+            invoke_id: 5,
+            service_choice: ConfirmedServiceChoice::ReadProperty as u8,
+            error_class: 2,
+            error_code: 32,
+            error_data: Vec::new(),
+        };
+        assert_eq!(
+            error.to_json(),
+            concat!(
+                r#"{"pdu_type":"Error","invoke_id":5,"service":"ReadProperty","#,
+                r#""error_class":"Property","error_code":"UnknownProperty"}"#
+            )
+        );
+
+        let unknown = Apdu::ComplexAck {
+            segmented: false,
+            more_follows: false,
+            invoke_id: 6,
+            sequence_number: None,
+            proposed_window_size: None,
+            service_choice: 99,
+            service_data: vec![0xAB, 0x01],
+        };
+        assert!(unknown
+            .to_json()
+            .ends_with(r#""service":"Unknown(99)","service_data":"ab01"}"#));
+    }
 }